@@ -76,10 +76,23 @@ class Database {
       if (!success) {
         throw Exception("Failed to initialize database: $message");
       }
+      await _setTwoFactorKey();
     }
     return _instance;
   }
 
+  /// TOTP secrets in the local database are sealed with a key kept in secure
+  /// storage, created on first use.
+  Future<void> _setTwoFactorKey() async {
+    final storage = SecureStorage();
+    var key = await storage.getTwoFactorKey();
+    if (key == null) {
+      key = api.generateTwoFactorEncryptionKey();
+      await storage.setTwoFactorKey(key);
+    }
+    api.setTwoFactorEncryptionKey(key: key);
+  }
+
   Future<String> _getDatabasePath() async {
     Directory appDocDir;
     if (Platform.isAndroid || Platform.isIOS) {
@@ -106,28 +119,72 @@ class Database {
       final dio = Dio(BaseOptions(baseUrl: endpoint));
       final db = _toAPI(dio);
       await db.initDb();
-      Response response = await dio.post('/auth/register', data: user.toJson());
-      final tokens = JwtTokens.fromJson(response.data);
+      Response response = await dio.post('/auth/register', data: {
+        'name': user.name,
+        'email': user.email,
+        'password': user.password,
+        're_password': user.rePassword,
+        'recovery_key': user.recoveryKey,
+      });
+      final tokens = _tokensFromJson(response.data);
       _setToken(tokens.refreshToken, tokens.accessToken);
       return tokens;
     }
   }
 
-  Future<JwtTokens> login(LoginData login, String? endpoint) async {
+  // The server sends its JSON in snake_case
+  JwtTokens _tokensFromJson(Map<String, dynamic> json) {
+    return JwtTokens(
+      accessToken: json['access_token'] as String,
+      refreshToken: json['refresh_token'] as String,
+      recoveryKey: json['recovery_key'] as String?,
+    );
+  }
+
+  // A challenge when the account has two-factor authentication on
+  LoginResult _loginResultFromJson(Map<String, dynamic> json) {
+    if (json.containsKey('challenge_token')) {
+      return LoginResult.twoFactorRequired(TwoFactorChallenge(
+        challengeToken: json['challenge_token'] as String,
+        expiresIn: BigInt.from(json['expires_in'] as int),
+      ));
+    }
+    return LoginResult.tokens(_tokensFromJson(json));
+  }
+
+  /// Signs in with a password. Tokens are kept unless the account asks for
+  /// a two-factor code, then [loginWithTwoFactor] finishes the login.
+  Future<LoginResult> login(LoginData login, String? endpoint) async {
+    final LoginResult result;
     if (endpoint == null) {
       await instance._toLocal().initDb();
-      final tokens = await api.login(user: login);
-      _setToken(tokens.refreshToken, tokens.accessToken);
-      return tokens;
+      result = await api.login(user: login);
     } else {
       final dio = Dio(BaseOptions(baseUrl: endpoint));
       final db = _toAPI(dio);
       await db.initDb();
       Response response = await dio.post('/auth/login', data: login.toJson());
-      final tokens = JwtTokens.fromJson(response.data);
-      _setToken(tokens.refreshToken, tokens.accessToken);
-      return tokens;
+      result = _loginResultFromJson(response.data);
+    }
+    if (result is LoginResult_Tokens) {
+      _setToken(result.field0.refreshToken, result.field0.accessToken);
     }
+    return result;
+  }
+
+  Future<JwtTokens> loginWithTwoFactor(TwoFactorLoginData data) async {
+    final JwtTokens tokens;
+    if (_config.type == DatabaseType.local) {
+      tokens = await api.loginWithTwoFactor(data: data);
+    } else {
+      Response response = await dio.post('/auth/login/2fa', data: {
+        'challenge_token': data.challengeToken,
+        'code': data.code,
+      });
+      tokens = _tokensFromJson(response.data);
+    }
+    _setToken(tokens.refreshToken, tokens.accessToken);
+    return tokens;
   }
 
   Future<Login> getLogin(String id) async {
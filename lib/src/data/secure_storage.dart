@@ -39,4 +39,12 @@ class SecureStorage {
   Future<void> setPassword(String password) async {
     await _storage.write(key: 'password', value: password);
   }
+
+  Future<String?> getTwoFactorKey() async {
+    return await _storage.read(key: 'two_factor_key');
+  }
+
+  Future<void> setTwoFactorKey(String key) async {
+    await _storage.write(key: 'two_factor_key', value: key);
+  }
 }
@@ -1,9 +1,21 @@
+import '../rust/models/others/authentication.dart';
+
 class AuthState {
   final bool isAuthenticated;
 
+  /// Issued at registration, shown to the user once
+  final String? recoveryKey;
+
+  /// Set while a login waits for a two-factor code
+  final TwoFactorChallenge? twoFactorChallenge;
+
   AuthState({
     required this.isAuthenticated,
+    this.recoveryKey,
+    this.twoFactorChallenge,
   });
 }
 
 enum AuthType { local, server }
+
+enum LoginStatus { success, twoFactorRequired, failed }
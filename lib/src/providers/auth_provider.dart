@@ -15,14 +15,47 @@ class Auth extends _$Auth {
     );
   }
 
-  Future<bool> login(
+  /// Signs in with a password. Accounts with two-factor authentication on
+  /// finish with [loginWithTwoFactor].
+  Future<LoginStatus> login(
     String email,
     String password, {
     String? endpoint,
   }) async {
     try {
       final loginData = LoginData(email: email, password: password);
-      final _ = await Database.instance.login(loginData, endpoint);
+      final result = await Database.instance.login(loginData, endpoint);
+      if (result is LoginResult_TwoFactorRequired) {
+        state = AuthState(
+          isAuthenticated: false,
+          twoFactorChallenge: result.field0,
+        );
+        return LoginStatus.twoFactorRequired;
+      }
+
+      state = AuthState(
+        isAuthenticated: true,
+      );
+
+      return LoginStatus.success;
+    } catch (e) {
+      return LoginStatus.failed;
+    }
+  }
+
+  /// Finishes a login with a code from the authenticator app, or one of the
+  /// backup codes.
+  Future<bool> loginWithTwoFactor(String code) async {
+    final challenge = state.twoFactorChallenge;
+    if (challenge == null) {
+      return false;
+    }
+    try {
+      final data = TwoFactorLoginData(
+        challengeToken: challenge.challengeToken,
+        code: code,
+      );
+      final _ = await Database.instance.loginWithTwoFactor(data);
 
       state = AuthState(
         isAuthenticated: true,
@@ -40,6 +73,7 @@ class Auth extends _$Auth {
     String password,
     String rePassword, {
     String? endpoint,
+    bool recoveryKey = false,
   }) async {
     try {
       final database = Database();
@@ -48,11 +82,13 @@ class Auth extends _$Auth {
         password: password,
         name: name,
         rePassword: rePassword,
+        recoveryKey: recoveryKey,
       );
-      final _ = await database.register(registerData, endpoint);
+      final tokens = await database.register(registerData, endpoint);
 
       state = AuthState(
         isAuthenticated: true,
+        recoveryKey: tokens.recoveryKey,
       );
 
       return true;
@@ -61,6 +97,13 @@ class Auth extends _$Auth {
     }
   }
 
+  /// Forgets the recovery key once the user has seen it.
+  void clearRecoveryKey() {
+    state = AuthState(
+      isAuthenticated: state.isAuthenticated,
+    );
+  }
+
   void logout() {
     state = AuthState(
       isAuthenticated: false,
@@ -9,12 +9,51 @@ import '../models/identity_cards.dart';
 import '../models/logins.dart';
 import '../models/notes.dart';
 import '../models/others/authentication.dart';
+import '../models/others/breach.dart';
+import '../models/others/generator.dart';
+import '../models/others/health.dart';
+import '../models/others/kdf_settings.dart';
+import '../models/others/otp.dart';
+import '../models/others/passkey.dart';
+import '../models/others/password_policy.dart';
+import '../models/others/qr.dart';
+import '../models/others/rekey_progress.dart';
+import '../models/others/share_settings.dart';
+import '../models/others/strength.dart';
+import '../models/others/two_factor.dart';
+import '../models/passkeys.dart';
+import '../models/password_history.dart';
 import '../models/tags.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `parse_shares`
+
 String greet({required String name}) =>
     RustLib.instance.api.crateApiSimpleGreet(name: name);
 
+/// Selects the cipher suite new values are sealed with, one of `auto`,
+/// `aes-256-gcm` or `xchacha20-poly1305`. Existing values stay readable.
+void setEncryptionCipher({required String policy}) =>
+    RustLib.instance.api.crateApiSimpleSetEncryptionCipher(policy: policy);
+
+/// Benchmarks Argon2id on this device, `max_memory` is in KiB.
+KdfSettings calibrateKdfSettings(
+        {required BigInt targetMs, required int maxMemory}) =>
+    RustLib.instance.api.crateApiSimpleCalibrateKdfSettings(
+        targetMs: targetMs, maxMemory: maxMemory);
+
+Future<KdfSettings> getKdfSettings({required String token}) =>
+    RustLib.instance.api.crateApiSimpleGetKdfSettings(token: token);
+
+/// Moves the vault to new Argon2id settings, the master password confirms
+/// the change.
+Future<void> saveKdfSettings(
+        {required KdfSettings settings,
+        required String masterPassword,
+        required String token}) =>
+    RustLib.instance.api.crateApiSimpleSaveKdfSettings(
+        settings: settings, masterPassword: masterPassword, token: token);
+
 Future<String> encryptData({required String data, required String password}) =>
     RustLib.instance.api
         .crateApiSimpleEncryptData(data: data, password: password);
@@ -32,9 +71,202 @@ Future<bool> isDatabaseInitialized() =>
 Future<JwtTokens> register({required RegisterData user}) =>
     RustLib.instance.api.crateApiSimpleRegister(user: user);
 
-Future<JwtTokens> login({required LoginData user}) =>
+/// Signs in with a password. Accounts with two-factor authentication get
+/// a challenge to finish with [`login_with_two_factor`].
+Future<LoginResult> login({required LoginData user}) =>
     RustLib.instance.api.crateApiSimpleLogin(user: user);
 
+Future<JwtTokens> loginWithTwoFactor({required TwoFactorLoginData data}) =>
+    RustLib.instance.api.crateApiSimpleLoginWithTwoFactor(data: data);
+
+/// Trades a refresh token for new tokens, the old refresh token stops
+/// working.
+Future<JwtTokens> refreshTokens({required RefreshTokenData data}) =>
+    RustLib.instance.api.crateApiSimpleRefreshTokens(data: data);
+
+/// Sets the key TOTP secrets are sealed with in the local database, 64 hex
+/// digits the app keeps in secure storage. Two-factor authentication can't
+/// be set up without it.
+void setTwoFactorEncryptionKey({required String key}) =>
+    RustLib.instance.api.crateApiSimpleSetTwoFactorEncryptionKey(key: key);
+
+/// A new random key for [`set_two_factor_encryption_key`].
+String generateTwoFactorEncryptionKey() =>
+    RustLib.instance.api.crateApiSimpleGenerateTwoFactorEncryptionKey();
+
+/// Starts two-factor enrollment, codes are needed on login once
+/// [`confirm_two_factor_setup`] succeeds.
+Future<TwoFactorSetup> beginTwoFactorSetup({required String token}) =>
+    RustLib.instance.api.crateApiSimpleBeginTwoFactorSetup(token: token);
+
+/// Turns two-factor authentication on, returning backup codes the user
+/// has to write down, they are not shown again.
+Future<TwoFactorBackupCodes> confirmTwoFactorSetup(
+        {required String code, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleConfirmTwoFactorSetup(code: code, token: token);
+
+Future<void> turnOffTwoFactor({required String code, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleTurnOffTwoFactor(code: code, token: token);
+
+/// Replaces the backup codes, `code` is from the authenticator or one of
+/// the current backup codes.
+Future<TwoFactorBackupCodes> regenerateTwoFactorBackupCodes(
+        {required String code, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleRegenerateTwoFactorBackupCodes(code: code, token: token);
+
+Future<TwoFactorStatus> getTwoFactorStatus({required String token}) =>
+    RustLib.instance.api.crateApiSimpleGetTwoFactorStatus(token: token);
+
+/// Unlocks the vault of the signed in user with the master password.
+Future<void> unlock({required String masterPassword, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleUnlock(masterPassword: masterPassword, token: token);
+
+/// Locks the vault of the signed in user and wipes its data key.
+Future<void> lock({required String token}) =>
+    RustLib.instance.api.crateApiSimpleLock(token: token);
+
+Future<bool> isVaultUnlocked({required String token}) =>
+    RustLib.instance.api.crateApiSimpleIsVaultUnlocked(token: token);
+
+/// Changes the account password, which also re-wraps the vault's data key.
+Future<void> changePassword(
+        {required ChangePasswordData data, required String token}) =>
+    RustLib.instance.api.crateApiSimpleChangePassword(data: data, token: token);
+
+/// Sets a new password with the recovery key and signs the user in, the
+/// returned tokens carry the rotated recovery key.
+Future<JwtTokens> recover({required RecoverAccountData data}) =>
+    RustLib.instance.api.crateApiSimpleRecover(data: data);
+
+/// Issues a new recovery key for the unlocked vault, the previous one stops
+/// working.
+Future<String> newRecoveryKey({required String token}) =>
+    RustLib.instance.api.crateApiSimpleNewRecoveryKey(token: token);
+
+/// Issues a new recovery key split among trusted people, any threshold of
+/// the returned shares restore it with [`combine_recovery_key_shares`].
+Future<List<String>> newRecoveryKeyShares(
+        {required ShareSettings settings, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleNewRecoveryKeyShares(settings: settings, token: token);
+
+/// Restores a recovery key from the shares printed by
+/// [`new_recovery_key_shares`].
+String combineRecoveryKeyShares({required List<String> shares}) =>
+    RustLib.instance.api.crateApiSimpleCombineRecoveryKeyShares(shares: shares);
+
+/// Generates a password, pronounceable password or passphrase and reports
+/// its entropy.
+GeneratedPassword generatePassword({required GeneratorOptions options}) =>
+    RustLib.instance.api.crateApiSimpleGeneratePassword(options: options);
+
+/// Encodes `payload` as a QR code for the app to draw itself.
+QrMatrix qrCodeMatrix({required String payload, required QrOptions options}) =>
+    RustLib.instance.api
+        .crateApiSimpleQrCodeMatrix(payload: payload, options: options);
+
+/// Renders `payload` as a QR code SVG image, the same the server serves.
+String qrCodeSvg({required String payload, required QrOptions options}) =>
+    RustLib.instance.api
+        .crateApiSimpleQrCodeSvg(payload: payload, options: options);
+
+/// Renders `payload` as a QR code PNG image.
+Uint8List qrCodePng({required String payload, required QrOptions options}) =>
+    RustLib.instance.api
+        .crateApiSimpleQrCodePng(payload: payload, options: options);
+
+/// Sets the policy account passwords are checked against on registration
+/// and password changes.
+void setAccountPasswordPolicy({required PasswordPolicy policy}) =>
+    RustLib.instance.api.crateApiSimpleSetAccountPasswordPolicy(policy: policy);
+
+PasswordPolicy getAccountPasswordPolicy() =>
+    RustLib.instance.api.crateApiSimpleGetAccountPasswordPolicy();
+
+/// Sets the Argon2id parameters account passwords are hashed with. Existing
+/// hashes are upgraded as their users log in.
+void setAccountPasswordHashSettings({required KdfSettings settings}) =>
+    RustLib.instance.api
+        .crateApiSimpleSetAccountPasswordHashSettings(settings: settings);
+
+KdfSettings getAccountPasswordHashSettings() =>
+    RustLib.instance.api.crateApiSimpleGetAccountPasswordHashSettings();
+
+/// Every rule of the account password policy `password` fails, for the
+/// registration and change password forms.
+List<PasswordRule> checkAccountPassword(
+        {required String password, required List<String> userInputs}) =>
+    RustLib.instance.api.crateApiSimpleCheckAccountPassword(
+        password: password, userInputs: userInputs);
+
+/// Rates how hard `password` is to guess, for strength meters in the item
+/// editors. `user_inputs` are words the user is likely to use, such as their
+/// name and email.
+PasswordStrength estimatePasswordStrength(
+        {required String password, required List<String> userInputs}) =>
+    RustLib.instance.api.crateApiSimpleEstimatePasswordStrength(
+        password: password, userInputs: userInputs);
+
+/// Splits any secret text into printable shares, any `threshold` of which
+/// recover it with [`combine_secret_shares`].
+List<String> splitSecretIntoShares(
+        {required String secret, required ShareSettings settings}) =>
+    RustLib.instance.api.crateApiSimpleSplitSecretIntoShares(
+        secret: secret, settings: settings);
+
+String combineSecretShares({required List<String> shares}) =>
+    RustLib.instance.api.crateApiSimpleCombineSecretShares(shares: shares);
+
+/// Moves every vault item to a freshly generated data key, poll
+/// [`get_rekey_vault_progress`] while it runs. A new recovery key replaces
+/// the previous one when the user had one.
+Future<RekeyResult> rekey(
+        {required String masterPassword, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleRekey(masterPassword: masterPassword, token: token);
+
+Future<RekeyProgress?> getRekeyVaultProgress({required String token}) =>
+    RustLib.instance.api.crateApiSimpleGetRekeyVaultProgress(token: token);
+
+/// Builds a breach index from a downloaded Pwned Passwords dataset, a file
+/// of `HASH:COUNT` lines or a directory of range files. Returns the number
+/// of records.
+Future<BigInt> buildPasswordBreachIndex(
+        {required String datasetPath, required String indexPath}) =>
+    RustLib.instance.api.crateApiSimpleBuildPasswordBreachIndex(
+        datasetPath: datasetPath, indexPath: indexPath);
+
+/// Loads the breach index used by [`get_password_breach_count`] and
+/// [`check_breached_passwords`].
+Future<BigInt> loadPasswordBreachIndex({required String indexPath}) =>
+    RustLib.instance.api
+        .crateApiSimpleLoadPasswordBreachIndex(indexPath: indexPath);
+
+/// Times `password` appears in the breach index, 0 if it does not.
+Future<int> getPasswordBreachCount({required String password}) =>
+    RustLib.instance.api
+        .crateApiSimpleGetPasswordBreachCount(password: password);
+
+/// Checks every login password against the breach index and stores the
+/// result on the logins.
+Future<BreachReport> checkBreachedPasswords({required String token}) =>
+    RustLib.instance.api.crateApiSimpleCheckBreachedPasswords(token: token);
+
+/// Reports reused, weak, stale and empty login passwords. Passwords older
+/// than `stale_days` days are stale, 365 by default.
+Future<VaultHealth> getVaultHealth({int? staleDays, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleGetVaultHealth(staleDays: staleDays, token: token);
+
+/// Sets the idle period after which the vault locks itself, 0 disables
+/// auto-lock.
+void setAutoLockSeconds({required BigInt seconds}) =>
+    RustLib.instance.api.crateApiSimpleSetAutoLockSeconds(seconds: seconds);
+
 Future<FinancialCard> getFinancialCard(
         {required String id, required String token}) =>
     RustLib.instance.api.crateApiSimpleGetFinancialCard(id: id, token: token);
@@ -103,6 +335,26 @@ Future<Login> deleteLogin({required String id, required String token}) =>
 Future<List<Login>> listLogin({required String query, required String token}) =>
     RustLib.instance.api.crateApiSimpleListLogin(query: query, token: token);
 
+/// Previous passwords of a login, most recent first.
+Future<List<PasswordHistory>> listLoginPasswordHistory(
+        {required String id, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleListLoginPasswordHistory(id: id, token: token);
+
+/// Current code of the authenticator stored with a login, and the seconds
+/// until it changes.
+Future<OtpCode> getLoginTotpCode({required String id, required String token}) =>
+    RustLib.instance.api.crateApiSimpleGetLoginTotpCode(id: id, token: token);
+
+/// Puts a previous password back on a login, the current one goes to the
+/// history.
+Future<Login> restoreLoginPassword(
+        {required String id,
+        required String historyId,
+        required String token}) =>
+    RustLib.instance.api.crateApiSimpleRestoreLoginPassword(
+        id: id, historyId: historyId, token: token);
+
 Future<Note> getNote({required String id, required String token}) =>
     RustLib.instance.api.crateApiSimpleGetNote(id: id, token: token);
 
@@ -120,6 +372,44 @@ Future<Note> deleteNote({required String id, required String token}) =>
 Future<List<Note>> listNote({required String query, required String token}) =>
     RustLib.instance.api.crateApiSimpleListNote(query: query, token: token);
 
+Future<Passkey> getPasskey({required String id, required String token}) =>
+    RustLib.instance.api.crateApiSimpleGetPasskey(id: id, token: token);
+
+Future<Passkey> postPasskey({required Passkey data, required String token}) =>
+    RustLib.instance.api.crateApiSimplePostPasskey(data: data, token: token);
+
+Future<Passkey> putPasskey(
+        {required String id, required Passkey data, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimplePutPasskey(id: id, data: data, token: token);
+
+Future<Passkey> deletePasskey({required String id, required String token}) =>
+    RustLib.instance.api.crateApiSimpleDeletePasskey(id: id, token: token);
+
+Future<List<Passkey>> listPasskey(
+        {required String query, required String token}) =>
+    RustLib.instance.api.crateApiSimpleListPasskey(query: query, token: token);
+
+/// Passkeys for a relying party, to offer when it asks to sign in.
+Future<List<Passkey>> listPasskeysForRp(
+        {required String rpId, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleListPasskeysForRp(rpId: rpId, token: token);
+
+/// Registers a new passkey with a relying party and keeps it in the vault.
+Future<PasskeyRegistration> createPasskey(
+        {required PasskeyCreation request, required String token}) =>
+    RustLib.instance.api
+        .crateApiSimpleCreatePasskey(request: request, token: token);
+
+/// Signs a relying party's challenge with a stored passkey.
+Future<PasskeyAssertion> signPasskeyAssertion(
+        {required String id,
+        required PasskeyAssertionRequest request,
+        required String token}) =>
+    RustLib.instance.api.crateApiSimpleSignPasskeyAssertion(
+        id: id, request: request, token: token);
+
 Future<Tag> getTag({required String id, required String token}) =>
     RustLib.instance.api.crateApiSimpleGetTag(id: id, token: token);
 
@@ -14,6 +14,20 @@ import 'models/identity_cards.dart';
 import 'models/logins.dart';
 import 'models/notes.dart';
 import 'models/others/authentication.dart';
+import 'models/others/breach.dart';
+import 'models/others/generator.dart';
+import 'models/others/health.dart';
+import 'models/others/kdf_settings.dart';
+import 'models/others/otp.dart';
+import 'models/others/passkey.dart';
+import 'models/others/password_policy.dart';
+import 'models/others/qr.dart';
+import 'models/others/rekey_progress.dart';
+import 'models/others/share_settings.dart';
+import 'models/others/strength.dart';
+import 'models/others/two_factor.dart';
+import 'models/passkeys.dart';
+import 'models/password_history.dart';
 import 'models/tags.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
@@ -74,7 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.7.0';
 
   @override
-  int get rustContentHash => -1303389640;
+  int get rustContentHash => 1484889814;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -87,6 +101,34 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 abstract class RustLibApi extends BaseApi {
   Future<void> crateApiSimpleBackupDataToServer();
 
+  Future<TwoFactorSetup> crateApiSimpleBeginTwoFactorSetup(
+      {required String token});
+
+  Future<BigInt> crateApiSimpleBuildPasswordBreachIndex(
+      {required String datasetPath, required String indexPath});
+
+  KdfSettings crateApiSimpleCalibrateKdfSettings(
+      {required BigInt targetMs, required int maxMemory});
+
+  Future<void> crateApiSimpleChangePassword(
+      {required ChangePasswordData data, required String token});
+
+  List<PasswordRule> crateApiSimpleCheckAccountPassword(
+      {required String password, required List<String> userInputs});
+
+  Future<BreachReport> crateApiSimpleCheckBreachedPasswords(
+      {required String token});
+
+  String crateApiSimpleCombineRecoveryKeyShares({required List<String> shares});
+
+  String crateApiSimpleCombineSecretShares({required List<String> shares});
+
+  Future<TwoFactorBackupCodes> crateApiSimpleConfirmTwoFactorSetup(
+      {required String code, required String token});
+
+  Future<PasskeyRegistration> crateApiSimpleCreatePasskey(
+      {required PasskeyCreation request, required String token});
+
   Future<Tag> crateApiSimpleCreateTag(
       {required Tag tag, required String token});
 
@@ -105,28 +147,62 @@ abstract class RustLibApi extends BaseApi {
   Future<Note> crateApiSimpleDeleteNote(
       {required String id, required String token});
 
+  Future<Passkey> crateApiSimpleDeletePasskey(
+      {required String id, required String token});
+
   Future<Tag> crateApiSimpleDeleteTag(
       {required String id, required String token});
 
   Future<String> crateApiSimpleEncryptData(
       {required String data, required String password});
 
+  PasswordStrength crateApiSimpleEstimatePasswordStrength(
+      {required String password, required List<String> userInputs});
+
   Future<String> crateApiSimpleExportAllDataToJson();
 
+  GeneratedPassword crateApiSimpleGeneratePassword(
+      {required GeneratorOptions options});
+
+  String crateApiSimpleGenerateTwoFactorEncryptionKey();
+
+  KdfSettings crateApiSimpleGetAccountPasswordHashSettings();
+
+  PasswordPolicy crateApiSimpleGetAccountPasswordPolicy();
+
   Future<FinancialCard> crateApiSimpleGetFinancialCard(
       {required String id, required String token});
 
   Future<IdentityCard> crateApiSimpleGetIdentityCard(
       {required String id, required String token});
 
+  Future<KdfSettings> crateApiSimpleGetKdfSettings({required String token});
+
   Future<Login> crateApiSimpleGetLogin(
       {required String id, required String token});
 
+  Future<OtpCode> crateApiSimpleGetLoginTotpCode(
+      {required String id, required String token});
+
   Future<Note> crateApiSimpleGetNote(
       {required String id, required String token});
 
+  Future<Passkey> crateApiSimpleGetPasskey(
+      {required String id, required String token});
+
+  Future<int> crateApiSimpleGetPasswordBreachCount({required String password});
+
+  Future<RekeyProgress?> crateApiSimpleGetRekeyVaultProgress(
+      {required String token});
+
   Future<Tag> crateApiSimpleGetTag({required String id, required String token});
 
+  Future<TwoFactorStatus> crateApiSimpleGetTwoFactorStatus(
+      {required String token});
+
+  Future<VaultHealth> crateApiSimpleGetVaultHealth(
+      {int? staleDays, required String token});
+
   String crateApiSimpleGreet({required String name});
 
   Future<(bool, String)> crateApiSimpleInit({required String dbPath});
@@ -135,6 +211,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateApiSimpleIsDatabaseInitialized();
 
+  Future<bool> crateApiSimpleIsVaultUnlocked({required String token});
+
   Future<List<FinancialCard>> crateApiSimpleListFinancialCard(
       {required String query, required String token});
 
@@ -144,13 +222,35 @@ abstract class RustLibApi extends BaseApi {
   Future<List<Login>> crateApiSimpleListLogin(
       {required String query, required String token});
 
+  Future<List<PasswordHistory>> crateApiSimpleListLoginPasswordHistory(
+      {required String id, required String token});
+
   Future<List<Note>> crateApiSimpleListNote(
       {required String query, required String token});
 
+  Future<List<Passkey>> crateApiSimpleListPasskey(
+      {required String query, required String token});
+
+  Future<List<Passkey>> crateApiSimpleListPasskeysForRp(
+      {required String rpId, required String token});
+
   Future<List<Tag>> crateApiSimpleListTags(
       {required String query, required String token});
 
-  Future<JwtTokens> crateApiSimpleLogin({required LoginData user});
+  Future<BigInt> crateApiSimpleLoadPasswordBreachIndex(
+      {required String indexPath});
+
+  Future<void> crateApiSimpleLock({required String token});
+
+  Future<LoginResult> crateApiSimpleLogin({required LoginData user});
+
+  Future<JwtTokens> crateApiSimpleLoginWithTwoFactor(
+      {required TwoFactorLoginData data});
+
+  Future<String> crateApiSimpleNewRecoveryKey({required String token});
+
+  Future<List<String>> crateApiSimpleNewRecoveryKeyShares(
+      {required ShareSettings settings, required String token});
 
   Future<FinancialCard> crateApiSimplePostFinancialCard(
       {required FinancialCard data, required String token});
@@ -164,6 +264,9 @@ abstract class RustLibApi extends BaseApi {
   Future<Note> crateApiSimplePostNote(
       {required Note data, required String token});
 
+  Future<Passkey> crateApiSimplePostPasskey(
+      {required Passkey data, required String token});
+
   Future<FinancialCard> crateApiSimplePutFinancialCard(
       {required String id, required FinancialCard data, required String token});
 
@@ -176,15 +279,71 @@ abstract class RustLibApi extends BaseApi {
   Future<Note> crateApiSimplePutNote(
       {required String id, required Note data, required String token});
 
+  Future<Passkey> crateApiSimplePutPasskey(
+      {required String id, required Passkey data, required String token});
+
   Future<Tag> crateApiSimplePutTag(
       {required String id, required Tag tag, required String token});
 
+  QrMatrix crateApiSimpleQrCodeMatrix(
+      {required String payload, required QrOptions options});
+
+  Uint8List crateApiSimpleQrCodePng(
+      {required String payload, required QrOptions options});
+
+  String crateApiSimpleQrCodeSvg(
+      {required String payload, required QrOptions options});
+
+  Future<JwtTokens> crateApiSimpleRecover({required RecoverAccountData data});
+
+  Future<JwtTokens> crateApiSimpleRefreshTokens(
+      {required RefreshTokenData data});
+
+  Future<TwoFactorBackupCodes> crateApiSimpleRegenerateTwoFactorBackupCodes(
+      {required String code, required String token});
+
   Future<JwtTokens> crateApiSimpleRegister({required RegisterData user});
 
+  Future<RekeyResult> crateApiSimpleRekey(
+      {required String masterPassword, required String token});
+
   Future<void> crateApiSimpleRestoreDataFromJson({required String data});
 
+  Future<Login> crateApiSimpleRestoreLoginPassword(
+      {required String id, required String historyId, required String token});
+
+  Future<void> crateApiSimpleSaveKdfSettings(
+      {required KdfSettings settings,
+      required String masterPassword,
+      required String token});
+
+  void crateApiSimpleSetAccountPasswordHashSettings(
+      {required KdfSettings settings});
+
+  void crateApiSimpleSetAccountPasswordPolicy({required PasswordPolicy policy});
+
+  void crateApiSimpleSetAutoLockSeconds({required BigInt seconds});
+
+  void crateApiSimpleSetEncryptionCipher({required String policy});
+
+  void crateApiSimpleSetTwoFactorEncryptionKey({required String key});
+
+  Future<PasskeyAssertion> crateApiSimpleSignPasskeyAssertion(
+      {required String id,
+      required PasskeyAssertionRequest request,
+      required String token});
+
+  List<String> crateApiSimpleSplitSecretIntoShares(
+      {required String secret, required ShareSettings settings});
+
   Future<bool?> crateApiSimpleToggleFavorite(
       {required String id, required String itemType});
+
+  Future<void> crateApiSimpleTurnOffTwoFactor(
+      {required String code, required String token});
+
+  Future<void> crateApiSimpleUnlock(
+      {required String masterPassword, required String token});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -220,321 +379,320 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<Tag> crateApiSimpleCreateTag(
-      {required Tag tag, required String token}) {
+  Future<TwoFactorSetup> crateApiSimpleBeginTwoFactorSetup(
+      {required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_tag(tag, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_tag,
+        decodeSuccessData: sse_decode_two_factor_setup,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleCreateTagConstMeta,
-      argValues: [tag, token],
+      constMeta: kCrateApiSimpleBeginTwoFactorSetupConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleCreateTagConstMeta => const TaskConstMeta(
-        debugName: "create_tag",
-        argNames: ["tag", "token"],
+  TaskConstMeta get kCrateApiSimpleBeginTwoFactorSetupConstMeta =>
+      const TaskConstMeta(
+        debugName: "begin_two_factor_setup",
+        argNames: ["token"],
       );
 
   @override
-  Future<String> crateApiSimpleDecryptData(
-      {required String data, required String password}) {
+  Future<BigInt> crateApiSimpleBuildPasswordBreachIndex(
+      {required String datasetPath, required String indexPath}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(data, serializer);
-        sse_encode_String(password, serializer);
+        sse_encode_String(datasetPath, serializer);
+        sse_encode_String(indexPath, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_u_64,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleDecryptDataConstMeta,
-      argValues: [data, password],
+      constMeta: kCrateApiSimpleBuildPasswordBreachIndexConstMeta,
+      argValues: [datasetPath, indexPath],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDecryptDataConstMeta => const TaskConstMeta(
-        debugName: "decrypt_data",
-        argNames: ["data", "password"],
+  TaskConstMeta get kCrateApiSimpleBuildPasswordBreachIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "build_password_breach_index",
+        argNames: ["datasetPath", "indexPath"],
       );
 
   @override
-  Future<FinancialCard> crateApiSimpleDeleteFinancialCard(
-      {required String id, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  KdfSettings crateApiSimpleCalibrateKdfSettings(
+      {required BigInt targetMs, required int maxMemory}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
+        sse_encode_u_64(targetMs, serializer);
+        sse_encode_u_32(maxMemory, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_financial_card,
+        decodeSuccessData: sse_decode_kdf_settings,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleDeleteFinancialCardConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCalibrateKdfSettingsConstMeta,
+      argValues: [targetMs, maxMemory],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDeleteFinancialCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleCalibrateKdfSettingsConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_financial_card",
-        argNames: ["id", "token"],
+        debugName: "calibrate_kdf_settings",
+        argNames: ["targetMs", "maxMemory"],
       );
 
   @override
-  Future<IdentityCard> crateApiSimpleDeleteIdentityCard(
-      {required String id, required String token}) {
+  Future<void> crateApiSimpleChangePassword(
+      {required ChangePasswordData data, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_change_password_data(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_identity_card,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleDeleteIdentityCardConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleChangePasswordConstMeta,
+      argValues: [data, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDeleteIdentityCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleChangePasswordConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_identity_card",
-        argNames: ["id", "token"],
+        debugName: "change_password",
+        argNames: ["data", "token"],
       );
 
   @override
-  Future<Login> crateApiSimpleDeleteLogin(
-      {required String id, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  List<PasswordRule> crateApiSimpleCheckAccountPassword(
+      {required String password, required List<String> userInputs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
+        sse_encode_String(password, serializer);
+        sse_encode_list_String(userInputs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_login,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_password_rule,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleDeleteLoginConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCheckAccountPasswordConstMeta,
+      argValues: [password, userInputs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDeleteLoginConstMeta => const TaskConstMeta(
-        debugName: "delete_login",
-        argNames: ["id", "token"],
+  TaskConstMeta get kCrateApiSimpleCheckAccountPasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_account_password",
+        argNames: ["password", "userInputs"],
       );
 
   @override
-  Future<Note> crateApiSimpleDeleteNote(
-      {required String id, required String token}) {
+  Future<BreachReport> crateApiSimpleCheckBreachedPasswords(
+      {required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_note,
+        decodeSuccessData: sse_decode_breach_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleDeleteNoteConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCheckBreachedPasswordsConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDeleteNoteConstMeta => const TaskConstMeta(
-        debugName: "delete_note",
-        argNames: ["id", "token"],
+  TaskConstMeta get kCrateApiSimpleCheckBreachedPasswordsConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_breached_passwords",
+        argNames: ["token"],
       );
 
   @override
-  Future<Tag> crateApiSimpleDeleteTag(
-      {required String id, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  String crateApiSimpleCombineRecoveryKeyShares(
+      {required List<String> shares}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
+        sse_encode_list_String(shares, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_tag,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleDeleteTagConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCombineRecoveryKeySharesConstMeta,
+      argValues: [shares],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleDeleteTagConstMeta => const TaskConstMeta(
-        debugName: "delete_tag",
-        argNames: ["id", "token"],
+  TaskConstMeta get kCrateApiSimpleCombineRecoveryKeySharesConstMeta =>
+      const TaskConstMeta(
+        debugName: "combine_recovery_key_shares",
+        argNames: ["shares"],
       );
 
   @override
-  Future<String> crateApiSimpleEncryptData(
-      {required String data, required String password}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  String crateApiSimpleCombineSecretShares({required List<String> shares}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(data, serializer);
-        sse_encode_String(password, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
+        sse_encode_list_String(shares, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleEncryptDataConstMeta,
-      argValues: [data, password],
+      constMeta: kCrateApiSimpleCombineSecretSharesConstMeta,
+      argValues: [shares],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleEncryptDataConstMeta => const TaskConstMeta(
-        debugName: "encrypt_data",
-        argNames: ["data", "password"],
+  TaskConstMeta get kCrateApiSimpleCombineSecretSharesConstMeta =>
+      const TaskConstMeta(
+        debugName: "combine_secret_shares",
+        argNames: ["shares"],
       );
 
   @override
-  Future<String> crateApiSimpleExportAllDataToJson() {
+  Future<TwoFactorBackupCodes> crateApiSimpleConfirmTwoFactorSetup(
+      {required String code, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(code, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_two_factor_backup_codes,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleExportAllDataToJsonConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSimpleConfirmTwoFactorSetupConstMeta,
+      argValues: [code, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleExportAllDataToJsonConstMeta =>
+  TaskConstMeta get kCrateApiSimpleConfirmTwoFactorSetupConstMeta =>
       const TaskConstMeta(
-        debugName: "export_all_data_to_json",
-        argNames: [],
+        debugName: "confirm_two_factor_setup",
+        argNames: ["code", "token"],
       );
 
   @override
-  Future<FinancialCard> crateApiSimpleGetFinancialCard(
-      {required String id, required String token}) {
+  Future<PasskeyRegistration> crateApiSimpleCreatePasskey(
+      {required PasskeyCreation request, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_passkey_creation(request, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_financial_card,
+        decodeSuccessData: sse_decode_passkey_registration,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGetFinancialCardConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCreatePasskeyConstMeta,
+      argValues: [request, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGetFinancialCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleCreatePasskeyConstMeta =>
       const TaskConstMeta(
-        debugName: "get_financial_card",
-        argNames: ["id", "token"],
+        debugName: "create_passkey",
+        argNames: ["request", "token"],
       );
 
   @override
-  Future<IdentityCard> crateApiSimpleGetIdentityCard(
-      {required String id, required String token}) {
+  Future<Tag> crateApiSimpleCreateTag(
+      {required Tag tag, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_tag(tag, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_identity_card,
+        decodeSuccessData: sse_decode_tag,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGetIdentityCardConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleCreateTagConstMeta,
+      argValues: [tag, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGetIdentityCardConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_identity_card",
-        argNames: ["id", "token"],
+  TaskConstMeta get kCrateApiSimpleCreateTagConstMeta => const TaskConstMeta(
+        debugName: "create_tag",
+        argNames: ["tag", "token"],
       );
 
   @override
-  Future<Login> crateApiSimpleGetLogin(
-      {required String id, required String token}) {
+  Future<String> crateApiSimpleDecryptData(
+      {required String data, required String password}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_String(token, serializer);
+        sse_encode_String(data, serializer);
+        sse_encode_String(password, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 13, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_login,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGetLoginConstMeta,
-      argValues: [id, token],
+      constMeta: kCrateApiSimpleDecryptDataConstMeta,
+      argValues: [data, password],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGetLoginConstMeta => const TaskConstMeta(
-        debugName: "get_login",
-        argNames: ["id", "token"],
+  TaskConstMeta get kCrateApiSimpleDecryptDataConstMeta => const TaskConstMeta(
+        debugName: "decrypt_data",
+        argNames: ["data", "password"],
       );
 
   @override
-  Future<Note> crateApiSimpleGetNote(
+  Future<FinancialCard> crateApiSimpleDeleteFinancialCard(
       {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
@@ -545,22 +703,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 14, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_note,
+        decodeSuccessData: sse_decode_financial_card,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGetNoteConstMeta,
+      constMeta: kCrateApiSimpleDeleteFinancialCardConstMeta,
       argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGetNoteConstMeta => const TaskConstMeta(
-        debugName: "get_note",
+  TaskConstMeta get kCrateApiSimpleDeleteFinancialCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_financial_card",
         argNames: ["id", "token"],
       );
 
   @override
-  Future<Tag> crateApiSimpleGetTag(
+  Future<IdentityCard> crateApiSimpleDeleteIdentityCard(
       {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
@@ -571,703 +730,2489 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 15, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_tag,
+        decodeSuccessData: sse_decode_identity_card,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGetTagConstMeta,
+      constMeta: kCrateApiSimpleDeleteIdentityCardConstMeta,
       argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGetTagConstMeta => const TaskConstMeta(
-        debugName: "get_tag",
+  TaskConstMeta get kCrateApiSimpleDeleteIdentityCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_identity_card",
         argNames: ["id", "token"],
       );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<Login> crateApiSimpleDeleteLogin(
+      {required String id, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_login,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleGreetConstMeta,
-      argValues: [name],
+      constMeta: kCrateApiSimpleDeleteLoginConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
-        debugName: "greet",
-        argNames: ["name"],
+  TaskConstMeta get kCrateApiSimpleDeleteLoginConstMeta => const TaskConstMeta(
+        debugName: "delete_login",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<(bool, String)> crateApiSimpleInit({required String dbPath}) {
+  Future<Note> crateApiSimpleDeleteNote(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dbPath, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_record_bool_string,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_note,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleInitConstMeta,
-      argValues: [dbPath],
+      constMeta: kCrateApiSimpleDeleteNoteConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleInitConstMeta => const TaskConstMeta(
-        debugName: "init",
-        argNames: ["dbPath"],
+  TaskConstMeta get kCrateApiSimpleDeleteNoteConstMeta => const TaskConstMeta(
+        debugName: "delete_note",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<Passkey> crateApiSimpleDeletePasskey(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_passkey,
+        decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSimpleDeletePasskeyConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
+  TaskConstMeta get kCrateApiSimpleDeletePasskeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_passkey",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<bool> crateApiSimpleIsDatabaseInitialized() {
+  Future<Tag> crateApiSimpleDeleteTag(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 19, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_tag,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleIsDatabaseInitializedConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSimpleDeleteTagConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleIsDatabaseInitializedConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_database_initialized",
-        argNames: [],
+  TaskConstMeta get kCrateApiSimpleDeleteTagConstMeta => const TaskConstMeta(
+        debugName: "delete_tag",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<List<FinancialCard>> crateApiSimpleListFinancialCard(
-      {required String query, required String token}) {
+  Future<String> crateApiSimpleEncryptData(
+      {required String data, required String password}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_String(token, serializer);
+        sse_encode_String(data, serializer);
+        sse_encode_String(password, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 20, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_financial_card,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleListFinancialCardConstMeta,
-      argValues: [query, token],
+      constMeta: kCrateApiSimpleEncryptDataConstMeta,
+      argValues: [data, password],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleListFinancialCardConstMeta =>
-      const TaskConstMeta(
-        debugName: "list_financial_card",
-        argNames: ["query", "token"],
+  TaskConstMeta get kCrateApiSimpleEncryptDataConstMeta => const TaskConstMeta(
+        debugName: "encrypt_data",
+        argNames: ["data", "password"],
       );
 
   @override
-  Future<List<IdentityCard>> crateApiSimpleListIdentityCard(
-      {required String query, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  PasswordStrength crateApiSimpleEstimatePasswordStrength(
+      {required String password, required List<String> userInputs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 21, port: port_);
+        sse_encode_String(password, serializer);
+        sse_encode_list_String(userInputs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_identity_card,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_password_strength,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleListIdentityCardConstMeta,
-      argValues: [query, token],
+      constMeta: kCrateApiSimpleEstimatePasswordStrengthConstMeta,
+      argValues: [password, userInputs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleListIdentityCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleEstimatePasswordStrengthConstMeta =>
       const TaskConstMeta(
-        debugName: "list_identity_card",
-        argNames: ["query", "token"],
+        debugName: "estimate_password_strength",
+        argNames: ["password", "userInputs"],
       );
 
   @override
-  Future<List<Login>> crateApiSimpleListLogin(
-      {required String query, required String token}) {
+  Future<String> crateApiSimpleExportAllDataToJson() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 22, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_login,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleListLoginConstMeta,
-      argValues: [query, token],
+      constMeta: kCrateApiSimpleExportAllDataToJsonConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleListLoginConstMeta => const TaskConstMeta(
-        debugName: "list_login",
-        argNames: ["query", "token"],
+  TaskConstMeta get kCrateApiSimpleExportAllDataToJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_all_data_to_json",
+        argNames: [],
       );
 
   @override
-  Future<List<Note>> crateApiSimpleListNote(
-      {required String query, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  GeneratedPassword crateApiSimpleGeneratePassword(
+      {required GeneratorOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
+        sse_encode_box_autoadd_generator_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_note,
+        decodeSuccessData: sse_decode_generated_password,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleListNoteConstMeta,
-      argValues: [query, token],
+      constMeta: kCrateApiSimpleGeneratePasswordConstMeta,
+      argValues: [options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleListNoteConstMeta => const TaskConstMeta(
-        debugName: "list_note",
-        argNames: ["query", "token"],
+  TaskConstMeta get kCrateApiSimpleGeneratePasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_password",
+        argNames: ["options"],
       );
 
   @override
-  Future<List<Tag>> crateApiSimpleListTags(
-      {required String query, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  String crateApiSimpleGenerateTwoFactorEncryptionKey() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 24, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_tag,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleListTagsConstMeta,
-      argValues: [query, token],
+      constMeta: kCrateApiSimpleGenerateTwoFactorEncryptionKeyConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleListTagsConstMeta => const TaskConstMeta(
-        debugName: "list_tags",
-        argNames: ["query", "token"],
+  TaskConstMeta get kCrateApiSimpleGenerateTwoFactorEncryptionKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_two_factor_encryption_key",
+        argNames: [],
       );
 
   @override
-  Future<JwtTokens> crateApiSimpleLogin({required LoginData user}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  KdfSettings crateApiSimpleGetAccountPasswordHashSettings() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_login_data(user, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 25, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_jwt_tokens,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_kdf_settings,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleLoginConstMeta,
-      argValues: [user],
+      constMeta: kCrateApiSimpleGetAccountPasswordHashSettingsConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleLoginConstMeta => const TaskConstMeta(
-        debugName: "login",
-        argNames: ["user"],
+  TaskConstMeta get kCrateApiSimpleGetAccountPasswordHashSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_account_password_hash_settings",
+        argNames: [],
       );
 
   @override
-  Future<FinancialCard> crateApiSimplePostFinancialCard(
-      {required FinancialCard data, required String token}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  PasswordPolicy crateApiSimpleGetAccountPasswordPolicy() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_financial_card(data, serializer);
-        sse_encode_String(token, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 26, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_financial_card,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_password_policy,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimplePostFinancialCardConstMeta,
-      argValues: [data, token],
+      constMeta: kCrateApiSimpleGetAccountPasswordPolicyConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePostFinancialCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetAccountPasswordPolicyConstMeta =>
       const TaskConstMeta(
-        debugName: "post_financial_card",
-        argNames: ["data", "token"],
+        debugName: "get_account_password_policy",
+        argNames: [],
       );
 
   @override
-  Future<IdentityCard> crateApiSimplePostIdentityCard(
-      {required IdentityCard data, required String token}) {
+  Future<FinancialCard> crateApiSimpleGetFinancialCard(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_identity_card(data, serializer);
+        sse_encode_String(id, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 27, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_identity_card,
+        decodeSuccessData: sse_decode_financial_card,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePostIdentityCardConstMeta,
-      argValues: [data, token],
+      constMeta: kCrateApiSimpleGetFinancialCardConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePostIdentityCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetFinancialCardConstMeta =>
       const TaskConstMeta(
-        debugName: "post_identity_card",
-        argNames: ["data", "token"],
+        debugName: "get_financial_card",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<Login> crateApiSimplePostLogin(
-      {required Login data, required String token}) {
+  Future<IdentityCard> crateApiSimpleGetIdentityCard(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_login(data, serializer);
+        sse_encode_String(id, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 28, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_login,
+        decodeSuccessData: sse_decode_identity_card,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePostLoginConstMeta,
-      argValues: [data, token],
+      constMeta: kCrateApiSimpleGetIdentityCardConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePostLoginConstMeta => const TaskConstMeta(
-        debugName: "post_login",
-        argNames: ["data", "token"],
+  TaskConstMeta get kCrateApiSimpleGetIdentityCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_identity_card",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<Note> crateApiSimplePostNote(
-      {required Note data, required String token}) {
+  Future<KdfSettings> crateApiSimpleGetKdfSettings({required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_note(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 29, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_note,
+        decodeSuccessData: sse_decode_kdf_settings,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePostNoteConstMeta,
-      argValues: [data, token],
+      constMeta: kCrateApiSimpleGetKdfSettingsConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePostNoteConstMeta => const TaskConstMeta(
-        debugName: "post_note",
-        argNames: ["data", "token"],
+  TaskConstMeta get kCrateApiSimpleGetKdfSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_kdf_settings",
+        argNames: ["token"],
       );
 
   @override
-  Future<FinancialCard> crateApiSimplePutFinancialCard(
-      {required String id,
-      required FinancialCard data,
-      required String token}) {
+  Future<Login> crateApiSimpleGetLogin(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(id, serializer);
-        sse_encode_box_autoadd_financial_card(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 30, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_financial_card,
+        decodeSuccessData: sse_decode_login,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePutFinancialCardConstMeta,
-      argValues: [id, data, token],
-      apiImpl: this,
+      constMeta: kCrateApiSimpleGetLoginConstMeta,
+      argValues: [id, token],
+      apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePutFinancialCardConstMeta =>
-      const TaskConstMeta(
-        debugName: "put_financial_card",
-        argNames: ["id", "data", "token"],
+  TaskConstMeta get kCrateApiSimpleGetLoginConstMeta => const TaskConstMeta(
+        debugName: "get_login",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<IdentityCard> crateApiSimplePutIdentityCard(
-      {required String id, required IdentityCard data, required String token}) {
+  Future<OtpCode> crateApiSimpleGetLoginTotpCode(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(id, serializer);
-        sse_encode_box_autoadd_identity_card(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 31, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_identity_card,
+        decodeSuccessData: sse_decode_otp_code,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePutIdentityCardConstMeta,
-      argValues: [id, data, token],
+      constMeta: kCrateApiSimpleGetLoginTotpCodeConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePutIdentityCardConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetLoginTotpCodeConstMeta =>
       const TaskConstMeta(
-        debugName: "put_identity_card",
-        argNames: ["id", "data", "token"],
+        debugName: "get_login_totp_code",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<Login> crateApiSimplePutLogin(
-      {required String id, required Login data, required String token}) {
+  Future<Note> crateApiSimpleGetNote(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(id, serializer);
-        sse_encode_box_autoadd_login(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 32, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_login,
+        decodeSuccessData: sse_decode_note,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePutLoginConstMeta,
-      argValues: [id, data, token],
+      constMeta: kCrateApiSimpleGetNoteConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePutLoginConstMeta => const TaskConstMeta(
-        debugName: "put_login",
-        argNames: ["id", "data", "token"],
+  TaskConstMeta get kCrateApiSimpleGetNoteConstMeta => const TaskConstMeta(
+        debugName: "get_note",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<Note> crateApiSimplePutNote(
-      {required String id, required Note data, required String token}) {
+  Future<Passkey> crateApiSimpleGetPasskey(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(id, serializer);
-        sse_encode_box_autoadd_note(data, serializer);
         sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 33, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_note,
+        decodeSuccessData: sse_decode_passkey,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePutNoteConstMeta,
-      argValues: [id, data, token],
+      constMeta: kCrateApiSimpleGetPasskeyConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePutNoteConstMeta => const TaskConstMeta(
-        debugName: "put_note",
-        argNames: ["id", "data", "token"],
+  TaskConstMeta get kCrateApiSimpleGetPasskeyConstMeta => const TaskConstMeta(
+        debugName: "get_passkey",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<Tag> crateApiSimplePutTag(
-      {required String id, required Tag tag, required String token}) {
+  Future<int> crateApiSimpleGetPasswordBreachCount({required String password}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_box_autoadd_tag(tag, serializer);
-        sse_encode_String(token, serializer);
+        sse_encode_String(password, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 34, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_tag,
+        decodeSuccessData: sse_decode_u_32,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimplePutTagConstMeta,
-      argValues: [id, tag, token],
+      constMeta: kCrateApiSimpleGetPasswordBreachCountConstMeta,
+      argValues: [password],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimplePutTagConstMeta => const TaskConstMeta(
-        debugName: "put_tag",
-        argNames: ["id", "tag", "token"],
+  TaskConstMeta get kCrateApiSimpleGetPasswordBreachCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_password_breach_count",
+        argNames: ["password"],
       );
 
   @override
-  Future<JwtTokens> crateApiSimpleRegister({required RegisterData user}) {
+  Future<RekeyProgress?> crateApiSimpleGetRekeyVaultProgress(
+      {required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_register_data(user, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 35, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_jwt_tokens,
+        decodeSuccessData: sse_decode_opt_box_autoadd_rekey_progress,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleRegisterConstMeta,
-      argValues: [user],
+      constMeta: kCrateApiSimpleGetRekeyVaultProgressConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleRegisterConstMeta => const TaskConstMeta(
-        debugName: "register",
-        argNames: ["user"],
+  TaskConstMeta get kCrateApiSimpleGetRekeyVaultProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_rekey_vault_progress",
+        argNames: ["token"],
       );
 
   @override
-  Future<void> crateApiSimpleRestoreDataFromJson({required String data}) {
+  Future<Tag> crateApiSimpleGetTag(
+      {required String id, required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(data, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 36, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_tag,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleRestoreDataFromJsonConstMeta,
-      argValues: [data],
+      constMeta: kCrateApiSimpleGetTagConstMeta,
+      argValues: [id, token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleRestoreDataFromJsonConstMeta =>
-      const TaskConstMeta(
-        debugName: "restore_data_from_json",
-        argNames: ["data"],
+  TaskConstMeta get kCrateApiSimpleGetTagConstMeta => const TaskConstMeta(
+        debugName: "get_tag",
+        argNames: ["id", "token"],
       );
 
   @override
-  Future<bool?> crateApiSimpleToggleFavorite(
-      {required String id, required String itemType}) {
+  Future<TwoFactorStatus> crateApiSimpleGetTwoFactorStatus(
+      {required String token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        sse_encode_String(itemType, serializer);
+        sse_encode_String(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 37, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+        decodeSuccessData: sse_decode_two_factor_status,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiSimpleToggleFavoriteConstMeta,
-      argValues: [id, itemType],
+      constMeta: kCrateApiSimpleGetTwoFactorStatusConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSimpleToggleFavoriteConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetTwoFactorStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "toggle_favorite",
-        argNames: ["id", "itemType"],
+        debugName: "get_two_factor_status",
+        argNames: ["token"],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<VaultHealth> crateApiSimpleGetVaultHealth(
+      {int? staleDays, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_u_32(staleDays, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_vault_health,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleGetVaultHealthConstMeta,
+      argValues: [staleDays, token],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiSimpleGetVaultHealthConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_vault_health",
+        argNames: ["staleDays", "token"],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleGreetConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+        debugName: "greet",
+        argNames: ["name"],
+      );
 
-  @protected
-  FinancialCard dco_decode_box_autoadd_financial_card(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_financial_card(raw);
+  @override
+  Future<(bool, String)> crateApiSimpleInit({required String dbPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dbPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_bool_string,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleInitConstMeta,
+      argValues: [dbPath],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
-  }
+  TaskConstMeta get kCrateApiSimpleInitConstMeta => const TaskConstMeta(
+        debugName: "init",
+        argNames: ["dbPath"],
+      );
 
-  @protected
-  IdentityCard dco_decode_box_autoadd_identity_card(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_identity_card(raw);
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleInitAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Login dco_decode_box_autoadd_login(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_login(raw);
-  }
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+      );
 
-  @protected
-  LoginData dco_decode_box_autoadd_login_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_login_data(raw);
+  @override
+  Future<bool> crateApiSimpleIsDatabaseInitialized() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleIsDatabaseInitializedConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Note dco_decode_box_autoadd_note(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_note(raw);
+  TaskConstMeta get kCrateApiSimpleIsDatabaseInitializedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_database_initialized",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiSimpleIsVaultUnlocked({required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleIsVaultUnlockedConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  RegisterData dco_decode_box_autoadd_register_data(dynamic raw) {
+  TaskConstMeta get kCrateApiSimpleIsVaultUnlockedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_vault_unlocked",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<List<FinancialCard>> crateApiSimpleListFinancialCard(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_financial_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListFinancialCardConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListFinancialCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_financial_card",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<List<IdentityCard>> crateApiSimpleListIdentityCard(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_identity_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListIdentityCardConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListIdentityCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_identity_card",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<List<Login>> crateApiSimpleListLogin(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_login,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListLoginConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListLoginConstMeta => const TaskConstMeta(
+        debugName: "list_login",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<List<PasswordHistory>> crateApiSimpleListLoginPasswordHistory(
+      {required String id, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_password_history,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListLoginPasswordHistoryConstMeta,
+      argValues: [id, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListLoginPasswordHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_login_password_history",
+        argNames: ["id", "token"],
+      );
+
+  @override
+  Future<List<Note>> crateApiSimpleListNote(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_note,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListNoteConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListNoteConstMeta => const TaskConstMeta(
+        debugName: "list_note",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<List<Passkey>> crateApiSimpleListPasskey(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_passkey,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListPasskeyConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListPasskeyConstMeta => const TaskConstMeta(
+        debugName: "list_passkey",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<List<Passkey>> crateApiSimpleListPasskeysForRp(
+      {required String rpId, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rpId, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_passkey,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListPasskeysForRpConstMeta,
+      argValues: [rpId, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListPasskeysForRpConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_passkeys_for_rp",
+        argNames: ["rpId", "token"],
+      );
+
+  @override
+  Future<List<Tag>> crateApiSimpleListTags(
+      {required String query, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_tag,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleListTagsConstMeta,
+      argValues: [query, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleListTagsConstMeta => const TaskConstMeta(
+        debugName: "list_tags",
+        argNames: ["query", "token"],
+      );
+
+  @override
+  Future<BigInt> crateApiSimpleLoadPasswordBreachIndex(
+      {required String indexPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(indexPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleLoadPasswordBreachIndexConstMeta,
+      argValues: [indexPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleLoadPasswordBreachIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_password_breach_index",
+        argNames: ["indexPath"],
+      );
+
+  @override
+  Future<void> crateApiSimpleLock({required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleLockConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleLockConstMeta => const TaskConstMeta(
+        debugName: "lock",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<LoginResult> crateApiSimpleLogin({required LoginData user}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_login_data(user, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login_result,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleLoginConstMeta,
+      argValues: [user],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleLoginConstMeta => const TaskConstMeta(
+        debugName: "login",
+        argNames: ["user"],
+      );
+
+  @override
+  Future<JwtTokens> crateApiSimpleLoginWithTwoFactor(
+      {required TwoFactorLoginData data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_two_factor_login_data(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_jwt_tokens,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleLoginWithTwoFactorConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleLoginWithTwoFactorConstMeta =>
+      const TaskConstMeta(
+        debugName: "login_with_two_factor",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<String> crateApiSimpleNewRecoveryKey({required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleNewRecoveryKeyConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleNewRecoveryKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_recovery_key",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<List<String>> crateApiSimpleNewRecoveryKeyShares(
+      {required ShareSettings settings, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_share_settings(settings, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleNewRecoveryKeySharesConstMeta,
+      argValues: [settings, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleNewRecoveryKeySharesConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_recovery_key_shares",
+        argNames: ["settings", "token"],
+      );
+
+  @override
+  Future<FinancialCard> crateApiSimplePostFinancialCard(
+      {required FinancialCard data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_financial_card(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_financial_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePostFinancialCardConstMeta,
+      argValues: [data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePostFinancialCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "post_financial_card",
+        argNames: ["data", "token"],
+      );
+
+  @override
+  Future<IdentityCard> crateApiSimplePostIdentityCard(
+      {required IdentityCard data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_identity_card(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_identity_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePostIdentityCardConstMeta,
+      argValues: [data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePostIdentityCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "post_identity_card",
+        argNames: ["data", "token"],
+      );
+
+  @override
+  Future<Login> crateApiSimplePostLogin(
+      {required Login data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_login(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePostLoginConstMeta,
+      argValues: [data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePostLoginConstMeta => const TaskConstMeta(
+        debugName: "post_login",
+        argNames: ["data", "token"],
+      );
+
+  @override
+  Future<Note> crateApiSimplePostNote(
+      {required Note data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_note(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_note,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePostNoteConstMeta,
+      argValues: [data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePostNoteConstMeta => const TaskConstMeta(
+        debugName: "post_note",
+        argNames: ["data", "token"],
+      );
+
+  @override
+  Future<Passkey> crateApiSimplePostPasskey(
+      {required Passkey data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_passkey(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_passkey,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePostPasskeyConstMeta,
+      argValues: [data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePostPasskeyConstMeta => const TaskConstMeta(
+        debugName: "post_passkey",
+        argNames: ["data", "token"],
+      );
+
+  @override
+  Future<FinancialCard> crateApiSimplePutFinancialCard(
+      {required String id,
+      required FinancialCard data,
+      required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_financial_card(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_financial_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutFinancialCardConstMeta,
+      argValues: [id, data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutFinancialCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "put_financial_card",
+        argNames: ["id", "data", "token"],
+      );
+
+  @override
+  Future<IdentityCard> crateApiSimplePutIdentityCard(
+      {required String id, required IdentityCard data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_identity_card(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_identity_card,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutIdentityCardConstMeta,
+      argValues: [id, data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutIdentityCardConstMeta =>
+      const TaskConstMeta(
+        debugName: "put_identity_card",
+        argNames: ["id", "data", "token"],
+      );
+
+  @override
+  Future<Login> crateApiSimplePutLogin(
+      {required String id, required Login data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_login(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutLoginConstMeta,
+      argValues: [id, data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutLoginConstMeta => const TaskConstMeta(
+        debugName: "put_login",
+        argNames: ["id", "data", "token"],
+      );
+
+  @override
+  Future<Note> crateApiSimplePutNote(
+      {required String id, required Note data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_note(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_note,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutNoteConstMeta,
+      argValues: [id, data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutNoteConstMeta => const TaskConstMeta(
+        debugName: "put_note",
+        argNames: ["id", "data", "token"],
+      );
+
+  @override
+  Future<Passkey> crateApiSimplePutPasskey(
+      {required String id, required Passkey data, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_passkey(data, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_passkey,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutPasskeyConstMeta,
+      argValues: [id, data, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutPasskeyConstMeta => const TaskConstMeta(
+        debugName: "put_passkey",
+        argNames: ["id", "data", "token"],
+      );
+
+  @override
+  Future<Tag> crateApiSimplePutTag(
+      {required String id, required Tag tag, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_tag(tag, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tag,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimplePutTagConstMeta,
+      argValues: [id, tag, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimplePutTagConstMeta => const TaskConstMeta(
+        debugName: "put_tag",
+        argNames: ["id", "tag", "token"],
+      );
+
+  @override
+  QrMatrix crateApiSimpleQrCodeMatrix(
+      {required String payload, required QrOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(payload, serializer);
+        sse_encode_box_autoadd_qr_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_qr_matrix,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleQrCodeMatrixConstMeta,
+      argValues: [payload, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleQrCodeMatrixConstMeta => const TaskConstMeta(
+        debugName: "qr_code_matrix",
+        argNames: ["payload", "options"],
+      );
+
+  @override
+  Uint8List crateApiSimpleQrCodePng(
+      {required String payload, required QrOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(payload, serializer);
+        sse_encode_box_autoadd_qr_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleQrCodePngConstMeta,
+      argValues: [payload, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleQrCodePngConstMeta => const TaskConstMeta(
+        debugName: "qr_code_png",
+        argNames: ["payload", "options"],
+      );
+
+  @override
+  String crateApiSimpleQrCodeSvg(
+      {required String payload, required QrOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(payload, serializer);
+        sse_encode_box_autoadd_qr_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleQrCodeSvgConstMeta,
+      argValues: [payload, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleQrCodeSvgConstMeta => const TaskConstMeta(
+        debugName: "qr_code_svg",
+        argNames: ["payload", "options"],
+      );
+
+  @override
+  Future<JwtTokens> crateApiSimpleRecover({required RecoverAccountData data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_recover_account_data(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_jwt_tokens,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRecoverConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRecoverConstMeta => const TaskConstMeta(
+        debugName: "recover",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<JwtTokens> crateApiSimpleRefreshTokens(
+      {required RefreshTokenData data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_refresh_token_data(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_jwt_tokens,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRefreshTokensConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRefreshTokensConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_tokens",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<TwoFactorBackupCodes> crateApiSimpleRegenerateTwoFactorBackupCodes(
+      {required String code, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(code, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_two_factor_backup_codes,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRegenerateTwoFactorBackupCodesConstMeta,
+      argValues: [code, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRegenerateTwoFactorBackupCodesConstMeta =>
+      const TaskConstMeta(
+        debugName: "regenerate_two_factor_backup_codes",
+        argNames: ["code", "token"],
+      );
+
+  @override
+  Future<JwtTokens> crateApiSimpleRegister({required RegisterData user}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_register_data(user, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 75, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_jwt_tokens,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRegisterConstMeta,
+      argValues: [user],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRegisterConstMeta => const TaskConstMeta(
+        debugName: "register",
+        argNames: ["user"],
+      );
+
+  @override
+  Future<RekeyResult> crateApiSimpleRekey(
+      {required String masterPassword, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(masterPassword, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 76, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_rekey_result,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRekeyConstMeta,
+      argValues: [masterPassword, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRekeyConstMeta => const TaskConstMeta(
+        debugName: "rekey",
+        argNames: ["masterPassword", "token"],
+      );
+
+  @override
+  Future<void> crateApiSimpleRestoreDataFromJson({required String data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 77, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRestoreDataFromJsonConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRestoreDataFromJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_data_from_json",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<Login> crateApiSimpleRestoreLoginPassword(
+      {required String id, required String historyId, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_String(historyId, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleRestoreLoginPasswordConstMeta,
+      argValues: [id, historyId, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleRestoreLoginPasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_login_password",
+        argNames: ["id", "historyId", "token"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSaveKdfSettings(
+      {required KdfSettings settings,
+      required String masterPassword,
+      required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_kdf_settings(settings, serializer);
+        sse_encode_String(masterPassword, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 79, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSaveKdfSettingsConstMeta,
+      argValues: [settings, masterPassword, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSaveKdfSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_kdf_settings",
+        argNames: ["settings", "masterPassword", "token"],
+      );
+
+  @override
+  void crateApiSimpleSetAccountPasswordHashSettings(
+      {required KdfSettings settings}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_kdf_settings(settings, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSetAccountPasswordHashSettingsConstMeta,
+      argValues: [settings],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetAccountPasswordHashSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_account_password_hash_settings",
+        argNames: ["settings"],
+      );
+
+  @override
+  void crateApiSimpleSetAccountPasswordPolicy(
+      {required PasswordPolicy policy}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_password_policy(policy, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSetAccountPasswordPolicyConstMeta,
+      argValues: [policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetAccountPasswordPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_account_password_policy",
+        argNames: ["policy"],
+      );
+
+  @override
+  void crateApiSimpleSetAutoLockSeconds({required BigInt seconds}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(seconds, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetAutoLockSecondsConstMeta,
+      argValues: [seconds],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetAutoLockSecondsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_auto_lock_seconds",
+        argNames: ["seconds"],
+      );
+
+  @override
+  void crateApiSimpleSetEncryptionCipher({required String policy}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(policy, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSetEncryptionCipherConstMeta,
+      argValues: [policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetEncryptionCipherConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_encryption_cipher",
+        argNames: ["policy"],
+      );
+
+  @override
+  void crateApiSimpleSetTwoFactorEncryptionKey({required String key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSetTwoFactorEncryptionKeyConstMeta,
+      argValues: [key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetTwoFactorEncryptionKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_two_factor_encryption_key",
+        argNames: ["key"],
+      );
+
+  @override
+  Future<PasskeyAssertion> crateApiSimpleSignPasskeyAssertion(
+      {required String id,
+      required PasskeyAssertionRequest request,
+      required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_passkey_assertion_request(request, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 85, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_passkey_assertion,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSignPasskeyAssertionConstMeta,
+      argValues: [id, request, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSignPasskeyAssertionConstMeta =>
+      const TaskConstMeta(
+        debugName: "sign_passkey_assertion",
+        argNames: ["id", "request", "token"],
+      );
+
+  @override
+  List<String> crateApiSimpleSplitSecretIntoShares(
+      {required String secret, required ShareSettings settings}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(secret, serializer);
+        sse_encode_box_autoadd_share_settings(settings, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleSplitSecretIntoSharesConstMeta,
+      argValues: [secret, settings],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleSplitSecretIntoSharesConstMeta =>
+      const TaskConstMeta(
+        debugName: "split_secret_into_shares",
+        argNames: ["secret", "settings"],
+      );
+
+  @override
+  Future<bool?> crateApiSimpleToggleFavorite(
+      {required String id, required String itemType}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        sse_encode_String(itemType, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 87, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleToggleFavoriteConstMeta,
+      argValues: [id, itemType],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleToggleFavoriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_favorite",
+        argNames: ["id", "itemType"],
+      );
+
+  @override
+  Future<void> crateApiSimpleTurnOffTwoFactor(
+      {required String code, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(code, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 88, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleTurnOffTwoFactorConstMeta,
+      argValues: [code, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleTurnOffTwoFactorConstMeta =>
+      const TaskConstMeta(
+        debugName: "turn_off_two_factor",
+        argNames: ["code", "token"],
+      );
+
+  @override
+  Future<void> crateApiSimpleUnlock(
+      {required String masterPassword, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(masterPassword, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 89, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiSimpleUnlockConstMeta,
+      argValues: [masterPassword, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSimpleUnlockConstMeta => const TaskConstMeta(
+        debugName: "unlock",
+        argNames: ["masterPassword", "token"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  String dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+        'Not implemented in this codec, please use the other one');
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ChangePasswordData dco_decode_box_autoadd_change_password_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_change_password_data(raw);
+  }
+
+  @protected
+  FinancialCard dco_decode_box_autoadd_financial_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_financial_card(raw);
+  }
+
+  @protected
+  GeneratorOptions dco_decode_box_autoadd_generator_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generator_options(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  IdentityCard dco_decode_box_autoadd_identity_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_identity_card(raw);
+  }
+
+  @protected
+  JwtTokens dco_decode_box_autoadd_jwt_tokens(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_jwt_tokens(raw);
+  }
+
+  @protected
+  KdfSettings dco_decode_box_autoadd_kdf_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_kdf_settings(raw);
+  }
+
+  @protected
+  Login dco_decode_box_autoadd_login(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_login(raw);
+  }
+
+  @protected
+  LoginData dco_decode_box_autoadd_login_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_login_data(raw);
+  }
+
+  @protected
+  Note dco_decode_box_autoadd_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_note(raw);
+  }
+
+  @protected
+  Passkey dco_decode_box_autoadd_passkey(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_passkey(raw);
+  }
+
+  @protected
+  PasskeyAssertionRequest dco_decode_box_autoadd_passkey_assertion_request(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_passkey_assertion_request(raw);
+  }
+
+  @protected
+  PasskeyCreation dco_decode_box_autoadd_passkey_creation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_passkey_creation(raw);
+  }
+
+  @protected
+  PassphraseOptions dco_decode_box_autoadd_passphrase_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_passphrase_options(raw);
+  }
+
+  @protected
+  PasswordOptions dco_decode_box_autoadd_password_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_password_options(raw);
+  }
+
+  @protected
+  PasswordPolicy dco_decode_box_autoadd_password_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_password_policy(raw);
+  }
+
+  @protected
+  PronounceableOptions dco_decode_box_autoadd_pronounceable_options(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pronounceable_options(raw);
+  }
+
+  @protected
+  QrOptions dco_decode_box_autoadd_qr_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_qr_options(raw);
+  }
+
+  @protected
+  RecoverAccountData dco_decode_box_autoadd_recover_account_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recover_account_data(raw);
+  }
+
+  @protected
+  RefreshTokenData dco_decode_box_autoadd_refresh_token_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_refresh_token_data(raw);
+  }
+
+  @protected
+  RegisterData dco_decode_box_autoadd_register_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_register_data(raw);
+  }
+
+  @protected
+  RekeyProgress dco_decode_box_autoadd_rekey_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rekey_progress(raw);
+  }
+
+  @protected
+  ShareSettings dco_decode_box_autoadd_share_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_share_settings(raw);
+  }
+
+  @protected
+  Tag dco_decode_box_autoadd_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tag(raw);
+  }
+
+  @protected
+  TwoFactorChallenge dco_decode_box_autoadd_two_factor_challenge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_two_factor_challenge(raw);
+  }
+
+  @protected
+  TwoFactorLoginData dco_decode_box_autoadd_two_factor_login_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_two_factor_login_data(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BreachReport dco_decode_breach_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BreachReport(
+      checked: dco_decode_u_32(arr[0]),
+      breachedCount: dco_decode_u_32(arr[1]),
+      breached: dco_decode_list_breached_login(arr[2]),
+      checkedAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  BreachedLogin dco_decode_breached_login(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BreachedLogin(
+      id: dco_decode_String(arr[0]),
+      count: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  Capitalization dco_decode_capitalization(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Capitalization.values[raw as int];
+  }
+
+  @protected
+  ChangePasswordData dco_decode_change_password_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChangePasswordData(
+      oldPassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      newPassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
+      rePassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FinancialCard dco_decode_financial_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return FinancialCard(
+      id: dco_decode_opt_String(arr[0]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      createdBy: dco_decode_opt_String(arr[2]),
+      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      updatedBy: dco_decode_opt_String(arr[4]),
+      cardHolderName: dco_decode_String(arr[5]),
+      cardNumber: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[6]),
+      cardProviderName: dco_decode_opt_String(arr[7]),
+      cardType: dco_decode_opt_String(arr[8]),
+      cvv: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[9]),
+      expiryDate: dco_decode_opt_String(arr[10]),
+      issueDate: dco_decode_opt_String(arr[11]),
+      name: dco_decode_String(arr[12]),
+      note: dco_decode_opt_String(arr[13]),
+      pin: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[14]),
+      isFavorite: dco_decode_opt_box_autoadd_bool(arr[15]),
+      tags: dco_decode_opt_String(arr[16]),
+    );
+  }
+
+  @protected
+  GeneratedPassword dco_decode_generated_password(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GeneratedPassword(
+      password: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      entropyBits: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  GeneratorOptions dco_decode_generator_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GeneratorOptions_Password(
+          dco_decode_box_autoadd_password_options(raw[1]),
+        );
+      case 1:
+        return GeneratorOptions_Pronounceable(
+          dco_decode_box_autoadd_pronounceable_options(raw[1]),
+        );
+      case 2:
+        return GeneratorOptions_Passphrase(
+          dco_decode_box_autoadd_passphrase_options(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IdentityCard dco_decode_identity_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return IdentityCard(
+      id: dco_decode_opt_String(arr[0]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      createdBy: dco_decode_opt_String(arr[2]),
+      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      updatedBy: dco_decode_opt_String(arr[4]),
+      name: dco_decode_String(arr[5]),
+      note: dco_decode_opt_String(arr[6]),
+      country: dco_decode_opt_String(arr[7]),
+      expiryDate: dco_decode_opt_String(arr[8]),
+      identityCardNumber: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[9]),
+      identityCardType: dco_decode_opt_String(arr[10]),
+      issueDate: dco_decode_opt_String(arr[11]),
+      nameOnCard: dco_decode_String(arr[12]),
+      state: dco_decode_opt_String(arr[13]),
+      isFavorite: dco_decode_opt_box_autoadd_bool(arr[14]),
+      tags: dco_decode_opt_String(arr[15]),
+    );
+  }
+
+  @protected
+  JwtTokens dco_decode_jwt_tokens(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JwtTokens(
+      accessToken: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      refreshToken: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
+      recoveryKey: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[2]),
+    );
+  }
+
+  @protected
+  KdfSettings dco_decode_kdf_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KdfSettings(
+      memoryCost: dco_decode_u_32(arr[0]),
+      timeCost: dco_decode_u_32(arr[1]),
+      parallelism: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+            dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString)
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bool).toList();
+  }
+
+  @protected
+  List<BreachedLogin> dco_decode_list_breached_login(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_breached_login).toList();
+  }
+
+  @protected
+  List<FinancialCard> dco_decode_list_financial_card(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_register_data(raw);
+    return (raw as List<dynamic>).map(dco_decode_financial_card).toList();
   }
 
   @protected
-  Tag dco_decode_box_autoadd_tag(dynamic raw) {
+  List<IdentityCard> dco_decode_list_identity_card(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_tag(raw);
+    return (raw as List<dynamic>).map(dco_decode_identity_card).toList();
   }
 
   @protected
-  FinancialCard dco_decode_financial_card(dynamic raw) {
+  List<List<String>> dco_decode_list_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  }
+
+  @protected
+  List<Login> dco_decode_list_login(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_login).toList();
+  }
+
+  @protected
+  List<Note> dco_decode_list_note(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note).toList();
+  }
+
+  @protected
+  List<Passkey> dco_decode_list_passkey(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_passkey).toList();
+  }
+
+  @protected
+  List<PasswordHistory> dco_decode_list_password_history(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_password_history).toList();
+  }
+
+  @protected
+  List<PasswordRule> dco_decode_list_password_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_password_rule).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<Tag> dco_decode_list_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag).toList();
+  }
+
+  @protected
+  List<WeakPassword> dco_decode_list_weak_password(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_weak_password).toList();
+  }
+
+  @protected
+  Login dco_decode_login(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 17)
-      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
-    return FinancialCard(
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return Login(
       id: dco_decode_opt_String(arr[0]),
       createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
       createdBy: dco_decode_opt_String(arr[2]),
       updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
       updatedBy: dco_decode_opt_String(arr[4]),
-      cardHolderName: dco_decode_String(arr[5]),
-      cardNumber: dco_decode_String(arr[6]),
-      cardProviderName: dco_decode_opt_String(arr[7]),
-      cardType: dco_decode_opt_String(arr[8]),
-      cvv: dco_decode_opt_String(arr[9]),
-      expiryDate: dco_decode_opt_String(arr[10]),
-      issueDate: dco_decode_opt_String(arr[11]),
-      name: dco_decode_String(arr[12]),
-      note: dco_decode_opt_String(arr[13]),
-      pin: dco_decode_opt_String(arr[14]),
-      isFavorite: dco_decode_opt_box_autoadd_bool(arr[15]),
-      tags: dco_decode_opt_String(arr[16]),
+      name: dco_decode_String(arr[5]),
+      note: dco_decode_opt_String(arr[6]),
+      username: dco_decode_String(arr[7]),
+      url: dco_decode_opt_String(arr[8]),
+      password: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[9]),
+      passwordHint: dco_decode_opt_String(arr[10]),
+      isFavorite: dco_decode_opt_box_autoadd_bool(arr[11]),
+      tags: dco_decode_opt_String(arr[12]),
+      apiKeys: dco_decode_opt_String(arr[13]),
+      breachCount: dco_decode_opt_box_autoadd_i_64(arr[14]),
+      breachCheckedAt: dco_decode_opt_box_autoadd_i_64(arr[15]),
+      historyLimit: dco_decode_opt_box_autoadd_i_64(arr[16]),
+      otpauth: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[17]),
     );
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
+  LoginData dco_decode_login_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LoginData(
+      email: dco_decode_String(arr[0]),
+      password: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
+    );
   }
 
   @protected
-  IdentityCard dco_decode_identity_card(dynamic raw) {
+  LoginResult dco_decode_login_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LoginResult_Tokens(
+          dco_decode_box_autoadd_jwt_tokens(raw[1]),
+        );
+      case 1:
+        return LoginResult_TwoFactorRequired(
+          dco_decode_box_autoadd_two_factor_challenge(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  Note dco_decode_note(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 16)
-      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
-    return IdentityCard(
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return Note(
       id: dco_decode_opt_String(arr[0]),
       createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
       createdBy: dco_decode_opt_String(arr[2]),
@@ -1275,180 +3220,529 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       updatedBy: dco_decode_opt_String(arr[4]),
       name: dco_decode_String(arr[5]),
       note: dco_decode_opt_String(arr[6]),
-      country: dco_decode_opt_String(arr[7]),
-      expiryDate: dco_decode_opt_String(arr[8]),
-      identityCardNumber: dco_decode_String(arr[9]),
-      identityCardType: dco_decode_opt_String(arr[10]),
-      issueDate: dco_decode_opt_String(arr[11]),
-      nameOnCard: dco_decode_String(arr[12]),
-      state: dco_decode_opt_String(arr[13]),
-      isFavorite: dco_decode_opt_box_autoadd_bool(arr[14]),
-      tags: dco_decode_opt_String(arr[15]),
+      isFavorite: dco_decode_opt_box_autoadd_bool(arr[7]),
+      tags: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  RekeyProgress? dco_decode_opt_box_autoadd_rekey_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rekey_progress(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  OtpCode dco_decode_otp_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return OtpCode(
+      code: dco_decode_String(arr[0]),
+      period: dco_decode_u_64(arr[1]),
+      remainingSeconds: dco_decode_u_64(arr[2]),
+      counter: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  Passkey dco_decode_passkey(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 19)
+      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
+    return Passkey(
+      id: dco_decode_opt_String(arr[0]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      createdBy: dco_decode_opt_String(arr[2]),
+      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      updatedBy: dco_decode_opt_String(arr[4]),
+      name: dco_decode_String(arr[5]),
+      note: dco_decode_opt_String(arr[6]),
+      rpId: dco_decode_String(arr[7]),
+      rpName: dco_decode_opt_String(arr[8]),
+      userHandle: dco_decode_String(arr[9]),
+      userName: dco_decode_opt_String(arr[10]),
+      userDisplayName: dco_decode_opt_String(arr[11]),
+      credentialId: dco_decode_String(arr[12]),
+      algorithm: dco_decode_i_64(arr[13]),
+      privateKey: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[14]),
+      signCount: dco_decode_i_64(arr[15]),
+      lastUsedAt: dco_decode_opt_box_autoadd_i_64(arr[16]),
+      isFavorite: dco_decode_opt_box_autoadd_bool(arr[17]),
+      tags: dco_decode_opt_String(arr[18]),
+    );
+  }
+
+  @protected
+  PasskeyAssertion dco_decode_passkey_assertion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PasskeyAssertion(
+      credentialId: dco_decode_String(arr[0]),
+      authenticatorData: dco_decode_String(arr[1]),
+      signature: dco_decode_String(arr[2]),
+      userHandle: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  PasskeyAssertionRequest dco_decode_passkey_assertion_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PasskeyAssertionRequest(
+      rpId: dco_decode_String(arr[0]),
+      clientDataHash: dco_decode_String(arr[1]),
+      userVerified: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  PasskeyCreation dco_decode_passkey_creation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PasskeyCreation(
+      rpId: dco_decode_String(arr[0]),
+      rpName: dco_decode_opt_String(arr[1]),
+      userHandle: dco_decode_String(arr[2]),
+      userName: dco_decode_opt_String(arr[3]),
+      userDisplayName: dco_decode_opt_String(arr[4]),
+      clientDataHash: dco_decode_String(arr[5]),
+      algorithms: dco_decode_list_prim_i_64_strict(arr[6]),
+      userVerified: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  PasskeyRegistration dco_decode_passkey_registration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PasskeyRegistration(
+      passkey: dco_decode_passkey(arr[0]),
+      credentialId: dco_decode_String(arr[1]),
+      attestationObject: dco_decode_String(arr[2]),
+      authenticatorData: dco_decode_String(arr[3]),
+      publicKey: dco_decode_String(arr[4]),
+      publicKeyAlgorithm: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  PassphraseOptions dco_decode_passphrase_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PassphraseOptions(
+      words: dco_decode_u_32(arr[0]),
+      separator: dco_decode_String(arr[1]),
+      capitalization: dco_decode_capitalization(arr[2]),
+    );
+  }
+
+  @protected
+  PasswordFeedback dco_decode_password_feedback(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PasswordFeedback(
+      warning: dco_decode_opt_String(arr[0]),
+      suggestions: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  PasswordHistory dco_decode_password_history(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PasswordHistory(
+      id: dco_decode_opt_String(arr[0]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      createdBy: dco_decode_opt_String(arr[2]),
+      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      updatedBy: dco_decode_opt_String(arr[4]),
+      loginId: dco_decode_String(arr[5]),
+      password: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[6]),
+    );
+  }
+
+  @protected
+  PasswordOptions dco_decode_password_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return PasswordOptions(
+      length: dco_decode_u_32(arr[0]),
+      uppercase: dco_decode_bool(arr[1]),
+      lowercase: dco_decode_bool(arr[2]),
+      digits: dco_decode_bool(arr[3]),
+      symbols: dco_decode_bool(arr[4]),
+      minUppercase: dco_decode_u_32(arr[5]),
+      minLowercase: dco_decode_u_32(arr[6]),
+      minDigits: dco_decode_u_32(arr[7]),
+      minSymbols: dco_decode_u_32(arr[8]),
+      symbolSet: dco_decode_opt_String(arr[9]),
+      excludeAmbiguous: dco_decode_bool(arr[10]),
+    );
+  }
+
+  @protected
+  PasswordPolicy dco_decode_password_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PasswordPolicy(
+      minLength: dco_decode_u_32(arr[0]),
+      maxLength: dco_decode_u_32(arr[1]),
+      requireUppercase: dco_decode_bool(arr[2]),
+      requireLowercase: dco_decode_bool(arr[3]),
+      requireDigit: dco_decode_bool(arr[4]),
+      requireSymbol: dco_decode_bool(arr[5]),
+      minScore: dco_decode_u_8(arr[6]),
+      allowUnicode: dco_decode_bool(arr[7]),
+      bannedWords: dco_decode_list_String(arr[8]),
+    );
+  }
+
+  @protected
+  PasswordRule dco_decode_password_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PasswordRule_TooShort(
+          minLength: dco_decode_u_32(raw[1]),
+        );
+      case 1:
+        return PasswordRule_TooLong(
+          maxLength: dco_decode_u_32(raw[1]),
+        );
+      case 2:
+        return PasswordRule_MissingUppercase();
+      case 3:
+        return PasswordRule_MissingLowercase();
+      case 4:
+        return PasswordRule_MissingDigit();
+      case 5:
+        return PasswordRule_MissingSymbol();
+      case 6:
+        return PasswordRule_TooWeak(
+          score: dco_decode_u_8(raw[1]),
+          minScore: dco_decode_u_8(raw[2]),
+        );
+      case 7:
+        return PasswordRule_UnicodeNotAllowed();
+      case 8:
+        return PasswordRule_BannedWord(
+          word: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PasswordStrength dco_decode_password_strength(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PasswordStrength(
+      score: dco_decode_u_8(arr[0]),
+      guesses: dco_decode_f_64(arr[1]),
+      guessesLog10: dco_decode_f_64(arr[2]),
+      crackTimeSeconds: dco_decode_f_64(arr[3]),
+      crackTimeDisplay: dco_decode_String(arr[4]),
+      feedback: dco_decode_password_feedback(arr[5]),
     );
   }
 
   @protected
-  JwtTokens dco_decode_jwt_tokens(dynamic raw) {
+  PronounceableOptions dco_decode_pronounceable_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PronounceableOptions(
+      length: dco_decode_u_32(arr[0]),
+      capitalize: dco_decode_bool(arr[1]),
+      digits: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  QrErrorCorrection dco_decode_qr_error_correction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QrErrorCorrection.values[raw as int];
+  }
+
+  @protected
+  QrMatrix dco_decode_qr_matrix(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2)
       throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return JwtTokens(
-      accessToken: dco_decode_String(arr[0]),
-      refreshToken: dco_decode_String(arr[1]),
+    return QrMatrix(
+      size: dco_decode_u_32(arr[0]),
+      modules: dco_decode_list_bool(arr[1]),
     );
   }
 
   @protected
-  List<FinancialCard> dco_decode_list_financial_card(dynamic raw) {
+  QrOptions dco_decode_qr_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_financial_card).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QrOptions(
+      errorCorrection: dco_decode_qr_error_correction(arr[0]),
+      quietZone: dco_decode_u_32(arr[1]),
+      moduleSize: dco_decode_u_32(arr[2]),
+    );
   }
 
   @protected
-  List<IdentityCard> dco_decode_list_identity_card(dynamic raw) {
+  (bool, String) dco_decode_record_bool_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_identity_card).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_bool(arr[0]),
+      dco_decode_String(arr[1]),
+    );
   }
 
   @protected
-  List<Login> dco_decode_list_login(dynamic raw) {
+  RecoverAccountData dco_decode_recover_account_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_login).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecoverAccountData(
+      email: dco_decode_String(arr[0]),
+      recoveryKey: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
+      newPassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[2]),
+      rePassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[3]),
+      twoFactorCode: dco_decode_opt_String(arr[4]),
+    );
   }
 
   @protected
-  List<Note> dco_decode_list_note(dynamic raw) {
+  RefreshTokenData dco_decode_refresh_token_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_note).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RefreshTokenData(
+      refreshToken: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+  RegisterData dco_decode_register_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RegisterData(
+      name: dco_decode_String(arr[0]),
+      email: dco_decode_String(arr[1]),
+      password: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[2]),
+      rePassword: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[3]),
+      recoveryKey: dco_decode_bool(arr[4]),
+    );
   }
 
   @protected
-  List<Tag> dco_decode_list_tag(dynamic raw) {
+  RekeyProgress dco_decode_rekey_progress(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_tag).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RekeyProgress(
+      done: dco_decode_u_32(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      finished: dco_decode_bool(arr[2]),
+    );
   }
 
   @protected
-  Login dco_decode_login(dynamic raw) {
+  RekeyResult dco_decode_rekey_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
-    return Login(
-      id: dco_decode_opt_String(arr[0]),
-      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
-      createdBy: dco_decode_opt_String(arr[2]),
-      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
-      updatedBy: dco_decode_opt_String(arr[4]),
-      name: dco_decode_String(arr[5]),
-      note: dco_decode_opt_String(arr[6]),
-      username: dco_decode_String(arr[7]),
-      url: dco_decode_opt_String(arr[8]),
-      password: dco_decode_opt_String(arr[9]),
-      passwordHint: dco_decode_opt_String(arr[10]),
-      isFavorite: dco_decode_opt_box_autoadd_bool(arr[11]),
-      tags: dco_decode_opt_String(arr[12]),
-      apiKeys: dco_decode_opt_String(arr[13]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RekeyResult(
+      progress: dco_decode_rekey_progress(arr[0]),
+      recoveryKey: dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
     );
   }
 
   @protected
-  LoginData dco_decode_login_data(dynamic raw) {
+  ShareSettings dco_decode_share_settings(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2)
       throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LoginData(
-      email: dco_decode_String(arr[0]),
-      password: dco_decode_String(arr[1]),
+    return ShareSettings(
+      threshold: dco_decode_u_8(arr[0]),
+      shares: dco_decode_u_8(arr[1]),
     );
   }
 
   @protected
-  Note dco_decode_note(dynamic raw) {
+  Tag dco_decode_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return Note(
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Tag(
       id: dco_decode_opt_String(arr[0]),
       createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
       createdBy: dco_decode_opt_String(arr[2]),
       updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
       updatedBy: dco_decode_opt_String(arr[4]),
       name: dco_decode_String(arr[5]),
-      note: dco_decode_opt_String(arr[6]),
-      isFavorite: dco_decode_opt_box_autoadd_bool(arr[7]),
-      tags: dco_decode_opt_String(arr[8]),
     );
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
+  TwoFactorBackupCodes dco_decode_two_factor_backup_codes(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return TwoFactorBackupCodes(
+      codes: dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+    );
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+  TwoFactorChallenge dco_decode_two_factor_challenge(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TwoFactorChallenge(
+      challengeToken: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      expiresIn: dco_decode_u_64(arr[1]),
+    );
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+  TwoFactorLoginData dco_decode_two_factor_login_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TwoFactorLoginData(
+      challengeToken: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      code: dco_decode_String(arr[1]),
+    );
   }
 
   @protected
-  (bool, String) dco_decode_record_bool_string(dynamic raw) {
+  TwoFactorSetup dco_decode_two_factor_setup(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_bool(arr[0]),
-      dco_decode_String(arr[1]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TwoFactorSetup(
+      secret: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[0]),
+      otpauthUrl: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          arr[1]),
     );
   }
 
   @protected
-  RegisterData dco_decode_register_data(dynamic raw) {
+  TwoFactorStatus dco_decode_two_factor_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return RegisterData(
-      name: dco_decode_String(arr[0]),
-      email: dco_decode_String(arr[1]),
-      password: dco_decode_String(arr[2]),
-      rePassword: dco_decode_String(arr[3]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TwoFactorStatus(
+      enabled: dco_decode_bool(arr[0]),
+      backupCodesRemaining: dco_decode_u_32(arr[1]),
     );
   }
 
   @protected
-  Tag dco_decode_tag(dynamic raw) {
+  int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return Tag(
-      id: dco_decode_opt_String(arr[0]),
-      createdAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
-      createdBy: dco_decode_opt_String(arr[2]),
-      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
-      updatedBy: dco_decode_opt_String(arr[4]),
-      name: dco_decode_String(arr[5]),
-    );
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
   }
 
   @protected
@@ -1463,6 +3757,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  VaultHealth dco_decode_vault_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return VaultHealth(
+      total: dco_decode_u_32(arr[0]),
+      reusedCount: dco_decode_u_32(arr[1]),
+      weakCount: dco_decode_u_32(arr[2]),
+      staleCount: dco_decode_u_32(arr[3]),
+      emptyCount: dco_decode_u_32(arr[4]),
+      reused: dco_decode_list_list_String(arr[5]),
+      weak: dco_decode_list_weak_password(arr[6]),
+      stale: dco_decode_list_String(arr[7]),
+      staleDays: dco_decode_u_32(arr[8]),
+      empty: dco_decode_list_String(arr[9]),
+    );
+  }
+
+  @protected
+  WeakPassword dco_decode_weak_password(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WeakPassword(
+      id: dco_decode_String(arr[0]),
+      score: dco_decode_u_8(arr[1]),
+      warning: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1470,6 +3797,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  String sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return inner;
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1489,6 +3824,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  ChangePasswordData sse_decode_box_autoadd_change_password_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_change_password_data(deserializer));
+  }
+
   @protected
   FinancialCard sse_decode_box_autoadd_financial_card(
       SseDeserializer deserializer) {
@@ -1496,6 +3838,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_financial_card(deserializer));
   }
 
+  @protected
+  GeneratorOptions sse_decode_box_autoadd_generator_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generator_options(deserializer));
+  }
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1509,6 +3858,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_identity_card(deserializer));
   }
 
+  @protected
+  JwtTokens sse_decode_box_autoadd_jwt_tokens(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_jwt_tokens(deserializer));
+  }
+
+  @protected
+  KdfSettings sse_decode_box_autoadd_kdf_settings(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_kdf_settings(deserializer));
+  }
+
   @protected
   Login sse_decode_box_autoadd_login(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1527,6 +3889,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_note(deserializer));
   }
 
+  @protected
+  Passkey sse_decode_box_autoadd_passkey(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_passkey(deserializer));
+  }
+
+  @protected
+  PasskeyAssertionRequest sse_decode_box_autoadd_passkey_assertion_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_passkey_assertion_request(deserializer));
+  }
+
+  @protected
+  PasskeyCreation sse_decode_box_autoadd_passkey_creation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_passkey_creation(deserializer));
+  }
+
+  @protected
+  PassphraseOptions sse_decode_box_autoadd_passphrase_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_passphrase_options(deserializer));
+  }
+
+  @protected
+  PasswordOptions sse_decode_box_autoadd_password_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_password_options(deserializer));
+  }
+
+  @protected
+  PasswordPolicy sse_decode_box_autoadd_password_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_password_policy(deserializer));
+  }
+
+  @protected
+  PronounceableOptions sse_decode_box_autoadd_pronounceable_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pronounceable_options(deserializer));
+  }
+
+  @protected
+  QrOptions sse_decode_box_autoadd_qr_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_qr_options(deserializer));
+  }
+
+  @protected
+  RecoverAccountData sse_decode_box_autoadd_recover_account_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recover_account_data(deserializer));
+  }
+
+  @protected
+  RefreshTokenData sse_decode_box_autoadd_refresh_token_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_refresh_token_data(deserializer));
+  }
+
   @protected
   RegisterData sse_decode_box_autoadd_register_data(
       SseDeserializer deserializer) {
@@ -1534,12 +3964,106 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_register_data(deserializer));
   }
 
+  @protected
+  RekeyProgress sse_decode_box_autoadd_rekey_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rekey_progress(deserializer));
+  }
+
+  @protected
+  ShareSettings sse_decode_box_autoadd_share_settings(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_share_settings(deserializer));
+  }
+
   @protected
   Tag sse_decode_box_autoadd_tag(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_tag(deserializer));
   }
 
+  @protected
+  TwoFactorChallenge sse_decode_box_autoadd_two_factor_challenge(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_two_factor_challenge(deserializer));
+  }
+
+  @protected
+  TwoFactorLoginData sse_decode_box_autoadd_two_factor_login_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_two_factor_login_data(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BreachReport sse_decode_breach_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_checked = sse_decode_u_32(deserializer);
+    var var_breachedCount = sse_decode_u_32(deserializer);
+    var var_breached = sse_decode_list_breached_login(deserializer);
+    var var_checkedAt = sse_decode_i_64(deserializer);
+    return BreachReport(
+        checked: var_checked,
+        breachedCount: var_breachedCount,
+        breached: var_breached,
+        checkedAt: var_checkedAt);
+  }
+
+  @protected
+  BreachedLogin sse_decode_breached_login(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    return BreachedLogin(id: var_id, count: var_count);
+  }
+
+  @protected
+  Capitalization sse_decode_capitalization(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Capitalization.values[inner];
+  }
+
+  @protected
+  ChangePasswordData sse_decode_change_password_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_oldPassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_newPassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_rePassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return ChangePasswordData(
+        oldPassword: var_oldPassword,
+        newPassword: var_newPassword,
+        rePassword: var_rePassword);
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
   @protected
   FinancialCard sse_decode_financial_card(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1549,15 +4073,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_updatedBy = sse_decode_opt_String(deserializer);
     var var_cardHolderName = sse_decode_String(deserializer);
-    var var_cardNumber = sse_decode_String(deserializer);
+    var var_cardNumber =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
     var var_cardProviderName = sse_decode_opt_String(deserializer);
     var var_cardType = sse_decode_opt_String(deserializer);
-    var var_cvv = sse_decode_opt_String(deserializer);
+    var var_cvv =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
     var var_expiryDate = sse_decode_opt_String(deserializer);
     var var_issueDate = sse_decode_opt_String(deserializer);
     var var_name = sse_decode_String(deserializer);
     var var_note = sse_decode_opt_String(deserializer);
-    var var_pin = sse_decode_opt_String(deserializer);
+    var var_pin =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
     var var_isFavorite = sse_decode_opt_box_autoadd_bool(deserializer);
     var var_tags = sse_decode_opt_String(deserializer);
     return FinancialCard(
@@ -1580,6 +4110,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         tags: var_tags);
   }
 
+  @protected
+  GeneratedPassword sse_decode_generated_password(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_password =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_entropyBits = sse_decode_f_64(deserializer);
+    return GeneratedPassword(
+        password: var_password, entropyBits: var_entropyBits);
+  }
+
+  @protected
+  GeneratorOptions sse_decode_generator_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_password_options(deserializer);
+        return GeneratorOptions_Password(var_field0);
+      case 1:
+        var var_field0 =
+            sse_decode_box_autoadd_pronounceable_options(deserializer);
+        return GeneratorOptions_Pronounceable(var_field0);
+      case 2:
+        var var_field0 =
+            sse_decode_box_autoadd_passphrase_options(deserializer);
+        return GeneratorOptions_Passphrase(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1598,7 +4168,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_note = sse_decode_opt_String(deserializer);
     var var_country = sse_decode_opt_String(deserializer);
     var var_expiryDate = sse_decode_opt_String(deserializer);
-    var var_identityCardNumber = sse_decode_String(deserializer);
+    var var_identityCardNumber =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
     var var_identityCardType = sse_decode_opt_String(deserializer);
     var var_issueDate = sse_decode_opt_String(deserializer);
     var var_nameOnCard = sse_decode_String(deserializer);
@@ -1627,10 +4199,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   JwtTokens sse_decode_jwt_tokens(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_accessToken = sse_decode_String(deserializer);
-    var var_refreshToken = sse_decode_String(deserializer);
+    var var_accessToken =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_refreshToken =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_recoveryKey =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
     return JwtTokens(
-        accessToken: var_accessToken, refreshToken: var_refreshToken);
+        accessToken: var_accessToken,
+        refreshToken: var_refreshToken,
+        recoveryKey: var_recoveryKey);
+  }
+
+  @protected
+  KdfSettings sse_decode_kdf_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_memoryCost = sse_decode_u_32(deserializer);
+    var var_timeCost = sse_decode_u_32(deserializer);
+    var var_parallelism = sse_decode_u_32(deserializer);
+    return KdfSettings(
+        memoryCost: var_memoryCost,
+        timeCost: var_timeCost,
+        parallelism: var_parallelism);
+  }
+
+  @protected
+  List<String> sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+          sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+              deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <bool>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bool(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BreachedLogin> sse_decode_list_breached_login(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BreachedLogin>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_breached_login(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -1659,6 +4304,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <List<String>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_String(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Login> sse_decode_list_login(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1683,6 +4340,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Passkey> sse_decode_list_passkey(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Passkey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_passkey(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PasswordHistory> sse_decode_list_password_history(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PasswordHistory>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_password_history(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PasswordRule> sse_decode_list_password_rule(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PasswordRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_password_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1703,7 +4405,212 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Login sse_decode_login(SseDeserializer deserializer) {
+  List<WeakPassword> sse_decode_list_weak_password(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WeakPassword>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_weak_password(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Login sse_decode_login(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_createdBy = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_updatedBy = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    var var_password =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_passwordHint = sse_decode_opt_String(deserializer);
+    var var_isFavorite = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_tags = sse_decode_opt_String(deserializer);
+    var var_apiKeys = sse_decode_opt_String(deserializer);
+    var var_breachCount = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_breachCheckedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_historyLimit = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_otpauth =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return Login(
+        id: var_id,
+        createdAt: var_createdAt,
+        createdBy: var_createdBy,
+        updatedAt: var_updatedAt,
+        updatedBy: var_updatedBy,
+        name: var_name,
+        note: var_note,
+        username: var_username,
+        url: var_url,
+        password: var_password,
+        passwordHint: var_passwordHint,
+        isFavorite: var_isFavorite,
+        tags: var_tags,
+        apiKeys: var_apiKeys,
+        breachCount: var_breachCount,
+        breachCheckedAt: var_breachCheckedAt,
+        historyLimit: var_historyLimit,
+        otpauth: var_otpauth);
+  }
+
+  @protected
+  LoginData sse_decode_login_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_email = sse_decode_String(deserializer);
+    var var_password =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return LoginData(email: var_email, password: var_password);
+  }
+
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_jwt_tokens(deserializer);
+        return LoginResult_Tokens(var_field0);
+      case 1:
+        var var_field0 =
+            sse_decode_box_autoadd_two_factor_challenge(deserializer);
+        return LoginResult_TwoFactorRequired(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  Note sse_decode_note(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_createdBy = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_updatedBy = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_isFavorite = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_tags = sse_decode_opt_String(deserializer);
+    return Note(
+        id: var_id,
+        createdAt: var_createdAt,
+        createdBy: var_createdBy,
+        updatedAt: var_updatedAt,
+        updatedBy: var_updatedBy,
+        name: var_name,
+        note: var_note,
+        isFavorite: var_isFavorite,
+        tags: var_tags);
+  }
+
+  @protected
+  String? sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RekeyProgress? sse_decode_opt_box_autoadd_rekey_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rekey_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OtpCode sse_decode_otp_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_String(deserializer);
+    var var_period = sse_decode_u_64(deserializer);
+    var var_remainingSeconds = sse_decode_u_64(deserializer);
+    var var_counter = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return OtpCode(
+        code: var_code,
+        period: var_period,
+        remainingSeconds: var_remainingSeconds,
+        counter: var_counter);
+  }
+
+  @protected
+  Passkey sse_decode_passkey(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_id = sse_decode_opt_String(deserializer);
     var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
@@ -1712,14 +4619,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_updatedBy = sse_decode_opt_String(deserializer);
     var var_name = sse_decode_String(deserializer);
     var var_note = sse_decode_opt_String(deserializer);
-    var var_username = sse_decode_String(deserializer);
-    var var_url = sse_decode_opt_String(deserializer);
-    var var_password = sse_decode_opt_String(deserializer);
-    var var_passwordHint = sse_decode_opt_String(deserializer);
+    var var_rpId = sse_decode_String(deserializer);
+    var var_rpName = sse_decode_opt_String(deserializer);
+    var var_userHandle = sse_decode_String(deserializer);
+    var var_userName = sse_decode_opt_String(deserializer);
+    var var_userDisplayName = sse_decode_opt_String(deserializer);
+    var var_credentialId = sse_decode_String(deserializer);
+    var var_algorithm = sse_decode_i_64(deserializer);
+    var var_privateKey =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_signCount = sse_decode_i_64(deserializer);
+    var var_lastUsedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_isFavorite = sse_decode_opt_box_autoadd_bool(deserializer);
     var var_tags = sse_decode_opt_String(deserializer);
-    var var_apiKeys = sse_decode_opt_String(deserializer);
-    return Login(
+    return Passkey(
         id: var_id,
         createdAt: var_createdAt,
         createdBy: var_createdBy,
@@ -1727,78 +4641,272 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         updatedBy: var_updatedBy,
         name: var_name,
         note: var_note,
-        username: var_username,
-        url: var_url,
-        password: var_password,
-        passwordHint: var_passwordHint,
+        rpId: var_rpId,
+        rpName: var_rpName,
+        userHandle: var_userHandle,
+        userName: var_userName,
+        userDisplayName: var_userDisplayName,
+        credentialId: var_credentialId,
+        algorithm: var_algorithm,
+        privateKey: var_privateKey,
+        signCount: var_signCount,
+        lastUsedAt: var_lastUsedAt,
         isFavorite: var_isFavorite,
-        tags: var_tags,
-        apiKeys: var_apiKeys);
+        tags: var_tags);
   }
 
   @protected
-  LoginData sse_decode_login_data(SseDeserializer deserializer) {
+  PasskeyAssertion sse_decode_passkey_assertion(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_email = sse_decode_String(deserializer);
-    var var_password = sse_decode_String(deserializer);
-    return LoginData(email: var_email, password: var_password);
+    var var_credentialId = sse_decode_String(deserializer);
+    var var_authenticatorData = sse_decode_String(deserializer);
+    var var_signature = sse_decode_String(deserializer);
+    var var_userHandle = sse_decode_String(deserializer);
+    return PasskeyAssertion(
+        credentialId: var_credentialId,
+        authenticatorData: var_authenticatorData,
+        signature: var_signature,
+        userHandle: var_userHandle);
   }
 
   @protected
-  Note sse_decode_note(SseDeserializer deserializer) {
+  PasskeyAssertionRequest sse_decode_passkey_assertion_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rpId = sse_decode_String(deserializer);
+    var var_clientDataHash = sse_decode_String(deserializer);
+    var var_userVerified = sse_decode_bool(deserializer);
+    return PasskeyAssertionRequest(
+        rpId: var_rpId,
+        clientDataHash: var_clientDataHash,
+        userVerified: var_userVerified);
+  }
+
+  @protected
+  PasskeyCreation sse_decode_passkey_creation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rpId = sse_decode_String(deserializer);
+    var var_rpName = sse_decode_opt_String(deserializer);
+    var var_userHandle = sse_decode_String(deserializer);
+    var var_userName = sse_decode_opt_String(deserializer);
+    var var_userDisplayName = sse_decode_opt_String(deserializer);
+    var var_clientDataHash = sse_decode_String(deserializer);
+    var var_algorithms = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_userVerified = sse_decode_bool(deserializer);
+    return PasskeyCreation(
+        rpId: var_rpId,
+        rpName: var_rpName,
+        userHandle: var_userHandle,
+        userName: var_userName,
+        userDisplayName: var_userDisplayName,
+        clientDataHash: var_clientDataHash,
+        algorithms: var_algorithms,
+        userVerified: var_userVerified);
+  }
+
+  @protected
+  PasskeyRegistration sse_decode_passkey_registration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_passkey = sse_decode_passkey(deserializer);
+    var var_credentialId = sse_decode_String(deserializer);
+    var var_attestationObject = sse_decode_String(deserializer);
+    var var_authenticatorData = sse_decode_String(deserializer);
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_publicKeyAlgorithm = sse_decode_i_64(deserializer);
+    return PasskeyRegistration(
+        passkey: var_passkey,
+        credentialId: var_credentialId,
+        attestationObject: var_attestationObject,
+        authenticatorData: var_authenticatorData,
+        publicKey: var_publicKey,
+        publicKeyAlgorithm: var_publicKeyAlgorithm);
+  }
+
+  @protected
+  PassphraseOptions sse_decode_passphrase_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_words = sse_decode_u_32(deserializer);
+    var var_separator = sse_decode_String(deserializer);
+    var var_capitalization = sse_decode_capitalization(deserializer);
+    return PassphraseOptions(
+        words: var_words,
+        separator: var_separator,
+        capitalization: var_capitalization);
+  }
+
+  @protected
+  PasswordFeedback sse_decode_password_feedback(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_warning = sse_decode_opt_String(deserializer);
+    var var_suggestions = sse_decode_list_String(deserializer);
+    return PasswordFeedback(warning: var_warning, suggestions: var_suggestions);
+  }
+
+  @protected
+  PasswordHistory sse_decode_password_history(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_id = sse_decode_opt_String(deserializer);
     var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_createdBy = sse_decode_opt_String(deserializer);
     var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_updatedBy = sse_decode_opt_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_note = sse_decode_opt_String(deserializer);
-    var var_isFavorite = sse_decode_opt_box_autoadd_bool(deserializer);
-    var var_tags = sse_decode_opt_String(deserializer);
-    return Note(
+    var var_loginId = sse_decode_String(deserializer);
+    var var_password =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return PasswordHistory(
         id: var_id,
         createdAt: var_createdAt,
         createdBy: var_createdBy,
         updatedAt: var_updatedAt,
         updatedBy: var_updatedBy,
-        name: var_name,
-        note: var_note,
-        isFavorite: var_isFavorite,
-        tags: var_tags);
+        loginId: var_loginId,
+        password: var_password);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  PasswordOptions sse_decode_password_options(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_length = sse_decode_u_32(deserializer);
+    var var_uppercase = sse_decode_bool(deserializer);
+    var var_lowercase = sse_decode_bool(deserializer);
+    var var_digits = sse_decode_bool(deserializer);
+    var var_symbols = sse_decode_bool(deserializer);
+    var var_minUppercase = sse_decode_u_32(deserializer);
+    var var_minLowercase = sse_decode_u_32(deserializer);
+    var var_minDigits = sse_decode_u_32(deserializer);
+    var var_minSymbols = sse_decode_u_32(deserializer);
+    var var_symbolSet = sse_decode_opt_String(deserializer);
+    var var_excludeAmbiguous = sse_decode_bool(deserializer);
+    return PasswordOptions(
+        length: var_length,
+        uppercase: var_uppercase,
+        lowercase: var_lowercase,
+        digits: var_digits,
+        symbols: var_symbols,
+        minUppercase: var_minUppercase,
+        minLowercase: var_minLowercase,
+        minDigits: var_minDigits,
+        minSymbols: var_minSymbols,
+        symbolSet: var_symbolSet,
+        excludeAmbiguous: var_excludeAmbiguous);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  PasswordPolicy sse_decode_password_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minLength = sse_decode_u_32(deserializer);
+    var var_maxLength = sse_decode_u_32(deserializer);
+    var var_requireUppercase = sse_decode_bool(deserializer);
+    var var_requireLowercase = sse_decode_bool(deserializer);
+    var var_requireDigit = sse_decode_bool(deserializer);
+    var var_requireSymbol = sse_decode_bool(deserializer);
+    var var_minScore = sse_decode_u_8(deserializer);
+    var var_allowUnicode = sse_decode_bool(deserializer);
+    var var_bannedWords = sse_decode_list_String(deserializer);
+    return PasswordPolicy(
+        minLength: var_minLength,
+        maxLength: var_maxLength,
+        requireUppercase: var_requireUppercase,
+        requireLowercase: var_requireLowercase,
+        requireDigit: var_requireDigit,
+        requireSymbol: var_requireSymbol,
+        minScore: var_minScore,
+        allowUnicode: var_allowUnicode,
+        bannedWords: var_bannedWords);
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  PasswordRule sse_decode_password_rule(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_minLength = sse_decode_u_32(deserializer);
+        return PasswordRule_TooShort(minLength: var_minLength);
+      case 1:
+        var var_maxLength = sse_decode_u_32(deserializer);
+        return PasswordRule_TooLong(maxLength: var_maxLength);
+      case 2:
+        return PasswordRule_MissingUppercase();
+      case 3:
+        return PasswordRule_MissingLowercase();
+      case 4:
+        return PasswordRule_MissingDigit();
+      case 5:
+        return PasswordRule_MissingSymbol();
+      case 6:
+        var var_score = sse_decode_u_8(deserializer);
+        var var_minScore = sse_decode_u_8(deserializer);
+        return PasswordRule_TooWeak(score: var_score, minScore: var_minScore);
+      case 7:
+        return PasswordRule_UnicodeNotAllowed();
+      case 8:
+        var var_word = sse_decode_String(deserializer);
+        return PasswordRule_BannedWord(word: var_word);
+      default:
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  PasswordStrength sse_decode_password_strength(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_u_8(deserializer);
+    var var_guesses = sse_decode_f_64(deserializer);
+    var var_guessesLog10 = sse_decode_f_64(deserializer);
+    var var_crackTimeSeconds = sse_decode_f_64(deserializer);
+    var var_crackTimeDisplay = sse_decode_String(deserializer);
+    var var_feedback = sse_decode_password_feedback(deserializer);
+    return PasswordStrength(
+        score: var_score,
+        guesses: var_guesses,
+        guessesLog10: var_guessesLog10,
+        crackTimeSeconds: var_crackTimeSeconds,
+        crackTimeDisplay: var_crackTimeDisplay,
+        feedback: var_feedback);
+  }
+
+  @protected
+  PronounceableOptions sse_decode_pronounceable_options(
+      SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_length = sse_decode_u_32(deserializer);
+    var var_capitalize = sse_decode_bool(deserializer);
+    var var_digits = sse_decode_u_32(deserializer);
+    return PronounceableOptions(
+        length: var_length, capitalize: var_capitalize, digits: var_digits);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  QrErrorCorrection sse_decode_qr_error_correction(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QrErrorCorrection.values[inner];
+  }
+
+  @protected
+  QrMatrix sse_decode_qr_matrix(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_size = sse_decode_u_32(deserializer);
+    var var_modules = sse_decode_list_bool(deserializer);
+    return QrMatrix(size: var_size, modules: var_modules);
+  }
+
+  @protected
+  QrOptions sse_decode_qr_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_errorCorrection = sse_decode_qr_error_correction(deserializer);
+    var var_quietZone = sse_decode_u_32(deserializer);
+    var var_moduleSize = sse_decode_u_32(deserializer);
+    return QrOptions(
+        errorCorrection: var_errorCorrection,
+        quietZone: var_quietZone,
+        moduleSize: var_moduleSize);
   }
 
   @protected
@@ -1809,18 +4917,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  RecoverAccountData sse_decode_recover_account_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_email = sse_decode_String(deserializer);
+    var var_recoveryKey =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_newPassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_rePassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_twoFactorCode = sse_decode_opt_String(deserializer);
+    return RecoverAccountData(
+        email: var_email,
+        recoveryKey: var_recoveryKey,
+        newPassword: var_newPassword,
+        rePassword: var_rePassword,
+        twoFactorCode: var_twoFactorCode);
+  }
+
+  @protected
+  RefreshTokenData sse_decode_refresh_token_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_refreshToken =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return RefreshTokenData(refreshToken: var_refreshToken);
+  }
+
   @protected
   RegisterData sse_decode_register_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_name = sse_decode_String(deserializer);
     var var_email = sse_decode_String(deserializer);
-    var var_password = sse_decode_String(deserializer);
-    var var_rePassword = sse_decode_String(deserializer);
+    var var_password =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_rePassword =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_recoveryKey = sse_decode_bool(deserializer);
     return RegisterData(
         name: var_name,
         email: var_email,
         password: var_password,
-        rePassword: var_rePassword);
+        rePassword: var_rePassword,
+        recoveryKey: var_recoveryKey);
+  }
+
+  @protected
+  RekeyProgress sse_decode_rekey_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_done = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    return RekeyProgress(
+        done: var_done, total: var_total, finished: var_finished);
+  }
+
+  @protected
+  RekeyResult sse_decode_rekey_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_progress = sse_decode_rekey_progress(deserializer);
+    var var_recoveryKey =
+        sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return RekeyResult(progress: var_progress, recoveryKey: var_recoveryKey);
+  }
+
+  @protected
+  ShareSettings sse_decode_share_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_threshold = sse_decode_u_8(deserializer);
+    var var_shares = sse_decode_u_8(deserializer);
+    return ShareSettings(threshold: var_threshold, shares: var_shares);
   }
 
   @protected
@@ -1841,6 +5015,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         name: var_name);
   }
 
+  @protected
+  TwoFactorBackupCodes sse_decode_two_factor_backup_codes(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codes =
+        sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return TwoFactorBackupCodes(codes: var_codes);
+  }
+
+  @protected
+  TwoFactorChallenge sse_decode_two_factor_challenge(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_challengeToken =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_expiresIn = sse_decode_u_64(deserializer);
+    return TwoFactorChallenge(
+        challengeToken: var_challengeToken, expiresIn: var_expiresIn);
+  }
+
+  @protected
+  TwoFactorLoginData sse_decode_two_factor_login_data(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_challengeToken =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_code = sse_decode_String(deserializer);
+    return TwoFactorLoginData(
+        challengeToken: var_challengeToken, code: var_code);
+  }
+
+  @protected
+  TwoFactorSetup sse_decode_two_factor_setup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_secret =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    var var_otpauthUrl =
+        sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+            deserializer);
+    return TwoFactorSetup(secret: var_secret, otpauthUrl: var_otpauthUrl);
+  }
+
+  @protected
+  TwoFactorStatus sse_decode_two_factor_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_backupCodesRemaining = sse_decode_u_32(deserializer);
+    return TwoFactorStatus(
+        enabled: var_enabled, backupCodesRemaining: var_backupCodesRemaining);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1853,9 +5094,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  VaultHealth sse_decode_vault_health(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    var var_total = sse_decode_u_32(deserializer);
+    var var_reusedCount = sse_decode_u_32(deserializer);
+    var var_weakCount = sse_decode_u_32(deserializer);
+    var var_staleCount = sse_decode_u_32(deserializer);
+    var var_emptyCount = sse_decode_u_32(deserializer);
+    var var_reused = sse_decode_list_list_String(deserializer);
+    var var_weak = sse_decode_list_weak_password(deserializer);
+    var var_stale = sse_decode_list_String(deserializer);
+    var var_staleDays = sse_decode_u_32(deserializer);
+    var var_empty = sse_decode_list_String(deserializer);
+    return VaultHealth(
+        total: var_total,
+        reusedCount: var_reusedCount,
+        weakCount: var_weakCount,
+        staleCount: var_staleCount,
+        emptyCount: var_emptyCount,
+        reused: var_reused,
+        weak: var_weak,
+        stale: var_stale,
+        staleDays: var_staleDays,
+        empty: var_empty);
+  }
+
+  @protected
+  WeakPassword sse_decode_weak_password(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_score = sse_decode_u_8(deserializer);
+    var var_warning = sse_decode_opt_String(deserializer);
+    return WeakPassword(id: var_id, score: var_score, warning: var_warning);
   }
 
   @protected
@@ -1865,6 +5135,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self, serializer);
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1883,6 +5160,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_change_password_data(
+      ChangePasswordData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_change_password_data(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_financial_card(
       FinancialCard self, SseSerializer serializer) {
@@ -1890,6 +5174,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_financial_card(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_generator_options(
+      GeneratorOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_generator_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_64(
       PlatformInt64 self, SseSerializer serializer) {
@@ -1904,6 +5195,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_identity_card(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_jwt_tokens(
+      JwtTokens self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_jwt_tokens(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_kdf_settings(
+      KdfSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_kdf_settings(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_login(Login self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1923,6 +5228,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_note(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_passkey(Passkey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_passkey(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_passkey_assertion_request(
+      PasskeyAssertionRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_passkey_assertion_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_passkey_creation(
+      PasskeyCreation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_passkey_creation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_passphrase_options(
+      PassphraseOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_passphrase_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_password_options(
+      PasswordOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_password_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_password_policy(
+      PasswordPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_password_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pronounceable_options(
+      PronounceableOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pronounceable_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_qr_options(
+      QrOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_qr_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_recover_account_data(
+      RecoverAccountData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recover_account_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_refresh_token_data(
+      RefreshTokenData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_refresh_token_data(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_register_data(
       RegisterData self, SseSerializer serializer) {
@@ -1930,12 +5304,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_register_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_rekey_progress(
+      RekeyProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rekey_progress(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_share_settings(
+      ShareSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_share_settings(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_tag(Tag self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_tag(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_two_factor_challenge(
+      TwoFactorChallenge self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_two_factor_challenge(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_two_factor_login_data(
+      TwoFactorLoginData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_two_factor_login_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_breach_report(BreachReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.checked, serializer);
+    sse_encode_u_32(self.breachedCount, serializer);
+    sse_encode_list_breached_login(self.breached, serializer);
+    sse_encode_i_64(self.checkedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_breached_login(BreachedLogin self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_u_32(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_capitalization(
+      Capitalization self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_change_password_data(
+      ChangePasswordData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.oldPassword, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.newPassword, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.rePassword, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
   @protected
   void sse_encode_financial_card(FinancialCard self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1945,19 +5400,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_64(self.updatedAt, serializer);
     sse_encode_opt_String(self.updatedBy, serializer);
     sse_encode_String(self.cardHolderName, serializer);
-    sse_encode_String(self.cardNumber, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.cardNumber, serializer);
     sse_encode_opt_String(self.cardProviderName, serializer);
     sse_encode_opt_String(self.cardType, serializer);
-    sse_encode_opt_String(self.cvv, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.cvv, serializer);
     sse_encode_opt_String(self.expiryDate, serializer);
     sse_encode_opt_String(self.issueDate, serializer);
     sse_encode_String(self.name, serializer);
     sse_encode_opt_String(self.note, serializer);
-    sse_encode_opt_String(self.pin, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.pin, serializer);
     sse_encode_opt_box_autoadd_bool(self.isFavorite, serializer);
     sse_encode_opt_String(self.tags, serializer);
   }
 
+  @protected
+  void sse_encode_generated_password(
+      GeneratedPassword self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.password, serializer);
+    sse_encode_f_64(self.entropyBits, serializer);
+  }
+
+  @protected
+  void sse_encode_generator_options(
+      GeneratorOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GeneratorOptions_Password(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_password_options(field0, serializer);
+      case GeneratorOptions_Pronounceable(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_pronounceable_options(field0, serializer);
+      case GeneratorOptions_Passphrase(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_passphrase_options(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1976,7 +5466,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.note, serializer);
     sse_encode_opt_String(self.country, serializer);
     sse_encode_opt_String(self.expiryDate, serializer);
-    sse_encode_String(self.identityCardNumber, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.identityCardNumber, serializer);
     sse_encode_opt_String(self.identityCardType, serializer);
     sse_encode_opt_String(self.issueDate, serializer);
     sse_encode_String(self.nameOnCard, serializer);
@@ -1988,8 +5479,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_jwt_tokens(JwtTokens self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.accessToken, serializer);
-    sse_encode_String(self.refreshToken, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.accessToken, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.refreshToken, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.recoveryKey, serializer);
+  }
+
+  @protected
+  void sse_encode_kdf_settings(KdfSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.memoryCost, serializer);
+    sse_encode_u_32(self.timeCost, serializer);
+    sse_encode_u_32(self.parallelism, serializer);
+  }
+
+  @protected
+  void sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bool(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_breached_login(
+      List<BreachedLogin> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_breached_login(item, serializer);
+    }
   }
 
   @protected
@@ -2012,6 +5554,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_list_String(
+      List<List<String>> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_String(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_login(List<Login> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2030,6 +5582,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_passkey(List<Passkey> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_passkey(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_password_history(
+      List<PasswordHistory> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_password_history(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_password_rule(
+      List<PasswordRule> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_password_rule(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+      Int64List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer) {
@@ -2047,6 +5636,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_weak_password(
+      List<WeakPassword> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_weak_password(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_login(Login self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2059,18 +5658,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.note, serializer);
     sse_encode_String(self.username, serializer);
     sse_encode_opt_String(self.url, serializer);
-    sse_encode_opt_String(self.password, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.password, serializer);
     sse_encode_opt_String(self.passwordHint, serializer);
     sse_encode_opt_box_autoadd_bool(self.isFavorite, serializer);
     sse_encode_opt_String(self.tags, serializer);
     sse_encode_opt_String(self.apiKeys, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.breachCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.breachCheckedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.historyLimit, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.otpauth, serializer);
   }
 
   @protected
   void sse_encode_login_data(LoginData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.email, serializer);
-    sse_encode_String(self.password, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LoginResult_Tokens(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_jwt_tokens(field0, serializer);
+      case LoginResult_TwoFactorRequired(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_two_factor_challenge(field0, serializer);
+    }
   }
 
   @protected
@@ -2087,6 +5706,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.tags, serializer);
   }
 
+  @protected
+  void sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+          self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2118,6 +5749,252 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_rekey_progress(
+      RekeyProgress? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_rekey_progress(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_otp_code(OtpCode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.code, serializer);
+    sse_encode_u_64(self.period, serializer);
+    sse_encode_u_64(self.remainingSeconds, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.counter, serializer);
+  }
+
+  @protected
+  void sse_encode_passkey(Passkey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.id, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_opt_String(self.createdBy, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.updatedAt, serializer);
+    sse_encode_opt_String(self.updatedBy, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_String(self.rpId, serializer);
+    sse_encode_opt_String(self.rpName, serializer);
+    sse_encode_String(self.userHandle, serializer);
+    sse_encode_opt_String(self.userName, serializer);
+    sse_encode_opt_String(self.userDisplayName, serializer);
+    sse_encode_String(self.credentialId, serializer);
+    sse_encode_i_64(self.algorithm, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.privateKey, serializer);
+    sse_encode_i_64(self.signCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastUsedAt, serializer);
+    sse_encode_opt_box_autoadd_bool(self.isFavorite, serializer);
+    sse_encode_opt_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_passkey_assertion(
+      PasskeyAssertion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.credentialId, serializer);
+    sse_encode_String(self.authenticatorData, serializer);
+    sse_encode_String(self.signature, serializer);
+    sse_encode_String(self.userHandle, serializer);
+  }
+
+  @protected
+  void sse_encode_passkey_assertion_request(
+      PasskeyAssertionRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rpId, serializer);
+    sse_encode_String(self.clientDataHash, serializer);
+    sse_encode_bool(self.userVerified, serializer);
+  }
+
+  @protected
+  void sse_encode_passkey_creation(
+      PasskeyCreation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rpId, serializer);
+    sse_encode_opt_String(self.rpName, serializer);
+    sse_encode_String(self.userHandle, serializer);
+    sse_encode_opt_String(self.userName, serializer);
+    sse_encode_opt_String(self.userDisplayName, serializer);
+    sse_encode_String(self.clientDataHash, serializer);
+    sse_encode_list_prim_i_64_strict(self.algorithms, serializer);
+    sse_encode_bool(self.userVerified, serializer);
+  }
+
+  @protected
+  void sse_encode_passkey_registration(
+      PasskeyRegistration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_passkey(self.passkey, serializer);
+    sse_encode_String(self.credentialId, serializer);
+    sse_encode_String(self.attestationObject, serializer);
+    sse_encode_String(self.authenticatorData, serializer);
+    sse_encode_String(self.publicKey, serializer);
+    sse_encode_i_64(self.publicKeyAlgorithm, serializer);
+  }
+
+  @protected
+  void sse_encode_passphrase_options(
+      PassphraseOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.words, serializer);
+    sse_encode_String(self.separator, serializer);
+    sse_encode_capitalization(self.capitalization, serializer);
+  }
+
+  @protected
+  void sse_encode_password_feedback(
+      PasswordFeedback self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.warning, serializer);
+    sse_encode_list_String(self.suggestions, serializer);
+  }
+
+  @protected
+  void sse_encode_password_history(
+      PasswordHistory self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.id, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_opt_String(self.createdBy, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.updatedAt, serializer);
+    sse_encode_opt_String(self.updatedBy, serializer);
+    sse_encode_String(self.loginId, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_password_options(
+      PasswordOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.length, serializer);
+    sse_encode_bool(self.uppercase, serializer);
+    sse_encode_bool(self.lowercase, serializer);
+    sse_encode_bool(self.digits, serializer);
+    sse_encode_bool(self.symbols, serializer);
+    sse_encode_u_32(self.minUppercase, serializer);
+    sse_encode_u_32(self.minLowercase, serializer);
+    sse_encode_u_32(self.minDigits, serializer);
+    sse_encode_u_32(self.minSymbols, serializer);
+    sse_encode_opt_String(self.symbolSet, serializer);
+    sse_encode_bool(self.excludeAmbiguous, serializer);
+  }
+
+  @protected
+  void sse_encode_password_policy(
+      PasswordPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.minLength, serializer);
+    sse_encode_u_32(self.maxLength, serializer);
+    sse_encode_bool(self.requireUppercase, serializer);
+    sse_encode_bool(self.requireLowercase, serializer);
+    sse_encode_bool(self.requireDigit, serializer);
+    sse_encode_bool(self.requireSymbol, serializer);
+    sse_encode_u_8(self.minScore, serializer);
+    sse_encode_bool(self.allowUnicode, serializer);
+    sse_encode_list_String(self.bannedWords, serializer);
+  }
+
+  @protected
+  void sse_encode_password_rule(PasswordRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case PasswordRule_TooShort(minLength: final minLength):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(minLength, serializer);
+      case PasswordRule_TooLong(maxLength: final maxLength):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(maxLength, serializer);
+      case PasswordRule_MissingUppercase():
+        sse_encode_i_32(2, serializer);
+      case PasswordRule_MissingLowercase():
+        sse_encode_i_32(3, serializer);
+      case PasswordRule_MissingDigit():
+        sse_encode_i_32(4, serializer);
+      case PasswordRule_MissingSymbol():
+        sse_encode_i_32(5, serializer);
+      case PasswordRule_TooWeak(score: final score, minScore: final minScore):
+        sse_encode_i_32(6, serializer);
+        sse_encode_u_8(score, serializer);
+        sse_encode_u_8(minScore, serializer);
+      case PasswordRule_UnicodeNotAllowed():
+        sse_encode_i_32(7, serializer);
+      case PasswordRule_BannedWord(word: final word):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(word, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_password_strength(
+      PasswordStrength self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self.score, serializer);
+    sse_encode_f_64(self.guesses, serializer);
+    sse_encode_f_64(self.guessesLog10, serializer);
+    sse_encode_f_64(self.crackTimeSeconds, serializer);
+    sse_encode_String(self.crackTimeDisplay, serializer);
+    sse_encode_password_feedback(self.feedback, serializer);
+  }
+
+  @protected
+  void sse_encode_pronounceable_options(
+      PronounceableOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.length, serializer);
+    sse_encode_bool(self.capitalize, serializer);
+    sse_encode_u_32(self.digits, serializer);
+  }
+
+  @protected
+  void sse_encode_qr_error_correction(
+      QrErrorCorrection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_qr_matrix(QrMatrix self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_list_bool(self.modules, serializer);
+  }
+
+  @protected
+  void sse_encode_qr_options(QrOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_qr_error_correction(self.errorCorrection, serializer);
+    sse_encode_u_32(self.quietZone, serializer);
+    sse_encode_u_32(self.moduleSize, serializer);
+  }
+
   @protected
   void sse_encode_record_bool_string(
       (bool, String) self, SseSerializer serializer) {
@@ -2126,13 +6003,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_recover_account_data(
+      RecoverAccountData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.email, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.recoveryKey, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.newPassword, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.rePassword, serializer);
+    sse_encode_opt_String(self.twoFactorCode, serializer);
+  }
+
+  @protected
+  void sse_encode_refresh_token_data(
+      RefreshTokenData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.refreshToken, serializer);
+  }
+
   @protected
   void sse_encode_register_data(RegisterData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.name, serializer);
     sse_encode_String(self.email, serializer);
-    sse_encode_String(self.password, serializer);
-    sse_encode_String(self.rePassword, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.password, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.rePassword, serializer);
+    sse_encode_bool(self.recoveryKey, serializer);
+  }
+
+  @protected
+  void sse_encode_rekey_progress(RekeyProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.done, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_bool(self.finished, serializer);
+  }
+
+  @protected
+  void sse_encode_rekey_result(RekeyResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rekey_progress(self.progress, serializer);
+    sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.recoveryKey, serializer);
+  }
+
+  @protected
+  void sse_encode_share_settings(ShareSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self.threshold, serializer);
+    sse_encode_u_8(self.shares, serializer);
   }
 
   @protected
@@ -2146,6 +6071,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.name, serializer);
   }
 
+  @protected
+  void sse_encode_two_factor_backup_codes(
+      TwoFactorBackupCodes self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.codes, serializer);
+  }
+
+  @protected
+  void sse_encode_two_factor_challenge(
+      TwoFactorChallenge self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.challengeToken, serializer);
+    sse_encode_u_64(self.expiresIn, serializer);
+  }
+
+  @protected
+  void sse_encode_two_factor_login_data(
+      TwoFactorLoginData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.challengeToken, serializer);
+    sse_encode_String(self.code, serializer);
+  }
+
+  @protected
+  void sse_encode_two_factor_setup(
+      TwoFactorSetup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.secret, serializer);
+    sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+        self.otpauthUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_two_factor_status(
+      TwoFactorStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.backupCodesRemaining, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2158,8 +6139,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_vault_health(VaultHealth self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_u_32(self.reusedCount, serializer);
+    sse_encode_u_32(self.weakCount, serializer);
+    sse_encode_u_32(self.staleCount, serializer);
+    sse_encode_u_32(self.emptyCount, serializer);
+    sse_encode_list_list_String(self.reused, serializer);
+    sse_encode_list_weak_password(self.weak, serializer);
+    sse_encode_list_String(self.stale, serializer);
+    sse_encode_u_32(self.staleDays, serializer);
+    sse_encode_list_String(self.empty, serializer);
+  }
+
+  @protected
+  void sse_encode_weak_password(WeakPassword self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_u_8(self.score, serializer);
+    sse_encode_opt_String(self.warning, serializer);
   }
 }
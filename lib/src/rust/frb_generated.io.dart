@@ -13,6 +13,20 @@ import 'models/identity_cards.dart';
 import 'models/logins.dart';
 import 'models/notes.dart';
 import 'models/others/authentication.dart';
+import 'models/others/breach.dart';
+import 'models/others/generator.dart';
+import 'models/others/health.dart';
+import 'models/others/kdf_settings.dart';
+import 'models/others/otp.dart';
+import 'models/others/passkey.dart';
+import 'models/others/password_policy.dart';
+import 'models/others/qr.dart';
+import 'models/others/rekey_progress.dart';
+import 'models/others/share_settings.dart';
+import 'models/others/strength.dart';
+import 'models/others/two_factor.dart';
+import 'models/passkeys.dart';
+import 'models/password_history.dart';
 import 'models/tags.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
@@ -27,6 +41,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  String dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -36,15 +54,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  ChangePasswordData dco_decode_box_autoadd_change_password_data(dynamic raw);
+
   @protected
   FinancialCard dco_decode_box_autoadd_financial_card(dynamic raw);
 
+  @protected
+  GeneratorOptions dco_decode_box_autoadd_generator_options(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
   IdentityCard dco_decode_box_autoadd_identity_card(dynamic raw);
 
+  @protected
+  JwtTokens dco_decode_box_autoadd_jwt_tokens(dynamic raw);
+
+  @protected
+  KdfSettings dco_decode_box_autoadd_kdf_settings(dynamic raw);
+
   @protected
   Login dco_decode_box_autoadd_login(dynamic raw);
 
@@ -54,15 +84,89 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Note dco_decode_box_autoadd_note(dynamic raw);
 
+  @protected
+  Passkey dco_decode_box_autoadd_passkey(dynamic raw);
+
+  @protected
+  PasskeyAssertionRequest dco_decode_box_autoadd_passkey_assertion_request(
+      dynamic raw);
+
+  @protected
+  PasskeyCreation dco_decode_box_autoadd_passkey_creation(dynamic raw);
+
+  @protected
+  PassphraseOptions dco_decode_box_autoadd_passphrase_options(dynamic raw);
+
+  @protected
+  PasswordOptions dco_decode_box_autoadd_password_options(dynamic raw);
+
+  @protected
+  PasswordPolicy dco_decode_box_autoadd_password_policy(dynamic raw);
+
+  @protected
+  PronounceableOptions dco_decode_box_autoadd_pronounceable_options(
+      dynamic raw);
+
+  @protected
+  QrOptions dco_decode_box_autoadd_qr_options(dynamic raw);
+
+  @protected
+  RecoverAccountData dco_decode_box_autoadd_recover_account_data(dynamic raw);
+
+  @protected
+  RefreshTokenData dco_decode_box_autoadd_refresh_token_data(dynamic raw);
+
   @protected
   RegisterData dco_decode_box_autoadd_register_data(dynamic raw);
 
+  @protected
+  RekeyProgress dco_decode_box_autoadd_rekey_progress(dynamic raw);
+
+  @protected
+  ShareSettings dco_decode_box_autoadd_share_settings(dynamic raw);
+
   @protected
   Tag dco_decode_box_autoadd_tag(dynamic raw);
 
+  @protected
+  TwoFactorChallenge dco_decode_box_autoadd_two_factor_challenge(dynamic raw);
+
+  @protected
+  TwoFactorLoginData dco_decode_box_autoadd_two_factor_login_data(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BreachReport dco_decode_breach_report(dynamic raw);
+
+  @protected
+  BreachedLogin dco_decode_breached_login(dynamic raw);
+
+  @protected
+  Capitalization dco_decode_capitalization(dynamic raw);
+
+  @protected
+  ChangePasswordData dco_decode_change_password_data(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
   @protected
   FinancialCard dco_decode_financial_card(dynamic raw);
 
+  @protected
+  GeneratedPassword dco_decode_generated_password(dynamic raw);
+
+  @protected
+  GeneratorOptions dco_decode_generator_options(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
@@ -72,33 +176,74 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   JwtTokens dco_decode_jwt_tokens(dynamic raw);
 
+  @protected
+  KdfSettings dco_decode_kdf_settings(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw);
+
+  @protected
+  List<BreachedLogin> dco_decode_list_breached_login(dynamic raw);
+
   @protected
   List<FinancialCard> dco_decode_list_financial_card(dynamic raw);
 
   @protected
   List<IdentityCard> dco_decode_list_identity_card(dynamic raw);
 
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw);
+
   @protected
   List<Login> dco_decode_list_login(dynamic raw);
 
   @protected
   List<Note> dco_decode_list_note(dynamic raw);
 
+  @protected
+  List<Passkey> dco_decode_list_passkey(dynamic raw);
+
+  @protected
+  List<PasswordHistory> dco_decode_list_password_history(dynamic raw);
+
+  @protected
+  List<PasswordRule> dco_decode_list_password_rule(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<Tag> dco_decode_list_tag(dynamic raw);
 
+  @protected
+  List<WeakPassword> dco_decode_list_weak_password(dynamic raw);
+
   @protected
   Login dco_decode_login(dynamic raw);
 
   @protected
   LoginData dco_decode_login_data(dynamic raw);
 
+  @protected
+  LoginResult dco_decode_login_result(dynamic raw);
+
   @protected
   Note dco_decode_note(dynamic raw);
 
+  @protected
+  String? dco_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -108,24 +253,130 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  RekeyProgress? dco_decode_opt_box_autoadd_rekey_progress(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  OtpCode dco_decode_otp_code(dynamic raw);
+
+  @protected
+  Passkey dco_decode_passkey(dynamic raw);
+
+  @protected
+  PasskeyAssertion dco_decode_passkey_assertion(dynamic raw);
+
+  @protected
+  PasskeyAssertionRequest dco_decode_passkey_assertion_request(dynamic raw);
+
+  @protected
+  PasskeyCreation dco_decode_passkey_creation(dynamic raw);
+
+  @protected
+  PasskeyRegistration dco_decode_passkey_registration(dynamic raw);
+
+  @protected
+  PassphraseOptions dco_decode_passphrase_options(dynamic raw);
+
+  @protected
+  PasswordFeedback dco_decode_password_feedback(dynamic raw);
+
+  @protected
+  PasswordHistory dco_decode_password_history(dynamic raw);
+
+  @protected
+  PasswordOptions dco_decode_password_options(dynamic raw);
+
+  @protected
+  PasswordPolicy dco_decode_password_policy(dynamic raw);
+
+  @protected
+  PasswordRule dco_decode_password_rule(dynamic raw);
+
+  @protected
+  PasswordStrength dco_decode_password_strength(dynamic raw);
+
+  @protected
+  PronounceableOptions dco_decode_pronounceable_options(dynamic raw);
+
+  @protected
+  QrErrorCorrection dco_decode_qr_error_correction(dynamic raw);
+
+  @protected
+  QrMatrix dco_decode_qr_matrix(dynamic raw);
+
+  @protected
+  QrOptions dco_decode_qr_options(dynamic raw);
+
   @protected
   (bool, String) dco_decode_record_bool_string(dynamic raw);
 
+  @protected
+  RecoverAccountData dco_decode_recover_account_data(dynamic raw);
+
+  @protected
+  RefreshTokenData dco_decode_refresh_token_data(dynamic raw);
+
   @protected
   RegisterData dco_decode_register_data(dynamic raw);
 
+  @protected
+  RekeyProgress dco_decode_rekey_progress(dynamic raw);
+
+  @protected
+  RekeyResult dco_decode_rekey_result(dynamic raw);
+
+  @protected
+  ShareSettings dco_decode_share_settings(dynamic raw);
+
   @protected
   Tag dco_decode_tag(dynamic raw);
 
+  @protected
+  TwoFactorBackupCodes dco_decode_two_factor_backup_codes(dynamic raw);
+
+  @protected
+  TwoFactorChallenge dco_decode_two_factor_challenge(dynamic raw);
+
+  @protected
+  TwoFactorLoginData dco_decode_two_factor_login_data(dynamic raw);
+
+  @protected
+  TwoFactorSetup dco_decode_two_factor_setup(dynamic raw);
+
+  @protected
+  TwoFactorStatus dco_decode_two_factor_status(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  VaultHealth dco_decode_vault_health(dynamic raw);
+
+  @protected
+  WeakPassword dco_decode_weak_password(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  String sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
@@ -135,10 +386,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  ChangePasswordData sse_decode_box_autoadd_change_password_data(
+      SseDeserializer deserializer);
+
   @protected
   FinancialCard sse_decode_box_autoadd_financial_card(
       SseDeserializer deserializer);
 
+  @protected
+  GeneratorOptions sse_decode_box_autoadd_generator_options(
+      SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
@@ -146,6 +405,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   IdentityCard sse_decode_box_autoadd_identity_card(
       SseDeserializer deserializer);
 
+  @protected
+  JwtTokens sse_decode_box_autoadd_jwt_tokens(SseDeserializer deserializer);
+
+  @protected
+  KdfSettings sse_decode_box_autoadd_kdf_settings(SseDeserializer deserializer);
+
   @protected
   Login sse_decode_box_autoadd_login(SseDeserializer deserializer);
 
@@ -155,16 +420,101 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Note sse_decode_box_autoadd_note(SseDeserializer deserializer);
 
+  @protected
+  Passkey sse_decode_box_autoadd_passkey(SseDeserializer deserializer);
+
+  @protected
+  PasskeyAssertionRequest sse_decode_box_autoadd_passkey_assertion_request(
+      SseDeserializer deserializer);
+
+  @protected
+  PasskeyCreation sse_decode_box_autoadd_passkey_creation(
+      SseDeserializer deserializer);
+
+  @protected
+  PassphraseOptions sse_decode_box_autoadd_passphrase_options(
+      SseDeserializer deserializer);
+
+  @protected
+  PasswordOptions sse_decode_box_autoadd_password_options(
+      SseDeserializer deserializer);
+
+  @protected
+  PasswordPolicy sse_decode_box_autoadd_password_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  PronounceableOptions sse_decode_box_autoadd_pronounceable_options(
+      SseDeserializer deserializer);
+
+  @protected
+  QrOptions sse_decode_box_autoadd_qr_options(SseDeserializer deserializer);
+
+  @protected
+  RecoverAccountData sse_decode_box_autoadd_recover_account_data(
+      SseDeserializer deserializer);
+
+  @protected
+  RefreshTokenData sse_decode_box_autoadd_refresh_token_data(
+      SseDeserializer deserializer);
+
   @protected
   RegisterData sse_decode_box_autoadd_register_data(
       SseDeserializer deserializer);
 
+  @protected
+  RekeyProgress sse_decode_box_autoadd_rekey_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  ShareSettings sse_decode_box_autoadd_share_settings(
+      SseDeserializer deserializer);
+
   @protected
   Tag sse_decode_box_autoadd_tag(SseDeserializer deserializer);
 
+  @protected
+  TwoFactorChallenge sse_decode_box_autoadd_two_factor_challenge(
+      SseDeserializer deserializer);
+
+  @protected
+  TwoFactorLoginData sse_decode_box_autoadd_two_factor_login_data(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BreachReport sse_decode_breach_report(SseDeserializer deserializer);
+
+  @protected
+  BreachedLogin sse_decode_breached_login(SseDeserializer deserializer);
+
+  @protected
+  Capitalization sse_decode_capitalization(SseDeserializer deserializer);
+
+  @protected
+  ChangePasswordData sse_decode_change_password_data(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
   @protected
   FinancialCard sse_decode_financial_card(SseDeserializer deserializer);
 
+  @protected
+  GeneratedPassword sse_decode_generated_password(SseDeserializer deserializer);
+
+  @protected
+  GeneratorOptions sse_decode_generator_options(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
@@ -174,6 +524,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   JwtTokens sse_decode_jwt_tokens(SseDeserializer deserializer);
 
+  @protected
+  KdfSettings sse_decode_kdf_settings(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer);
+
+  @protected
+  List<BreachedLogin> sse_decode_list_breached_login(
+      SseDeserializer deserializer);
+
   @protected
   List<FinancialCard> sse_decode_list_financial_card(
       SseDeserializer deserializer);
@@ -182,27 +549,55 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<IdentityCard> sse_decode_list_identity_card(
       SseDeserializer deserializer);
 
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
   @protected
   List<Login> sse_decode_list_login(SseDeserializer deserializer);
 
   @protected
   List<Note> sse_decode_list_note(SseDeserializer deserializer);
 
+  @protected
+  List<Passkey> sse_decode_list_passkey(SseDeserializer deserializer);
+
+  @protected
+  List<PasswordHistory> sse_decode_list_password_history(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PasswordRule> sse_decode_list_password_rule(
+      SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
   List<Tag> sse_decode_list_tag(SseDeserializer deserializer);
 
+  @protected
+  List<WeakPassword> sse_decode_list_weak_password(
+      SseDeserializer deserializer);
+
   @protected
   Login sse_decode_login(SseDeserializer deserializer);
 
   @protected
   LoginData sse_decode_login_data(SseDeserializer deserializer);
 
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer);
+
   @protected
   Note sse_decode_note(SseDeserializer deserializer);
 
+  @protected
+  String? sse_decode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -212,15 +607,120 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  RekeyProgress? sse_decode_opt_box_autoadd_rekey_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  OtpCode sse_decode_otp_code(SseDeserializer deserializer);
+
+  @protected
+  Passkey sse_decode_passkey(SseDeserializer deserializer);
+
+  @protected
+  PasskeyAssertion sse_decode_passkey_assertion(SseDeserializer deserializer);
+
+  @protected
+  PasskeyAssertionRequest sse_decode_passkey_assertion_request(
+      SseDeserializer deserializer);
+
+  @protected
+  PasskeyCreation sse_decode_passkey_creation(SseDeserializer deserializer);
+
+  @protected
+  PasskeyRegistration sse_decode_passkey_registration(
+      SseDeserializer deserializer);
+
+  @protected
+  PassphraseOptions sse_decode_passphrase_options(SseDeserializer deserializer);
+
+  @protected
+  PasswordFeedback sse_decode_password_feedback(SseDeserializer deserializer);
+
+  @protected
+  PasswordHistory sse_decode_password_history(SseDeserializer deserializer);
+
+  @protected
+  PasswordOptions sse_decode_password_options(SseDeserializer deserializer);
+
+  @protected
+  PasswordPolicy sse_decode_password_policy(SseDeserializer deserializer);
+
+  @protected
+  PasswordRule sse_decode_password_rule(SseDeserializer deserializer);
+
+  @protected
+  PasswordStrength sse_decode_password_strength(SseDeserializer deserializer);
+
+  @protected
+  PronounceableOptions sse_decode_pronounceable_options(
+      SseDeserializer deserializer);
+
+  @protected
+  QrErrorCorrection sse_decode_qr_error_correction(
+      SseDeserializer deserializer);
+
+  @protected
+  QrMatrix sse_decode_qr_matrix(SseDeserializer deserializer);
+
+  @protected
+  QrOptions sse_decode_qr_options(SseDeserializer deserializer);
+
   @protected
   (bool, String) sse_decode_record_bool_string(SseDeserializer deserializer);
 
+  @protected
+  RecoverAccountData sse_decode_recover_account_data(
+      SseDeserializer deserializer);
+
+  @protected
+  RefreshTokenData sse_decode_refresh_token_data(SseDeserializer deserializer);
+
   @protected
   RegisterData sse_decode_register_data(SseDeserializer deserializer);
 
+  @protected
+  RekeyProgress sse_decode_rekey_progress(SseDeserializer deserializer);
+
+  @protected
+  RekeyResult sse_decode_rekey_result(SseDeserializer deserializer);
+
+  @protected
+  ShareSettings sse_decode_share_settings(SseDeserializer deserializer);
+
   @protected
   Tag sse_decode_tag(SseDeserializer deserializer);
 
+  @protected
+  TwoFactorBackupCodes sse_decode_two_factor_backup_codes(
+      SseDeserializer deserializer);
+
+  @protected
+  TwoFactorChallenge sse_decode_two_factor_challenge(
+      SseDeserializer deserializer);
+
+  @protected
+  TwoFactorLoginData sse_decode_two_factor_login_data(
+      SseDeserializer deserializer);
+
+  @protected
+  TwoFactorSetup sse_decode_two_factor_setup(SseDeserializer deserializer);
+
+  @protected
+  TwoFactorStatus sse_decode_two_factor_status(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -228,12 +728,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  VaultHealth sse_decode_vault_health(SseDeserializer deserializer);
+
+  @protected
+  WeakPassword sse_decode_weak_password(SseDeserializer deserializer);
 
   @protected
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      String self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -243,10 +750,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_change_password_data(
+      ChangePasswordData self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_financial_card(
       FinancialCard self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_generator_options(
+      GeneratorOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_64(
       PlatformInt64 self, SseSerializer serializer);
@@ -255,6 +770,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_identity_card(
       IdentityCard self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_jwt_tokens(
+      JwtTokens self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_kdf_settings(
+      KdfSettings self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_login(Login self, SseSerializer serializer);
 
@@ -265,16 +788,104 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_note(Note self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_passkey(Passkey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_passkey_assertion_request(
+      PasskeyAssertionRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_passkey_creation(
+      PasskeyCreation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_passphrase_options(
+      PassphraseOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_password_options(
+      PasswordOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_password_policy(
+      PasswordPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_pronounceable_options(
+      PronounceableOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_qr_options(
+      QrOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_recover_account_data(
+      RecoverAccountData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_refresh_token_data(
+      RefreshTokenData self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_register_data(
       RegisterData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_rekey_progress(
+      RekeyProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_share_settings(
+      ShareSettings self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_tag(Tag self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_two_factor_challenge(
+      TwoFactorChallenge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_two_factor_login_data(
+      TwoFactorLoginData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_breach_report(BreachReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_breached_login(BreachedLogin self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_capitalization(Capitalization self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_change_password_data(
+      ChangePasswordData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_financial_card(FinancialCard self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_generated_password(
+      GeneratedPassword self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generator_options(
+      GeneratorOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
@@ -284,6 +895,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_jwt_tokens(JwtTokens self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_kdf_settings(KdfSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_breached_login(
+      List<BreachedLogin> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_financial_card(
       List<FinancialCard> self, SseSerializer serializer);
@@ -292,12 +920,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_identity_card(
       List<IdentityCard> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_list_String(
+      List<List<String>> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_login(List<Login> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_note(List<Note> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_passkey(List<Passkey> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_password_history(
+      List<PasswordHistory> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_password_rule(
+      List<PasswordRule> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+      Int64List self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
@@ -305,15 +952,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_tag(List<Tag> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_weak_password(
+      List<WeakPassword> self, SseSerializer serializer);
+
   @protected
   void sse_encode_login(Login self, SseSerializer serializer);
 
   @protected
   void sse_encode_login_data(LoginData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_note(Note self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecretString(
+      String? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -324,16 +982,132 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_i_64(
       PlatformInt64? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_rekey_progress(
+      RekeyProgress? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_otp_code(OtpCode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passkey(Passkey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passkey_assertion(
+      PasskeyAssertion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passkey_assertion_request(
+      PasskeyAssertionRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passkey_creation(
+      PasskeyCreation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passkey_registration(
+      PasskeyRegistration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_passphrase_options(
+      PassphraseOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_feedback(
+      PasswordFeedback self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_history(
+      PasswordHistory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_options(
+      PasswordOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_policy(
+      PasswordPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_rule(PasswordRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_strength(
+      PasswordStrength self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pronounceable_options(
+      PronounceableOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_qr_error_correction(
+      QrErrorCorrection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_qr_matrix(QrMatrix self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_qr_options(QrOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_bool_string(
       (bool, String) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_recover_account_data(
+      RecoverAccountData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_refresh_token_data(
+      RefreshTokenData self, SseSerializer serializer);
+
   @protected
   void sse_encode_register_data(RegisterData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_rekey_progress(RekeyProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rekey_result(RekeyResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_share_settings(ShareSettings self, SseSerializer serializer);
+
   @protected
   void sse_encode_tag(Tag self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_two_factor_backup_codes(
+      TwoFactorBackupCodes self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_two_factor_challenge(
+      TwoFactorChallenge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_two_factor_login_data(
+      TwoFactorLoginData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_two_factor_setup(
+      TwoFactorSetup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_two_factor_status(
+      TwoFactorStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -341,7 +1115,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_vault_health(VaultHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_weak_password(WeakPassword self, SseSerializer serializer);
 }
 
 // Section: wire_class
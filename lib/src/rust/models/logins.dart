@@ -31,6 +31,10 @@ class Login with _$Login {
     bool? isFavorite,
     String? tags,
     String? apiKeys,
+    PlatformInt64? breachCount,
+    PlatformInt64? breachCheckedAt,
+    PlatformInt64? historyLimit,
+    String? otpauth,
   }) = _Login;
 
   factory Login.fromJson(Map<String, Object?> json) => _$LoginFromJson(json);
@@ -34,6 +34,10 @@ mixin _$Login {
   bool? get isFavorite => throw _privateConstructorUsedError;
   String? get tags => throw _privateConstructorUsedError;
   String? get apiKeys => throw _privateConstructorUsedError;
+  int? get breachCount => throw _privateConstructorUsedError;
+  int? get breachCheckedAt => throw _privateConstructorUsedError;
+  int? get historyLimit => throw _privateConstructorUsedError;
+  String? get otpauth => throw _privateConstructorUsedError;
 
   /// Serializes this Login to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
@@ -63,7 +67,11 @@ abstract class $LoginCopyWith<$Res> {
       String? passwordHint,
       bool? isFavorite,
       String? tags,
-      String? apiKeys});
+      String? apiKeys,
+      int? breachCount,
+      int? breachCheckedAt,
+      int? historyLimit,
+      String? otpauth});
 }
 
 /// @nodoc
@@ -95,6 +103,10 @@ class _$LoginCopyWithImpl<$Res, $Val extends Login>
     Object? isFavorite = freezed,
     Object? tags = freezed,
     Object? apiKeys = freezed,
+    Object? breachCount = freezed,
+    Object? breachCheckedAt = freezed,
+    Object? historyLimit = freezed,
+    Object? otpauth = freezed,
   }) {
     return _then(_value.copyWith(
       id: freezed == id
@@ -153,6 +165,22 @@ class _$LoginCopyWithImpl<$Res, $Val extends Login>
           ? _value.apiKeys
           : apiKeys // ignore: cast_nullable_to_non_nullable
               as String?,
+      breachCount: freezed == breachCount
+          ? _value.breachCount
+          : breachCount // ignore: cast_nullable_to_non_nullable
+              as int?,
+      breachCheckedAt: freezed == breachCheckedAt
+          ? _value.breachCheckedAt
+          : breachCheckedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      historyLimit: freezed == historyLimit
+          ? _value.historyLimit
+          : historyLimit // ignore: cast_nullable_to_non_nullable
+              as int?,
+      otpauth: freezed == otpauth
+          ? _value.otpauth
+          : otpauth // ignore: cast_nullable_to_non_nullable
+              as String?,
     ) as $Val);
   }
 }
@@ -178,7 +206,11 @@ abstract class _$$LoginImplCopyWith<$Res> implements $LoginCopyWith<$Res> {
       String? passwordHint,
       bool? isFavorite,
       String? tags,
-      String? apiKeys});
+      String? apiKeys,
+      int? breachCount,
+      int? breachCheckedAt,
+      int? historyLimit,
+      String? otpauth});
 }
 
 /// @nodoc
@@ -208,6 +240,10 @@ class __$$LoginImplCopyWithImpl<$Res>
     Object? isFavorite = freezed,
     Object? tags = freezed,
     Object? apiKeys = freezed,
+    Object? breachCount = freezed,
+    Object? breachCheckedAt = freezed,
+    Object? historyLimit = freezed,
+    Object? otpauth = freezed,
   }) {
     return _then(_$LoginImpl(
       id: freezed == id
@@ -266,6 +302,22 @@ class __$$LoginImplCopyWithImpl<$Res>
           ? _value.apiKeys
           : apiKeys // ignore: cast_nullable_to_non_nullable
               as String?,
+      breachCount: freezed == breachCount
+          ? _value.breachCount
+          : breachCount // ignore: cast_nullable_to_non_nullable
+              as int?,
+      breachCheckedAt: freezed == breachCheckedAt
+          ? _value.breachCheckedAt
+          : breachCheckedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      historyLimit: freezed == historyLimit
+          ? _value.historyLimit
+          : historyLimit // ignore: cast_nullable_to_non_nullable
+              as int?,
+      otpauth: freezed == otpauth
+          ? _value.otpauth
+          : otpauth // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
@@ -287,7 +339,11 @@ class _$LoginImpl implements _Login {
       this.passwordHint,
       this.isFavorite,
       this.tags,
-      this.apiKeys});
+      this.apiKeys,
+      this.breachCount,
+      this.breachCheckedAt,
+      this.historyLimit,
+      this.otpauth});
 
   factory _$LoginImpl.fromJson(Map<String, dynamic> json) =>
       _$$LoginImplFromJson(json);
@@ -320,10 +376,18 @@ class _$LoginImpl implements _Login {
   final String? tags;
   @override
   final String? apiKeys;
+  @override
+  final int? breachCount;
+  @override
+  final int? breachCheckedAt;
+  @override
+  final int? historyLimit;
+  @override
+  final String? otpauth;
 
   @override
   String toString() {
-    return 'Login(id: $id, createdAt: $createdAt, createdBy: $createdBy, updatedAt: $updatedAt, updatedBy: $updatedBy, name: $name, note: $note, username: $username, url: $url, password: $password, passwordHint: $passwordHint, isFavorite: $isFavorite, tags: $tags, apiKeys: $apiKeys)';
+    return 'Login(id: $id, createdAt: $createdAt, createdBy: $createdBy, updatedAt: $updatedAt, updatedBy: $updatedBy, name: $name, note: $note, username: $username, url: $url, password: $password, passwordHint: $passwordHint, isFavorite: $isFavorite, tags: $tags, apiKeys: $apiKeys, breachCount: $breachCount, breachCheckedAt: $breachCheckedAt, historyLimit: $historyLimit, otpauth: $otpauth)';
   }
 
   @override
@@ -352,7 +416,14 @@ class _$LoginImpl implements _Login {
             (identical(other.isFavorite, isFavorite) ||
                 other.isFavorite == isFavorite) &&
             (identical(other.tags, tags) || other.tags == tags) &&
-            (identical(other.apiKeys, apiKeys) || other.apiKeys == apiKeys));
+            (identical(other.apiKeys, apiKeys) || other.apiKeys == apiKeys) &&
+            (identical(other.breachCount, breachCount) ||
+                other.breachCount == breachCount) &&
+            (identical(other.breachCheckedAt, breachCheckedAt) ||
+                other.breachCheckedAt == breachCheckedAt) &&
+            (identical(other.historyLimit, historyLimit) ||
+                other.historyLimit == historyLimit) &&
+            (identical(other.otpauth, otpauth) || other.otpauth == otpauth));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
@@ -372,7 +443,11 @@ class _$LoginImpl implements _Login {
       passwordHint,
       isFavorite,
       tags,
-      apiKeys);
+      apiKeys,
+      breachCount,
+      breachCheckedAt,
+      historyLimit,
+      otpauth);
 
   /// Create a copy of Login
   /// with the given fields replaced by the non-null parameter values.
@@ -405,7 +480,11 @@ abstract class _Login implements Login {
       final String? passwordHint,
       final bool? isFavorite,
       final String? tags,
-      final String? apiKeys}) = _$LoginImpl;
+      final String? apiKeys,
+      final int? breachCount,
+      final int? breachCheckedAt,
+      final int? historyLimit,
+      final String? otpauth}) = _$LoginImpl;
 
   factory _Login.fromJson(Map<String, dynamic> json) = _$LoginImpl.fromJson;
 
@@ -437,6 +516,14 @@ abstract class _Login implements Login {
   String? get tags;
   @override
   String? get apiKeys;
+  @override
+  int? get breachCount;
+  @override
+  int? get breachCheckedAt;
+  @override
+  int? get historyLimit;
+  @override
+  String? get otpauth;
 
   /// Create a copy of Login
   /// with the given fields replaced by the non-null parameter values.
@@ -21,6 +21,10 @@ _$LoginImpl _$$LoginImplFromJson(Map<String, dynamic> json) => _$LoginImpl(
       isFavorite: json['isFavorite'] as bool?,
       tags: json['tags'] as String?,
       apiKeys: json['apiKeys'] as String?,
+      breachCount: (json['breachCount'] as num?)?.toInt(),
+      breachCheckedAt: (json['breachCheckedAt'] as num?)?.toInt(),
+      historyLimit: (json['historyLimit'] as num?)?.toInt(),
+      otpauth: json['otpauth'] as String?,
     );
 
 Map<String, dynamic> _$$LoginImplToJson(_$LoginImpl instance) =>
@@ -39,4 +43,8 @@ Map<String, dynamic> _$$LoginImplToJson(_$LoginImpl instance) =>
       'isFavorite': instance.isFavorite,
       'tags': instance.tags,
       'apiKeys': instance.apiKeys,
+      'breachCount': instance.breachCount,
+      'breachCheckedAt': instance.breachCheckedAt,
+      'historyLimit': instance.historyLimit,
+      'otpauth': instance.otpauth,
     };
@@ -11,11 +11,21 @@ import '../../frb_generated.dart';
 part 'authentication.freezed.dart';
 part 'authentication.g.dart';
 
+@freezed
+class ChangePasswordData with _$ChangePasswordData {
+  const factory ChangePasswordData({
+    required String oldPassword,
+    required String newPassword,
+    required String rePassword,
+  }) = _ChangePasswordData;
+}
+
 @freezed
 class JwtTokens with _$JwtTokens {
   const factory JwtTokens({
     required String accessToken,
     required String refreshToken,
+    String? recoveryKey,
   }) = _JwtTokens;
 
   factory JwtTokens.fromJson(Map<String, Object?> json) =>
@@ -33,6 +43,38 @@ class LoginData with _$LoginData {
       _$LoginDataFromJson(json);
 }
 
+@freezed
+sealed class LoginResult with _$LoginResult {
+  const LoginResult._();
+
+  const factory LoginResult.tokens(
+    JwtTokens field0,
+  ) = LoginResult_Tokens;
+  /// The account has two-factor authentication on, the login completes
+  /// with a code for the challenge
+  const factory LoginResult.twoFactorRequired(
+    TwoFactorChallenge field0,
+  ) = LoginResult_TwoFactorRequired;
+}
+
+@freezed
+class RecoverAccountData with _$RecoverAccountData {
+  const factory RecoverAccountData({
+    required String email,
+    required String recoveryKey,
+    required String newPassword,
+    required String rePassword,
+    String? twoFactorCode,
+  }) = _RecoverAccountData;
+}
+
+@freezed
+class RefreshTokenData with _$RefreshTokenData {
+  const factory RefreshTokenData({
+    required String refreshToken,
+  }) = _RefreshTokenData;
+}
+
 @freezed
 class RegisterData with _$RegisterData {
   const factory RegisterData({
@@ -40,8 +82,25 @@ class RegisterData with _$RegisterData {
     required String email,
     required String password,
     required String rePassword,
+    required bool recoveryKey,
   }) = _RegisterData;
 
   factory RegisterData.fromJson(Map<String, Object?> json) =>
       _$RegisterDataFromJson(json);
 }
+
+@freezed
+class TwoFactorChallenge with _$TwoFactorChallenge {
+  const factory TwoFactorChallenge({
+    required String challengeToken,
+    required BigInt expiresIn,
+  }) = _TwoFactorChallenge;
+}
+
+@freezed
+class TwoFactorLoginData with _$TwoFactorLoginData {
+  const factory TwoFactorLoginData({
+    required String challengeToken,
+    required String code,
+  }) = _TwoFactorLoginData;
+}
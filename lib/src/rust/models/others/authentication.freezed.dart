@@ -14,6 +14,179 @@ T _$identity<T>(T value) => value;
 final _privateConstructorUsedError = UnsupportedError(
     'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
 
+/// @nodoc
+mixin _$ChangePasswordData {
+  String get oldPassword => throw _privateConstructorUsedError;
+  String get newPassword => throw _privateConstructorUsedError;
+  String get rePassword => throw _privateConstructorUsedError;
+
+  /// Create a copy of ChangePasswordData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $ChangePasswordDataCopyWith<ChangePasswordData> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ChangePasswordDataCopyWith<$Res> {
+  factory $ChangePasswordDataCopyWith(
+          ChangePasswordData value, $Res Function(ChangePasswordData) then) =
+      _$ChangePasswordDataCopyWithImpl<$Res, ChangePasswordData>;
+  @useResult
+  $Res call({String oldPassword, String newPassword, String rePassword});
+}
+
+/// @nodoc
+class _$ChangePasswordDataCopyWithImpl<$Res, $Val extends ChangePasswordData>
+    implements $ChangePasswordDataCopyWith<$Res> {
+  _$ChangePasswordDataCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ChangePasswordData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? oldPassword = null,
+    Object? newPassword = null,
+    Object? rePassword = null,
+  }) {
+    return _then(_value.copyWith(
+      oldPassword: null == oldPassword
+          ? _value.oldPassword
+          : oldPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      newPassword: null == newPassword
+          ? _value.newPassword
+          : newPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$ChangePasswordDataImplCopyWith<$Res>
+    implements $ChangePasswordDataCopyWith<$Res> {
+  factory _$$ChangePasswordDataImplCopyWith(
+          _$ChangePasswordDataImpl value,
+          $Res Function(_$ChangePasswordDataImpl) then) =
+      __$$ChangePasswordDataImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String oldPassword, String newPassword, String rePassword});
+}
+
+/// @nodoc
+class __$$ChangePasswordDataImplCopyWithImpl<$Res>
+    extends _$ChangePasswordDataCopyWithImpl<$Res, _$ChangePasswordDataImpl>
+    implements _$$ChangePasswordDataImplCopyWith<$Res> {
+  __$$ChangePasswordDataImplCopyWithImpl(
+      _$ChangePasswordDataImpl _value,
+      $Res Function(_$ChangePasswordDataImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ChangePasswordData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? oldPassword = null,
+    Object? newPassword = null,
+    Object? rePassword = null,
+  }) {
+    return _then(_$ChangePasswordDataImpl(
+      oldPassword: null == oldPassword
+          ? _value.oldPassword
+          : oldPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      newPassword: null == newPassword
+          ? _value.newPassword
+          : newPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChangePasswordDataImpl implements _ChangePasswordData {
+  const _$ChangePasswordDataImpl(
+      {required this.oldPassword,
+      required this.newPassword,
+      required this.rePassword});
+
+  @override
+  final String oldPassword;
+  @override
+  final String newPassword;
+  @override
+  final String rePassword;
+
+  @override
+  String toString() {
+    return 'ChangePasswordData(oldPassword: $oldPassword, newPassword: $newPassword, rePassword: $rePassword)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChangePasswordDataImpl &&
+            (identical(other.oldPassword, oldPassword) ||
+                other.oldPassword == oldPassword) &&
+            (identical(other.newPassword, newPassword) ||
+                other.newPassword == newPassword) &&
+            (identical(other.rePassword, rePassword) ||
+                other.rePassword == rePassword));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, oldPassword, newPassword, rePassword);
+
+  /// Create a copy of ChangePasswordData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChangePasswordDataImplCopyWith<_$ChangePasswordDataImpl> get copyWith =>
+      __$$ChangePasswordDataImplCopyWithImpl<_$ChangePasswordDataImpl>(
+          this, _$identity);
+}
+
+abstract class _ChangePasswordData implements ChangePasswordData {
+  const factory _ChangePasswordData(
+      {required final String oldPassword,
+      required final String newPassword,
+      required final String rePassword}) = _$ChangePasswordDataImpl;
+
+  @override
+  String get oldPassword;
+  @override
+  String get newPassword;
+  @override
+  String get rePassword;
+
+  /// Create a copy of ChangePasswordData
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ChangePasswordDataImplCopyWith<_$ChangePasswordDataImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 JwtTokens _$JwtTokensFromJson(Map<String, dynamic> json) {
   return _JwtTokens.fromJson(json);
 }
@@ -22,6 +195,7 @@ JwtTokens _$JwtTokensFromJson(Map<String, dynamic> json) {
 mixin _$JwtTokens {
   String get accessToken => throw _privateConstructorUsedError;
   String get refreshToken => throw _privateConstructorUsedError;
+  String? get recoveryKey => throw _privateConstructorUsedError;
 
   /// Serializes this JwtTokens to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
@@ -38,7 +212,7 @@ abstract class $JwtTokensCopyWith<$Res> {
   factory $JwtTokensCopyWith(JwtTokens value, $Res Function(JwtTokens) then) =
       _$JwtTokensCopyWithImpl<$Res, JwtTokens>;
   @useResult
-  $Res call({String accessToken, String refreshToken});
+  $Res call({String accessToken, String refreshToken, String? recoveryKey});
 }
 
 /// @nodoc
@@ -58,6 +232,7 @@ class _$JwtTokensCopyWithImpl<$Res, $Val extends JwtTokens>
   $Res call({
     Object? accessToken = null,
     Object? refreshToken = null,
+    Object? recoveryKey = freezed,
   }) {
     return _then(_value.copyWith(
       accessToken: null == accessToken
@@ -68,6 +243,10 @@ class _$JwtTokensCopyWithImpl<$Res, $Val extends JwtTokens>
           ? _value.refreshToken
           : refreshToken // ignore: cast_nullable_to_non_nullable
               as String,
+      recoveryKey: freezed == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String?,
     ) as $Val);
   }
 }
@@ -80,7 +259,7 @@ abstract class _$$JwtTokensImplCopyWith<$Res>
       __$$JwtTokensImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String accessToken, String refreshToken});
+  $Res call({String accessToken, String refreshToken, String? recoveryKey});
 }
 
 /// @nodoc
@@ -98,6 +277,7 @@ class __$$JwtTokensImplCopyWithImpl<$Res>
   $Res call({
     Object? accessToken = null,
     Object? refreshToken = null,
+    Object? recoveryKey = freezed,
   }) {
     return _then(_$JwtTokensImpl(
       accessToken: null == accessToken
@@ -108,6 +288,10 @@ class __$$JwtTokensImplCopyWithImpl<$Res>
           ? _value.refreshToken
           : refreshToken // ignore: cast_nullable_to_non_nullable
               as String,
+      recoveryKey: freezed == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
@@ -116,7 +300,9 @@ class __$$JwtTokensImplCopyWithImpl<$Res>
 @JsonSerializable()
 class _$JwtTokensImpl implements _JwtTokens {
   const _$JwtTokensImpl(
-      {required this.accessToken, required this.refreshToken});
+      {required this.accessToken,
+      required this.refreshToken,
+      this.recoveryKey});
 
   factory _$JwtTokensImpl.fromJson(Map<String, dynamic> json) =>
       _$$JwtTokensImplFromJson(json);
@@ -125,10 +311,12 @@ class _$JwtTokensImpl implements _JwtTokens {
   final String accessToken;
   @override
   final String refreshToken;
+  @override
+  final String? recoveryKey;
 
   @override
   String toString() {
-    return 'JwtTokens(accessToken: $accessToken, refreshToken: $refreshToken)';
+    return 'JwtTokens(accessToken: $accessToken, refreshToken: $refreshToken, recoveryKey: $recoveryKey)';
   }
 
   @override
@@ -139,12 +327,15 @@ class _$JwtTokensImpl implements _JwtTokens {
             (identical(other.accessToken, accessToken) ||
                 other.accessToken == accessToken) &&
             (identical(other.refreshToken, refreshToken) ||
-                other.refreshToken == refreshToken));
+                other.refreshToken == refreshToken) &&
+            (identical(other.recoveryKey, recoveryKey) ||
+                other.recoveryKey == recoveryKey));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
-  int get hashCode => Object.hash(runtimeType, accessToken, refreshToken);
+  int get hashCode =>
+      Object.hash(runtimeType, accessToken, refreshToken, recoveryKey);
 
   /// Create a copy of JwtTokens
   /// with the given fields replaced by the non-null parameter values.
@@ -165,7 +356,8 @@ class _$JwtTokensImpl implements _JwtTokens {
 abstract class _JwtTokens implements JwtTokens {
   const factory _JwtTokens(
       {required final String accessToken,
-      required final String refreshToken}) = _$JwtTokensImpl;
+      required final String refreshToken,
+      final String? recoveryKey}) = _$JwtTokensImpl;
 
   factory _JwtTokens.fromJson(Map<String, dynamic> json) =
       _$JwtTokensImpl.fromJson;
@@ -174,6 +366,8 @@ abstract class _JwtTokens implements JwtTokens {
   String get accessToken;
   @override
   String get refreshToken;
+  @override
+  String? get recoveryKey;
 
   /// Create a copy of JwtTokens
   /// with the given fields replaced by the non-null parameter values.
@@ -350,205 +544,972 @@ abstract class _LoginData implements LoginData {
       throw _privateConstructorUsedError;
 }
 
-RegisterData _$RegisterDataFromJson(Map<String, dynamic> json) {
-  return _RegisterData.fromJson(json);
-}
-
 /// @nodoc
-mixin _$RegisterData {
-  String get name => throw _privateConstructorUsedError;
-  String get email => throw _privateConstructorUsedError;
-  String get password => throw _privateConstructorUsedError;
-  String get rePassword => throw _privateConstructorUsedError;
-
-  /// Serializes this RegisterData to a JSON map.
-  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
-
-  /// Create a copy of RegisterData
-  /// with the given fields replaced by the non-null parameter values.
-  @JsonKey(includeFromJson: false, includeToJson: false)
-  $RegisterDataCopyWith<RegisterData> get copyWith =>
+mixin _$LoginResult {
+  Object get field0 => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(JwtTokens field0) tokens,
+    required TResult Function(TwoFactorChallenge field0) twoFactorRequired,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(JwtTokens field0)? tokens,
+    TResult? Function(TwoFactorChallenge field0)? twoFactorRequired,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(JwtTokens field0)? tokens,
+    TResult Function(TwoFactorChallenge field0)? twoFactorRequired,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LoginResult_Tokens value) tokens,
+    required TResult Function(LoginResult_TwoFactorRequired value)
+        twoFactorRequired,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LoginResult_Tokens value)? tokens,
+    TResult? Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LoginResult_Tokens value)? tokens,
+    TResult Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+    required TResult orElse(),
+  }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $RegisterDataCopyWith<$Res> {
-  factory $RegisterDataCopyWith(
-          RegisterData value, $Res Function(RegisterData) then) =
-      _$RegisterDataCopyWithImpl<$Res, RegisterData>;
-  @useResult
-  $Res call({String name, String email, String password, String rePassword});
+abstract class $LoginResultCopyWith<$Res> {
+  factory $LoginResultCopyWith(
+          LoginResult value, $Res Function(LoginResult) then) =
+      _$LoginResultCopyWithImpl<$Res, LoginResult>;
 }
 
 /// @nodoc
-class _$RegisterDataCopyWithImpl<$Res, $Val extends RegisterData>
-    implements $RegisterDataCopyWith<$Res> {
-  _$RegisterDataCopyWithImpl(this._value, this._then);
+class _$LoginResultCopyWithImpl<$Res, $Val extends LoginResult>
+    implements $LoginResultCopyWith<$Res> {
+  _$LoginResultCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of RegisterData
+  /// Create a copy of LoginResult
   /// with the given fields replaced by the non-null parameter values.
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? name = null,
-    Object? email = null,
-    Object? password = null,
-    Object? rePassword = null,
-  }) {
-    return _then(_value.copyWith(
-      name: null == name
-          ? _value.name
-          : name // ignore: cast_nullable_to_non_nullable
-              as String,
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
-              as String,
-      password: null == password
-          ? _value.password
-          : password // ignore: cast_nullable_to_non_nullable
-              as String,
-      rePassword: null == rePassword
-          ? _value.rePassword
-          : rePassword // ignore: cast_nullable_to_non_nullable
-              as String,
-    ) as $Val);
-  }
 }
 
 /// @nodoc
-abstract class _$$RegisterDataImplCopyWith<$Res>
-    implements $RegisterDataCopyWith<$Res> {
-  factory _$$RegisterDataImplCopyWith(
-          _$RegisterDataImpl value, $Res Function(_$RegisterDataImpl) then) =
-      __$$RegisterDataImplCopyWithImpl<$Res>;
-  @override
+abstract class _$$LoginResult_TokensImplCopyWith<$Res> {
+  factory _$$LoginResult_TokensImplCopyWith(
+          _$LoginResult_TokensImpl value,
+          $Res Function(_$LoginResult_TokensImpl) then) =
+      __$$LoginResult_TokensImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({String name, String email, String password, String rePassword});
+  $Res call({JwtTokens field0});
+
+  $JwtTokensCopyWith<$Res> get field0;
 }
 
 /// @nodoc
-class __$$RegisterDataImplCopyWithImpl<$Res>
-    extends _$RegisterDataCopyWithImpl<$Res, _$RegisterDataImpl>
-    implements _$$RegisterDataImplCopyWith<$Res> {
-  __$$RegisterDataImplCopyWithImpl(
-      _$RegisterDataImpl _value, $Res Function(_$RegisterDataImpl) _then)
+class __$$LoginResult_TokensImplCopyWithImpl<$Res>
+    extends _$LoginResultCopyWithImpl<$Res, _$LoginResult_TokensImpl>
+    implements _$$LoginResult_TokensImplCopyWith<$Res> {
+  __$$LoginResult_TokensImplCopyWithImpl(
+      _$LoginResult_TokensImpl _value,
+      $Res Function(_$LoginResult_TokensImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of RegisterData
+  /// Create a copy of LoginResult
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? name = null,
-    Object? email = null,
-    Object? password = null,
-    Object? rePassword = null,
+    Object? field0 = null,
   }) {
-    return _then(_$RegisterDataImpl(
-      name: null == name
-          ? _value.name
-          : name // ignore: cast_nullable_to_non_nullable
-              as String,
-      email: null == email
-          ? _value.email
-          : email // ignore: cast_nullable_to_non_nullable
-              as String,
-      password: null == password
-          ? _value.password
-          : password // ignore: cast_nullable_to_non_nullable
-              as String,
-      rePassword: null == rePassword
-          ? _value.rePassword
-          : rePassword // ignore: cast_nullable_to_non_nullable
-              as String,
+    return _then(_$LoginResult_TokensImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as JwtTokens,
     ));
   }
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $JwtTokensCopyWith<$Res> get field0 {
+    return $JwtTokensCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
 }
 
 /// @nodoc
-@JsonSerializable()
-class _$RegisterDataImpl implements _RegisterData {
-  const _$RegisterDataImpl(
-      {required this.name,
-      required this.email,
-      required this.password,
-      required this.rePassword});
 
-  factory _$RegisterDataImpl.fromJson(Map<String, dynamic> json) =>
-      _$$RegisterDataImplFromJson(json);
+class _$LoginResult_TokensImpl extends LoginResult_Tokens {
+  const _$LoginResult_TokensImpl(this.field0) : super._();
 
   @override
-  final String name;
-  @override
-  final String email;
-  @override
-  final String password;
-  @override
-  final String rePassword;
+  final JwtTokens field0;
 
   @override
   String toString() {
-    return 'RegisterData(name: $name, email: $email, password: $password, rePassword: $rePassword)';
+    return 'LoginResult.tokens(field0: $field0)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$RegisterDataImpl &&
-            (identical(other.name, name) || other.name == name) &&
-            (identical(other.email, email) || other.email == email) &&
-            (identical(other.password, password) ||
-                other.password == password) &&
-            (identical(other.rePassword, rePassword) ||
-                other.rePassword == rePassword));
+            other is _$LoginResult_TokensImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
   }
 
-  @JsonKey(includeFromJson: false, includeToJson: false)
   @override
-  int get hashCode =>
-      Object.hash(runtimeType, name, email, password, rePassword);
+  int get hashCode => Object.hash(runtimeType, field0);
 
-  /// Create a copy of RegisterData
+  /// Create a copy of LoginResult
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$RegisterDataImplCopyWith<_$RegisterDataImpl> get copyWith =>
-      __$$RegisterDataImplCopyWithImpl<_$RegisterDataImpl>(this, _$identity);
+  _$$LoginResult_TokensImplCopyWith<_$LoginResult_TokensImpl> get copyWith =>
+      __$$LoginResult_TokensImplCopyWithImpl<_$LoginResult_TokensImpl>(
+          this, _$identity);
 
   @override
-  Map<String, dynamic> toJson() {
-    return _$$RegisterDataImplToJson(
-      this,
-    );
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(JwtTokens field0) tokens,
+    required TResult Function(TwoFactorChallenge field0) twoFactorRequired,
+  }) {
+    return tokens(field0);
   }
-}
-
-abstract class _RegisterData implements RegisterData {
-  const factory _RegisterData(
-      {required final String name,
-      required final String email,
-      required final String password,
-      required final String rePassword}) = _$RegisterDataImpl;
 
-  factory _RegisterData.fromJson(Map<String, dynamic> json) =
-      _$RegisterDataImpl.fromJson;
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(JwtTokens field0)? tokens,
+    TResult? Function(TwoFactorChallenge field0)? twoFactorRequired,
+  }) {
+    return tokens?.call(field0);
+  }
 
   @override
-  String get name;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(JwtTokens field0)? tokens,
+    TResult Function(TwoFactorChallenge field0)? twoFactorRequired,
+    required TResult orElse(),
+  }) {
+    if (tokens != null) {
+      return tokens(field0);
+    }
+    return orElse();
+  }
+
   @override
-  String get email;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LoginResult_Tokens value) tokens,
+    required TResult Function(LoginResult_TwoFactorRequired value)
+        twoFactorRequired,
+  }) {
+    return tokens(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LoginResult_Tokens value)? tokens,
+    TResult? Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+  }) {
+    return tokens?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LoginResult_Tokens value)? tokens,
+    TResult Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+    required TResult orElse(),
+  }) {
+    if (tokens != null) {
+      return tokens(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LoginResult_Tokens extends LoginResult {
+  const factory LoginResult_Tokens(final JwtTokens field0) =
+      _$LoginResult_TokensImpl;
+  const LoginResult_Tokens._() : super._();
+
+  @override
+  JwtTokens get field0;
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LoginResult_TokensImplCopyWith<_$LoginResult_TokensImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$LoginResult_TwoFactorRequiredImplCopyWith<$Res> {
+  factory _$$LoginResult_TwoFactorRequiredImplCopyWith(
+          _$LoginResult_TwoFactorRequiredImpl value,
+          $Res Function(_$LoginResult_TwoFactorRequiredImpl) then) =
+      __$$LoginResult_TwoFactorRequiredImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({TwoFactorChallenge field0});
+
+  $TwoFactorChallengeCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$LoginResult_TwoFactorRequiredImplCopyWithImpl<$Res>
+    extends _$LoginResultCopyWithImpl<$Res, _$LoginResult_TwoFactorRequiredImpl>
+    implements _$$LoginResult_TwoFactorRequiredImplCopyWith<$Res> {
+  __$$LoginResult_TwoFactorRequiredImplCopyWithImpl(
+      _$LoginResult_TwoFactorRequiredImpl _value,
+      $Res Function(_$LoginResult_TwoFactorRequiredImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LoginResult_TwoFactorRequiredImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as TwoFactorChallenge,
+    ));
+  }
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $TwoFactorChallengeCopyWith<$Res> get field0 {
+    return $TwoFactorChallengeCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$LoginResult_TwoFactorRequiredImpl
+    extends LoginResult_TwoFactorRequired {
+  const _$LoginResult_TwoFactorRequiredImpl(this.field0) : super._();
+
+  @override
+  final TwoFactorChallenge field0;
+
+  @override
+  String toString() {
+    return 'LoginResult.twoFactorRequired(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LoginResult_TwoFactorRequiredImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LoginResult_TwoFactorRequiredImplCopyWith<
+          _$LoginResult_TwoFactorRequiredImpl>
+      get copyWith => __$$LoginResult_TwoFactorRequiredImplCopyWithImpl<
+          _$LoginResult_TwoFactorRequiredImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(JwtTokens field0) tokens,
+    required TResult Function(TwoFactorChallenge field0) twoFactorRequired,
+  }) {
+    return twoFactorRequired(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(JwtTokens field0)? tokens,
+    TResult? Function(TwoFactorChallenge field0)? twoFactorRequired,
+  }) {
+    return twoFactorRequired?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(JwtTokens field0)? tokens,
+    TResult Function(TwoFactorChallenge field0)? twoFactorRequired,
+    required TResult orElse(),
+  }) {
+    if (twoFactorRequired != null) {
+      return twoFactorRequired(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LoginResult_Tokens value) tokens,
+    required TResult Function(LoginResult_TwoFactorRequired value)
+        twoFactorRequired,
+  }) {
+    return twoFactorRequired(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LoginResult_Tokens value)? tokens,
+    TResult? Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+  }) {
+    return twoFactorRequired?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LoginResult_Tokens value)? tokens,
+    TResult Function(LoginResult_TwoFactorRequired value)? twoFactorRequired,
+    required TResult orElse(),
+  }) {
+    if (twoFactorRequired != null) {
+      return twoFactorRequired(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LoginResult_TwoFactorRequired extends LoginResult {
+  const factory LoginResult_TwoFactorRequired(final TwoFactorChallenge field0) =
+      _$LoginResult_TwoFactorRequiredImpl;
+  const LoginResult_TwoFactorRequired._() : super._();
+
+  @override
+  TwoFactorChallenge get field0;
+
+  /// Create a copy of LoginResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LoginResult_TwoFactorRequiredImplCopyWith<
+          _$LoginResult_TwoFactorRequiredImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$RecoverAccountData {
+  String get email => throw _privateConstructorUsedError;
+  String get recoveryKey => throw _privateConstructorUsedError;
+  String get newPassword => throw _privateConstructorUsedError;
+  String get rePassword => throw _privateConstructorUsedError;
+  String? get twoFactorCode => throw _privateConstructorUsedError;
+
+  /// Create a copy of RecoverAccountData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $RecoverAccountDataCopyWith<RecoverAccountData> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RecoverAccountDataCopyWith<$Res> {
+  factory $RecoverAccountDataCopyWith(
+          RecoverAccountData value, $Res Function(RecoverAccountData) then) =
+      _$RecoverAccountDataCopyWithImpl<$Res, RecoverAccountData>;
+  @useResult
+  $Res call(
+      {String email,
+      String recoveryKey,
+      String newPassword,
+      String rePassword,
+      String? twoFactorCode});
+}
+
+/// @nodoc
+class _$RecoverAccountDataCopyWithImpl<$Res, $Val extends RecoverAccountData>
+    implements $RecoverAccountDataCopyWith<$Res> {
+  _$RecoverAccountDataCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RecoverAccountData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? recoveryKey = null,
+    Object? newPassword = null,
+    Object? rePassword = null,
+    Object? twoFactorCode = freezed,
+  }) {
+    return _then(_value.copyWith(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      recoveryKey: null == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      newPassword: null == newPassword
+          ? _value.newPassword
+          : newPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      twoFactorCode: freezed == twoFactorCode
+          ? _value.twoFactorCode
+          : twoFactorCode // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$RecoverAccountDataImplCopyWith<$Res>
+    implements $RecoverAccountDataCopyWith<$Res> {
+  factory _$$RecoverAccountDataImplCopyWith(
+          _$RecoverAccountDataImpl value,
+          $Res Function(_$RecoverAccountDataImpl) then) =
+      __$$RecoverAccountDataImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String email,
+      String recoveryKey,
+      String newPassword,
+      String rePassword,
+      String? twoFactorCode});
+}
+
+/// @nodoc
+class __$$RecoverAccountDataImplCopyWithImpl<$Res>
+    extends _$RecoverAccountDataCopyWithImpl<$Res, _$RecoverAccountDataImpl>
+    implements _$$RecoverAccountDataImplCopyWith<$Res> {
+  __$$RecoverAccountDataImplCopyWithImpl(
+      _$RecoverAccountDataImpl _value,
+      $Res Function(_$RecoverAccountDataImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of RecoverAccountData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? email = null,
+    Object? recoveryKey = null,
+    Object? newPassword = null,
+    Object? rePassword = null,
+    Object? twoFactorCode = freezed,
+  }) {
+    return _then(_$RecoverAccountDataImpl(
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      recoveryKey: null == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      newPassword: null == newPassword
+          ? _value.newPassword
+          : newPassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      twoFactorCode: freezed == twoFactorCode
+          ? _value.twoFactorCode
+          : twoFactorCode // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RecoverAccountDataImpl implements _RecoverAccountData {
+  const _$RecoverAccountDataImpl(
+      {required this.email,
+      required this.recoveryKey,
+      required this.newPassword,
+      required this.rePassword,
+      this.twoFactorCode});
+
+  @override
+  final String email;
+  @override
+  final String recoveryKey;
+  @override
+  final String newPassword;
+  @override
+  final String rePassword;
+  @override
+  final String? twoFactorCode;
+
+  @override
+  String toString() {
+    return 'RecoverAccountData(email: $email, recoveryKey: $recoveryKey, newPassword: $newPassword, rePassword: $rePassword, twoFactorCode: $twoFactorCode)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RecoverAccountDataImpl &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.recoveryKey, recoveryKey) ||
+                other.recoveryKey == recoveryKey) &&
+            (identical(other.newPassword, newPassword) ||
+                other.newPassword == newPassword) &&
+            (identical(other.rePassword, rePassword) ||
+                other.rePassword == rePassword) &&
+            (identical(other.twoFactorCode, twoFactorCode) ||
+                other.twoFactorCode == twoFactorCode));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, email, recoveryKey, newPassword, rePassword, twoFactorCode);
+
+  /// Create a copy of RecoverAccountData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RecoverAccountDataImplCopyWith<_$RecoverAccountDataImpl> get copyWith =>
+      __$$RecoverAccountDataImplCopyWithImpl<_$RecoverAccountDataImpl>(
+          this, _$identity);
+}
+
+abstract class _RecoverAccountData implements RecoverAccountData {
+  const factory _RecoverAccountData(
+      {required final String email,
+      required final String recoveryKey,
+      required final String newPassword,
+      required final String rePassword,
+      final String? twoFactorCode}) = _$RecoverAccountDataImpl;
+
+  @override
+  String get email;
+  @override
+  String get recoveryKey;
+  @override
+  String get newPassword;
+  @override
+  String get rePassword;
+  @override
+  String? get twoFactorCode;
+
+  /// Create a copy of RecoverAccountData
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RecoverAccountDataImplCopyWith<_$RecoverAccountDataImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$RefreshTokenData {
+  String get refreshToken => throw _privateConstructorUsedError;
+
+  /// Create a copy of RefreshTokenData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $RefreshTokenDataCopyWith<RefreshTokenData> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RefreshTokenDataCopyWith<$Res> {
+  factory $RefreshTokenDataCopyWith(
+          RefreshTokenData value, $Res Function(RefreshTokenData) then) =
+      _$RefreshTokenDataCopyWithImpl<$Res, RefreshTokenData>;
+  @useResult
+  $Res call({String refreshToken});
+}
+
+/// @nodoc
+class _$RefreshTokenDataCopyWithImpl<$Res, $Val extends RefreshTokenData>
+    implements $RefreshTokenDataCopyWith<$Res> {
+  _$RefreshTokenDataCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RefreshTokenData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? refreshToken = null,
+  }) {
+    return _then(_value.copyWith(
+      refreshToken: null == refreshToken
+          ? _value.refreshToken
+          : refreshToken // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$RefreshTokenDataImplCopyWith<$Res>
+    implements $RefreshTokenDataCopyWith<$Res> {
+  factory _$$RefreshTokenDataImplCopyWith(
+          _$RefreshTokenDataImpl value,
+          $Res Function(_$RefreshTokenDataImpl) then) =
+      __$$RefreshTokenDataImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String refreshToken});
+}
+
+/// @nodoc
+class __$$RefreshTokenDataImplCopyWithImpl<$Res>
+    extends _$RefreshTokenDataCopyWithImpl<$Res, _$RefreshTokenDataImpl>
+    implements _$$RefreshTokenDataImplCopyWith<$Res> {
+  __$$RefreshTokenDataImplCopyWithImpl(
+      _$RefreshTokenDataImpl _value,
+      $Res Function(_$RefreshTokenDataImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of RefreshTokenData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? refreshToken = null,
+  }) {
+    return _then(_$RefreshTokenDataImpl(
+      refreshToken: null == refreshToken
+          ? _value.refreshToken
+          : refreshToken // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RefreshTokenDataImpl implements _RefreshTokenData {
+  const _$RefreshTokenDataImpl({required this.refreshToken});
+
+  @override
+  final String refreshToken;
+
+  @override
+  String toString() {
+    return 'RefreshTokenData(refreshToken: $refreshToken)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RefreshTokenDataImpl &&
+            (identical(other.refreshToken, refreshToken) ||
+                other.refreshToken == refreshToken));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, refreshToken);
+
+  /// Create a copy of RefreshTokenData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RefreshTokenDataImplCopyWith<_$RefreshTokenDataImpl> get copyWith =>
+      __$$RefreshTokenDataImplCopyWithImpl<_$RefreshTokenDataImpl>(
+          this, _$identity);
+}
+
+abstract class _RefreshTokenData implements RefreshTokenData {
+  const factory _RefreshTokenData({required final String refreshToken}) =
+      _$RefreshTokenDataImpl;
+
+  @override
+  String get refreshToken;
+
+  /// Create a copy of RefreshTokenData
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RefreshTokenDataImplCopyWith<_$RefreshTokenDataImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+RegisterData _$RegisterDataFromJson(Map<String, dynamic> json) {
+  return _RegisterData.fromJson(json);
+}
+
+/// @nodoc
+mixin _$RegisterData {
+  String get name => throw _privateConstructorUsedError;
+  String get email => throw _privateConstructorUsedError;
+  String get password => throw _privateConstructorUsedError;
+  String get rePassword => throw _privateConstructorUsedError;
+  bool get recoveryKey => throw _privateConstructorUsedError;
+
+  /// Serializes this RegisterData to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of RegisterData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $RegisterDataCopyWith<RegisterData> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RegisterDataCopyWith<$Res> {
+  factory $RegisterDataCopyWith(
+          RegisterData value, $Res Function(RegisterData) then) =
+      _$RegisterDataCopyWithImpl<$Res, RegisterData>;
+  @useResult
+  $Res call(
+      {String name,
+      String email,
+      String password,
+      String rePassword,
+      bool recoveryKey});
+}
+
+/// @nodoc
+class _$RegisterDataCopyWithImpl<$Res, $Val extends RegisterData>
+    implements $RegisterDataCopyWith<$Res> {
+  _$RegisterDataCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RegisterData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? email = null,
+    Object? password = null,
+    Object? rePassword = null,
+    Object? recoveryKey = null,
+  }) {
+    return _then(_value.copyWith(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      recoveryKey: null == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$RegisterDataImplCopyWith<$Res>
+    implements $RegisterDataCopyWith<$Res> {
+  factory _$$RegisterDataImplCopyWith(
+          _$RegisterDataImpl value, $Res Function(_$RegisterDataImpl) then) =
+      __$$RegisterDataImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String name,
+      String email,
+      String password,
+      String rePassword,
+      bool recoveryKey});
+}
+
+/// @nodoc
+class __$$RegisterDataImplCopyWithImpl<$Res>
+    extends _$RegisterDataCopyWithImpl<$Res, _$RegisterDataImpl>
+    implements _$$RegisterDataImplCopyWith<$Res> {
+  __$$RegisterDataImplCopyWithImpl(
+      _$RegisterDataImpl _value, $Res Function(_$RegisterDataImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of RegisterData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? email = null,
+    Object? password = null,
+    Object? rePassword = null,
+    Object? recoveryKey = null,
+  }) {
+    return _then(_$RegisterDataImpl(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      email: null == email
+          ? _value.email
+          : email // ignore: cast_nullable_to_non_nullable
+              as String,
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+      rePassword: null == rePassword
+          ? _value.rePassword
+          : rePassword // ignore: cast_nullable_to_non_nullable
+              as String,
+      recoveryKey: null == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$RegisterDataImpl implements _RegisterData {
+  const _$RegisterDataImpl(
+      {required this.name,
+      required this.email,
+      required this.password,
+      required this.rePassword,
+      required this.recoveryKey});
+
+  factory _$RegisterDataImpl.fromJson(Map<String, dynamic> json) =>
+      _$$RegisterDataImplFromJson(json);
+
+  @override
+  final String name;
+  @override
+  final String email;
+  @override
+  final String password;
+  @override
+  final String rePassword;
+  @override
+  final bool recoveryKey;
+
+  @override
+  String toString() {
+    return 'RegisterData(name: $name, email: $email, password: $password, rePassword: $rePassword, recoveryKey: $recoveryKey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RegisterDataImpl &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.email, email) || other.email == email) &&
+            (identical(other.password, password) ||
+                other.password == password) &&
+            (identical(other.rePassword, rePassword) ||
+                other.rePassword == rePassword) &&
+            (identical(other.recoveryKey, recoveryKey) ||
+                other.recoveryKey == recoveryKey));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, name, email, password, rePassword, recoveryKey);
+
+  /// Create a copy of RegisterData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RegisterDataImplCopyWith<_$RegisterDataImpl> get copyWith =>
+      __$$RegisterDataImplCopyWithImpl<_$RegisterDataImpl>(this, _$identity);
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$RegisterDataImplToJson(
+      this,
+    );
+  }
+}
+
+abstract class _RegisterData implements RegisterData {
+  const factory _RegisterData(
+      {required final String name,
+      required final String email,
+      required final String password,
+      required final String rePassword,
+      required final bool recoveryKey}) = _$RegisterDataImpl;
+
+  factory _RegisterData.fromJson(Map<String, dynamic> json) =
+      _$RegisterDataImpl.fromJson;
+
+  @override
+  String get name;
+  @override
+  String get email;
   @override
   String get password;
   @override
   String get rePassword;
+  @override
+  bool get recoveryKey;
 
   /// Create a copy of RegisterData
   /// with the given fields replaced by the non-null parameter values.
@@ -557,3 +1518,306 @@ abstract class _RegisterData implements RegisterData {
   _$$RegisterDataImplCopyWith<_$RegisterDataImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
+
+/// @nodoc
+mixin _$TwoFactorChallenge {
+  String get challengeToken => throw _privateConstructorUsedError;
+  BigInt get expiresIn => throw _privateConstructorUsedError;
+
+  /// Create a copy of TwoFactorChallenge
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TwoFactorChallengeCopyWith<TwoFactorChallenge> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TwoFactorChallengeCopyWith<$Res> {
+  factory $TwoFactorChallengeCopyWith(
+          TwoFactorChallenge value, $Res Function(TwoFactorChallenge) then) =
+      _$TwoFactorChallengeCopyWithImpl<$Res, TwoFactorChallenge>;
+  @useResult
+  $Res call({String challengeToken, BigInt expiresIn});
+}
+
+/// @nodoc
+class _$TwoFactorChallengeCopyWithImpl<$Res, $Val extends TwoFactorChallenge>
+    implements $TwoFactorChallengeCopyWith<$Res> {
+  _$TwoFactorChallengeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TwoFactorChallenge
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? challengeToken = null,
+    Object? expiresIn = null,
+  }) {
+    return _then(_value.copyWith(
+      challengeToken: null == challengeToken
+          ? _value.challengeToken
+          : challengeToken // ignore: cast_nullable_to_non_nullable
+              as String,
+      expiresIn: null == expiresIn
+          ? _value.expiresIn
+          : expiresIn // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TwoFactorChallengeImplCopyWith<$Res>
+    implements $TwoFactorChallengeCopyWith<$Res> {
+  factory _$$TwoFactorChallengeImplCopyWith(
+          _$TwoFactorChallengeImpl value,
+          $Res Function(_$TwoFactorChallengeImpl) then) =
+      __$$TwoFactorChallengeImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String challengeToken, BigInt expiresIn});
+}
+
+/// @nodoc
+class __$$TwoFactorChallengeImplCopyWithImpl<$Res>
+    extends _$TwoFactorChallengeCopyWithImpl<$Res, _$TwoFactorChallengeImpl>
+    implements _$$TwoFactorChallengeImplCopyWith<$Res> {
+  __$$TwoFactorChallengeImplCopyWithImpl(
+      _$TwoFactorChallengeImpl _value,
+      $Res Function(_$TwoFactorChallengeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TwoFactorChallenge
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? challengeToken = null,
+    Object? expiresIn = null,
+  }) {
+    return _then(_$TwoFactorChallengeImpl(
+      challengeToken: null == challengeToken
+          ? _value.challengeToken
+          : challengeToken // ignore: cast_nullable_to_non_nullable
+              as String,
+      expiresIn: null == expiresIn
+          ? _value.expiresIn
+          : expiresIn // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TwoFactorChallengeImpl implements _TwoFactorChallenge {
+  const _$TwoFactorChallengeImpl(
+      {required this.challengeToken, required this.expiresIn});
+
+  @override
+  final String challengeToken;
+  @override
+  final BigInt expiresIn;
+
+  @override
+  String toString() {
+    return 'TwoFactorChallenge(challengeToken: $challengeToken, expiresIn: $expiresIn)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TwoFactorChallengeImpl &&
+            (identical(other.challengeToken, challengeToken) ||
+                other.challengeToken == challengeToken) &&
+            (identical(other.expiresIn, expiresIn) ||
+                other.expiresIn == expiresIn));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, challengeToken, expiresIn);
+
+  /// Create a copy of TwoFactorChallenge
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TwoFactorChallengeImplCopyWith<_$TwoFactorChallengeImpl> get copyWith =>
+      __$$TwoFactorChallengeImplCopyWithImpl<_$TwoFactorChallengeImpl>(
+          this, _$identity);
+}
+
+abstract class _TwoFactorChallenge implements TwoFactorChallenge {
+  const factory _TwoFactorChallenge(
+      {required final String challengeToken,
+      required final BigInt expiresIn}) = _$TwoFactorChallengeImpl;
+
+  @override
+  String get challengeToken;
+  @override
+  BigInt get expiresIn;
+
+  /// Create a copy of TwoFactorChallenge
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TwoFactorChallengeImplCopyWith<_$TwoFactorChallengeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$TwoFactorLoginData {
+  String get challengeToken => throw _privateConstructorUsedError;
+  String get code => throw _privateConstructorUsedError;
+
+  /// Create a copy of TwoFactorLoginData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TwoFactorLoginDataCopyWith<TwoFactorLoginData> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TwoFactorLoginDataCopyWith<$Res> {
+  factory $TwoFactorLoginDataCopyWith(
+          TwoFactorLoginData value, $Res Function(TwoFactorLoginData) then) =
+      _$TwoFactorLoginDataCopyWithImpl<$Res, TwoFactorLoginData>;
+  @useResult
+  $Res call({String challengeToken, String code});
+}
+
+/// @nodoc
+class _$TwoFactorLoginDataCopyWithImpl<$Res, $Val extends TwoFactorLoginData>
+    implements $TwoFactorLoginDataCopyWith<$Res> {
+  _$TwoFactorLoginDataCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TwoFactorLoginData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? challengeToken = null,
+    Object? code = null,
+  }) {
+    return _then(_value.copyWith(
+      challengeToken: null == challengeToken
+          ? _value.challengeToken
+          : challengeToken // ignore: cast_nullable_to_non_nullable
+              as String,
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TwoFactorLoginDataImplCopyWith<$Res>
+    implements $TwoFactorLoginDataCopyWith<$Res> {
+  factory _$$TwoFactorLoginDataImplCopyWith(
+          _$TwoFactorLoginDataImpl value,
+          $Res Function(_$TwoFactorLoginDataImpl) then) =
+      __$$TwoFactorLoginDataImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String challengeToken, String code});
+}
+
+/// @nodoc
+class __$$TwoFactorLoginDataImplCopyWithImpl<$Res>
+    extends _$TwoFactorLoginDataCopyWithImpl<$Res, _$TwoFactorLoginDataImpl>
+    implements _$$TwoFactorLoginDataImplCopyWith<$Res> {
+  __$$TwoFactorLoginDataImplCopyWithImpl(
+      _$TwoFactorLoginDataImpl _value,
+      $Res Function(_$TwoFactorLoginDataImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TwoFactorLoginData
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? challengeToken = null,
+    Object? code = null,
+  }) {
+    return _then(_$TwoFactorLoginDataImpl(
+      challengeToken: null == challengeToken
+          ? _value.challengeToken
+          : challengeToken // ignore: cast_nullable_to_non_nullable
+              as String,
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TwoFactorLoginDataImpl implements _TwoFactorLoginData {
+  const _$TwoFactorLoginDataImpl(
+      {required this.challengeToken, required this.code});
+
+  @override
+  final String challengeToken;
+  @override
+  final String code;
+
+  @override
+  String toString() {
+    return 'TwoFactorLoginData(challengeToken: $challengeToken, code: $code)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TwoFactorLoginDataImpl &&
+            (identical(other.challengeToken, challengeToken) ||
+                other.challengeToken == challengeToken) &&
+            (identical(other.code, code) || other.code == code));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, challengeToken, code);
+
+  /// Create a copy of TwoFactorLoginData
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TwoFactorLoginDataImplCopyWith<_$TwoFactorLoginDataImpl> get copyWith =>
+      __$$TwoFactorLoginDataImplCopyWithImpl<_$TwoFactorLoginDataImpl>(
+          this, _$identity);
+}
+
+abstract class _TwoFactorLoginData implements TwoFactorLoginData {
+  const factory _TwoFactorLoginData(
+      {required final String challengeToken,
+      required final String code}) = _$TwoFactorLoginDataImpl;
+
+  @override
+  String get challengeToken;
+  @override
+  String get code;
+
+  /// Create a copy of TwoFactorLoginData
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TwoFactorLoginDataImplCopyWith<_$TwoFactorLoginDataImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -10,12 +10,14 @@ _$JwtTokensImpl _$$JwtTokensImplFromJson(Map<String, dynamic> json) =>
     _$JwtTokensImpl(
       accessToken: json['accessToken'] as String,
       refreshToken: json['refreshToken'] as String,
+      recoveryKey: json['recoveryKey'] as String?,
     );
 
 Map<String, dynamic> _$$JwtTokensImplToJson(_$JwtTokensImpl instance) =>
     <String, dynamic>{
       'accessToken': instance.accessToken,
       'refreshToken': instance.refreshToken,
+      'recoveryKey': instance.recoveryKey,
     };
 
 _$LoginDataImpl _$$LoginDataImplFromJson(Map<String, dynamic> json) =>
@@ -36,6 +38,7 @@ _$RegisterDataImpl _$$RegisterDataImplFromJson(Map<String, dynamic> json) =>
       email: json['email'] as String,
       password: json['password'] as String,
       rePassword: json['rePassword'] as String,
+      recoveryKey: json['recoveryKey'] as bool,
     );
 
 Map<String, dynamic> _$$RegisterDataImplToJson(_$RegisterDataImpl instance) =>
@@ -44,4 +47,5 @@ Map<String, dynamic> _$$RegisterDataImplToJson(_$RegisterDataImpl instance) =>
       'email': instance.email,
       'password': instance.password,
       'rePassword': instance.rePassword,
+      'recoveryKey': instance.recoveryKey,
     };
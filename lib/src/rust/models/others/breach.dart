@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'breach.freezed.dart';
+
+/// Outcome of checking a user's login passwords against the breach index.
+@freezed
+class BreachReport with _$BreachReport {
+  const factory BreachReport({
+    required int checked,
+    required int breachedCount,
+    required List<BreachedLogin> breached,
+    required PlatformInt64 checkedAt,
+  }) = _BreachReport;
+}
+
+@freezed
+class BreachedLogin with _$BreachedLogin {
+  const factory BreachedLogin({
+    required String id,
+    required int count,
+  }) = _BreachedLogin;
+}
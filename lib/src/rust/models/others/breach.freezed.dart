@@ -0,0 +1,363 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'breach.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$BreachReport {
+  int get checked => throw _privateConstructorUsedError;
+  int get breachedCount => throw _privateConstructorUsedError;
+  List<BreachedLogin> get breached => throw _privateConstructorUsedError;
+  int get checkedAt => throw _privateConstructorUsedError;
+
+  /// Create a copy of BreachReport
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $BreachReportCopyWith<BreachReport> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BreachReportCopyWith<$Res> {
+  factory $BreachReportCopyWith(
+          BreachReport value, $Res Function(BreachReport) then) =
+      _$BreachReportCopyWithImpl<$Res, BreachReport>;
+  @useResult
+  $Res call(
+      {int checked,
+      int breachedCount,
+      List<BreachedLogin> breached,
+      int checkedAt});
+}
+
+/// @nodoc
+class _$BreachReportCopyWithImpl<$Res, $Val extends BreachReport>
+    implements $BreachReportCopyWith<$Res> {
+  _$BreachReportCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BreachReport
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? checked = null,
+    Object? breachedCount = null,
+    Object? breached = null,
+    Object? checkedAt = null,
+  }) {
+    return _then(_value.copyWith(
+      checked: null == checked
+          ? _value.checked
+          : checked // ignore: cast_nullable_to_non_nullable
+              as int,
+      breachedCount: null == breachedCount
+          ? _value.breachedCount
+          : breachedCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      breached: null == breached
+          ? _value.breached
+          : breached // ignore: cast_nullable_to_non_nullable
+              as List<BreachedLogin>,
+      checkedAt: null == checkedAt
+          ? _value.checkedAt
+          : checkedAt // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$BreachReportImplCopyWith<$Res>
+    implements $BreachReportCopyWith<$Res> {
+  factory _$$BreachReportImplCopyWith(
+          _$BreachReportImpl value, $Res Function(_$BreachReportImpl) then) =
+      __$$BreachReportImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {int checked,
+      int breachedCount,
+      List<BreachedLogin> breached,
+      int checkedAt});
+}
+
+/// @nodoc
+class __$$BreachReportImplCopyWithImpl<$Res>
+    extends _$BreachReportCopyWithImpl<$Res, _$BreachReportImpl>
+    implements _$$BreachReportImplCopyWith<$Res> {
+  __$$BreachReportImplCopyWithImpl(
+      _$BreachReportImpl _value, $Res Function(_$BreachReportImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BreachReport
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? checked = null,
+    Object? breachedCount = null,
+    Object? breached = null,
+    Object? checkedAt = null,
+  }) {
+    return _then(_$BreachReportImpl(
+      checked: null == checked
+          ? _value.checked
+          : checked // ignore: cast_nullable_to_non_nullable
+              as int,
+      breachedCount: null == breachedCount
+          ? _value.breachedCount
+          : breachedCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      breached: null == breached
+          ? _value._breached
+          : breached // ignore: cast_nullable_to_non_nullable
+              as List<BreachedLogin>,
+      checkedAt: null == checkedAt
+          ? _value.checkedAt
+          : checkedAt // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreachReportImpl implements _BreachReport {
+  const _$BreachReportImpl(
+      {required this.checked,
+      required this.breachedCount,
+      required final List<BreachedLogin> breached,
+      required this.checkedAt})
+      : _breached = breached;
+
+  @override
+  final int checked;
+  @override
+  final int breachedCount;
+  final List<BreachedLogin> _breached;
+  @override
+  List<BreachedLogin> get breached {
+    if (_breached is EqualUnmodifiableListView) return _breached;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_breached);
+  }
+
+  @override
+  final int checkedAt;
+
+  @override
+  String toString() {
+    return 'BreachReport(checked: $checked, breachedCount: $breachedCount, breached: $breached, checkedAt: $checkedAt)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreachReportImpl &&
+            (identical(other.checked, checked) || other.checked == checked) &&
+            (identical(other.breachedCount, breachedCount) ||
+                other.breachedCount == breachedCount) &&
+            const DeepCollectionEquality().equals(other._breached, _breached) &&
+            (identical(other.checkedAt, checkedAt) ||
+                other.checkedAt == checkedAt));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, checked, breachedCount,
+      const DeepCollectionEquality().hash(_breached), checkedAt);
+
+  /// Create a copy of BreachReport
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreachReportImplCopyWith<_$BreachReportImpl> get copyWith =>
+      __$$BreachReportImplCopyWithImpl<_$BreachReportImpl>(this, _$identity);
+}
+
+abstract class _BreachReport implements BreachReport {
+  const factory _BreachReport(
+      {required final int checked,
+      required final int breachedCount,
+      required final List<BreachedLogin> breached,
+      required final int checkedAt}) = _$BreachReportImpl;
+
+  @override
+  int get checked;
+  @override
+  int get breachedCount;
+  @override
+  List<BreachedLogin> get breached;
+  @override
+  int get checkedAt;
+
+  /// Create a copy of BreachReport
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BreachReportImplCopyWith<_$BreachReportImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$BreachedLogin {
+  String get id => throw _privateConstructorUsedError;
+  int get count => throw _privateConstructorUsedError;
+
+  /// Create a copy of BreachedLogin
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $BreachedLoginCopyWith<BreachedLogin> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BreachedLoginCopyWith<$Res> {
+  factory $BreachedLoginCopyWith(
+          BreachedLogin value, $Res Function(BreachedLogin) then) =
+      _$BreachedLoginCopyWithImpl<$Res, BreachedLogin>;
+  @useResult
+  $Res call({String id, int count});
+}
+
+/// @nodoc
+class _$BreachedLoginCopyWithImpl<$Res, $Val extends BreachedLogin>
+    implements $BreachedLoginCopyWith<$Res> {
+  _$BreachedLoginCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BreachedLogin
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? count = null,
+  }) {
+    return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$BreachedLoginImplCopyWith<$Res>
+    implements $BreachedLoginCopyWith<$Res> {
+  factory _$$BreachedLoginImplCopyWith(
+          _$BreachedLoginImpl value, $Res Function(_$BreachedLoginImpl) then) =
+      __$$BreachedLoginImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String id, int count});
+}
+
+/// @nodoc
+class __$$BreachedLoginImplCopyWithImpl<$Res>
+    extends _$BreachedLoginCopyWithImpl<$Res, _$BreachedLoginImpl>
+    implements _$$BreachedLoginImplCopyWith<$Res> {
+  __$$BreachedLoginImplCopyWithImpl(
+      _$BreachedLoginImpl _value, $Res Function(_$BreachedLoginImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BreachedLogin
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? count = null,
+  }) {
+    return _then(_$BreachedLoginImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      count: null == count
+          ? _value.count
+          : count // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreachedLoginImpl implements _BreachedLogin {
+  const _$BreachedLoginImpl({required this.id, required this.count});
+
+  @override
+  final String id;
+  @override
+  final int count;
+
+  @override
+  String toString() {
+    return 'BreachedLogin(id: $id, count: $count)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreachedLoginImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.count, count) || other.count == count));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, count);
+
+  /// Create a copy of BreachedLogin
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreachedLoginImplCopyWith<_$BreachedLoginImpl> get copyWith =>
+      __$$BreachedLoginImplCopyWithImpl<_$BreachedLoginImpl>(this, _$identity);
+}
+
+abstract class _BreachedLogin implements BreachedLogin {
+  const factory _BreachedLogin(
+      {required final String id,
+      required final int count}) = _$BreachedLoginImpl;
+
+  @override
+  String get id;
+  @override
+  int get count;
+
+  /// Create a copy of BreachedLogin
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BreachedLoginImplCopyWith<_$BreachedLoginImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
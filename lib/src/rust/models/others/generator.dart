@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'generator.freezed.dart';
+
+enum Capitalization {
+  lowercase,
+  uppercase,
+
+  /// First letter of every word
+  capitalize,
+
+  /// First letter of each word at random, one more bit per word
+  random,
+  ;
+}
+
+@freezed
+class GeneratedPassword with _$GeneratedPassword {
+  const factory GeneratedPassword({
+    required String password,
+    required double entropyBits,
+  }) = _GeneratedPassword;
+}
+
+@freezed
+sealed class GeneratorOptions with _$GeneratorOptions {
+  const GeneratorOptions._();
+
+  const factory GeneratorOptions.password(
+    PasswordOptions field0,
+  ) = GeneratorOptions_Password;
+  const factory GeneratorOptions.pronounceable(
+    PronounceableOptions field0,
+  ) = GeneratorOptions_Pronounceable;
+  const factory GeneratorOptions.passphrase(
+    PassphraseOptions field0,
+  ) = GeneratorOptions_Passphrase;
+}
+
+/// Random words from the embedded wordlist.
+@freezed
+class PassphraseOptions with _$PassphraseOptions {
+  const factory PassphraseOptions({
+    required int words,
+    required String separator,
+    required Capitalization capitalization,
+  }) = _PassphraseOptions;
+}
+
+/// Random characters drawn from the enabled character classes.
+@freezed
+class PasswordOptions with _$PasswordOptions {
+  const factory PasswordOptions({
+    required int length,
+    required bool uppercase,
+    required bool lowercase,
+    required bool digits,
+    required bool symbols,
+    required int minUppercase,
+    required int minLowercase,
+    required int minDigits,
+    required int minSymbols,
+    String? symbolSet,
+    required bool excludeAmbiguous,
+  }) = _PasswordOptions;
+}
+
+/// Alternating consonants and vowels that can be read out loud.
+@freezed
+class PronounceableOptions with _$PronounceableOptions {
+  const factory PronounceableOptions({
+    required int length,
+    required bool capitalize,
+    required int digits,
+  }) = _PronounceableOptions;
+}
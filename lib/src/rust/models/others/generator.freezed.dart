@@ -0,0 +1,1439 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'generator.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$GeneratedPassword {
+  String get password => throw _privateConstructorUsedError;
+  double get entropyBits => throw _privateConstructorUsedError;
+
+  /// Create a copy of GeneratedPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $GeneratedPasswordCopyWith<GeneratedPassword> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GeneratedPasswordCopyWith<$Res> {
+  factory $GeneratedPasswordCopyWith(
+          GeneratedPassword value, $Res Function(GeneratedPassword) then) =
+      _$GeneratedPasswordCopyWithImpl<$Res, GeneratedPassword>;
+  @useResult
+  $Res call({String password, double entropyBits});
+}
+
+/// @nodoc
+class _$GeneratedPasswordCopyWithImpl<$Res, $Val extends GeneratedPassword>
+    implements $GeneratedPasswordCopyWith<$Res> {
+  _$GeneratedPasswordCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GeneratedPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? password = null,
+    Object? entropyBits = null,
+  }) {
+    return _then(_value.copyWith(
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+      entropyBits: null == entropyBits
+          ? _value.entropyBits
+          : entropyBits // ignore: cast_nullable_to_non_nullable
+              as double,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$GeneratedPasswordImplCopyWith<$Res>
+    implements $GeneratedPasswordCopyWith<$Res> {
+  factory _$$GeneratedPasswordImplCopyWith(
+          _$GeneratedPasswordImpl value,
+          $Res Function(_$GeneratedPasswordImpl) then) =
+      __$$GeneratedPasswordImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String password, double entropyBits});
+}
+
+/// @nodoc
+class __$$GeneratedPasswordImplCopyWithImpl<$Res>
+    extends _$GeneratedPasswordCopyWithImpl<$Res, _$GeneratedPasswordImpl>
+    implements _$$GeneratedPasswordImplCopyWith<$Res> {
+  __$$GeneratedPasswordImplCopyWithImpl(
+      _$GeneratedPasswordImpl _value,
+      $Res Function(_$GeneratedPasswordImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GeneratedPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? password = null,
+    Object? entropyBits = null,
+  }) {
+    return _then(_$GeneratedPasswordImpl(
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+      entropyBits: null == entropyBits
+          ? _value.entropyBits
+          : entropyBits // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GeneratedPasswordImpl implements _GeneratedPassword {
+  const _$GeneratedPasswordImpl(
+      {required this.password, required this.entropyBits});
+
+  @override
+  final String password;
+  @override
+  final double entropyBits;
+
+  @override
+  String toString() {
+    return 'GeneratedPassword(password: $password, entropyBits: $entropyBits)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GeneratedPasswordImpl &&
+            (identical(other.password, password) ||
+                other.password == password) &&
+            (identical(other.entropyBits, entropyBits) ||
+                other.entropyBits == entropyBits));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, password, entropyBits);
+
+  /// Create a copy of GeneratedPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GeneratedPasswordImplCopyWith<_$GeneratedPasswordImpl> get copyWith =>
+      __$$GeneratedPasswordImplCopyWithImpl<_$GeneratedPasswordImpl>(
+          this, _$identity);
+}
+
+abstract class _GeneratedPassword implements GeneratedPassword {
+  const factory _GeneratedPassword(
+      {required final String password,
+      required final double entropyBits}) = _$GeneratedPasswordImpl;
+
+  @override
+  String get password;
+  @override
+  double get entropyBits;
+
+  /// Create a copy of GeneratedPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GeneratedPasswordImplCopyWith<_$GeneratedPasswordImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$GeneratorOptions {
+  Object get field0 => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PasswordOptions field0) password,
+    required TResult Function(PronounceableOptions field0) pronounceable,
+    required TResult Function(PassphraseOptions field0) passphrase,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PasswordOptions field0)? password,
+    TResult? Function(PronounceableOptions field0)? pronounceable,
+    TResult? Function(PassphraseOptions field0)? passphrase,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PasswordOptions field0)? password,
+    TResult Function(PronounceableOptions field0)? pronounceable,
+    TResult Function(PassphraseOptions field0)? passphrase,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GeneratorOptions_Password value) password,
+    required TResult Function(GeneratorOptions_Pronounceable value)
+        pronounceable,
+    required TResult Function(GeneratorOptions_Passphrase value) passphrase,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GeneratorOptions_Password value)? password,
+    TResult? Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult? Function(GeneratorOptions_Passphrase value)? passphrase,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GeneratorOptions_Password value)? password,
+    TResult Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult Function(GeneratorOptions_Passphrase value)? passphrase,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GeneratorOptionsCopyWith<$Res> {
+  factory $GeneratorOptionsCopyWith(
+          GeneratorOptions value, $Res Function(GeneratorOptions) then) =
+      _$GeneratorOptionsCopyWithImpl<$Res, GeneratorOptions>;
+}
+
+/// @nodoc
+class _$GeneratorOptionsCopyWithImpl<$Res, $Val extends GeneratorOptions>
+    implements $GeneratorOptionsCopyWith<$Res> {
+  _$GeneratorOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GeneratorOptions_PasswordImplCopyWith<$Res> {
+  factory _$$GeneratorOptions_PasswordImplCopyWith(
+          _$GeneratorOptions_PasswordImpl value,
+          $Res Function(_$GeneratorOptions_PasswordImpl) then) =
+      __$$GeneratorOptions_PasswordImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PasswordOptions field0});
+
+  $PasswordOptionsCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$GeneratorOptions_PasswordImplCopyWithImpl<$Res>
+    extends _$GeneratorOptionsCopyWithImpl<$Res,
+        _$GeneratorOptions_PasswordImpl>
+    implements _$$GeneratorOptions_PasswordImplCopyWith<$Res> {
+  __$$GeneratorOptions_PasswordImplCopyWithImpl(
+      _$GeneratorOptions_PasswordImpl _value,
+      $Res Function(_$GeneratorOptions_PasswordImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$GeneratorOptions_PasswordImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as PasswordOptions,
+    ));
+  }
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PasswordOptionsCopyWith<$Res> get field0 {
+    return $PasswordOptionsCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$GeneratorOptions_PasswordImpl extends GeneratorOptions_Password {
+  const _$GeneratorOptions_PasswordImpl(this.field0) : super._();
+
+  @override
+  final PasswordOptions field0;
+
+  @override
+  String toString() {
+    return 'GeneratorOptions.password(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GeneratorOptions_PasswordImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GeneratorOptions_PasswordImplCopyWith<
+          _$GeneratorOptions_PasswordImpl>
+      get copyWith => __$$GeneratorOptions_PasswordImplCopyWithImpl<
+          _$GeneratorOptions_PasswordImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PasswordOptions field0) password,
+    required TResult Function(PronounceableOptions field0) pronounceable,
+    required TResult Function(PassphraseOptions field0) passphrase,
+  }) {
+    return password(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PasswordOptions field0)? password,
+    TResult? Function(PronounceableOptions field0)? pronounceable,
+    TResult? Function(PassphraseOptions field0)? passphrase,
+  }) {
+    return password?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PasswordOptions field0)? password,
+    TResult Function(PronounceableOptions field0)? pronounceable,
+    TResult Function(PassphraseOptions field0)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (password != null) {
+      return password(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GeneratorOptions_Password value) password,
+    required TResult Function(GeneratorOptions_Pronounceable value)
+        pronounceable,
+    required TResult Function(GeneratorOptions_Passphrase value) passphrase,
+  }) {
+    return password(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GeneratorOptions_Password value)? password,
+    TResult? Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult? Function(GeneratorOptions_Passphrase value)? passphrase,
+  }) {
+    return password?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GeneratorOptions_Password value)? password,
+    TResult Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult Function(GeneratorOptions_Passphrase value)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (password != null) {
+      return password(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GeneratorOptions_Password extends GeneratorOptions {
+  const factory GeneratorOptions_Password(final PasswordOptions field0) =
+      _$GeneratorOptions_PasswordImpl;
+  const GeneratorOptions_Password._() : super._();
+
+  @override
+  PasswordOptions get field0;
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GeneratorOptions_PasswordImplCopyWith<
+          _$GeneratorOptions_PasswordImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$GeneratorOptions_PronounceableImplCopyWith<$Res> {
+  factory _$$GeneratorOptions_PronounceableImplCopyWith(
+          _$GeneratorOptions_PronounceableImpl value,
+          $Res Function(_$GeneratorOptions_PronounceableImpl) then) =
+      __$$GeneratorOptions_PronounceableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PronounceableOptions field0});
+
+  $PronounceableOptionsCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$GeneratorOptions_PronounceableImplCopyWithImpl<$Res>
+    extends _$GeneratorOptionsCopyWithImpl<$Res,
+        _$GeneratorOptions_PronounceableImpl>
+    implements _$$GeneratorOptions_PronounceableImplCopyWith<$Res> {
+  __$$GeneratorOptions_PronounceableImplCopyWithImpl(
+      _$GeneratorOptions_PronounceableImpl _value,
+      $Res Function(_$GeneratorOptions_PronounceableImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$GeneratorOptions_PronounceableImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as PronounceableOptions,
+    ));
+  }
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PronounceableOptionsCopyWith<$Res> get field0 {
+    return $PronounceableOptionsCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$GeneratorOptions_PronounceableImpl
+    extends GeneratorOptions_Pronounceable {
+  const _$GeneratorOptions_PronounceableImpl(this.field0) : super._();
+
+  @override
+  final PronounceableOptions field0;
+
+  @override
+  String toString() {
+    return 'GeneratorOptions.pronounceable(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GeneratorOptions_PronounceableImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GeneratorOptions_PronounceableImplCopyWith<
+          _$GeneratorOptions_PronounceableImpl>
+      get copyWith => __$$GeneratorOptions_PronounceableImplCopyWithImpl<
+          _$GeneratorOptions_PronounceableImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PasswordOptions field0) password,
+    required TResult Function(PronounceableOptions field0) pronounceable,
+    required TResult Function(PassphraseOptions field0) passphrase,
+  }) {
+    return pronounceable(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PasswordOptions field0)? password,
+    TResult? Function(PronounceableOptions field0)? pronounceable,
+    TResult? Function(PassphraseOptions field0)? passphrase,
+  }) {
+    return pronounceable?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PasswordOptions field0)? password,
+    TResult Function(PronounceableOptions field0)? pronounceable,
+    TResult Function(PassphraseOptions field0)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (pronounceable != null) {
+      return pronounceable(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GeneratorOptions_Password value) password,
+    required TResult Function(GeneratorOptions_Pronounceable value)
+        pronounceable,
+    required TResult Function(GeneratorOptions_Passphrase value) passphrase,
+  }) {
+    return pronounceable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GeneratorOptions_Password value)? password,
+    TResult? Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult? Function(GeneratorOptions_Passphrase value)? passphrase,
+  }) {
+    return pronounceable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GeneratorOptions_Password value)? password,
+    TResult Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult Function(GeneratorOptions_Passphrase value)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (pronounceable != null) {
+      return pronounceable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GeneratorOptions_Pronounceable extends GeneratorOptions {
+  const factory GeneratorOptions_Pronounceable(
+      final PronounceableOptions field0) = _$GeneratorOptions_PronounceableImpl;
+  const GeneratorOptions_Pronounceable._() : super._();
+
+  @override
+  PronounceableOptions get field0;
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GeneratorOptions_PronounceableImplCopyWith<
+          _$GeneratorOptions_PronounceableImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$GeneratorOptions_PassphraseImplCopyWith<$Res> {
+  factory _$$GeneratorOptions_PassphraseImplCopyWith(
+          _$GeneratorOptions_PassphraseImpl value,
+          $Res Function(_$GeneratorOptions_PassphraseImpl) then) =
+      __$$GeneratorOptions_PassphraseImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({PassphraseOptions field0});
+
+  $PassphraseOptionsCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$GeneratorOptions_PassphraseImplCopyWithImpl<$Res>
+    extends _$GeneratorOptionsCopyWithImpl<$Res,
+        _$GeneratorOptions_PassphraseImpl>
+    implements _$$GeneratorOptions_PassphraseImplCopyWith<$Res> {
+  __$$GeneratorOptions_PassphraseImplCopyWithImpl(
+      _$GeneratorOptions_PassphraseImpl _value,
+      $Res Function(_$GeneratorOptions_PassphraseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$GeneratorOptions_PassphraseImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as PassphraseOptions,
+    ));
+  }
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PassphraseOptionsCopyWith<$Res> get field0 {
+    return $PassphraseOptionsCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$GeneratorOptions_PassphraseImpl extends GeneratorOptions_Passphrase {
+  const _$GeneratorOptions_PassphraseImpl(this.field0) : super._();
+
+  @override
+  final PassphraseOptions field0;
+
+  @override
+  String toString() {
+    return 'GeneratorOptions.passphrase(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GeneratorOptions_PassphraseImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GeneratorOptions_PassphraseImplCopyWith<
+          _$GeneratorOptions_PassphraseImpl>
+      get copyWith => __$$GeneratorOptions_PassphraseImplCopyWithImpl<
+          _$GeneratorOptions_PassphraseImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PasswordOptions field0) password,
+    required TResult Function(PronounceableOptions field0) pronounceable,
+    required TResult Function(PassphraseOptions field0) passphrase,
+  }) {
+    return passphrase(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PasswordOptions field0)? password,
+    TResult? Function(PronounceableOptions field0)? pronounceable,
+    TResult? Function(PassphraseOptions field0)? passphrase,
+  }) {
+    return passphrase?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PasswordOptions field0)? password,
+    TResult Function(PronounceableOptions field0)? pronounceable,
+    TResult Function(PassphraseOptions field0)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (passphrase != null) {
+      return passphrase(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GeneratorOptions_Password value) password,
+    required TResult Function(GeneratorOptions_Pronounceable value)
+        pronounceable,
+    required TResult Function(GeneratorOptions_Passphrase value) passphrase,
+  }) {
+    return passphrase(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GeneratorOptions_Password value)? password,
+    TResult? Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult? Function(GeneratorOptions_Passphrase value)? passphrase,
+  }) {
+    return passphrase?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GeneratorOptions_Password value)? password,
+    TResult Function(GeneratorOptions_Pronounceable value)? pronounceable,
+    TResult Function(GeneratorOptions_Passphrase value)? passphrase,
+    required TResult orElse(),
+  }) {
+    if (passphrase != null) {
+      return passphrase(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GeneratorOptions_Passphrase extends GeneratorOptions {
+  const factory GeneratorOptions_Passphrase(final PassphraseOptions field0) =
+      _$GeneratorOptions_PassphraseImpl;
+  const GeneratorOptions_Passphrase._() : super._();
+
+  @override
+  PassphraseOptions get field0;
+
+  /// Create a copy of GeneratorOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GeneratorOptions_PassphraseImplCopyWith<
+          _$GeneratorOptions_PassphraseImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PassphraseOptions {
+  int get words => throw _privateConstructorUsedError;
+  String get separator => throw _privateConstructorUsedError;
+  Capitalization get capitalization => throw _privateConstructorUsedError;
+
+  /// Create a copy of PassphraseOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PassphraseOptionsCopyWith<PassphraseOptions> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PassphraseOptionsCopyWith<$Res> {
+  factory $PassphraseOptionsCopyWith(
+          PassphraseOptions value, $Res Function(PassphraseOptions) then) =
+      _$PassphraseOptionsCopyWithImpl<$Res, PassphraseOptions>;
+  @useResult
+  $Res call({int words, String separator, Capitalization capitalization});
+}
+
+/// @nodoc
+class _$PassphraseOptionsCopyWithImpl<$Res, $Val extends PassphraseOptions>
+    implements $PassphraseOptionsCopyWith<$Res> {
+  _$PassphraseOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PassphraseOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? words = null,
+    Object? separator = null,
+    Object? capitalization = null,
+  }) {
+    return _then(_value.copyWith(
+      words: null == words
+          ? _value.words
+          : words // ignore: cast_nullable_to_non_nullable
+              as int,
+      separator: null == separator
+          ? _value.separator
+          : separator // ignore: cast_nullable_to_non_nullable
+              as String,
+      capitalization: null == capitalization
+          ? _value.capitalization
+          : capitalization // ignore: cast_nullable_to_non_nullable
+              as Capitalization,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PassphraseOptionsImplCopyWith<$Res>
+    implements $PassphraseOptionsCopyWith<$Res> {
+  factory _$$PassphraseOptionsImplCopyWith(
+          _$PassphraseOptionsImpl value,
+          $Res Function(_$PassphraseOptionsImpl) then) =
+      __$$PassphraseOptionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int words, String separator, Capitalization capitalization});
+}
+
+/// @nodoc
+class __$$PassphraseOptionsImplCopyWithImpl<$Res>
+    extends _$PassphraseOptionsCopyWithImpl<$Res, _$PassphraseOptionsImpl>
+    implements _$$PassphraseOptionsImplCopyWith<$Res> {
+  __$$PassphraseOptionsImplCopyWithImpl(
+      _$PassphraseOptionsImpl _value,
+      $Res Function(_$PassphraseOptionsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PassphraseOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? words = null,
+    Object? separator = null,
+    Object? capitalization = null,
+  }) {
+    return _then(_$PassphraseOptionsImpl(
+      words: null == words
+          ? _value.words
+          : words // ignore: cast_nullable_to_non_nullable
+              as int,
+      separator: null == separator
+          ? _value.separator
+          : separator // ignore: cast_nullable_to_non_nullable
+              as String,
+      capitalization: null == capitalization
+          ? _value.capitalization
+          : capitalization // ignore: cast_nullable_to_non_nullable
+              as Capitalization,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PassphraseOptionsImpl implements _PassphraseOptions {
+  const _$PassphraseOptionsImpl(
+      {required this.words,
+      required this.separator,
+      required this.capitalization});
+
+  @override
+  final int words;
+  @override
+  final String separator;
+  @override
+  final Capitalization capitalization;
+
+  @override
+  String toString() {
+    return 'PassphraseOptions(words: $words, separator: $separator, capitalization: $capitalization)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PassphraseOptionsImpl &&
+            (identical(other.words, words) || other.words == words) &&
+            (identical(other.separator, separator) ||
+                other.separator == separator) &&
+            (identical(other.capitalization, capitalization) ||
+                other.capitalization == capitalization));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, words, separator, capitalization);
+
+  /// Create a copy of PassphraseOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PassphraseOptionsImplCopyWith<_$PassphraseOptionsImpl> get copyWith =>
+      __$$PassphraseOptionsImplCopyWithImpl<_$PassphraseOptionsImpl>(
+          this, _$identity);
+}
+
+abstract class _PassphraseOptions implements PassphraseOptions {
+  const factory _PassphraseOptions(
+      {required final int words,
+      required final String separator,
+      required final Capitalization capitalization}) = _$PassphraseOptionsImpl;
+
+  @override
+  int get words;
+  @override
+  String get separator;
+  @override
+  Capitalization get capitalization;
+
+  /// Create a copy of PassphraseOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PassphraseOptionsImplCopyWith<_$PassphraseOptionsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasswordOptions {
+  int get length => throw _privateConstructorUsedError;
+  bool get uppercase => throw _privateConstructorUsedError;
+  bool get lowercase => throw _privateConstructorUsedError;
+  bool get digits => throw _privateConstructorUsedError;
+  bool get symbols => throw _privateConstructorUsedError;
+  int get minUppercase => throw _privateConstructorUsedError;
+  int get minLowercase => throw _privateConstructorUsedError;
+  int get minDigits => throw _privateConstructorUsedError;
+  int get minSymbols => throw _privateConstructorUsedError;
+  String? get symbolSet => throw _privateConstructorUsedError;
+  bool get excludeAmbiguous => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasswordOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasswordOptionsCopyWith<PasswordOptions> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordOptionsCopyWith<$Res> {
+  factory $PasswordOptionsCopyWith(
+          PasswordOptions value, $Res Function(PasswordOptions) then) =
+      _$PasswordOptionsCopyWithImpl<$Res, PasswordOptions>;
+  @useResult
+  $Res call(
+      {int length,
+      bool uppercase,
+      bool lowercase,
+      bool digits,
+      bool symbols,
+      int minUppercase,
+      int minLowercase,
+      int minDigits,
+      int minSymbols,
+      String? symbolSet,
+      bool excludeAmbiguous});
+}
+
+/// @nodoc
+class _$PasswordOptionsCopyWithImpl<$Res, $Val extends PasswordOptions>
+    implements $PasswordOptionsCopyWith<$Res> {
+  _$PasswordOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? length = null,
+    Object? uppercase = null,
+    Object? lowercase = null,
+    Object? digits = null,
+    Object? symbols = null,
+    Object? minUppercase = null,
+    Object? minLowercase = null,
+    Object? minDigits = null,
+    Object? minSymbols = null,
+    Object? symbolSet = freezed,
+    Object? excludeAmbiguous = null,
+  }) {
+    return _then(_value.copyWith(
+      length: null == length
+          ? _value.length
+          : length // ignore: cast_nullable_to_non_nullable
+              as int,
+      uppercase: null == uppercase
+          ? _value.uppercase
+          : uppercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      lowercase: null == lowercase
+          ? _value.lowercase
+          : lowercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      digits: null == digits
+          ? _value.digits
+          : digits // ignore: cast_nullable_to_non_nullable
+              as bool,
+      symbols: null == symbols
+          ? _value.symbols
+          : symbols // ignore: cast_nullable_to_non_nullable
+              as bool,
+      minUppercase: null == minUppercase
+          ? _value.minUppercase
+          : minUppercase // ignore: cast_nullable_to_non_nullable
+              as int,
+      minLowercase: null == minLowercase
+          ? _value.minLowercase
+          : minLowercase // ignore: cast_nullable_to_non_nullable
+              as int,
+      minDigits: null == minDigits
+          ? _value.minDigits
+          : minDigits // ignore: cast_nullable_to_non_nullable
+              as int,
+      minSymbols: null == minSymbols
+          ? _value.minSymbols
+          : minSymbols // ignore: cast_nullable_to_non_nullable
+              as int,
+      symbolSet: freezed == symbolSet
+          ? _value.symbolSet
+          : symbolSet // ignore: cast_nullable_to_non_nullable
+              as String?,
+      excludeAmbiguous: null == excludeAmbiguous
+          ? _value.excludeAmbiguous
+          : excludeAmbiguous // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasswordOptionsImplCopyWith<$Res>
+    implements $PasswordOptionsCopyWith<$Res> {
+  factory _$$PasswordOptionsImplCopyWith(
+          _$PasswordOptionsImpl value,
+          $Res Function(_$PasswordOptionsImpl) then) =
+      __$$PasswordOptionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {int length,
+      bool uppercase,
+      bool lowercase,
+      bool digits,
+      bool symbols,
+      int minUppercase,
+      int minLowercase,
+      int minDigits,
+      int minSymbols,
+      String? symbolSet,
+      bool excludeAmbiguous});
+}
+
+/// @nodoc
+class __$$PasswordOptionsImplCopyWithImpl<$Res>
+    extends _$PasswordOptionsCopyWithImpl<$Res, _$PasswordOptionsImpl>
+    implements _$$PasswordOptionsImplCopyWith<$Res> {
+  __$$PasswordOptionsImplCopyWithImpl(
+      _$PasswordOptionsImpl _value, $Res Function(_$PasswordOptionsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? length = null,
+    Object? uppercase = null,
+    Object? lowercase = null,
+    Object? digits = null,
+    Object? symbols = null,
+    Object? minUppercase = null,
+    Object? minLowercase = null,
+    Object? minDigits = null,
+    Object? minSymbols = null,
+    Object? symbolSet = freezed,
+    Object? excludeAmbiguous = null,
+  }) {
+    return _then(_$PasswordOptionsImpl(
+      length: null == length
+          ? _value.length
+          : length // ignore: cast_nullable_to_non_nullable
+              as int,
+      uppercase: null == uppercase
+          ? _value.uppercase
+          : uppercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      lowercase: null == lowercase
+          ? _value.lowercase
+          : lowercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      digits: null == digits
+          ? _value.digits
+          : digits // ignore: cast_nullable_to_non_nullable
+              as bool,
+      symbols: null == symbols
+          ? _value.symbols
+          : symbols // ignore: cast_nullable_to_non_nullable
+              as bool,
+      minUppercase: null == minUppercase
+          ? _value.minUppercase
+          : minUppercase // ignore: cast_nullable_to_non_nullable
+              as int,
+      minLowercase: null == minLowercase
+          ? _value.minLowercase
+          : minLowercase // ignore: cast_nullable_to_non_nullable
+              as int,
+      minDigits: null == minDigits
+          ? _value.minDigits
+          : minDigits // ignore: cast_nullable_to_non_nullable
+              as int,
+      minSymbols: null == minSymbols
+          ? _value.minSymbols
+          : minSymbols // ignore: cast_nullable_to_non_nullable
+              as int,
+      symbolSet: freezed == symbolSet
+          ? _value.symbolSet
+          : symbolSet // ignore: cast_nullable_to_non_nullable
+              as String?,
+      excludeAmbiguous: null == excludeAmbiguous
+          ? _value.excludeAmbiguous
+          : excludeAmbiguous // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordOptionsImpl implements _PasswordOptions {
+  const _$PasswordOptionsImpl(
+      {required this.length,
+      required this.uppercase,
+      required this.lowercase,
+      required this.digits,
+      required this.symbols,
+      required this.minUppercase,
+      required this.minLowercase,
+      required this.minDigits,
+      required this.minSymbols,
+      this.symbolSet,
+      required this.excludeAmbiguous});
+
+  @override
+  final int length;
+  @override
+  final bool uppercase;
+  @override
+  final bool lowercase;
+  @override
+  final bool digits;
+  @override
+  final bool symbols;
+  @override
+  final int minUppercase;
+  @override
+  final int minLowercase;
+  @override
+  final int minDigits;
+  @override
+  final int minSymbols;
+  @override
+  final String? symbolSet;
+  @override
+  final bool excludeAmbiguous;
+
+  @override
+  String toString() {
+    return 'PasswordOptions(length: $length, uppercase: $uppercase, lowercase: $lowercase, digits: $digits, symbols: $symbols, minUppercase: $minUppercase, minLowercase: $minLowercase, minDigits: $minDigits, minSymbols: $minSymbols, symbolSet: $symbolSet, excludeAmbiguous: $excludeAmbiguous)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordOptionsImpl &&
+            (identical(other.length, length) || other.length == length) &&
+            (identical(other.uppercase, uppercase) ||
+                other.uppercase == uppercase) &&
+            (identical(other.lowercase, lowercase) ||
+                other.lowercase == lowercase) &&
+            (identical(other.digits, digits) || other.digits == digits) &&
+            (identical(other.symbols, symbols) || other.symbols == symbols) &&
+            (identical(other.minUppercase, minUppercase) ||
+                other.minUppercase == minUppercase) &&
+            (identical(other.minLowercase, minLowercase) ||
+                other.minLowercase == minLowercase) &&
+            (identical(other.minDigits, minDigits) ||
+                other.minDigits == minDigits) &&
+            (identical(other.minSymbols, minSymbols) ||
+                other.minSymbols == minSymbols) &&
+            (identical(other.symbolSet, symbolSet) ||
+                other.symbolSet == symbolSet) &&
+            (identical(other.excludeAmbiguous, excludeAmbiguous) ||
+                other.excludeAmbiguous == excludeAmbiguous));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      length,
+      uppercase,
+      lowercase,
+      digits,
+      symbols,
+      minUppercase,
+      minLowercase,
+      minDigits,
+      minSymbols,
+      symbolSet,
+      excludeAmbiguous);
+
+  /// Create a copy of PasswordOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordOptionsImplCopyWith<_$PasswordOptionsImpl> get copyWith =>
+      __$$PasswordOptionsImplCopyWithImpl<_$PasswordOptionsImpl>(
+          this, _$identity);
+}
+
+abstract class _PasswordOptions implements PasswordOptions {
+  const factory _PasswordOptions(
+      {required final int length,
+      required final bool uppercase,
+      required final bool lowercase,
+      required final bool digits,
+      required final bool symbols,
+      required final int minUppercase,
+      required final int minLowercase,
+      required final int minDigits,
+      required final int minSymbols,
+      final String? symbolSet,
+      required final bool excludeAmbiguous}) = _$PasswordOptionsImpl;
+
+  @override
+  int get length;
+  @override
+  bool get uppercase;
+  @override
+  bool get lowercase;
+  @override
+  bool get digits;
+  @override
+  bool get symbols;
+  @override
+  int get minUppercase;
+  @override
+  int get minLowercase;
+  @override
+  int get minDigits;
+  @override
+  int get minSymbols;
+  @override
+  String? get symbolSet;
+  @override
+  bool get excludeAmbiguous;
+
+  /// Create a copy of PasswordOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordOptionsImplCopyWith<_$PasswordOptionsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PronounceableOptions {
+  int get length => throw _privateConstructorUsedError;
+  bool get capitalize => throw _privateConstructorUsedError;
+  int get digits => throw _privateConstructorUsedError;
+
+  /// Create a copy of PronounceableOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PronounceableOptionsCopyWith<PronounceableOptions> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PronounceableOptionsCopyWith<$Res> {
+  factory $PronounceableOptionsCopyWith(
+          PronounceableOptions value,
+          $Res Function(PronounceableOptions) then) =
+      _$PronounceableOptionsCopyWithImpl<$Res, PronounceableOptions>;
+  @useResult
+  $Res call({int length, bool capitalize, int digits});
+}
+
+/// @nodoc
+class _$PronounceableOptionsCopyWithImpl<$Res,
+        $Val extends PronounceableOptions>
+    implements $PronounceableOptionsCopyWith<$Res> {
+  _$PronounceableOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PronounceableOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? length = null,
+    Object? capitalize = null,
+    Object? digits = null,
+  }) {
+    return _then(_value.copyWith(
+      length: null == length
+          ? _value.length
+          : length // ignore: cast_nullable_to_non_nullable
+              as int,
+      capitalize: null == capitalize
+          ? _value.capitalize
+          : capitalize // ignore: cast_nullable_to_non_nullable
+              as bool,
+      digits: null == digits
+          ? _value.digits
+          : digits // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PronounceableOptionsImplCopyWith<$Res>
+    implements $PronounceableOptionsCopyWith<$Res> {
+  factory _$$PronounceableOptionsImplCopyWith(
+          _$PronounceableOptionsImpl value,
+          $Res Function(_$PronounceableOptionsImpl) then) =
+      __$$PronounceableOptionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int length, bool capitalize, int digits});
+}
+
+/// @nodoc
+class __$$PronounceableOptionsImplCopyWithImpl<$Res>
+    extends _$PronounceableOptionsCopyWithImpl<$Res, _$PronounceableOptionsImpl>
+    implements _$$PronounceableOptionsImplCopyWith<$Res> {
+  __$$PronounceableOptionsImplCopyWithImpl(
+      _$PronounceableOptionsImpl _value,
+      $Res Function(_$PronounceableOptionsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PronounceableOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? length = null,
+    Object? capitalize = null,
+    Object? digits = null,
+  }) {
+    return _then(_$PronounceableOptionsImpl(
+      length: null == length
+          ? _value.length
+          : length // ignore: cast_nullable_to_non_nullable
+              as int,
+      capitalize: null == capitalize
+          ? _value.capitalize
+          : capitalize // ignore: cast_nullable_to_non_nullable
+              as bool,
+      digits: null == digits
+          ? _value.digits
+          : digits // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PronounceableOptionsImpl implements _PronounceableOptions {
+  const _$PronounceableOptionsImpl(
+      {required this.length, required this.capitalize, required this.digits});
+
+  @override
+  final int length;
+  @override
+  final bool capitalize;
+  @override
+  final int digits;
+
+  @override
+  String toString() {
+    return 'PronounceableOptions(length: $length, capitalize: $capitalize, digits: $digits)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PronounceableOptionsImpl &&
+            (identical(other.length, length) || other.length == length) &&
+            (identical(other.capitalize, capitalize) ||
+                other.capitalize == capitalize) &&
+            (identical(other.digits, digits) || other.digits == digits));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, length, capitalize, digits);
+
+  /// Create a copy of PronounceableOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PronounceableOptionsImplCopyWith<
+          _$PronounceableOptionsImpl>
+      get copyWith => __$$PronounceableOptionsImplCopyWithImpl<
+          _$PronounceableOptionsImpl>(this, _$identity);
+}
+
+abstract class _PronounceableOptions implements PronounceableOptions {
+  const factory _PronounceableOptions(
+      {required final int length,
+      required final bool capitalize,
+      required final int digits}) = _$PronounceableOptionsImpl;
+
+  @override
+  int get length;
+  @override
+  bool get capitalize;
+  @override
+  int get digits;
+
+  /// Create a copy of PronounceableOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PronounceableOptionsImplCopyWith<
+          _$PronounceableOptionsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
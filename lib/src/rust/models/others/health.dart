@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'health.freezed.dart';
+
+/// Problems with the passwords of a user's logins, by login id.
+@freezed
+class VaultHealth with _$VaultHealth {
+  const factory VaultHealth({
+    required int total,
+    required int reusedCount,
+    required int weakCount,
+    required int staleCount,
+    required int emptyCount,
+    required List<List<String>> reused,
+    required List<WeakPassword> weak,
+    required List<String> stale,
+    required int staleDays,
+    required List<String> empty,
+  }) = _VaultHealth;
+}
+
+@freezed
+class WeakPassword with _$WeakPassword {
+  const factory WeakPassword({
+    required String id,
+    required int score,
+    String? warning,
+  }) = _WeakPassword;
+}
@@ -0,0 +1,534 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'health.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$VaultHealth {
+  int get total => throw _privateConstructorUsedError;
+  int get reusedCount => throw _privateConstructorUsedError;
+  int get weakCount => throw _privateConstructorUsedError;
+  int get staleCount => throw _privateConstructorUsedError;
+  int get emptyCount => throw _privateConstructorUsedError;
+  List<List<String>> get reused => throw _privateConstructorUsedError;
+  List<WeakPassword> get weak => throw _privateConstructorUsedError;
+  List<String> get stale => throw _privateConstructorUsedError;
+  int get staleDays => throw _privateConstructorUsedError;
+  List<String> get empty => throw _privateConstructorUsedError;
+
+  /// Create a copy of VaultHealth
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $VaultHealthCopyWith<VaultHealth> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $VaultHealthCopyWith<$Res> {
+  factory $VaultHealthCopyWith(
+          VaultHealth value, $Res Function(VaultHealth) then) =
+      _$VaultHealthCopyWithImpl<$Res, VaultHealth>;
+  @useResult
+  $Res call(
+      {int total,
+      int reusedCount,
+      int weakCount,
+      int staleCount,
+      int emptyCount,
+      List<List<String>> reused,
+      List<WeakPassword> weak,
+      List<String> stale,
+      int staleDays,
+      List<String> empty});
+}
+
+/// @nodoc
+class _$VaultHealthCopyWithImpl<$Res, $Val extends VaultHealth>
+    implements $VaultHealthCopyWith<$Res> {
+  _$VaultHealthCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of VaultHealth
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? total = null,
+    Object? reusedCount = null,
+    Object? weakCount = null,
+    Object? staleCount = null,
+    Object? emptyCount = null,
+    Object? reused = null,
+    Object? weak = null,
+    Object? stale = null,
+    Object? staleDays = null,
+    Object? empty = null,
+  }) {
+    return _then(_value.copyWith(
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+      reusedCount: null == reusedCount
+          ? _value.reusedCount
+          : reusedCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      weakCount: null == weakCount
+          ? _value.weakCount
+          : weakCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      staleCount: null == staleCount
+          ? _value.staleCount
+          : staleCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      emptyCount: null == emptyCount
+          ? _value.emptyCount
+          : emptyCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      reused: null == reused
+          ? _value.reused
+          : reused // ignore: cast_nullable_to_non_nullable
+              as List<List<String>>,
+      weak: null == weak
+          ? _value.weak
+          : weak // ignore: cast_nullable_to_non_nullable
+              as List<WeakPassword>,
+      stale: null == stale
+          ? _value.stale
+          : stale // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      staleDays: null == staleDays
+          ? _value.staleDays
+          : staleDays // ignore: cast_nullable_to_non_nullable
+              as int,
+      empty: null == empty
+          ? _value.empty
+          : empty // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$VaultHealthImplCopyWith<$Res>
+    implements $VaultHealthCopyWith<$Res> {
+  factory _$$VaultHealthImplCopyWith(
+          _$VaultHealthImpl value, $Res Function(_$VaultHealthImpl) then) =
+      __$$VaultHealthImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {int total,
+      int reusedCount,
+      int weakCount,
+      int staleCount,
+      int emptyCount,
+      List<List<String>> reused,
+      List<WeakPassword> weak,
+      List<String> stale,
+      int staleDays,
+      List<String> empty});
+}
+
+/// @nodoc
+class __$$VaultHealthImplCopyWithImpl<$Res>
+    extends _$VaultHealthCopyWithImpl<$Res, _$VaultHealthImpl>
+    implements _$$VaultHealthImplCopyWith<$Res> {
+  __$$VaultHealthImplCopyWithImpl(
+      _$VaultHealthImpl _value, $Res Function(_$VaultHealthImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultHealth
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? total = null,
+    Object? reusedCount = null,
+    Object? weakCount = null,
+    Object? staleCount = null,
+    Object? emptyCount = null,
+    Object? reused = null,
+    Object? weak = null,
+    Object? stale = null,
+    Object? staleDays = null,
+    Object? empty = null,
+  }) {
+    return _then(_$VaultHealthImpl(
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+      reusedCount: null == reusedCount
+          ? _value.reusedCount
+          : reusedCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      weakCount: null == weakCount
+          ? _value.weakCount
+          : weakCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      staleCount: null == staleCount
+          ? _value.staleCount
+          : staleCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      emptyCount: null == emptyCount
+          ? _value.emptyCount
+          : emptyCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      reused: null == reused
+          ? _value._reused
+          : reused // ignore: cast_nullable_to_non_nullable
+              as List<List<String>>,
+      weak: null == weak
+          ? _value._weak
+          : weak // ignore: cast_nullable_to_non_nullable
+              as List<WeakPassword>,
+      stale: null == stale
+          ? _value._stale
+          : stale // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      staleDays: null == staleDays
+          ? _value.staleDays
+          : staleDays // ignore: cast_nullable_to_non_nullable
+              as int,
+      empty: null == empty
+          ? _value._empty
+          : empty // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultHealthImpl implements _VaultHealth {
+  const _$VaultHealthImpl(
+      {required this.total,
+      required this.reusedCount,
+      required this.weakCount,
+      required this.staleCount,
+      required this.emptyCount,
+      required final List<List<String>> reused,
+      required final List<WeakPassword> weak,
+      required final List<String> stale,
+      required this.staleDays,
+      required final List<String> empty})
+      : _reused = reused,
+        _weak = weak,
+        _stale = stale,
+        _empty = empty;
+
+  @override
+  final int total;
+  @override
+  final int reusedCount;
+  @override
+  final int weakCount;
+  @override
+  final int staleCount;
+  @override
+  final int emptyCount;
+  final List<List<String>> _reused;
+  @override
+  List<List<String>> get reused {
+    if (_reused is EqualUnmodifiableListView) return _reused;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_reused);
+  }
+
+  final List<WeakPassword> _weak;
+  @override
+  List<WeakPassword> get weak {
+    if (_weak is EqualUnmodifiableListView) return _weak;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_weak);
+  }
+
+  final List<String> _stale;
+  @override
+  List<String> get stale {
+    if (_stale is EqualUnmodifiableListView) return _stale;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_stale);
+  }
+
+  @override
+  final int staleDays;
+  final List<String> _empty;
+  @override
+  List<String> get empty {
+    if (_empty is EqualUnmodifiableListView) return _empty;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_empty);
+  }
+
+  @override
+  String toString() {
+    return 'VaultHealth(total: $total, reusedCount: $reusedCount, weakCount: $weakCount, staleCount: $staleCount, emptyCount: $emptyCount, reused: $reused, weak: $weak, stale: $stale, staleDays: $staleDays, empty: $empty)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultHealthImpl &&
+            (identical(other.total, total) || other.total == total) &&
+            (identical(other.reusedCount, reusedCount) ||
+                other.reusedCount == reusedCount) &&
+            (identical(other.weakCount, weakCount) ||
+                other.weakCount == weakCount) &&
+            (identical(other.staleCount, staleCount) ||
+                other.staleCount == staleCount) &&
+            (identical(other.emptyCount, emptyCount) ||
+                other.emptyCount == emptyCount) &&
+            const DeepCollectionEquality().equals(other._reused, _reused) &&
+            const DeepCollectionEquality().equals(other._weak, _weak) &&
+            const DeepCollectionEquality().equals(other._stale, _stale) &&
+            (identical(other.staleDays, staleDays) ||
+                other.staleDays == staleDays) &&
+            const DeepCollectionEquality().equals(other._empty, _empty));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      total,
+      reusedCount,
+      weakCount,
+      staleCount,
+      emptyCount,
+      const DeepCollectionEquality().hash(_reused),
+      const DeepCollectionEquality().hash(_weak),
+      const DeepCollectionEquality().hash(_stale),
+      staleDays,
+      const DeepCollectionEquality().hash(_empty));
+
+  /// Create a copy of VaultHealth
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultHealthImplCopyWith<_$VaultHealthImpl> get copyWith =>
+      __$$VaultHealthImplCopyWithImpl<_$VaultHealthImpl>(this, _$identity);
+}
+
+abstract class _VaultHealth implements VaultHealth {
+  const factory _VaultHealth(
+      {required final int total,
+      required final int reusedCount,
+      required final int weakCount,
+      required final int staleCount,
+      required final int emptyCount,
+      required final List<List<String>> reused,
+      required final List<WeakPassword> weak,
+      required final List<String> stale,
+      required final int staleDays,
+      required final List<String> empty}) = _$VaultHealthImpl;
+
+  @override
+  int get total;
+  @override
+  int get reusedCount;
+  @override
+  int get weakCount;
+  @override
+  int get staleCount;
+  @override
+  int get emptyCount;
+  @override
+  List<List<String>> get reused;
+  @override
+  List<WeakPassword> get weak;
+  @override
+  List<String> get stale;
+  @override
+  int get staleDays;
+  @override
+  List<String> get empty;
+
+  /// Create a copy of VaultHealth
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultHealthImplCopyWith<_$VaultHealthImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$WeakPassword {
+  String get id => throw _privateConstructorUsedError;
+  int get score => throw _privateConstructorUsedError;
+  String? get warning => throw _privateConstructorUsedError;
+
+  /// Create a copy of WeakPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $WeakPasswordCopyWith<WeakPassword> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $WeakPasswordCopyWith<$Res> {
+  factory $WeakPasswordCopyWith(
+          WeakPassword value, $Res Function(WeakPassword) then) =
+      _$WeakPasswordCopyWithImpl<$Res, WeakPassword>;
+  @useResult
+  $Res call({String id, int score, String? warning});
+}
+
+/// @nodoc
+class _$WeakPasswordCopyWithImpl<$Res, $Val extends WeakPassword>
+    implements $WeakPasswordCopyWith<$Res> {
+  _$WeakPasswordCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of WeakPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? score = null,
+    Object? warning = freezed,
+  }) {
+    return _then(_value.copyWith(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      score: null == score
+          ? _value.score
+          : score // ignore: cast_nullable_to_non_nullable
+              as int,
+      warning: freezed == warning
+          ? _value.warning
+          : warning // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$WeakPasswordImplCopyWith<$Res>
+    implements $WeakPasswordCopyWith<$Res> {
+  factory _$$WeakPasswordImplCopyWith(
+          _$WeakPasswordImpl value, $Res Function(_$WeakPasswordImpl) then) =
+      __$$WeakPasswordImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String id, int score, String? warning});
+}
+
+/// @nodoc
+class __$$WeakPasswordImplCopyWithImpl<$Res>
+    extends _$WeakPasswordCopyWithImpl<$Res, _$WeakPasswordImpl>
+    implements _$$WeakPasswordImplCopyWith<$Res> {
+  __$$WeakPasswordImplCopyWithImpl(
+      _$WeakPasswordImpl _value, $Res Function(_$WeakPasswordImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WeakPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? score = null,
+    Object? warning = freezed,
+  }) {
+    return _then(_$WeakPasswordImpl(
+      id: null == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String,
+      score: null == score
+          ? _value.score
+          : score // ignore: cast_nullable_to_non_nullable
+              as int,
+      warning: freezed == warning
+          ? _value.warning
+          : warning // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WeakPasswordImpl implements _WeakPassword {
+  const _$WeakPasswordImpl(
+      {required this.id, required this.score, this.warning});
+
+  @override
+  final String id;
+  @override
+  final int score;
+  @override
+  final String? warning;
+
+  @override
+  String toString() {
+    return 'WeakPassword(id: $id, score: $score, warning: $warning)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WeakPasswordImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.score, score) || other.score == score) &&
+            (identical(other.warning, warning) || other.warning == warning));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, score, warning);
+
+  /// Create a copy of WeakPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WeakPasswordImplCopyWith<_$WeakPasswordImpl> get copyWith =>
+      __$$WeakPasswordImplCopyWithImpl<_$WeakPasswordImpl>(this, _$identity);
+}
+
+abstract class _WeakPassword implements WeakPassword {
+  const factory _WeakPassword(
+      {required final String id,
+      required final int score,
+      final String? warning}) = _$WeakPasswordImpl;
+
+  @override
+  String get id;
+  @override
+  int get score;
+  @override
+  String? get warning;
+
+  /// Create a copy of WeakPassword
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WeakPasswordImplCopyWith<_$WeakPasswordImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
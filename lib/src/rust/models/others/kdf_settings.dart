@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'kdf_settings.freezed.dart';
+
+/// Argon2id parameters used to wrap the vault's data key and to hash
+/// account passwords.
+@freezed
+class KdfSettings with _$KdfSettings {
+  const factory KdfSettings({
+    required int memoryCost,
+    required int timeCost,
+    required int parallelism,
+  }) = _KdfSettings;
+}
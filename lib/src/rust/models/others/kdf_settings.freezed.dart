@@ -0,0 +1,185 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'kdf_settings.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$KdfSettings {
+  int get memoryCost => throw _privateConstructorUsedError;
+  int get timeCost => throw _privateConstructorUsedError;
+  int get parallelism => throw _privateConstructorUsedError;
+
+  /// Create a copy of KdfSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $KdfSettingsCopyWith<KdfSettings> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $KdfSettingsCopyWith<$Res> {
+  factory $KdfSettingsCopyWith(
+          KdfSettings value, $Res Function(KdfSettings) then) =
+      _$KdfSettingsCopyWithImpl<$Res, KdfSettings>;
+  @useResult
+  $Res call({int memoryCost, int timeCost, int parallelism});
+}
+
+/// @nodoc
+class _$KdfSettingsCopyWithImpl<$Res, $Val extends KdfSettings>
+    implements $KdfSettingsCopyWith<$Res> {
+  _$KdfSettingsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of KdfSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? memoryCost = null,
+    Object? timeCost = null,
+    Object? parallelism = null,
+  }) {
+    return _then(_value.copyWith(
+      memoryCost: null == memoryCost
+          ? _value.memoryCost
+          : memoryCost // ignore: cast_nullable_to_non_nullable
+              as int,
+      timeCost: null == timeCost
+          ? _value.timeCost
+          : timeCost // ignore: cast_nullable_to_non_nullable
+              as int,
+      parallelism: null == parallelism
+          ? _value.parallelism
+          : parallelism // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$KdfSettingsImplCopyWith<$Res>
+    implements $KdfSettingsCopyWith<$Res> {
+  factory _$$KdfSettingsImplCopyWith(
+          _$KdfSettingsImpl value, $Res Function(_$KdfSettingsImpl) then) =
+      __$$KdfSettingsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int memoryCost, int timeCost, int parallelism});
+}
+
+/// @nodoc
+class __$$KdfSettingsImplCopyWithImpl<$Res>
+    extends _$KdfSettingsCopyWithImpl<$Res, _$KdfSettingsImpl>
+    implements _$$KdfSettingsImplCopyWith<$Res> {
+  __$$KdfSettingsImplCopyWithImpl(
+      _$KdfSettingsImpl _value, $Res Function(_$KdfSettingsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of KdfSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? memoryCost = null,
+    Object? timeCost = null,
+    Object? parallelism = null,
+  }) {
+    return _then(_$KdfSettingsImpl(
+      memoryCost: null == memoryCost
+          ? _value.memoryCost
+          : memoryCost // ignore: cast_nullable_to_non_nullable
+              as int,
+      timeCost: null == timeCost
+          ? _value.timeCost
+          : timeCost // ignore: cast_nullable_to_non_nullable
+              as int,
+      parallelism: null == parallelism
+          ? _value.parallelism
+          : parallelism // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$KdfSettingsImpl implements _KdfSettings {
+  const _$KdfSettingsImpl(
+      {required this.memoryCost,
+      required this.timeCost,
+      required this.parallelism});
+
+  @override
+  final int memoryCost;
+  @override
+  final int timeCost;
+  @override
+  final int parallelism;
+
+  @override
+  String toString() {
+    return 'KdfSettings(memoryCost: $memoryCost, timeCost: $timeCost, parallelism: $parallelism)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$KdfSettingsImpl &&
+            (identical(other.memoryCost, memoryCost) ||
+                other.memoryCost == memoryCost) &&
+            (identical(other.timeCost, timeCost) ||
+                other.timeCost == timeCost) &&
+            (identical(other.parallelism, parallelism) ||
+                other.parallelism == parallelism));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, memoryCost, timeCost, parallelism);
+
+  /// Create a copy of KdfSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$KdfSettingsImplCopyWith<_$KdfSettingsImpl> get copyWith =>
+      __$$KdfSettingsImplCopyWithImpl<_$KdfSettingsImpl>(this, _$identity);
+}
+
+abstract class _KdfSettings implements KdfSettings {
+  const factory _KdfSettings(
+      {required final int memoryCost,
+      required final int timeCost,
+      required final int parallelism}) = _$KdfSettingsImpl;
+
+  @override
+  int get memoryCost;
+  @override
+  int get timeCost;
+  @override
+  int get parallelism;
+
+  /// Create a copy of KdfSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$KdfSettingsImplCopyWith<_$KdfSettingsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
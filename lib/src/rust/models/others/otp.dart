@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'otp.freezed.dart';
+
+/// A one-time password from the authenticator of a login.
+@freezed
+class OtpCode with _$OtpCode {
+  const factory OtpCode({
+    required String code,
+    required BigInt period,
+    required BigInt remainingSeconds,
+    BigInt? counter,
+  }) = _OtpCode;
+}
@@ -0,0 +1,200 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'otp.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$OtpCode {
+  String get code => throw _privateConstructorUsedError;
+  BigInt get period => throw _privateConstructorUsedError;
+  BigInt get remainingSeconds => throw _privateConstructorUsedError;
+  BigInt? get counter => throw _privateConstructorUsedError;
+
+  /// Create a copy of OtpCode
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $OtpCodeCopyWith<OtpCode> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $OtpCodeCopyWith<$Res> {
+  factory $OtpCodeCopyWith(OtpCode value, $Res Function(OtpCode) then) =
+      _$OtpCodeCopyWithImpl<$Res, OtpCode>;
+  @useResult
+  $Res call(
+      {String code, BigInt period, BigInt remainingSeconds, BigInt? counter});
+}
+
+/// @nodoc
+class _$OtpCodeCopyWithImpl<$Res, $Val extends OtpCode>
+    implements $OtpCodeCopyWith<$Res> {
+  _$OtpCodeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of OtpCode
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? code = null,
+    Object? period = null,
+    Object? remainingSeconds = null,
+    Object? counter = freezed,
+  }) {
+    return _then(_value.copyWith(
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as String,
+      period: null == period
+          ? _value.period
+          : period // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      remainingSeconds: null == remainingSeconds
+          ? _value.remainingSeconds
+          : remainingSeconds // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      counter: freezed == counter
+          ? _value.counter
+          : counter // ignore: cast_nullable_to_non_nullable
+              as BigInt?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$OtpCodeImplCopyWith<$Res> implements $OtpCodeCopyWith<$Res> {
+  factory _$$OtpCodeImplCopyWith(
+          _$OtpCodeImpl value, $Res Function(_$OtpCodeImpl) then) =
+      __$$OtpCodeImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String code, BigInt period, BigInt remainingSeconds, BigInt? counter});
+}
+
+/// @nodoc
+class __$$OtpCodeImplCopyWithImpl<$Res>
+    extends _$OtpCodeCopyWithImpl<$Res, _$OtpCodeImpl>
+    implements _$$OtpCodeImplCopyWith<$Res> {
+  __$$OtpCodeImplCopyWithImpl(
+      _$OtpCodeImpl _value, $Res Function(_$OtpCodeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of OtpCode
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? code = null,
+    Object? period = null,
+    Object? remainingSeconds = null,
+    Object? counter = freezed,
+  }) {
+    return _then(_$OtpCodeImpl(
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as String,
+      period: null == period
+          ? _value.period
+          : period // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      remainingSeconds: null == remainingSeconds
+          ? _value.remainingSeconds
+          : remainingSeconds // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      counter: freezed == counter
+          ? _value.counter
+          : counter // ignore: cast_nullable_to_non_nullable
+              as BigInt?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$OtpCodeImpl implements _OtpCode {
+  const _$OtpCodeImpl(
+      {required this.code,
+      required this.period,
+      required this.remainingSeconds,
+      this.counter});
+
+  @override
+  final String code;
+  @override
+  final BigInt period;
+  @override
+  final BigInt remainingSeconds;
+  @override
+  final BigInt? counter;
+
+  @override
+  String toString() {
+    return 'OtpCode(code: $code, period: $period, remainingSeconds: $remainingSeconds, counter: $counter)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$OtpCodeImpl &&
+            (identical(other.code, code) || other.code == code) &&
+            (identical(other.period, period) || other.period == period) &&
+            (identical(other.remainingSeconds, remainingSeconds) ||
+                other.remainingSeconds == remainingSeconds) &&
+            (identical(other.counter, counter) || other.counter == counter));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, code, period, remainingSeconds, counter);
+
+  /// Create a copy of OtpCode
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$OtpCodeImplCopyWith<_$OtpCodeImpl> get copyWith =>
+      __$$OtpCodeImplCopyWithImpl<_$OtpCodeImpl>(this, _$identity);
+}
+
+abstract class _OtpCode implements OtpCode {
+  const factory _OtpCode(
+      {required final String code,
+      required final BigInt period,
+      required final BigInt remainingSeconds,
+      final BigInt? counter}) = _$OtpCodeImpl;
+
+  @override
+  String get code;
+  @override
+  BigInt get period;
+  @override
+  BigInt get remainingSeconds;
+  @override
+  BigInt? get counter;
+
+  /// Create a copy of OtpCode
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$OtpCodeImplCopyWith<_$OtpCodeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../passkeys.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'passkey.freezed.dart';
+
+/// The response for the relying party, base64url without padding.
+@freezed
+class PasskeyAssertion with _$PasskeyAssertion {
+  const factory PasskeyAssertion({
+    required String credentialId,
+    required String authenticatorData,
+    required String signature,
+    required String userHandle,
+  }) = _PasskeyAssertion;
+}
+
+/// A relying party asking a passkey to sign in.
+@freezed
+class PasskeyAssertionRequest with _$PasskeyAssertionRequest {
+  const factory PasskeyAssertionRequest({
+    required String rpId,
+    required String clientDataHash,
+    required bool userVerified,
+  }) = _PasskeyAssertionRequest;
+}
+
+/// A relying party asking for a new passkey, the parts of
+/// `PublicKeyCredentialCreationOptions` an authenticator uses. Binary
+/// fields are base64url without padding.
+@freezed
+class PasskeyCreation with _$PasskeyCreation {
+  const factory PasskeyCreation({
+    required String rpId,
+    String? rpName,
+    required String userHandle,
+    String? userName,
+    String? userDisplayName,
+    required String clientDataHash,
+    required Int64List algorithms,
+    required bool userVerified,
+  }) = _PasskeyCreation;
+}
+
+/// The new passkey, and the response for the relying party.
+@freezed
+class PasskeyRegistration with _$PasskeyRegistration {
+  const factory PasskeyRegistration({
+    required Passkey passkey,
+    required String credentialId,
+    required String attestationObject,
+    required String authenticatorData,
+    required String publicKey,
+    required PlatformInt64 publicKeyAlgorithm,
+  }) = _PasskeyRegistration;
+}
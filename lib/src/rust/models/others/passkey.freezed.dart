@@ -0,0 +1,929 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'passkey.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$PasskeyAssertion {
+  String get credentialId => throw _privateConstructorUsedError;
+  String get authenticatorData => throw _privateConstructorUsedError;
+  String get signature => throw _privateConstructorUsedError;
+  String get userHandle => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasskeyAssertion
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasskeyAssertionCopyWith<PasskeyAssertion> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasskeyAssertionCopyWith<$Res> {
+  factory $PasskeyAssertionCopyWith(
+          PasskeyAssertion value, $Res Function(PasskeyAssertion) then) =
+      _$PasskeyAssertionCopyWithImpl<$Res, PasskeyAssertion>;
+  @useResult
+  $Res call(
+      {String credentialId,
+      String authenticatorData,
+      String signature,
+      String userHandle});
+}
+
+/// @nodoc
+class _$PasskeyAssertionCopyWithImpl<$Res, $Val extends PasskeyAssertion>
+    implements $PasskeyAssertionCopyWith<$Res> {
+  _$PasskeyAssertionCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasskeyAssertion
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? credentialId = null,
+    Object? authenticatorData = null,
+    Object? signature = null,
+    Object? userHandle = null,
+  }) {
+    return _then(_value.copyWith(
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      authenticatorData: null == authenticatorData
+          ? _value.authenticatorData
+          : authenticatorData // ignore: cast_nullable_to_non_nullable
+              as String,
+      signature: null == signature
+          ? _value.signature
+          : signature // ignore: cast_nullable_to_non_nullable
+              as String,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasskeyAssertionImplCopyWith<$Res>
+    implements $PasskeyAssertionCopyWith<$Res> {
+  factory _$$PasskeyAssertionImplCopyWith(
+          _$PasskeyAssertionImpl value,
+          $Res Function(_$PasskeyAssertionImpl) then) =
+      __$$PasskeyAssertionImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String credentialId,
+      String authenticatorData,
+      String signature,
+      String userHandle});
+}
+
+/// @nodoc
+class __$$PasskeyAssertionImplCopyWithImpl<$Res>
+    extends _$PasskeyAssertionCopyWithImpl<$Res, _$PasskeyAssertionImpl>
+    implements _$$PasskeyAssertionImplCopyWith<$Res> {
+  __$$PasskeyAssertionImplCopyWithImpl(
+      _$PasskeyAssertionImpl _value,
+      $Res Function(_$PasskeyAssertionImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasskeyAssertion
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? credentialId = null,
+    Object? authenticatorData = null,
+    Object? signature = null,
+    Object? userHandle = null,
+  }) {
+    return _then(_$PasskeyAssertionImpl(
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      authenticatorData: null == authenticatorData
+          ? _value.authenticatorData
+          : authenticatorData // ignore: cast_nullable_to_non_nullable
+              as String,
+      signature: null == signature
+          ? _value.signature
+          : signature // ignore: cast_nullable_to_non_nullable
+              as String,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasskeyAssertionImpl implements _PasskeyAssertion {
+  const _$PasskeyAssertionImpl(
+      {required this.credentialId,
+      required this.authenticatorData,
+      required this.signature,
+      required this.userHandle});
+
+  @override
+  final String credentialId;
+  @override
+  final String authenticatorData;
+  @override
+  final String signature;
+  @override
+  final String userHandle;
+
+  @override
+  String toString() {
+    return 'PasskeyAssertion(credentialId: $credentialId, authenticatorData: $authenticatorData, signature: $signature, userHandle: $userHandle)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasskeyAssertionImpl &&
+            (identical(other.credentialId, credentialId) ||
+                other.credentialId == credentialId) &&
+            (identical(other.authenticatorData, authenticatorData) ||
+                other.authenticatorData == authenticatorData) &&
+            (identical(other.signature, signature) ||
+                other.signature == signature) &&
+            (identical(other.userHandle, userHandle) ||
+                other.userHandle == userHandle));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, credentialId, authenticatorData, signature, userHandle);
+
+  /// Create a copy of PasskeyAssertion
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasskeyAssertionImplCopyWith<_$PasskeyAssertionImpl> get copyWith =>
+      __$$PasskeyAssertionImplCopyWithImpl<_$PasskeyAssertionImpl>(
+          this, _$identity);
+}
+
+abstract class _PasskeyAssertion implements PasskeyAssertion {
+  const factory _PasskeyAssertion(
+      {required final String credentialId,
+      required final String authenticatorData,
+      required final String signature,
+      required final String userHandle}) = _$PasskeyAssertionImpl;
+
+  @override
+  String get credentialId;
+  @override
+  String get authenticatorData;
+  @override
+  String get signature;
+  @override
+  String get userHandle;
+
+  /// Create a copy of PasskeyAssertion
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasskeyAssertionImplCopyWith<_$PasskeyAssertionImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasskeyAssertionRequest {
+  String get rpId => throw _privateConstructorUsedError;
+  String get clientDataHash => throw _privateConstructorUsedError;
+  bool get userVerified => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasskeyAssertionRequest
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasskeyAssertionRequestCopyWith<PasskeyAssertionRequest> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasskeyAssertionRequestCopyWith<$Res> {
+  factory $PasskeyAssertionRequestCopyWith(
+          PasskeyAssertionRequest value,
+          $Res Function(PasskeyAssertionRequest) then) =
+      _$PasskeyAssertionRequestCopyWithImpl<$Res, PasskeyAssertionRequest>;
+  @useResult
+  $Res call({String rpId, String clientDataHash, bool userVerified});
+}
+
+/// @nodoc
+class _$PasskeyAssertionRequestCopyWithImpl<$Res,
+        $Val extends PasskeyAssertionRequest>
+    implements $PasskeyAssertionRequestCopyWith<$Res> {
+  _$PasskeyAssertionRequestCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasskeyAssertionRequest
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? rpId = null,
+    Object? clientDataHash = null,
+    Object? userVerified = null,
+  }) {
+    return _then(_value.copyWith(
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      clientDataHash: null == clientDataHash
+          ? _value.clientDataHash
+          : clientDataHash // ignore: cast_nullable_to_non_nullable
+              as String,
+      userVerified: null == userVerified
+          ? _value.userVerified
+          : userVerified // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasskeyAssertionRequestImplCopyWith<$Res>
+    implements $PasskeyAssertionRequestCopyWith<$Res> {
+  factory _$$PasskeyAssertionRequestImplCopyWith(
+          _$PasskeyAssertionRequestImpl value,
+          $Res Function(_$PasskeyAssertionRequestImpl) then) =
+      __$$PasskeyAssertionRequestImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String rpId, String clientDataHash, bool userVerified});
+}
+
+/// @nodoc
+class __$$PasskeyAssertionRequestImplCopyWithImpl<$Res>
+    extends _$PasskeyAssertionRequestCopyWithImpl<$Res,
+        _$PasskeyAssertionRequestImpl>
+    implements _$$PasskeyAssertionRequestImplCopyWith<$Res> {
+  __$$PasskeyAssertionRequestImplCopyWithImpl(
+      _$PasskeyAssertionRequestImpl _value,
+      $Res Function(_$PasskeyAssertionRequestImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasskeyAssertionRequest
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? rpId = null,
+    Object? clientDataHash = null,
+    Object? userVerified = null,
+  }) {
+    return _then(_$PasskeyAssertionRequestImpl(
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      clientDataHash: null == clientDataHash
+          ? _value.clientDataHash
+          : clientDataHash // ignore: cast_nullable_to_non_nullable
+              as String,
+      userVerified: null == userVerified
+          ? _value.userVerified
+          : userVerified // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasskeyAssertionRequestImpl implements _PasskeyAssertionRequest {
+  const _$PasskeyAssertionRequestImpl(
+      {required this.rpId,
+      required this.clientDataHash,
+      required this.userVerified});
+
+  @override
+  final String rpId;
+  @override
+  final String clientDataHash;
+  @override
+  final bool userVerified;
+
+  @override
+  String toString() {
+    return 'PasskeyAssertionRequest(rpId: $rpId, clientDataHash: $clientDataHash, userVerified: $userVerified)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasskeyAssertionRequestImpl &&
+            (identical(other.rpId, rpId) || other.rpId == rpId) &&
+            (identical(other.clientDataHash, clientDataHash) ||
+                other.clientDataHash == clientDataHash) &&
+            (identical(other.userVerified, userVerified) ||
+                other.userVerified == userVerified));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, rpId, clientDataHash, userVerified);
+
+  /// Create a copy of PasskeyAssertionRequest
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasskeyAssertionRequestImplCopyWith<
+          _$PasskeyAssertionRequestImpl>
+      get copyWith => __$$PasskeyAssertionRequestImplCopyWithImpl<
+          _$PasskeyAssertionRequestImpl>(this, _$identity);
+}
+
+abstract class _PasskeyAssertionRequest implements PasskeyAssertionRequest {
+  const factory _PasskeyAssertionRequest(
+      {required final String rpId,
+      required final String clientDataHash,
+      required final bool userVerified}) = _$PasskeyAssertionRequestImpl;
+
+  @override
+  String get rpId;
+  @override
+  String get clientDataHash;
+  @override
+  bool get userVerified;
+
+  /// Create a copy of PasskeyAssertionRequest
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasskeyAssertionRequestImplCopyWith<
+          _$PasskeyAssertionRequestImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasskeyCreation {
+  String get rpId => throw _privateConstructorUsedError;
+  String? get rpName => throw _privateConstructorUsedError;
+  String get userHandle => throw _privateConstructorUsedError;
+  String? get userName => throw _privateConstructorUsedError;
+  String? get userDisplayName => throw _privateConstructorUsedError;
+  String get clientDataHash => throw _privateConstructorUsedError;
+  Int64List get algorithms => throw _privateConstructorUsedError;
+  bool get userVerified => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasskeyCreation
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasskeyCreationCopyWith<PasskeyCreation> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasskeyCreationCopyWith<$Res> {
+  factory $PasskeyCreationCopyWith(
+          PasskeyCreation value, $Res Function(PasskeyCreation) then) =
+      _$PasskeyCreationCopyWithImpl<$Res, PasskeyCreation>;
+  @useResult
+  $Res call(
+      {String rpId,
+      String? rpName,
+      String userHandle,
+      String? userName,
+      String? userDisplayName,
+      String clientDataHash,
+      Int64List algorithms,
+      bool userVerified});
+}
+
+/// @nodoc
+class _$PasskeyCreationCopyWithImpl<$Res, $Val extends PasskeyCreation>
+    implements $PasskeyCreationCopyWith<$Res> {
+  _$PasskeyCreationCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasskeyCreation
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? rpId = null,
+    Object? rpName = freezed,
+    Object? userHandle = null,
+    Object? userName = freezed,
+    Object? userDisplayName = freezed,
+    Object? clientDataHash = null,
+    Object? algorithms = null,
+    Object? userVerified = null,
+  }) {
+    return _then(_value.copyWith(
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      rpName: freezed == rpName
+          ? _value.rpName
+          : rpName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+      userName: freezed == userName
+          ? _value.userName
+          : userName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userDisplayName: freezed == userDisplayName
+          ? _value.userDisplayName
+          : userDisplayName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      clientDataHash: null == clientDataHash
+          ? _value.clientDataHash
+          : clientDataHash // ignore: cast_nullable_to_non_nullable
+              as String,
+      algorithms: null == algorithms
+          ? _value.algorithms
+          : algorithms // ignore: cast_nullable_to_non_nullable
+              as Int64List,
+      userVerified: null == userVerified
+          ? _value.userVerified
+          : userVerified // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasskeyCreationImplCopyWith<$Res>
+    implements $PasskeyCreationCopyWith<$Res> {
+  factory _$$PasskeyCreationImplCopyWith(
+          _$PasskeyCreationImpl value,
+          $Res Function(_$PasskeyCreationImpl) then) =
+      __$$PasskeyCreationImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String rpId,
+      String? rpName,
+      String userHandle,
+      String? userName,
+      String? userDisplayName,
+      String clientDataHash,
+      Int64List algorithms,
+      bool userVerified});
+}
+
+/// @nodoc
+class __$$PasskeyCreationImplCopyWithImpl<$Res>
+    extends _$PasskeyCreationCopyWithImpl<$Res, _$PasskeyCreationImpl>
+    implements _$$PasskeyCreationImplCopyWith<$Res> {
+  __$$PasskeyCreationImplCopyWithImpl(
+      _$PasskeyCreationImpl _value, $Res Function(_$PasskeyCreationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasskeyCreation
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? rpId = null,
+    Object? rpName = freezed,
+    Object? userHandle = null,
+    Object? userName = freezed,
+    Object? userDisplayName = freezed,
+    Object? clientDataHash = null,
+    Object? algorithms = null,
+    Object? userVerified = null,
+  }) {
+    return _then(_$PasskeyCreationImpl(
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      rpName: freezed == rpName
+          ? _value.rpName
+          : rpName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+      userName: freezed == userName
+          ? _value.userName
+          : userName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userDisplayName: freezed == userDisplayName
+          ? _value.userDisplayName
+          : userDisplayName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      clientDataHash: null == clientDataHash
+          ? _value.clientDataHash
+          : clientDataHash // ignore: cast_nullable_to_non_nullable
+              as String,
+      algorithms: null == algorithms
+          ? _value.algorithms
+          : algorithms // ignore: cast_nullable_to_non_nullable
+              as Int64List,
+      userVerified: null == userVerified
+          ? _value.userVerified
+          : userVerified // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasskeyCreationImpl implements _PasskeyCreation {
+  const _$PasskeyCreationImpl(
+      {required this.rpId,
+      this.rpName,
+      required this.userHandle,
+      this.userName,
+      this.userDisplayName,
+      required this.clientDataHash,
+      required this.algorithms,
+      required this.userVerified});
+
+  @override
+  final String rpId;
+  @override
+  final String? rpName;
+  @override
+  final String userHandle;
+  @override
+  final String? userName;
+  @override
+  final String? userDisplayName;
+  @override
+  final String clientDataHash;
+  @override
+  final Int64List algorithms;
+  @override
+  final bool userVerified;
+
+  @override
+  String toString() {
+    return 'PasskeyCreation(rpId: $rpId, rpName: $rpName, userHandle: $userHandle, userName: $userName, userDisplayName: $userDisplayName, clientDataHash: $clientDataHash, algorithms: $algorithms, userVerified: $userVerified)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasskeyCreationImpl &&
+            (identical(other.rpId, rpId) || other.rpId == rpId) &&
+            (identical(other.rpName, rpName) || other.rpName == rpName) &&
+            (identical(other.userHandle, userHandle) ||
+                other.userHandle == userHandle) &&
+            (identical(other.userName, userName) ||
+                other.userName == userName) &&
+            (identical(other.userDisplayName, userDisplayName) ||
+                other.userDisplayName == userDisplayName) &&
+            (identical(other.clientDataHash, clientDataHash) ||
+                other.clientDataHash == clientDataHash) &&
+            (identical(other.algorithms, algorithms) ||
+                other.algorithms == algorithms) &&
+            (identical(other.userVerified, userVerified) ||
+                other.userVerified == userVerified));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, rpId, rpName, userHandle,
+      userName, userDisplayName, clientDataHash, algorithms, userVerified);
+
+  /// Create a copy of PasskeyCreation
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasskeyCreationImplCopyWith<_$PasskeyCreationImpl> get copyWith =>
+      __$$PasskeyCreationImplCopyWithImpl<_$PasskeyCreationImpl>(
+          this, _$identity);
+}
+
+abstract class _PasskeyCreation implements PasskeyCreation {
+  const factory _PasskeyCreation(
+      {required final String rpId,
+      final String? rpName,
+      required final String userHandle,
+      final String? userName,
+      final String? userDisplayName,
+      required final String clientDataHash,
+      required final Int64List algorithms,
+      required final bool userVerified}) = _$PasskeyCreationImpl;
+
+  @override
+  String get rpId;
+  @override
+  String? get rpName;
+  @override
+  String get userHandle;
+  @override
+  String? get userName;
+  @override
+  String? get userDisplayName;
+  @override
+  String get clientDataHash;
+  @override
+  Int64List get algorithms;
+  @override
+  bool get userVerified;
+
+  /// Create a copy of PasskeyCreation
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasskeyCreationImplCopyWith<_$PasskeyCreationImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasskeyRegistration {
+  Passkey get passkey => throw _privateConstructorUsedError;
+  String get credentialId => throw _privateConstructorUsedError;
+  String get attestationObject => throw _privateConstructorUsedError;
+  String get authenticatorData => throw _privateConstructorUsedError;
+  String get publicKey => throw _privateConstructorUsedError;
+  int get publicKeyAlgorithm => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasskeyRegistrationCopyWith<PasskeyRegistration> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasskeyRegistrationCopyWith<$Res> {
+  factory $PasskeyRegistrationCopyWith(
+          PasskeyRegistration value, $Res Function(PasskeyRegistration) then) =
+      _$PasskeyRegistrationCopyWithImpl<$Res, PasskeyRegistration>;
+  @useResult
+  $Res call(
+      {Passkey passkey,
+      String credentialId,
+      String attestationObject,
+      String authenticatorData,
+      String publicKey,
+      int publicKeyAlgorithm});
+
+  $PasskeyCopyWith<$Res> get passkey;
+}
+
+/// @nodoc
+class _$PasskeyRegistrationCopyWithImpl<$Res, $Val extends PasskeyRegistration>
+    implements $PasskeyRegistrationCopyWith<$Res> {
+  _$PasskeyRegistrationCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? passkey = null,
+    Object? credentialId = null,
+    Object? attestationObject = null,
+    Object? authenticatorData = null,
+    Object? publicKey = null,
+    Object? publicKeyAlgorithm = null,
+  }) {
+    return _then(_value.copyWith(
+      passkey: null == passkey
+          ? _value.passkey
+          : passkey // ignore: cast_nullable_to_non_nullable
+              as Passkey,
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      attestationObject: null == attestationObject
+          ? _value.attestationObject
+          : attestationObject // ignore: cast_nullable_to_non_nullable
+              as String,
+      authenticatorData: null == authenticatorData
+          ? _value.authenticatorData
+          : authenticatorData // ignore: cast_nullable_to_non_nullable
+              as String,
+      publicKey: null == publicKey
+          ? _value.publicKey
+          : publicKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      publicKeyAlgorithm: null == publicKeyAlgorithm
+          ? _value.publicKeyAlgorithm
+          : publicKeyAlgorithm // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PasskeyCopyWith<$Res> get passkey {
+    return $PasskeyCopyWith<$Res>(_value.passkey, (value) {
+      return _then(_value.copyWith(passkey: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$PasskeyRegistrationImplCopyWith<$Res>
+    implements $PasskeyRegistrationCopyWith<$Res> {
+  factory _$$PasskeyRegistrationImplCopyWith(
+          _$PasskeyRegistrationImpl value,
+          $Res Function(_$PasskeyRegistrationImpl) then) =
+      __$$PasskeyRegistrationImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {Passkey passkey,
+      String credentialId,
+      String attestationObject,
+      String authenticatorData,
+      String publicKey,
+      int publicKeyAlgorithm});
+
+  @override
+  $PasskeyCopyWith<$Res> get passkey;
+}
+
+/// @nodoc
+class __$$PasskeyRegistrationImplCopyWithImpl<$Res>
+    extends _$PasskeyRegistrationCopyWithImpl<$Res, _$PasskeyRegistrationImpl>
+    implements _$$PasskeyRegistrationImplCopyWith<$Res> {
+  __$$PasskeyRegistrationImplCopyWithImpl(
+      _$PasskeyRegistrationImpl _value,
+      $Res Function(_$PasskeyRegistrationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? passkey = null,
+    Object? credentialId = null,
+    Object? attestationObject = null,
+    Object? authenticatorData = null,
+    Object? publicKey = null,
+    Object? publicKeyAlgorithm = null,
+  }) {
+    return _then(_$PasskeyRegistrationImpl(
+      passkey: null == passkey
+          ? _value.passkey
+          : passkey // ignore: cast_nullable_to_non_nullable
+              as Passkey,
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      attestationObject: null == attestationObject
+          ? _value.attestationObject
+          : attestationObject // ignore: cast_nullable_to_non_nullable
+              as String,
+      authenticatorData: null == authenticatorData
+          ? _value.authenticatorData
+          : authenticatorData // ignore: cast_nullable_to_non_nullable
+              as String,
+      publicKey: null == publicKey
+          ? _value.publicKey
+          : publicKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      publicKeyAlgorithm: null == publicKeyAlgorithm
+          ? _value.publicKeyAlgorithm
+          : publicKeyAlgorithm // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasskeyRegistrationImpl implements _PasskeyRegistration {
+  const _$PasskeyRegistrationImpl(
+      {required this.passkey,
+      required this.credentialId,
+      required this.attestationObject,
+      required this.authenticatorData,
+      required this.publicKey,
+      required this.publicKeyAlgorithm});
+
+  @override
+  final Passkey passkey;
+  @override
+  final String credentialId;
+  @override
+  final String attestationObject;
+  @override
+  final String authenticatorData;
+  @override
+  final String publicKey;
+  @override
+  final int publicKeyAlgorithm;
+
+  @override
+  String toString() {
+    return 'PasskeyRegistration(passkey: $passkey, credentialId: $credentialId, attestationObject: $attestationObject, authenticatorData: $authenticatorData, publicKey: $publicKey, publicKeyAlgorithm: $publicKeyAlgorithm)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasskeyRegistrationImpl &&
+            (identical(other.passkey, passkey) || other.passkey == passkey) &&
+            (identical(other.credentialId, credentialId) ||
+                other.credentialId == credentialId) &&
+            (identical(other.attestationObject, attestationObject) ||
+                other.attestationObject == attestationObject) &&
+            (identical(other.authenticatorData, authenticatorData) ||
+                other.authenticatorData == authenticatorData) &&
+            (identical(other.publicKey, publicKey) ||
+                other.publicKey == publicKey) &&
+            (identical(other.publicKeyAlgorithm, publicKeyAlgorithm) ||
+                other.publicKeyAlgorithm == publicKeyAlgorithm));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, passkey, credentialId,
+      attestationObject, authenticatorData, publicKey, publicKeyAlgorithm);
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasskeyRegistrationImplCopyWith<_$PasskeyRegistrationImpl> get copyWith =>
+      __$$PasskeyRegistrationImplCopyWithImpl<_$PasskeyRegistrationImpl>(
+          this, _$identity);
+}
+
+abstract class _PasskeyRegistration implements PasskeyRegistration {
+  const factory _PasskeyRegistration(
+      {required final Passkey passkey,
+      required final String credentialId,
+      required final String attestationObject,
+      required final String authenticatorData,
+      required final String publicKey,
+      required final int publicKeyAlgorithm}) = _$PasskeyRegistrationImpl;
+
+  @override
+  Passkey get passkey;
+  @override
+  String get credentialId;
+  @override
+  String get attestationObject;
+  @override
+  String get authenticatorData;
+  @override
+  String get publicKey;
+  @override
+  int get publicKeyAlgorithm;
+
+  /// Create a copy of PasskeyRegistration
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasskeyRegistrationImplCopyWith<_$PasskeyRegistrationImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'password_policy.freezed.dart';
+
+/// Rules account passwords must follow, set by the server admin.
+@freezed
+class PasswordPolicy with _$PasswordPolicy {
+  const factory PasswordPolicy({
+    required int minLength,
+    required int maxLength,
+    required bool requireUppercase,
+    required bool requireLowercase,
+    required bool requireDigit,
+    required bool requireSymbol,
+    required int minScore,
+    required bool allowUnicode,
+    required List<String> bannedWords,
+  }) = _PasswordPolicy;
+}
+
+@freezed
+sealed class PasswordRule with _$PasswordRule {
+  const PasswordRule._();
+
+  const factory PasswordRule.tooShort({
+    required int minLength,
+  }) = PasswordRule_TooShort;
+  const factory PasswordRule.tooLong({
+    required int maxLength,
+  }) = PasswordRule_TooLong;
+  const factory PasswordRule.missingUppercase() = PasswordRule_MissingUppercase;
+  const factory PasswordRule.missingLowercase() = PasswordRule_MissingLowercase;
+  const factory PasswordRule.missingDigit() = PasswordRule_MissingDigit;
+  const factory PasswordRule.missingSymbol() = PasswordRule_MissingSymbol;
+  const factory PasswordRule.tooWeak({
+    required int score,
+    required int minScore,
+  }) = PasswordRule_TooWeak;
+  const factory PasswordRule.unicodeNotAllowed() =
+      PasswordRule_UnicodeNotAllowed;
+  const factory PasswordRule.bannedWord({
+    required String word,
+  }) = PasswordRule_BannedWord;
+}
@@ -0,0 +1,1996 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'password_policy.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$PasswordPolicy {
+  int get minLength => throw _privateConstructorUsedError;
+  int get maxLength => throw _privateConstructorUsedError;
+  bool get requireUppercase => throw _privateConstructorUsedError;
+  bool get requireLowercase => throw _privateConstructorUsedError;
+  bool get requireDigit => throw _privateConstructorUsedError;
+  bool get requireSymbol => throw _privateConstructorUsedError;
+  int get minScore => throw _privateConstructorUsedError;
+  bool get allowUnicode => throw _privateConstructorUsedError;
+  List<String> get bannedWords => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasswordPolicyCopyWith<PasswordPolicy> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordPolicyCopyWith<$Res> {
+  factory $PasswordPolicyCopyWith(
+          PasswordPolicy value, $Res Function(PasswordPolicy) then) =
+      _$PasswordPolicyCopyWithImpl<$Res, PasswordPolicy>;
+  @useResult
+  $Res call(
+      {int minLength,
+      int maxLength,
+      bool requireUppercase,
+      bool requireLowercase,
+      bool requireDigit,
+      bool requireSymbol,
+      int minScore,
+      bool allowUnicode,
+      List<String> bannedWords});
+}
+
+/// @nodoc
+class _$PasswordPolicyCopyWithImpl<$Res, $Val extends PasswordPolicy>
+    implements $PasswordPolicyCopyWith<$Res> {
+  _$PasswordPolicyCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minLength = null,
+    Object? maxLength = null,
+    Object? requireUppercase = null,
+    Object? requireLowercase = null,
+    Object? requireDigit = null,
+    Object? requireSymbol = null,
+    Object? minScore = null,
+    Object? allowUnicode = null,
+    Object? bannedWords = null,
+  }) {
+    return _then(_value.copyWith(
+      minLength: null == minLength
+          ? _value.minLength
+          : minLength // ignore: cast_nullable_to_non_nullable
+              as int,
+      maxLength: null == maxLength
+          ? _value.maxLength
+          : maxLength // ignore: cast_nullable_to_non_nullable
+              as int,
+      requireUppercase: null == requireUppercase
+          ? _value.requireUppercase
+          : requireUppercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireLowercase: null == requireLowercase
+          ? _value.requireLowercase
+          : requireLowercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireDigit: null == requireDigit
+          ? _value.requireDigit
+          : requireDigit // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireSymbol: null == requireSymbol
+          ? _value.requireSymbol
+          : requireSymbol // ignore: cast_nullable_to_non_nullable
+              as bool,
+      minScore: null == minScore
+          ? _value.minScore
+          : minScore // ignore: cast_nullable_to_non_nullable
+              as int,
+      allowUnicode: null == allowUnicode
+          ? _value.allowUnicode
+          : allowUnicode // ignore: cast_nullable_to_non_nullable
+              as bool,
+      bannedWords: null == bannedWords
+          ? _value.bannedWords
+          : bannedWords // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasswordPolicyImplCopyWith<$Res>
+    implements $PasswordPolicyCopyWith<$Res> {
+  factory _$$PasswordPolicyImplCopyWith(
+          _$PasswordPolicyImpl value,
+          $Res Function(_$PasswordPolicyImpl) then) =
+      __$$PasswordPolicyImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {int minLength,
+      int maxLength,
+      bool requireUppercase,
+      bool requireLowercase,
+      bool requireDigit,
+      bool requireSymbol,
+      int minScore,
+      bool allowUnicode,
+      List<String> bannedWords});
+}
+
+/// @nodoc
+class __$$PasswordPolicyImplCopyWithImpl<$Res>
+    extends _$PasswordPolicyCopyWithImpl<$Res, _$PasswordPolicyImpl>
+    implements _$$PasswordPolicyImplCopyWith<$Res> {
+  __$$PasswordPolicyImplCopyWithImpl(
+      _$PasswordPolicyImpl _value, $Res Function(_$PasswordPolicyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minLength = null,
+    Object? maxLength = null,
+    Object? requireUppercase = null,
+    Object? requireLowercase = null,
+    Object? requireDigit = null,
+    Object? requireSymbol = null,
+    Object? minScore = null,
+    Object? allowUnicode = null,
+    Object? bannedWords = null,
+  }) {
+    return _then(_$PasswordPolicyImpl(
+      minLength: null == minLength
+          ? _value.minLength
+          : minLength // ignore: cast_nullable_to_non_nullable
+              as int,
+      maxLength: null == maxLength
+          ? _value.maxLength
+          : maxLength // ignore: cast_nullable_to_non_nullable
+              as int,
+      requireUppercase: null == requireUppercase
+          ? _value.requireUppercase
+          : requireUppercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireLowercase: null == requireLowercase
+          ? _value.requireLowercase
+          : requireLowercase // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireDigit: null == requireDigit
+          ? _value.requireDigit
+          : requireDigit // ignore: cast_nullable_to_non_nullable
+              as bool,
+      requireSymbol: null == requireSymbol
+          ? _value.requireSymbol
+          : requireSymbol // ignore: cast_nullable_to_non_nullable
+              as bool,
+      minScore: null == minScore
+          ? _value.minScore
+          : minScore // ignore: cast_nullable_to_non_nullable
+              as int,
+      allowUnicode: null == allowUnicode
+          ? _value.allowUnicode
+          : allowUnicode // ignore: cast_nullable_to_non_nullable
+              as bool,
+      bannedWords: null == bannedWords
+          ? _value._bannedWords
+          : bannedWords // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordPolicyImpl implements _PasswordPolicy {
+  const _$PasswordPolicyImpl(
+      {required this.minLength,
+      required this.maxLength,
+      required this.requireUppercase,
+      required this.requireLowercase,
+      required this.requireDigit,
+      required this.requireSymbol,
+      required this.minScore,
+      required this.allowUnicode,
+      required final List<String> bannedWords})
+      : _bannedWords = bannedWords;
+
+  @override
+  final int minLength;
+  @override
+  final int maxLength;
+  @override
+  final bool requireUppercase;
+  @override
+  final bool requireLowercase;
+  @override
+  final bool requireDigit;
+  @override
+  final bool requireSymbol;
+  @override
+  final int minScore;
+  @override
+  final bool allowUnicode;
+  final List<String> _bannedWords;
+  @override
+  List<String> get bannedWords {
+    if (_bannedWords is EqualUnmodifiableListView) return _bannedWords;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_bannedWords);
+  }
+
+  @override
+  String toString() {
+    return 'PasswordPolicy(minLength: $minLength, maxLength: $maxLength, requireUppercase: $requireUppercase, requireLowercase: $requireLowercase, requireDigit: $requireDigit, requireSymbol: $requireSymbol, minScore: $minScore, allowUnicode: $allowUnicode, bannedWords: $bannedWords)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordPolicyImpl &&
+            (identical(other.minLength, minLength) ||
+                other.minLength == minLength) &&
+            (identical(other.maxLength, maxLength) ||
+                other.maxLength == maxLength) &&
+            (identical(other.requireUppercase, requireUppercase) ||
+                other.requireUppercase == requireUppercase) &&
+            (identical(other.requireLowercase, requireLowercase) ||
+                other.requireLowercase == requireLowercase) &&
+            (identical(other.requireDigit, requireDigit) ||
+                other.requireDigit == requireDigit) &&
+            (identical(other.requireSymbol, requireSymbol) ||
+                other.requireSymbol == requireSymbol) &&
+            (identical(other.minScore, minScore) ||
+                other.minScore == minScore) &&
+            (identical(other.allowUnicode, allowUnicode) ||
+                other.allowUnicode == allowUnicode) &&
+            const DeepCollectionEquality()
+                .equals(other._bannedWords, _bannedWords));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      minLength,
+      maxLength,
+      requireUppercase,
+      requireLowercase,
+      requireDigit,
+      requireSymbol,
+      minScore,
+      allowUnicode,
+      const DeepCollectionEquality().hash(_bannedWords));
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordPolicyImplCopyWith<_$PasswordPolicyImpl> get copyWith =>
+      __$$PasswordPolicyImplCopyWithImpl<_$PasswordPolicyImpl>(
+          this, _$identity);
+}
+
+abstract class _PasswordPolicy implements PasswordPolicy {
+  const factory _PasswordPolicy(
+      {required final int minLength,
+      required final int maxLength,
+      required final bool requireUppercase,
+      required final bool requireLowercase,
+      required final bool requireDigit,
+      required final bool requireSymbol,
+      required final int minScore,
+      required final bool allowUnicode,
+      required final List<String> bannedWords}) = _$PasswordPolicyImpl;
+
+  @override
+  int get minLength;
+  @override
+  int get maxLength;
+  @override
+  bool get requireUppercase;
+  @override
+  bool get requireLowercase;
+  @override
+  bool get requireDigit;
+  @override
+  bool get requireSymbol;
+  @override
+  int get minScore;
+  @override
+  bool get allowUnicode;
+  @override
+  List<String> get bannedWords;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordPolicyImplCopyWith<_$PasswordPolicyImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasswordRule {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordRuleCopyWith<$Res> {
+  factory $PasswordRuleCopyWith(
+          PasswordRule value, $Res Function(PasswordRule) then) =
+      _$PasswordRuleCopyWithImpl<$Res, PasswordRule>;
+}
+
+/// @nodoc
+class _$PasswordRuleCopyWithImpl<$Res, $Val extends PasswordRule>
+    implements $PasswordRuleCopyWith<$Res> {
+  _$PasswordRuleCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$PasswordRule_TooShortImplCopyWith<$Res> {
+  factory _$$PasswordRule_TooShortImplCopyWith(
+          _$PasswordRule_TooShortImpl value,
+          $Res Function(_$PasswordRule_TooShortImpl) then) =
+      __$$PasswordRule_TooShortImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int minLength});
+}
+
+/// @nodoc
+class __$$PasswordRule_TooShortImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_TooShortImpl>
+    implements _$$PasswordRule_TooShortImplCopyWith<$Res> {
+  __$$PasswordRule_TooShortImplCopyWithImpl(
+      _$PasswordRule_TooShortImpl _value,
+      $Res Function(_$PasswordRule_TooShortImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minLength = null,
+  }) {
+    return _then(_$PasswordRule_TooShortImpl(
+      minLength: null == minLength
+          ? _value.minLength
+          : minLength // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordRule_TooShortImpl extends PasswordRule_TooShort {
+  const _$PasswordRule_TooShortImpl({required this.minLength}) : super._();
+
+  @override
+  final int minLength;
+
+  @override
+  String toString() {
+    return 'PasswordRule.tooShort(minLength: $minLength)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_TooShortImpl &&
+            (identical(other.minLength, minLength) ||
+                other.minLength == minLength));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, minLength);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordRule_TooShortImplCopyWith<
+          _$PasswordRule_TooShortImpl>
+      get copyWith => __$$PasswordRule_TooShortImplCopyWithImpl<
+          _$PasswordRule_TooShortImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return tooShort(minLength);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return tooShort?.call(minLength);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooShort != null) {
+      return tooShort(minLength);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return tooShort(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return tooShort?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooShort != null) {
+      return tooShort(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_TooShort extends PasswordRule {
+  const factory PasswordRule_TooShort({required final int minLength}) =
+      _$PasswordRule_TooShortImpl;
+  const PasswordRule_TooShort._() : super._();
+
+  int get minLength;
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordRule_TooShortImplCopyWith<
+          _$PasswordRule_TooShortImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$PasswordRule_TooLongImplCopyWith<$Res> {
+  factory _$$PasswordRule_TooLongImplCopyWith(
+          _$PasswordRule_TooLongImpl value,
+          $Res Function(_$PasswordRule_TooLongImpl) then) =
+      __$$PasswordRule_TooLongImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int maxLength});
+}
+
+/// @nodoc
+class __$$PasswordRule_TooLongImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_TooLongImpl>
+    implements _$$PasswordRule_TooLongImplCopyWith<$Res> {
+  __$$PasswordRule_TooLongImplCopyWithImpl(
+      _$PasswordRule_TooLongImpl _value,
+      $Res Function(_$PasswordRule_TooLongImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? maxLength = null,
+  }) {
+    return _then(_$PasswordRule_TooLongImpl(
+      maxLength: null == maxLength
+          ? _value.maxLength
+          : maxLength // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordRule_TooLongImpl extends PasswordRule_TooLong {
+  const _$PasswordRule_TooLongImpl({required this.maxLength}) : super._();
+
+  @override
+  final int maxLength;
+
+  @override
+  String toString() {
+    return 'PasswordRule.tooLong(maxLength: $maxLength)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_TooLongImpl &&
+            (identical(other.maxLength, maxLength) ||
+                other.maxLength == maxLength));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, maxLength);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordRule_TooLongImplCopyWith<
+          _$PasswordRule_TooLongImpl>
+      get copyWith => __$$PasswordRule_TooLongImplCopyWithImpl<
+          _$PasswordRule_TooLongImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return tooLong(maxLength);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return tooLong?.call(maxLength);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooLong != null) {
+      return tooLong(maxLength);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return tooLong(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return tooLong?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooLong != null) {
+      return tooLong(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_TooLong extends PasswordRule {
+  const factory PasswordRule_TooLong({required final int maxLength}) =
+      _$PasswordRule_TooLongImpl;
+  const PasswordRule_TooLong._() : super._();
+
+  int get maxLength;
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordRule_TooLongImplCopyWith<
+          _$PasswordRule_TooLongImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$PasswordRule_MissingUppercaseImplCopyWith<$Res> {
+  factory _$$PasswordRule_MissingUppercaseImplCopyWith(
+          _$PasswordRule_MissingUppercaseImpl value,
+          $Res Function(_$PasswordRule_MissingUppercaseImpl) then) =
+      __$$PasswordRule_MissingUppercaseImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PasswordRule_MissingUppercaseImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res,
+        _$PasswordRule_MissingUppercaseImpl>
+    implements _$$PasswordRule_MissingUppercaseImplCopyWith<$Res> {
+  __$$PasswordRule_MissingUppercaseImplCopyWithImpl(
+      _$PasswordRule_MissingUppercaseImpl _value,
+      $Res Function(_$PasswordRule_MissingUppercaseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PasswordRule_MissingUppercaseImpl
+    extends PasswordRule_MissingUppercase {
+  const _$PasswordRule_MissingUppercaseImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PasswordRule.missingUppercase()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_MissingUppercaseImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return missingUppercase();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return missingUppercase?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingUppercase != null) {
+      return missingUppercase();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return missingUppercase(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return missingUppercase?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingUppercase != null) {
+      return missingUppercase(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_MissingUppercase extends PasswordRule {
+  const factory PasswordRule_MissingUppercase() =
+      _$PasswordRule_MissingUppercaseImpl;
+  const PasswordRule_MissingUppercase._() : super._();
+}
+/// @nodoc
+abstract class _$$PasswordRule_MissingLowercaseImplCopyWith<$Res> {
+  factory _$$PasswordRule_MissingLowercaseImplCopyWith(
+          _$PasswordRule_MissingLowercaseImpl value,
+          $Res Function(_$PasswordRule_MissingLowercaseImpl) then) =
+      __$$PasswordRule_MissingLowercaseImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PasswordRule_MissingLowercaseImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res,
+        _$PasswordRule_MissingLowercaseImpl>
+    implements _$$PasswordRule_MissingLowercaseImplCopyWith<$Res> {
+  __$$PasswordRule_MissingLowercaseImplCopyWithImpl(
+      _$PasswordRule_MissingLowercaseImpl _value,
+      $Res Function(_$PasswordRule_MissingLowercaseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PasswordRule_MissingLowercaseImpl
+    extends PasswordRule_MissingLowercase {
+  const _$PasswordRule_MissingLowercaseImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PasswordRule.missingLowercase()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_MissingLowercaseImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return missingLowercase();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return missingLowercase?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingLowercase != null) {
+      return missingLowercase();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return missingLowercase(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return missingLowercase?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingLowercase != null) {
+      return missingLowercase(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_MissingLowercase extends PasswordRule {
+  const factory PasswordRule_MissingLowercase() =
+      _$PasswordRule_MissingLowercaseImpl;
+  const PasswordRule_MissingLowercase._() : super._();
+}
+/// @nodoc
+abstract class _$$PasswordRule_MissingDigitImplCopyWith<$Res> {
+  factory _$$PasswordRule_MissingDigitImplCopyWith(
+          _$PasswordRule_MissingDigitImpl value,
+          $Res Function(_$PasswordRule_MissingDigitImpl) then) =
+      __$$PasswordRule_MissingDigitImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PasswordRule_MissingDigitImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_MissingDigitImpl>
+    implements _$$PasswordRule_MissingDigitImplCopyWith<$Res> {
+  __$$PasswordRule_MissingDigitImplCopyWithImpl(
+      _$PasswordRule_MissingDigitImpl _value,
+      $Res Function(_$PasswordRule_MissingDigitImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PasswordRule_MissingDigitImpl extends PasswordRule_MissingDigit {
+  const _$PasswordRule_MissingDigitImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PasswordRule.missingDigit()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_MissingDigitImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return missingDigit();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return missingDigit?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingDigit != null) {
+      return missingDigit();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return missingDigit(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return missingDigit?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingDigit != null) {
+      return missingDigit(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_MissingDigit extends PasswordRule {
+  const factory PasswordRule_MissingDigit() = _$PasswordRule_MissingDigitImpl;
+  const PasswordRule_MissingDigit._() : super._();
+}
+/// @nodoc
+abstract class _$$PasswordRule_MissingSymbolImplCopyWith<$Res> {
+  factory _$$PasswordRule_MissingSymbolImplCopyWith(
+          _$PasswordRule_MissingSymbolImpl value,
+          $Res Function(_$PasswordRule_MissingSymbolImpl) then) =
+      __$$PasswordRule_MissingSymbolImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PasswordRule_MissingSymbolImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_MissingSymbolImpl>
+    implements _$$PasswordRule_MissingSymbolImplCopyWith<$Res> {
+  __$$PasswordRule_MissingSymbolImplCopyWithImpl(
+      _$PasswordRule_MissingSymbolImpl _value,
+      $Res Function(_$PasswordRule_MissingSymbolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PasswordRule_MissingSymbolImpl extends PasswordRule_MissingSymbol {
+  const _$PasswordRule_MissingSymbolImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PasswordRule.missingSymbol()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_MissingSymbolImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return missingSymbol();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return missingSymbol?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingSymbol != null) {
+      return missingSymbol();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return missingSymbol(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return missingSymbol?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (missingSymbol != null) {
+      return missingSymbol(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_MissingSymbol extends PasswordRule {
+  const factory PasswordRule_MissingSymbol() = _$PasswordRule_MissingSymbolImpl;
+  const PasswordRule_MissingSymbol._() : super._();
+}
+/// @nodoc
+abstract class _$$PasswordRule_TooWeakImplCopyWith<$Res> {
+  factory _$$PasswordRule_TooWeakImplCopyWith(
+          _$PasswordRule_TooWeakImpl value,
+          $Res Function(_$PasswordRule_TooWeakImpl) then) =
+      __$$PasswordRule_TooWeakImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int score, int minScore});
+}
+
+/// @nodoc
+class __$$PasswordRule_TooWeakImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_TooWeakImpl>
+    implements _$$PasswordRule_TooWeakImplCopyWith<$Res> {
+  __$$PasswordRule_TooWeakImplCopyWithImpl(
+      _$PasswordRule_TooWeakImpl _value,
+      $Res Function(_$PasswordRule_TooWeakImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? score = null,
+    Object? minScore = null,
+  }) {
+    return _then(_$PasswordRule_TooWeakImpl(
+      score: null == score
+          ? _value.score
+          : score // ignore: cast_nullable_to_non_nullable
+              as int,
+      minScore: null == minScore
+          ? _value.minScore
+          : minScore // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordRule_TooWeakImpl extends PasswordRule_TooWeak {
+  const _$PasswordRule_TooWeakImpl(
+      {required this.score, required this.minScore})
+      : super._();
+
+  @override
+  final int score;
+  @override
+  final int minScore;
+
+  @override
+  String toString() {
+    return 'PasswordRule.tooWeak(score: $score, minScore: $minScore)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_TooWeakImpl &&
+            (identical(other.score, score) || other.score == score) &&
+            (identical(other.minScore, minScore) ||
+                other.minScore == minScore));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, score, minScore);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordRule_TooWeakImplCopyWith<
+          _$PasswordRule_TooWeakImpl>
+      get copyWith => __$$PasswordRule_TooWeakImplCopyWithImpl<
+          _$PasswordRule_TooWeakImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return tooWeak(score, minScore);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return tooWeak?.call(score, minScore);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooWeak != null) {
+      return tooWeak(score, minScore);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return tooWeak(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return tooWeak?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (tooWeak != null) {
+      return tooWeak(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_TooWeak extends PasswordRule {
+  const factory PasswordRule_TooWeak(
+      {required final int score,
+      required final int minScore}) = _$PasswordRule_TooWeakImpl;
+  const PasswordRule_TooWeak._() : super._();
+
+  int get score;
+  int get minScore;
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordRule_TooWeakImplCopyWith<
+          _$PasswordRule_TooWeakImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$PasswordRule_UnicodeNotAllowedImplCopyWith<$Res> {
+  factory _$$PasswordRule_UnicodeNotAllowedImplCopyWith(
+          _$PasswordRule_UnicodeNotAllowedImpl value,
+          $Res Function(_$PasswordRule_UnicodeNotAllowedImpl) then) =
+      __$$PasswordRule_UnicodeNotAllowedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PasswordRule_UnicodeNotAllowedImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res,
+        _$PasswordRule_UnicodeNotAllowedImpl>
+    implements _$$PasswordRule_UnicodeNotAllowedImplCopyWith<$Res> {
+  __$$PasswordRule_UnicodeNotAllowedImplCopyWithImpl(
+      _$PasswordRule_UnicodeNotAllowedImpl _value,
+      $Res Function(_$PasswordRule_UnicodeNotAllowedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PasswordRule_UnicodeNotAllowedImpl
+    extends PasswordRule_UnicodeNotAllowed {
+  const _$PasswordRule_UnicodeNotAllowedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PasswordRule.unicodeNotAllowed()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_UnicodeNotAllowedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return unicodeNotAllowed();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return unicodeNotAllowed?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (unicodeNotAllowed != null) {
+      return unicodeNotAllowed();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return unicodeNotAllowed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return unicodeNotAllowed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (unicodeNotAllowed != null) {
+      return unicodeNotAllowed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_UnicodeNotAllowed extends PasswordRule {
+  const factory PasswordRule_UnicodeNotAllowed() =
+      _$PasswordRule_UnicodeNotAllowedImpl;
+  const PasswordRule_UnicodeNotAllowed._() : super._();
+}
+/// @nodoc
+abstract class _$$PasswordRule_BannedWordImplCopyWith<$Res> {
+  factory _$$PasswordRule_BannedWordImplCopyWith(
+          _$PasswordRule_BannedWordImpl value,
+          $Res Function(_$PasswordRule_BannedWordImpl) then) =
+      __$$PasswordRule_BannedWordImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String word});
+}
+
+/// @nodoc
+class __$$PasswordRule_BannedWordImplCopyWithImpl<$Res>
+    extends _$PasswordRuleCopyWithImpl<$Res, _$PasswordRule_BannedWordImpl>
+    implements _$$PasswordRule_BannedWordImplCopyWith<$Res> {
+  __$$PasswordRule_BannedWordImplCopyWithImpl(
+      _$PasswordRule_BannedWordImpl _value,
+      $Res Function(_$PasswordRule_BannedWordImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? word = null,
+  }) {
+    return _then(_$PasswordRule_BannedWordImpl(
+      word: null == word
+          ? _value.word
+          : word // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordRule_BannedWordImpl extends PasswordRule_BannedWord {
+  const _$PasswordRule_BannedWordImpl({required this.word}) : super._();
+
+  @override
+  final String word;
+
+  @override
+  String toString() {
+    return 'PasswordRule.bannedWord(word: $word)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordRule_BannedWordImpl &&
+            (identical(other.word, word) || other.word == word));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, word);
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordRule_BannedWordImplCopyWith<
+          _$PasswordRule_BannedWordImpl>
+      get copyWith => __$$PasswordRule_BannedWordImplCopyWithImpl<
+          _$PasswordRule_BannedWordImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int minLength) tooShort,
+    required TResult Function(int maxLength) tooLong,
+    required TResult Function() missingUppercase,
+    required TResult Function() missingLowercase,
+    required TResult Function() missingDigit,
+    required TResult Function() missingSymbol,
+    required TResult Function(int score, int minScore) tooWeak,
+    required TResult Function() unicodeNotAllowed,
+    required TResult Function(String word) bannedWord,
+  }) {
+    return bannedWord(word);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int minLength)? tooShort,
+    TResult? Function(int maxLength)? tooLong,
+    TResult? Function()? missingUppercase,
+    TResult? Function()? missingLowercase,
+    TResult? Function()? missingDigit,
+    TResult? Function()? missingSymbol,
+    TResult? Function(int score, int minScore)? tooWeak,
+    TResult? Function()? unicodeNotAllowed,
+    TResult? Function(String word)? bannedWord,
+  }) {
+    return bannedWord?.call(word);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int minLength)? tooShort,
+    TResult Function(int maxLength)? tooLong,
+    TResult Function()? missingUppercase,
+    TResult Function()? missingLowercase,
+    TResult Function()? missingDigit,
+    TResult Function()? missingSymbol,
+    TResult Function(int score, int minScore)? tooWeak,
+    TResult Function()? unicodeNotAllowed,
+    TResult Function(String word)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (bannedWord != null) {
+      return bannedWord(word);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordRule_TooShort value) tooShort,
+    required TResult Function(PasswordRule_TooLong value) tooLong,
+    required TResult Function(PasswordRule_MissingUppercase value)
+        missingUppercase,
+    required TResult Function(PasswordRule_MissingLowercase value)
+        missingLowercase,
+    required TResult Function(PasswordRule_MissingDigit value) missingDigit,
+    required TResult Function(PasswordRule_MissingSymbol value) missingSymbol,
+    required TResult Function(PasswordRule_TooWeak value) tooWeak,
+    required TResult Function(PasswordRule_UnicodeNotAllowed value)
+        unicodeNotAllowed,
+    required TResult Function(PasswordRule_BannedWord value) bannedWord,
+  }) {
+    return bannedWord(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordRule_TooShort value)? tooShort,
+    TResult? Function(PasswordRule_TooLong value)? tooLong,
+    TResult? Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult? Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult? Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult? Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult? Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult? Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult? Function(PasswordRule_BannedWord value)? bannedWord,
+  }) {
+    return bannedWord?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordRule_TooShort value)? tooShort,
+    TResult Function(PasswordRule_TooLong value)? tooLong,
+    TResult Function(PasswordRule_MissingUppercase value)? missingUppercase,
+    TResult Function(PasswordRule_MissingLowercase value)? missingLowercase,
+    TResult Function(PasswordRule_MissingDigit value)? missingDigit,
+    TResult Function(PasswordRule_MissingSymbol value)? missingSymbol,
+    TResult Function(PasswordRule_TooWeak value)? tooWeak,
+    TResult Function(PasswordRule_UnicodeNotAllowed value)? unicodeNotAllowed,
+    TResult Function(PasswordRule_BannedWord value)? bannedWord,
+    required TResult orElse(),
+  }) {
+    if (bannedWord != null) {
+      return bannedWord(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordRule_BannedWord extends PasswordRule {
+  const factory PasswordRule_BannedWord({required final String word}) =
+      _$PasswordRule_BannedWordImpl;
+  const PasswordRule_BannedWord._() : super._();
+
+  String get word;
+
+  /// Create a copy of PasswordRule
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordRule_BannedWordImplCopyWith<
+          _$PasswordRule_BannedWordImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
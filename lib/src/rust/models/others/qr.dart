@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'qr.freezed.dart';
+
+/// How much of a QR code can be damaged and still be read, roughly 7%,
+/// 15%, 25% and 30%. Higher levels make bigger codes.
+enum QrErrorCorrection {
+  low,
+  medium,
+  quartile,
+  high,
+  ;
+}
+
+/// Modules of a QR code, for clients that draw it themselves.
+@freezed
+class QrMatrix with _$QrMatrix {
+  const factory QrMatrix({
+    required int size,
+    required List<bool> modules,
+  }) = _QrMatrix;
+}
+
+/// How a QR code is encoded and drawn.
+@freezed
+class QrOptions with _$QrOptions {
+  const factory QrOptions({
+    required QrErrorCorrection errorCorrection,
+    required int quietZone,
+    required int moduleSize,
+  }) = _QrOptions;
+}
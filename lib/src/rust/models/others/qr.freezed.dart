@@ -0,0 +1,337 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'qr.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$QrMatrix {
+  int get size => throw _privateConstructorUsedError;
+  List<bool> get modules => throw _privateConstructorUsedError;
+
+  /// Create a copy of QrMatrix
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $QrMatrixCopyWith<QrMatrix> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $QrMatrixCopyWith<$Res> {
+  factory $QrMatrixCopyWith(QrMatrix value, $Res Function(QrMatrix) then) =
+      _$QrMatrixCopyWithImpl<$Res, QrMatrix>;
+  @useResult
+  $Res call({int size, List<bool> modules});
+}
+
+/// @nodoc
+class _$QrMatrixCopyWithImpl<$Res, $Val extends QrMatrix>
+    implements $QrMatrixCopyWith<$Res> {
+  _$QrMatrixCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of QrMatrix
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? size = null,
+    Object? modules = null,
+  }) {
+    return _then(_value.copyWith(
+      size: null == size
+          ? _value.size
+          : size // ignore: cast_nullable_to_non_nullable
+              as int,
+      modules: null == modules
+          ? _value.modules
+          : modules // ignore: cast_nullable_to_non_nullable
+              as List<bool>,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$QrMatrixImplCopyWith<$Res>
+    implements $QrMatrixCopyWith<$Res> {
+  factory _$$QrMatrixImplCopyWith(
+          _$QrMatrixImpl value, $Res Function(_$QrMatrixImpl) then) =
+      __$$QrMatrixImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int size, List<bool> modules});
+}
+
+/// @nodoc
+class __$$QrMatrixImplCopyWithImpl<$Res>
+    extends _$QrMatrixCopyWithImpl<$Res, _$QrMatrixImpl>
+    implements _$$QrMatrixImplCopyWith<$Res> {
+  __$$QrMatrixImplCopyWithImpl(
+      _$QrMatrixImpl _value, $Res Function(_$QrMatrixImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of QrMatrix
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? size = null,
+    Object? modules = null,
+  }) {
+    return _then(_$QrMatrixImpl(
+      size: null == size
+          ? _value.size
+          : size // ignore: cast_nullable_to_non_nullable
+              as int,
+      modules: null == modules
+          ? _value._modules
+          : modules // ignore: cast_nullable_to_non_nullable
+              as List<bool>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$QrMatrixImpl implements _QrMatrix {
+  const _$QrMatrixImpl({required this.size, required final List<bool> modules})
+      : _modules = modules;
+
+  @override
+  final int size;
+  final List<bool> _modules;
+  @override
+  List<bool> get modules {
+    if (_modules is EqualUnmodifiableListView) return _modules;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_modules);
+  }
+
+  @override
+  String toString() {
+    return 'QrMatrix(size: $size, modules: $modules)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$QrMatrixImpl &&
+            (identical(other.size, size) || other.size == size) &&
+            const DeepCollectionEquality().equals(other._modules, _modules));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, size, const DeepCollectionEquality()
+          .hash(_modules));
+
+  /// Create a copy of QrMatrix
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$QrMatrixImplCopyWith<_$QrMatrixImpl> get copyWith =>
+      __$$QrMatrixImplCopyWithImpl<_$QrMatrixImpl>(this, _$identity);
+}
+
+abstract class _QrMatrix implements QrMatrix {
+  const factory _QrMatrix(
+      {required final int size,
+      required final List<bool> modules}) = _$QrMatrixImpl;
+
+  @override
+  int get size;
+  @override
+  List<bool> get modules;
+
+  /// Create a copy of QrMatrix
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$QrMatrixImplCopyWith<_$QrMatrixImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$QrOptions {
+  QrErrorCorrection get errorCorrection => throw _privateConstructorUsedError;
+  int get quietZone => throw _privateConstructorUsedError;
+  int get moduleSize => throw _privateConstructorUsedError;
+
+  /// Create a copy of QrOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $QrOptionsCopyWith<QrOptions> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $QrOptionsCopyWith<$Res> {
+  factory $QrOptionsCopyWith(QrOptions value, $Res Function(QrOptions) then) =
+      _$QrOptionsCopyWithImpl<$Res, QrOptions>;
+  @useResult
+  $Res call({QrErrorCorrection errorCorrection, int quietZone, int moduleSize});
+}
+
+/// @nodoc
+class _$QrOptionsCopyWithImpl<$Res, $Val extends QrOptions>
+    implements $QrOptionsCopyWith<$Res> {
+  _$QrOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of QrOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? errorCorrection = null,
+    Object? quietZone = null,
+    Object? moduleSize = null,
+  }) {
+    return _then(_value.copyWith(
+      errorCorrection: null == errorCorrection
+          ? _value.errorCorrection
+          : errorCorrection // ignore: cast_nullable_to_non_nullable
+              as QrErrorCorrection,
+      quietZone: null == quietZone
+          ? _value.quietZone
+          : quietZone // ignore: cast_nullable_to_non_nullable
+              as int,
+      moduleSize: null == moduleSize
+          ? _value.moduleSize
+          : moduleSize // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$QrOptionsImplCopyWith<$Res>
+    implements $QrOptionsCopyWith<$Res> {
+  factory _$$QrOptionsImplCopyWith(
+          _$QrOptionsImpl value, $Res Function(_$QrOptionsImpl) then) =
+      __$$QrOptionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({QrErrorCorrection errorCorrection, int quietZone, int moduleSize});
+}
+
+/// @nodoc
+class __$$QrOptionsImplCopyWithImpl<$Res>
+    extends _$QrOptionsCopyWithImpl<$Res, _$QrOptionsImpl>
+    implements _$$QrOptionsImplCopyWith<$Res> {
+  __$$QrOptionsImplCopyWithImpl(
+      _$QrOptionsImpl _value, $Res Function(_$QrOptionsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of QrOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? errorCorrection = null,
+    Object? quietZone = null,
+    Object? moduleSize = null,
+  }) {
+    return _then(_$QrOptionsImpl(
+      errorCorrection: null == errorCorrection
+          ? _value.errorCorrection
+          : errorCorrection // ignore: cast_nullable_to_non_nullable
+              as QrErrorCorrection,
+      quietZone: null == quietZone
+          ? _value.quietZone
+          : quietZone // ignore: cast_nullable_to_non_nullable
+              as int,
+      moduleSize: null == moduleSize
+          ? _value.moduleSize
+          : moduleSize // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$QrOptionsImpl implements _QrOptions {
+  const _$QrOptionsImpl(
+      {required this.errorCorrection,
+      required this.quietZone,
+      required this.moduleSize});
+
+  @override
+  final QrErrorCorrection errorCorrection;
+  @override
+  final int quietZone;
+  @override
+  final int moduleSize;
+
+  @override
+  String toString() {
+    return 'QrOptions(errorCorrection: $errorCorrection, quietZone: $quietZone, moduleSize: $moduleSize)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$QrOptionsImpl &&
+            (identical(other.errorCorrection, errorCorrection) ||
+                other.errorCorrection == errorCorrection) &&
+            (identical(other.quietZone, quietZone) ||
+                other.quietZone == quietZone) &&
+            (identical(other.moduleSize, moduleSize) ||
+                other.moduleSize == moduleSize));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, errorCorrection, quietZone, moduleSize);
+
+  /// Create a copy of QrOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$QrOptionsImplCopyWith<_$QrOptionsImpl> get copyWith =>
+      __$$QrOptionsImplCopyWithImpl<_$QrOptionsImpl>(this, _$identity);
+}
+
+abstract class _QrOptions implements QrOptions {
+  const factory _QrOptions(
+      {required final QrErrorCorrection errorCorrection,
+      required final int quietZone,
+      required final int moduleSize}) = _$QrOptionsImpl;
+
+  @override
+  QrErrorCorrection get errorCorrection;
+  @override
+  int get quietZone;
+  @override
+  int get moduleSize;
+
+  /// Create a copy of QrOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$QrOptionsImplCopyWith<_$QrOptionsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
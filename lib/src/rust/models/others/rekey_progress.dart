@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'rekey_progress.freezed.dart';
+
+/// Progress of a job moving every vault item to a new data key.
+@freezed
+class RekeyProgress with _$RekeyProgress {
+  const factory RekeyProgress({
+    required int done,
+    required int total,
+    required bool finished,
+  }) = _RekeyProgress;
+}
+
+/// Outcome of a finished re-key job.
+@freezed
+class RekeyResult with _$RekeyResult {
+  const factory RekeyResult({
+    required RekeyProgress progress,
+    String? recoveryKey,
+  }) = _RekeyResult;
+}
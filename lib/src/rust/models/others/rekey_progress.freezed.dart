@@ -0,0 +1,343 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'rekey_progress.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$RekeyProgress {
+  int get done => throw _privateConstructorUsedError;
+  int get total => throw _privateConstructorUsedError;
+  bool get finished => throw _privateConstructorUsedError;
+
+  /// Create a copy of RekeyProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $RekeyProgressCopyWith<RekeyProgress> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RekeyProgressCopyWith<$Res> {
+  factory $RekeyProgressCopyWith(
+          RekeyProgress value, $Res Function(RekeyProgress) then) =
+      _$RekeyProgressCopyWithImpl<$Res, RekeyProgress>;
+  @useResult
+  $Res call({int done, int total, bool finished});
+}
+
+/// @nodoc
+class _$RekeyProgressCopyWithImpl<$Res, $Val extends RekeyProgress>
+    implements $RekeyProgressCopyWith<$Res> {
+  _$RekeyProgressCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RekeyProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? done = null,
+    Object? total = null,
+    Object? finished = null,
+  }) {
+    return _then(_value.copyWith(
+      done: null == done
+          ? _value.done
+          : done // ignore: cast_nullable_to_non_nullable
+              as int,
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+      finished: null == finished
+          ? _value.finished
+          : finished // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$RekeyProgressImplCopyWith<$Res>
+    implements $RekeyProgressCopyWith<$Res> {
+  factory _$$RekeyProgressImplCopyWith(
+          _$RekeyProgressImpl value, $Res Function(_$RekeyProgressImpl) then) =
+      __$$RekeyProgressImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int done, int total, bool finished});
+}
+
+/// @nodoc
+class __$$RekeyProgressImplCopyWithImpl<$Res>
+    extends _$RekeyProgressCopyWithImpl<$Res, _$RekeyProgressImpl>
+    implements _$$RekeyProgressImplCopyWith<$Res> {
+  __$$RekeyProgressImplCopyWithImpl(
+      _$RekeyProgressImpl _value, $Res Function(_$RekeyProgressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of RekeyProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? done = null,
+    Object? total = null,
+    Object? finished = null,
+  }) {
+    return _then(_$RekeyProgressImpl(
+      done: null == done
+          ? _value.done
+          : done // ignore: cast_nullable_to_non_nullable
+              as int,
+      total: null == total
+          ? _value.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+      finished: null == finished
+          ? _value.finished
+          : finished // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RekeyProgressImpl implements _RekeyProgress {
+  const _$RekeyProgressImpl(
+      {required this.done, required this.total, required this.finished});
+
+  @override
+  final int done;
+  @override
+  final int total;
+  @override
+  final bool finished;
+
+  @override
+  String toString() {
+    return 'RekeyProgress(done: $done, total: $total, finished: $finished)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RekeyProgressImpl &&
+            (identical(other.done, done) || other.done == done) &&
+            (identical(other.total, total) || other.total == total) &&
+            (identical(other.finished, finished) ||
+                other.finished == finished));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, done, total, finished);
+
+  /// Create a copy of RekeyProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RekeyProgressImplCopyWith<_$RekeyProgressImpl> get copyWith =>
+      __$$RekeyProgressImplCopyWithImpl<_$RekeyProgressImpl>(this, _$identity);
+}
+
+abstract class _RekeyProgress implements RekeyProgress {
+  const factory _RekeyProgress(
+      {required final int done,
+      required final int total,
+      required final bool finished}) = _$RekeyProgressImpl;
+
+  @override
+  int get done;
+  @override
+  int get total;
+  @override
+  bool get finished;
+
+  /// Create a copy of RekeyProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RekeyProgressImplCopyWith<_$RekeyProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$RekeyResult {
+  RekeyProgress get progress => throw _privateConstructorUsedError;
+  String? get recoveryKey => throw _privateConstructorUsedError;
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $RekeyResultCopyWith<RekeyResult> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RekeyResultCopyWith<$Res> {
+  factory $RekeyResultCopyWith(
+          RekeyResult value, $Res Function(RekeyResult) then) =
+      _$RekeyResultCopyWithImpl<$Res, RekeyResult>;
+  @useResult
+  $Res call({RekeyProgress progress, String? recoveryKey});
+
+  $RekeyProgressCopyWith<$Res> get progress;
+}
+
+/// @nodoc
+class _$RekeyResultCopyWithImpl<$Res, $Val extends RekeyResult>
+    implements $RekeyResultCopyWith<$Res> {
+  _$RekeyResultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? progress = null,
+    Object? recoveryKey = freezed,
+  }) {
+    return _then(_value.copyWith(
+      progress: null == progress
+          ? _value.progress
+          : progress // ignore: cast_nullable_to_non_nullable
+              as RekeyProgress,
+      recoveryKey: freezed == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $RekeyProgressCopyWith<$Res> get progress {
+    return $RekeyProgressCopyWith<$Res>(_value.progress, (value) {
+      return _then(_value.copyWith(progress: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$RekeyResultImplCopyWith<$Res>
+    implements $RekeyResultCopyWith<$Res> {
+  factory _$$RekeyResultImplCopyWith(
+          _$RekeyResultImpl value, $Res Function(_$RekeyResultImpl) then) =
+      __$$RekeyResultImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({RekeyProgress progress, String? recoveryKey});
+
+  @override
+  $RekeyProgressCopyWith<$Res> get progress;
+}
+
+/// @nodoc
+class __$$RekeyResultImplCopyWithImpl<$Res>
+    extends _$RekeyResultCopyWithImpl<$Res, _$RekeyResultImpl>
+    implements _$$RekeyResultImplCopyWith<$Res> {
+  __$$RekeyResultImplCopyWithImpl(
+      _$RekeyResultImpl _value, $Res Function(_$RekeyResultImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? progress = null,
+    Object? recoveryKey = freezed,
+  }) {
+    return _then(_$RekeyResultImpl(
+      progress: null == progress
+          ? _value.progress
+          : progress // ignore: cast_nullable_to_non_nullable
+              as RekeyProgress,
+      recoveryKey: freezed == recoveryKey
+          ? _value.recoveryKey
+          : recoveryKey // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RekeyResultImpl implements _RekeyResult {
+  const _$RekeyResultImpl({required this.progress, this.recoveryKey});
+
+  @override
+  final RekeyProgress progress;
+  @override
+  final String? recoveryKey;
+
+  @override
+  String toString() {
+    return 'RekeyResult(progress: $progress, recoveryKey: $recoveryKey)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RekeyResultImpl &&
+            (identical(other.progress, progress) ||
+                other.progress == progress) &&
+            (identical(other.recoveryKey, recoveryKey) ||
+                other.recoveryKey == recoveryKey));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, progress, recoveryKey);
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RekeyResultImplCopyWith<_$RekeyResultImpl> get copyWith =>
+      __$$RekeyResultImplCopyWithImpl<_$RekeyResultImpl>(this, _$identity);
+}
+
+abstract class _RekeyResult implements RekeyResult {
+  const factory _RekeyResult(
+      {required final RekeyProgress progress,
+      final String? recoveryKey}) = _$RekeyResultImpl;
+
+  @override
+  RekeyProgress get progress;
+  @override
+  String? get recoveryKey;
+
+  /// Create a copy of RekeyResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RekeyResultImplCopyWith<_$RekeyResultImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,18 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'share_settings.freezed.dart';
+
+/// How a secret is split among trusted people.
+@freezed
+class ShareSettings with _$ShareSettings {
+  const factory ShareSettings({
+    required int threshold,
+    required int shares,
+  }) = _ShareSettings;
+}
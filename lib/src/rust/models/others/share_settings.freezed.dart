@@ -0,0 +1,162 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'share_settings.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$ShareSettings {
+  int get threshold => throw _privateConstructorUsedError;
+  int get shares => throw _privateConstructorUsedError;
+
+  /// Create a copy of ShareSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $ShareSettingsCopyWith<ShareSettings> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ShareSettingsCopyWith<$Res> {
+  factory $ShareSettingsCopyWith(
+          ShareSettings value, $Res Function(ShareSettings) then) =
+      _$ShareSettingsCopyWithImpl<$Res, ShareSettings>;
+  @useResult
+  $Res call({int threshold, int shares});
+}
+
+/// @nodoc
+class _$ShareSettingsCopyWithImpl<$Res, $Val extends ShareSettings>
+    implements $ShareSettingsCopyWith<$Res> {
+  _$ShareSettingsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ShareSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? threshold = null,
+    Object? shares = null,
+  }) {
+    return _then(_value.copyWith(
+      threshold: null == threshold
+          ? _value.threshold
+          : threshold // ignore: cast_nullable_to_non_nullable
+              as int,
+      shares: null == shares
+          ? _value.shares
+          : shares // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$ShareSettingsImplCopyWith<$Res>
+    implements $ShareSettingsCopyWith<$Res> {
+  factory _$$ShareSettingsImplCopyWith(
+          _$ShareSettingsImpl value, $Res Function(_$ShareSettingsImpl) then) =
+      __$$ShareSettingsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int threshold, int shares});
+}
+
+/// @nodoc
+class __$$ShareSettingsImplCopyWithImpl<$Res>
+    extends _$ShareSettingsCopyWithImpl<$Res, _$ShareSettingsImpl>
+    implements _$$ShareSettingsImplCopyWith<$Res> {
+  __$$ShareSettingsImplCopyWithImpl(
+      _$ShareSettingsImpl _value, $Res Function(_$ShareSettingsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ShareSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? threshold = null,
+    Object? shares = null,
+  }) {
+    return _then(_$ShareSettingsImpl(
+      threshold: null == threshold
+          ? _value.threshold
+          : threshold // ignore: cast_nullable_to_non_nullable
+              as int,
+      shares: null == shares
+          ? _value.shares
+          : shares // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ShareSettingsImpl implements _ShareSettings {
+  const _$ShareSettingsImpl({required this.threshold, required this.shares});
+
+  @override
+  final int threshold;
+  @override
+  final int shares;
+
+  @override
+  String toString() {
+    return 'ShareSettings(threshold: $threshold, shares: $shares)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ShareSettingsImpl &&
+            (identical(other.threshold, threshold) ||
+                other.threshold == threshold) &&
+            (identical(other.shares, shares) || other.shares == shares));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, threshold, shares);
+
+  /// Create a copy of ShareSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ShareSettingsImplCopyWith<_$ShareSettingsImpl> get copyWith =>
+      __$$ShareSettingsImplCopyWithImpl<_$ShareSettingsImpl>(this, _$identity);
+}
+
+abstract class _ShareSettings implements ShareSettings {
+  const factory _ShareSettings(
+      {required final int threshold,
+      required final int shares}) = _$ShareSettingsImpl;
+
+  @override
+  int get threshold;
+  @override
+  int get shares;
+
+  /// Create a copy of ShareSettings
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ShareSettingsImplCopyWith<_$ShareSettingsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
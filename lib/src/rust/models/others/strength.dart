@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'strength.freezed.dart';
+
+@freezed
+class PasswordFeedback with _$PasswordFeedback {
+  const factory PasswordFeedback({
+    String? warning,
+    required List<String> suggestions,
+  }) = _PasswordFeedback;
+}
+
+/// How hard a password is to guess.
+@freezed
+class PasswordStrength with _$PasswordStrength {
+  const factory PasswordStrength({
+    required int score,
+    required double guesses,
+    required double guessesLog10,
+    required double crackTimeSeconds,
+    required String crackTimeDisplay,
+    required PasswordFeedback feedback,
+  }) = _PasswordStrength;
+}
@@ -0,0 +1,429 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'strength.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$PasswordFeedback {
+  String? get warning => throw _privateConstructorUsedError;
+  List<String> get suggestions => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasswordFeedback
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasswordFeedbackCopyWith<PasswordFeedback> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordFeedbackCopyWith<$Res> {
+  factory $PasswordFeedbackCopyWith(
+          PasswordFeedback value, $Res Function(PasswordFeedback) then) =
+      _$PasswordFeedbackCopyWithImpl<$Res, PasswordFeedback>;
+  @useResult
+  $Res call({String? warning, List<String> suggestions});
+}
+
+/// @nodoc
+class _$PasswordFeedbackCopyWithImpl<$Res, $Val extends PasswordFeedback>
+    implements $PasswordFeedbackCopyWith<$Res> {
+  _$PasswordFeedbackCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordFeedback
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? warning = freezed,
+    Object? suggestions = null,
+  }) {
+    return _then(_value.copyWith(
+      warning: freezed == warning
+          ? _value.warning
+          : warning // ignore: cast_nullable_to_non_nullable
+              as String?,
+      suggestions: null == suggestions
+          ? _value.suggestions
+          : suggestions // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasswordFeedbackImplCopyWith<$Res>
+    implements $PasswordFeedbackCopyWith<$Res> {
+  factory _$$PasswordFeedbackImplCopyWith(
+          _$PasswordFeedbackImpl value,
+          $Res Function(_$PasswordFeedbackImpl) then) =
+      __$$PasswordFeedbackImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String? warning, List<String> suggestions});
+}
+
+/// @nodoc
+class __$$PasswordFeedbackImplCopyWithImpl<$Res>
+    extends _$PasswordFeedbackCopyWithImpl<$Res, _$PasswordFeedbackImpl>
+    implements _$$PasswordFeedbackImplCopyWith<$Res> {
+  __$$PasswordFeedbackImplCopyWithImpl(
+      _$PasswordFeedbackImpl _value,
+      $Res Function(_$PasswordFeedbackImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordFeedback
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? warning = freezed,
+    Object? suggestions = null,
+  }) {
+    return _then(_$PasswordFeedbackImpl(
+      warning: freezed == warning
+          ? _value.warning
+          : warning // ignore: cast_nullable_to_non_nullable
+              as String?,
+      suggestions: null == suggestions
+          ? _value._suggestions
+          : suggestions // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordFeedbackImpl implements _PasswordFeedback {
+  const _$PasswordFeedbackImpl(
+      {this.warning, required final List<String> suggestions})
+      : _suggestions = suggestions;
+
+  @override
+  final String? warning;
+  final List<String> _suggestions;
+  @override
+  List<String> get suggestions {
+    if (_suggestions is EqualUnmodifiableListView) return _suggestions;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_suggestions);
+  }
+
+  @override
+  String toString() {
+    return 'PasswordFeedback(warning: $warning, suggestions: $suggestions)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordFeedbackImpl &&
+            (identical(other.warning, warning) || other.warning == warning) &&
+            const DeepCollectionEquality()
+                .equals(other._suggestions, _suggestions));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, warning, const DeepCollectionEquality()
+          .hash(_suggestions));
+
+  /// Create a copy of PasswordFeedback
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordFeedbackImplCopyWith<_$PasswordFeedbackImpl> get copyWith =>
+      __$$PasswordFeedbackImplCopyWithImpl<_$PasswordFeedbackImpl>(
+          this, _$identity);
+}
+
+abstract class _PasswordFeedback implements PasswordFeedback {
+  const factory _PasswordFeedback(
+      {final String? warning,
+      required final List<String> suggestions}) = _$PasswordFeedbackImpl;
+
+  @override
+  String? get warning;
+  @override
+  List<String> get suggestions;
+
+  /// Create a copy of PasswordFeedback
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordFeedbackImplCopyWith<_$PasswordFeedbackImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$PasswordStrength {
+  int get score => throw _privateConstructorUsedError;
+  double get guesses => throw _privateConstructorUsedError;
+  double get guessesLog10 => throw _privateConstructorUsedError;
+  double get crackTimeSeconds => throw _privateConstructorUsedError;
+  String get crackTimeDisplay => throw _privateConstructorUsedError;
+  PasswordFeedback get feedback => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasswordStrengthCopyWith<PasswordStrength> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordStrengthCopyWith<$Res> {
+  factory $PasswordStrengthCopyWith(
+          PasswordStrength value, $Res Function(PasswordStrength) then) =
+      _$PasswordStrengthCopyWithImpl<$Res, PasswordStrength>;
+  @useResult
+  $Res call(
+      {int score,
+      double guesses,
+      double guessesLog10,
+      double crackTimeSeconds,
+      String crackTimeDisplay,
+      PasswordFeedback feedback});
+
+  $PasswordFeedbackCopyWith<$Res> get feedback;
+}
+
+/// @nodoc
+class _$PasswordStrengthCopyWithImpl<$Res, $Val extends PasswordStrength>
+    implements $PasswordStrengthCopyWith<$Res> {
+  _$PasswordStrengthCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? score = null,
+    Object? guesses = null,
+    Object? guessesLog10 = null,
+    Object? crackTimeSeconds = null,
+    Object? crackTimeDisplay = null,
+    Object? feedback = null,
+  }) {
+    return _then(_value.copyWith(
+      score: null == score
+          ? _value.score
+          : score // ignore: cast_nullable_to_non_nullable
+              as int,
+      guesses: null == guesses
+          ? _value.guesses
+          : guesses // ignore: cast_nullable_to_non_nullable
+              as double,
+      guessesLog10: null == guessesLog10
+          ? _value.guessesLog10
+          : guessesLog10 // ignore: cast_nullable_to_non_nullable
+              as double,
+      crackTimeSeconds: null == crackTimeSeconds
+          ? _value.crackTimeSeconds
+          : crackTimeSeconds // ignore: cast_nullable_to_non_nullable
+              as double,
+      crackTimeDisplay: null == crackTimeDisplay
+          ? _value.crackTimeDisplay
+          : crackTimeDisplay // ignore: cast_nullable_to_non_nullable
+              as String,
+      feedback: null == feedback
+          ? _value.feedback
+          : feedback // ignore: cast_nullable_to_non_nullable
+              as PasswordFeedback,
+    ) as $Val);
+  }
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $PasswordFeedbackCopyWith<$Res> get feedback {
+    return $PasswordFeedbackCopyWith<$Res>(_value.feedback, (value) {
+      return _then(_value.copyWith(feedback: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$PasswordStrengthImplCopyWith<$Res>
+    implements $PasswordStrengthCopyWith<$Res> {
+  factory _$$PasswordStrengthImplCopyWith(
+          _$PasswordStrengthImpl value,
+          $Res Function(_$PasswordStrengthImpl) then) =
+      __$$PasswordStrengthImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {int score,
+      double guesses,
+      double guessesLog10,
+      double crackTimeSeconds,
+      String crackTimeDisplay,
+      PasswordFeedback feedback});
+
+  @override
+  $PasswordFeedbackCopyWith<$Res> get feedback;
+}
+
+/// @nodoc
+class __$$PasswordStrengthImplCopyWithImpl<$Res>
+    extends _$PasswordStrengthCopyWithImpl<$Res, _$PasswordStrengthImpl>
+    implements _$$PasswordStrengthImplCopyWith<$Res> {
+  __$$PasswordStrengthImplCopyWithImpl(
+      _$PasswordStrengthImpl _value,
+      $Res Function(_$PasswordStrengthImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? score = null,
+    Object? guesses = null,
+    Object? guessesLog10 = null,
+    Object? crackTimeSeconds = null,
+    Object? crackTimeDisplay = null,
+    Object? feedback = null,
+  }) {
+    return _then(_$PasswordStrengthImpl(
+      score: null == score
+          ? _value.score
+          : score // ignore: cast_nullable_to_non_nullable
+              as int,
+      guesses: null == guesses
+          ? _value.guesses
+          : guesses // ignore: cast_nullable_to_non_nullable
+              as double,
+      guessesLog10: null == guessesLog10
+          ? _value.guessesLog10
+          : guessesLog10 // ignore: cast_nullable_to_non_nullable
+              as double,
+      crackTimeSeconds: null == crackTimeSeconds
+          ? _value.crackTimeSeconds
+          : crackTimeSeconds // ignore: cast_nullable_to_non_nullable
+              as double,
+      crackTimeDisplay: null == crackTimeDisplay
+          ? _value.crackTimeDisplay
+          : crackTimeDisplay // ignore: cast_nullable_to_non_nullable
+              as String,
+      feedback: null == feedback
+          ? _value.feedback
+          : feedback // ignore: cast_nullable_to_non_nullable
+              as PasswordFeedback,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordStrengthImpl implements _PasswordStrength {
+  const _$PasswordStrengthImpl(
+      {required this.score,
+      required this.guesses,
+      required this.guessesLog10,
+      required this.crackTimeSeconds,
+      required this.crackTimeDisplay,
+      required this.feedback});
+
+  @override
+  final int score;
+  @override
+  final double guesses;
+  @override
+  final double guessesLog10;
+  @override
+  final double crackTimeSeconds;
+  @override
+  final String crackTimeDisplay;
+  @override
+  final PasswordFeedback feedback;
+
+  @override
+  String toString() {
+    return 'PasswordStrength(score: $score, guesses: $guesses, guessesLog10: $guessesLog10, crackTimeSeconds: $crackTimeSeconds, crackTimeDisplay: $crackTimeDisplay, feedback: $feedback)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordStrengthImpl &&
+            (identical(other.score, score) || other.score == score) &&
+            (identical(other.guesses, guesses) || other.guesses == guesses) &&
+            (identical(other.guessesLog10, guessesLog10) ||
+                other.guessesLog10 == guessesLog10) &&
+            (identical(other.crackTimeSeconds, crackTimeSeconds) ||
+                other.crackTimeSeconds == crackTimeSeconds) &&
+            (identical(other.crackTimeDisplay, crackTimeDisplay) ||
+                other.crackTimeDisplay == crackTimeDisplay) &&
+            (identical(other.feedback, feedback) ||
+                other.feedback == feedback));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, score, guesses, guessesLog10,
+      crackTimeSeconds, crackTimeDisplay, feedback);
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordStrengthImplCopyWith<_$PasswordStrengthImpl> get copyWith =>
+      __$$PasswordStrengthImplCopyWithImpl<_$PasswordStrengthImpl>(
+          this, _$identity);
+}
+
+abstract class _PasswordStrength implements PasswordStrength {
+  const factory _PasswordStrength(
+      {required final int score,
+      required final double guesses,
+      required final double guessesLog10,
+      required final double crackTimeSeconds,
+      required final String crackTimeDisplay,
+      required final PasswordFeedback feedback}) = _$PasswordStrengthImpl;
+
+  @override
+  int get score;
+  @override
+  double get guesses;
+  @override
+  double get guessesLog10;
+  @override
+  double get crackTimeSeconds;
+  @override
+  String get crackTimeDisplay;
+  @override
+  PasswordFeedback get feedback;
+
+  /// Create a copy of PasswordStrength
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordStrengthImplCopyWith<_$PasswordStrengthImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
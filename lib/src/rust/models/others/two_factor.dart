@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'two_factor.freezed.dart';
+
+/// Single-use codes for logging in without the authenticator. Only shown
+/// once, the account keeps their hashes.
+@freezed
+class TwoFactorBackupCodes with _$TwoFactorBackupCodes {
+  const factory TwoFactorBackupCodes({
+    required List<String> codes,
+  }) = _TwoFactorBackupCodes;
+}
+
+/// Secret of a pending two-factor enrollment, for the user to add to an
+/// authenticator app.
+@freezed
+class TwoFactorSetup with _$TwoFactorSetup {
+  const factory TwoFactorSetup({
+    required String secret,
+    required String otpauthUrl,
+  }) = _TwoFactorSetup;
+}
+
+@freezed
+class TwoFactorStatus with _$TwoFactorStatus {
+  const factory TwoFactorStatus({
+    required bool enabled,
+    required int backupCodesRemaining,
+  }) = _TwoFactorStatus;
+}
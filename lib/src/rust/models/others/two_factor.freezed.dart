@@ -0,0 +1,457 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'two_factor.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$TwoFactorBackupCodes {
+  List<String> get codes => throw _privateConstructorUsedError;
+
+  /// Create a copy of TwoFactorBackupCodes
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TwoFactorBackupCodesCopyWith<TwoFactorBackupCodes> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TwoFactorBackupCodesCopyWith<$Res> {
+  factory $TwoFactorBackupCodesCopyWith(
+          TwoFactorBackupCodes value,
+          $Res Function(TwoFactorBackupCodes) then) =
+      _$TwoFactorBackupCodesCopyWithImpl<$Res, TwoFactorBackupCodes>;
+  @useResult
+  $Res call({List<String> codes});
+}
+
+/// @nodoc
+class _$TwoFactorBackupCodesCopyWithImpl<$Res,
+        $Val extends TwoFactorBackupCodes>
+    implements $TwoFactorBackupCodesCopyWith<$Res> {
+  _$TwoFactorBackupCodesCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TwoFactorBackupCodes
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? codes = null,
+  }) {
+    return _then(_value.copyWith(
+      codes: null == codes
+          ? _value.codes
+          : codes // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TwoFactorBackupCodesImplCopyWith<$Res>
+    implements $TwoFactorBackupCodesCopyWith<$Res> {
+  factory _$$TwoFactorBackupCodesImplCopyWith(
+          _$TwoFactorBackupCodesImpl value,
+          $Res Function(_$TwoFactorBackupCodesImpl) then) =
+      __$$TwoFactorBackupCodesImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({List<String> codes});
+}
+
+/// @nodoc
+class __$$TwoFactorBackupCodesImplCopyWithImpl<$Res>
+    extends _$TwoFactorBackupCodesCopyWithImpl<$Res, _$TwoFactorBackupCodesImpl>
+    implements _$$TwoFactorBackupCodesImplCopyWith<$Res> {
+  __$$TwoFactorBackupCodesImplCopyWithImpl(
+      _$TwoFactorBackupCodesImpl _value,
+      $Res Function(_$TwoFactorBackupCodesImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TwoFactorBackupCodes
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? codes = null,
+  }) {
+    return _then(_$TwoFactorBackupCodesImpl(
+      codes: null == codes
+          ? _value._codes
+          : codes // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TwoFactorBackupCodesImpl implements _TwoFactorBackupCodes {
+  const _$TwoFactorBackupCodesImpl({required final List<String> codes})
+      : _codes = codes;
+
+  final List<String> _codes;
+  @override
+  List<String> get codes {
+    if (_codes is EqualUnmodifiableListView) return _codes;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_codes);
+  }
+
+  @override
+  String toString() {
+    return 'TwoFactorBackupCodes(codes: $codes)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TwoFactorBackupCodesImpl &&
+            const DeepCollectionEquality().equals(other._codes, _codes));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_codes));
+
+  /// Create a copy of TwoFactorBackupCodes
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TwoFactorBackupCodesImplCopyWith<
+          _$TwoFactorBackupCodesImpl>
+      get copyWith => __$$TwoFactorBackupCodesImplCopyWithImpl<
+          _$TwoFactorBackupCodesImpl>(this, _$identity);
+}
+
+abstract class _TwoFactorBackupCodes implements TwoFactorBackupCodes {
+  const factory _TwoFactorBackupCodes({required final List<String> codes}) =
+      _$TwoFactorBackupCodesImpl;
+
+  @override
+  List<String> get codes;
+
+  /// Create a copy of TwoFactorBackupCodes
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TwoFactorBackupCodesImplCopyWith<
+          _$TwoFactorBackupCodesImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$TwoFactorSetup {
+  String get secret => throw _privateConstructorUsedError;
+  String get otpauthUrl => throw _privateConstructorUsedError;
+
+  /// Create a copy of TwoFactorSetup
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TwoFactorSetupCopyWith<TwoFactorSetup> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TwoFactorSetupCopyWith<$Res> {
+  factory $TwoFactorSetupCopyWith(
+          TwoFactorSetup value, $Res Function(TwoFactorSetup) then) =
+      _$TwoFactorSetupCopyWithImpl<$Res, TwoFactorSetup>;
+  @useResult
+  $Res call({String secret, String otpauthUrl});
+}
+
+/// @nodoc
+class _$TwoFactorSetupCopyWithImpl<$Res, $Val extends TwoFactorSetup>
+    implements $TwoFactorSetupCopyWith<$Res> {
+  _$TwoFactorSetupCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TwoFactorSetup
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? secret = null,
+    Object? otpauthUrl = null,
+  }) {
+    return _then(_value.copyWith(
+      secret: null == secret
+          ? _value.secret
+          : secret // ignore: cast_nullable_to_non_nullable
+              as String,
+      otpauthUrl: null == otpauthUrl
+          ? _value.otpauthUrl
+          : otpauthUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TwoFactorSetupImplCopyWith<$Res>
+    implements $TwoFactorSetupCopyWith<$Res> {
+  factory _$$TwoFactorSetupImplCopyWith(
+          _$TwoFactorSetupImpl value,
+          $Res Function(_$TwoFactorSetupImpl) then) =
+      __$$TwoFactorSetupImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String secret, String otpauthUrl});
+}
+
+/// @nodoc
+class __$$TwoFactorSetupImplCopyWithImpl<$Res>
+    extends _$TwoFactorSetupCopyWithImpl<$Res, _$TwoFactorSetupImpl>
+    implements _$$TwoFactorSetupImplCopyWith<$Res> {
+  __$$TwoFactorSetupImplCopyWithImpl(
+      _$TwoFactorSetupImpl _value, $Res Function(_$TwoFactorSetupImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TwoFactorSetup
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? secret = null,
+    Object? otpauthUrl = null,
+  }) {
+    return _then(_$TwoFactorSetupImpl(
+      secret: null == secret
+          ? _value.secret
+          : secret // ignore: cast_nullable_to_non_nullable
+              as String,
+      otpauthUrl: null == otpauthUrl
+          ? _value.otpauthUrl
+          : otpauthUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TwoFactorSetupImpl implements _TwoFactorSetup {
+  const _$TwoFactorSetupImpl({required this.secret, required this.otpauthUrl});
+
+  @override
+  final String secret;
+  @override
+  final String otpauthUrl;
+
+  @override
+  String toString() {
+    return 'TwoFactorSetup(secret: $secret, otpauthUrl: $otpauthUrl)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TwoFactorSetupImpl &&
+            (identical(other.secret, secret) || other.secret == secret) &&
+            (identical(other.otpauthUrl, otpauthUrl) ||
+                other.otpauthUrl == otpauthUrl));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, secret, otpauthUrl);
+
+  /// Create a copy of TwoFactorSetup
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TwoFactorSetupImplCopyWith<_$TwoFactorSetupImpl> get copyWith =>
+      __$$TwoFactorSetupImplCopyWithImpl<_$TwoFactorSetupImpl>(
+          this, _$identity);
+}
+
+abstract class _TwoFactorSetup implements TwoFactorSetup {
+  const factory _TwoFactorSetup(
+      {required final String secret,
+      required final String otpauthUrl}) = _$TwoFactorSetupImpl;
+
+  @override
+  String get secret;
+  @override
+  String get otpauthUrl;
+
+  /// Create a copy of TwoFactorSetup
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TwoFactorSetupImplCopyWith<_$TwoFactorSetupImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$TwoFactorStatus {
+  bool get enabled => throw _privateConstructorUsedError;
+  int get backupCodesRemaining => throw _privateConstructorUsedError;
+
+  /// Create a copy of TwoFactorStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TwoFactorStatusCopyWith<TwoFactorStatus> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TwoFactorStatusCopyWith<$Res> {
+  factory $TwoFactorStatusCopyWith(
+          TwoFactorStatus value, $Res Function(TwoFactorStatus) then) =
+      _$TwoFactorStatusCopyWithImpl<$Res, TwoFactorStatus>;
+  @useResult
+  $Res call({bool enabled, int backupCodesRemaining});
+}
+
+/// @nodoc
+class _$TwoFactorStatusCopyWithImpl<$Res, $Val extends TwoFactorStatus>
+    implements $TwoFactorStatusCopyWith<$Res> {
+  _$TwoFactorStatusCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TwoFactorStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? enabled = null,
+    Object? backupCodesRemaining = null,
+  }) {
+    return _then(_value.copyWith(
+      enabled: null == enabled
+          ? _value.enabled
+          : enabled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      backupCodesRemaining: null == backupCodesRemaining
+          ? _value.backupCodesRemaining
+          : backupCodesRemaining // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TwoFactorStatusImplCopyWith<$Res>
+    implements $TwoFactorStatusCopyWith<$Res> {
+  factory _$$TwoFactorStatusImplCopyWith(
+          _$TwoFactorStatusImpl value,
+          $Res Function(_$TwoFactorStatusImpl) then) =
+      __$$TwoFactorStatusImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({bool enabled, int backupCodesRemaining});
+}
+
+/// @nodoc
+class __$$TwoFactorStatusImplCopyWithImpl<$Res>
+    extends _$TwoFactorStatusCopyWithImpl<$Res, _$TwoFactorStatusImpl>
+    implements _$$TwoFactorStatusImplCopyWith<$Res> {
+  __$$TwoFactorStatusImplCopyWithImpl(
+      _$TwoFactorStatusImpl _value, $Res Function(_$TwoFactorStatusImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TwoFactorStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? enabled = null,
+    Object? backupCodesRemaining = null,
+  }) {
+    return _then(_$TwoFactorStatusImpl(
+      enabled: null == enabled
+          ? _value.enabled
+          : enabled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      backupCodesRemaining: null == backupCodesRemaining
+          ? _value.backupCodesRemaining
+          : backupCodesRemaining // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TwoFactorStatusImpl implements _TwoFactorStatus {
+  const _$TwoFactorStatusImpl(
+      {required this.enabled, required this.backupCodesRemaining});
+
+  @override
+  final bool enabled;
+  @override
+  final int backupCodesRemaining;
+
+  @override
+  String toString() {
+    return 'TwoFactorStatus(enabled: $enabled, backupCodesRemaining: $backupCodesRemaining)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TwoFactorStatusImpl &&
+            (identical(other.enabled, enabled) || other.enabled == enabled) &&
+            (identical(other.backupCodesRemaining, backupCodesRemaining) ||
+                other.backupCodesRemaining == backupCodesRemaining));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, enabled, backupCodesRemaining);
+
+  /// Create a copy of TwoFactorStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TwoFactorStatusImplCopyWith<_$TwoFactorStatusImpl> get copyWith =>
+      __$$TwoFactorStatusImplCopyWithImpl<_$TwoFactorStatusImpl>(
+          this, _$identity);
+}
+
+abstract class _TwoFactorStatus implements TwoFactorStatus {
+  const factory _TwoFactorStatus(
+      {required final bool enabled,
+      required final int backupCodesRemaining}) = _$TwoFactorStatusImpl;
+
+  @override
+  bool get enabled;
+  @override
+  int get backupCodesRemaining;
+
+  /// Create a copy of TwoFactorStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TwoFactorStatusImplCopyWith<_$TwoFactorStatusImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
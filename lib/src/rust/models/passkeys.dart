@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'passkeys.freezed.dart';
+
+/// A WebAuthn credential the app signs for as a software authenticator.
+/// Binary fields are base64url without padding, as in WebAuthn JSON.
+@freezed
+class Passkey with _$Passkey {
+  const factory Passkey({
+    String? id,
+    PlatformInt64? createdAt,
+    String? createdBy,
+    PlatformInt64? updatedAt,
+    String? updatedBy,
+    required String name,
+    String? note,
+    required String rpId,
+    String? rpName,
+    required String userHandle,
+    String? userName,
+    String? userDisplayName,
+    required String credentialId,
+    required PlatformInt64 algorithm,
+    required String privateKey,
+    required PlatformInt64 signCount,
+    PlatformInt64? lastUsedAt,
+    bool? isFavorite,
+    String? tags,
+  }) = _Passkey;
+}
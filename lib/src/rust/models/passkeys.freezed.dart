@@ -0,0 +1,538 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'passkeys.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$Passkey {
+  String? get id => throw _privateConstructorUsedError;
+  int? get createdAt => throw _privateConstructorUsedError;
+  String? get createdBy => throw _privateConstructorUsedError;
+  int? get updatedAt => throw _privateConstructorUsedError;
+  String? get updatedBy => throw _privateConstructorUsedError;
+  String get name => throw _privateConstructorUsedError;
+  String? get note => throw _privateConstructorUsedError;
+  String get rpId => throw _privateConstructorUsedError;
+  String? get rpName => throw _privateConstructorUsedError;
+  String get userHandle => throw _privateConstructorUsedError;
+  String? get userName => throw _privateConstructorUsedError;
+  String? get userDisplayName => throw _privateConstructorUsedError;
+  String get credentialId => throw _privateConstructorUsedError;
+  int get algorithm => throw _privateConstructorUsedError;
+  String get privateKey => throw _privateConstructorUsedError;
+  int get signCount => throw _privateConstructorUsedError;
+  int? get lastUsedAt => throw _privateConstructorUsedError;
+  bool? get isFavorite => throw _privateConstructorUsedError;
+  String? get tags => throw _privateConstructorUsedError;
+
+  /// Create a copy of Passkey
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasskeyCopyWith<Passkey> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasskeyCopyWith<$Res> {
+  factory $PasskeyCopyWith(Passkey value, $Res Function(Passkey) then) =
+      _$PasskeyCopyWithImpl<$Res, Passkey>;
+  @useResult
+  $Res call(
+      {String? id,
+      int? createdAt,
+      String? createdBy,
+      int? updatedAt,
+      String? updatedBy,
+      String name,
+      String? note,
+      String rpId,
+      String? rpName,
+      String userHandle,
+      String? userName,
+      String? userDisplayName,
+      String credentialId,
+      int algorithm,
+      String privateKey,
+      int signCount,
+      int? lastUsedAt,
+      bool? isFavorite,
+      String? tags});
+}
+
+/// @nodoc
+class _$PasskeyCopyWithImpl<$Res, $Val extends Passkey>
+    implements $PasskeyCopyWith<$Res> {
+  _$PasskeyCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of Passkey
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = freezed,
+    Object? createdAt = freezed,
+    Object? createdBy = freezed,
+    Object? updatedAt = freezed,
+    Object? updatedBy = freezed,
+    Object? name = null,
+    Object? note = freezed,
+    Object? rpId = null,
+    Object? rpName = freezed,
+    Object? userHandle = null,
+    Object? userName = freezed,
+    Object? userDisplayName = freezed,
+    Object? credentialId = null,
+    Object? algorithm = null,
+    Object? privateKey = null,
+    Object? signCount = null,
+    Object? lastUsedAt = freezed,
+    Object? isFavorite = freezed,
+    Object? tags = freezed,
+  }) {
+    return _then(_value.copyWith(
+      id: freezed == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String?,
+      createdAt: freezed == createdAt
+          ? _value.createdAt
+          : createdAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      createdBy: freezed == createdBy
+          ? _value.createdBy
+          : createdBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      updatedAt: freezed == updatedAt
+          ? _value.updatedAt
+          : updatedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      updatedBy: freezed == updatedBy
+          ? _value.updatedBy
+          : updatedBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      note: freezed == note
+          ? _value.note
+          : note // ignore: cast_nullable_to_non_nullable
+              as String?,
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      rpName: freezed == rpName
+          ? _value.rpName
+          : rpName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+      userName: freezed == userName
+          ? _value.userName
+          : userName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userDisplayName: freezed == userDisplayName
+          ? _value.userDisplayName
+          : userDisplayName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      algorithm: null == algorithm
+          ? _value.algorithm
+          : algorithm // ignore: cast_nullable_to_non_nullable
+              as int,
+      privateKey: null == privateKey
+          ? _value.privateKey
+          : privateKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      signCount: null == signCount
+          ? _value.signCount
+          : signCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      lastUsedAt: freezed == lastUsedAt
+          ? _value.lastUsedAt
+          : lastUsedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      isFavorite: freezed == isFavorite
+          ? _value.isFavorite
+          : isFavorite // ignore: cast_nullable_to_non_nullable
+              as bool?,
+      tags: freezed == tags
+          ? _value.tags
+          : tags // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasskeyImplCopyWith<$Res> implements $PasskeyCopyWith<$Res> {
+  factory _$$PasskeyImplCopyWith(
+          _$PasskeyImpl value, $Res Function(_$PasskeyImpl) then) =
+      __$$PasskeyImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String? id,
+      int? createdAt,
+      String? createdBy,
+      int? updatedAt,
+      String? updatedBy,
+      String name,
+      String? note,
+      String rpId,
+      String? rpName,
+      String userHandle,
+      String? userName,
+      String? userDisplayName,
+      String credentialId,
+      int algorithm,
+      String privateKey,
+      int signCount,
+      int? lastUsedAt,
+      bool? isFavorite,
+      String? tags});
+}
+
+/// @nodoc
+class __$$PasskeyImplCopyWithImpl<$Res>
+    extends _$PasskeyCopyWithImpl<$Res, _$PasskeyImpl>
+    implements _$$PasskeyImplCopyWith<$Res> {
+  __$$PasskeyImplCopyWithImpl(
+      _$PasskeyImpl _value, $Res Function(_$PasskeyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of Passkey
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = freezed,
+    Object? createdAt = freezed,
+    Object? createdBy = freezed,
+    Object? updatedAt = freezed,
+    Object? updatedBy = freezed,
+    Object? name = null,
+    Object? note = freezed,
+    Object? rpId = null,
+    Object? rpName = freezed,
+    Object? userHandle = null,
+    Object? userName = freezed,
+    Object? userDisplayName = freezed,
+    Object? credentialId = null,
+    Object? algorithm = null,
+    Object? privateKey = null,
+    Object? signCount = null,
+    Object? lastUsedAt = freezed,
+    Object? isFavorite = freezed,
+    Object? tags = freezed,
+  }) {
+    return _then(_$PasskeyImpl(
+      id: freezed == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String?,
+      createdAt: freezed == createdAt
+          ? _value.createdAt
+          : createdAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      createdBy: freezed == createdBy
+          ? _value.createdBy
+          : createdBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      updatedAt: freezed == updatedAt
+          ? _value.updatedAt
+          : updatedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      updatedBy: freezed == updatedBy
+          ? _value.updatedBy
+          : updatedBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      note: freezed == note
+          ? _value.note
+          : note // ignore: cast_nullable_to_non_nullable
+              as String?,
+      rpId: null == rpId
+          ? _value.rpId
+          : rpId // ignore: cast_nullable_to_non_nullable
+              as String,
+      rpName: freezed == rpName
+          ? _value.rpName
+          : rpName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userHandle: null == userHandle
+          ? _value.userHandle
+          : userHandle // ignore: cast_nullable_to_non_nullable
+              as String,
+      userName: freezed == userName
+          ? _value.userName
+          : userName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      userDisplayName: freezed == userDisplayName
+          ? _value.userDisplayName
+          : userDisplayName // ignore: cast_nullable_to_non_nullable
+              as String?,
+      credentialId: null == credentialId
+          ? _value.credentialId
+          : credentialId // ignore: cast_nullable_to_non_nullable
+              as String,
+      algorithm: null == algorithm
+          ? _value.algorithm
+          : algorithm // ignore: cast_nullable_to_non_nullable
+              as int,
+      privateKey: null == privateKey
+          ? _value.privateKey
+          : privateKey // ignore: cast_nullable_to_non_nullable
+              as String,
+      signCount: null == signCount
+          ? _value.signCount
+          : signCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      lastUsedAt: freezed == lastUsedAt
+          ? _value.lastUsedAt
+          : lastUsedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      isFavorite: freezed == isFavorite
+          ? _value.isFavorite
+          : isFavorite // ignore: cast_nullable_to_non_nullable
+              as bool?,
+      tags: freezed == tags
+          ? _value.tags
+          : tags // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasskeyImpl implements _Passkey {
+  const _$PasskeyImpl(
+      {this.id,
+      this.createdAt,
+      this.createdBy,
+      this.updatedAt,
+      this.updatedBy,
+      required this.name,
+      this.note,
+      required this.rpId,
+      this.rpName,
+      required this.userHandle,
+      this.userName,
+      this.userDisplayName,
+      required this.credentialId,
+      required this.algorithm,
+      required this.privateKey,
+      required this.signCount,
+      this.lastUsedAt,
+      this.isFavorite,
+      this.tags});
+
+  @override
+  final String? id;
+  @override
+  final int? createdAt;
+  @override
+  final String? createdBy;
+  @override
+  final int? updatedAt;
+  @override
+  final String? updatedBy;
+  @override
+  final String name;
+  @override
+  final String? note;
+  @override
+  final String rpId;
+  @override
+  final String? rpName;
+  @override
+  final String userHandle;
+  @override
+  final String? userName;
+  @override
+  final String? userDisplayName;
+  @override
+  final String credentialId;
+  @override
+  final int algorithm;
+  @override
+  final String privateKey;
+  @override
+  final int signCount;
+  @override
+  final int? lastUsedAt;
+  @override
+  final bool? isFavorite;
+  @override
+  final String? tags;
+
+  @override
+  String toString() {
+    return 'Passkey(id: $id, createdAt: $createdAt, createdBy: $createdBy, updatedAt: $updatedAt, updatedBy: $updatedBy, name: $name, note: $note, rpId: $rpId, rpName: $rpName, userHandle: $userHandle, userName: $userName, userDisplayName: $userDisplayName, credentialId: $credentialId, algorithm: $algorithm, privateKey: $privateKey, signCount: $signCount, lastUsedAt: $lastUsedAt, isFavorite: $isFavorite, tags: $tags)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasskeyImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.createdAt, createdAt) ||
+                other.createdAt == createdAt) &&
+            (identical(other.createdBy, createdBy) ||
+                other.createdBy == createdBy) &&
+            (identical(other.updatedAt, updatedAt) ||
+                other.updatedAt == updatedAt) &&
+            (identical(other.updatedBy, updatedBy) ||
+                other.updatedBy == updatedBy) &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.note, note) || other.note == note) &&
+            (identical(other.rpId, rpId) || other.rpId == rpId) &&
+            (identical(other.rpName, rpName) || other.rpName == rpName) &&
+            (identical(other.userHandle, userHandle) ||
+                other.userHandle == userHandle) &&
+            (identical(other.userName, userName) ||
+                other.userName == userName) &&
+            (identical(other.userDisplayName, userDisplayName) ||
+                other.userDisplayName == userDisplayName) &&
+            (identical(other.credentialId, credentialId) ||
+                other.credentialId == credentialId) &&
+            (identical(other.algorithm, algorithm) ||
+                other.algorithm == algorithm) &&
+            (identical(other.privateKey, privateKey) ||
+                other.privateKey == privateKey) &&
+            (identical(other.signCount, signCount) ||
+                other.signCount == signCount) &&
+            (identical(other.lastUsedAt, lastUsedAt) ||
+                other.lastUsedAt == lastUsedAt) &&
+            (identical(other.isFavorite, isFavorite) ||
+                other.isFavorite == isFavorite) &&
+            (identical(other.tags, tags) || other.tags == tags));
+  }
+
+  @override
+  int get hashCode => Object.hashAll([
+        runtimeType,
+        id,
+        createdAt,
+        createdBy,
+        updatedAt,
+        updatedBy,
+        name,
+        note,
+        rpId,
+        rpName,
+        userHandle,
+        userName,
+        userDisplayName,
+        credentialId,
+        algorithm,
+        privateKey,
+        signCount,
+        lastUsedAt,
+        isFavorite,
+        tags
+      ]);
+
+  /// Create a copy of Passkey
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasskeyImplCopyWith<_$PasskeyImpl> get copyWith =>
+      __$$PasskeyImplCopyWithImpl<_$PasskeyImpl>(this, _$identity);
+}
+
+abstract class _Passkey implements Passkey {
+  const factory _Passkey(
+      {final String? id,
+      final int? createdAt,
+      final String? createdBy,
+      final int? updatedAt,
+      final String? updatedBy,
+      required final String name,
+      final String? note,
+      required final String rpId,
+      final String? rpName,
+      required final String userHandle,
+      final String? userName,
+      final String? userDisplayName,
+      required final String credentialId,
+      required final int algorithm,
+      required final String privateKey,
+      required final int signCount,
+      final int? lastUsedAt,
+      final bool? isFavorite,
+      final String? tags}) = _$PasskeyImpl;
+
+  @override
+  String? get id;
+  @override
+  int? get createdAt;
+  @override
+  String? get createdBy;
+  @override
+  int? get updatedAt;
+  @override
+  String? get updatedBy;
+  @override
+  String get name;
+  @override
+  String? get note;
+  @override
+  String get rpId;
+  @override
+  String? get rpName;
+  @override
+  String get userHandle;
+  @override
+  String? get userName;
+  @override
+  String? get userDisplayName;
+  @override
+  String get credentialId;
+  @override
+  int get algorithm;
+  @override
+  String get privateKey;
+  @override
+  int get signCount;
+  @override
+  int? get lastUsedAt;
+  @override
+  bool? get isFavorite;
+  @override
+  String? get tags;
+
+  /// Create a copy of Passkey
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasskeyImplCopyWith<_$PasskeyImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'password_history.freezed.dart';
+
+/// A password a login had before it was changed.
+@freezed
+class PasswordHistory with _$PasswordHistory {
+  const factory PasswordHistory({
+    String? id,
+    PlatformInt64? createdAt,
+    String? createdBy,
+    PlatformInt64? updatedAt,
+    String? updatedBy,
+    required String loginId,
+    required String password,
+  }) = _PasswordHistory;
+}
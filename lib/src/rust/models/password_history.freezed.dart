@@ -0,0 +1,275 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'password_history.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$PasswordHistory {
+  String? get id => throw _privateConstructorUsedError;
+  int? get createdAt => throw _privateConstructorUsedError;
+  String? get createdBy => throw _privateConstructorUsedError;
+  int? get updatedAt => throw _privateConstructorUsedError;
+  String? get updatedBy => throw _privateConstructorUsedError;
+  String get loginId => throw _privateConstructorUsedError;
+  String get password => throw _privateConstructorUsedError;
+
+  /// Create a copy of PasswordHistory
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $PasswordHistoryCopyWith<PasswordHistory> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordHistoryCopyWith<$Res> {
+  factory $PasswordHistoryCopyWith(
+          PasswordHistory value, $Res Function(PasswordHistory) then) =
+      _$PasswordHistoryCopyWithImpl<$Res, PasswordHistory>;
+  @useResult
+  $Res call(
+      {String? id,
+      int? createdAt,
+      String? createdBy,
+      int? updatedAt,
+      String? updatedBy,
+      String loginId,
+      String password});
+}
+
+/// @nodoc
+class _$PasswordHistoryCopyWithImpl<$Res, $Val extends PasswordHistory>
+    implements $PasswordHistoryCopyWith<$Res> {
+  _$PasswordHistoryCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordHistory
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = freezed,
+    Object? createdAt = freezed,
+    Object? createdBy = freezed,
+    Object? updatedAt = freezed,
+    Object? updatedBy = freezed,
+    Object? loginId = null,
+    Object? password = null,
+  }) {
+    return _then(_value.copyWith(
+      id: freezed == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String?,
+      createdAt: freezed == createdAt
+          ? _value.createdAt
+          : createdAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      createdBy: freezed == createdBy
+          ? _value.createdBy
+          : createdBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      updatedAt: freezed == updatedAt
+          ? _value.updatedAt
+          : updatedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      updatedBy: freezed == updatedBy
+          ? _value.updatedBy
+          : updatedBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      loginId: null == loginId
+          ? _value.loginId
+          : loginId // ignore: cast_nullable_to_non_nullable
+              as String,
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$PasswordHistoryImplCopyWith<$Res>
+    implements $PasswordHistoryCopyWith<$Res> {
+  factory _$$PasswordHistoryImplCopyWith(
+          _$PasswordHistoryImpl value,
+          $Res Function(_$PasswordHistoryImpl) then) =
+      __$$PasswordHistoryImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call(
+      {String? id,
+      int? createdAt,
+      String? createdBy,
+      int? updatedAt,
+      String? updatedBy,
+      String loginId,
+      String password});
+}
+
+/// @nodoc
+class __$$PasswordHistoryImplCopyWithImpl<$Res>
+    extends _$PasswordHistoryCopyWithImpl<$Res, _$PasswordHistoryImpl>
+    implements _$$PasswordHistoryImplCopyWith<$Res> {
+  __$$PasswordHistoryImplCopyWithImpl(
+      _$PasswordHistoryImpl _value, $Res Function(_$PasswordHistoryImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PasswordHistory
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = freezed,
+    Object? createdAt = freezed,
+    Object? createdBy = freezed,
+    Object? updatedAt = freezed,
+    Object? updatedBy = freezed,
+    Object? loginId = null,
+    Object? password = null,
+  }) {
+    return _then(_$PasswordHistoryImpl(
+      id: freezed == id
+          ? _value.id
+          : id // ignore: cast_nullable_to_non_nullable
+              as String?,
+      createdAt: freezed == createdAt
+          ? _value.createdAt
+          : createdAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      createdBy: freezed == createdBy
+          ? _value.createdBy
+          : createdBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      updatedAt: freezed == updatedAt
+          ? _value.updatedAt
+          : updatedAt // ignore: cast_nullable_to_non_nullable
+              as int?,
+      updatedBy: freezed == updatedBy
+          ? _value.updatedBy
+          : updatedBy // ignore: cast_nullable_to_non_nullable
+              as String?,
+      loginId: null == loginId
+          ? _value.loginId
+          : loginId // ignore: cast_nullable_to_non_nullable
+              as String,
+      password: null == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PasswordHistoryImpl implements _PasswordHistory {
+  const _$PasswordHistoryImpl(
+      {this.id,
+      this.createdAt,
+      this.createdBy,
+      this.updatedAt,
+      this.updatedBy,
+      required this.loginId,
+      required this.password});
+
+  @override
+  final String? id;
+  @override
+  final int? createdAt;
+  @override
+  final String? createdBy;
+  @override
+  final int? updatedAt;
+  @override
+  final String? updatedBy;
+  @override
+  final String loginId;
+  @override
+  final String password;
+
+  @override
+  String toString() {
+    return 'PasswordHistory(id: $id, createdAt: $createdAt, createdBy: $createdBy, updatedAt: $updatedAt, updatedBy: $updatedBy, loginId: $loginId, password: $password)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordHistoryImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.createdAt, createdAt) ||
+                other.createdAt == createdAt) &&
+            (identical(other.createdBy, createdBy) ||
+                other.createdBy == createdBy) &&
+            (identical(other.updatedAt, updatedAt) ||
+                other.updatedAt == updatedAt) &&
+            (identical(other.updatedBy, updatedBy) ||
+                other.updatedBy == updatedBy) &&
+            (identical(other.loginId, loginId) || other.loginId == loginId) &&
+            (identical(other.password, password) ||
+                other.password == password));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, createdAt, createdBy,
+      updatedAt, updatedBy, loginId, password);
+
+  /// Create a copy of PasswordHistory
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordHistoryImplCopyWith<_$PasswordHistoryImpl> get copyWith =>
+      __$$PasswordHistoryImplCopyWithImpl<_$PasswordHistoryImpl>(
+          this, _$identity);
+}
+
+abstract class _PasswordHistory implements PasswordHistory {
+  const factory _PasswordHistory(
+      {final String? id,
+      final int? createdAt,
+      final String? createdBy,
+      final int? updatedAt,
+      final String? updatedBy,
+      required final String loginId,
+      required final String password}) = _$PasswordHistoryImpl;
+
+  @override
+  String? get id;
+  @override
+  int? get createdAt;
+  @override
+  String? get createdBy;
+  @override
+  int? get updatedAt;
+  @override
+  String? get updatedBy;
+  @override
+  String get loginId;
+  @override
+  String get password;
+
+  /// Create a copy of PasswordHistory
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordHistoryImplCopyWith<_$PasswordHistoryImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
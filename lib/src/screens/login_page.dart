@@ -255,14 +255,57 @@ class _LoginPageState extends ConsumerState<LoginPage> {
     );
   }
 
+  Future<String?> _askTwoFactorCode() {
+    final codeController = TextEditingController();
+    return showDialog<String>(
+      context: context,
+      builder: (context) => AlertDialog(
+        title: const Text('Two-factor authentication'),
+        content: TextField(
+          controller: codeController,
+          autofocus: true,
+          decoration: const InputDecoration(
+            labelText: 'Authenticator or backup code',
+            border: OutlineInputBorder(),
+          ),
+          onSubmitted: (code) => Navigator.of(context).pop(code),
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.of(context).pop(),
+            child: const Text('Cancel'),
+          ),
+          ElevatedButton(
+            onPressed: () => Navigator.of(context).pop(codeController.text),
+            child: const Text('Verify'),
+          ),
+        ],
+      ),
+    );
+  }
+
   Future<void> _handleLogin() async {
     if (_formKey.currentState!.validate()) {
-      final success = await ref.read(authProvider.notifier).login(
-            _emailController.text,
-            _passwordController.text,
-            endpoint: _selectedOption == 'server' ? _urlController.text : null,
-          );
-      if (success) {
+      final auth = ref.read(authProvider.notifier);
+      var status = await auth.login(
+        _emailController.text,
+        _passwordController.text,
+        endpoint: _selectedOption == 'server' ? _urlController.text : null,
+      );
+      if (status == LoginStatus.twoFactorRequired && mounted) {
+        final code = await _askTwoFactorCode();
+        if (code == null || code.isEmpty) {
+          return;
+        }
+        final verified = await auth.loginWithTwoFactor(code.trim());
+        if (!verified && mounted) {
+          ScaffoldMessenger.of(context).showSnackBar(const SnackBar(
+            content: Text('Wrong two-factor code'),
+          ));
+        }
+        status = verified ? LoginStatus.success : LoginStatus.failed;
+      }
+      if (status == LoginStatus.success) {
         await _secureStorage.setPassword(_passwordController.text);
         if (mounted) {
           GoRouter.of(context).go(HomeScreen.path);
@@ -31,6 +31,7 @@ class _RegistrationPageState extends ConsumerState<RegistrationPage> {
   bool _showPassword = false;
   bool _showConfirmPassword = false;
   bool _addDummyData = true;
+  bool _createRecoveryKey = true;
 
   final _urlFieldKey = GlobalKey<FormFieldState>();
   final _urlFocusNode = FocusNode();
@@ -140,6 +141,57 @@ class _RegistrationPageState extends ConsumerState<RegistrationPage> {
     );
   }
 
+  Widget _buildRecoveryKeyCheckbox() {
+    return Padding(
+      padding: const EdgeInsets.only(bottom: 16),
+      child: Row(
+        children: [
+          Checkbox(
+            value: _createRecoveryKey,
+            onChanged: (value) {
+              setState(() {
+                _createRecoveryKey = value!;
+              });
+            },
+          ),
+          const Text('Create a recovery key'),
+        ],
+      ),
+    );
+  }
+
+  // The key is not kept anywhere, this is the only time the user sees it
+  Future<void> _showRecoveryKey(String recoveryKey) {
+    return showDialog<void>(
+      context: context,
+      barrierDismissible: false,
+      builder: (context) => AlertDialog(
+        title: const Text('Your recovery key'),
+        content: Column(
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            const Text(
+              'Write this key down and keep it somewhere safe. It restores '
+              'your account if you forget your password, and it will not be '
+              'shown again.',
+            ),
+            const SizedBox(height: 16),
+            SelectableText(
+              recoveryKey,
+              style: GoogleFonts.robotoMono(fontWeight: FontWeight.w600),
+            ),
+          ],
+        ),
+        actions: [
+          ElevatedButton(
+            onPressed: () => Navigator.of(context).pop(),
+            child: const Text('I have saved it'),
+          ),
+        ],
+      ),
+    );
+  }
+
   Future<void> _handleRegistration() async {
     if (_formKey.currentState!.validate()) {
       try {
@@ -150,9 +202,17 @@ class _RegistrationPageState extends ConsumerState<RegistrationPage> {
               _passwordController.text,
               _confirmPasswordController.text,
               endpoint: url,
+              recoveryKey: _createRecoveryKey,
             );
         if (success) {
           await _secureStorage.setPassword(_passwordController.text);
+          if (mounted) {
+            final recoveryKey = ref.read(authProvider).recoveryKey;
+            if (recoveryKey != null) {
+              await _showRecoveryKey(recoveryKey);
+              ref.read(authProvider.notifier).clearRecoveryKey();
+            }
+          }
           if (mounted) {
             if (_addDummyData) {
               context.go(DummyDataProgressPage.path);
@@ -278,6 +338,7 @@ class _RegistrationPageState extends ConsumerState<RegistrationPage> {
                             duration: const Duration(milliseconds: 300),
                           ),
                           _buildRadioOptions(),
+                          _buildRecoveryKeyCheckbox(),
                           _buildDummyDataCheckbox(),
                           ElevatedButton(
                             onPressed: _handleRegistration,
@@ -49,25 +49,33 @@ pub fn crudlf_insert(input: TokenStream) -> TokenStream {
 
     let t = TokenStream::from(quote! {
         impl #name {
-            pub async fn insert(mut data: #name) -> Result<#name, sql::SqlError> {
+            pub async fn insert(data: #name) -> Result<#name, sql::SqlError> {
                 let mut conn = sql::get_db_connection()?;
                 let tx = conn.transaction()?;
 
+                let data = Self::insert_with(&tx, data)?;
+                tx.commit()?;
+                Ok(data)
+            }
+
+            /// Inserts on a connection or transaction owned by the caller.
+            pub fn insert_with(
+                conn: &rusqlite::Connection,
+                mut data: #name,
+            ) -> Result<#name, sql::SqlError> {
                 if data.id.is_none() {
                     let id = sql::get_ulid();
                     data.id = Some(id);
                 }
 
-                let result = tx.execute(
+                let result = conn.execute(
                     #query,
                     rusqlite::params![#(data.#struct_fields_names),*],
                 )?;
 
                 if result == 1 {
-                    tx.commit()?;
                     Ok(data)
                 } else {
-                    tx.rollback()?;
                     Err(sql::SqlError::QueryReturnedNoRows)
                 }
             }
@@ -121,23 +129,32 @@ pub fn crudlf_update(input: TokenStream) -> TokenStream {
         impl #name {
             pub async fn update(
                 id: String,
-                mut data: #name,
+                data: #name,
             ) -> Result<#name, sql::SqlError> {
                 let mut conn = sql::get_db_connection()?;
                 let tx = conn.transaction()?;
 
+                let data = Self::update_with(&tx, id, data)?;
+                tx.commit()?;
+                Ok(data)
+            }
+
+            /// Updates on a connection or transaction owned by the caller.
+            pub fn update_with(
+                conn: &rusqlite::Connection,
+                id: String,
+                mut data: #name,
+            ) -> Result<#name, sql::SqlError> {
                 data.id = Some(id);
 
-                let result = tx.execute(
+                let result = conn.execute(
                     #query,
                     rusqlite::params![#(data.#struct_fields_names,)* data.id],
                 )?;
 
                 if result == 1 {
-                    tx.commit()?;
                     Ok(data)
                 } else {
-                    tx.rollback()?;
                     Err(sql::SqlError::QueryReturnedNoRows)
                 }
             }
@@ -183,6 +200,14 @@ pub fn crudlf_select(input: TokenStream) -> TokenStream {
             pub async fn get(id: String) -> Result<#name, sql::SqlError> {
                 let conn = sql::get_db_connection()?;
 
+                Self::get_with(&conn, id)
+            }
+
+            /// Selects on a connection or transaction owned by the caller.
+            pub fn get_with(
+                conn: &rusqlite::Connection,
+                id: String,
+            ) -> Result<#name, sql::SqlError> {
                 let mut stmt = conn.prepare(#query)?;
                 let mut rows = stmt.query(rusqlite::params![id])?;
 
@@ -210,29 +235,9 @@ pub fn crudlf_delete(input: TokenStream) -> TokenStream {
 
     let name = input.ident.clone();
 
-    let db_fields: Vec<CrudlfField> = input
-        .fields
-        .iter()
-        .map(|field| get_db_field_name(field))
-        .filter(|field| !field.skip)
-        .collect();
-
-    let db_return_fields = db_fields
-        .iter()
-        .map(|field| field.db_field_name.clone())
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    // let struct_fields_names: Vec<Ident> = db_fields
-    //     .iter()
-    //     .map(|field| format_ident!("{}", field.struct_field_name.clone()))
-    //     .collect::<Vec<Ident>>();
-
     let db_table_name: String = get_db_table_name(input.clone());
-    let query = format!(
-        "DELETE FROM {} WHERE id = ? RETURNING {}",
-        db_table_name, db_return_fields
-    );
+    // `execute` rejects statements that return rows, so no RETURNING here
+    let query = format!("DELETE FROM {} WHERE id = ?", db_table_name);
 
     let t = TokenStream::from(quote! {
         impl #name {
@@ -240,16 +245,24 @@ pub fn crudlf_delete(input: TokenStream) -> TokenStream {
                 let mut conn = sql::get_db_connection()?;
                 let tx = conn.transaction()?;
 
-                let result = tx.execute(#query, rusqlite::params![id])?;
+                let data = Self::delete_with(&tx, id)?;
+                tx.commit()?;
+                Ok(data)
+            }
+
+            /// Deletes on a connection or transaction owned by the caller.
+            pub fn delete_with(
+                conn: &rusqlite::Connection,
+                id: String,
+            ) -> Result<#name, sql::SqlError> {
+                let result = conn.execute(#query, rusqlite::params![id])?;
 
                 if result == 1 {
-                    tx.commit()?;
                     Ok(#name {
                         id: Some(id),
                         ..Default::default()
                     })
                 } else {
-                    tx.rollback()?;
                     Err(sql::SqlError::QueryReturnedNoRows)
                 }
             }
@@ -285,6 +298,14 @@ pub fn crudlf_list_filter(input: TokenStream) -> TokenStream {
             pub async fn get_list(query: sql::HttpQuery) -> Result<Vec<#name>, sql::SqlError> {
                 let conn = sql::get_db_connection()?;
 
+                Self::get_list_with(&conn, query)
+            }
+
+            /// Lists on a connection or transaction owned by the caller.
+            pub fn get_list_with(
+                conn: &rusqlite::Connection,
+                query: sql::HttpQuery,
+            ) -> Result<Vec<#name>, sql::SqlError> {
                 let mut query = sql::SqLiteQueryBuilder::new(#db_table_name.to_string(), query);
                 let (query_string, params) = query.build_sqlite_query();
 
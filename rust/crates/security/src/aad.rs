@@ -0,0 +1,47 @@
+/// Associated data that a ciphertext is bound to.
+///
+/// Every field is length prefixed, so `("ab", "c")` and `("a", "bc")` never
+/// encode to the same bytes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssociatedData {
+    encoded: Vec<u8>,
+}
+
+impl AssociatedData {
+    pub fn new() -> Self {
+        AssociatedData::default()
+    }
+
+    pub fn field(mut self, value: impl AsRef<[u8]>) -> Self {
+        let value = value.as_ref();
+        self.encoded
+            .extend_from_slice(&(value.len() as u32).to_be_bytes());
+        self.encoded.extend_from_slice(value);
+        self
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.encoded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_are_unambiguous() {
+        let first = AssociatedData::new().field("ab").field("c");
+        let second = AssociatedData::new().field("a").field("bc");
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_field_order_matters() {
+        let first = AssociatedData::new().field("logins").field("password");
+        let second = AssociatedData::new().field("password").field("logins");
+
+        assert_ne!(first.as_bytes(), second.as_bytes());
+    }
+}
@@ -0,0 +1,84 @@
+use std::time::{Duration, Instant};
+
+use crate::encryption::{
+    derive_key, EncryptionError, KdfParams, MAX_MEMORY_COST, MAX_TIME_COST, MIN_MEMORY_COST,
+    PARALLELISM,
+};
+
+/// Benchmarks Argon2id on this machine and picks parameters that take about
+/// `target_ms` to derive a key.
+///
+/// `max_memory` is the memory budget in KiB. The full budget is used when a
+/// single pass fits the target and extra passes fill the remaining time,
+/// otherwise memory is reduced until one pass fits, but never below 8 MB.
+/// Budgets above 1 GB are capped there.
+pub fn calibrate_kdf(target_ms: u64, max_memory: u32) -> Result<KdfParams, EncryptionError> {
+    if target_ms == 0 || max_memory < MIN_MEMORY_COST {
+        return Err(EncryptionError::InvalidKdfParams);
+    }
+    let max_memory = max_memory.min(MAX_MEMORY_COST);
+    let target = Duration::from_millis(target_ms);
+
+    // Every memory block gets a lane, round down to a multiple of the lanes
+    let mut params = KdfParams {
+        memory_cost: max_memory - max_memory % (8 * PARALLELISM),
+        time_cost: 1,
+        parallelism: PARALLELISM,
+    };
+    let pass = measure(&params)?;
+
+    if pass > target {
+        // One pass is too slow, derivation time scales with memory
+        let scaled = params.memory_cost as f64 * target.as_secs_f64() / pass.as_secs_f64();
+        let memory_cost = (scaled as u32).max(MIN_MEMORY_COST);
+        params.memory_cost = memory_cost - memory_cost % (8 * PARALLELISM);
+    } else {
+        let passes = target.as_secs_f64() / pass.as_secs_f64().max(f64::EPSILON);
+        params.time_cost = (passes as u32).clamp(1, MAX_TIME_COST);
+    }
+
+    params.validate()?;
+    Ok(params)
+}
+
+fn measure(params: &KdfParams) -> Result<Duration, EncryptionError> {
+    let start = Instant::now();
+    derive_key("calibration", &[0u8; 16], params)?;
+    Ok(start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibrate_kdf_stays_within_budget() {
+        let params = calibrate_kdf(50, 16384).unwrap();
+
+        assert!(params.memory_cost <= 16384);
+        assert!(params.memory_cost >= MIN_MEMORY_COST);
+        assert!((1..=MAX_TIME_COST).contains(&params.time_cost));
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn test_calibrate_kdf_tight_target_reduces_memory() {
+        // No machine derives a 64 MB key in a millisecond
+        let params = calibrate_kdf(1, 65536).unwrap();
+
+        assert_eq!(params.time_cost, 1);
+        assert!(params.memory_cost < 65536);
+    }
+
+    #[test]
+    fn test_calibrate_kdf_rejects_small_budget() {
+        assert!(matches!(
+            calibrate_kdf(500, 4096),
+            Err(EncryptionError::InvalidKdfParams)
+        ));
+        assert!(matches!(
+            calibrate_kdf(0, 16384),
+            Err(EncryptionError::InvalidKdfParams)
+        ));
+    }
+}
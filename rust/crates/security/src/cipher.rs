@@ -0,0 +1,240 @@
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm,
+};
+use chacha20poly1305::XChaCha20Poly1305;
+use rand::{rngs::OsRng, RngCore};
+
+use crate::EncryptionError;
+
+/// AEAD cipher suites an envelope can be sealed with.
+///
+/// The id is written into every envelope, so data sealed with either suite
+/// stays readable whatever the current policy is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cipher {
+    /// AES-256-GCM with a random 96-bit nonce
+    Aes256Gcm,
+    /// XChaCha20-Poly1305 with a random 192-bit nonce
+    XChaCha20Poly1305,
+}
+
+impl Cipher {
+    pub fn id(self) -> u8 {
+        match self {
+            Cipher::Aes256Gcm => 1,
+            Cipher::XChaCha20Poly1305 => 2,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Result<Cipher, EncryptionError> {
+        match id {
+            1 => Ok(Cipher::Aes256Gcm),
+            2 => Ok(Cipher::XChaCha20Poly1305),
+            _ => Err(EncryptionError::UnsupportedCipher),
+        }
+    }
+
+    pub fn nonce_length(self) -> usize {
+        match self {
+            Cipher::Aes256Gcm => 12,
+            Cipher::XChaCha20Poly1305 => 24,
+        }
+    }
+
+    pub(crate) fn random_nonce(self) -> Vec<u8> {
+        let mut nonce = vec![0u8; self.nonce_length()];
+        OsRng.fill_bytes(&mut nonce);
+        nonce
+    }
+
+    pub(crate) fn seal(
+        self,
+        key: &[u8],
+        nonce: &[u8],
+        msg: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            Cipher::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKeyLength)?
+                .encrypt(nonce.into(), payload),
+            Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKeyLength)?
+                .encrypt(nonce.into(), payload),
+        }
+        .map_err(|_| EncryptionError::EncryptionFailed)
+    }
+
+    pub(crate) fn open(
+        self,
+        key: &[u8],
+        nonce: &[u8],
+        msg: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            Cipher::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKeyLength)?
+                .decrypt(nonce.into(), payload),
+            Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EncryptionError::InvalidKeyLength)?
+                .decrypt(nonce.into(), payload),
+        }
+        .map_err(|_| EncryptionError::DecryptionFailed)
+    }
+}
+
+/// Which cipher suite new envelopes are sealed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CipherPolicy {
+    /// AES-256-GCM where the CPU has AES instructions, XChaCha20-Poly1305
+    /// everywhere else
+    #[default]
+    Auto,
+    Aes256Gcm,
+    XChaCha20Poly1305,
+}
+
+impl CipherPolicy {
+    fn id(self) -> u8 {
+        match self {
+            CipherPolicy::Auto => 0,
+            CipherPolicy::Aes256Gcm => 1,
+            CipherPolicy::XChaCha20Poly1305 => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> CipherPolicy {
+        match id {
+            1 => CipherPolicy::Aes256Gcm,
+            2 => CipherPolicy::XChaCha20Poly1305,
+            _ => CipherPolicy::Auto,
+        }
+    }
+}
+
+impl FromStr for CipherPolicy {
+    type Err = EncryptionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "auto" => Ok(CipherPolicy::Auto),
+            "aes-256-gcm" => Ok(CipherPolicy::Aes256Gcm),
+            "xchacha20-poly1305" => Ok(CipherPolicy::XChaCha20Poly1305),
+            _ => Err(EncryptionError::UnsupportedCipher),
+        }
+    }
+}
+
+static CIPHER_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the process wide policy used when sealing new envelopes.
+pub fn set_cipher_policy(policy: CipherPolicy) {
+    CIPHER_POLICY.store(policy.id(), Ordering::Relaxed);
+}
+
+pub fn cipher_policy() -> CipherPolicy {
+    CipherPolicy::from_id(CIPHER_POLICY.load(Ordering::Relaxed))
+}
+
+/// The cipher suite new envelopes are sealed with under the current policy.
+pub fn preferred_cipher() -> Cipher {
+    match cipher_policy() {
+        CipherPolicy::Auto if has_aes_instructions() => Cipher::Aes256Gcm,
+        CipherPolicy::Auto => Cipher::XChaCha20Poly1305,
+        CipherPolicy::Aes256Gcm => Cipher::Aes256Gcm,
+        CipherPolicy::XChaCha20Poly1305 => Cipher::XChaCha20Poly1305,
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn has_aes_instructions() -> bool {
+    std::arch::is_x86_feature_detected!("aes") && std::arch::is_x86_feature_detected!("pclmulqdq")
+}
+
+#[cfg(target_arch = "aarch64")]
+fn has_aes_instructions() -> bool {
+    std::arch::is_aarch64_feature_detected!("aes")
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn has_aes_instructions() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CIPHERS: [Cipher; 2] = [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305];
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let key = [7u8; 32];
+        for cipher in CIPHERS {
+            let nonce = cipher.random_nonce();
+            let sealed = cipher.seal(&key, &nonce, b"hunter2", b"aad").unwrap();
+
+            assert_eq!(
+                cipher.open(&key, &nonce, &sealed, b"aad").unwrap(),
+                b"hunter2"
+            );
+            assert!(cipher.open(&key, &nonce, &sealed, b"other").is_err());
+        }
+    }
+
+    #[test]
+    fn test_suites_do_not_open_each_other() {
+        let key = [7u8; 32];
+        let nonce = Cipher::XChaCha20Poly1305.random_nonce();
+        let sealed = Cipher::XChaCha20Poly1305
+            .seal(&key, &nonce, b"hunter2", b"")
+            .unwrap();
+
+        assert!(Cipher::Aes256Gcm
+            .open(&key, &nonce[..12], &sealed, b"")
+            .is_err());
+    }
+
+    #[test]
+    fn test_cipher_ids() {
+        for cipher in CIPHERS {
+            assert_eq!(Cipher::from_id(cipher.id()).unwrap(), cipher);
+        }
+        assert!(matches!(
+            Cipher::from_id(9),
+            Err(EncryptionError::UnsupportedCipher)
+        ));
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(
+            "XChaCha20-Poly1305".parse::<CipherPolicy>().unwrap(),
+            CipherPolicy::XChaCha20Poly1305
+        );
+        assert_eq!("auto".parse::<CipherPolicy>().unwrap(), CipherPolicy::Auto);
+        assert!("rot13".parse::<CipherPolicy>().is_err());
+    }
+
+    // The only test that changes the process wide policy
+    #[test]
+    fn test_policy_selects_cipher() {
+        set_cipher_policy(CipherPolicy::XChaCha20Poly1305);
+        assert_eq!(preferred_cipher(), Cipher::XChaCha20Poly1305);
+
+        set_cipher_policy(CipherPolicy::Aes256Gcm);
+        assert_eq!(preferred_cipher(), Cipher::Aes256Gcm);
+
+        set_cipher_policy(CipherPolicy::Auto);
+        assert_eq!(cipher_policy(), CipherPolicy::Auto);
+    }
+}
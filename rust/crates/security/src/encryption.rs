@@ -1,15 +1,13 @@
-use aes_gcm::{
-    aead::{Aead, KeyInit},
-    Aes256Gcm, Nonce,
-};
 use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
+    password_hash::{rand_core::OsRng, SaltString},
     Argon2, ParamsBuilder,
 };
 use base64::{engine::general_purpose, Engine as _};
-use rand::Rng;
+use rand::RngCore;
 use thiserror::Error;
-use zeroize::Zeroize;
+use zeroize::Zeroizing;
+
+use crate::{preferred_cipher, Cipher};
 
 #[derive(Error, Debug)]
 pub enum EncryptionError {
@@ -23,32 +21,122 @@ pub enum EncryptionError {
     UnsupportedVersion,
     #[error("Argon2 parameters builder failed")]
     Argon2ParametersBuilderFailed,
+    #[error("Invalid key length")]
+    InvalidKeyLength,
+    #[error("Invalid key derivation parameters")]
+    InvalidKdfParams,
+    #[error("Unsupported key derivation function")]
+    UnsupportedKdf,
+    #[error("Unsupported cipher")]
+    UnsupportedCipher,
+    #[error("Invalid recovery key")]
+    InvalidRecoveryKey,
+    #[error("Invalid share threshold")]
+    InvalidShareThreshold,
+    #[error("Invalid share")]
+    InvalidShare,
+    #[error("Not enough shares")]
+    NotEnoughShares,
+    #[error("Invalid one-time password secret")]
+    InvalidOtpSecret,
+    #[error("Invalid one-time password parameters")]
+    InvalidOtpParams,
+    #[error("Unsupported passkey algorithm")]
+    UnsupportedPasskeyAlgorithm,
+    #[error("Invalid passkey key")]
+    InvalidPasskeyKey,
+    #[error("Invalid client data hash")]
+    InvalidClientDataHash,
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
-// Constants for Argon2 parameters
-// const MEMORY_COST: u32 = 65536; // 64 MB
-// const MEMORY_COST: u32 = 32768; // 32 MB
+// Constants for Argon2 parameters, `calibrate_kdf` tunes them per device
 const MEMORY_COST: u32 = 16384; // 16 MB
-                                // const MEMORY_COST: u32 = 8192; // 8 MB
-                                // const MEMORY_COST: u32 = 4096; // 4 MB
 const TIME_COST: u32 = 3;
-const PARALLELISM: u32 = 4;
+pub(crate) const PARALLELISM: u32 = 4;
+
+// Lower bounds accepted for stored parameters
+pub(crate) const MIN_MEMORY_COST: u32 = 8192; // 8 MB
+pub(crate) const MAX_MEMORY_COST: u32 = 1024 * 1024; // 1 GB
+pub(crate) const MAX_TIME_COST: u32 = 16;
+
+// Version 1 envelopes don't record their parameters, these must never change
+const V1_PARAMS: KdfParams = KdfParams {
+    memory_cost: 16384,
+    time_cost: 3,
+    parallelism: 4,
+};
 
 // Explicitly define the structure of encrypted data
-const SALT_LENGTH: usize = 22; // Base64 encoded salt length
-const NONCE_LENGTH: usize = 12;
+const VERSION_1: u8 = 1;
+const VERSION_2: u8 = 2;
+pub(crate) const KDF_ARGON2ID: u8 = 1;
+const SALT_LENGTH: usize = 22; // Base64 encoded salt length (version 1)
+pub(crate) const V2_SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12; // AES-GCM nonce length (version 1)
+const KEY_LENGTH: usize = 32; // AES-256 key length
+
+// version, kdf, m_cost, t_cost, p_cost, cipher, salt length
+const V2_FIXED_HEADER_LENGTH: usize = 1 + 1 + 4 + 4 + 4 + 1 + 1;
+
+/// Argon2id cost parameters used to derive a key from a password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory size in KiB
+    pub memory_cost: u32,
+    /// Number of iterations
+    pub time_cost: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
 
-pub fn encrypt(data: &str, password: &str) -> Result<String, EncryptionError> {
-    // Generate a random salt
-    let salt = SaltString::generate(&mut OsRng);
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams {
+            memory_cost: MEMORY_COST,
+            time_cost: TIME_COST,
+            parallelism: PARALLELISM,
+        }
+    }
+}
 
-    // Derive a key from the password with explicit parameters
+impl KdfParams {
+    /// Checks the parameters are within the bounds this crate accepts, so a
+    /// stored value can't weaken or stall key derivation.
+    pub fn validate(&self) -> Result<(), EncryptionError> {
+        if !(MIN_MEMORY_COST..=MAX_MEMORY_COST).contains(&self.memory_cost)
+            || !(1..=MAX_TIME_COST).contains(&self.time_cost)
+            || !(1..=PARALLELISM).contains(&self.parallelism)
+        {
+            return Err(EncryptionError::InvalidKdfParams);
+        }
+        Ok(())
+    }
+}
+
+/// The parsed header of an encrypted envelope.
+struct Envelope<'a> {
+    version: u8,
+    params: KdfParams,
+    cipher: Cipher,
+    salt: Vec<u8>,
+    header: &'a [u8],
+    nonce: &'a [u8],
+    ciphertext: &'a [u8],
+}
+
+pub(crate) fn derive_key(
+    password: &str,
+    salt: &[u8],
+    params: &KdfParams,
+) -> Result<Zeroizing<Vec<u8>>, EncryptionError> {
     let mut argon2_params = ParamsBuilder::new();
     argon2_params
-        .m_cost(MEMORY_COST)
-        .t_cost(TIME_COST)
-        .p_cost(PARALLELISM)
-        .output_len(32); // AES-256 key length
+        .m_cost(params.memory_cost)
+        .t_cost(params.time_cost)
+        .p_cost(params.parallelism)
+        .output_len(KEY_LENGTH);
     let argon2 = Argon2::new(
         argon2::Algorithm::Argon2id,
         argon2::Version::V0x13,
@@ -57,34 +145,131 @@ pub fn encrypt(data: &str, password: &str) -> Result<String, EncryptionError> {
             .map_err(|_| EncryptionError::Argon2ParametersBuilderFailed)?,
     );
 
-    let mut key = argon2
-        .hash_password(password.as_bytes(), &salt)
-        .map_err(|_| EncryptionError::KeyDerivationFailed)?
-        .hash
-        .ok_or(EncryptionError::KeyDerivationFailed)?
-        .as_bytes()
-        .to_vec();
+    let mut key = Zeroizing::new(vec![0u8; KEY_LENGTH]);
+    argon2
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|_| EncryptionError::KeyDerivationFailed)?;
+    Ok(key)
+}
+
+fn parse_envelope(decoded: &[u8]) -> Result<Envelope<'_>, EncryptionError> {
+    let version = *decoded.first().ok_or(EncryptionError::DecryptionFailed)?;
+    match version {
+        VERSION_1 => {
+            if decoded.len() < 1 + SALT_LENGTH + NONCE_LENGTH {
+                return Err(EncryptionError::DecryptionFailed);
+            }
+            let salt = SaltString::from_b64(&String::from_utf8_lossy(&decoded[1..1 + SALT_LENGTH]))
+                .map_err(|_| EncryptionError::DecryptionFailed)?;
+            let mut salt_buffer = [0u8; 64];
+            let salt = salt
+                .decode_b64(&mut salt_buffer)
+                .map_err(|_| EncryptionError::DecryptionFailed)?
+                .to_vec();
+            Ok(Envelope {
+                version,
+                params: V1_PARAMS,
+                cipher: Cipher::Aes256Gcm,
+                salt,
+                header: &[],
+                nonce: &decoded[1 + SALT_LENGTH..1 + SALT_LENGTH + NONCE_LENGTH],
+                ciphertext: &decoded[1 + SALT_LENGTH + NONCE_LENGTH..],
+            })
+        }
+        VERSION_2 => {
+            if decoded.len() < V2_FIXED_HEADER_LENGTH {
+                return Err(EncryptionError::DecryptionFailed);
+            }
+            if decoded[1] != KDF_ARGON2ID {
+                return Err(EncryptionError::UnsupportedKdf);
+            }
+            let read_u32 =
+                |offset: usize| u32::from_be_bytes(decoded[offset..offset + 4].try_into().unwrap());
+            let params = KdfParams {
+                memory_cost: read_u32(2),
+                time_cost: read_u32(6),
+                parallelism: read_u32(10),
+            };
+            // Refuse attacker-chosen costs before they ever reach Argon2
+            params
+                .validate()
+                .map_err(|_| EncryptionError::DecryptionFailed)?;
+            let cipher = Cipher::from_id(decoded[14])?;
+            let nonce_length = cipher.nonce_length();
+            let salt_length = decoded[15] as usize;
+            let header_length = V2_FIXED_HEADER_LENGTH + salt_length;
+            if decoded.len() < header_length + nonce_length {
+                return Err(EncryptionError::DecryptionFailed);
+            }
+            Ok(Envelope {
+                version,
+                params,
+                cipher,
+                salt: decoded[V2_FIXED_HEADER_LENGTH..header_length].to_vec(),
+                header: &decoded[..header_length],
+                nonce: &decoded[header_length..header_length + nonce_length],
+                ciphertext: &decoded[header_length + nonce_length..],
+            })
+        }
+        _ => Err(EncryptionError::UnsupportedVersion),
+    }
+}
+
+fn decode_envelope(encrypted_data: &str) -> Result<Vec<u8>, EncryptionError> {
+    general_purpose::STANDARD
+        .decode(encrypted_data)
+        .map_err(|_| EncryptionError::DecryptionFailed)
+}
+
+pub fn encrypt(data: &str, password: &str) -> Result<String, EncryptionError> {
+    encrypt_with_params(data, password, &KdfParams::default())
+}
 
-    // Create AES-GCM cipher
-    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| EncryptionError::EncryptionFailed)?;
+/// Encrypts `data` into a version 2 envelope.
+///
+/// The header records the KDF, its parameters, the cipher and the salt, so
+/// the envelope stays readable after the defaults are tuned. The cipher is
+/// picked by the current [`CipherPolicy`](crate::CipherPolicy).
+pub fn encrypt_with_params(
+    data: &str,
+    password: &str,
+    params: &KdfParams,
+) -> Result<String, EncryptionError> {
+    encrypt_with_cipher(data, password, params, preferred_cipher())
+}
+
+/// Encrypts `data` into a version 2 envelope sealed with `cipher`.
+pub fn encrypt_with_cipher(
+    data: &str,
+    password: &str,
+    params: &KdfParams,
+    cipher: Cipher,
+) -> Result<String, EncryptionError> {
+    // Generate a random salt
+    let mut salt = [0u8; V2_SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+
+    // Derive a key from the password with explicit parameters
+    let key = derive_key(password, &salt, params)?;
 
     // Generate a random nonce
-    let x = rand::thread_rng().gen::<[u8; NONCE_LENGTH]>();
-    let nonce = Nonce::from_slice(&x);
+    let nonce = cipher.random_nonce();
+
+    // Build the header, it is authenticated along with the data
+    let mut result = vec![VERSION_2, KDF_ARGON2ID];
+    result.extend_from_slice(&params.memory_cost.to_be_bytes());
+    result.extend_from_slice(&params.time_cost.to_be_bytes());
+    result.extend_from_slice(&params.parallelism.to_be_bytes());
+    result.push(cipher.id());
+    result.push(V2_SALT_LENGTH as u8);
+    result.extend_from_slice(&salt);
 
     // Encrypt the data
-    let ciphertext = cipher
-        .encrypt(nonce, data.as_bytes())
-        .map_err(|_| EncryptionError::EncryptionFailed)?;
-
-    // Combine version, salt, nonce, and ciphertext
-    let mut result = vec![1]; // Version 1
-    result.extend_from_slice(salt.as_str().as_bytes());
-    result.extend_from_slice(nonce);
-    result.extend_from_slice(&ciphertext);
+    let ciphertext = cipher.seal(&key, &nonce, data.as_bytes(), &result)?;
 
-    // Securely clear the key from memory
-    key.zeroize();
+    // Combine header, nonce, and ciphertext
+    result.extend_from_slice(&nonce);
+    result.extend_from_slice(&ciphertext);
 
     // Encode the result as base64
     Ok(general_purpose::STANDARD.encode(result))
@@ -92,58 +277,58 @@ pub fn encrypt(data: &str, password: &str) -> Result<String, EncryptionError> {
 
 pub fn decrypt(encrypted_data: &str, password: &str) -> Result<String, EncryptionError> {
     // Decode the base64 input
-    let decoded = general_purpose::STANDARD
-        .decode(encrypted_data)
-        .map_err(|_| EncryptionError::DecryptionFailed)?;
+    let decoded = decode_envelope(encrypted_data)?;
 
-    // Extract version, salt, nonce, and ciphertext
-    if decoded.len() < 1 + SALT_LENGTH + NONCE_LENGTH {
-        return Err(EncryptionError::DecryptionFailed);
-    }
-    let version = decoded[0];
-    if version != 1 {
-        return Err(EncryptionError::UnsupportedVersion);
-    }
-    let salt = SaltString::from_b64(&String::from_utf8_lossy(&decoded[1..1 + SALT_LENGTH]))
-        .map_err(|_| EncryptionError::DecryptionFailed)?;
-    let nonce = Nonce::from_slice(&decoded[1 + SALT_LENGTH..1 + SALT_LENGTH + NONCE_LENGTH]);
-    let ciphertext = &decoded[1 + SALT_LENGTH + NONCE_LENGTH..];
+    // Extract version, parameters, salt, nonce, and ciphertext
+    let envelope = parse_envelope(&decoded)?;
 
     // Derive the key from the password
-    let mut argon2_params = ParamsBuilder::new();
-    argon2_params
-        .m_cost(MEMORY_COST)
-        .t_cost(TIME_COST)
-        .p_cost(PARALLELISM)
-        .output_len(32); // AES-256 key length
-    let argon2 = Argon2::new(
-        argon2::Algorithm::Argon2id,
-        argon2::Version::V0x13,
-        argon2_params
-            .build()
-            .map_err(|_| EncryptionError::Argon2ParametersBuilderFailed)?,
-    );
+    let key = derive_key(password, &envelope.salt, &envelope.params)?;
 
-    let mut key = argon2
-        .hash_password(password.as_bytes(), &salt)
-        .map_err(|_| EncryptionError::KeyDerivationFailed)?
-        .hash
-        .ok_or(EncryptionError::KeyDerivationFailed)?
-        .as_bytes()
-        .to_vec();
+    // Decrypt the data with the cipher named in the header
+    let plaintext =
+        envelope
+            .cipher
+            .open(&key, envelope.nonce, envelope.ciphertext, envelope.header)?;
 
-    // Create AES-GCM cipher
-    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| EncryptionError::DecryptionFailed)?;
+    String::from_utf8(plaintext).map_err(|_| EncryptionError::DecryptionFailed)
+}
 
-    // Decrypt the data
-    let plaintext = cipher
-        .decrypt(nonce, ciphertext)
-        .map_err(|_| EncryptionError::DecryptionFailed)?;
+/// Returns the format version of an encrypted envelope.
+pub fn envelope_version(encrypted_data: &str) -> Result<u8, EncryptionError> {
+    let decoded = decode_envelope(encrypted_data)?;
+    Ok(parse_envelope(&decoded)?.version)
+}
 
-    // Securely clear the key from memory
-    key.zeroize();
+/// Returns the KDF parameters an envelope was encrypted with.
+pub fn envelope_params(encrypted_data: &str) -> Result<KdfParams, EncryptionError> {
+    let decoded = decode_envelope(encrypted_data)?;
+    Ok(parse_envelope(&decoded)?.params)
+}
 
-    String::from_utf8(plaintext).map_err(|_| EncryptionError::DecryptionFailed)
+/// Returns the cipher suite an envelope was sealed with.
+pub fn envelope_cipher(encrypted_data: &str) -> Result<Cipher, EncryptionError> {
+    let decoded = decode_envelope(encrypted_data)?;
+    Ok(parse_envelope(&decoded)?.cipher)
+}
+
+/// Whether an envelope is older than version 2 or was derived with other
+/// parameters than `params`.
+pub fn needs_reencrypt(encrypted_data: &str, params: &KdfParams) -> Result<bool, EncryptionError> {
+    let decoded = decode_envelope(encrypted_data)?;
+    let envelope = parse_envelope(&decoded)?;
+    Ok(envelope.version != VERSION_2 || envelope.params != *params)
+}
+
+/// Decrypts an envelope of any supported version and encrypts it again as
+/// version 2 with `params`.
+pub fn reencrypt(
+    encrypted_data: &str,
+    password: &str,
+    params: &KdfParams,
+) -> Result<String, EncryptionError> {
+    let plaintext = Zeroizing::new(decrypt(encrypted_data, password)?);
+    encrypt_with_params(&plaintext, password, params)
 }
 
 #[cfg(test)]
@@ -188,6 +373,157 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // Produced by the version 1 implementation
+    const V1_CIPHERTEXT: &str =
+        "AXpMRTVYWkhVQ1h1b25KcE15NTJBMnehVTpZcwlTVHYTzdglaK++XRUanFznigPZ8VM8o7jkr4zzvB/jKoXZLQ==";
+
+    #[test]
+    fn test_decrypt_v1() {
+        assert_eq!(envelope_version(V1_CIPHERTEXT).unwrap(), 1);
+        assert_eq!(
+            decrypt(V1_CIPHERTEXT, "secret_password").unwrap(),
+            "Hello, world!"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_writes_v2() {
+        let encrypted = encrypt("Hello, world!", "secret_password").unwrap();
+
+        assert_eq!(envelope_version(&encrypted).unwrap(), 2);
+        assert_eq!(envelope_params(&encrypted).unwrap(), KdfParams::default());
+    }
+
+    #[test]
+    fn test_encrypt_with_params_round_trip() {
+        let params = KdfParams {
+            memory_cost: 8192,
+            time_cost: 2,
+            parallelism: 1,
+        };
+        let encrypted = encrypt_with_params("Hello, world!", "secret_password", &params).unwrap();
+
+        // Decryption reads the parameters from the header
+        assert_eq!(envelope_params(&encrypted).unwrap(), params);
+        assert_eq!(
+            decrypt(&encrypted, "secret_password").unwrap(),
+            "Hello, world!"
+        );
+    }
+
+    #[test]
+    fn test_reencrypt_upgrades_v1() {
+        let params = KdfParams::default();
+        assert!(needs_reencrypt(V1_CIPHERTEXT, &params).unwrap());
+
+        let upgraded = reencrypt(V1_CIPHERTEXT, "secret_password", &params).unwrap();
+
+        assert_eq!(envelope_version(&upgraded).unwrap(), 2);
+        assert!(!needs_reencrypt(&upgraded, &params).unwrap());
+        assert_eq!(
+            decrypt(&upgraded, "secret_password").unwrap(),
+            "Hello, world!"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_with_each_cipher() {
+        let params = KdfParams::default();
+        for cipher in [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            let encrypted =
+                encrypt_with_cipher("Hello, world!", "secret_password", &params, cipher).unwrap();
+
+            assert_eq!(envelope_cipher(&encrypted).unwrap(), cipher);
+            assert_eq!(
+                decrypt(&encrypted, "secret_password").unwrap(),
+                "Hello, world!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_suite_cipher_swap_fails() {
+        let encrypted = encrypt_with_cipher(
+            "Hello, world!",
+            "secret_password",
+            &KdfParams::default(),
+            Cipher::XChaCha20Poly1305,
+        )
+        .unwrap();
+        let mut decoded = general_purpose::STANDARD.decode(&encrypted).unwrap();
+        // Claim the envelope was sealed with AES-GCM
+        decoded[14] = Cipher::Aes256Gcm.id();
+        let swapped = general_purpose::STANDARD.encode(decoded);
+
+        assert!(decrypt(&swapped, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_v1_stays_aes_gcm() {
+        assert_eq!(envelope_cipher(V1_CIPHERTEXT).unwrap(), Cipher::Aes256Gcm);
+    }
+
+    #[test]
+    fn test_validate_kdf_params() {
+        assert!(KdfParams::default().validate().is_ok());
+        assert!(V1_PARAMS.validate().is_ok());
+
+        let weak = KdfParams {
+            memory_cost: 1024,
+            ..KdfParams::default()
+        };
+        assert!(matches!(
+            weak.validate(),
+            Err(EncryptionError::InvalidKdfParams)
+        ));
+
+        let oversized = KdfParams {
+            memory_cost: MAX_MEMORY_COST + 1,
+            ..KdfParams::default()
+        };
+        assert!(matches!(
+            oversized.validate(),
+            Err(EncryptionError::InvalidKdfParams)
+        ));
+    }
+
+    #[test]
+    fn test_tampered_header_fails() {
+        let encrypted = encrypt("Hello, world!", "secret_password").unwrap();
+        let mut decoded = general_purpose::STANDARD.decode(&encrypted).unwrap();
+        // Flip a bit of the salt
+        decoded[V2_FIXED_HEADER_LENGTH] ^= 1;
+        let tampered = general_purpose::STANDARD.encode(decoded);
+
+        assert!(decrypt(&tampered, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_oversized_header_params_fail() {
+        let encrypted = encrypt("Hello, world!", "secret_password").unwrap();
+        let mut decoded = general_purpose::STANDARD.decode(&encrypted).unwrap();
+        // Claim 4 TiB of memory and an absurd number of passes
+        decoded[2..6].copy_from_slice(&u32::MAX.to_be_bytes());
+        decoded[6..10].copy_from_slice(&u32::MAX.to_be_bytes());
+        let forged = general_purpose::STANDARD.encode(decoded);
+
+        assert!(matches!(
+            decrypt(&forged, "secret_password"),
+            Err(EncryptionError::DecryptionFailed)
+        ));
+        assert!(envelope_params(&forged).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let encoded = general_purpose::STANDARD.encode([9u8; 64]);
+
+        assert!(matches!(
+            decrypt(&encoded, "secret_password"),
+            Err(EncryptionError::UnsupportedVersion)
+        ));
+    }
+
     #[test]
     fn test_timing_attack_resistance() {
         use statrs::distribution::Normal;
@@ -0,0 +1,415 @@
+use base64::{engine::general_purpose, Engine as _};
+use hmac::{Hmac, Mac};
+use rand::{rngs::OsRng, RngCore};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::{
+    decrypt, encrypt_with_params, preferred_cipher, AssociatedData, Cipher, EncryptionError,
+    KdfParams, SecretBytes,
+};
+
+// A data key is a random AES-256 key that never leaves memory unwrapped
+pub const DATA_KEY_LENGTH: usize = 32;
+
+// Values sealed under a data key
+//
+// version 1: AES-GCM, no associated data      [1][nonce][ciphertext]
+// version 2: AES-GCM, bound to associated data [2][nonce][ciphertext]
+// version 3: bound to associated data          [3][cipher][nonce][ciphertext]
+pub const VERSION_UNBOUND: u8 = 1;
+pub const VERSION_BOUND_AES_GCM: u8 = 2;
+pub const VERSION_BOUND: u8 = 3;
+
+/// Generates a random per-user data key.
+pub fn generate_data_key() -> SecretBytes {
+    let mut key = Zeroizing::new(vec![0u8; DATA_KEY_LENGTH]);
+    OsRng.fill_bytes(&mut key);
+    key.into()
+}
+
+/// Wraps a data key with a key-encryption key derived from `password`.
+///
+/// The derivation (Argon2id with a random salt) is the same one used by
+/// [`encrypt`], so the wrapped key is an ordinary password envelope.
+pub fn wrap_data_key(data_key: &[u8], password: &str) -> Result<String, EncryptionError> {
+    wrap_data_key_with_params(data_key, password, &KdfParams::default())
+}
+
+/// Wraps a data key with a key-encryption key derived with explicit
+/// Argon2id parameters.
+pub fn wrap_data_key_with_params(
+    data_key: &[u8],
+    password: &str,
+    params: &KdfParams,
+) -> Result<String, EncryptionError> {
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    let encoded = Zeroizing::new(general_purpose::STANDARD.encode(data_key));
+    encrypt_with_params(&encoded, password, params)
+}
+
+/// Recovers a data key previously wrapped with [`wrap_data_key`].
+pub fn unwrap_data_key(wrapped_key: &str, password: &str) -> Result<SecretBytes, EncryptionError> {
+    let encoded = Zeroizing::new(decrypt(wrapped_key, password)?);
+    let data_key = Zeroizing::new(
+        general_purpose::STANDARD
+            .decode(encoded.as_bytes())
+            .map_err(|_| EncryptionError::DecryptionFailed)?,
+    );
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    Ok(data_key.into())
+}
+
+/// Computes a keyed fingerprint (HMAC-SHA256) of `data` under a data key.
+///
+/// Equal values give equal fingerprints for the same key and `context`, so
+/// they can be compared without opening them, while a fingerprint reveals
+/// nothing about the value to anyone without the key.
+pub fn keyed_hash(key: &[u8], context: &str, data: &[u8]) -> Result<String, EncryptionError> {
+    if key.len() != DATA_KEY_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key).map_err(|_| EncryptionError::InvalidKeyLength)?;
+    mac.update(context.as_bytes());
+    mac.update(&[0]);
+    mac.update(data);
+    Ok(general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
+}
+
+/// Seals `data` with AES-256-GCM under a raw data key.
+///
+/// Unlike [`encrypt`] there is no key derivation here, which keeps per-field
+/// encryption cheap enough to run on every read and write.
+pub fn encrypt_with_key(data: &str, key: &[u8]) -> Result<String, EncryptionError> {
+    seal_with_key(&[VERSION_UNBOUND], Cipher::Aes256Gcm, data, key, &[])
+}
+
+/// Opens a value sealed with [`encrypt_with_key`].
+pub fn decrypt_with_key(encrypted_data: &str, key: &[u8]) -> Result<String, EncryptionError> {
+    let decoded = decode_key_envelope(encrypted_data)?;
+    if decoded[0] != VERSION_UNBOUND {
+        return Err(EncryptionError::UnsupportedVersion);
+    }
+    open_with_key(&decoded, 1, Cipher::Aes256Gcm, key, &[])
+}
+
+/// Seals `data` under a raw data key and binds it to `aad`.
+///
+/// The value only opens again with the very same associated data, so a
+/// ciphertext moved to another record, column or owner fails authentication.
+/// The cipher is picked by the current [`CipherPolicy`](crate::CipherPolicy).
+pub fn encrypt_with_key_aad(
+    data: &str,
+    key: &[u8],
+    aad: &AssociatedData,
+) -> Result<String, EncryptionError> {
+    encrypt_with_key_aad_cipher(data, key, aad, preferred_cipher())
+}
+
+/// Seals `data` under a raw data key with `cipher` and binds it to `aad`.
+pub fn encrypt_with_key_aad_cipher(
+    data: &str,
+    key: &[u8],
+    aad: &AssociatedData,
+    cipher: Cipher,
+) -> Result<String, EncryptionError> {
+    seal_with_key(
+        &[VERSION_BOUND, cipher.id()],
+        cipher,
+        data,
+        key,
+        aad.as_bytes(),
+    )
+}
+
+/// Opens a value sealed with [`encrypt_with_key_aad`], whatever cipher it
+/// was sealed with.
+pub fn decrypt_with_key_aad(
+    encrypted_data: &str,
+    key: &[u8],
+    aad: &AssociatedData,
+) -> Result<String, EncryptionError> {
+    let decoded = decode_key_envelope(encrypted_data)?;
+    match decoded[0] {
+        VERSION_BOUND_AES_GCM => open_with_key(&decoded, 1, Cipher::Aes256Gcm, key, aad.as_bytes()),
+        VERSION_BOUND if decoded.len() > 1 => {
+            let cipher = Cipher::from_id(decoded[1])?;
+            open_with_key(&decoded, 2, cipher, key, aad.as_bytes())
+        }
+        _ => Err(EncryptionError::UnsupportedVersion),
+    }
+}
+
+fn seal_with_key(
+    header: &[u8],
+    cipher: Cipher,
+    data: &str,
+    key: &[u8],
+    aad: &[u8],
+) -> Result<String, EncryptionError> {
+    let nonce = cipher.random_nonce();
+
+    // The header is authenticated too, a bound value can't pass as unbound
+    let ciphertext = cipher.seal(key, &nonce, data.as_bytes(), &[header, aad].concat())?;
+
+    // Combine header, nonce, and ciphertext
+    let mut result = header.to_vec();
+    result.extend_from_slice(&nonce);
+    result.extend_from_slice(&ciphertext);
+
+    Ok(general_purpose::STANDARD.encode(result))
+}
+
+fn open_with_key(
+    decoded: &[u8],
+    header_length: usize,
+    cipher: Cipher,
+    key: &[u8],
+    aad: &[u8],
+) -> Result<String, EncryptionError> {
+    let nonce_end = header_length + cipher.nonce_length();
+    if decoded.len() < nonce_end {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+    let (header, rest) = decoded.split_at(header_length);
+    let (nonce, ciphertext) = rest.split_at(cipher.nonce_length());
+
+    let plaintext = Zeroizing::new(cipher.open(key, nonce, ciphertext, &[header, aad].concat())?);
+
+    String::from_utf8(plaintext.to_vec()).map_err(|_| EncryptionError::DecryptionFailed)
+}
+
+fn decode_key_envelope(encrypted_data: &str) -> Result<Vec<u8>, EncryptionError> {
+    let decoded = general_purpose::STANDARD
+        .decode(encrypted_data)
+        .map_err(|_| EncryptionError::DecryptionFailed)?;
+    if decoded.is_empty() {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+    Ok(decoded)
+}
+
+/// Returns the format version of a value sealed under a data key.
+pub fn key_envelope_version(encrypted_data: &str) -> Result<u8, EncryptionError> {
+    Ok(decode_key_envelope(encrypted_data)?[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_unwrap_data_key() {
+        let data_key = generate_data_key();
+        let wrapped = wrap_data_key(data_key.expose_secret(), "master_password").unwrap();
+        let unwrapped = unwrap_data_key(&wrapped, "master_password").unwrap();
+
+        assert_eq!(data_key, unwrapped);
+    }
+
+    #[test]
+    fn test_unwrap_data_key_wrong_password() {
+        let data_key = generate_data_key();
+        let wrapped = wrap_data_key(data_key.expose_secret(), "master_password").unwrap();
+
+        assert!(unwrap_data_key(&wrapped, "wrong_password").is_err());
+    }
+
+    #[test]
+    fn test_wrap_data_key_with_params() {
+        let data_key = generate_data_key();
+        let params = KdfParams {
+            memory_cost: 8192,
+            time_cost: 1,
+            parallelism: 1,
+        };
+        let wrapped =
+            wrap_data_key_with_params(data_key.expose_secret(), "master_password", &params)
+                .unwrap();
+
+        assert_eq!(crate::envelope_params(&wrapped).unwrap(), params);
+        assert_eq!(
+            unwrap_data_key(&wrapped, "master_password").unwrap(),
+            data_key
+        );
+    }
+
+    #[test]
+    fn test_wrap_data_key_rejects_short_key() {
+        let result = wrap_data_key(&[0u8; 16], "master_password");
+
+        assert!(matches!(result, Err(EncryptionError::InvalidKeyLength)));
+    }
+
+    #[test]
+    fn test_keyed_hash() {
+        let key = generate_data_key();
+        let other_key = generate_data_key();
+        let hash = keyed_hash(key.expose_secret(), "password", b"hunter2").unwrap();
+
+        assert_eq!(
+            hash,
+            keyed_hash(key.expose_secret(), "password", b"hunter2").unwrap()
+        );
+        assert_ne!(
+            hash,
+            keyed_hash(key.expose_secret(), "password", b"hunter3").unwrap()
+        );
+        assert_ne!(
+            hash,
+            keyed_hash(key.expose_secret(), "username", b"hunter2").unwrap()
+        );
+        assert_ne!(
+            hash,
+            keyed_hash(other_key.expose_secret(), "password", b"hunter2").unwrap()
+        );
+        assert!(matches!(
+            keyed_hash(&[0u8; 16], "password", b"hunter2"),
+            Err(EncryptionError::InvalidKeyLength)
+        ));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_with_key() {
+        let key = generate_data_key();
+        let encrypted = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+
+        assert_ne!(encrypted, "hunter2");
+        assert_eq!(
+            decrypt_with_key(&encrypted, key.expose_secret()).unwrap(),
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key() {
+        let key = generate_data_key();
+        let other_key = generate_data_key();
+        let encrypted = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+
+        assert!(decrypt_with_key(&encrypted, other_key.expose_secret()).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_with_key_uses_fresh_nonce() {
+        let key = generate_data_key();
+        let first = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+        let second = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    fn login_password(record_id: &str) -> AssociatedData {
+        AssociatedData::new()
+            .field("logins")
+            .field("password")
+            .field(record_id)
+            .field("user_1")
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_with_key_aad() {
+        let key = generate_data_key();
+        let encrypted =
+            encrypt_with_key_aad("hunter2", key.expose_secret(), &login_password("1")).unwrap();
+
+        assert_eq!(key_envelope_version(&encrypted).unwrap(), VERSION_BOUND);
+        assert_eq!(
+            decrypt_with_key_aad(&encrypted, key.expose_secret(), &login_password("1")).unwrap(),
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn test_relocated_ciphertext_fails() {
+        let key = generate_data_key();
+        let encrypted =
+            encrypt_with_key_aad("hunter2", key.expose_secret(), &login_password("1")).unwrap();
+
+        assert!(
+            decrypt_with_key_aad(&encrypted, key.expose_secret(), &login_password("2")).is_err()
+        );
+    }
+
+    #[test]
+    fn test_bound_value_does_not_open_unbound() {
+        let key = generate_data_key();
+        let bound =
+            encrypt_with_key_aad("hunter2", key.expose_secret(), &AssociatedData::new()).unwrap();
+        let unbound = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+
+        assert!(decrypt_with_key(&bound, key.expose_secret()).is_err());
+        assert!(
+            decrypt_with_key_aad(&unbound, key.expose_secret(), &AssociatedData::new()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_encrypt_with_key_aad_each_cipher() {
+        let key = generate_data_key();
+        for cipher in [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            let encrypted = encrypt_with_key_aad_cipher(
+                "hunter2",
+                key.expose_secret(),
+                &login_password("1"),
+                cipher,
+            )
+            .unwrap();
+
+            assert_eq!(
+                decrypt_with_key_aad(&encrypted, key.expose_secret(), &login_password("1"))
+                    .unwrap(),
+                "hunter2"
+            );
+            assert!(
+                decrypt_with_key_aad(&encrypted, key.expose_secret(), &login_password("2"))
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_suite_cipher_swap_fails() {
+        let key = generate_data_key();
+        let encrypted = encrypt_with_key_aad_cipher(
+            "hunter2",
+            key.expose_secret(),
+            &login_password("1"),
+            Cipher::XChaCha20Poly1305,
+        )
+        .unwrap();
+        let mut decoded = general_purpose::STANDARD.decode(&encrypted).unwrap();
+        decoded[1] = Cipher::Aes256Gcm.id();
+        let swapped = general_purpose::STANDARD.encode(decoded);
+
+        assert!(decrypt_with_key_aad(&swapped, key.expose_secret(), &login_password("1")).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_version_2_aes_gcm() {
+        let key = generate_data_key();
+        let aad = login_password("1");
+        let nonce = Cipher::Aes256Gcm.random_nonce();
+        let header = [VERSION_BOUND_AES_GCM];
+        let ciphertext = Cipher::Aes256Gcm
+            .seal(
+                key.expose_secret(),
+                &nonce,
+                b"hunter2",
+                &[&header[..], aad.as_bytes()].concat(),
+            )
+            .unwrap();
+        let encrypted =
+            general_purpose::STANDARD.encode([&header[..], &nonce, &ciphertext].concat());
+
+        assert_eq!(
+            decrypt_with_key_aad(&encrypted, key.expose_secret(), &aad).unwrap(),
+            "hunter2"
+        );
+    }
+}
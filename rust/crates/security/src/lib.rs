@@ -1,3 +1,26 @@
+mod aad;
+mod calibrate;
+mod cipher;
 mod encryption;
+mod keys;
+mod otp;
+mod passkey;
+mod pepper;
+mod recovery;
+mod secret;
+mod shamir;
+mod stream;
 
+pub use aad::*;
+pub use calibrate::*;
+pub use cipher::*;
 pub use encryption::*;
+pub use keys::*;
+pub use otp::*;
+pub use passkey::*;
+pub use pepper::*;
+pub use recovery::*;
+pub use secret::*;
+pub use shamir::*;
+pub use stream::*;
+pub use zeroize::Zeroizing;
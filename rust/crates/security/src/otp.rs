@@ -0,0 +1,305 @@
+use hmac::{Hmac, Mac};
+use rand::{rngs::OsRng, RngCore};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::{EncryptionError, SecretBytes};
+
+// RFC 4226 recommends at least 160 bits
+pub const OTP_SECRET_LENGTH: usize = 20;
+pub const MIN_OTP_DIGITS: u32 = 6;
+pub const MAX_OTP_DIGITS: u32 = 9;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// Steam Guard codes are 5 characters from an alphabet without look-alikes
+pub const STEAM_CODE_LENGTH: usize = 5;
+pub const STEAM_PERIOD: u64 = 30;
+const STEAM_ALPHABET: &[u8; 26] = b"23456789BCDFGHJKMNPQRTVWXY";
+
+/// HMAC function of a one-time password, SHA-1 unless an authenticator
+/// was told otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpAlgorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+/// Generates a random secret for a new authenticator.
+pub fn generate_otp_secret() -> SecretBytes {
+    let mut secret = Zeroizing::new(vec![0u8; OTP_SECRET_LENGTH]);
+    OsRng.fill_bytes(&mut secret);
+    secret.into()
+}
+
+/// Encodes bytes as unpadded RFC 4648 base32, the form authenticator apps
+/// take secrets in.
+pub fn base32_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(5) * 8);
+    for chunk in data.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer
+            .iter()
+            .fold(0u64, |bits, byte| (bits << 8) | *byte as u64);
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..chars {
+            let index = (bits >> (35 - i * 5)) & 0x1f;
+            encoded.push(BASE32_ALPHABET[index as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decodes base32, ignoring case, spaces, dashes and padding as people
+/// type secrets in every way.
+pub fn base32_decode(encoded: &str) -> Result<SecretBytes, EncryptionError> {
+    let mut decoded = Zeroizing::new(Vec::with_capacity(encoded.len() * 5 / 8));
+    let mut bits = 0u64;
+    let mut bit_count = 0;
+    for c in encoded.chars() {
+        if c == ' ' || c == '-' || c == '=' {
+            continue;
+        }
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&symbol| symbol as char == c.to_ascii_uppercase())
+            .ok_or(EncryptionError::InvalidOtpSecret)?;
+        bits = (bits << 5) | value as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            decoded.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+    if decoded.is_empty() {
+        return Err(EncryptionError::InvalidOtpSecret);
+    }
+    Ok(decoded.into())
+}
+
+fn otp_mac(secret: &[u8], counter: u64, algorithm: OtpAlgorithm) -> Vec<u8> {
+    fn mac<M: Mac + hmac::digest::KeyInit>(secret: &[u8], counter: u64) -> Vec<u8> {
+        let mut mac = <M as hmac::digest::KeyInit>::new_from_slice(secret)
+            .expect("HMAC takes keys of any length");
+        mac.update(&counter.to_be_bytes());
+        mac.finalize().into_bytes().to_vec()
+    }
+    match algorithm {
+        OtpAlgorithm::Sha1 => mac::<Hmac<Sha1>>(secret, counter),
+        OtpAlgorithm::Sha256 => mac::<Hmac<Sha256>>(secret, counter),
+        OtpAlgorithm::Sha512 => mac::<Hmac<Sha512>>(secret, counter),
+    }
+}
+
+// Dynamic truncation of RFC 4226, a 31 bit number picked by the last nibble
+fn truncate(mac: &[u8]) -> u32 {
+    let offset = (mac[mac.len() - 1] & 0x0f) as usize;
+    u32::from_be_bytes([
+        mac[offset] & 0x7f,
+        mac[offset + 1],
+        mac[offset + 2],
+        mac[offset + 3],
+    ])
+}
+
+/// Computes the RFC 4226 one-time password for `counter`.
+pub fn hotp(
+    secret: &[u8],
+    counter: u64,
+    digits: u32,
+    algorithm: OtpAlgorithm,
+) -> Result<String, EncryptionError> {
+    if !(MIN_OTP_DIGITS..=MAX_OTP_DIGITS).contains(&digits) {
+        return Err(EncryptionError::InvalidOtpParams);
+    }
+    let code = truncate(&otp_mac(secret, counter, algorithm)) % 10u32.pow(digits);
+    Ok(format!("{:0width$}", code, width = digits as usize))
+}
+
+/// The RFC 6238 time step `time` (seconds since the epoch) falls in.
+pub fn totp_counter(time: u64, period: u64) -> Result<u64, EncryptionError> {
+    if period == 0 {
+        return Err(EncryptionError::InvalidOtpParams);
+    }
+    Ok(time / period)
+}
+
+/// Computes the RFC 6238 one-time password at `time`.
+pub fn totp(
+    secret: &[u8],
+    time: u64,
+    period: u64,
+    digits: u32,
+    algorithm: OtpAlgorithm,
+) -> Result<String, EncryptionError> {
+    hotp(secret, totp_counter(time, period)?, digits, algorithm)
+}
+
+/// Computes the Steam Guard code at `time`, a TOTP with SHA-1 and a 30
+/// second period written in Steam's own alphabet.
+pub fn steam_guard(secret: &[u8], time: u64) -> Result<String, EncryptionError> {
+    let counter = totp_counter(time, STEAM_PERIOD)?;
+    let mut value = truncate(&otp_mac(secret, counter, OtpAlgorithm::Sha1));
+    let mut code = String::with_capacity(STEAM_CODE_LENGTH);
+    for _ in 0..STEAM_CODE_LENGTH {
+        code.push(STEAM_ALPHABET[(value % 26) as usize] as char);
+        value /= 26;
+    }
+    Ok(code)
+}
+
+/// Checks a code against the time steps within `skew` steps of `time`, to
+/// allow for clock drift. Returns the matching step, so callers can refuse
+/// a code that was used before.
+pub fn verify_totp(
+    secret: &[u8],
+    code: &str,
+    time: u64,
+    period: u64,
+    digits: u32,
+    algorithm: OtpAlgorithm,
+    skew: u64,
+) -> Result<Option<u64>, EncryptionError> {
+    let counter = totp_counter(time, period)?;
+    let code = code.trim();
+    for step in counter.saturating_sub(skew)..=counter.saturating_add(skew) {
+        let expected = hotp(secret, step, digits, algorithm)?;
+        if bool::from(expected.as_bytes().ct_eq(code.as_bytes())) {
+            return Ok(Some(step));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 4226 appendix D
+    const RFC4226_SECRET: &[u8] = b"12345678901234567890";
+    const RFC4226_CODES: [&str; 10] = [
+        "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583", "399871",
+        "520489",
+    ];
+
+    #[test]
+    fn test_hotp_rfc4226() {
+        for (counter, expected) in RFC4226_CODES.iter().enumerate() {
+            assert_eq!(
+                hotp(RFC4226_SECRET, counter as u64, 6, OtpAlgorithm::Sha1).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_totp_rfc6238() {
+        // RFC 6238 appendix B, the seed is repeated to the length of the hash
+        let sha1 = b"12345678901234567890".as_slice();
+        let sha256 = b"12345678901234567890123456789012".as_slice();
+        let sha512 = b"1234567890123456789012345678901234567890123456789012345678901234".as_slice();
+        let vectors = [
+            (59, "94287082", "46119246", "90693936"),
+            (1111111109, "07081804", "68084774", "25091201"),
+            (1111111111, "14050471", "67062674", "99943326"),
+            (1234567890, "89005924", "91819424", "93441116"),
+            (2000000000, "69279037", "90698825", "38618901"),
+            (20000000000, "65353130", "77737706", "47863826"),
+        ];
+        for (time, expected_sha1, expected_sha256, expected_sha512) in vectors {
+            let code = |secret, algorithm| totp(secret, time, 30, 8, algorithm).unwrap();
+            assert_eq!(code(sha1, OtpAlgorithm::Sha1), expected_sha1);
+            assert_eq!(code(sha256, OtpAlgorithm::Sha256), expected_sha256);
+            assert_eq!(code(sha512, OtpAlgorithm::Sha512), expected_sha512);
+        }
+    }
+
+    #[test]
+    fn test_steam_guard() {
+        let vectors = [
+            (59, "PV9M4"),
+            (1111111109, "PY4YB"),
+            (1234567890, "VHHQY"),
+            (2000000000, "9N776"),
+        ];
+        for (time, expected) in vectors {
+            assert_eq!(steam_guard(RFC4226_SECRET, time).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_totp() {
+        let secret = generate_otp_secret();
+        let time = 1_700_000_000;
+        let code = totp(secret.expose_secret(), time, 30, 6, OtpAlgorithm::Sha1).unwrap();
+        let step = time / 30;
+        let verify = |code: &str, time: u64| {
+            verify_totp(
+                secret.expose_secret(),
+                code,
+                time,
+                30,
+                6,
+                OtpAlgorithm::Sha1,
+                1,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(verify(&code, time), Some(step));
+        assert_eq!(verify(&code, time + 30), Some(step));
+        assert_eq!(verify(&code, time - 30), Some(step));
+        assert_eq!(verify(&code, time + 60), None);
+        assert_eq!(verify("000000", time).is_some(), code == "000000");
+    }
+
+    #[test]
+    fn test_invalid_otp_params() {
+        assert!(matches!(
+            hotp(RFC4226_SECRET, 0, 5, OtpAlgorithm::Sha1),
+            Err(EncryptionError::InvalidOtpParams)
+        ));
+        assert!(matches!(
+            totp(RFC4226_SECRET, 0, 0, 6, OtpAlgorithm::Sha1),
+            Err(EncryptionError::InvalidOtpParams)
+        ));
+    }
+
+    #[test]
+    fn test_base32() {
+        // RFC 4648 section 10, without padding
+        let vectors = [
+            ("f", "MY"),
+            ("fo", "MZXQ"),
+            ("foo", "MZXW6"),
+            ("foob", "MZXW6YQ"),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI"),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(base32_encode(data.as_bytes()), encoded);
+            assert_eq!(
+                base32_decode(encoded).unwrap().expose_secret(),
+                data.as_bytes()
+            );
+        }
+        assert_eq!(
+            base32_decode("mzxw 6ytb-oi======").unwrap().expose_secret(),
+            b"foobar"
+        );
+        assert!(base32_decode("MZXW1").is_err());
+        assert!(base32_decode("").is_err());
+
+        let secret = generate_otp_secret();
+        assert_eq!(
+            base32_decode(&base32_encode(secret.expose_secret())).unwrap(),
+            secret
+        );
+    }
+}
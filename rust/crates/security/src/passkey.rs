@@ -0,0 +1,488 @@
+use ed25519_dalek::SigningKey as Ed25519SigningKey;
+use p256::ecdsa::{signature::Signer, Signature, SigningKey as P256SigningKey};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::{EncryptionError, SecretBytes};
+
+pub const PASSKEY_KEY_LENGTH: usize = 32;
+pub const CLIENT_DATA_HASH_LENGTH: usize = 32;
+// WebAuthn caps credential ids at 1023 bytes
+pub const MAX_CREDENTIAL_ID_LENGTH: usize = 1023;
+
+// Flags of authenticator data, WebAuthn section 6.1
+pub const FLAG_USER_PRESENT: u8 = 0x01;
+pub const FLAG_USER_VERIFIED: u8 = 0x04;
+pub const FLAG_BACKUP_ELIGIBLE: u8 = 0x08;
+pub const FLAG_BACKED_UP: u8 = 0x10;
+pub const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+/// Signature algorithm of a passkey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasskeyAlgorithm {
+    /// ECDSA on P-256 with SHA-256
+    Es256,
+    /// Ed25519
+    EdDsa,
+}
+
+impl PasskeyAlgorithm {
+    /// The COSE algorithm identifier relying parties list algorithms by.
+    pub fn cose_id(self) -> i64 {
+        match self {
+            PasskeyAlgorithm::Es256 => -7,
+            PasskeyAlgorithm::EdDsa => -8,
+        }
+    }
+
+    pub fn from_cose_id(id: i64) -> Result<Self, EncryptionError> {
+        match id {
+            -7 => Ok(PasskeyAlgorithm::Es256),
+            -8 => Ok(PasskeyAlgorithm::EdDsa),
+            _ => Err(EncryptionError::UnsupportedPasskeyAlgorithm),
+        }
+    }
+}
+
+enum PasskeyKey {
+    Es256(P256SigningKey),
+    EdDsa(Ed25519SigningKey),
+}
+
+// Private keys are kept as the 32 byte P-256 scalar or Ed25519 seed
+fn signing_key(
+    algorithm: PasskeyAlgorithm,
+    private_key: &[u8],
+) -> Result<PasskeyKey, EncryptionError> {
+    match algorithm {
+        PasskeyAlgorithm::Es256 => P256SigningKey::from_slice(private_key)
+            .map(PasskeyKey::Es256)
+            .map_err(|_| EncryptionError::InvalidPasskeyKey),
+        PasskeyAlgorithm::EdDsa => {
+            let seed: &[u8; PASSKEY_KEY_LENGTH] = private_key
+                .try_into()
+                .map_err(|_| EncryptionError::InvalidPasskeyKey)?;
+            Ok(PasskeyKey::EdDsa(Ed25519SigningKey::from_bytes(seed)))
+        }
+    }
+}
+
+/// Generates the private key of a new passkey.
+pub fn generate_passkey_key(algorithm: PasskeyAlgorithm) -> SecretBytes {
+    match algorithm {
+        PasskeyAlgorithm::Es256 => {
+            let key = P256SigningKey::random(&mut OsRng);
+            Zeroizing::new(key.to_bytes().to_vec()).into()
+        }
+        PasskeyAlgorithm::EdDsa => {
+            let mut seed = Zeroizing::new(vec![0u8; PASSKEY_KEY_LENGTH]);
+            OsRng.fill_bytes(&mut seed);
+            seed.into()
+        }
+    }
+}
+
+/// The public key of a passkey as a COSE_Key, the form relying parties
+/// store it in.
+pub fn passkey_public_key(
+    algorithm: PasskeyAlgorithm,
+    private_key: &[u8],
+) -> Result<Vec<u8>, EncryptionError> {
+    let mut cose = Vec::new();
+    match signing_key(algorithm, private_key)? {
+        PasskeyKey::Es256(key) => {
+            let point = key.verifying_key().to_encoded_point(false);
+            cbor::map(&mut cose, 5);
+            // kty EC2, alg, crv P-256, x, y
+            cbor::int(&mut cose, 1);
+            cbor::int(&mut cose, 2);
+            cbor::int(&mut cose, 3);
+            cbor::int(&mut cose, algorithm.cose_id());
+            cbor::int(&mut cose, -1);
+            cbor::int(&mut cose, 1);
+            cbor::int(&mut cose, -2);
+            cbor::bytes(&mut cose, point.x().unwrap());
+            cbor::int(&mut cose, -3);
+            cbor::bytes(&mut cose, point.y().unwrap());
+        }
+        PasskeyKey::EdDsa(key) => {
+            cbor::map(&mut cose, 4);
+            // kty OKP, alg, crv Ed25519, x
+            cbor::int(&mut cose, 1);
+            cbor::int(&mut cose, 1);
+            cbor::int(&mut cose, 3);
+            cbor::int(&mut cose, algorithm.cose_id());
+            cbor::int(&mut cose, -1);
+            cbor::int(&mut cose, 6);
+            cbor::int(&mut cose, -2);
+            cbor::bytes(&mut cose, key.verifying_key().as_bytes());
+        }
+    }
+    Ok(cose)
+}
+
+/// A new credential as it appears in the authenticator data of a
+/// registration.
+pub struct AttestedCredential<'a> {
+    pub aaguid: [u8; 16],
+    pub credential_id: &'a [u8],
+    /// COSE_Key from [`passkey_public_key`]
+    pub public_key: &'a [u8],
+}
+
+/// Builds authenticator data: the SHA-256 of the relying party id, flags,
+/// the signature counter and, when registering, the new credential.
+pub fn authenticator_data(
+    rp_id: &str,
+    mut flags: u8,
+    sign_count: u32,
+    attested_credential: Option<&AttestedCredential>,
+) -> Result<Vec<u8>, EncryptionError> {
+    let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+    if attested_credential.is_some() {
+        flags |= FLAG_ATTESTED_CREDENTIAL_DATA;
+    }
+    data.push(flags);
+    data.extend_from_slice(&sign_count.to_be_bytes());
+    if let Some(credential) = attested_credential {
+        if credential.credential_id.is_empty()
+            || credential.credential_id.len() > MAX_CREDENTIAL_ID_LENGTH
+        {
+            return Err(EncryptionError::InvalidPasskeyKey);
+        }
+        data.extend_from_slice(&credential.aaguid);
+        data.extend_from_slice(&(credential.credential_id.len() as u16).to_be_bytes());
+        data.extend_from_slice(credential.credential_id);
+        data.extend_from_slice(credential.public_key);
+    }
+    Ok(data)
+}
+
+/// Wraps registration authenticator data in an attestation object of the
+/// "none" format, which vouches for nothing about the authenticator.
+pub fn none_attestation_object(authenticator_data: &[u8]) -> Vec<u8> {
+    let mut object = Vec::new();
+    // Keys in CTAP2 canonical order, shortest first
+    cbor::map(&mut object, 3);
+    cbor::text(&mut object, "fmt");
+    cbor::text(&mut object, "none");
+    cbor::text(&mut object, "attStmt");
+    cbor::map(&mut object, 0);
+    cbor::text(&mut object, "authData");
+    cbor::bytes(&mut object, authenticator_data);
+    object
+}
+
+/// Signs an assertion, which covers the authenticator data followed by
+/// the hash of the client data. ES256 signatures are DER encoded.
+pub fn sign_assertion(
+    algorithm: PasskeyAlgorithm,
+    private_key: &[u8],
+    authenticator_data: &[u8],
+    client_data_hash: &[u8],
+) -> Result<Vec<u8>, EncryptionError> {
+    if client_data_hash.len() != CLIENT_DATA_HASH_LENGTH {
+        return Err(EncryptionError::InvalidClientDataHash);
+    }
+    let mut message = authenticator_data.to_vec();
+    message.extend_from_slice(client_data_hash);
+    let signature = match signing_key(algorithm, private_key)? {
+        PasskeyKey::Es256(key) => {
+            let signature: Signature = key.sign(&message);
+            signature.to_der().as_bytes().to_vec()
+        }
+        PasskeyKey::EdDsa(key) => key.sign(&message).to_bytes().to_vec(),
+    };
+    Ok(signature)
+}
+
+// Just enough CBOR (RFC 8949) for COSE keys and attestation objects
+mod cbor {
+    const UNSIGNED: u8 = 0;
+    const NEGATIVE: u8 = 1;
+    const BYTES: u8 = 2;
+    const TEXT: u8 = 3;
+    const MAP: u8 = 5;
+
+    fn head(out: &mut Vec<u8>, major: u8, value: u64) {
+        let major = major << 5;
+        match value {
+            0..=23 => out.push(major | value as u8),
+            24..=0xff => out.extend_from_slice(&[major | 24, value as u8]),
+            0x100..=0xffff => {
+                out.push(major | 25);
+                out.extend_from_slice(&(value as u16).to_be_bytes());
+            }
+            0x1_0000..=0xffff_ffff => {
+                out.push(major | 26);
+                out.extend_from_slice(&(value as u32).to_be_bytes());
+            }
+            _ => {
+                out.push(major | 27);
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+    }
+
+    pub fn int(out: &mut Vec<u8>, value: i64) {
+        match value {
+            0.. => head(out, UNSIGNED, value as u64),
+            _ => head(out, NEGATIVE, !value as u64),
+        }
+    }
+
+    pub fn bytes(out: &mut Vec<u8>, value: &[u8]) {
+        head(out, BYTES, value.len() as u64);
+        out.extend_from_slice(value);
+    }
+
+    pub fn text(out: &mut Vec<u8>, value: &str) {
+        head(out, TEXT, value.len() as u64);
+        out.extend_from_slice(value.as_bytes());
+    }
+
+    pub fn map(out: &mut Vec<u8>, len: usize) {
+        head(out, MAP, len as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ciborium::Value;
+    use ed25519_dalek::{Verifier, VerifyingKey as Ed25519VerifyingKey};
+    use p256::{ecdsa::VerifyingKey as P256VerifyingKey, EncodedPoint};
+
+    use super::*;
+
+    const ALGORITHMS: [PasskeyAlgorithm; 2] = [PasskeyAlgorithm::Es256, PasskeyAlgorithm::EdDsa];
+
+    fn decode(data: &[u8]) -> Value {
+        ciborium::from_reader(data).unwrap()
+    }
+
+    fn entry(map: &Value, key: Value) -> &Value {
+        &map.as_map()
+            .unwrap()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .unwrap()
+            .1
+    }
+
+    fn cose_bytes(key: &Value, label: i64) -> Vec<u8> {
+        entry(key, Value::from(label)).as_bytes().unwrap().clone()
+    }
+
+    // What a relying party does with a stored COSE key and an assertion
+    fn verify(cose_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let key = decode(cose_key);
+        match entry(&key, Value::from(3)).as_integer().map(i128::from) {
+            Some(-7) => {
+                let point = EncodedPoint::from_affine_coordinates(
+                    cose_bytes(&key, -2).as_slice().into(),
+                    cose_bytes(&key, -3).as_slice().into(),
+                    false,
+                );
+                let key = P256VerifyingKey::from_encoded_point(&point).unwrap();
+                let signature = Signature::from_der(signature).unwrap();
+                key.verify(message, &signature).is_ok()
+            }
+            Some(-8) => {
+                let key = Ed25519VerifyingKey::from_bytes(
+                    cose_bytes(&key, -2).as_slice().try_into().unwrap(),
+                )
+                .unwrap();
+                let signature = ed25519_dalek::Signature::from_slice(signature).unwrap();
+                key.verify(message, &signature).is_ok()
+            }
+            alg => panic!("unexpected algorithm {:?}", alg),
+        }
+    }
+
+    fn hex(data: &str) -> Vec<u8> {
+        (0..data.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&data[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_ed25519_rfc8032() {
+        // RFC 8032 section 7.1, test 1
+        let seed = hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+        let cose = decode(&passkey_public_key(PasskeyAlgorithm::EdDsa, &seed).unwrap());
+        assert_eq!(
+            cose_bytes(&cose, -2),
+            hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+        );
+        let PasskeyKey::EdDsa(key) = signing_key(PasskeyAlgorithm::EdDsa, &seed).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(
+            key.sign(b"").to_bytes().to_vec(),
+            hex(concat!(
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555",
+                "fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_p256_rfc6979() {
+        // RFC 6979 appendix A.2.5, SHA-256 over "sample"
+        let private_key = hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let cose = decode(&passkey_public_key(PasskeyAlgorithm::Es256, &private_key).unwrap());
+        assert_eq!(
+            cose_bytes(&cose, -2),
+            hex("60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6")
+        );
+        assert_eq!(
+            cose_bytes(&cose, -3),
+            hex("7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299")
+        );
+        let PasskeyKey::Es256(key) = signing_key(PasskeyAlgorithm::Es256, &private_key).unwrap()
+        else {
+            unreachable!()
+        };
+        let signature: Signature = key.sign(b"sample");
+        assert_eq!(
+            signature.to_bytes().to_vec(),
+            hex(concat!(
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+                "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_registration() {
+        for algorithm in ALGORITHMS {
+            let private_key = generate_passkey_key(algorithm);
+            let public_key = passkey_public_key(algorithm, private_key.expose_secret()).unwrap();
+            let credential_id = [7u8; 16];
+            let data = authenticator_data(
+                "example.com",
+                FLAG_USER_PRESENT | FLAG_BACKUP_ELIGIBLE,
+                0,
+                Some(&AttestedCredential {
+                    aaguid: [0; 16],
+                    credential_id: &credential_id,
+                    public_key: &public_key,
+                }),
+            )
+            .unwrap();
+
+            let object = decode(&none_attestation_object(&data));
+            assert_eq!(entry(&object, "fmt".into()).as_text(), Some("none"));
+            assert_eq!(entry(&object, "attStmt".into()).as_map().unwrap().len(), 0);
+            let auth_data = entry(&object, "authData".into()).as_bytes().unwrap();
+            assert_eq!(auth_data, &data);
+
+            assert_eq!(&auth_data[..32], Sha256::digest(b"example.com").as_slice());
+            assert_eq!(
+                auth_data[32],
+                FLAG_USER_PRESENT | FLAG_BACKUP_ELIGIBLE | FLAG_ATTESTED_CREDENTIAL_DATA
+            );
+            assert_eq!(&auth_data[33..37], &[0, 0, 0, 0]);
+            assert_eq!(&auth_data[37..53], &[0; 16]);
+            assert_eq!(&auth_data[53..55], &[0, 16]);
+            assert_eq!(&auth_data[55..71], &credential_id);
+            assert_eq!(&auth_data[71..], public_key.as_slice());
+            let cose = decode(&auth_data[71..]);
+            assert_eq!(
+                entry(&cose, Value::from(3)),
+                &Value::from(algorithm.cose_id())
+            );
+        }
+    }
+
+    #[test]
+    fn test_assertion() {
+        let client_data_hash = Sha256::digest(br#"{"type":"webauthn.get"}"#);
+        for algorithm in ALGORITHMS {
+            let private_key = generate_passkey_key(algorithm);
+            let public_key = passkey_public_key(algorithm, private_key.expose_secret()).unwrap();
+            let data = authenticator_data(
+                "example.com",
+                FLAG_USER_PRESENT | FLAG_USER_VERIFIED,
+                7,
+                None,
+            )
+            .unwrap();
+            assert_eq!(data.len(), 37);
+            assert_eq!(&data[33..37], &[0, 0, 0, 7]);
+
+            let signature = sign_assertion(
+                algorithm,
+                private_key.expose_secret(),
+                &data,
+                &client_data_hash,
+            )
+            .unwrap();
+            let mut message = data.clone();
+            message.extend_from_slice(&client_data_hash);
+            assert!(verify(&public_key, &message, &signature));
+            message[32] ^= FLAG_USER_VERIFIED;
+            assert!(!verify(&public_key, &message, &signature));
+
+            assert!(matches!(
+                sign_assertion(algorithm, private_key.expose_secret(), &data, &[0; 16]),
+                Err(EncryptionError::InvalidClientDataHash)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invalid_passkeys() {
+        assert!(matches!(
+            PasskeyAlgorithm::from_cose_id(-257),
+            Err(EncryptionError::UnsupportedPasskeyAlgorithm)
+        ));
+        assert_eq!(
+            PasskeyAlgorithm::from_cose_id(-8).unwrap(),
+            PasskeyAlgorithm::EdDsa
+        );
+        // Zero is not a valid P-256 scalar
+        assert!(passkey_public_key(PasskeyAlgorithm::Es256, &[0; 32]).is_err());
+        assert!(passkey_public_key(PasskeyAlgorithm::EdDsa, &[1; 31]).is_err());
+        assert!(authenticator_data(
+            "example.com",
+            0,
+            0,
+            Some(&AttestedCredential {
+                aaguid: [0; 16],
+                credential_id: &[],
+                public_key: &[],
+            }),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_cbor() {
+        // RFC 8949 appendix A
+        let vectors = [
+            (0, "00"),
+            (23, "17"),
+            (24, "1818"),
+            (255, "18ff"),
+            (256, "190100"),
+            (1_000_000, "1a000f4240"),
+            (1_000_000_000_000, "1b000000e8d4a51000"),
+            (-1, "20"),
+            (-10, "29"),
+            (-100, "3863"),
+            (-1000, "3903e7"),
+        ];
+        for (value, expected) in vectors {
+            let mut out = Vec::new();
+            cbor::int(&mut out, value);
+            assert_eq!(out, hex(expected));
+        }
+        let mut out = Vec::new();
+        cbor::text(&mut out, "IETF");
+        cbor::bytes(&mut out, &[1, 2, 3, 4]);
+        assert_eq!(out, hex("64494554464401020304"));
+    }
+}
@@ -0,0 +1,75 @@
+use base64::{engine::general_purpose, Engine as _};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{EncryptionError, SecretString};
+
+// A pepper is a server side secret, it must be hard to guess on its own
+pub const MIN_PEPPER_LENGTH: usize = 16;
+pub const PEPPER_ID_LENGTH: usize = 8;
+
+fn pepper_mac(pepper: &[u8], context: &str, data: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+    if pepper.len() < MIN_PEPPER_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(pepper).map_err(|_| EncryptionError::InvalidKeyLength)?;
+    mac.update(context.as_bytes());
+    mac.update(&[0]);
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Mixes a pepper into a password (HMAC-SHA256) before it is hashed.
+///
+/// A stolen password hash can't be cracked without the pepper, which is
+/// kept out of the database.
+pub fn pepper_password(pepper: &[u8], password: &str) -> Result<SecretString, EncryptionError> {
+    let mac = pepper_mac(pepper, "password", password.as_bytes())?;
+    Ok(general_purpose::STANDARD_NO_PAD.encode(mac).into())
+}
+
+/// Identifies a pepper without revealing it, so a hash can record which
+/// pepper it was made with.
+pub fn pepper_id(pepper: &[u8]) -> Result<[u8; PEPPER_ID_LENGTH], EncryptionError> {
+    let mac = pepper_mac(pepper, "pepper id", &[])?;
+    let mut id = [0u8; PEPPER_ID_LENGTH];
+    id.copy_from_slice(&mac[..PEPPER_ID_LENGTH]);
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PEPPER: &[u8] = b"0123456789abcdef0123456789abcdef";
+    const OTHER_PEPPER: &[u8] = b"fedcba9876543210fedcba9876543210";
+
+    #[test]
+    fn test_pepper_password() {
+        let peppered = pepper_password(PEPPER, "hunter2").unwrap();
+
+        assert_ne!(peppered.expose_secret(), "hunter2");
+        assert_eq!(peppered, pepper_password(PEPPER, "hunter2").unwrap());
+        assert_ne!(peppered, pepper_password(PEPPER, "hunter3").unwrap());
+        assert_ne!(peppered, pepper_password(OTHER_PEPPER, "hunter2").unwrap());
+    }
+
+    #[test]
+    fn test_pepper_id() {
+        assert_eq!(pepper_id(PEPPER).unwrap(), pepper_id(PEPPER).unwrap());
+        assert_ne!(pepper_id(PEPPER).unwrap(), pepper_id(OTHER_PEPPER).unwrap());
+    }
+
+    #[test]
+    fn test_short_pepper() {
+        assert!(matches!(
+            pepper_password(b"short", "hunter2"),
+            Err(EncryptionError::InvalidKeyLength)
+        ));
+        assert!(matches!(
+            pepper_id(b"short"),
+            Err(EncryptionError::InvalidKeyLength)
+        ));
+    }
+}
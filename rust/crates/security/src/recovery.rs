@@ -0,0 +1,210 @@
+use base64::{engine::general_purpose, Engine as _};
+use bip39::{Language, Mnemonic};
+use rand::{rngs::OsRng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    combine_shares, decrypt_with_key_aad, encrypt_with_key_aad, split_secret, AssociatedData,
+    EncryptionError, SecretBytes, SecretString, Share, DATA_KEY_LENGTH,
+};
+
+// 256 bits of entropy, written as 24 BIP-39 words whose last word carries an
+// 8 bit checksum
+pub const RECOVERY_KEY_LENGTH: usize = 32;
+pub const RECOVERY_KEY_WORDS: usize = 24;
+
+/// Generates a random recovery key encoded as a list of English words.
+///
+/// The words encode the key itself, so a recovery key works as a raw data
+/// key and needs no key derivation.
+pub fn generate_recovery_key() -> SecretString {
+    let mut entropy = Zeroizing::new([0u8; RECOVERY_KEY_LENGTH]);
+    OsRng.fill_bytes(entropy.as_mut());
+    recovery_key_from_bytes(entropy.as_ref()).expect("recovery key entropy has a valid length")
+}
+
+// Encodes the raw bytes of a recovery key as words
+fn recovery_key_from_bytes(bytes: &[u8]) -> Result<SecretString, EncryptionError> {
+    if bytes.len() != RECOVERY_KEY_LENGTH {
+        return Err(EncryptionError::InvalidRecoveryKey);
+    }
+    let mut mnemonic = Mnemonic::from_entropy_in(Language::English, bytes)
+        .map_err(|_| EncryptionError::InvalidRecoveryKey)?;
+    let words = mnemonic.words().collect::<Vec<_>>().join(" ");
+    mnemonic.zeroize();
+    Ok(words.into())
+}
+
+/// Decodes a recovery key typed back by the user.
+///
+/// Case and spacing are ignored, a key with the wrong number of words, an
+/// unknown word or a checksum mismatch is rejected.
+pub fn parse_recovery_key(recovery_key: &str) -> Result<SecretBytes, EncryptionError> {
+    let normalized = Zeroizing::new(
+        recovery_key
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    let mut mnemonic = Mnemonic::parse_in_normalized(Language::English, &normalized)
+        .map_err(|_| EncryptionError::InvalidRecoveryKey)?;
+    if mnemonic.word_count() != RECOVERY_KEY_WORDS {
+        mnemonic.zeroize();
+        return Err(EncryptionError::InvalidRecoveryKey);
+    }
+    let (mut entropy, length) = mnemonic.to_entropy_array();
+    mnemonic.zeroize();
+    let key = Zeroizing::new(entropy[..length].to_vec());
+    entropy.zeroize();
+    Ok(key.into())
+}
+
+/// Splits a recovery key into `shares` shares held by trusted people, any
+/// `threshold` of them restore it with [`recovery_key_from_shares`].
+pub fn split_recovery_key(
+    recovery_key: &str,
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<Share>, EncryptionError> {
+    let key = parse_recovery_key(recovery_key)?;
+    split_secret(key.expose_secret(), threshold, shares)
+}
+
+/// Restores a recovery key split with [`split_recovery_key`].
+pub fn recovery_key_from_shares(shares: &[Share]) -> Result<SecretString, EncryptionError> {
+    let key = combine_shares(shares)?;
+    recovery_key_from_bytes(key.expose_secret())
+}
+
+/// Wraps a data key with a recovery key, independently of the master
+/// password.
+pub fn wrap_data_key_with_recovery_key(
+    data_key: &[u8],
+    recovery_key: &str,
+    aad: &AssociatedData,
+) -> Result<String, EncryptionError> {
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    let key = parse_recovery_key(recovery_key)?;
+    let encoded = Zeroizing::new(general_purpose::STANDARD.encode(data_key));
+    encrypt_with_key_aad(&encoded, key.expose_secret(), aad)
+}
+
+/// Recovers a data key wrapped with [`wrap_data_key_with_recovery_key`].
+pub fn unwrap_data_key_with_recovery_key(
+    wrapped_key: &str,
+    recovery_key: &str,
+    aad: &AssociatedData,
+) -> Result<SecretBytes, EncryptionError> {
+    let key = parse_recovery_key(recovery_key)?;
+    let encoded = Zeroizing::new(decrypt_with_key_aad(wrapped_key, key.expose_secret(), aad)?);
+    let data_key = Zeroizing::new(
+        general_purpose::STANDARD
+            .decode(encoded.as_bytes())
+            .map_err(|_| EncryptionError::DecryptionFailed)?,
+    );
+    if data_key.len() != DATA_KEY_LENGTH {
+        return Err(EncryptionError::InvalidKeyLength);
+    }
+    Ok(data_key.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_data_key;
+
+    fn aad() -> AssociatedData {
+        AssociatedData::new().field("vault_keys").field("user_1")
+    }
+
+    #[test]
+    fn test_recovery_key_round_trip() {
+        let recovery_key = generate_recovery_key();
+        assert_eq!(
+            recovery_key.expose_secret().split(' ').count(),
+            RECOVERY_KEY_WORDS
+        );
+
+        let data_key = generate_data_key();
+        let wrapped = wrap_data_key_with_recovery_key(
+            data_key.expose_secret(),
+            recovery_key.expose_secret(),
+            &aad(),
+        )
+        .unwrap();
+        let unwrapped =
+            unwrap_data_key_with_recovery_key(&wrapped, recovery_key.expose_secret(), &aad())
+                .unwrap();
+        assert_eq!(unwrapped, data_key);
+    }
+
+    #[test]
+    fn test_parse_recovery_key_ignores_case_and_spacing() {
+        let recovery_key = generate_recovery_key();
+        let typed = format!(
+            "  {}\n",
+            recovery_key
+                .expose_secret()
+                .to_uppercase()
+                .replace(' ', "   ")
+        );
+
+        assert_eq!(
+            parse_recovery_key(&typed).unwrap(),
+            parse_recovery_key(recovery_key.expose_secret()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_recovery_key_checks_words() {
+        // All-zero entropy, the last word carries the checksum
+        let valid = format!("{} art", ["abandon"; 23].join(" "));
+        assert_eq!(
+            parse_recovery_key(&valid).unwrap().expose_secret(),
+            &[0u8; RECOVERY_KEY_LENGTH]
+        );
+
+        let bad_checksum = format!("{} zoo", ["abandon"; 23].join(" "));
+        let unknown_word = format!("{} artt", ["abandon"; 23].join(" "));
+        let too_short = format!("{} about", ["abandon"; 11].join(" "));
+        for recovery_key in [bad_checksum, unknown_word, too_short] {
+            assert!(matches!(
+                parse_recovery_key(&recovery_key),
+                Err(EncryptionError::InvalidRecoveryKey)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_recovery_key_from_shares() {
+        let recovery_key = generate_recovery_key();
+        let shares = split_recovery_key(recovery_key.expose_secret(), 2, 3).unwrap();
+
+        let restored = recovery_key_from_shares(&shares[1..]).unwrap();
+        assert_eq!(restored, recovery_key);
+        assert!(recovery_key_from_shares(&shares[..1]).is_err());
+    }
+
+    #[test]
+    fn test_unwrap_with_another_recovery_key() {
+        let data_key = generate_data_key();
+        let wrapped = wrap_data_key_with_recovery_key(
+            data_key.expose_secret(),
+            generate_recovery_key().expose_secret(),
+            &aad(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            unwrap_data_key_with_recovery_key(
+                &wrapped,
+                generate_recovery_key().expose_secret(),
+                &aad()
+            ),
+            Err(EncryptionError::DecryptionFailed)
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+/// A string that is wiped from memory on drop and never shows up in logs.
+///
+/// The value is only reachable through [`SecretString::expose_secret`], which
+/// keeps every place that reads a secret easy to find.
+#[derive(Clone, Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(value: String) -> Self {
+        SecretString(value)
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Hands the value over to code outside the crate's control, such as the
+    /// Dart side of the bridge, which can't be wiped from here.
+    pub fn into_exposed(mut self) -> String {
+        std::mem::take(&mut self.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+impl PartialEq for SecretString {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+}
+
+impl Eq for SecretString {}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        SecretString(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        SecretString(value.to_string())
+    }
+}
+
+/// Bytes, such as a raw data key, that are wiped from memory on drop and
+/// never show up in logs.
+#[derive(Clone, Default)]
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    pub fn new(value: Vec<u8>) -> Self {
+        SecretBytes(value)
+    }
+
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretBytes([REDACTED])")
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(value: Vec<u8>) -> Self {
+        SecretBytes(value)
+    }
+}
+
+impl From<Zeroizing<Vec<u8>>> for SecretBytes {
+    fn from(mut value: Zeroizing<Vec<u8>>) -> Self {
+        // Moves the buffer out without leaving a copy behind
+        SecretBytes(std::mem::take(&mut *value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(SecretString)
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for SecretString {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for SecretString {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        String::column_result(value).map(SecretString)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = SecretString::from("hunter2");
+        let bytes = SecretBytes::from(vec![1, 2, 3]);
+
+        assert!(!format!("{:?}", secret).contains("hunter2"));
+        assert_eq!(
+            format!("{:?}", Some(secret)),
+            "Some(SecretString([REDACTED]))"
+        );
+        assert_eq!(format!("{:?}", bytes), "SecretBytes([REDACTED])");
+    }
+
+    #[test]
+    fn test_equality() {
+        assert_eq!(SecretString::from("hunter2"), SecretString::from("hunter2"));
+        assert_ne!(SecretString::from("hunter2"), SecretString::from("hunter3"));
+        assert_ne!(
+            SecretBytes::from(vec![1, 2]),
+            SecretBytes::from(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_from_zeroizing_moves_buffer() {
+        let mut key = Zeroizing::new(vec![7u8; 32]);
+        let pointer = key.as_ptr();
+        let secret = SecretBytes::from(std::mem::take(&mut key));
+
+        assert_eq!(secret.expose_secret().as_ptr(), pointer);
+        assert_eq!(secret.len(), 32);
+    }
+
+    #[test]
+    fn test_into_exposed() {
+        let secret = SecretString::from("hunter2");
+
+        assert_eq!(secret.into_exposed(), "hunter2");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_is_transparent() {
+        let secret: SecretString = serde_json::from_str("\"hunter2\"").unwrap();
+
+        assert_eq!(secret.expose_secret(), "hunter2");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"hunter2\"");
+    }
+}
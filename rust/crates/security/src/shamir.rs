@@ -0,0 +1,365 @@
+use std::fmt;
+
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::{EncryptionError, SecretBytes, SecretString};
+
+// A share is encoded as
+//
+// [version][threshold][index][split id][value][checksum]
+//
+// the split id tells shares of different splits apart and the checksum, the
+// first bytes of a SHA-256 over everything before it, catches typos
+const SHARE_VERSION: u8 = 1;
+const SPLIT_ID_LENGTH: usize = 4;
+const CHECKSUM_LENGTH: usize = 4;
+const HEADER_LENGTH: usize = 3 + SPLIT_ID_LENGTH;
+
+// Printed shares are hex digits in dash separated groups
+const TEXT_GROUP_LENGTH: usize = 4;
+
+/// One share of a secret split with [`split_secret`].
+#[derive(Clone)]
+pub struct Share {
+    threshold: u8,
+    index: u8,
+    split_id: [u8; SPLIT_ID_LENGTH],
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("threshold", &self.threshold)
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Share {
+    /// Number of shares needed to recover the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Position of the share, from 1 to the number of shares.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(
+            HEADER_LENGTH + self.value.len() + CHECKSUM_LENGTH,
+        ));
+        bytes.extend_from_slice(&[SHARE_VERSION, self.threshold, self.index]);
+        bytes.extend_from_slice(&self.split_id);
+        bytes.extend_from_slice(&self.value);
+        let checksum = checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Share, EncryptionError> {
+        if bytes.len() <= HEADER_LENGTH + CHECKSUM_LENGTH {
+            return Err(EncryptionError::InvalidShare);
+        }
+        let (body, stored) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+        if !bool::from(checksum(body).ct_eq(stored)) {
+            return Err(EncryptionError::InvalidShare);
+        }
+        if body[0] != SHARE_VERSION {
+            return Err(EncryptionError::UnsupportedVersion);
+        }
+        let (threshold, index) = (body[1], body[2]);
+        if threshold < 2 || index == 0 {
+            return Err(EncryptionError::InvalidShare);
+        }
+        let mut split_id = [0u8; SPLIT_ID_LENGTH];
+        split_id.copy_from_slice(&body[3..HEADER_LENGTH]);
+        Ok(Share {
+            threshold,
+            index,
+            split_id,
+            value: Zeroizing::new(body[HEADER_LENGTH..].to_vec()),
+        })
+    }
+
+    /// Encodes the share as printable text, hex digits in groups of four.
+    pub fn to_text(&self) -> SecretString {
+        let bytes = self.to_bytes();
+        let digits = Zeroizing::new(
+            bytes
+                .iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<String>(),
+        );
+        let groups = digits
+            .as_bytes()
+            .chunks(TEXT_GROUP_LENGTH)
+            .map(|group| std::str::from_utf8(group).unwrap())
+            .collect::<Vec<_>>();
+        groups.join("-").into()
+    }
+
+    /// Decodes a share printed with [`Share::to_text`], case, spaces and
+    /// dashes are ignored.
+    pub fn from_text(text: &str) -> Result<Share, EncryptionError> {
+        let digits = Zeroizing::new(
+            text.chars()
+                .filter(|c| !c.is_whitespace() && *c != '-')
+                .collect::<String>(),
+        );
+        if digits.len() % 2 != 0 || !digits.is_ascii() {
+            return Err(EncryptionError::InvalidShare);
+        }
+        let bytes = Zeroizing::new(
+            (0..digits.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| EncryptionError::InvalidShare)?,
+        );
+        Share::from_bytes(&bytes)
+    }
+}
+
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let digest = Sha256::digest(bytes);
+    let mut checksum = [0u8; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LENGTH]);
+    checksum
+}
+
+// Multiplication in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1,
+// without branches or table lookups on secret values
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    product
+}
+
+// a^254 is the inverse of a in GF(2^8)
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Checks that `threshold` out of `shares` is a valid split, at least two
+/// shares are always needed.
+pub fn check_share_threshold(threshold: u8, shares: u8) -> Result<(), EncryptionError> {
+    if threshold < 2 || shares < threshold {
+        return Err(EncryptionError::InvalidShareThreshold);
+    }
+    Ok(())
+}
+
+/// Splits `secret` into `shares` shares, any `threshold` of which recover it.
+///
+/// Every byte of the secret is the constant term of its own random
+/// polynomial of degree `threshold - 1` over GF(256), fewer shares than the
+/// threshold reveal nothing about it.
+pub fn split_secret(
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<Share>, EncryptionError> {
+    check_share_threshold(threshold, shares)?;
+    if secret.is_empty() {
+        return Err(EncryptionError::InvalidShare);
+    }
+
+    let mut split_id = [0u8; SPLIT_ID_LENGTH];
+    OsRng.fill_bytes(&mut split_id);
+    let mut result = (1..=shares)
+        .map(|index| Share {
+            threshold,
+            index,
+            split_id,
+            value: Zeroizing::new(Vec::with_capacity(secret.len())),
+        })
+        .collect::<Vec<_>>();
+
+    let mut coefficients = Zeroizing::new(vec![0u8; threshold as usize]);
+    for &byte in secret {
+        coefficients[0] = byte;
+        OsRng.fill_bytes(&mut coefficients[1..]);
+        for share in result.iter_mut() {
+            // Horner's rule
+            let value = coefficients.iter().rev().fold(0u8, |acc, &coefficient| {
+                gf_mul(acc, share.index) ^ coefficient
+            });
+            share.value.push(value);
+        }
+    }
+    Ok(result)
+}
+
+/// Recovers a secret from at least `threshold` shares of one split.
+pub fn combine_shares(shares: &[Share]) -> Result<SecretBytes, EncryptionError> {
+    let first = shares.first().ok_or(EncryptionError::NotEnoughShares)?;
+    for share in shares {
+        if share.threshold != first.threshold
+            || share.split_id != first.split_id
+            || share.value.len() != first.value.len()
+        {
+            return Err(EncryptionError::InvalidShare);
+        }
+    }
+
+    // Only distinct shares count towards the threshold
+    let mut distinct: Vec<&Share> = Vec::with_capacity(shares.len());
+    for share in shares {
+        match distinct.iter().find(|other| other.index == share.index) {
+            Some(other) if other.value != share.value => return Err(EncryptionError::InvalidShare),
+            Some(_) => {}
+            None => distinct.push(share),
+        }
+    }
+    if distinct.len() < first.threshold as usize {
+        return Err(EncryptionError::NotEnoughShares);
+    }
+    let used = &distinct[..first.threshold as usize];
+
+    // Lagrange interpolation at x = 0, subtraction is xor in GF(2^8)
+    let basis = used
+        .iter()
+        .map(|share| {
+            used.iter()
+                .filter(|other| other.index != share.index)
+                .fold(1u8, |acc, other| {
+                    gf_mul(acc, gf_mul(other.index, gf_inv(other.index ^ share.index)))
+                })
+        })
+        .collect::<Vec<_>>();
+    let mut secret = Zeroizing::new(vec![0u8; first.value.len()]);
+    for (position, byte) in secret.iter_mut().enumerate() {
+        *byte = used.iter().zip(&basis).fold(0u8, |acc, (share, &weight)| {
+            acc ^ gf_mul(share.value[position], weight)
+        });
+    }
+    Ok(secret.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_arithmetic() {
+        // Known products from FIPS-197
+        assert_eq!(gf_mul(0x57, 0x83), 0xc1);
+        assert_eq!(gf_mul(0x57, 0x13), 0xfe);
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+
+    #[test]
+    fn test_any_threshold_subset_recovers() {
+        let secret = b"correct horse battery staple";
+        let shares = split_secret(secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[a].clone(), shares[b].clone(), shares[c].clone()];
+                    let recovered = combine_shares(&subset).unwrap();
+                    assert_eq!(recovered.expose_secret(), secret);
+                }
+            }
+        }
+        assert_eq!(
+            combine_shares(&shares).unwrap().expose_secret(),
+            secret.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_not_enough_shares() {
+        let shares = split_secret(b"secret", 3, 5).unwrap();
+        let duplicated = [shares[0].clone(), shares[0].clone(), shares[1].clone()];
+
+        assert!(matches!(
+            combine_shares(&shares[..2]),
+            Err(EncryptionError::NotEnoughShares)
+        ));
+        assert!(matches!(
+            combine_shares(&duplicated),
+            Err(EncryptionError::NotEnoughShares)
+        ));
+    }
+
+    #[test]
+    fn test_shares_of_different_splits_do_not_mix() {
+        let first = split_secret(b"secret", 2, 3).unwrap();
+        let second = split_secret(b"secret", 2, 3).unwrap();
+
+        assert!(matches!(
+            combine_shares(&[first[0].clone(), second[1].clone()]),
+            Err(EncryptionError::InvalidShare)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        assert!(split_secret(b"secret", 1, 3).is_err());
+        assert!(split_secret(b"secret", 4, 3).is_err());
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let shares = split_secret(b"secret", 2, 3).unwrap();
+        let text = shares[1].to_text();
+        assert!(text
+            .expose_secret()
+            .split('-')
+            .all(|group| group.len() <= TEXT_GROUP_LENGTH));
+
+        let typed = text.expose_secret().to_lowercase().replace('-', " ");
+        let parsed = Share::from_text(&typed).unwrap();
+        assert_eq!(parsed.index(), 2);
+        assert_eq!(parsed.threshold(), 2);
+        assert_eq!(
+            combine_shares(&[shares[0].clone(), parsed])
+                .unwrap()
+                .expose_secret(),
+            b"secret"
+        );
+    }
+
+    #[test]
+    fn test_checksum_catches_typos() {
+        let shares = split_secret(b"secret", 2, 3).unwrap();
+        let text = shares[0].to_text();
+        let mut typo = text.expose_secret().to_string();
+        let last = typo.pop().unwrap();
+        typo.push(if last == '0' { '1' } else { '0' });
+
+        assert!(matches!(
+            Share::from_text(&typo),
+            Err(EncryptionError::InvalidShare)
+        ));
+        assert!(matches!(
+            Share::from_text("not a share"),
+            Err(EncryptionError::InvalidShare)
+        ));
+    }
+}
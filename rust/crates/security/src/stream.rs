@@ -0,0 +1,393 @@
+use std::io::{ErrorKind, Read, Write};
+
+use rand::{rngs::OsRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::{
+    encryption::{derive_key, EncryptionError, KdfParams, KDF_ARGON2ID, V2_SALT_LENGTH},
+    preferred_cipher, Cipher,
+};
+
+// Explicitly define the structure of an encrypted stream
+//
+// header: version, kdf, m_cost, t_cost, p_cost, cipher, salt length, salt,
+//         nonce prefix, chunk size
+// body:   one AEAD ciphertext per chunk, every chunk but the last one is
+//         exactly chunk size + tag length bytes long
+//
+// Chunk nonces follow the STREAM construction: nonce prefix || counter || last
+// flag. The counter catches reordered chunks and the last flag catches a
+// stream that was cut at a chunk boundary. The prefix fills the rest of the
+// cipher's nonce, 7 bytes for AES-GCM and 19 for XChaCha20-Poly1305. Every
+// chunk authenticates the header as associated data.
+const STREAM_VERSION: u8 = 1;
+const TAG_LENGTH: usize = 16;
+const CHUNK_SIZE: usize = 64 * 1024;
+const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+// version, kdf, m_cost, t_cost, p_cost, cipher, salt length
+const STREAM_FIXED_HEADER_LENGTH: usize = 1 + 1 + 4 + 4 + 4 + 1 + 1;
+
+fn nonce_prefix_length(cipher: Cipher) -> usize {
+    // counter and last flag
+    cipher.nonce_length() - 4 - 1
+}
+
+fn chunk_nonce(prefix: &[u8], counter: u32, last: bool) -> Vec<u8> {
+    let mut nonce = prefix.to_vec();
+    nonce.extend_from_slice(&counter.to_be_bytes());
+    nonce.push(last as u8);
+    nonce
+}
+
+// Like `Read::read_exact`, but stops quietly at the end of the input
+fn read_full<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<usize, EncryptionError> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+/// Encrypts everything `reader` yields into `writer`, one chunk at a time.
+///
+/// The key is derived from `password` once for the whole stream and the cipher
+/// is picked by the current [`CipherPolicy`](crate::CipherPolicy). Returns the
+/// number of plaintext bytes read.
+pub fn encrypt_stream<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    password: &str,
+) -> Result<u64, EncryptionError> {
+    encrypt_stream_with_params(reader, writer, password, &KdfParams::default())
+}
+
+pub fn encrypt_stream_with_params<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    password: &str,
+    params: &KdfParams,
+) -> Result<u64, EncryptionError> {
+    encrypt_chunks(
+        reader,
+        writer,
+        password,
+        params,
+        preferred_cipher(),
+        CHUNK_SIZE,
+    )
+}
+
+fn encrypt_chunks<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    password: &str,
+    params: &KdfParams,
+    cipher: Cipher,
+    chunk_size: usize,
+) -> Result<u64, EncryptionError> {
+    let mut salt = [0u8; V2_SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce_prefix = vec![0u8; nonce_prefix_length(cipher)];
+    OsRng.fill_bytes(&mut nonce_prefix);
+
+    let key = derive_key(password, &salt, params)?;
+
+    let mut header = vec![STREAM_VERSION, KDF_ARGON2ID];
+    header.extend_from_slice(&params.memory_cost.to_be_bytes());
+    header.extend_from_slice(&params.time_cost.to_be_bytes());
+    header.extend_from_slice(&params.parallelism.to_be_bytes());
+    header.push(cipher.id());
+    header.push(V2_SALT_LENGTH as u8);
+    header.extend_from_slice(&salt);
+    header.extend_from_slice(&nonce_prefix);
+    header.extend_from_slice(&(chunk_size as u32).to_be_bytes());
+    writer.write_all(&header)?;
+
+    let mut current = Zeroizing::new(vec![0u8; chunk_size]);
+    let mut next = Zeroizing::new(vec![0u8; chunk_size]);
+    let mut current_length = read_full(&mut reader, &mut current)?;
+    let mut counter: u32 = 0;
+    let mut total: u64 = 0;
+
+    loop {
+        // Only a short read proves the current chunk is the last one
+        let next_length = if current_length == chunk_size {
+            read_full(&mut reader, &mut next)?
+        } else {
+            0
+        };
+        let last = next_length == 0;
+
+        let nonce = chunk_nonce(&nonce_prefix, counter, last);
+        let ciphertext = cipher.seal(&key, &nonce, &current[..current_length], &header)?;
+        writer.write_all(&ciphertext)?;
+        total += current_length as u64;
+
+        if last {
+            break;
+        }
+        counter = counter
+            .checked_add(1)
+            .ok_or(EncryptionError::EncryptionFailed)?;
+        std::mem::swap(&mut current, &mut next);
+        current_length = next_length;
+    }
+
+    writer.flush()?;
+    Ok(total)
+}
+
+/// Decrypts a stream produced by [`encrypt_stream`] into `writer`.
+///
+/// Plaintext is written as soon as each chunk authenticates, so on error the
+/// caller must discard whatever already reached `writer`. Returns the number
+/// of plaintext bytes written.
+pub fn decrypt_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    password: &str,
+) -> Result<u64, EncryptionError> {
+    let mut header = vec![0u8; STREAM_FIXED_HEADER_LENGTH];
+    if read_full(&mut reader, &mut header)? != STREAM_FIXED_HEADER_LENGTH {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+    if header[0] != STREAM_VERSION {
+        return Err(EncryptionError::UnsupportedVersion);
+    }
+    if header[1] != KDF_ARGON2ID {
+        return Err(EncryptionError::UnsupportedKdf);
+    }
+    let cipher = Cipher::from_id(header[14])?;
+    if header[15] as usize != V2_SALT_LENGTH {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+
+    // The rest of the header depends on the cipher's nonce length
+    let prefix_length = nonce_prefix_length(cipher);
+    header.resize(
+        STREAM_FIXED_HEADER_LENGTH + V2_SALT_LENGTH + prefix_length + 4,
+        0,
+    );
+    if read_full(&mut reader, &mut header[STREAM_FIXED_HEADER_LENGTH..])?
+        != V2_SALT_LENGTH + prefix_length + 4
+    {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+
+    let read_u32 =
+        |offset: usize| u32::from_be_bytes(header[offset..offset + 4].try_into().unwrap());
+    let params = KdfParams {
+        memory_cost: read_u32(2),
+        time_cost: read_u32(6),
+        parallelism: read_u32(10),
+    };
+    // Refuse attacker-chosen costs before they ever reach Argon2
+    params
+        .validate()
+        .map_err(|_| EncryptionError::DecryptionFailed)?;
+    let salt = &header[16..16 + V2_SALT_LENGTH];
+    let prefix_start = 16 + V2_SALT_LENGTH;
+    let nonce_prefix = &header[prefix_start..prefix_start + prefix_length];
+    let chunk_size = read_u32(prefix_start + prefix_length) as usize;
+    if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+        return Err(EncryptionError::DecryptionFailed);
+    }
+
+    let key = derive_key(password, salt, &params)?;
+
+    let encrypted_chunk_size = chunk_size + TAG_LENGTH;
+    let mut current = vec![0u8; encrypted_chunk_size];
+    let mut next = vec![0u8; encrypted_chunk_size];
+    let mut current_length = read_full(&mut reader, &mut current)?;
+    let mut counter: u32 = 0;
+    let mut total: u64 = 0;
+
+    loop {
+        if current_length < TAG_LENGTH {
+            return Err(EncryptionError::DecryptionFailed);
+        }
+        let next_length = if current_length == encrypted_chunk_size {
+            read_full(&mut reader, &mut next)?
+        } else {
+            0
+        };
+        let last = next_length == 0;
+
+        let nonce = chunk_nonce(nonce_prefix, counter, last);
+        let plaintext =
+            Zeroizing::new(cipher.open(&key, &nonce, &current[..current_length], &header)?);
+        writer.write_all(&plaintext)?;
+        total += plaintext.len() as u64;
+
+        if last {
+            break;
+        }
+        counter = counter
+            .checked_add(1)
+            .ok_or(EncryptionError::DecryptionFailed)?;
+        std::mem::swap(&mut current, &mut next);
+        current_length = next_length;
+    }
+
+    writer.flush()?;
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::MIN_MEMORY_COST;
+
+    const CHUNK: usize = 16;
+
+    // Cheapest accepted parameters, the tests exercise the chunking not the KDF
+    fn test_params() -> KdfParams {
+        KdfParams {
+            memory_cost: MIN_MEMORY_COST,
+            time_cost: 1,
+            parallelism: 1,
+        }
+    }
+
+    fn encrypt_bytes(data: &[u8], password: &str) -> Vec<u8> {
+        encrypt_bytes_with(data, password, Cipher::Aes256Gcm)
+    }
+
+    fn encrypt_bytes_with(data: &[u8], password: &str, cipher: Cipher) -> Vec<u8> {
+        let mut encrypted = Vec::new();
+        encrypt_chunks(
+            data,
+            &mut encrypted,
+            password,
+            &test_params(),
+            cipher,
+            CHUNK,
+        )
+        .unwrap();
+        encrypted
+    }
+
+    // Header length of an AES-GCM stream
+    const STREAM_HEADER_LENGTH: usize = STREAM_FIXED_HEADER_LENGTH + V2_SALT_LENGTH + 7 + 4;
+
+    fn decrypt_bytes(encrypted: &[u8], password: &str) -> Result<Vec<u8>, EncryptionError> {
+        let mut decrypted = Vec::new();
+        decrypt_stream(encrypted, &mut decrypted, password)?;
+        Ok(decrypted)
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        for length in [0, 1, CHUNK - 1, CHUNK, CHUNK + 1, 3 * CHUNK, 5 * CHUNK + 7] {
+            let data: Vec<u8> = (0..length).map(|i| i as u8).collect();
+            let encrypted = encrypt_bytes(&data, "secret_password");
+
+            assert_eq!(
+                decrypt_bytes(&encrypted, "secret_password").unwrap(),
+                data,
+                "length {}",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_round_trip_xchacha20() {
+        for length in [0, CHUNK, 3 * CHUNK + 7] {
+            let data: Vec<u8> = (0..length).map(|i| i as u8).collect();
+            let encrypted = encrypt_bytes_with(&data, "secret_password", Cipher::XChaCha20Poly1305);
+
+            assert_eq!(encrypted[14], Cipher::XChaCha20Poly1305.id());
+            assert_eq!(decrypt_bytes(&encrypted, "secret_password").unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_stream_cross_suite_cipher_swap_fails() {
+        let mut encrypted =
+            encrypt_bytes_with(b"attachment", "secret_password", Cipher::XChaCha20Poly1305);
+        encrypted[14] = Cipher::Aes256Gcm.id();
+
+        assert!(decrypt_bytes(&encrypted, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_default_chunk_size() {
+        let data = vec![42u8; CHUNK_SIZE * 2 + 100];
+        let mut encrypted = Vec::new();
+        let written = encrypt_stream_with_params(
+            &data[..],
+            &mut encrypted,
+            "secret_password",
+            &test_params(),
+        )
+        .unwrap();
+
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(decrypt_bytes(&encrypted, "secret_password").unwrap(), data);
+    }
+
+    #[test]
+    fn test_stream_wrong_password() {
+        let encrypted = encrypt_bytes(b"attachment", "secret_password");
+
+        assert!(decrypt_bytes(&encrypted, "wrong_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_truncated_at_chunk_boundary() {
+        let data = vec![7u8; 3 * CHUNK];
+        let encrypted = encrypt_bytes(&data, "secret_password");
+        // Drop the final chunk, the remaining last chunk isn't flagged as final
+        let truncated = &encrypted[..encrypted.len() - (CHUNK + TAG_LENGTH)];
+
+        assert!(decrypt_bytes(truncated, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_truncated_mid_chunk() {
+        let data = vec![7u8; 3 * CHUNK];
+        let encrypted = encrypt_bytes(&data, "secret_password");
+
+        assert!(decrypt_bytes(&encrypted[..encrypted.len() - 5], "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_reordered_chunks() {
+        let data: Vec<u8> = (0..3 * CHUNK).map(|i| i as u8).collect();
+        let mut encrypted = encrypt_bytes(&data, "secret_password");
+        let first = STREAM_HEADER_LENGTH;
+        let second = first + CHUNK + TAG_LENGTH;
+        let (head, tail) = encrypted.split_at_mut(second);
+        head[first..].swap_with_slice(&mut tail[..CHUNK + TAG_LENGTH]);
+
+        assert!(decrypt_bytes(&encrypted, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_tampered_header() {
+        let mut encrypted = encrypt_bytes(b"attachment", "secret_password");
+        // Flip a bit of the nonce prefix
+        encrypted[16 + V2_SALT_LENGTH] ^= 1;
+
+        assert!(decrypt_bytes(&encrypted, "secret_password").is_err());
+    }
+
+    #[test]
+    fn test_stream_forged_header_params() {
+        let mut encrypted = encrypt_bytes(b"attachment", "secret_password");
+        // Claim 4 TiB of memory and an absurd number of passes
+        encrypted[2..6].copy_from_slice(&u32::MAX.to_be_bytes());
+        encrypted[6..10].copy_from_slice(&u32::MAX.to_be_bytes());
+
+        assert!(matches!(
+            decrypt_bytes(&encrypted, "secret_password"),
+            Err(EncryptionError::DecryptionFailed)
+        ));
+    }
+}
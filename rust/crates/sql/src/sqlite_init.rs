@@ -118,6 +118,62 @@ pub async fn migrate_sqlite(db_file: &str) -> Result<()> {
             "0_init.up.sql",
             include_str!("../../../migrations/0_init.up.sql"),
         ),
+        (
+            "1_vault_keys.up.sql",
+            include_str!("../../../migrations/1_vault_keys.up.sql"),
+        ),
+        (
+            "2_vault_item_version.up.sql",
+            include_str!("../../../migrations/2_vault_item_version.up.sql"),
+        ),
+        (
+            "3_vault_kdf_params.up.sql",
+            include_str!("../../../migrations/3_vault_kdf_params.up.sql"),
+        ),
+        (
+            "4_vault_recovery_key.up.sql",
+            include_str!("../../../migrations/4_vault_recovery_key.up.sql"),
+        ),
+        (
+            "5_login_breach_status.up.sql",
+            include_str!("../../../migrations/5_login_breach_status.up.sql"),
+        ),
+        (
+            "6_password_history.up.sql",
+            include_str!("../../../migrations/6_password_history.up.sql"),
+        ),
+        (
+            "7_two_factor.up.sql",
+            include_str!("../../../migrations/7_two_factor.up.sql"),
+        ),
+        (
+            "8_login_otpauth.up.sql",
+            include_str!("../../../migrations/8_login_otpauth.up.sql"),
+        ),
+        (
+            "9_two_factor_backup_codes.up.sql",
+            include_str!("../../../migrations/9_two_factor_backup_codes.up.sql"),
+        ),
+        (
+            "10_passkeys.up.sql",
+            include_str!("../../../migrations/10_passkeys.up.sql"),
+        ),
+        (
+            "11_refresh_tokens.up.sql",
+            include_str!("../../../migrations/11_refresh_tokens.up.sql"),
+        ),
+        (
+            "12_jwt_keys.up.sql",
+            include_str!("../../../migrations/12_jwt_keys.up.sql"),
+        ),
+        (
+            "13_token_epoch.up.sql",
+            include_str!("../../../migrations/13_token_epoch.up.sql"),
+        ),
+        (
+            "14_two_factor_lockout.up.sql",
+            include_str!("../../../migrations/14_two_factor_lockout.up.sql"),
+        ),
         // Add more migrations as needed
     ];
 
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS passkeys (
+    id TEXT PRIMARY KEY,
+    created_at INTEGER NOT NULL,
+    created_by TEXT NOT NULL,
+    updated_at INTEGER,
+    updated_by TEXT,
+    name TEXT NOT NULL,
+    note TEXT,
+    rp_id TEXT NOT NULL,
+    rp_name TEXT,
+    user_handle TEXT NOT NULL,
+    user_name TEXT,
+    user_display_name TEXT,
+    credential_id TEXT NOT NULL,
+    algorithm INTEGER NOT NULL,
+    private_key TEXT NOT NULL,
+    sign_count INTEGER NOT NULL DEFAULT 0,
+    last_used_at INTEGER,
+    is_favorite BOOLEAN NOT NULL DEFAULT FALSE,
+    tags TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_passkeys_rp_id ON passkeys (rp_id);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS jwt_keys (
+    kid TEXT PRIMARY KEY,
+    purpose TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    retired_at INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_jwt_keys_purpose ON jwt_keys (purpose);
@@ -0,0 +1,2 @@
+ALTER TABLE users ADD COLUMN two_factor_failures INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN two_factor_locked_until INTEGER;
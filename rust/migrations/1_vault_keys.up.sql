@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS vault_keys (
+    id TEXT PRIMARY KEY,
+    created_at INTEGER NOT NULL,
+    created_by TEXT NOT NULL UNIQUE,
+    updated_at INTEGER,
+    updated_by TEXT,
+    wrapped_data_key TEXT NOT NULL
+);
@@ -0,0 +1 @@
+ALTER TABLE vault_keys ADD COLUMN item_version INTEGER NOT NULL DEFAULT 1;
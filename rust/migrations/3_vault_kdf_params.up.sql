@@ -0,0 +1,3 @@
+ALTER TABLE vault_keys ADD COLUMN kdf_memory_cost INTEGER;
+ALTER TABLE vault_keys ADD COLUMN kdf_time_cost INTEGER;
+ALTER TABLE vault_keys ADD COLUMN kdf_parallelism INTEGER;
@@ -0,0 +1 @@
+ALTER TABLE vault_keys ADD COLUMN recovery_wrapped_key TEXT;
@@ -0,0 +1,2 @@
+ALTER TABLE logins ADD COLUMN breach_count INTEGER;
+ALTER TABLE logins ADD COLUMN breach_checked_at INTEGER;
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS password_history (
+    id TEXT PRIMARY KEY,
+    created_at INTEGER NOT NULL,
+    created_by TEXT NOT NULL,
+    updated_at INTEGER,
+    updated_by TEXT,
+    login_id TEXT NOT NULL,
+    password TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_password_history_login_id ON password_history (login_id);
+
+ALTER TABLE logins ADD COLUMN history_limit INTEGER;
@@ -0,0 +1 @@
+ALTER TABLE logins ADD COLUMN otpauth TEXT;
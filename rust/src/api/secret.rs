@@ -0,0 +1,16 @@
+use flutter_rust_bridge::frb;
+// Re-exported for the generated bridge code, which names it unqualified
+pub(crate) use security::SecretString;
+
+// Dart strings can't be wiped, so secrets are plain strings on the Dart side
+// and only wrapped again once they are back in Rust.
+
+#[frb(rust2dart(dart_type = "String", dart_code = "{}"))]
+pub fn encode_secret_string(raw: SecretString) -> String {
+    raw.into_exposed()
+}
+
+#[frb(dart2rust(dart_type = "String", dart_code = "{}"))]
+pub fn decode_secret_string(raw: String) -> SecretString {
+    SecretString::from(raw)
+}
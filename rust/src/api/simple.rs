@@ -1,11 +1,19 @@
-use security::{decrypt, encrypt};
+use std::{path::Path, time::Duration};
+
+use security::{
+    calibrate_kdf, combine_shares, decrypt, encrypt, generate_data_key, recovery_key_from_shares,
+    set_cipher_policy, split_secret, CipherPolicy, SecretString, Share,
+};
 
 use crate::{
     common::{
+        breach::{breach_count, build_breach_index, check_breached_logins, load_breach_index},
         financial_cards::{
             add_financial_card, fetch_financial_card, get_all_financial_cards,
             remove_financial_card, update_financial_card,
         },
+        generator::generate,
+        health::{vault_health, DEFAULT_STALE_DAYS},
         identity_cards::{
             add_identity_card, fetch_identity_card, get_all_identity_cards, remove_identity_card,
             update_identity_card,
@@ -13,10 +21,40 @@ use crate::{
         jwt::get_user_id_from_token,
         logins::{add_login, fetch_login, get_all_logins, remove_login, update_login},
         notes::{add_note, fetch_note, get_all_notes, remove_note, update_note},
+        otp::get_totp_code,
+        passkeys::{
+            add_passkey, fetch_passkey, find_passkeys, get_all_passkeys, remove_passkey,
+            update_passkey,
+        },
+        password::{password_hash_settings, set_password_hash_settings},
+        password_history::{get_password_history, restore_password},
+        qr::{qr_matrix, qr_png, qr_svg},
+        refresh_tokens::rotate_refresh_token,
+        strength::estimate_strength,
         tags::{add_tag, fetch_tag, get_all_tags, remove_tag, update_tag},
-        users::{create_user, login_user},
+        two_factor::{
+            begin_two_factor, confirm_two_factor, disable_two_factor, regenerate_backup_codes,
+            set_two_factor_key, two_factor_status,
+        },
+        users::{
+            change_master_password, create_user, login_two_factor, login_user, recover_account,
+        },
+        validation::{password_policy, password_violations, set_password_policy},
+        vault::{
+            create_recovery_key, create_recovery_shares, get_rekey_progress, get_vault_kdf_params,
+            lock_vault, rekey_vault, set_vault_kdf_params, unlock_vault,
+        },
+        vault_session::{is_unlocked, set_auto_lock_timeout},
+    },
+    models::{
+        BreachReport, ChangePasswordData, FinancialCard, GeneratedPassword, GeneratorOptions,
+        IdentityCard, JwtTokens, KdfSettings, Login, LoginData, LoginResult, Note, OtpCode,
+        Passkey, PasskeyAssertion, PasskeyAssertionRequest, PasskeyCreation, PasskeyRegistration,
+        PasswordHistory, PasswordPolicy, PasswordRule, PasswordStrength, QrMatrix, QrOptions,
+        RecoverAccountData, RefreshTokenData, RegisterData, RekeyProgress, RekeyResult,
+        ShareSettings, Tag, TwoFactorBackupCodes, TwoFactorLoginData, TwoFactorSetup,
+        TwoFactorStatus, VaultHealth,
     },
-    models::{FinancialCard, IdentityCard, JwtTokens, Login, LoginData, Note, RegisterData, Tag},
 };
 
 #[flutter_rust_bridge::frb(sync)] // Synchronous mode for simplicity of the demo
@@ -30,15 +68,57 @@ pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
 }
 
+/// Selects the cipher suite new values are sealed with, one of `auto`,
+/// `aes-256-gcm` or `xchacha20-poly1305`. Existing values stay readable.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_encryption_cipher(policy: String) -> anyhow::Result<()> {
+    set_cipher_policy(policy.parse::<CipherPolicy>()?);
+    Ok(())
+}
+
+/// Benchmarks Argon2id on this device, `max_memory` is in KiB.
+#[flutter_rust_bridge::frb(sync)]
+pub fn calibrate_kdf_settings(target_ms: u64, max_memory: u32) -> anyhow::Result<KdfSettings> {
+    let params = calibrate_kdf(target_ms, max_memory)?;
+    Ok(params.into())
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_kdf_settings(token: String) -> anyhow::Result<KdfSettings> {
+    let user = get_user_id_from_token(token).await?;
+    let params = get_vault_kdf_params(&user).await?;
+    Ok(params.into())
+}
+
+/// Moves the vault to new Argon2id settings, the master password confirms
+/// the change.
+#[tokio::main(flavor = "current_thread")]
+pub async fn save_kdf_settings(
+    settings: KdfSettings,
+    master_password: String,
+    token: String,
+) -> anyhow::Result<()> {
+    let user = get_user_id_from_token(token).await?;
+    set_vault_kdf_params(
+        &user,
+        &SecretString::from(master_password),
+        &settings.into(),
+    )
+    .await?;
+    Ok(())
+}
+
 #[tokio::main(flavor = "current_thread")]
 pub async fn encrypt_data(data: String, password: String) -> anyhow::Result<String> {
-    let encrypted = encrypt(data.as_str(), password.as_str())?;
+    let password = SecretString::from(password);
+    let encrypted = encrypt(data.as_str(), password.expose_secret())?;
     Ok(encrypted)
 }
 
 #[tokio::main(flavor = "current_thread")]
 pub async fn decrypt_data(data: String, password: String) -> anyhow::Result<String> {
-    let decrypted = decrypt(data.as_str(), password.as_str())?;
+    let password = SecretString::from(password);
+    let decrypted = decrypt(data.as_str(), password.expose_secret())?;
     Ok(decrypted)
 }
 
@@ -65,12 +145,341 @@ pub async fn register(user: RegisterData) -> anyhow::Result<JwtTokens> {
     Ok(tokens)
 }
 
+/// Signs in with a password. Accounts with two-factor authentication get
+/// a challenge to finish with [`login_with_two_factor`].
 #[tokio::main(flavor = "current_thread")]
-pub async fn login(user: LoginData) -> anyhow::Result<JwtTokens> {
+pub async fn login(user: LoginData) -> anyhow::Result<LoginResult> {
     let user = login_user(user).await?;
     Ok(user)
 }
 
+#[tokio::main(flavor = "current_thread")]
+pub async fn login_with_two_factor(data: TwoFactorLoginData) -> anyhow::Result<JwtTokens> {
+    let tokens = login_two_factor(data).await?;
+    Ok(tokens)
+}
+
+/// Trades a refresh token for new tokens, the old refresh token stops
+/// working.
+#[tokio::main(flavor = "current_thread")]
+pub async fn refresh_tokens(data: RefreshTokenData) -> anyhow::Result<JwtTokens> {
+    let tokens = rotate_refresh_token(data.refresh_token.expose_secret())?;
+    Ok(tokens)
+}
+
+/// Sets the key TOTP secrets are sealed with in the local database, 64 hex
+/// digits the app keeps in secure storage. Two-factor authentication can't
+/// be set up without it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_two_factor_encryption_key(key: String) -> anyhow::Result<()> {
+    let key = hex::decode(key.trim())?;
+    set_two_factor_key(Some(&key))?;
+    Ok(())
+}
+
+/// A new random key for [`set_two_factor_encryption_key`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn generate_two_factor_encryption_key() -> String {
+    hex::encode(generate_data_key().expose_secret())
+}
+
+/// Starts two-factor enrollment, codes are needed on login once
+/// [`confirm_two_factor_setup`] succeeds.
+#[tokio::main(flavor = "current_thread")]
+pub async fn begin_two_factor_setup(token: String) -> anyhow::Result<TwoFactorSetup> {
+    let user = get_user_id_from_token(token).await?;
+    let setup = begin_two_factor(&user).await?;
+    Ok(setup)
+}
+
+/// Turns two-factor authentication on, returning backup codes the user
+/// has to write down, they are not shown again.
+#[tokio::main(flavor = "current_thread")]
+pub async fn confirm_two_factor_setup(
+    code: String,
+    token: String,
+) -> anyhow::Result<TwoFactorBackupCodes> {
+    let user = get_user_id_from_token(token).await?;
+    let backup_codes = confirm_two_factor(&user, &code).await?;
+    Ok(backup_codes)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn turn_off_two_factor(code: String, token: String) -> anyhow::Result<()> {
+    let user = get_user_id_from_token(token).await?;
+    disable_two_factor(&user, &code).await?;
+    Ok(())
+}
+
+/// Replaces the backup codes, `code` is from the authenticator or one of
+/// the current backup codes.
+#[tokio::main(flavor = "current_thread")]
+pub async fn regenerate_two_factor_backup_codes(
+    code: String,
+    token: String,
+) -> anyhow::Result<TwoFactorBackupCodes> {
+    let user = get_user_id_from_token(token).await?;
+    let backup_codes = regenerate_backup_codes(&user, &code).await?;
+    Ok(backup_codes)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_two_factor_status(token: String) -> anyhow::Result<TwoFactorStatus> {
+    let user = get_user_id_from_token(token).await?;
+    let status = two_factor_status(&user).await?;
+    Ok(status)
+}
+
+/// Unlocks the vault of the signed in user with the master password.
+#[tokio::main(flavor = "current_thread")]
+pub async fn unlock(master_password: String, token: String) -> anyhow::Result<()> {
+    let user = get_user_id_from_token(token).await?;
+    unlock_vault(&user, &SecretString::from(master_password)).await?;
+    Ok(())
+}
+
+/// Locks the vault of the signed in user and wipes its data key.
+#[tokio::main(flavor = "current_thread")]
+pub async fn lock(token: String) -> anyhow::Result<()> {
+    let user = get_user_id_from_token(token).await?;
+    lock_vault(&user);
+    Ok(())
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn is_vault_unlocked(token: String) -> anyhow::Result<bool> {
+    let user = get_user_id_from_token(token).await?;
+    Ok(is_unlocked(&user))
+}
+
+/// Changes the account password, which also re-wraps the vault's data key.
+#[tokio::main(flavor = "current_thread")]
+pub async fn change_password(data: ChangePasswordData, token: String) -> anyhow::Result<()> {
+    let user = get_user_id_from_token(token).await?;
+    change_master_password(user, data).await?;
+    Ok(())
+}
+
+/// Sets a new password with the recovery key and signs the user in, the
+/// returned tokens carry the rotated recovery key.
+#[tokio::main(flavor = "current_thread")]
+pub async fn recover(data: RecoverAccountData) -> anyhow::Result<JwtTokens> {
+    let tokens = recover_account(data).await?;
+    Ok(tokens)
+}
+
+/// Issues a new recovery key for the unlocked vault, the previous one stops
+/// working.
+#[tokio::main(flavor = "current_thread")]
+pub async fn new_recovery_key(token: String) -> anyhow::Result<String> {
+    let user = get_user_id_from_token(token).await?;
+    let recovery_key = create_recovery_key(&user).await?;
+    Ok(recovery_key.into_exposed())
+}
+
+/// Issues a new recovery key split among trusted people, any threshold of
+/// the returned shares restore it with [`combine_recovery_key_shares`].
+#[tokio::main(flavor = "current_thread")]
+pub async fn new_recovery_key_shares(
+    settings: ShareSettings,
+    token: String,
+) -> anyhow::Result<Vec<String>> {
+    let user = get_user_id_from_token(token).await?;
+    let shares = create_recovery_shares(&user, &settings).await?;
+    Ok(shares
+        .into_iter()
+        .map(|share| share.into_exposed())
+        .collect())
+}
+
+/// Restores a recovery key from the shares printed by
+/// [`new_recovery_key_shares`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn combine_recovery_key_shares(shares: Vec<String>) -> anyhow::Result<String> {
+    let shares = parse_shares(shares)?;
+    let recovery_key = recovery_key_from_shares(&shares)?;
+    Ok(recovery_key.into_exposed())
+}
+
+/// Generates a password, pronounceable password or passphrase and reports
+/// its entropy.
+#[flutter_rust_bridge::frb(sync)]
+pub fn generate_password(options: GeneratorOptions) -> anyhow::Result<GeneratedPassword> {
+    let generated = generate(&options)?;
+    Ok(generated)
+}
+
+/// Encodes `payload` as a QR code for the app to draw itself.
+#[flutter_rust_bridge::frb(sync)]
+pub fn qr_code_matrix(payload: String, options: QrOptions) -> anyhow::Result<QrMatrix> {
+    let matrix = qr_matrix(payload.as_bytes(), &options)?;
+    Ok(matrix)
+}
+
+/// Renders `payload` as a QR code SVG image, the same the server serves.
+#[flutter_rust_bridge::frb(sync)]
+pub fn qr_code_svg(payload: String, options: QrOptions) -> anyhow::Result<String> {
+    let svg = qr_svg(payload.as_bytes(), &options)?;
+    Ok(svg)
+}
+
+/// Renders `payload` as a QR code PNG image.
+#[flutter_rust_bridge::frb(sync)]
+pub fn qr_code_png(payload: String, options: QrOptions) -> anyhow::Result<Vec<u8>> {
+    let png = qr_png(payload.as_bytes(), &options)?;
+    Ok(png)
+}
+
+/// Sets the policy account passwords are checked against on registration
+/// and password changes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_account_password_policy(policy: PasswordPolicy) -> anyhow::Result<()> {
+    set_password_policy(policy)?;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_account_password_policy() -> PasswordPolicy {
+    password_policy()
+}
+
+/// Sets the Argon2id parameters account passwords are hashed with. Existing
+/// hashes are upgraded as their users log in.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_account_password_hash_settings(settings: KdfSettings) -> anyhow::Result<()> {
+    set_password_hash_settings(settings)?;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_account_password_hash_settings() -> KdfSettings {
+    password_hash_settings()
+}
+
+/// Every rule of the account password policy `password` fails, for the
+/// registration and change password forms.
+#[flutter_rust_bridge::frb(sync)]
+pub fn check_account_password(password: String, user_inputs: Vec<String>) -> Vec<PasswordRule> {
+    let user_inputs = user_inputs.iter().map(String::as_str).collect::<Vec<_>>();
+    password_violations(&password_policy(), &password, &user_inputs)
+}
+
+/// Rates how hard `password` is to guess, for strength meters in the item
+/// editors. `user_inputs` are words the user is likely to use, such as their
+/// name and email.
+#[flutter_rust_bridge::frb(sync)]
+pub fn estimate_password_strength(password: String, user_inputs: Vec<String>) -> PasswordStrength {
+    let user_inputs = user_inputs.iter().map(String::as_str).collect::<Vec<_>>();
+    estimate_strength(&password, &user_inputs)
+}
+
+/// Splits any secret text into printable shares, any `threshold` of which
+/// recover it with [`combine_secret_shares`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn split_secret_into_shares(
+    secret: String,
+    settings: ShareSettings,
+) -> anyhow::Result<Vec<String>> {
+    let secret = SecretString::from(secret);
+    let shares = split_secret(
+        secret.expose_secret().as_bytes(),
+        settings.threshold,
+        settings.shares,
+    )?;
+    Ok(shares
+        .iter()
+        .map(|share| share.to_text().into_exposed())
+        .collect())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn combine_secret_shares(shares: Vec<String>) -> anyhow::Result<String> {
+    let shares = parse_shares(shares)?;
+    let secret = combine_shares(&shares)?;
+    let secret = String::from_utf8(secret.expose_secret().to_vec())?;
+    Ok(secret)
+}
+
+fn parse_shares(shares: Vec<String>) -> anyhow::Result<Vec<Share>> {
+    let shares = shares
+        .into_iter()
+        .map(SecretString::from)
+        .map(|share| Share::from_text(share.expose_secret()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(shares)
+}
+
+/// Moves every vault item to a freshly generated data key, poll
+/// [`get_rekey_vault_progress`] while it runs. A new recovery key replaces
+/// the previous one when the user had one.
+#[tokio::main(flavor = "current_thread")]
+pub async fn rekey(master_password: String, token: String) -> anyhow::Result<RekeyResult> {
+    let user = get_user_id_from_token(token).await?;
+    let result = rekey_vault(&user, &SecretString::from(master_password), |_| {}).await?;
+    Ok(result)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_rekey_vault_progress(token: String) -> anyhow::Result<Option<RekeyProgress>> {
+    let user = get_user_id_from_token(token).await?;
+    Ok(get_rekey_progress(&user))
+}
+
+/// Builds a breach index from a downloaded Pwned Passwords dataset, a file
+/// of `HASH:COUNT` lines or a directory of range files. Returns the number
+/// of records.
+pub fn build_password_breach_index(
+    dataset_path: String,
+    index_path: String,
+) -> anyhow::Result<u64> {
+    let records = build_breach_index(Path::new(&dataset_path), Path::new(&index_path))?;
+    Ok(records)
+}
+
+/// Loads the breach index used by [`get_password_breach_count`] and
+/// [`check_breached_passwords`].
+pub fn load_password_breach_index(index_path: String) -> anyhow::Result<u64> {
+    let records = load_breach_index(Path::new(&index_path))?;
+    Ok(records)
+}
+
+/// Times `password` appears in the breach index, 0 if it does not.
+pub fn get_password_breach_count(password: String) -> anyhow::Result<u32> {
+    let count = breach_count(&password)?;
+    Ok(count)
+}
+
+/// Checks every login password against the breach index and stores the
+/// result on the logins.
+#[tokio::main(flavor = "current_thread")]
+pub async fn check_breached_passwords(token: String) -> anyhow::Result<BreachReport> {
+    let user = get_user_id_from_token(token).await?;
+    let report = check_breached_logins(&user).await?;
+    Ok(report)
+}
+
+/// Reports reused, weak, stale and empty login passwords. Passwords older
+/// than `stale_days` days are stale, 365 by default.
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_vault_health(
+    stale_days: Option<u32>,
+    token: String,
+) -> anyhow::Result<VaultHealth> {
+    let user = get_user_id_from_token(token).await?;
+    let health = vault_health(&user, stale_days.unwrap_or(DEFAULT_STALE_DAYS)).await?;
+    Ok(health)
+}
+
+/// Sets the idle period after which the vault locks itself, 0 disables
+/// auto-lock.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_auto_lock_seconds(seconds: u64) {
+    set_auto_lock_timeout(match seconds {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    });
+}
+
 #[tokio::main(flavor = "current_thread")]
 pub async fn get_financial_card(id: String, token: String) -> anyhow::Result<FinancialCard> {
     let user = get_user_id_from_token(token).await?;
@@ -192,6 +601,39 @@ pub async fn list_login(query: String, token: String) -> anyhow::Result<Vec<Logi
     Ok(logins)
 }
 
+/// Previous passwords of a login, most recent first.
+#[tokio::main(flavor = "current_thread")]
+pub async fn list_login_password_history(
+    id: String,
+    token: String,
+) -> anyhow::Result<Vec<PasswordHistory>> {
+    let user = get_user_id_from_token(token).await?;
+    let history = get_password_history(id, user).await?;
+    Ok(history)
+}
+
+/// Current code of the authenticator stored with a login, and the seconds
+/// until it changes.
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_login_totp_code(id: String, token: String) -> anyhow::Result<OtpCode> {
+    let user = get_user_id_from_token(token).await?;
+    let code = get_totp_code(id, user).await?;
+    Ok(code)
+}
+
+/// Puts a previous password back on a login, the current one goes to the
+/// history.
+#[tokio::main(flavor = "current_thread")]
+pub async fn restore_login_password(
+    id: String,
+    history_id: String,
+    token: String,
+) -> anyhow::Result<Login> {
+    let user = get_user_id_from_token(token).await?;
+    let login = restore_password(id, history_id, user).await?;
+    Ok(login)
+}
+
 #[tokio::main(flavor = "current_thread")]
 pub async fn get_note(id: String, token: String) -> anyhow::Result<Note> {
     let user = get_user_id_from_token(token).await?;
@@ -228,6 +670,72 @@ pub async fn list_note(query: String, token: String) -> anyhow::Result<Vec<Note>
     Ok(notes)
 }
 
+#[tokio::main(flavor = "current_thread")]
+pub async fn get_passkey(id: String, token: String) -> anyhow::Result<Passkey> {
+    let user = get_user_id_from_token(token).await?;
+    let passkey = fetch_passkey(id, user).await?;
+    Ok(passkey)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn post_passkey(data: Passkey, token: String) -> anyhow::Result<Passkey> {
+    let user = get_user_id_from_token(token).await?;
+    let passkey = add_passkey(data, user).await?;
+    Ok(passkey)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn put_passkey(id: String, data: Passkey, token: String) -> anyhow::Result<Passkey> {
+    let user = get_user_id_from_token(token).await?;
+    let passkey = update_passkey(id, data, user).await?;
+    Ok(passkey)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn delete_passkey(id: String, token: String) -> anyhow::Result<Passkey> {
+    let user = get_user_id_from_token(token).await?;
+    let passkey = remove_passkey(id, user).await?;
+    Ok(passkey)
+}
+
+#[tokio::main(flavor = "current_thread")]
+pub async fn list_passkey(query: String, token: String) -> anyhow::Result<Vec<Passkey>> {
+    let user = get_user_id_from_token(token).await?;
+    let passkeys = get_all_passkeys(query, user).await?;
+    Ok(passkeys)
+}
+
+/// Passkeys for a relying party, to offer when it asks to sign in.
+#[tokio::main(flavor = "current_thread")]
+pub async fn list_passkeys_for_rp(rp_id: String, token: String) -> anyhow::Result<Vec<Passkey>> {
+    let user = get_user_id_from_token(token).await?;
+    let passkeys = find_passkeys(rp_id, user).await?;
+    Ok(passkeys)
+}
+
+/// Registers a new passkey with a relying party and keeps it in the vault.
+#[tokio::main(flavor = "current_thread")]
+pub async fn create_passkey(
+    request: PasskeyCreation,
+    token: String,
+) -> anyhow::Result<PasskeyRegistration> {
+    let user = get_user_id_from_token(token).await?;
+    let registration = crate::common::passkeys::create_passkey(request, user).await?;
+    Ok(registration)
+}
+
+/// Signs a relying party's challenge with a stored passkey.
+#[tokio::main(flavor = "current_thread")]
+pub async fn sign_passkey_assertion(
+    id: String,
+    request: PasskeyAssertionRequest,
+    token: String,
+) -> anyhow::Result<PasskeyAssertion> {
+    let user = get_user_id_from_token(token).await?;
+    let assertion = crate::common::passkeys::sign_passkey_assertion(id, request, user).await?;
+    Ok(assertion)
+}
+
 #[tokio::main(flavor = "current_thread")]
 pub async fn get_tag(id: String, token: String) -> anyhow::Result<Tag> {
     let user = get_user_id_from_token(token).await?;
@@ -298,6 +806,14 @@ pub async fn toggle_favorite(id: String, item_type: String) -> anyhow::Result<Op
                 .unwrap()
                 .is_favorite
         }
+        "passkey" => {
+            let mut passkey = Passkey::get(id).await.unwrap();
+            passkey.is_favorite = Some(!passkey.is_favorite.unwrap_or(false));
+            Passkey::update(passkey.id.clone().unwrap(), passkey)
+                .await
+                .unwrap()
+                .is_favorite
+        }
         _ => return Err(anyhow::anyhow!("Invalid item type")),
     };
     Ok(result)
@@ -1,12 +1,23 @@
-use crate::models::{FinancialCard, IdentityCard, Login, Note, Tag};
+use crate::models::{
+    FinancialCard, IdentityCard, Login, Note, Passkey, PasswordHistory, Tag, VaultKey,
+};
 
 use serde_json::json;
 
+/// Exports every table as JSON.
+///
+/// Sealed columns stay sealed, the wrapped data keys are exported alongside
+/// so a restored vault unlocks with the master password it had when the
+/// backup was taken.
 pub async fn export_all_data_to_json() -> anyhow::Result<String> {
     let mut all_data = json!({});
 
     let query = sql::HttpQuery::default();
 
+    // Export VaultKeys
+    let vault_keys: Vec<VaultKey> = VaultKey::get_list(query.clone()).await?;
+    all_data["vault_keys"] = serde_json::to_value(vault_keys)?;
+
     // Export FinancialCards
     let financial_cards: Vec<FinancialCard> = FinancialCard::get_list(query.clone()).await?;
     all_data["financial_cards"] = serde_json::to_value(financial_cards)?;
@@ -19,6 +30,14 @@ pub async fn export_all_data_to_json() -> anyhow::Result<String> {
     let logins: Vec<Login> = Login::get_list(query.clone()).await?;
     all_data["logins"] = serde_json::to_value(logins)?;
 
+    // Export PasswordHistory
+    let password_history: Vec<PasswordHistory> = PasswordHistory::get_list(query.clone()).await?;
+    all_data["password_history"] = serde_json::to_value(password_history)?;
+
+    // Export Passkeys
+    let passkeys: Vec<Passkey> = Passkey::get_list(query.clone()).await?;
+    all_data["passkeys"] = serde_json::to_value(passkeys)?;
+
     // Export Notes
     let notes: Vec<Note> = Note::get_list(query.clone()).await?;
     all_data["notes"] = serde_json::to_value(notes)?;
@@ -33,6 +52,13 @@ pub async fn export_all_data_to_json() -> anyhow::Result<String> {
 pub async fn restore_data_from_json(data: String) -> anyhow::Result<()> {
     let json_data: serde_json::Value = serde_json::from_str(&data)?;
 
+    if let Some(vault_keys) = json_data.get("vault_keys").and_then(|v| v.as_array()) {
+        for record in vault_keys {
+            let record: VaultKey = serde_json::from_value(record.clone())?;
+            restore_vault_keys(record).await?;
+        }
+    }
+
     if let Some(tags) = json_data.get("tags").and_then(|v| v.as_array()) {
         for record in tags {
             let record: Tag = serde_json::from_value(record.clone())?;
@@ -61,6 +87,20 @@ pub async fn restore_data_from_json(data: String) -> anyhow::Result<()> {
         }
     }
 
+    if let Some(password_history) = json_data.get("password_history").and_then(|v| v.as_array()) {
+        for record in password_history {
+            let record: PasswordHistory = serde_json::from_value(record.clone())?;
+            restore_password_history(record).await?;
+        }
+    }
+
+    if let Some(passkeys) = json_data.get("passkeys").and_then(|v| v.as_array()) {
+        for record in passkeys {
+            let record: Passkey = serde_json::from_value(record.clone())?;
+            restore_passkeys(record).await?;
+        }
+    }
+
     if let Some(notes) = json_data.get("notes").and_then(|v| v.as_array()) {
         for record in notes {
             let record: Note = serde_json::from_value(record.clone())?;
@@ -71,6 +111,20 @@ pub async fn restore_data_from_json(data: String) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn restore_vault_keys(vault_key: VaultKey) -> anyhow::Result<()> {
+    if let Ok(existing_record) = VaultKey::get(vault_key.id.clone().unwrap()).await {
+        if vault_key.created_at >= existing_record.created_at
+            || vault_key.updated_at >= existing_record.updated_at
+        {
+            VaultKey::update(vault_key.id.clone().unwrap(), vault_key.clone()).await?;
+        }
+    } else {
+        VaultKey::insert(vault_key.clone()).await?;
+    }
+
+    Ok(())
+}
+
 async fn restore_logins(login: Login) -> anyhow::Result<()> {
     if let Some(existing_record) = Login::get(login.id.clone().unwrap()).await.ok() {
         if login.created_at >= existing_record.created_at
@@ -85,6 +139,20 @@ async fn restore_logins(login: Login) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn restore_password_history(entry: PasswordHistory) -> anyhow::Result<()> {
+    if let Ok(existing_record) = PasswordHistory::get(entry.id.clone().unwrap()).await {
+        if entry.created_at >= existing_record.created_at
+            || entry.updated_at >= existing_record.updated_at
+        {
+            PasswordHistory::update(entry.id.clone().unwrap(), entry.clone()).await?;
+        }
+    } else {
+        PasswordHistory::insert(entry.clone()).await?;
+    }
+
+    Ok(())
+}
+
 async fn restore_financial_cards(financial_card: FinancialCard) -> anyhow::Result<()> {
     if let Some(existing_record) = FinancialCard::get(financial_card.id.clone().unwrap())
         .await
@@ -120,6 +188,23 @@ async fn restore_identity_cards(identity_card: IdentityCard) -> anyhow::Result<(
     Ok(())
 }
 
+async fn restore_passkeys(mut passkey: Passkey) -> anyhow::Result<()> {
+    if let Some(existing_record) = Passkey::get(passkey.id.clone().unwrap()).await.ok() {
+        if passkey.created_at >= existing_record.created_at
+            || passkey.updated_at >= existing_record.updated_at
+        {
+            // Signing does not touch updated_at, an older backup must not
+            // wind the counter back
+            passkey.sign_count = passkey.sign_count.max(existing_record.sign_count);
+            Passkey::update(passkey.id.clone().unwrap(), passkey.clone()).await?;
+        }
+    } else {
+        Passkey::insert(passkey.clone()).await?;
+    }
+
+    Ok(())
+}
+
 async fn restore_notes(note: Note) -> anyhow::Result<()> {
     if let Some(existing_record) = Note::get(note.id.clone().unwrap()).await.ok() {
         if note.created_at >= existing_record.created_at
@@ -152,10 +237,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_export_and_restore_json() {
-        let temp_db = tempfile::NamedTempFile::new().unwrap();
-        let db_path = temp_db.path().to_str().unwrap();
-
-        sql::migrate_sqlite(db_path).await.unwrap();
+        crate::common::test_utils::init_test_db().await;
         let json_data = crate::common::backup_and_restore::export_all_data_to_json()
             .await
             .unwrap();
@@ -163,7 +245,24 @@ mod tests {
             .await
             .unwrap();
         println!("Data restored successfully");
+    }
 
-        // The temporary file will be automatically deleted when it goes out of scope
+    #[tokio::test]
+    async fn test_export_includes_vault_keys() {
+        crate::common::test_utils::init_test_db().await;
+        let user_id = sql::get_ulid();
+        crate::common::vault::setup_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+
+        let json_data = crate::common::backup_and_restore::export_all_data_to_json()
+            .await
+            .unwrap();
+        let json_data: serde_json::Value = serde_json::from_str(&json_data).unwrap();
+        let vault_keys = json_data["vault_keys"].as_array().unwrap();
+        assert!(vault_keys
+            .iter()
+            .any(|vault_key| vault_key["created_by"] == user_id.as_str()));
+        assert!(json_data["password_history"].is_array());
     }
 }
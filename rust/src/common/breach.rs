@@ -0,0 +1,523 @@
+use std::{
+    cmp::Ordering,
+    fs::{self, File},
+    io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use rusqlite::params;
+use sha1::{Digest, Sha1};
+use sql::SqlError;
+
+use crate::models::{BreachReport, BreachedLogin, Login};
+
+use super::{
+    errors::AppError,
+    time::now,
+    vault::{get_data_key, owned_by, SealedItem},
+};
+
+// Index layout, integers are little endian
+//
+// [magic][record count u64][fan-out][records]
+//
+// The fan-out holds, for every value of the first two bytes of a SHA-1 hash,
+// the number of records up to and including it. Records are sorted by hash
+// and hold the next ten bytes of the hash and the breach count (u32). The 96
+// bits of hash stored keep false matches out of a dataset of billions.
+const MAGIC: &[u8; 8] = b"HIBPIDX1";
+const BUCKETS: usize = 1 << 16;
+const BUCKET_BYTES: usize = 2;
+const KEY_BYTES: usize = 10;
+const RECORD_LENGTH: usize = KEY_BYTES + 4;
+const HEADER_LENGTH: usize = MAGIC.len() + 8 + BUCKETS * 8;
+
+const HASH_LENGTH: usize = 20;
+// Range files of the dataset are named after the first five hex digits
+const PREFIX_HEX_LENGTH: usize = 5;
+
+// Index used by every check, see `load_breach_index`
+static BREACH_INDEX: Lazy<RwLock<Option<Arc<BreachIndex>>>> = Lazy::new(|| RwLock::new(None));
+
+type Hash = [u8; HASH_LENGTH];
+
+fn index_error(error: impl std::fmt::Display) -> AppError {
+    AppError::BreachIndexError(error.to_string())
+}
+
+fn sha1(password: &str) -> Hash {
+    let mut hash = [0u8; HASH_LENGTH];
+    hash.copy_from_slice(&Sha1::digest(password.as_bytes()));
+    hash
+}
+
+/// Builds a breach index from a downloaded Pwned Passwords dataset.
+///
+/// `dataset` is either one file of `HASH:COUNT` lines or a directory of
+/// range files, named after their five hex digit prefix and holding
+/// `SUFFIX:COUNT` lines. Hashes are SHA-1 and must be in ascending order, as
+/// the Pwned Passwords downloader writes them. The index is only moved to
+/// `index` once complete. Returns the number of records.
+pub fn build_breach_index(dataset: &Path, index: &Path) -> Result<u64, AppError> {
+    let partial = index.with_extension("partial");
+    let result = File::create(&partial)
+        .map_err(index_error)
+        .and_then(|file| write_index(dataset, BufWriter::new(file)));
+    match result {
+        Ok(records) => {
+            fs::rename(&partial, index).map_err(index_error)?;
+            Ok(records)
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+fn write_index<W: Write + Seek>(dataset: &Path, out: W) -> Result<u64, AppError> {
+    let mut writer = IndexWriter::new(out)?;
+    if dataset.is_dir() {
+        let mut ranges = fs::read_dir(dataset)
+            .map_err(index_error)?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let prefix = path.file_stem()?.to_str()?.to_ascii_uppercase();
+                let is_range = prefix.len() == PREFIX_HEX_LENGTH
+                    && prefix.chars().all(|c| c.is_ascii_hexdigit());
+                is_range.then_some((prefix, path))
+            })
+            .collect::<Vec<_>>();
+        ranges.sort();
+        for (prefix, path) in ranges {
+            let file = File::open(&path).map_err(index_error)?;
+            add_lines(&mut writer, BufReader::new(file), &prefix)?;
+        }
+    } else {
+        let file = File::open(dataset).map_err(index_error)?;
+        add_lines(&mut writer, BufReader::new(file), "")?;
+    }
+    writer.finish()
+}
+
+fn add_lines<W: Write + Seek>(
+    writer: &mut IndexWriter<W>,
+    reader: impl BufRead,
+    prefix: &str,
+) -> Result<(), AppError> {
+    for (number, line) in reader.lines().enumerate() {
+        let line = line.map_err(index_error)?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (hash, count) = parse_line(line, prefix).ok_or_else(|| {
+            index_error(format!("invalid line {} in range {:?}", number + 1, prefix))
+        })?;
+        writer.add(&hash, count)?;
+    }
+    Ok(())
+}
+
+fn parse_line(line: &str, prefix: &str) -> Option<(Hash, u32)> {
+    let (suffix, count) = line.split_once(':')?;
+    let mut hash = [0u8; HASH_LENGTH];
+    hex::decode_to_slice(format!("{}{}", prefix, suffix), &mut hash).ok()?;
+    let count = count.trim().parse::<u64>().ok()?;
+    Some((hash, count.min(u32::MAX as u64) as u32))
+}
+
+struct IndexWriter<W: Write + Seek> {
+    out: W,
+    // Records per bucket, summed up in `finish`
+    fanout: Vec<u64>,
+    last: Option<Hash>,
+    // Hashes that only differ past the bytes kept share a record
+    pending: Option<(Hash, u32)>,
+}
+
+impl<W: Write + Seek> IndexWriter<W> {
+    fn new(mut out: W) -> Result<Self, AppError> {
+        // The header is written once the counts are known
+        out.write_all(&vec![0u8; HEADER_LENGTH])
+            .map_err(index_error)?;
+        Ok(IndexWriter {
+            out,
+            fanout: vec![0; BUCKETS],
+            last: None,
+            pending: None,
+        })
+    }
+
+    fn add(&mut self, hash: &Hash, count: u32) -> Result<(), AppError> {
+        if self.last.is_some_and(|last| last >= *hash) {
+            return Err(index_error(format!(
+                "dataset is not sorted by hash at {}",
+                hex::encode_upper(hash)
+            )));
+        }
+        self.last = Some(*hash);
+        match &mut self.pending {
+            Some((pending, total))
+                if pending[..BUCKET_BYTES + KEY_BYTES] == hash[..BUCKET_BYTES + KEY_BYTES] =>
+            {
+                *total = total.saturating_add(count);
+                Ok(())
+            }
+            _ => {
+                self.flush()?;
+                self.pending = Some((*hash, count));
+                Ok(())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), AppError> {
+        if let Some((hash, count)) = self.pending.take() {
+            self.out
+                .write_all(&hash[BUCKET_BYTES..BUCKET_BYTES + KEY_BYTES])
+                .and_then(|_| self.out.write_all(&count.to_le_bytes()))
+                .map_err(index_error)?;
+            self.fanout[bucket(&hash)] += 1;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<u64, AppError> {
+        self.flush()?;
+        let mut header = Vec::with_capacity(HEADER_LENGTH);
+        header.extend_from_slice(MAGIC);
+        let mut records = 0;
+        let fanout = self
+            .fanout
+            .iter()
+            .map(|count| {
+                records += count;
+                records
+            })
+            .collect::<Vec<_>>();
+        header.extend_from_slice(&records.to_le_bytes());
+        for records in fanout {
+            header.extend_from_slice(&records.to_le_bytes());
+        }
+        self.out
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| self.out.write_all(&header))
+            .and_then(|_| self.out.flush())
+            .map_err(index_error)?;
+        Ok(records)
+    }
+}
+
+fn bucket(hash: &Hash) -> usize {
+    u16::from_be_bytes([hash[0], hash[1]]) as usize
+}
+
+/// An index built with [`build_breach_index`], searched on disk so only the
+/// fan-out table is held in memory.
+pub struct BreachIndex {
+    file: Mutex<File>,
+    fanout: Vec<u64>,
+}
+
+impl BreachIndex {
+    pub fn open(path: &Path) -> Result<Self, AppError> {
+        let mut file = File::open(path).map_err(index_error)?;
+        let mut header = vec![0u8; HEADER_LENGTH];
+        file.read_exact(&mut header)
+            .map_err(|_| index_error("not a breach index"))?;
+        if header[..MAGIC.len()] != MAGIC[..] {
+            return Err(index_error("not a breach index"));
+        }
+        let read_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+        let records = read_u64(&header[MAGIC.len()..MAGIC.len() + 8]);
+        let fanout = header[MAGIC.len() + 8..]
+            .chunks_exact(8)
+            .map(read_u64)
+            .collect::<Vec<_>>();
+
+        let length = file.metadata().map_err(index_error)?.len();
+        let expected = records
+            .checked_mul(RECORD_LENGTH as u64)
+            .and_then(|length| length.checked_add(HEADER_LENGTH as u64));
+        let sorted = fanout.windows(2).all(|pair| pair[0] <= pair[1]);
+        if !sorted || fanout.last() != Some(&records) || expected != Some(length) {
+            return Err(index_error("breach index is damaged"));
+        }
+        Ok(BreachIndex {
+            file: Mutex::new(file),
+            fanout,
+        })
+    }
+
+    pub fn records(&self) -> u64 {
+        self.fanout[BUCKETS - 1]
+    }
+
+    /// Times `password` appears in the dataset, 0 if it does not.
+    pub fn count(&self, password: &str) -> Result<u32, AppError> {
+        self.count_hash(&sha1(password))
+    }
+
+    fn count_hash(&self, hash: &Hash) -> Result<u32, AppError> {
+        let bucket = bucket(hash);
+        let mut low = match bucket {
+            0 => 0,
+            bucket => self.fanout[bucket - 1],
+        };
+        let mut high = self.fanout[bucket];
+        let key = &hash[BUCKET_BYTES..BUCKET_BYTES + KEY_BYTES];
+
+        let mut file = self.file.lock().unwrap();
+        let mut record = [0u8; RECORD_LENGTH];
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let offset = HEADER_LENGTH as u64 + middle * RECORD_LENGTH as u64;
+            file.seek(SeekFrom::Start(offset))
+                .and_then(|_| file.read_exact(&mut record))
+                .map_err(index_error)?;
+            match record[..KEY_BYTES].cmp(key) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => {
+                    return Ok(u32::from_le_bytes(record[KEY_BYTES..].try_into().unwrap()))
+                }
+            }
+        }
+        Ok(0)
+    }
+}
+
+/// Opens the index at `path` and uses it for every later check, replacing
+/// any index loaded before. Returns the number of records.
+pub fn load_breach_index(path: &Path) -> Result<u64, AppError> {
+    let index = BreachIndex::open(path)?;
+    let records = index.records();
+    *BREACH_INDEX.write().unwrap() = Some(Arc::new(index));
+    Ok(records)
+}
+
+fn loaded_index() -> Result<Arc<BreachIndex>, AppError> {
+    BREACH_INDEX
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or(AppError::BreachIndexNotLoaded)
+}
+
+/// Times `password` appears in the loaded breach index, 0 if it does not.
+pub fn breach_count(password: &str) -> Result<u32, AppError> {
+    loaded_index()?.count(password)
+}
+
+/// Checks the password of every login of a user against the loaded breach
+/// index, without any network calls.
+///
+/// The count and time of the check are stored on each login, so the UI can
+/// flag compromised credentials without checking again.
+pub async fn check_breached_logins(user_id: &str) -> Result<BreachReport, AppError> {
+    let index = loaded_index()?;
+    let key = get_data_key(user_id)?;
+    let mut logins = Login::get_list(owned_by(user_id)).await?;
+    let checked_at = now() as i64;
+
+    let mut report = BreachReport {
+        checked_at,
+        ..Default::default()
+    };
+    let mut counts = Vec::new();
+    for login in logins.iter_mut() {
+        login.open(&key)?;
+        let id = login.id.clone().unwrap_or_default();
+        let count = match &login.password {
+            Some(password) if !password.expose_secret().is_empty() => {
+                index.count(password.expose_secret())?
+            }
+            _ => continue,
+        };
+        report.checked += 1;
+        if count > 0 {
+            report.breached.push(BreachedLogin {
+                id: id.clone(),
+                count,
+            });
+        }
+        counts.push((id, count));
+    }
+    save_breach_counts(user_id, &counts, checked_at)?;
+
+    report
+        .breached
+        .sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.id.cmp(&b.id)));
+    report.breached_count = report.breached.len() as u32;
+    Ok(report)
+}
+
+fn save_breach_counts(
+    user_id: &str,
+    counts: &[(String, u32)],
+    checked_at: i64,
+) -> Result<(), AppError> {
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    for (id, count) in counts {
+        tx.execute(
+            "UPDATE logins SET breach_count = ?1, breach_checked_at = ?2 \
+             WHERE id = ?3 AND created_by = ?4",
+            params![*count as i64, checked_at, id, user_id],
+        )
+        .map_err(SqlError::from)?;
+    }
+    tx.commit().map_err(SqlError::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::vault::setup_vault;
+
+    const BREACHED: [(&str, u32); 4] = [
+        ("password", 9_545_824),
+        ("hunter2", 50_012),
+        ("correct horse battery staple", 384),
+        ("Tr0ub4dor&3", 2),
+    ];
+
+    // Dataset lines in ascending hash order
+    fn dataset_lines() -> Vec<String> {
+        let mut lines = BREACHED
+            .iter()
+            .map(|(password, count)| format!("{}:{}", hex::encode_upper(sha1(password)), count))
+            .collect::<Vec<_>>();
+        lines.sort();
+        lines
+    }
+
+    fn build(dir: &Path) -> BreachIndex {
+        let dataset = dir.join("pwnedpasswords.txt");
+        fs::write(&dataset, dataset_text()).unwrap();
+        let index = dir.join("breach.idx");
+        assert_eq!(build_breach_index(&dataset, &index).unwrap(), 4);
+        BreachIndex::open(&index).unwrap()
+    }
+
+    fn dataset_text() -> String {
+        dataset_lines().join("\r\n") + "\r\n"
+    }
+
+    #[test]
+    fn test_build_and_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let index = build(dir.path());
+
+        assert_eq!(index.records(), 4);
+        for (password, count) in BREACHED {
+            assert_eq!(index.count(password).unwrap(), count);
+        }
+        assert_eq!(index.count("Password").unwrap(), 0);
+        assert_eq!(index.count("").unwrap(), 0);
+        assert!(!dir.path().join("breach.partial").exists());
+    }
+
+    #[test]
+    fn test_build_from_ranges() {
+        let dir = tempfile::tempdir().unwrap();
+        let ranges = dir.path().join("ranges");
+        fs::create_dir(&ranges).unwrap();
+        for line in dataset_lines() {
+            let (prefix, suffix) = line.split_at(PREFIX_HEX_LENGTH);
+            let path = ranges.join(format!("{}.txt", prefix.to_lowercase()));
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .unwrap();
+            writeln!(file, "{}", suffix).unwrap();
+        }
+        fs::write(ranges.join("README.md"), "not a range").unwrap();
+
+        let path = dir.path().join("breach.idx");
+        assert_eq!(build_breach_index(&ranges, &path).unwrap(), 4);
+        let index = BreachIndex::open(&path).unwrap();
+        assert_eq!(index.count("hunter2").unwrap(), 50_012);
+    }
+
+    #[test]
+    fn test_invalid_datasets() {
+        let dir = tempfile::tempdir().unwrap();
+        let dataset = dir.path().join("pwnedpasswords.txt");
+        let index = dir.path().join("breach.idx");
+
+        let mut unsorted = dataset_lines();
+        unsorted.reverse();
+        fs::write(&dataset, unsorted.join("\n")).unwrap();
+        assert!(matches!(
+            build_breach_index(&dataset, &index),
+            Err(AppError::BreachIndexError(_))
+        ));
+        assert!(!index.exists());
+        assert!(!dir.path().join("breach.partial").exists());
+
+        fs::write(&dataset, "not a hash:12\n").unwrap();
+        assert!(build_breach_index(&dataset, &index).is_err());
+
+        fs::write(&index, "HIBPIDX1 but cut short").unwrap();
+        assert!(BreachIndex::open(&index).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_breached_logins() {
+        crate::common::test_utils::init_test_db().await;
+        let dir = tempfile::tempdir().unwrap();
+        build(dir.path());
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let key = get_data_key(&user_id).unwrap();
+        let mut ids = Vec::new();
+        for password in [Some("hunter2"), Some("password"), Some("unbreached"), None] {
+            let mut login = Login {
+                id: Some(sql::get_ulid()),
+                created_at: Some(now() as i64),
+                created_by: Some(user_id.clone()),
+                name: "Example".to_string(),
+                username: "user".to_string(),
+                password: password.map(Into::into),
+                is_favorite: Some(false),
+                ..Default::default()
+            };
+            login.seal(&key).unwrap();
+            ids.push(Login::insert(login).await.unwrap().id.unwrap());
+        }
+
+        load_breach_index(&dir.path().join("breach.idx")).unwrap();
+        assert_eq!(breach_count("password").unwrap(), 9_545_824);
+        let report = check_breached_logins(&user_id).await.unwrap();
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.breached_count, 2);
+        assert_eq!(
+            report.breached,
+            vec![
+                BreachedLogin {
+                    id: ids[1].clone(),
+                    count: 9_545_824
+                },
+                BreachedLogin {
+                    id: ids[0].clone(),
+                    count: 50_012
+                },
+            ]
+        );
+        let stored = Login::get(ids[0].clone()).await.unwrap();
+        assert_eq!(stored.breach_count, Some(50_012));
+        assert_eq!(stored.breach_checked_at, Some(report.checked_at));
+        let stored = Login::get(ids[2].clone()).await.unwrap();
+        assert_eq!(stored.breach_count, Some(0));
+        let stored = Login::get(ids[3].clone()).await.unwrap();
+        assert_eq!(stored.breach_count, None);
+    }
+}
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::models::PasswordRule;
+
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("Library error: {0}")]
@@ -18,12 +20,18 @@ pub enum AppError {
     JWTKeyError(#[from] jsonwebtoken::errors::Error),
     #[error("JWT token expired")]
     JWTTokenExpired,
+    #[error("Refresh token invalid or revoked")]
+    RefreshTokenInvalid,
+    #[error("Refresh token reused, the session has been revoked")]
+    RefreshTokenReused,
     #[error("Username too short")]
     UsernameTooShort,
     #[error("Password too short")]
     PasswordTooShort,
     #[error("Password too weak")]
     PasswordTooWeak,
+    #[error("Password {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    PasswordPolicyViolation(Vec<PasswordRule>),
     #[error("Invalid email")]
     InvalidEmail,
     #[error("Password too long")]
@@ -54,12 +62,32 @@ pub enum AppError {
     Unauthorized,
     #[error("TOTP secret parse error")]
     TOTPSecretParseError,
+    #[error("Invalid otpauth URI: {0}")]
+    InvalidOtpAuthUri(String),
+    #[error("Invalid passkey: {0}")]
+    InvalidPasskey(String),
     #[error("TOTP already enabled")]
     TOTPAlreadyEnabled,
+    #[error("Two-factor challenge invalid or expired")]
+    TwoFactorChallengeInvalid,
+    #[error("Two-factor code required")]
+    TwoFactorCodeRequired,
+    #[error("Too many wrong two-factor codes, try again later")]
+    TwoFactorLocked,
     #[error("Internal server error")]
     InternalServerError,
-    #[error("QR code generation error")]
-    QRCodeGenerationError,
+    #[error("QR code generation error: {0}")]
+    QRCodeGenerationError(String),
+    #[error("Vault is locked")]
+    VaultLocked,
+    #[error("Invalid recovery key")]
+    InvalidRecoveryKey,
+    #[error("Breach index not loaded")]
+    BreachIndexNotLoaded,
+    #[error("Breach index error: {0}")]
+    BreachIndexError(String),
+    #[error("Vault encryption error: {0}")]
+    VaultEncryptionError(#[from] security::EncryptionError),
 }
 
 impl From<argon2::password_hash::Error> for AppError {
@@ -68,3 +96,9 @@ impl From<argon2::password_hash::Error> for AppError {
         AppError::PasswordHashError(err.to_string()) // Adjust this line based on your AppError variants
     }
 }
+
+impl From<argon2::Error> for AppError {
+    fn from(err: argon2::Error) -> Self {
+        AppError::PasswordHashError(err.to_string())
+    }
+}
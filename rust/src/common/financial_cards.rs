@@ -2,13 +2,18 @@ use sql::{Filter, Glue, HttpQuery};
 
 use crate::models::FinancialCard;
 
-use super::errors::AppError;
+use super::{
+    errors::AppError,
+    vault::{get_data_key, SealedItem},
+    vault_session::ensure_unlocked,
+};
 
 pub async fn fetch_financial_card(id: String, user: String) -> anyhow::Result<FinancialCard> {
-    let financial_card = FinancialCard::get(id).await.unwrap();
+    let mut financial_card = FinancialCard::get(id).await.unwrap();
     if user != financial_card.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
     }
+    financial_card.open(&get_data_key(&user)?)?;
     Ok(financial_card)
 }
 
@@ -19,7 +24,12 @@ pub async fn add_financial_card(
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
     data.created_by = Some(user.clone());
     data.created_at = Some(chrono::Utc::now().timestamp());
-    let financial_card = FinancialCard::insert(data).await.unwrap();
+    // The record id is part of the associated data, so it is fixed before sealing
+    data.id = Some(data.id.unwrap_or_else(sql::get_ulid));
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut financial_card = FinancialCard::insert(data).await.unwrap();
+    financial_card.open(&key)?;
     Ok(financial_card)
 }
 
@@ -35,11 +45,17 @@ pub async fn update_financial_card(
     }
     data.updated_by = Some(user.clone());
     data.updated_at = Some(chrono::Utc::now().timestamp());
-    let financial_card = FinancialCard::update(id, data).await.unwrap();
+    data.id = Some(id.clone());
+    data.created_by = Some(user.clone());
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut financial_card = FinancialCard::update(id, data).await.unwrap();
+    financial_card.open(&key)?;
     Ok(financial_card)
 }
 
 pub async fn remove_financial_card(id: String, user: String) -> anyhow::Result<FinancialCard> {
+    ensure_unlocked(&user)?;
     let financial_card = FinancialCard::get(id.clone()).await.unwrap();
     if user != financial_card.created_by.unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -52,6 +68,7 @@ pub async fn get_all_financial_cards(
     query: String,
     user: String,
 ) -> anyhow::Result<Vec<FinancialCard>> {
+    let key = get_data_key(&user)?;
     let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
     query = if query.filters.is_none() {
         query.filters = Some(vec![Filter::equal("created_by", user, None)]);
@@ -64,6 +81,9 @@ pub async fn get_all_financial_cards(
             .push(Filter::equal("created_by", user, Some(Glue::And)));
         query
     };
-    let financial_cards = FinancialCard::get_list(query).await.unwrap();
+    let mut financial_cards = FinancialCard::get_list(query).await.unwrap();
+    for financial_card in financial_cards.iter_mut() {
+        financial_card.open(&key)?;
+    }
     Ok(financial_cards)
 }
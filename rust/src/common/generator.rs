@@ -0,0 +1,393 @@
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, seq::SliceRandom, Rng};
+use security::SecretString;
+
+use crate::models::{
+    Capitalization, GeneratedPassword, GeneratorOptions, PassphraseOptions, PasswordOptions,
+    PronounceableOptions,
+};
+
+use super::errors::AppError;
+
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const DIGITS: &str = "0123456789";
+// Same default as the Flutter generator dialog
+const SYMBOLS: &str = "!@#$%^&*()_+-=[]{}|;:,.<>?";
+// Characters that look alike in many fonts
+const AMBIGUOUS: &str = "0Oo1lI|`'\";:,.{}[]()<>/\\~";
+
+const CONSONANTS: &str = "bcdfghjkmnprstvz";
+const VOWELS: &str = "aeiu";
+
+const MAX_LENGTH: u32 = 1024;
+const MAX_WORDS: u32 = 64;
+
+// One word per line, the EFF large wordlist of 7776 words (five dice rolls)
+pub(crate) static WORDLIST: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("wordlist.txt").lines().collect());
+
+/// Generates a password with the given options and reports its entropy.
+pub fn generate(options: &GeneratorOptions) -> Result<GeneratedPassword, AppError> {
+    match options {
+        GeneratorOptions::Password(options) => generate_password(options),
+        GeneratorOptions::Pronounceable(options) => generate_pronounceable(options),
+        GeneratorOptions::Passphrase(options) => generate_passphrase(options),
+    }
+}
+
+fn class_pool(characters: &str, exclude_ambiguous: bool) -> Vec<char> {
+    let mut pool = Vec::new();
+    for c in characters.chars() {
+        let ambiguous = exclude_ambiguous && AMBIGUOUS.contains(c);
+        if ambiguous || pool.contains(&c) {
+            continue;
+        }
+        pool.push(c);
+    }
+    pool
+}
+
+fn pick(pool: &[char]) -> char {
+    pool[OsRng.gen_range(0..pool.len())]
+}
+
+/// Random characters from the enabled classes with a minimum per class.
+///
+/// The required characters are drawn from their own class, the rest from
+/// all enabled classes, then everything is shuffled.
+pub fn generate_password(options: &PasswordOptions) -> Result<GeneratedPassword, AppError> {
+    let symbols = options.symbol_set.as_deref().unwrap_or(SYMBOLS);
+    let classes = [
+        (options.uppercase, options.min_uppercase, UPPERCASE),
+        (options.lowercase, options.min_lowercase, LOWERCASE),
+        (options.digits, options.min_digits, DIGITS),
+        (options.symbols, options.min_symbols, symbols),
+    ]
+    .into_iter()
+    .filter(|(enabled, _, _)| *enabled)
+    .map(|(_, minimum, characters)| (minimum, class_pool(characters, options.exclude_ambiguous)))
+    .collect::<Vec<_>>();
+
+    if options.length == 0 || options.length > MAX_LENGTH {
+        return Err(AppError::InvalidPasswordConfig);
+    }
+    if classes.is_empty() || classes.iter().any(|(_, pool)| pool.is_empty()) {
+        return Err(AppError::InvalidPasswordConfig);
+    }
+    // The minimums come straight from the caller, so their sum may overflow
+    let required = classes
+        .iter()
+        .try_fold(0u32, |total, (minimum, _)| total.checked_add(*minimum))
+        .filter(|required| *required <= options.length)
+        .ok_or(AppError::InvalidPasswordConfig)?;
+
+    let mut all = Vec::new();
+    for (_, pool) in &classes {
+        for c in pool {
+            if !all.contains(c) {
+                all.push(*c);
+            }
+        }
+    }
+
+    let mut characters = Vec::with_capacity(options.length as usize);
+    let mut entropy_bits = 0.0;
+    for (minimum, pool) in &classes {
+        for _ in 0..*minimum {
+            characters.push(pick(pool));
+        }
+        entropy_bits += *minimum as f64 * (pool.len() as f64).log2();
+    }
+    let remaining = options.length - required;
+    for _ in 0..remaining {
+        characters.push(pick(&all));
+    }
+    entropy_bits += remaining as f64 * (all.len() as f64).log2();
+    characters.shuffle(&mut OsRng);
+
+    Ok(GeneratedPassword {
+        password: characters.into_iter().collect::<String>().into(),
+        entropy_bits,
+    })
+}
+
+/// Alternating consonants and vowels, optionally followed by digits.
+pub fn generate_pronounceable(
+    options: &PronounceableOptions,
+) -> Result<GeneratedPassword, AppError> {
+    let total = options
+        .length
+        .checked_add(options.digits)
+        .filter(|total| options.length > 0 && *total <= MAX_LENGTH)
+        .ok_or(AppError::InvalidPasswordConfig)?;
+    let consonants = CONSONANTS.chars().collect::<Vec<_>>();
+    let vowels = VOWELS.chars().collect::<Vec<_>>();
+    let digits = DIGITS.chars().collect::<Vec<_>>();
+
+    let mut password = String::with_capacity(total as usize);
+    let mut entropy_bits = 0.0;
+    for position in 0..options.length {
+        let pool = match position % 2 {
+            0 => &consonants,
+            _ => &vowels,
+        };
+        let c = pick(pool);
+        if position == 0 && options.capitalize {
+            password.push(c.to_ascii_uppercase());
+        } else {
+            password.push(c);
+        }
+        entropy_bits += (pool.len() as f64).log2();
+    }
+    for _ in 0..options.digits {
+        password.push(pick(&digits));
+    }
+    entropy_bits += options.digits as f64 * (digits.len() as f64).log2();
+
+    Ok(GeneratedPassword {
+        password: password.into(),
+        entropy_bits,
+    })
+}
+
+/// Diceware style passphrase of random words from the embedded wordlist.
+pub fn generate_passphrase(options: &PassphraseOptions) -> Result<GeneratedPassword, AppError> {
+    if options.words == 0 || options.words > MAX_WORDS {
+        return Err(AppError::InvalidPasswordConfig);
+    }
+
+    let mut words = Vec::with_capacity(options.words as usize);
+    let mut entropy_bits = options.words as f64 * (WORDLIST.len() as f64).log2();
+    for _ in 0..options.words {
+        let word = WORDLIST[OsRng.gen_range(0..WORDLIST.len())];
+        let word = match options.capitalization {
+            Capitalization::Lowercase => word.to_string(),
+            Capitalization::Uppercase => word.to_uppercase(),
+            Capitalization::Capitalize => capitalize(word),
+            Capitalization::Random => match OsRng.gen_bool(0.5) {
+                true => capitalize(word),
+                false => word.to_string(),
+            },
+        };
+        words.push(word);
+    }
+    if options.capitalization == Capitalization::Random {
+        entropy_bits += options.words as f64;
+    }
+
+    Ok(GeneratedPassword {
+        password: SecretString::from(words.join(&options.separator)),
+        entropy_bits,
+    })
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn password_options(length: u32) -> PasswordOptions {
+        PasswordOptions {
+            length,
+            uppercase: true,
+            lowercase: true,
+            digits: true,
+            symbols: true,
+            min_uppercase: 0,
+            min_lowercase: 0,
+            min_digits: 0,
+            min_symbols: 0,
+            symbol_set: None,
+            exclude_ambiguous: false,
+        }
+    }
+
+    #[test]
+    fn test_wordlist() {
+        assert_eq!(WORDLIST.len(), 7776);
+        assert!(WORDLIST.iter().all(|word| !word.is_empty()));
+    }
+
+    #[test]
+    fn test_password_minimums_per_class() {
+        let options = PasswordOptions {
+            min_uppercase: 3,
+            min_digits: 4,
+            min_symbols: 2,
+            ..password_options(12)
+        };
+        for _ in 0..100 {
+            let generated = generate_password(&options).unwrap();
+            let password = generated.password.expose_secret();
+            assert_eq!(password.chars().count(), 12);
+            assert!(password.chars().filter(char::is_ascii_uppercase).count() >= 3);
+            assert!(password.chars().filter(char::is_ascii_digit).count() >= 4);
+            assert!(password.chars().filter(|c| SYMBOLS.contains(*c)).count() >= 2);
+        }
+    }
+
+    #[test]
+    fn test_password_excludes_ambiguous() {
+        let options = PasswordOptions {
+            exclude_ambiguous: true,
+            ..password_options(256)
+        };
+        let generated = generate_password(&options).unwrap();
+
+        assert!(!generated
+            .password
+            .expose_secret()
+            .chars()
+            .any(|c| AMBIGUOUS.contains(c)));
+    }
+
+    #[test]
+    fn test_password_entropy() {
+        let options = PasswordOptions {
+            uppercase: false,
+            symbols: false,
+            ..password_options(10)
+        };
+        let generated = generate_password(&options).unwrap();
+
+        // 36 characters to choose from
+        assert!((generated.entropy_bits - 10.0 * 36f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_password_invalid_options() {
+        let too_many = PasswordOptions {
+            min_digits: 13,
+            ..password_options(12)
+        };
+        let nothing = PasswordOptions {
+            uppercase: false,
+            lowercase: false,
+            digits: false,
+            symbols: false,
+            ..password_options(12)
+        };
+
+        // Each minimum fits, their sum overflows a u32
+        let overflowing = PasswordOptions {
+            min_uppercase: u32::MAX,
+            min_lowercase: 2,
+            ..password_options(12)
+        };
+        let too_long = password_options(MAX_LENGTH + 1);
+
+        for options in [
+            too_many,
+            nothing,
+            overflowing,
+            too_long,
+            password_options(0),
+        ] {
+            assert!(matches!(
+                generate_password(&options),
+                Err(AppError::InvalidPasswordConfig)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_pronounceable() {
+        let options = PronounceableOptions {
+            length: 8,
+            capitalize: true,
+            digits: 2,
+        };
+        let generated = generate_pronounceable(&options).unwrap();
+        let password = generated.password.expose_secret();
+
+        assert_eq!(password.len(), 10);
+        assert!(password.starts_with(|c: char| c.is_ascii_uppercase()));
+        for (position, c) in password[..8].to_lowercase().chars().enumerate() {
+            let pool = if position % 2 == 0 {
+                CONSONANTS
+            } else {
+                VOWELS
+            };
+            assert!(pool.contains(c));
+        }
+        assert!(password[8..].chars().all(|c| c.is_ascii_digit()));
+        let expected = 4.0 * 16f64.log2() + 4.0 * 4f64.log2() + 2.0 * 10f64.log2();
+        assert!((generated.entropy_bits - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pronounceable_invalid_options() {
+        let empty = PronounceableOptions {
+            length: 0,
+            capitalize: false,
+            digits: 2,
+        };
+        let overflowing = PronounceableOptions {
+            length: 8,
+            capitalize: false,
+            digits: u32::MAX,
+        };
+        let too_long = PronounceableOptions {
+            length: MAX_LENGTH,
+            capitalize: false,
+            digits: 1,
+        };
+
+        for options in [empty, overflowing, too_long] {
+            assert!(matches!(
+                generate_pronounceable(&options),
+                Err(AppError::InvalidPasswordConfig)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_passphrase() {
+        let options = PassphraseOptions {
+            words: 6,
+            separator: ".".to_string(),
+            capitalization: Capitalization::Capitalize,
+        };
+        let generated = generate_passphrase(&options).unwrap();
+        let words = generated
+            .password
+            .expose_secret()
+            .split('.')
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(words.len(), 6);
+        for word in &words {
+            assert!(word.starts_with(|c: char| c.is_ascii_uppercase()));
+            assert!(WORDLIST.contains(&word.to_lowercase().as_str()));
+        }
+        // Five dice rolls, about 12.9 bits per word
+        let expected = 6.0 * 7776f64.log2();
+        assert!((generated.entropy_bits - expected).abs() < 1e-9);
+
+        let random = PassphraseOptions {
+            capitalization: Capitalization::Random,
+            ..options
+        };
+        let generated = generate_passphrase(&random).unwrap();
+        assert!((generated.entropy_bits - (expected + 6.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generate_from_json() {
+        let options: GeneratorOptions =
+            serde_json::from_str(r#"{"mode": "passphrase", "words": 4, "separator": " "}"#)
+                .unwrap();
+        let generated = generate(&options).unwrap();
+
+        assert_eq!(generated.password.expose_secret().split(' ').count(), 4);
+    }
+}
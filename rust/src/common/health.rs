@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use security::keyed_hash;
+
+use crate::models::{Login, VaultHealth, WeakPassword};
+
+use super::{
+    errors::AppError,
+    strength::estimate_strength,
+    time::now,
+    vault::{get_data_key, owned_by, SealedItem},
+};
+
+pub const DEFAULT_STALE_DAYS: u32 = 365;
+
+// Passwords scoring below this are reported as weak, the same bar as the
+// master password
+const MIN_SCORE: u8 = 3;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Checks the passwords of every login of a user for reuse, weakness, age
+/// and absence.
+///
+/// Reused passwords are found by comparing keyed hashes under the user's
+/// data key, so the report itself reveals nothing about the passwords. A
+/// password is stale when its login has not been updated for `stale_days`.
+pub async fn vault_health(user_id: &str, stale_days: u32) -> Result<VaultHealth, AppError> {
+    let key = get_data_key(user_id)?;
+    let mut logins = Login::get_list(owned_by(user_id)).await?;
+    let stale_before = now() as i64 - stale_days as i64 * SECONDS_PER_DAY;
+
+    let mut health = VaultHealth {
+        total: logins.len() as u32,
+        stale_days,
+        ..Default::default()
+    };
+    let mut reused: HashMap<String, Vec<String>> = HashMap::new();
+    for login in logins.iter_mut() {
+        login.open(&key)?;
+        let id = login.id.clone().unwrap_or_default();
+
+        let changed_at = login.updated_at.or(login.created_at).unwrap_or_default();
+        if changed_at < stale_before {
+            health.stale.push(id.clone());
+        }
+
+        let password = match &login.password {
+            Some(password) if !password.expose_secret().is_empty() => password,
+            _ => {
+                health.empty.push(id);
+                continue;
+            }
+        };
+
+        let fingerprint = keyed_hash(
+            key.expose_secret(),
+            "password",
+            password.expose_secret().as_bytes(),
+        )?;
+        reused.entry(fingerprint).or_default().push(id.clone());
+
+        let strength = estimate_strength(password.expose_secret(), &[&login.name, &login.username]);
+        if strength.score < MIN_SCORE {
+            health.weak.push(WeakPassword {
+                id,
+                score: strength.score,
+                warning: strength.feedback.warning,
+            });
+        }
+    }
+
+    health.reused = reused
+        .into_values()
+        .filter(|ids| ids.len() > 1)
+        .map(|mut ids| {
+            ids.sort();
+            ids
+        })
+        .collect();
+    // Largest groups first, ties in a stable order
+    health
+        .reused
+        .sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    health.reused_count = health.reused.iter().map(|ids| ids.len() as u32).sum();
+    health.weak_count = health.weak.len() as u32;
+    health.stale_count = health.stale.len() as u32;
+    health.empty_count = health.empty.len() as u32;
+    Ok(health)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::vault::setup_vault;
+
+    async fn add(user_id: &str, password: Option<&str>, changed_days_ago: i64) -> String {
+        let mut login = Login {
+            id: Some(sql::get_ulid()),
+            created_at: Some(now() as i64 - changed_days_ago * SECONDS_PER_DAY),
+            created_by: Some(user_id.to_string()),
+            name: "Example".to_string(),
+            username: "user".to_string(),
+            password: password.map(Into::into),
+            is_favorite: Some(false),
+            ..Default::default()
+        };
+        login.seal(&get_data_key(user_id).unwrap()).unwrap();
+        Login::insert(login).await.unwrap().id.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_vault_health() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let strong = "correct horse battery staple";
+        let reused_1 = add(&user_id, Some(strong), 0).await;
+        let reused_2 = add(&user_id, Some(strong), 10).await;
+        let weak = add(&user_id, Some("password1"), 0).await;
+        let stale = add(&user_id, Some("tr0ub4dor&3-Xq9#"), 400).await;
+        let empty = add(&user_id, Some(""), 0).await;
+        let missing = add(&user_id, None, 0).await;
+        // Another user's logins are not part of the report
+        let other_id = sql::get_ulid();
+        setup_vault(&other_id, &"Master@12345".into())
+            .await
+            .unwrap();
+        add(&other_id, Some(strong), 0).await;
+
+        let mut health = vault_health(&user_id, DEFAULT_STALE_DAYS).await.unwrap();
+
+        assert_eq!(health.total, 6);
+        let mut group = vec![reused_1, reused_2];
+        group.sort();
+        assert_eq!(health.reused, vec![group]);
+        assert_eq!(health.reused_count, 2);
+        assert_eq!(health.weak_count, 1);
+        assert_eq!(health.weak[0].id, weak);
+        assert!(health.weak[0].score < MIN_SCORE);
+        assert_eq!(health.stale, vec![stale]);
+        assert_eq!(health.stale_count, 1);
+        let mut empties = vec![empty, missing];
+        empties.sort();
+        health.empty.sort();
+        assert_eq!(health.empty, empties);
+        assert_eq!(health.empty_count, 2);
+
+        let health = vault_health(&user_id, 5).await.unwrap();
+        assert_eq!(health.stale_count, 2);
+        assert_eq!(health.stale_days, 5);
+    }
+}
@@ -2,14 +2,20 @@ use sql::{Filter, Glue, HttpQuery};
 
 use crate::models::IdentityCard;
 
-use super::{errors::AppError, jwt::get_user_id_from_token};
+use super::{
+    errors::AppError,
+    jwt::get_user_id_from_token,
+    vault::{get_data_key, SealedItem},
+    vault_session::ensure_unlocked,
+};
 
 pub async fn fetch_identity_card(id: String, token: String) -> anyhow::Result<IdentityCard> {
     let user = get_user_id_from_token(token).await?;
-    let identity_card = IdentityCard::get(id).await.unwrap();
+    let mut identity_card = IdentityCard::get(id).await.unwrap();
     if user != identity_card.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
     }
+    identity_card.open(&get_data_key(&user)?)?;
     Ok(identity_card)
 }
 
@@ -20,7 +26,12 @@ pub async fn add_identity_card(
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
     data.created_by = Some(user.clone());
     data.created_at = Some(chrono::Utc::now().timestamp());
-    let identity_card = IdentityCard::insert(data).await.unwrap();
+    // The record id is part of the associated data, so it is fixed before sealing
+    data.id = Some(data.id.unwrap_or_else(sql::get_ulid));
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut identity_card = IdentityCard::insert(data).await.unwrap();
+    identity_card.open(&key)?;
     Ok(identity_card)
 }
 
@@ -36,11 +47,17 @@ pub async fn update_identity_card(
     }
     data.updated_by = Some(user.clone());
     data.updated_at = Some(chrono::Utc::now().timestamp());
-    let identity_card = IdentityCard::update(id, data).await.unwrap();
+    data.id = Some(id.clone());
+    data.created_by = Some(user.clone());
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut identity_card = IdentityCard::update(id, data).await.unwrap();
+    identity_card.open(&key)?;
     Ok(identity_card)
 }
 
 pub async fn remove_identity_card(id: String, user: String) -> anyhow::Result<IdentityCard> {
+    ensure_unlocked(&user)?;
     let identity_card = IdentityCard::get(id.clone()).await.unwrap();
     if user != identity_card.created_by.unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -53,6 +70,7 @@ pub async fn get_all_identity_cards(
     query: String,
     user: String,
 ) -> anyhow::Result<Vec<IdentityCard>> {
+    let key = get_data_key(&user)?;
     let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
     query = if query.filters.is_none() {
         query.filters = Some(vec![Filter::equal("created_by", user, None)]);
@@ -65,6 +83,9 @@ pub async fn get_all_identity_cards(
             .push(Filter::equal("created_by", user, Some(Glue::And)));
         query
     };
-    let identity_cards = IdentityCard::get_list(query).await.unwrap();
+    let mut identity_cards = IdentityCard::get_list(query).await.unwrap();
+    for identity_card in identity_cards.iter_mut() {
+        identity_card.open(&key)?;
+    }
     Ok(identity_cards)
 }
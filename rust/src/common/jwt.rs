@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
-use jsonwebtoken::{decode, encode, Header, Validation};
+use jsonwebtoken::{
+    decode, decode_header, encode, errors::ErrorKind, DecodingKey, EncodingKey, Header, Validation,
+};
+use security::SecretString;
 
 use crate::models::others::jwt_claims::{Claims, RefreshTokenClaims};
 
@@ -10,9 +13,13 @@ use super::{
         get_access_decoding_key, get_access_encoding_key, get_refresh_decoding_key,
         get_refresh_encoding_key,
     },
+    refresh_tokens::check_access_token,
     time::now,
 };
 
+pub const ACCESS_TOKEN_LIFETIME: usize = 15 * 60;
+pub const REFRESH_TOKEN_LIFETIME: usize = 60 * 60 * 24 * 30;
+
 pub fn is_not_expired(exp: usize) -> bool {
     return exp > chrono::Utc::now().timestamp() as usize;
 }
@@ -23,9 +30,13 @@ pub fn generate_access_token(user_id: String, duration: usize) -> Result<String,
         exp: (n + duration),
         uid: user_id,
         iat: n,
+        epoch: 0,
     };
-    let key = get_access_encoding_key();
-    encode(&Header::default(), &claims, &key).map_err(AppError::JWTKeyError)
+    encode_access_claims(&claims)
+}
+
+pub fn encode_access_claims(claims: &Claims) -> Result<String, AppError> {
+    sign(claims, get_access_encoding_key())
 }
 
 pub fn generate_refresh_token(uid: String, duration: usize) -> Result<String, AppError> {
@@ -36,42 +47,74 @@ pub fn generate_refresh_token(uid: String, duration: usize) -> Result<String, Ap
         iat: n,
         exp: (n + duration),
     };
+    encode_refresh_claims(&claims)
+}
+
+pub fn encode_refresh_claims(claims: &RefreshTokenClaims) -> Result<String, AppError> {
+    sign(claims, get_refresh_encoding_key())
+}
+
+// The header names the key, so tokens survive a key rotation
+fn sign<T: serde::Serialize>(
+    claims: &T,
+    (kid, key): (String, EncodingKey),
+) -> Result<String, AppError> {
+    let header = Header {
+        kid: Some(kid),
+        ..Header::default()
+    };
+    encode(&header, claims, &key).map_err(AppError::JWTKeyError)
+}
 
-    let key = get_refresh_encoding_key();
-    encode(&Header::default(), &claims, &key).map_err(AppError::JWTKeyError)
+fn verify<T: serde::de::DeserializeOwned>(
+    token: &str,
+    find_key: fn(&str) -> Option<DecodingKey>,
+) -> Result<T, AppError> {
+    let header = decode_header(token).map_err(|_| AppError::JwtDecodingError)?;
+    let key = header
+        .kid
+        .as_deref()
+        .and_then(find_key)
+        .ok_or(AppError::JwtDecodingError)?;
+    decode::<T>(token, &key, &Validation::default())
+        .map(|decoded| decoded.claims)
+        .map_err(|e| match e.kind() {
+            ErrorKind::ExpiredSignature => AppError::JWTTokenExpired,
+            _ => AppError::JwtDecodingError,
+        })
 }
 
 pub fn get_refresh_claims(token: &str) -> Result<RefreshTokenClaims, AppError> {
-    let key = get_refresh_decoding_key();
-    let decoded = decode::<RefreshTokenClaims>(token, &key, &Validation::default())?;
-    if decoded.claims.exp <= now() {
+    let claims: RefreshTokenClaims = verify(token, get_refresh_decoding_key)?;
+    if claims.exp <= now() {
         return Err(AppError::JWTTokenExpired);
     }
-    Ok(decoded.claims)
+    Ok(claims)
 }
 
 pub fn get_access_claims(token: &str) -> Result<Claims, AppError> {
-    let key = get_access_decoding_key();
-    let decoded = decode::<Claims>(token, &key, &Validation::default())?;
-    if decoded.claims.exp <= now() {
+    let claims: Claims = verify(token, get_access_decoding_key)?;
+    if claims.exp <= now() {
         return Err(AppError::JWTTokenExpired);
     }
-    Ok(decoded.claims)
+    Ok(claims)
 }
 
-pub fn generate_access_and_refresh_tokens(uid: String) -> Result<(String, String), AppError> {
-    let access_token = generate_access_token(uid.clone(), 15 * 60)?;
-    let refresh_token = generate_refresh_token(uid, 60 * 60 * 24 * 30)?;
-    Ok((access_token, refresh_token))
+pub fn generate_access_and_refresh_tokens(
+    uid: String,
+) -> Result<(SecretString, SecretString), AppError> {
+    let access_token = generate_access_token(uid.clone(), ACCESS_TOKEN_LIFETIME)?;
+    let refresh_token = generate_refresh_token(uid, REFRESH_TOKEN_LIFETIME)?;
+    Ok((access_token.into(), refresh_token.into()))
 }
 
 pub fn generate_access_token_from_refresh_token(refresh_token: &str) -> Result<String, AppError> {
     let claims = get_refresh_claims(refresh_token)?;
-    generate_access_token(claims.uid, 15 * 60)
+    generate_access_token(claims.uid, ACCESS_TOKEN_LIFETIME)
 }
 
 pub async fn get_user_id_from_token(token: String) -> Result<String, AppError> {
-    let claims = get_access_claims(token.as_str())?;
+    let claims = check_access_token(token.as_str())?;
     Ok(claims.uid)
 }
 
@@ -87,6 +130,7 @@ mod tests {
         generate_access_token_from_refresh_token, generate_refresh_token, get_access_claims,
         get_refresh_claims, get_user_id_from_refresh_token, get_user_id_from_token, is_not_expired,
     };
+    use jwt_key_manager::{get_access_encoding_key, rotate_jwt_keys};
     use time::now;
     use tokio::runtime::Runtime;
 
@@ -151,6 +195,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_user_id_from_token() {
+        crate::common::test_utils::init_test_db().await;
         let token = generate_access_token(test_user_id(), 900).unwrap();
         let user_id = get_user_id_from_token(token).await.unwrap();
         assert_eq!(user_id, test_user_id());
@@ -188,4 +233,26 @@ mod tests {
         assert!(get_access_claims(&refresh_token).is_err());
         assert!(get_refresh_claims(&access_token).is_err());
     }
+
+    #[test]
+    fn test_tokens_outlive_key_rotation() {
+        let token = generate_access_token(test_user_id(), 900).unwrap();
+        let kid = jsonwebtoken::decode_header(&token).unwrap().kid.unwrap();
+
+        let rotated = rotate_jwt_keys(false).unwrap();
+        assert_ne!(rotated.access_kid, kid);
+        assert_eq!(get_access_claims(&token).unwrap().uid, test_user_id());
+        let token = generate_access_token(test_user_id(), 900).unwrap();
+        assert_eq!(
+            jsonwebtoken::decode_header(&token).unwrap().kid,
+            Some(rotated.access_kid)
+        );
+
+        // Tokens without a key id are not tried against every key
+        let header = jsonwebtoken::Header::default();
+        let (_, key) = get_access_encoding_key();
+        let claims = get_access_claims(&token).unwrap();
+        let unnamed = jsonwebtoken::encode(&header, &claims, &key).unwrap();
+        assert!(get_access_claims(&unnamed).is_err());
+    }
 }
@@ -1,53 +1,184 @@
 #![allow(dead_code)]
 
+use chrono::{DateTime, Utc};
 use jsonwebtoken::{DecodingKey, EncodingKey};
-use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
+use security::{
+    decrypt_with_key_aad, encrypt_with_key_aad, AssociatedData, SecretBytes, SecretString,
+    DATA_KEY_LENGTH,
+};
+use serde_json::json;
+use sql::SqlError;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+use crate::models::JwtKeyIds;
+
+use super::{
+    errors::AppError,
+    jwt::{ACCESS_TOKEN_LIFETIME, REFRESH_TOKEN_LIFETIME},
+};
+
+// Retired keys kept for tokens signed before a rotation, at most. Refresh
+// keys rotate daily and their tokens live for 30 days.
+pub const MAX_PREVIOUS_KEYS: usize = 32;
+
+const DEFAULT_ACCESS_ROTATION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const DEFAULT_REFRESH_ROTATION_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPurpose {
+    Access,
+    Refresh,
+}
+
+impl KeyPurpose {
+    fn as_str(self) -> &'static str {
+        match self {
+            KeyPurpose::Access => "access",
+            KeyPurpose::Refresh => "refresh",
+        }
+    }
+
+    // A retired key is needed until the last token it signed expires
+    fn grace_period(self) -> chrono::Duration {
+        let lifetime = match self {
+            KeyPurpose::Access => ACCESS_TOKEN_LIFETIME,
+            KeyPurpose::Refresh => REFRESH_TOKEN_LIFETIME,
+        };
+        chrono::Duration::seconds(lifetime as i64)
+    }
+}
+
+pub struct JwtKey {
+    kid: String,
+    secret: SecretString,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    created_at: DateTime<Utc>,
+    retired_at: Option<DateTime<Utc>>,
+}
+
+impl JwtKey {
+    fn new(
+        kid: String,
+        secret: SecretString,
+        created_at: DateTime<Utc>,
+        retired_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        let bytes = secret.expose_secret().as_bytes();
+        JwtKey {
+            encoding_key: EncodingKey::from_secret(bytes),
+            decoding_key: DecodingKey::from_secret(bytes),
+            kid,
+            secret,
+            created_at,
+            retired_at,
+        }
+    }
+
+    fn generate() -> Self {
+        JwtKey::new(
+            sql::get_ulid(),
+            JwtKeySet::generate_secret().into(),
+            Utc::now(),
+            None,
+        )
+    }
+
+    pub fn kid(&self) -> &str {
+        &self.kid
+    }
+
+    fn aged_out(&self, purpose: KeyPurpose, now: DateTime<Utc>) -> bool {
+        self.retired_at
+            .is_some_and(|retired_at| retired_at + purpose.grace_period() <= now)
+    }
+}
 
 pub struct JwtKeySet {
-    access_encoding_key: EncodingKey,
-    access_decoding_key: DecodingKey,
-    access_created_at: Instant,
-    refresh_encoding_key: EncodingKey,
-    refresh_decoding_key: DecodingKey,
-    refresh_created_at: Instant,
+    // Newest first, only the first key signs
+    access_keys: Vec<JwtKey>,
+    refresh_keys: Vec<JwtKey>,
 }
 
 pub struct JwtKeyManager {
     current_keys: Arc<Mutex<JwtKeySet>>,
     access_rotation_interval: Duration,
     refresh_rotation_interval: Duration,
+    // Keys are kept in the database, sealed with this key, when it is set
+    encryption_key: Option<Arc<SecretBytes>>,
 }
 
 impl JwtKeyManager {
+    /// Keys that only live in memory, every token stops working when the
+    /// process ends.
     pub fn new(
         access_rotation_interval: Duration,
         refresh_rotation_interval: Duration,
     ) -> Arc<Self> {
         println!("Creating new JwtKeyManager");
-        let initial_keys = JwtKeySet::new();
-        let key_manager = Arc::new(JwtKeyManager {
-            current_keys: Arc::new(Mutex::new(initial_keys)),
+        JwtKeyManager::start(JwtKeyManager {
+            current_keys: Arc::new(Mutex::new(JwtKeySet::new())),
             access_rotation_interval,
             refresh_rotation_interval,
-        });
+            encryption_key: None,
+        })
+    }
+
+    /// Keys kept in the database, sealed with `encryption_key`, so tokens
+    /// outlive a restart. Keys due for rotation are rotated right away.
+    pub fn load(
+        access_rotation_interval: Duration,
+        refresh_rotation_interval: Duration,
+        encryption_key: SecretBytes,
+    ) -> Result<Arc<Self>, AppError> {
+        if encryption_key.len() != DATA_KEY_LENGTH {
+            return Err(AppError::LibraryError(format!(
+                "JWT key encryption key must be {} bytes",
+                DATA_KEY_LENGTH
+            )));
+        }
+        let key_set = JwtKeySet::load(&encryption_key)?;
+        let manager = JwtKeyManager {
+            current_keys: Arc::new(Mutex::new(key_set)),
+            access_rotation_interval,
+            refresh_rotation_interval,
+            encryption_key: Some(Arc::new(encryption_key)),
+        };
+        for purpose in [KeyPurpose::Access, KeyPurpose::Refresh] {
+            let due = {
+                let key_set = manager.current_keys.lock().unwrap();
+                match key_set.current(purpose) {
+                    Some(key) => key_set.key_age(key) >= manager.rotation_interval(purpose),
+                    None => true,
+                }
+            };
+            if due {
+                manager.rotate(purpose)?;
+            }
+        }
+        Ok(JwtKeyManager::start(manager))
+    }
+
+    fn start(manager: JwtKeyManager) -> Arc<Self> {
+        let key_manager = Arc::new(manager);
 
         // Clone Arc for access key rotation thread
         let access_manager = Arc::clone(&key_manager);
-        let access_interval = access_rotation_interval;
+        let access_interval = key_manager.access_rotation_interval;
         std::thread::spawn(move || loop {
             std::thread::sleep(access_interval);
-            access_manager.rotate_access_keys();
+            access_manager.rotate_in_background(KeyPurpose::Access);
             println!("Access keys rotated");
         });
 
         // Clone Arc for refresh key rotation thread
         let refresh_manager = Arc::clone(&key_manager);
-        let refresh_interval = refresh_rotation_interval;
+        let refresh_interval = key_manager.refresh_rotation_interval;
         std::thread::spawn(move || loop {
             std::thread::sleep(refresh_interval);
-            refresh_manager.rotate_refresh_keys();
+            refresh_manager.rotate_in_background(KeyPurpose::Refresh);
             println!("Refresh keys rotated");
         });
 
@@ -58,20 +189,61 @@ impl JwtKeyManager {
         self.current_keys.clone()
     }
 
-    fn rotate_access_keys(&self) {
-        let mut current_keys = self.current_keys.lock().unwrap();
-        let (new_encoding_key, new_decoding_key) = JwtKeySet::generate_key_pair();
-        current_keys.access_encoding_key = new_encoding_key;
-        current_keys.access_decoding_key = new_decoding_key;
-        current_keys.access_created_at = Instant::now();
+    fn rotation_interval(&self, purpose: KeyPurpose) -> Duration {
+        match purpose {
+            KeyPurpose::Access => self.access_rotation_interval,
+            KeyPurpose::Refresh => self.refresh_rotation_interval,
+        }
+    }
+
+    /// Starts signing with a new key. Tokens signed with the previous keys
+    /// stay valid until they expire.
+    pub fn rotate(&self, purpose: KeyPurpose) -> Result<String, AppError> {
+        self.replace_key(purpose, false)
+    }
+
+    /// Starts signing with a new key and drops every previous one, tokens
+    /// signed before are refused from now on.
+    pub fn revoke(&self, purpose: KeyPurpose) -> Result<String, AppError> {
+        self.replace_key(purpose, true)
+    }
+
+    fn replace_key(&self, purpose: KeyPurpose, revoke: bool) -> Result<String, AppError> {
+        let mut key_set = self.current_keys.lock().unwrap();
+        let key = JwtKey::generate();
+        let now = key.created_at;
+        // Stored first, a key that is not saved must not sign anything
+        if let Some(encryption_key) = &self.encryption_key {
+            save_rotation(encryption_key, purpose, &key, now, revoke)?;
+        }
+        let kid = key.kid.clone();
+        key_set.install(purpose, key, now);
+        if revoke {
+            key_set.keys_mut(purpose).truncate(1);
+        }
+        Ok(kid)
+    }
+
+    fn rotate_in_background(&self, purpose: KeyPurpose) {
+        if let Err(e) = self.rotate(purpose) {
+            // The current key keeps signing until the next attempt
+            println!(
+                "{}",
+                json!({
+                    "message": "JWT key rotation failed",
+                    "purpose": purpose.as_str(),
+                    "error": e.to_string()
+                })
+            );
+        }
+    }
+
+    fn rotate_access_keys(&self) -> Result<String, AppError> {
+        self.rotate(KeyPurpose::Access)
     }
 
-    fn rotate_refresh_keys(&self) {
-        let mut current_keys = self.current_keys.lock().unwrap();
-        let (new_encoding_key, new_decoding_key) = JwtKeySet::generate_key_pair();
-        current_keys.refresh_encoding_key = new_encoding_key;
-        current_keys.refresh_decoding_key = new_decoding_key;
-        current_keys.refresh_created_at = Instant::now();
+    fn rotate_refresh_keys(&self) -> Result<String, AppError> {
+        self.rotate(KeyPurpose::Refresh)
     }
 
     pub fn clone(&self) -> Self {
@@ -79,25 +251,166 @@ impl JwtKeyManager {
             current_keys: self.current_keys.clone(),
             access_rotation_interval: self.access_rotation_interval,
             refresh_rotation_interval: self.refresh_rotation_interval,
+            encryption_key: self.encryption_key.clone(),
         }
     }
 }
 
+fn key_associated_data(kid: &str, purpose: &str) -> AssociatedData {
+    AssociatedData::new()
+        .field("jwt_keys")
+        .field("secret")
+        .field(kid)
+        .field(purpose)
+}
+
+// Adds the new key, retires the current one and forgets keys no longer
+// needed, the same way `JwtKeySet::install` does in memory. Revoking
+// forgets every previous key.
+fn save_rotation(
+    encryption_key: &SecretBytes,
+    purpose: KeyPurpose,
+    key: &JwtKey,
+    now: DateTime<Utc>,
+    revoke: bool,
+) -> Result<(), AppError> {
+    let secret = encrypt_with_key_aad(
+        key.secret.expose_secret(),
+        encryption_key.expose_secret(),
+        &key_associated_data(&key.kid, purpose.as_str()),
+    )?;
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    tx.execute(
+        "UPDATE jwt_keys SET retired_at = ? WHERE purpose = ? AND retired_at IS NULL",
+        (now.timestamp(), purpose.as_str()),
+    )
+    .map_err(SqlError::from)?;
+    tx.execute(
+        "INSERT INTO jwt_keys (kid, purpose, secret, created_at) VALUES (?, ?, ?, ?)",
+        (&key.kid, purpose.as_str(), &secret, now.timestamp()),
+    )
+    .map_err(SqlError::from)?;
+    tx.execute(
+        "DELETE FROM jwt_keys WHERE purpose = ?1 AND (retired_at <= ?2 OR kid NOT IN \
+         (SELECT kid FROM jwt_keys WHERE purpose = ?1 \
+          ORDER BY created_at DESC, kid DESC LIMIT ?3))",
+        (
+            purpose.as_str(),
+            (now - purpose.grace_period()).timestamp(),
+            (MAX_PREVIOUS_KEYS + 1) as i64,
+        ),
+    )
+    .map_err(SqlError::from)?;
+    if revoke {
+        tx.execute(
+            "DELETE FROM jwt_keys WHERE purpose = ? AND kid != ?",
+            (purpose.as_str(), &key.kid),
+        )
+        .map_err(SqlError::from)?;
+    }
+    tx.commit().map_err(SqlError::from)?;
+    Ok(())
+}
+
 impl JwtKeySet {
     fn new() -> Self {
-        let (access_encoding_key, access_decoding_key) = JwtKeySet::generate_key_pair();
-        let (refresh_encoding_key, refresh_decoding_key) = JwtKeySet::generate_key_pair();
-        let access_created_at = Instant::now();
-        // Try not to rotate the refresh key at the same time as the access key
-        let refresh_created_at = access_created_at + Duration::from_millis(200);
         JwtKeySet {
-            access_encoding_key,
-            access_decoding_key,
-            access_created_at,
-            refresh_encoding_key,
-            refresh_decoding_key,
-            refresh_created_at,
+            access_keys: vec![JwtKey::generate()],
+            refresh_keys: vec![JwtKey::generate()],
+        }
+    }
+
+    // Keys that aged out are left for the next rotation to delete
+    fn load(encryption_key: &SecretBytes) -> Result<Self, AppError> {
+        let conn = sql::get_db_connection()?;
+        let rows = conn
+            .prepare(
+                "SELECT kid, purpose, secret, created_at, retired_at FROM jwt_keys \
+                 ORDER BY created_at DESC, kid DESC",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, i64>(3)?,
+                        row.get::<_, Option<i64>>(4)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(SqlError::from)?;
+
+        let now = Utc::now();
+        let mut key_set = JwtKeySet {
+            access_keys: Vec::new(),
+            refresh_keys: Vec::new(),
+        };
+        for (kid, purpose, secret, created_at, retired_at) in rows {
+            let purpose = match purpose.as_str() {
+                "access" => KeyPurpose::Access,
+                "refresh" => KeyPurpose::Refresh,
+                _ => continue,
+            };
+            let secret = decrypt_with_key_aad(
+                &secret,
+                encryption_key.expose_secret(),
+                &key_associated_data(&kid, purpose.as_str()),
+            )?;
+            let key = JwtKey::new(
+                kid,
+                secret.into(),
+                DateTime::from_timestamp(created_at, 0).unwrap_or_default(),
+                retired_at.and_then(|retired_at| DateTime::from_timestamp(retired_at, 0)),
+            );
+            let keys = key_set.keys_mut(purpose);
+            if !key.aged_out(purpose, now) && keys.len() <= MAX_PREVIOUS_KEYS {
+                keys.push(key);
+            }
         }
+        Ok(key_set)
+    }
+
+    fn keys(&self, purpose: KeyPurpose) -> &[JwtKey] {
+        match purpose {
+            KeyPurpose::Access => &self.access_keys,
+            KeyPurpose::Refresh => &self.refresh_keys,
+        }
+    }
+
+    fn keys_mut(&mut self, purpose: KeyPurpose) -> &mut Vec<JwtKey> {
+        match purpose {
+            KeyPurpose::Access => &mut self.access_keys,
+            KeyPurpose::Refresh => &mut self.refresh_keys,
+        }
+    }
+
+    /// The key new tokens are signed with.
+    pub fn current(&self, purpose: KeyPurpose) -> Option<&JwtKey> {
+        self.keys(purpose)
+            .first()
+            .filter(|key| key.retired_at.is_none())
+    }
+
+    /// The key a token names in its `kid` header, while it is still
+    /// accepted.
+    pub fn find(&self, purpose: KeyPurpose, kid: &str) -> Option<&JwtKey> {
+        let now = Utc::now();
+        self.keys(purpose)
+            .iter()
+            .find(|key| key.kid == kid && !key.aged_out(purpose, now))
+    }
+
+    fn install(&mut self, purpose: KeyPurpose, key: JwtKey, now: DateTime<Utc>) {
+        let keys = self.keys_mut(purpose);
+        for previous in keys.iter_mut().filter(|key| key.retired_at.is_none()) {
+            previous.retired_at = Some(now);
+        }
+        keys.insert(0, key);
+        keys.retain(|key| !key.aged_out(purpose, now));
+        keys.truncate(MAX_PREVIOUS_KEYS + 1);
     }
 
     fn generate_key_pair() -> (EncodingKey, DecodingKey) {
@@ -124,68 +437,113 @@ impl JwtKeySet {
             .collect()
     }
 
+    fn key_age(&self, key: &JwtKey) -> Duration {
+        (Utc::now() - key.created_at).to_std().unwrap_or_default()
+    }
+
     pub fn access_encoding_key(&self) -> &EncodingKey {
-        &self.access_encoding_key
+        &self.access_keys[0].encoding_key
     }
 
     pub fn access_decoding_key(&self) -> &DecodingKey {
-        &self.access_decoding_key
+        &self.access_keys[0].decoding_key
     }
 
     pub fn refresh_encoding_key(&self) -> &EncodingKey {
-        &self.refresh_encoding_key
+        &self.refresh_keys[0].encoding_key
     }
 
     pub fn refresh_decoding_key(&self) -> &DecodingKey {
-        &self.refresh_decoding_key
+        &self.refresh_keys[0].decoding_key
     }
 
     pub fn access_key_age(&self) -> Duration {
-        self.access_created_at.elapsed()
+        self.key_age(&self.access_keys[0])
     }
 
     pub fn refresh_key_age(&self) -> Duration {
-        self.refresh_created_at.elapsed()
+        self.key_age(&self.refresh_keys[0])
+    }
+}
+
+static JWT_KEY_MANAGER: OnceCell<Arc<JwtKeyManager>> = OnceCell::new();
+
+/// Keeps JWT signing keys in the database, sealed with `encryption_key`,
+/// so sessions survive a restart. Has to run before the first token is
+/// issued, keys only live in memory otherwise.
+pub fn init_jwt_key_manager(encryption_key: &[u8]) -> Result<(), AppError> {
+    if JWT_KEY_MANAGER.get().is_some() {
+        return Err(AppError::LibraryError("JWT keys are already in use".into()));
     }
+    let manager = JwtKeyManager::load(
+        DEFAULT_ACCESS_ROTATION_INTERVAL,
+        DEFAULT_REFRESH_ROTATION_INTERVAL,
+        encryption_key.to_vec().into(),
+    )?;
+    JWT_KEY_MANAGER
+        .set(manager)
+        .map_err(|_| AppError::LibraryError("JWT keys are already in use".into()))
 }
 
-// Modify the JWT_KEY_MANAGER static to use a function for initialization
 pub fn get_jwt_key_manager() -> Arc<JwtKeyManager> {
-    static JWT_KEY_MANAGER: Lazy<Arc<JwtKeyManager>> = Lazy::new(|| {
-        JwtKeyManager::new(
-            Duration::from_secs(60 * 60),      // 1 hour for access keys
-            Duration::from_secs(24 * 60 * 60), // 24 hours for refresh keys
-        )
-    });
-    JWT_KEY_MANAGER.clone()
+    JWT_KEY_MANAGER
+        .get_or_init(|| {
+            JwtKeyManager::new(
+                DEFAULT_ACCESS_ROTATION_INTERVAL,
+                DEFAULT_REFRESH_ROTATION_INTERVAL,
+            )
+        })
+        .clone()
 }
 
-// Update the helper functions to use get_jwt_key_manager()
-pub fn get_access_encoding_key() -> EncodingKey {
-    let keys = get_jwt_key_manager().get_current_keys();
-    let key_set = keys.lock().unwrap();
-    key_set.access_encoding_key().clone()
+/// Rotates the access and refresh keys now, such as after a suspected leak.
+/// Tokens already issued stay valid until they expire, unless `revoke` is
+/// set, then they are refused right away and every user signs in again.
+pub fn rotate_jwt_keys(revoke: bool) -> Result<JwtKeyIds, AppError> {
+    let manager = get_jwt_key_manager();
+    let rotate = |purpose| {
+        if revoke {
+            manager.revoke(purpose)
+        } else {
+            manager.rotate(purpose)
+        }
+    };
+    Ok(JwtKeyIds {
+        access_kid: rotate(KeyPurpose::Access)?,
+        refresh_kid: rotate(KeyPurpose::Refresh)?,
+    })
 }
 
-pub fn get_access_decoding_key() -> DecodingKey {
+fn signing_key(purpose: KeyPurpose) -> (String, EncodingKey) {
     let keys = get_jwt_key_manager().get_current_keys();
     let key_set = keys.lock().unwrap();
-    key_set.access_decoding_key().clone()
+    let key = &key_set.keys(purpose)[0];
+    (key.kid.clone(), key.encoding_key.clone())
 }
 
-pub fn get_refresh_encoding_key() -> EncodingKey {
+fn verifying_key(purpose: KeyPurpose, kid: &str) -> Option<DecodingKey> {
     let keys = get_jwt_key_manager().get_current_keys();
     let key_set = keys.lock().unwrap();
-    key_set.refresh_encoding_key().clone()
+    key_set
+        .find(purpose, kid)
+        .map(|key| key.decoding_key.clone())
 }
 
-pub fn get_refresh_decoding_key() -> DecodingKey {
-    let keys = get_jwt_key_manager().get_current_keys();
-    let key_set = keys.lock().unwrap();
-    key_set.refresh_decoding_key().clone()
+pub fn get_access_encoding_key() -> (String, EncodingKey) {
+    signing_key(KeyPurpose::Access)
+}
+
+pub fn get_access_decoding_key(kid: &str) -> Option<DecodingKey> {
+    verifying_key(KeyPurpose::Access, kid)
+}
+
+pub fn get_refresh_encoding_key() -> (String, EncodingKey) {
+    signing_key(KeyPurpose::Refresh)
 }
 
-// Add the following tests module at the bottom of jwt_key_manager.rs
+pub fn get_refresh_decoding_key(kid: &str) -> Option<DecodingKey> {
+    verifying_key(KeyPurpose::Refresh, kid)
+}
 
 #[cfg(test)]
 mod tests {
@@ -256,8 +614,8 @@ mod tests {
                 for _ in 0..10 {
                     let keys = manager_clone.get_current_keys();
                     let key_set = keys.lock().unwrap();
-                    let _ = key_set.access_decoding_key.clone();
-                    let _ = key_set.refresh_encoding_key.clone();
+                    let _ = key_set.access_decoding_key().clone();
+                    let _ = key_set.refresh_encoding_key().clone();
                 }
             });
             handles.push(handle);
@@ -276,16 +634,16 @@ mod tests {
         let manager = JwtKeyManager::new(Duration::from_millis(100), Duration::from_millis(100));
         let keys_before = manager.get_current_keys();
         let key_set_before = keys_before.lock().unwrap();
-        let _ = key_set_before.access_encoding_key.clone();
-        let _ = key_set_before.refresh_encoding_key.clone();
+        let _ = key_set_before.access_encoding_key().clone();
+        let _ = key_set_before.refresh_encoding_key().clone();
         drop(key_set_before); // Release the lock
 
         thread::sleep(Duration::from_millis(150)); // Wait for rotation
 
         let keys_after = manager.get_current_keys();
         let key_set_after = keys_after.lock().unwrap();
-        let _ = key_set_after.access_encoding_key.clone();
-        let _ = key_set_after.refresh_encoding_key.clone();
+        let _ = key_set_after.access_encoding_key().clone();
+        let _ = key_set_after.refresh_encoding_key().clone();
     }
 
     /// Test that `get_jwt_key_manager` returns a singleton instance.
@@ -361,4 +719,123 @@ mod tests {
             );
         }
     }
+    /// Test that tokens signed with a retired key are accepted until the key ages out.
+    #[test]
+    fn test_previous_keys_until_aged_out() {
+        let manager = JwtKeyManager::new(
+            Duration::from_secs(60 * 60),
+            Duration::from_secs(24 * 60 * 60),
+        );
+        let keys = manager.get_current_keys();
+        let first = keys.lock().unwrap().access_keys[0].kid.clone();
+        let second = manager.rotate(KeyPurpose::Access).unwrap();
+
+        let mut key_set = keys.lock().unwrap();
+        assert_eq!(key_set.current(KeyPurpose::Access).unwrap().kid(), second);
+        assert!(key_set.find(KeyPurpose::Access, &first).is_some());
+        assert!(key_set.find(KeyPurpose::Refresh, &first).is_none());
+
+        // Retired longer ago than an access token lives
+        let retired_at = Utc::now() - KeyPurpose::Access.grace_period();
+        key_set.access_keys[1].retired_at = Some(retired_at);
+        assert!(key_set.find(KeyPurpose::Access, &first).is_none());
+        key_set.install(KeyPurpose::Access, JwtKey::generate(), Utc::now());
+        assert_eq!(key_set.access_keys.len(), 2);
+        assert!(key_set.find(KeyPurpose::Access, &second).is_some());
+    }
+
+    /// Test that no more than `MAX_PREVIOUS_KEYS` retired keys are kept.
+    #[test]
+    fn test_previous_keys_limit() {
+        let manager = JwtKeyManager::new(
+            Duration::from_secs(60 * 60),
+            Duration::from_secs(24 * 60 * 60),
+        );
+        let first = manager.rotate(KeyPurpose::Refresh).unwrap();
+        for _ in 0..MAX_PREVIOUS_KEYS {
+            manager.rotate(KeyPurpose::Refresh).unwrap();
+        }
+        let keys = manager.get_current_keys();
+        let key_set = keys.lock().unwrap();
+        assert_eq!(key_set.refresh_keys.len(), MAX_PREVIOUS_KEYS + 1);
+        assert!(key_set.find(KeyPurpose::Refresh, &first).is_some());
+        drop(key_set);
+
+        manager.rotate(KeyPurpose::Refresh).unwrap();
+        let key_set = keys.lock().unwrap();
+        assert_eq!(key_set.refresh_keys.len(), MAX_PREVIOUS_KEYS + 1);
+        assert!(key_set.find(KeyPurpose::Refresh, &first).is_none());
+    }
+
+    /// Test that keys kept in the database are the same after a restart,
+    /// and that revoking drops every previous key.
+    #[tokio::test]
+    async fn test_persisted_keys() {
+        crate::common::test_utils::init_test_db().await;
+        let encryption_key = security::generate_data_key();
+        let load = || {
+            JwtKeyManager::load(
+                Duration::from_secs(60 * 60),
+                Duration::from_secs(24 * 60 * 60),
+                encryption_key.clone(),
+            )
+        };
+        let kids = |manager: &JwtKeyManager, purpose| {
+            let keys = manager.get_current_keys();
+            let key_set = keys.lock().unwrap();
+            key_set
+                .keys(purpose)
+                .iter()
+                .map(|key| key.kid.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let manager = load().unwrap();
+        let access = kids(&manager, KeyPurpose::Access);
+        assert_eq!(access.len(), 1);
+        let restarted = load().unwrap();
+        assert_eq!(kids(&restarted, KeyPurpose::Access), access);
+        assert_eq!(
+            kids(&restarted, KeyPurpose::Refresh),
+            kids(&manager, KeyPurpose::Refresh)
+        );
+
+        let rotated = manager.rotate(KeyPurpose::Access).unwrap();
+        let restarted = load().unwrap();
+        assert_eq!(
+            kids(&restarted, KeyPurpose::Access),
+            vec![rotated.clone(), access[0].clone()]
+        );
+
+        // Secrets are sealed, another key opens nothing
+        let conn = sql::get_db_connection().unwrap();
+        let secret: String = conn
+            .query_row(
+                "SELECT secret FROM jwt_keys WHERE kid = ?",
+                [&access[0]],
+                |row| row.get(0),
+            )
+            .unwrap();
+        let keys = manager.get_current_keys();
+        assert_ne!(
+            secret,
+            keys.lock().unwrap().access_keys[1].secret.expose_secret()
+        );
+        assert!(JwtKeyManager::load(
+            Duration::from_secs(60 * 60),
+            Duration::from_secs(24 * 60 * 60),
+            security::generate_data_key(),
+        )
+        .is_err());
+
+        let revoked = manager.revoke(KeyPurpose::Access).unwrap();
+        assert_eq!(kids(&manager, KeyPurpose::Access), vec![revoked.clone()]);
+        assert!(keys
+            .lock()
+            .unwrap()
+            .find(KeyPurpose::Access, &rotated)
+            .is_none());
+        let restarted = load().unwrap();
+        assert_eq!(kids(&restarted, KeyPurpose::Access), vec![revoked]);
+    }
 }
@@ -1,47 +1,118 @@
-use sql::{Filter, Glue, HttpQuery};
+use sql::{Filter, Glue, HttpQuery, SqlError};
 
 use crate::models::Login;
 
-use super::errors::AppError;
+use super::{
+    errors::AppError,
+    otp::check_otpauth,
+    password_history::{
+        delete_history_entry, delete_password_history, history_limit, prune_password_history,
+        record_password,
+    },
+    vault::{get_data_key, SealedItem},
+    vault_session::ensure_unlocked,
+};
 
 pub async fn fetch_login(id: String, user: String) -> anyhow::Result<Login> {
-    let login = Login::get(id).await.unwrap();
+    let mut login = Login::get(id).await.unwrap();
     if user != login.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
     }
+    login.open(&get_data_key(&user)?)?;
     Ok(login)
 }
 
 pub async fn add_login(mut data: Login, user: String) -> anyhow::Result<Login> {
+    check_otpauth(&data)?;
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
     data.created_by = Some(user.clone());
     data.created_at = Some(chrono::Utc::now().timestamp());
-    let login = Login::insert(data).await.unwrap();
+    data.breach_count = None;
+    data.breach_checked_at = None;
+    // The record id is part of the associated data, so it is fixed before sealing
+    data.id = Some(data.id.unwrap_or_else(sql::get_ulid));
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut login = Login::insert(data).await.unwrap();
+    login.open(&key)?;
     Ok(login)
 }
 
-pub async fn update_login(id: String, mut data: Login, user: String) -> anyhow::Result<Login> {
+pub async fn update_login(id: String, data: Login, user: String) -> anyhow::Result<Login> {
+    save_login(id, data, user, None).await
+}
+
+/// Writes a changed login together with its password history.
+///
+/// The replaced password, the pruning of the history and the login itself
+/// are written in one transaction. `restored` names a history entry whose
+/// password goes back on the login, so it leaves the history as well.
+pub(crate) async fn save_login(
+    id: String,
+    mut data: Login,
+    user: String,
+    restored: Option<&str>,
+) -> anyhow::Result<Login> {
+    check_otpauth(&data)?;
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
-    let login = Login::get(id.clone()).await.unwrap();
-    if user != login.created_by.unwrap() {
+    let mut login = Login::get(id.clone()).await.unwrap();
+    if user != login.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
     }
+    let key = get_data_key(&user)?;
+    login.open(&key)?;
+    let previous = if login.password == data.password {
+        // A breach check only holds for the password it was made on
+        data.breach_count = login.breach_count;
+        data.breach_checked_at = login.breach_checked_at;
+        None
+    } else {
+        data.breach_count = None;
+        data.breach_checked_at = None;
+        login
+            .password
+            .take()
+            .filter(|p| !p.expose_secret().is_empty())
+    };
+    let limit = history_limit(&data);
     data.updated_by = Some(user.clone());
     data.updated_at = Some(chrono::Utc::now().timestamp());
-    let login = Login::update(id, data).await.unwrap();
+    data.id = Some(id.clone());
+    data.created_by = Some(user.clone());
+    data.seal(&key)?;
+
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    if let Some(history_id) = restored {
+        delete_history_entry(&tx, &id, history_id)?;
+    }
+    if let Some(previous) = previous {
+        record_password(&tx, &id, &user, previous, &key)?;
+    }
+    prune_password_history(&tx, &id, limit)?;
+    let mut login = Login::update_with(&tx, id, data)?;
+    tx.commit().map_err(SqlError::from)?;
+    login.open(&key)?;
     Ok(login)
 }
 
 pub async fn remove_login(id: String, user: String) -> anyhow::Result<Login> {
-    let login = Login::get(id.clone()).await.unwrap();
-    if user != login.created_by.unwrap() {
+    ensure_unlocked(&user)?;
+    let login = Login::get(id.clone()).await?;
+    if user != login.created_by.ok_or(AppError::Unauthorized)? {
         return Err(AppError::Unauthorized.into());
     }
-    let login = Login::delete(id).await.unwrap();
+    // A login never goes without its history, nor the other way round
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    delete_password_history(&tx, &id)?;
+    let login = Login::delete_with(&tx, id)?;
+    tx.commit().map_err(SqlError::from)?;
     Ok(login)
 }
 
 pub async fn get_all_logins(query: String, user: String) -> anyhow::Result<Vec<Login>> {
+    let key = get_data_key(&user)?;
     let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
     query = if query.filters.is_none() {
         query.filters = Some(vec![Filter::equal("created_by", user, None)]);
@@ -54,6 +125,9 @@ pub async fn get_all_logins(query: String, user: String) -> anyhow::Result<Vec<L
             .push(Filter::equal("created_by", user, Some(Glue::And)));
         query
     };
-    let logins = Login::get_list(query).await.unwrap();
+    let mut logins = Login::get_list(query).await.unwrap();
+    for login in logins.iter_mut() {
+        login.open(&key)?;
+    }
     Ok(logins)
 }
@@ -1,13 +1,27 @@
 pub mod backup_and_restore;
+pub mod breach;
 pub mod errors;
 pub mod financial_cards;
+pub mod generator;
+pub mod health;
 pub mod identity_cards;
 pub mod jwt;
 pub mod jwt_key_manager;
 pub mod logins;
 pub mod notes;
+pub mod otp;
+pub mod passkeys;
 pub mod password;
+pub mod password_history;
+pub mod qr;
+pub mod refresh_tokens;
+pub mod strength;
 pub mod tags;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub mod time;
+pub mod two_factor;
 pub mod users;
 pub mod validation;
+pub mod vault;
+pub mod vault_session;
@@ -2,9 +2,10 @@ use sql::{Filter, Glue, HttpQuery};
 
 use crate::models::Note;
 
-use super::errors::AppError;
+use super::{errors::AppError, vault_session::ensure_unlocked};
 
 pub async fn fetch_note(id: String, user: String) -> anyhow::Result<Note> {
+    ensure_unlocked(&user)?;
     let note = Note::get(id).await.unwrap();
     if user != note.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -13,6 +14,7 @@ pub async fn fetch_note(id: String, user: String) -> anyhow::Result<Note> {
 }
 
 pub async fn add_note(mut data: Note, user: String) -> anyhow::Result<Note> {
+    ensure_unlocked(&user)?;
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
     data.created_by = Some(user.clone());
     data.created_at = Some(chrono::Utc::now().timestamp());
@@ -21,6 +23,7 @@ pub async fn add_note(mut data: Note, user: String) -> anyhow::Result<Note> {
 }
 
 pub async fn update_note(id: String, mut data: Note, user: String) -> anyhow::Result<Note> {
+    ensure_unlocked(&user)?;
     data.is_favorite = Some(data.is_favorite.unwrap_or(false));
     let note = Note::get(id.clone()).await.unwrap();
     if user != note.created_by.unwrap() {
@@ -33,6 +36,7 @@ pub async fn update_note(id: String, mut data: Note, user: String) -> anyhow::Re
 }
 
 pub async fn remove_note(id: String, user: String) -> anyhow::Result<Note> {
+    ensure_unlocked(&user)?;
     let note = Note::get(id.clone()).await.unwrap();
     if user != note.created_by.unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -42,6 +46,7 @@ pub async fn remove_note(id: String, user: String) -> anyhow::Result<Note> {
 }
 
 pub async fn get_all_notes(query: String, user: String) -> anyhow::Result<Vec<Note>> {
+    ensure_unlocked(&user)?;
     let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
     query = if query.filters.is_none() {
         query.filters = Some(vec![Filter::equal("created_by", user, None)]);
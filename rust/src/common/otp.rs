@@ -0,0 +1,414 @@
+use percent_encoding::percent_decode_str;
+use rusqlite::TransactionBehavior;
+use security::{
+    base32_decode, base32_encode, hotp, steam_guard, totp, OtpAlgorithm, SecretBytes, STEAM_PERIOD,
+};
+use sql::SqlError;
+use url::Url;
+
+use crate::models::{Login, OtpCode};
+
+use super::{
+    errors::AppError,
+    time::now,
+    vault::{get_data_key, SealedItem},
+};
+
+// What authenticator apps assume when a URI says nothing else
+pub const DEFAULT_DIGITS: u32 = 6;
+pub const DEFAULT_PERIOD: u64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpKind {
+    /// RFC 6238, a new code every `period` seconds
+    Totp { period: u64 },
+    /// RFC 4226, a new code each time the counter moves
+    Hotp { counter: u64 },
+    /// Steam Guard, a TOTP in Steam's own alphabet
+    Steam,
+}
+
+/// An authenticator, parsed from an `otpauth://` URI as written by the
+/// Google Authenticator key URI format, or a `steam://` URI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OtpAuth {
+    pub kind: OtpKind,
+    pub secret: SecretBytes,
+    pub algorithm: OtpAlgorithm,
+    pub digits: u32,
+    pub issuer: Option<String>,
+    pub account: Option<String>,
+}
+
+fn invalid(reason: &str) -> AppError {
+    AppError::InvalidOtpAuthUri(reason.to_string())
+}
+
+fn decode_secret(secret: &str) -> Result<SecretBytes, AppError> {
+    base32_decode(secret).map_err(|_| AppError::TOTPSecretInvalid)
+}
+
+fn parse_algorithm(algorithm: &str) -> Result<OtpAlgorithm, AppError> {
+    match algorithm.to_ascii_uppercase().as_str() {
+        "SHA1" => Ok(OtpAlgorithm::Sha1),
+        "SHA256" => Ok(OtpAlgorithm::Sha256),
+        "SHA512" => Ok(OtpAlgorithm::Sha512),
+        _ => Err(invalid("unsupported algorithm")),
+    }
+}
+
+fn algorithm_name(algorithm: OtpAlgorithm) -> &'static str {
+    match algorithm {
+        OtpAlgorithm::Sha1 => "SHA1",
+        OtpAlgorithm::Sha256 => "SHA256",
+        OtpAlgorithm::Sha512 => "SHA512",
+    }
+}
+
+impl OtpAuth {
+    pub fn parse(uri: &str) -> Result<OtpAuth, AppError> {
+        let uri = uri.trim();
+        // Some apps keep Steam secrets as `steam://SECRET`
+        if let Some(secret) = uri
+            .get(..8)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("steam://"))
+            .map(|_| &uri[8..])
+        {
+            return Ok(OtpAuth {
+                kind: OtpKind::Steam,
+                secret: decode_secret(secret)?,
+                algorithm: OtpAlgorithm::Sha1,
+                digits: security::STEAM_CODE_LENGTH as u32,
+                issuer: Some("Steam".to_string()),
+                account: None,
+            });
+        }
+
+        let url = Url::parse(uri).map_err(|_| invalid("not a URI"))?;
+        if url.scheme() != "otpauth" {
+            return Err(invalid("not an otpauth URI"));
+        }
+        let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+
+        // The label is `issuer:account` or just `account`
+        let label = percent_decode_str(url.path().trim_start_matches('/'))
+            .decode_utf8()
+            .map_err(|_| invalid("label is not UTF-8"))?;
+        let (mut issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.trim().to_string()), account.trim()),
+            None => (None, label.trim()),
+        };
+        let account = Some(account.to_string()).filter(|account| !account.is_empty());
+
+        let mut secret = None;
+        let mut algorithm = OtpAlgorithm::Sha1;
+        let mut digits = DEFAULT_DIGITS;
+        let mut period = DEFAULT_PERIOD;
+        let mut counter = None;
+        let mut steam = false;
+        for (key, value) in url.query_pairs() {
+            match key.to_ascii_lowercase().as_str() {
+                "secret" => secret = Some(decode_secret(&value)?),
+                "issuer" => issuer = Some(value.trim().to_string()),
+                "algorithm" => algorithm = parse_algorithm(&value)?,
+                "digits" => digits = value.parse().map_err(|_| invalid("digits"))?,
+                "period" => period = value.parse().map_err(|_| invalid("period"))?,
+                "counter" => counter = Some(value.parse().map_err(|_| invalid("counter"))?),
+                "encoder" => steam = value.eq_ignore_ascii_case("steam"),
+                _ => {}
+            }
+        }
+        let issuer = issuer.filter(|issuer| !issuer.is_empty());
+
+        let kind = match host.as_str() {
+            "totp" if steam => OtpKind::Steam,
+            "totp" if period == 0 => return Err(invalid("period")),
+            "totp" => OtpKind::Totp { period },
+            "hotp" => OtpKind::Hotp {
+                counter: counter.ok_or_else(|| invalid("missing counter"))?,
+            },
+            _ => return Err(invalid("unknown type")),
+        };
+        let auth = OtpAuth {
+            kind,
+            secret: secret.ok_or_else(|| invalid("missing secret"))?,
+            algorithm,
+            digits: match kind {
+                OtpKind::Steam => security::STEAM_CODE_LENGTH as u32,
+                _ => digits,
+            },
+            issuer,
+            account,
+        };
+        // Rejects digits out of range before the URI is stored
+        auth.code(0).map_err(|_| invalid("digits"))?;
+        Ok(auth)
+    }
+
+    pub fn to_uri(&self) -> String {
+        let kind = match self.kind {
+            OtpKind::Hotp { .. } => "hotp",
+            _ => "totp",
+        };
+        let mut url = Url::parse(&format!("otpauth://{}/", kind)).unwrap();
+        let label = match (&self.issuer, &self.account) {
+            (Some(issuer), Some(account)) => format!("{}:{}", issuer, account),
+            // Keeps the issuer from reading back as the account
+            (Some(issuer), None) => format!("{}:", issuer),
+            (None, account) => account.clone().unwrap_or_default(),
+        };
+        url.path_segments_mut().unwrap().push(&label);
+
+        let mut query = url.query_pairs_mut();
+        query.append_pair("secret", &base32_encode(self.secret.expose_secret()));
+        if let Some(issuer) = &self.issuer {
+            query.append_pair("issuer", issuer);
+        }
+        match self.kind {
+            OtpKind::Totp { period } => {
+                query
+                    .append_pair("algorithm", algorithm_name(self.algorithm))
+                    .append_pair("digits", &self.digits.to_string())
+                    .append_pair("period", &period.to_string());
+            }
+            OtpKind::Hotp { counter } => {
+                query
+                    .append_pair("algorithm", algorithm_name(self.algorithm))
+                    .append_pair("digits", &self.digits.to_string())
+                    .append_pair("counter", &counter.to_string());
+            }
+            OtpKind::Steam => {
+                query.append_pair("encoder", "steam");
+            }
+        }
+        drop(query);
+        url.to_string()
+    }
+
+    /// The code at `time` (seconds since the epoch), or at the counter for
+    /// HOTP.
+    pub fn code(&self, time: u64) -> Result<OtpCode, AppError> {
+        let secret = self.secret.expose_secret();
+        let (code, period, counter) = match self.kind {
+            OtpKind::Totp { period } => (
+                totp(secret, time, period, self.digits, self.algorithm)?,
+                period,
+                None,
+            ),
+            OtpKind::Hotp { counter } => (
+                hotp(secret, counter, self.digits, self.algorithm)?,
+                0,
+                Some(counter),
+            ),
+            OtpKind::Steam => (steam_guard(secret, time)?, STEAM_PERIOD, None),
+        };
+        Ok(OtpCode {
+            code,
+            period,
+            remaining_seconds: match period {
+                0 => 0,
+                period => period - time % period,
+            },
+            counter,
+        })
+    }
+}
+
+/// Checks the authenticator URI of a login can make codes, before it is
+/// stored.
+pub(crate) fn check_otpauth(login: &Login) -> Result<(), AppError> {
+    match login.otpauth.as_ref().map(|uri| uri.expose_secret()) {
+        Some(uri) if !uri.trim().is_empty() => OtpAuth::parse(uri).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+/// The current code of the authenticator stored with a login. HOTP logins
+/// move on to their next counter, so each call gives a fresh code.
+pub async fn get_totp_code(login_id: String, user: String) -> anyhow::Result<OtpCode> {
+    let key = get_data_key(&user)?;
+    let mut conn = sql::get_db_connection()?;
+    // The counter is read and moved under one write lock, two calls never
+    // get the same HOTP code
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(SqlError::from)?;
+    let mut login = Login::get_with(&tx, login_id.clone())?;
+    if login.created_by.as_deref() != Some(user.as_str()) {
+        return Err(AppError::Unauthorized.into());
+    }
+    login.open(&key)?;
+    let uri = login
+        .otpauth
+        .as_ref()
+        .map(|uri| uri.expose_secret())
+        .filter(|uri| !uri.trim().is_empty())
+        .ok_or(AppError::TOTPNotEnabled)?;
+    let mut auth = OtpAuth::parse(uri)?;
+    let code = auth.code(now() as u64)?;
+    if let OtpKind::Hotp { counter } = auth.kind {
+        auth.kind = OtpKind::Hotp {
+            counter: counter.checked_add(1).ok_or_else(|| invalid("counter"))?,
+        };
+        login.otpauth = Some(auth.to_uri().into());
+        login.updated_at = Some(chrono::Utc::now().timestamp());
+        login.updated_by = Some(user);
+        login.seal(&key)?;
+        Login::update_with(&tx, login_id, login)?;
+        tx.commit().map_err(SqlError::from)?;
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{logins::add_login, vault::setup_vault};
+    use crate::models::Login;
+
+    // "12345678901234567890", the seed of the RFC test vectors
+    const SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn test_parse_otpauth() {
+        let auth = OtpAuth::parse(
+            "otpauth://totp/ACME%20Co:john.doe@email.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\
+             &issuer=ACME%20Co&algorithm=SHA256&digits=8&period=60",
+        )
+        .unwrap();
+        assert_eq!(auth.kind, OtpKind::Totp { period: 60 });
+        assert_eq!(auth.secret.expose_secret(), b"12345678901234567890");
+        assert_eq!(auth.algorithm, OtpAlgorithm::Sha256);
+        assert_eq!(auth.digits, 8);
+        assert_eq!(auth.issuer.as_deref(), Some("ACME Co"));
+        assert_eq!(auth.account.as_deref(), Some("john.doe@email.com"));
+        assert_eq!(OtpAuth::parse(&auth.to_uri()).unwrap(), auth);
+
+        // Defaults
+        let auth = OtpAuth::parse(&format!("otpauth://totp/alice?secret={}", SECRET)).unwrap();
+        assert_eq!(auth.kind, OtpKind::Totp { period: 30 });
+        assert_eq!(auth.algorithm, OtpAlgorithm::Sha1);
+        assert_eq!(auth.digits, 6);
+        assert_eq!(auth.issuer, None);
+        assert_eq!(auth.account.as_deref(), Some("alice"));
+
+        let auth =
+            OtpAuth::parse(&format!("otpauth://hotp/alice?secret={}&counter=3", SECRET)).unwrap();
+        assert_eq!(auth.kind, OtpKind::Hotp { counter: 3 });
+        assert_eq!(OtpAuth::parse(&auth.to_uri()).unwrap(), auth);
+    }
+
+    #[test]
+    fn test_parse_steam() {
+        let steam = OtpAuth::parse(&format!("steam://{}", SECRET)).unwrap();
+        assert_eq!(steam.kind, OtpKind::Steam);
+        assert_eq!(steam.digits, 5);
+        assert_eq!(
+            OtpAuth::parse(&format!(
+                "otpauth://totp/Steam:alice?secret={}&encoder=steam",
+                SECRET
+            ))
+            .unwrap()
+            .kind,
+            OtpKind::Steam
+        );
+        assert_eq!(OtpAuth::parse(&steam.to_uri()).unwrap(), steam);
+        assert_eq!(steam.code(59).unwrap().code, "PV9M4");
+    }
+
+    #[test]
+    fn test_invalid_otpauth() {
+        let invalid = [
+            "https://example.com".to_string(),
+            "otpauth://totp/alice".to_string(),
+            format!("otpauth://motp/alice?secret={}", SECRET),
+            format!("otpauth://hotp/alice?secret={}", SECRET),
+            format!("otpauth://totp/alice?secret={}&algorithm=MD5", SECRET),
+            format!("otpauth://totp/alice?secret={}&digits=12", SECRET),
+            format!("otpauth://totp/alice?secret={}&period=0", SECRET),
+            "otpauth://totp/alice?secret=not-base32!".to_string(),
+        ];
+        for uri in invalid {
+            assert!(OtpAuth::parse(&uri).is_err(), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_code() {
+        let auth =
+            OtpAuth::parse(&format!("otpauth://totp/alice?secret={}&digits=8", SECRET)).unwrap();
+        let code = auth.code(1111111109).unwrap();
+        assert_eq!(code.code, "07081804");
+        assert_eq!(code.period, 30);
+        assert_eq!(code.remaining_seconds, 1);
+        assert_eq!(code.counter, None);
+
+        let auth =
+            OtpAuth::parse(&format!("otpauth://hotp/alice?secret={}&counter=1", SECRET)).unwrap();
+        let code = auth.code(1111111109).unwrap();
+        assert_eq!(code.code, "287082");
+        assert_eq!(code.remaining_seconds, 0);
+        assert_eq!(code.counter, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_get_totp_code() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+        setup_vault(&user, &"Master@12345".into()).await.unwrap();
+        let add = |otpauth: Option<String>| {
+            add_login(
+                Login {
+                    name: "Example".to_string(),
+                    username: "user".to_string(),
+                    otpauth: otpauth.map(Into::into),
+                    ..Default::default()
+                },
+                user.clone(),
+            )
+        };
+
+        let totp = add(Some(format!("otpauth://totp/alice?secret={}", SECRET)))
+            .await
+            .unwrap();
+        let code = get_totp_code(totp.id.clone().unwrap(), user.clone())
+            .await
+            .unwrap();
+        assert_eq!(code.code.len(), 6);
+        assert!((1..=30).contains(&code.remaining_seconds));
+
+        // Stored sealed
+        let stored = Login::get(totp.id.clone().unwrap()).await.unwrap();
+        assert!(!stored.otpauth.unwrap().expose_secret().contains(SECRET));
+
+        let hotp = add(Some(format!(
+            "otpauth://hotp/alice?secret={}&counter=0",
+            SECRET
+        )))
+        .await
+        .unwrap();
+        let id = hotp.id.clone().unwrap();
+        assert_eq!(
+            get_totp_code(id.clone(), user.clone()).await.unwrap().code,
+            "755224"
+        );
+        assert_eq!(
+            get_totp_code(id.clone(), user.clone()).await.unwrap().code,
+            "287082"
+        );
+
+        // A counter that can't move on gives no code rather than a repeat
+        let last = add(Some(format!(
+            "otpauth://hotp/alice?secret={}&counter={}",
+            SECRET,
+            u64::MAX
+        )))
+        .await
+        .unwrap();
+        assert!(get_totp_code(last.id.unwrap(), user.clone()).await.is_err());
+
+        let none = add(None).await.unwrap();
+        assert!(get_totp_code(none.id.unwrap(), user.clone()).await.is_err());
+        assert!(add(Some("otpauth://totp/alice".to_string())).await.is_err());
+    }
+}
@@ -0,0 +1,534 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::{rngs::OsRng, RngCore};
+use security::{
+    authenticator_data, generate_passkey_key, none_attestation_object, passkey_public_key,
+    sign_assertion, AttestedCredential, PasskeyAlgorithm, SecretBytes, CLIENT_DATA_HASH_LENGTH,
+    FLAG_BACKED_UP, FLAG_BACKUP_ELIGIBLE, FLAG_USER_PRESENT, FLAG_USER_VERIFIED,
+    MAX_CREDENTIAL_ID_LENGTH,
+};
+use sql::{Filter, Glue, HttpQuery, SqlError};
+
+use crate::models::{
+    Passkey, PasskeyAssertion, PasskeyAssertionRequest, PasskeyCreation, PasskeyRegistration,
+};
+
+use super::{
+    errors::AppError,
+    time::now,
+    vault::{get_data_key, owned_by, SealedItem},
+    vault_session::ensure_unlocked,
+};
+
+pub const CREDENTIAL_ID_LENGTH: usize = 16;
+// WebAuthn caps user handles at 64 bytes
+pub const MAX_USER_HANDLE_LENGTH: usize = 64;
+// Software passkeys have no attested model
+const AAGUID: [u8; 16] = [0; 16];
+
+fn decode(field: &str, value: &str) -> Result<Vec<u8>, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|_| AppError::InvalidPasskey(format!("{} is not base64url", field)))
+}
+
+fn encode(value: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(value)
+}
+
+fn algorithm(passkey: &Passkey) -> Result<PasskeyAlgorithm, AppError> {
+    PasskeyAlgorithm::from_cose_id(passkey.algorithm).map_err(|_| {
+        AppError::InvalidPasskey(format!("unsupported algorithm {}", passkey.algorithm))
+    })
+}
+
+fn private_key(passkey: &Passkey) -> Result<SecretBytes, AppError> {
+    decode("private key", passkey.private_key.expose_secret()).map(SecretBytes::from)
+}
+
+/// Checks an opened passkey can sign, before it is stored.
+fn check_passkey(passkey: &Passkey) -> Result<(), AppError> {
+    if passkey.rp_id.trim().is_empty() {
+        return Err(AppError::InvalidPasskey("relying party id is empty".into()));
+    }
+    let credential_id = decode("credential id", &passkey.credential_id)?;
+    if credential_id.is_empty() || credential_id.len() > MAX_CREDENTIAL_ID_LENGTH {
+        return Err(AppError::InvalidPasskey(
+            "credential id must be 1 to 1023 bytes".into(),
+        ));
+    }
+    let user_handle = decode("user handle", &passkey.user_handle)?;
+    if user_handle.is_empty() || user_handle.len() > MAX_USER_HANDLE_LENGTH {
+        return Err(AppError::InvalidPasskey(
+            "user handle must be 1 to 64 bytes".into(),
+        ));
+    }
+    if passkey.sign_count < 0 || passkey.sign_count > u32::MAX as i64 {
+        return Err(AppError::InvalidPasskey("sign count out of range".into()));
+    }
+    let key = private_key(passkey)?;
+    passkey_public_key(algorithm(passkey)?, key.expose_secret())
+        .map_err(|_| AppError::InvalidPasskey("private key does not fit the algorithm".into()))?;
+    Ok(())
+}
+
+fn client_data_hash(value: &str) -> Result<Vec<u8>, AppError> {
+    let hash = decode("client data hash", value)?;
+    if hash.len() != CLIENT_DATA_HASH_LENGTH {
+        return Err(AppError::InvalidPasskey(
+            "client data hash must be a SHA-256 digest".into(),
+        ));
+    }
+    Ok(hash)
+}
+
+// Passkeys live in a synced vault, so they are always backed up
+fn flags(user_verified: bool) -> u8 {
+    let flags = FLAG_USER_PRESENT | FLAG_BACKUP_ELIGIBLE | FLAG_BACKED_UP;
+    if user_verified {
+        flags | FLAG_USER_VERIFIED
+    } else {
+        flags
+    }
+}
+
+pub async fn fetch_passkey(id: String, user: String) -> anyhow::Result<Passkey> {
+    let mut passkey = Passkey::get(id).await.unwrap();
+    if user != passkey.created_by.clone().unwrap() {
+        return Err(AppError::Unauthorized.into());
+    }
+    passkey.open(&get_data_key(&user)?)?;
+    Ok(passkey)
+}
+
+pub async fn add_passkey(mut data: Passkey, user: String) -> anyhow::Result<Passkey> {
+    check_passkey(&data)?;
+    data.is_favorite = Some(data.is_favorite.unwrap_or(false));
+    data.created_by = Some(user.clone());
+    data.created_at = Some(chrono::Utc::now().timestamp());
+    // The record id is part of the associated data, so it is fixed before sealing
+    data.id = Some(data.id.unwrap_or_else(sql::get_ulid));
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut passkey = Passkey::insert(data).await.unwrap();
+    passkey.open(&key)?;
+    Ok(passkey)
+}
+
+pub async fn update_passkey(
+    id: String,
+    mut data: Passkey,
+    user: String,
+) -> anyhow::Result<Passkey> {
+    check_passkey(&data)?;
+    data.is_favorite = Some(data.is_favorite.unwrap_or(false));
+    let passkey = Passkey::get(id.clone()).await.unwrap();
+    if user != passkey.created_by.unwrap() {
+        return Err(AppError::Unauthorized.into());
+    }
+    // The counter only moves forward, relying parties treat a step back as
+    // a cloned authenticator
+    data.sign_count = data.sign_count.max(passkey.sign_count);
+    data.last_used_at = passkey.last_used_at;
+    data.created_at = passkey.created_at;
+    data.updated_by = Some(user.clone());
+    data.updated_at = Some(chrono::Utc::now().timestamp());
+    data.id = Some(id.clone());
+    data.created_by = Some(user.clone());
+    let key = get_data_key(&user)?;
+    data.seal(&key)?;
+    let mut passkey = Passkey::update(id, data).await.unwrap();
+    passkey.open(&key)?;
+    Ok(passkey)
+}
+
+pub async fn remove_passkey(id: String, user: String) -> anyhow::Result<Passkey> {
+    ensure_unlocked(&user)?;
+    let passkey = Passkey::get(id.clone()).await.unwrap();
+    if user != passkey.created_by.unwrap() {
+        return Err(AppError::Unauthorized.into());
+    }
+    let passkey = Passkey::delete(id).await.unwrap();
+    Ok(passkey)
+}
+
+pub async fn get_all_passkeys(query: String, user: String) -> anyhow::Result<Vec<Passkey>> {
+    let key = get_data_key(&user)?;
+    let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
+    query = if query.filters.is_none() {
+        query.filters = Some(vec![Filter::equal("created_by", user, None)]);
+        query
+    } else {
+        query
+            .filters
+            .as_mut()
+            .unwrap()
+            .push(Filter::equal("created_by", user, Some(Glue::And)));
+        query
+    };
+    let mut passkeys = Passkey::get_list(query).await.unwrap();
+    for passkey in passkeys.iter_mut() {
+        passkey.open(&key)?;
+    }
+    Ok(passkeys)
+}
+
+/// Passkeys a user holds for a relying party, for picking one to sign in
+/// with.
+pub async fn find_passkeys(rp_id: String, user: String) -> anyhow::Result<Vec<Passkey>> {
+    let key = get_data_key(&user)?;
+    let mut passkeys = Passkey::get_list(owned_by(&user)).await?;
+    passkeys.retain(|passkey| passkey.rp_id == rp_id);
+    for passkey in passkeys.iter_mut() {
+        passkey.open(&key)?;
+    }
+    Ok(passkeys)
+}
+
+/// Makes a passkey for a relying party, as `navigator.credentials.create`
+/// would, and stores it in the vault.
+pub async fn create_passkey(
+    request: PasskeyCreation,
+    user: String,
+) -> anyhow::Result<PasskeyRegistration> {
+    // Nothing is signed with "none" attestation, the hash only has to be
+    // well formed
+    client_data_hash(&request.client_data_hash)?;
+    let algorithm = request
+        .algorithms
+        .iter()
+        .find_map(|&id| PasskeyAlgorithm::from_cose_id(id).ok())
+        .ok_or_else(|| AppError::InvalidPasskey("no supported algorithm".into()))?;
+
+    let private_key = generate_passkey_key(algorithm);
+    let public_key = passkey_public_key(algorithm, private_key.expose_secret())?;
+    let mut credential_id = vec![0u8; CREDENTIAL_ID_LENGTH];
+    OsRng.fill_bytes(&mut credential_id);
+
+    let auth_data = authenticator_data(
+        &request.rp_id,
+        flags(request.user_verified),
+        0,
+        Some(&AttestedCredential {
+            aaguid: AAGUID,
+            credential_id: &credential_id,
+            public_key: &public_key,
+        }),
+    )?;
+    let attestation_object = none_attestation_object(&auth_data);
+
+    let passkey = add_passkey(
+        Passkey {
+            name: request
+                .rp_name
+                .clone()
+                .unwrap_or_else(|| request.rp_id.clone()),
+            rp_id: request.rp_id,
+            rp_name: request.rp_name,
+            user_handle: request.user_handle,
+            user_name: request.user_name,
+            user_display_name: request.user_display_name,
+            credential_id: encode(&credential_id),
+            algorithm: algorithm.cose_id(),
+            private_key: encode(private_key.expose_secret()).into(),
+            sign_count: 0,
+            ..Default::default()
+        },
+        user,
+    )
+    .await?;
+
+    Ok(PasskeyRegistration {
+        passkey,
+        credential_id: encode(&credential_id),
+        attestation_object: encode(&attestation_object),
+        authenticator_data: encode(&auth_data),
+        public_key: encode(&public_key),
+        public_key_algorithm: algorithm.cose_id(),
+    })
+}
+
+// Moves the counter on and returns it, in one statement so two assertions
+// never share a count
+fn next_sign_count(id: &str, user: &str) -> Result<u32, AppError> {
+    let n = now() as i64;
+    let conn = sql::get_db_connection()?;
+    let count = conn
+        .query_row(
+            "UPDATE passkeys SET sign_count = sign_count + 1, last_used_at = ? \
+             WHERE id = ? AND created_by = ? RETURNING sign_count",
+            (n, id, user),
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(SqlError::from)?;
+    u32::try_from(count).map_err(|_| AppError::InvalidPasskey("sign count out of range".into()))
+}
+
+/// Signs in to a relying party with a stored passkey, as
+/// `navigator.credentials.get` would.
+pub async fn sign_passkey_assertion(
+    id: String,
+    request: PasskeyAssertionRequest,
+    user: String,
+) -> anyhow::Result<PasskeyAssertion> {
+    let hash = client_data_hash(&request.client_data_hash)?;
+    let passkey = fetch_passkey(id.clone(), user.clone()).await?;
+    if passkey.rp_id != request.rp_id {
+        return Err(
+            AppError::InvalidPasskey("passkey belongs to another relying party".into()).into(),
+        );
+    }
+    let algorithm = algorithm(&passkey)?;
+    let private_key = private_key(&passkey)?;
+
+    let sign_count = next_sign_count(&id, &user)?;
+    let auth_data = authenticator_data(
+        &passkey.rp_id,
+        flags(request.user_verified),
+        sign_count,
+        None,
+    )?;
+    let signature = sign_assertion(algorithm, private_key.expose_secret(), &auth_data, &hash)?;
+
+    Ok(PasskeyAssertion {
+        credential_id: passkey.credential_id,
+        authenticator_data: encode(&auth_data),
+        signature: encode(&signature),
+        user_handle: passkey.user_handle,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::vault::setup_vault;
+    use ciborium::Value;
+    use ed25519_dalek::{Signature as Ed25519Signature, Verifier, VerifyingKey as Ed25519Key};
+    use p256::{
+        ecdsa::{Signature as P256Signature, VerifyingKey as P256Key},
+        EncodedPoint,
+    };
+
+    const RP_ID: &str = "example.com";
+
+    fn cbor(data: &[u8]) -> Value {
+        ciborium::from_reader(data).unwrap()
+    }
+
+    fn entry(map: &Value, key: Value) -> Value {
+        map.as_map()
+            .unwrap()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    }
+
+    fn cose_bytes(key: &Value, label: i64) -> Vec<u8> {
+        entry(key, Value::from(label)).into_bytes().unwrap()
+    }
+
+    // A relying party checking an assertion against the stored COSE key
+    fn verify(cose_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let key = cbor(cose_key);
+        match entry(&key, Value::from(3)).as_integer().map(i64::try_from) {
+            Some(Ok(-7)) => {
+                let point = EncodedPoint::from_affine_coordinates(
+                    cose_bytes(&key, -2).as_slice().into(),
+                    cose_bytes(&key, -3).as_slice().into(),
+                    false,
+                );
+                let key = P256Key::from_encoded_point(&point).unwrap();
+                let signature = P256Signature::from_der(signature).unwrap();
+                key.verify(message, &signature).is_ok()
+            }
+            Some(Ok(-8)) => {
+                let x: [u8; 32] = cose_bytes(&key, -2).try_into().unwrap();
+                let key = Ed25519Key::from_bytes(&x).unwrap();
+                let signature = Ed25519Signature::from_slice(signature).unwrap();
+                key.verify(message, &signature).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    fn creation(algorithms: Vec<i64>) -> PasskeyCreation {
+        PasskeyCreation {
+            rp_id: RP_ID.to_string(),
+            rp_name: Some("Example".to_string()),
+            user_handle: encode(b"user-1"),
+            user_name: Some("alice".to_string()),
+            user_display_name: None,
+            client_data_hash: encode(&[1; 32]),
+            algorithms,
+            user_verified: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_passkey_ceremonies() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+        setup_vault(&user, &"Master@12345".into()).await.unwrap();
+
+        for (algorithms, expected) in [(vec![-7, -8], -7), (vec![-257, -8], -8)] {
+            let registration = create_passkey(creation(algorithms), user.clone())
+                .await
+                .unwrap();
+            assert_eq!(registration.public_key_algorithm, expected);
+            let id = registration.passkey.id.clone().unwrap();
+
+            // Stored sealed
+            let stored = Passkey::get(id.clone()).await.unwrap();
+            assert_ne!(
+                stored.private_key.expose_secret(),
+                registration.passkey.private_key.expose_secret()
+            );
+
+            let attestation = cbor(&decode("", &registration.attestation_object).unwrap());
+            assert_eq!(entry(&attestation, "fmt".into()), Value::from("none"));
+            let auth_data = decode("", &registration.authenticator_data).unwrap();
+            assert_eq!(
+                entry(&attestation, "authData".into()),
+                Value::Bytes(auth_data.clone())
+            );
+            assert_eq!(
+                auth_data[32],
+                FLAG_USER_PRESENT
+                    | FLAG_USER_VERIFIED
+                    | FLAG_BACKUP_ELIGIBLE
+                    | FLAG_BACKED_UP
+                    | 0x40
+            );
+            assert_eq!(auth_data[33..37], [0, 0, 0, 0]);
+            let credential_id = decode("", &registration.credential_id).unwrap();
+            assert_eq!(auth_data[53..55], [0, CREDENTIAL_ID_LENGTH as u8]);
+            assert_eq!(auth_data[55..71], credential_id);
+            let public_key = decode("", &registration.public_key).unwrap();
+            assert_eq!(auth_data[71..], public_key);
+
+            let request = |user_verified| PasskeyAssertionRequest {
+                rp_id: RP_ID.to_string(),
+                client_data_hash: encode(&[2; 32]),
+                user_verified,
+            };
+            for (count, user_verified) in [(1u8, false), (2, true)] {
+                let assertion =
+                    sign_passkey_assertion(id.clone(), request(user_verified), user.clone())
+                        .await
+                        .unwrap();
+                assert_eq!(assertion.credential_id, registration.credential_id);
+                assert_eq!(assertion.user_handle, encode(b"user-1"));
+                let data = decode("", &assertion.authenticator_data).unwrap();
+                assert_eq!(data.len(), 37);
+                assert_eq!(data[..32], auth_data[..32]);
+                assert_eq!(data[32] & FLAG_USER_VERIFIED != 0, user_verified);
+                assert_eq!(data[33..37], [0, 0, 0, count]);
+
+                let mut message = data.clone();
+                message.extend_from_slice(&[2; 32]);
+                let signature = decode("", &assertion.signature).unwrap();
+                assert!(verify(&public_key, &message, &signature));
+                message[0] ^= 1;
+                assert!(!verify(&public_key, &message, &signature));
+            }
+            let passkey = fetch_passkey(id.clone(), user.clone()).await.unwrap();
+            assert_eq!(passkey.sign_count, 2);
+            assert!(passkey.last_used_at.is_some());
+
+            // Other sites and other users get nothing
+            let mut other_site = request(true);
+            other_site.rp_id = "evil.example".to_string();
+            assert!(sign_passkey_assertion(id.clone(), other_site, user.clone())
+                .await
+                .is_err());
+            assert!(
+                sign_passkey_assertion(id.clone(), request(true), sql::get_ulid())
+                    .await
+                    .is_err()
+            );
+        }
+
+        let found = find_passkeys(RP_ID.to_string(), user.clone())
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(find_passkeys("other.example".to_string(), user.clone())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_passkeys() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+        setup_vault(&user, &"Master@12345".into()).await.unwrap();
+
+        assert!(create_passkey(creation(vec![-257]), user.clone())
+            .await
+            .is_err());
+        let mut short_hash = creation(vec![-7]);
+        short_hash.client_data_hash = encode(&[1; 16]);
+        assert!(create_passkey(short_hash, user.clone()).await.is_err());
+        let mut no_handle = creation(vec![-7]);
+        no_handle.user_handle = String::new();
+        assert!(create_passkey(no_handle, user.clone()).await.is_err());
+
+        let passkey = Passkey {
+            name: "Example".to_string(),
+            rp_id: RP_ID.to_string(),
+            user_handle: encode(b"user-1"),
+            credential_id: encode(&[7; 16]),
+            algorithm: -8,
+            private_key: encode(&[9; 32]).into(),
+            ..Default::default()
+        };
+        let added = add_passkey(passkey.clone(), user.clone()).await.unwrap();
+        for broken in [
+            Passkey {
+                algorithm: -257,
+                ..passkey.clone()
+            },
+            Passkey {
+                private_key: encode(&[9; 31]).into(),
+                ..passkey.clone()
+            },
+            Passkey {
+                credential_id: "not base64!".to_string(),
+                ..passkey.clone()
+            },
+            Passkey {
+                sign_count: -1,
+                ..passkey.clone()
+            },
+        ] {
+            assert!(add_passkey(broken, user.clone()).await.is_err());
+        }
+
+        // An edit never winds the counter back
+        let id = added.id.clone().unwrap();
+        sign_passkey_assertion(
+            id.clone(),
+            PasskeyAssertionRequest {
+                rp_id: RP_ID.to_string(),
+                client_data_hash: encode(&[2; 32]),
+                user_verified: false,
+            },
+            user.clone(),
+        )
+        .await
+        .unwrap();
+        let updated = update_passkey(
+            id,
+            Passkey {
+                name: "Renamed".to_string(),
+                ..passkey
+            },
+            user,
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.name, "Renamed");
+        assert_eq!(updated.sign_count, 1);
+    }
+}
@@ -1,51 +1,254 @@
+use std::sync::RwLock;
+
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
-    Argon2,
+    Algorithm, Argon2, KeyId, Params, ParamsBuilder, Version,
 };
+use once_cell::sync::Lazy;
+use security::{pepper_id, pepper_password, KdfParams, SecretBytes, SecretString};
+
+use crate::models::KdfSettings;
 
 use super::errors::AppError;
 
-pub fn password_hash(password: &str) -> Result<String, AppError> {
-    let salt = SaltString::generate(&mut OsRng); // Generate random salt
-    let argon2 = Argon2::default(); // Use standard Argon2id configuration
+// Parameters of `Argon2::default()`, which hashed every password before
+// they were configurable
+const DEFAULT_HASH_SETTINGS: KdfSettings = KdfSettings {
+    memory_cost: Params::DEFAULT_M_COST,
+    time_cost: Params::DEFAULT_T_COST,
+    parallelism: Params::DEFAULT_P_COST,
+};
 
-    // Hash the password with generated salt
-    let password_hash = argon2
-        .hash_password(password.as_bytes(), &salt)
-        .map_err(|e| AppError::from(e))?; // Convert argon2 error to AppError
+struct Pepper {
+    secret: SecretBytes,
+    id: [u8; security::PEPPER_ID_LENGTH],
+}
+
+static HASH_SETTINGS: Lazy<RwLock<KdfSettings>> = Lazy::new(|| RwLock::new(DEFAULT_HASH_SETTINGS));
 
-    // Extract salt and hash strings for storage
-    // let salt_str = salt.to_string();
-    let hash_str = password_hash.to_string();
+// Set from server config, never stored in the database
+static PEPPER: Lazy<RwLock<Option<Pepper>>> = Lazy::new(|| RwLock::new(None));
 
-    Ok(hash_str)
+/// Sets the Argon2id parameters new password hashes are made with. Hashes
+/// made with other parameters are replaced on the next login.
+pub fn set_password_hash_settings(settings: KdfSettings) -> Result<(), AppError> {
+    KdfParams::from(settings)
+        .validate()
+        .map_err(|_| AppError::InvalidPasswordConfig)?;
+    *HASH_SETTINGS.write().unwrap() = settings;
+    Ok(())
 }
 
-pub fn verify_password(
-    password: &str,
-    password_hash: &str,
-) -> Result<bool, argon2::password_hash::Error> {
-    // Parse the stored hash string
-    let parsed_hash = PasswordHash::new(password_hash);
+pub fn password_hash_settings() -> KdfSettings {
+    *HASH_SETTINGS.read().unwrap()
+}
+
+/// Sets the secret mixed into every password before it is hashed, or
+/// `None` to hash passwords as they are.
+pub fn set_password_pepper(pepper: Option<&[u8]>) -> Result<(), AppError> {
+    let pepper = match pepper {
+        Some(secret) => Some(Pepper {
+            id: pepper_id(secret).map_err(|_| AppError::InvalidPasswordConfig)?,
+            secret: secret.to_vec().into(),
+        }),
+        None => None,
+    };
+    *PEPPER.write().unwrap() = pepper;
+    Ok(())
+}
 
-    if parsed_hash.is_err() {
-        return Err(parsed_hash.unwrap_err());
+fn is_bcrypt(password_hash: &str) -> bool {
+    ["$2a$", "$2b$", "$2x$", "$2y$"]
+        .iter()
+        .any(|prefix| password_hash.starts_with(prefix))
+}
+
+fn peppered(password: &str, pepper: Option<&Pepper>) -> Result<SecretString, AppError> {
+    match pepper {
+        Some(pepper) => Ok(pepper_password(pepper.secret.expose_secret(), password)?),
+        None => Ok(password.into()),
     }
-    let parsed_hash = parsed_hash.unwrap();
+}
 
-    let res = Argon2::default().verify_password(password.as_bytes(), &parsed_hash);
-    if res.is_err() {
-        return Ok(false);
+fn hash_with(
+    password: &str,
+    settings: &KdfSettings,
+    pepper: Option<&Pepper>,
+) -> Result<String, AppError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let mut params = ParamsBuilder::new();
+    params
+        .m_cost(settings.memory_cost)
+        .t_cost(settings.time_cost)
+        .p_cost(settings.parallelism);
+    // The hash records which pepper it needs
+    if let Some(pepper) = pepper {
+        params.keyid(KeyId::new(&pepper.id)?);
     }
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params.build()?);
+    let password = peppered(password, pepper)?;
+    let password_hash = argon2.hash_password(password.expose_secret().as_bytes(), &salt)?;
+    Ok(password_hash.to_string())
+}
+
+fn verify_with(
+    password: &str,
+    password_hash: &str,
+    pepper: Option<&Pepper>,
+) -> Result<bool, AppError> {
+    // Hashes from before Argon2, never peppered
+    if is_bcrypt(password_hash) {
+        return Ok(bcrypt::verify(password, password_hash)?);
+    }
+
+    let parsed_hash = PasswordHash::new(password_hash)?;
+    let params = Params::try_from(&parsed_hash)?;
+    let pepper = match params.keyid() {
+        [] => None,
+        keyid => match pepper {
+            Some(pepper) if pepper.id == keyid => Some(pepper),
+            _ => {
+                return Err(AppError::PasswordHashError(
+                    "Password hash needs a pepper that is not configured".to_string(),
+                ))
+            }
+        },
+    };
+    let password = peppered(password, pepper)?;
+    let res = Argon2::default().verify_password(password.expose_secret().as_bytes(), &parsed_hash);
+    Ok(res.is_ok())
+}
+
+fn needs_rehash_with(password_hash: &str, settings: &KdfSettings, pepper: Option<&Pepper>) -> bool {
+    let Ok(parsed_hash) = PasswordHash::new(password_hash) else {
+        return true;
+    };
+    let Ok(params) = Params::try_from(&parsed_hash) else {
+        return true;
+    };
+    let current = parsed_hash.algorithm == Algorithm::Argon2id.ident()
+        && parsed_hash.version == Some(Version::V0x13.into())
+        && params.m_cost() == settings.memory_cost
+        && params.t_cost() == settings.time_cost
+        && params.p_cost() == settings.parallelism
+        && params.keyid() == pepper.map_or(&[][..], |pepper| &pepper.id[..]);
+    !current
+}
+
+pub fn password_hash(password: &str) -> Result<String, AppError> {
+    hash_with(
+        password,
+        &password_hash_settings(),
+        PEPPER.read().unwrap().as_ref(),
+    )
+}
 
-    Ok(true)
+/// Checks a password against an Argon2 hash, or a bcrypt hash left from
+/// older versions.
+pub fn verify_password(password: &str, password_hash: &str) -> Result<bool, AppError> {
+    verify_with(password, password_hash, PEPPER.read().unwrap().as_ref())
 }
 
+/// Whether a hash was made with another algorithm, parameters or pepper
+/// than new hashes are, so it should be replaced once the password is known.
+pub fn needs_rehash(password_hash: &str) -> bool {
+    needs_rehash_with(
+        password_hash,
+        &password_hash_settings(),
+        PEPPER.read().unwrap().as_ref(),
+    )
+}
+
+#[cfg(test)]
 mod tests {
+    use super::*;
+
+    const FAST: KdfSettings = KdfSettings {
+        memory_cost: 8192,
+        time_cost: 1,
+        parallelism: 1,
+    };
+
+    fn pepper(secret: &[u8]) -> Pepper {
+        Pepper {
+            secret: secret.to_vec().into(),
+            id: pepper_id(secret).unwrap(),
+        }
+    }
+
     #[test]
     fn test_password_hash() {
         let password = "XXXXXXXXX@1234";
         let hash = super::password_hash(password).unwrap();
         assert_eq!(super::verify_password(password, &hash).unwrap(), true);
     }
+
+    #[test]
+    fn test_default_hash_is_current() {
+        let hash = Argon2::default()
+            .hash_password(b"hunter2", &SaltString::generate(&mut OsRng))
+            .unwrap()
+            .to_string();
+
+        assert!(!needs_rehash_with(&hash, &DEFAULT_HASH_SETTINGS, None));
+        assert!(needs_rehash_with(&hash, &FAST, None));
+    }
+
+    #[test]
+    fn test_hash_settings() {
+        let hash = hash_with("hunter2", &FAST, None).unwrap();
+
+        assert!(hash.contains("m=8192,t=1,p=1"));
+        assert!(verify_with("hunter2", &hash, None).unwrap());
+        assert!(!verify_with("hunter3", &hash, None).unwrap());
+        assert!(!needs_rehash_with(&hash, &FAST, None));
+        assert!(needs_rehash_with(&hash, &DEFAULT_HASH_SETTINGS, None));
+
+        let argon2i = Argon2::new(Algorithm::Argon2i, Version::V0x13, Params::default())
+            .hash_password(b"hunter2", &SaltString::generate(&mut OsRng))
+            .unwrap()
+            .to_string();
+        assert!(verify_with("hunter2", &argon2i, None).unwrap());
+        assert!(needs_rehash_with(&argon2i, &DEFAULT_HASH_SETTINGS, None));
+    }
+
+    #[test]
+    fn test_invalid_hash_settings() {
+        let settings = KdfSettings {
+            memory_cost: 1024,
+            ..FAST
+        };
+        assert!(set_password_hash_settings(settings).is_err());
+        assert_eq!(password_hash_settings(), DEFAULT_HASH_SETTINGS);
+        assert!(set_password_pepper(Some(b"short")).is_err());
+    }
+
+    #[test]
+    fn test_pepper() {
+        let current = pepper(b"0123456789abcdef0123456789abcdef");
+        let other = pepper(b"fedcba9876543210fedcba9876543210");
+        let hash = hash_with("hunter2", &FAST, Some(&current)).unwrap();
+
+        assert!(hash.contains("keyid="));
+        assert!(verify_with("hunter2", &hash, Some(&current)).unwrap());
+        assert!(!verify_with("hunter3", &hash, Some(&current)).unwrap());
+        assert!(verify_with("hunter2", &hash, Some(&other)).is_err());
+        assert!(verify_with("hunter2", &hash, None).is_err());
+        assert!(!needs_rehash_with(&hash, &FAST, Some(&current)));
+        assert!(needs_rehash_with(&hash, &FAST, Some(&other)));
+
+        // Unpeppered hashes still verify, and get the pepper on rehash
+        let unpeppered = hash_with("hunter2", &FAST, None).unwrap();
+        assert!(verify_with("hunter2", &unpeppered, Some(&current)).unwrap());
+        assert!(needs_rehash_with(&unpeppered, &FAST, Some(&current)));
+    }
+
+    #[test]
+    fn test_legacy_bcrypt() {
+        let hash = bcrypt::hash("hunter2", 4).unwrap();
+
+        assert!(verify_with("hunter2", &hash, None).unwrap());
+        assert!(!verify_with("hunter3", &hash, None).unwrap());
+        assert!(needs_rehash_with(&hash, &FAST, None));
+    }
 }
@@ -0,0 +1,250 @@
+use rusqlite::{params, Connection};
+use security::{SecretBytes, SecretString};
+use sql::{FilterOperator, HttpQuery, OrderDirection, SqlError};
+
+use crate::models::{Login, PasswordHistory};
+
+use super::{
+    errors::AppError,
+    logins::{fetch_login, save_login},
+    time::now,
+    vault::{get_data_key, SealedItem},
+};
+
+pub const DEFAULT_HISTORY_LIMIT: i64 = 10;
+pub const MAX_HISTORY_LIMIT: i64 = 100;
+
+/// Number of previous passwords kept for a login.
+pub fn history_limit(login: &Login) -> i64 {
+    login
+        .history_limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(0, MAX_HISTORY_LIMIT)
+}
+
+/// Keeps `password` as the previous password of a login, sealed like the
+/// login itself.
+pub(crate) fn record_password(
+    conn: &Connection,
+    login_id: &str,
+    user: &str,
+    password: SecretString,
+    key: &SecretBytes,
+) -> Result<(), AppError> {
+    let mut entry = PasswordHistory {
+        id: Some(sql::get_ulid()),
+        created_at: Some(now() as i64),
+        created_by: Some(user.to_string()),
+        login_id: login_id.to_string(),
+        password,
+        ..Default::default()
+    };
+    entry.seal(key)?;
+    PasswordHistory::insert_with(conn, entry)?;
+    Ok(())
+}
+
+/// Drops all but the `limit` most recent previous passwords of a login.
+pub(crate) fn prune_password_history(
+    conn: &Connection,
+    login_id: &str,
+    limit: i64,
+) -> Result<(), AppError> {
+    conn.execute(
+        "DELETE FROM password_history WHERE login_id = ?1 AND id NOT IN \
+         (SELECT id FROM password_history WHERE login_id = ?1 \
+          ORDER BY created_at DESC, rowid DESC LIMIT ?2)",
+        params![login_id, limit],
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+pub(crate) fn delete_password_history(conn: &Connection, login_id: &str) -> Result<(), AppError> {
+    prune_password_history(conn, login_id, 0)
+}
+
+pub(crate) fn delete_history_entry(
+    conn: &Connection,
+    login_id: &str,
+    history_id: &str,
+) -> Result<(), AppError> {
+    conn.execute(
+        "DELETE FROM password_history WHERE id = ?1 AND login_id = ?2",
+        params![history_id, login_id],
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+// Entries belong to the owner of their login, which callers check
+fn history_of(login_id: &str) -> HttpQuery {
+    HttpQuery::builder()
+        .filter(|filter| {
+            filter
+                .column("login_id")
+                .operator(FilterOperator::Eq)
+                .value(login_id)
+                .build()
+        })
+        .order("created_at", OrderDirection::Desc)
+        .order("rowid", OrderDirection::Desc)
+        .build()
+}
+
+/// Lists the previous passwords of a login, most recent first.
+pub async fn get_password_history(
+    login_id: String,
+    user: String,
+) -> anyhow::Result<Vec<PasswordHistory>> {
+    // Fails for logins of other users
+    fetch_login(login_id.clone(), user.clone()).await?;
+    let key = get_data_key(&user)?;
+    let mut history = PasswordHistory::get_list(history_of(&login_id)).await?;
+    for entry in history.iter_mut() {
+        entry.open(&key)?;
+    }
+    Ok(history)
+}
+
+/// Puts a previous password back on its login.
+///
+/// The password it replaces goes to the history like on any other change,
+/// and the restored entry leaves it.
+pub async fn restore_password(
+    login_id: String,
+    history_id: String,
+    user: String,
+) -> anyhow::Result<Login> {
+    let entry = get_password_history(login_id.clone(), user.clone())
+        .await?
+        .into_iter()
+        .find(|entry| entry.id.as_deref() == Some(history_id.as_str()))
+        .ok_or(AppError::LibraryError(
+            "Password history entry not found".to_string(),
+        ))?;
+
+    let mut login = fetch_login(login_id.clone(), user.clone()).await?;
+    login.password = Some(entry.password);
+    // Leaves the history before it is pruned, so no other entry is dropped
+    save_login(login_id, login, user, Some(&history_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{
+        logins::{add_login, remove_login, update_login},
+        vault::setup_vault,
+    };
+
+    async fn change_password(login: &Login, password: &str, user: &str) -> Login {
+        let mut changed = login.clone();
+        changed.password = Some(password.into());
+        update_login(login.id.clone().unwrap(), changed, user.to_string())
+            .await
+            .unwrap()
+    }
+
+    fn passwords(history: &[PasswordHistory]) -> Vec<&str> {
+        history
+            .iter()
+            .map(|entry| entry.password.expose_secret())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_password_history() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+        setup_vault(&user, &"Master@12345".into()).await.unwrap();
+        let login = add_login(
+            Login {
+                name: "Example".to_string(),
+                username: "user".to_string(),
+                password: Some("first".into()),
+                history_limit: Some(2),
+                ..Default::default()
+            },
+            user.clone(),
+        )
+        .await
+        .unwrap();
+        let id = login.id.clone().unwrap();
+
+        // Edits that keep the password leave no trace
+        let mut renamed = login.clone();
+        renamed.name = "Renamed".to_string();
+        let login = update_login(id.clone(), renamed, user.clone())
+            .await
+            .unwrap();
+        assert!(get_password_history(id.clone(), user.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        let login = change_password(&login, "second", &user).await;
+        let login = change_password(&login, "third", &user).await;
+        let login = change_password(&login, "fourth", &user).await;
+        let history = get_password_history(id.clone(), user.clone())
+            .await
+            .unwrap();
+        assert_eq!(passwords(&history), vec!["third", "second"]);
+
+        // Stored sealed
+        let stored = PasswordHistory::get(history[0].id.clone().unwrap())
+            .await
+            .unwrap();
+        assert_ne!(stored.password.expose_secret(), "third");
+
+        // Other users can't read or restore it
+        let other = sql::get_ulid();
+        setup_vault(&other, &"Master@12345".into()).await.unwrap();
+        assert!(get_password_history(id.clone(), other.clone())
+            .await
+            .is_err());
+        let history_id = history[1].id.clone().unwrap();
+        assert!(restore_password(id.clone(), history_id.clone(), other)
+            .await
+            .is_err());
+
+        let restored = restore_password(id.clone(), history_id, user.clone())
+            .await
+            .unwrap();
+        assert_eq!(restored.password, Some("second".into()));
+        let history = get_password_history(id.clone(), user.clone())
+            .await
+            .unwrap();
+        assert_eq!(passwords(&history), vec!["fourth", "third"]);
+
+        // Restoring from a full history keeps every other entry
+        let history_id = history[0].id.clone().unwrap();
+        let restored = restore_password(id.clone(), history_id, user.clone())
+            .await
+            .unwrap();
+        assert_eq!(restored.password, Some("fourth".into()));
+        let history = get_password_history(id.clone(), user.clone())
+            .await
+            .unwrap();
+        assert_eq!(passwords(&history), vec!["second", "third"]);
+
+        let mut no_history = login.clone();
+        no_history.password = Some("second".into());
+        no_history.history_limit = Some(0);
+        update_login(id.clone(), no_history, user.clone())
+            .await
+            .unwrap();
+        assert!(get_password_history(id.clone(), user.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        change_password(&login, "fifth", &user).await;
+        remove_login(id.clone(), user.clone()).await.unwrap();
+        assert!(PasswordHistory::get_list(history_of(&id))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(remove_login(id.clone(), user.clone()).await.is_err());
+    }
+}
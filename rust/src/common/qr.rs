@@ -0,0 +1,934 @@
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression};
+
+use crate::models::{QrErrorCorrection, QrMatrix, QrOptions};
+
+use super::errors::AppError;
+
+pub const MAX_QUIET_ZONE: u32 = 64;
+pub const MAX_MODULE_SIZE: u32 = 64;
+
+const MAX_VERSION: usize = 40;
+
+// Weights of the mask penalty rules of ISO/IEC 18004 section 7.8.3
+const PENALTY_RUN: usize = 3;
+const PENALTY_BLOCK: usize = 3;
+const PENALTY_FINDER: usize = 40;
+const PENALTY_BALANCE: usize = 10;
+
+// Error correction codewords per block and number of blocks, by level
+// (low, medium, quartile, high) and version, index 0 is unused
+const ECC_CODEWORDS_PER_BLOCK: [[u8; MAX_VERSION + 1]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+const ERROR_CORRECTION_BLOCKS: [[u8; MAX_VERSION + 1]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+const ALPHANUMERIC_CHARSET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+impl QrErrorCorrection {
+    fn index(self) -> usize {
+        match self {
+            QrErrorCorrection::Low => 0,
+            QrErrorCorrection::Medium => 1,
+            QrErrorCorrection::Quartile => 2,
+            QrErrorCorrection::High => 3,
+        }
+    }
+
+    // The level as written in the format information
+    fn format_bits(self) -> u32 {
+        match self {
+            QrErrorCorrection::Low => 1,
+            QrErrorCorrection::Medium => 0,
+            QrErrorCorrection::Quartile => 3,
+            QrErrorCorrection::High => 2,
+        }
+    }
+}
+
+/// The densest mode that can hold the whole payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Numeric,
+    Alphanumeric,
+    Byte,
+}
+
+impl Mode {
+    fn of(payload: &[u8]) -> Self {
+        if payload.iter().all(u8::is_ascii_digit) {
+            Mode::Numeric
+        } else if payload
+            .iter()
+            .all(|byte| ALPHANUMERIC_CHARSET.contains(byte))
+        {
+            Mode::Alphanumeric
+        } else {
+            Mode::Byte
+        }
+    }
+
+    fn indicator(self) -> u32 {
+        match self {
+            Mode::Numeric => 0x1,
+            Mode::Alphanumeric => 0x2,
+            Mode::Byte => 0x4,
+        }
+    }
+
+    fn char_count_bits(self, version: usize) -> usize {
+        let widths = match self {
+            Mode::Numeric => [10, 12, 14],
+            Mode::Alphanumeric => [9, 11, 13],
+            Mode::Byte => [8, 16, 16],
+        };
+        widths[(version + 7) / 17]
+    }
+
+    fn data_bits(self, len: usize) -> usize {
+        match self {
+            Mode::Numeric => len / 3 * 10 + [0, 4, 7][len % 3],
+            Mode::Alphanumeric => len / 2 * 11 + len % 2 * 6,
+            Mode::Byte => len * 8,
+        }
+    }
+}
+
+#[derive(Default)]
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+    fn push(&mut self, value: u32, len: usize) {
+        self.0.extend((0..len).rev().map(|i| (value >> i) & 1 == 1));
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0
+            .chunks(8)
+            .map(|bits| {
+                bits.iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, &bit)| byte | (bit as u8) << (7 - i))
+            })
+            .collect()
+    }
+}
+
+// Modules left for data and error correction once the function patterns
+// are drawn
+fn raw_data_modules(version: usize) -> usize {
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignments = version / 7 + 2;
+        modules -= (25 * alignments - 10) * alignments - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+fn data_codewords(version: usize, level: QrErrorCorrection) -> usize {
+    raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[level.index()][version] as usize
+            * ERROR_CORRECTION_BLOCKS[level.index()][version] as usize
+}
+
+// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z = 0u32;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11d);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+// Generator polynomial of the Reed-Solomon code, highest coefficient
+// (always 1) left out
+fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut divisor = vec![0u8; degree];
+    divisor[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            divisor[j] = gf_mul(divisor[j], root);
+            if j + 1 < degree {
+                divisor[j] ^= divisor[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    divisor
+}
+
+fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut remainder = vec![0u8; divisor.len()];
+    for byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (r, d) in remainder.iter_mut().zip(divisor) {
+            *r ^= gf_mul(*d, factor);
+        }
+    }
+    remainder
+}
+
+// Segment, terminator and padding, filling every data codeword
+fn encode_data(payload: &[u8], mode: Mode, version: usize, capacity: usize) -> Vec<u8> {
+    let mut bits = BitBuffer::default();
+    bits.push(mode.indicator(), 4);
+    bits.push(payload.len() as u32, mode.char_count_bits(version));
+    match mode {
+        Mode::Numeric => {
+            for digits in payload.chunks(3) {
+                let value = digits
+                    .iter()
+                    .fold(0, |value, digit| value * 10 + (digit - b'0') as u32);
+                bits.push(value, digits.len() * 3 + 1);
+            }
+        }
+        Mode::Alphanumeric => {
+            let code = |byte: &u8| {
+                ALPHANUMERIC_CHARSET
+                    .iter()
+                    .position(|symbol| symbol == byte)
+                    .unwrap() as u32
+            };
+            for pair in payload.chunks(2) {
+                match pair {
+                    [first, second] => bits.push(code(first) * 45 + code(second), 11),
+                    [single] => bits.push(code(single), 6),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        Mode::Byte => {
+            for byte in payload {
+                bits.push(*byte as u32, 8);
+            }
+        }
+    }
+
+    let capacity_bits = capacity * 8;
+    let terminator = (capacity_bits - bits.0.len()).min(4);
+    bits.push(0, terminator);
+    bits.push(0, (8 - bits.0.len() % 8) % 8);
+    let mut data = bits.into_bytes();
+    for pad in [0xec, 0x11].into_iter().cycle() {
+        if data.len() == capacity {
+            break;
+        }
+        data.push(pad);
+    }
+    data
+}
+
+// Splits the data into blocks, adds their error correction and interleaves
+// the lot
+fn add_error_correction(data: &[u8], version: usize, level: QrErrorCorrection) -> Vec<u8> {
+    let blocks = ERROR_CORRECTION_BLOCKS[level.index()][version] as usize;
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[level.index()][version] as usize;
+    let raw_codewords = raw_data_modules(version) / 8;
+    let short_blocks = blocks - raw_codewords % blocks;
+    let short_len = raw_codewords / blocks;
+    let divisor = rs_divisor(ecc_len);
+
+    let mut split = Vec::with_capacity(blocks);
+    let mut offset = 0;
+    for i in 0..blocks {
+        let len = short_len - ecc_len + usize::from(i >= short_blocks);
+        let block = &data[offset..offset + len];
+        offset += len;
+        let mut codewords = block.to_vec();
+        // Keeps the columns aligned, skipped when interleaving
+        if i < short_blocks {
+            codewords.push(0);
+        }
+        codewords.extend(rs_remainder(block, &divisor));
+        split.push(codewords);
+    }
+
+    let mut interleaved = Vec::with_capacity(raw_codewords);
+    for i in 0..=short_len {
+        for (j, block) in split.iter().enumerate() {
+            if i != short_len - ecc_len || j >= short_blocks {
+                interleaved.push(block[i]);
+            }
+        }
+    }
+    interleaved
+}
+
+fn format_info(level: QrErrorCorrection, mask: u8) -> u32 {
+    let data = (level.format_bits() << 3) | mask as u32;
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    ((data << 10) | remainder) ^ 0x5412
+}
+
+fn version_info(version: usize) -> u32 {
+    let mut remainder = version as u32;
+    for _ in 0..12 {
+        remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1f25);
+    }
+    ((version as u32) << 12) | remainder
+}
+
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let size = version * 4 + 17;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+fn mask_bit(mask: u8, x: usize, y: usize) -> bool {
+    // Modules are flipped where the mask condition comes to zero
+    let condition = match mask {
+        0 => (x + y) % 2,
+        1 => y % 2,
+        2 => x % 3,
+        3 => (x + y) % 3,
+        4 => (x / 3 + y / 2) % 2,
+        5 => x * y % 2 + x * y % 3,
+        6 => (x * y % 2 + x * y % 3) % 2,
+        7 => ((x + y) % 2 + x * y % 3) % 2,
+        _ => unreachable!(),
+    };
+    condition == 0
+}
+
+struct Symbol {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+    // Modules of finder, timing, alignment and information patterns,
+    // which are never masked
+    function: Vec<bool>,
+}
+
+impl Symbol {
+    fn new(version: usize) -> Self {
+        let size = version * 4 + 17;
+        let mut symbol = Symbol {
+            version,
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+        symbol.draw_function_patterns();
+        symbol
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        for i in 0..self.size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        let far = self.size - 4;
+        for (x, y) in [(3, 3), (far, 3), (3, far)] {
+            self.draw_finder(x, y);
+        }
+
+        let positions = alignment_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // The corners taken by finder patterns
+                if (i, j) != (0, 0) && (i, j) != (0, last) && (i, j) != (last, 0) {
+                    self.draw_alignment(x, y);
+                }
+            }
+        }
+
+        // Reserves the format modules until the mask is known
+        self.draw_format(0);
+        self.draw_version();
+    }
+
+    // Finder pattern centred on (x, y) with its light separator
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4..=4isize {
+            for dx in -4..=4isize {
+                let (xx, yy) = (x as isize + dx, y as isize + dy);
+                if (0..self.size as isize).contains(&xx) && (0..self.size as isize).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2..=2isize {
+            for dx in -2..=2isize {
+                let distance = dx.abs().max(dy.abs());
+                self.set_function(
+                    (x as isize + dx) as usize,
+                    (y as isize + dy) as usize,
+                    distance != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format(&mut self, bits: u32) {
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+        let size = self.size;
+
+        // Around the top left finder
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // Split between the other two finders
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Always dark
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_info(self.version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 == 1;
+            let (a, b) = (self.size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    // Visits the data modules in placement order: two module wide columns
+    // from the right, zigzagging up and down, skipping the vertical timing
+    // pattern
+    fn data_positions(&self) -> Vec<(usize, usize)> {
+        let mut positions = Vec::new();
+        let mut right = self.size as isize - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..self.size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let y = if upward {
+                        self.size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    if !self.function[y * self.size + x] {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            right -= 2;
+        }
+        positions
+    }
+
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        for (i, (x, y)) in self.data_positions().into_iter().enumerate() {
+            // Remainder bits stay light
+            self.modules[y * self.size + x] = codewords
+                .get(i / 8)
+                .is_some_and(|byte| (byte >> (7 - i % 8)) & 1 == 1);
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.function[y * self.size + x] && mask_bit(mask, x, y) {
+                    self.modules[y * self.size + x] ^= true;
+                }
+            }
+        }
+    }
+
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let rows = (0..size).map(|y| (0..size).map(|x| self.get(x, y)).collect::<Vec<_>>());
+        let columns = (0..size).map(|x| (0..size).map(|y| self.get(x, y)).collect::<Vec<_>>());
+        let mut penalty = 0;
+
+        for line in rows.chain(columns) {
+            // Runs of five or more modules of one colour
+            let mut run = 1;
+            for i in 1..=size {
+                if i < size && line[i] == line[i - 1] {
+                    run += 1;
+                    continue;
+                }
+                if run >= 5 {
+                    penalty += PENALTY_RUN + run - 5;
+                }
+                run = 1;
+            }
+
+            // Patterns that look like finders, dark 1:1:3:1:1 next to four
+            // light modules
+            const FINDER: [bool; 7] = [true, false, true, true, true, false, true];
+            for window in line.windows(11) {
+                if (window[..4].iter().all(|dark| !dark) && window[4..] == FINDER)
+                    || (window[..7] == FINDER && window[7..].iter().all(|dark| !dark))
+                {
+                    penalty += PENALTY_FINDER;
+                }
+            }
+        }
+
+        // 2x2 blocks of one colour
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.get(x, y);
+                if dark == self.get(x + 1, y)
+                    && dark == self.get(x, y + 1)
+                    && dark == self.get(x + 1, y + 1)
+                {
+                    penalty += PENALTY_BLOCK;
+                }
+            }
+        }
+
+        // Every 5% the dark share is off 50%
+        let dark = self.modules.iter().filter(|dark| **dark).count();
+        let total = size * size;
+        let deviation = (dark * 20).abs_diff(total * 10);
+        penalty += deviation.div_ceil(total).saturating_sub(1) * PENALTY_BALANCE;
+        penalty
+    }
+}
+
+/// Encodes a payload in the smallest QR code that holds it at the
+/// requested error correction level, with the mask that is easiest to scan.
+pub fn encode_qr(payload: &[u8], level: QrErrorCorrection) -> Result<QrMatrix, AppError> {
+    let mode = Mode::of(payload);
+    let (version, capacity) = (1..=MAX_VERSION)
+        .map(|version| (version, data_codewords(version, level)))
+        .find(|&(version, capacity)| {
+            payload.len() < 1 << mode.char_count_bits(version)
+                && 4 + mode.char_count_bits(version) + mode.data_bits(payload.len()) <= capacity * 8
+        })
+        .ok_or_else(|| {
+            AppError::QRCodeGenerationError("Payload is too long for a QR code".to_string())
+        })?;
+
+    let data = encode_data(payload, mode, version, capacity);
+    let codewords = add_error_correction(&data, version, level);
+
+    let mut symbol = Symbol::new(version);
+    symbol.draw_codewords(&codewords);
+    let mask = (0..8u8)
+        .min_by_key(|&mask| {
+            symbol.apply_mask(mask);
+            symbol.draw_format(format_info(level, mask));
+            let penalty = symbol.penalty();
+            symbol.apply_mask(mask);
+            penalty
+        })
+        .unwrap();
+    symbol.apply_mask(mask);
+    symbol.draw_format(format_info(level, mask));
+
+    Ok(QrMatrix {
+        size: symbol.size as u32,
+        modules: symbol.modules,
+    })
+}
+
+fn check_options(options: &QrOptions) -> Result<(), AppError> {
+    if options.quiet_zone > MAX_QUIET_ZONE {
+        return Err(AppError::QRCodeGenerationError(format!(
+            "Quiet zone must be at most {} modules",
+            MAX_QUIET_ZONE
+        )));
+    }
+    if !(1..=MAX_MODULE_SIZE).contains(&options.module_size) {
+        return Err(AppError::QRCodeGenerationError(format!(
+            "Module size must be between 1 and {} pixels",
+            MAX_MODULE_SIZE
+        )));
+    }
+    Ok(())
+}
+
+pub fn qr_matrix(payload: &[u8], options: &QrOptions) -> Result<QrMatrix, AppError> {
+    check_options(options)?;
+    encode_qr(payload, options.error_correction)
+}
+
+/// Draws a QR code as an SVG image, one path for all dark modules.
+pub fn qr_svg(payload: &[u8], options: &QrOptions) -> Result<String, AppError> {
+    let matrix = qr_matrix(payload, options)?;
+    let size = matrix.size as usize;
+    let quiet_zone = options.quiet_zone as usize;
+    let dimension = size + 2 * quiet_zone;
+    let pixels = dimension * options.module_size as usize;
+
+    let mut path = String::new();
+    for (i, _) in matrix.modules.iter().enumerate().filter(|(_, dark)| **dark) {
+        if !path.is_empty() {
+            path.push(' ');
+        }
+        path.push_str(&format!(
+            "M{},{}h1v1h-1z",
+            i % size + quiet_zone,
+            i / size + quiet_zone
+        ));
+    }
+
+    Ok(format!(
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" ",
+            "width=\"{pixels}\" height=\"{pixels}\" viewBox=\"0 0 {dimension} {dimension}\" ",
+            "shape-rendering=\"crispEdges\">",
+            "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>",
+            "<path d=\"{path}\" fill=\"#000000\"/>",
+            "</svg>"
+        ),
+        pixels = pixels,
+        dimension = dimension,
+        path = path,
+    ))
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&crc.finalize().to_be_bytes());
+}
+
+/// Draws a QR code as a black and white PNG image.
+pub fn qr_png(payload: &[u8], options: &QrOptions) -> Result<Vec<u8>, AppError> {
+    let matrix = qr_matrix(payload, options)?;
+    let size = matrix.size as usize;
+    let quiet_zone = options.quiet_zone as usize;
+    let module_size = options.module_size as usize;
+    let pixels = (size + 2 * quiet_zone) * module_size;
+
+    // One bit per pixel, set for white, each row led by filter type 0
+    let row_len = 1 + pixels.div_ceil(8);
+    let mut rows = Vec::with_capacity(row_len * pixels);
+    for py in 0..pixels {
+        let mut row = vec![0u8; row_len];
+        for px in 0..pixels {
+            let (x, y) = (px / module_size, py / module_size);
+            let dark = (quiet_zone..quiet_zone + size).contains(&x)
+                && (quiet_zone..quiet_zone + size).contains(&y)
+                && matrix.modules[(y - quiet_zone) * size + x - quiet_zone];
+            if !dark {
+                row[1 + px / 8] |= 0x80 >> (px % 8);
+            }
+        }
+        rows.extend(row);
+    }
+
+    let io_error = |e: std::io::Error| AppError::QRCodeGenerationError(e.to_string());
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&rows).map_err(io_error)?;
+    let compressed = encoder.finish().map_err(io_error)?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(pixels as u32).to_be_bytes());
+    header.extend_from_slice(&(pixels as u32).to_be_bytes());
+    // Bit depth 1, greyscale, deflate, adaptive filters, no interlace
+    header.extend_from_slice(&[1, 0, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &compressed);
+    png_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::ZlibDecoder;
+
+    use super::*;
+
+    const LEVELS: [QrErrorCorrection; 4] = [
+        QrErrorCorrection::Low,
+        QrErrorCorrection::Medium,
+        QrErrorCorrection::Quartile,
+        QrErrorCorrection::High,
+    ];
+
+    // Reads the codewords back out of a matrix, finding the mask from the
+    // format information
+    fn read_codewords(matrix: &QrMatrix, level: QrErrorCorrection) -> (usize, Vec<u8>) {
+        let version = (matrix.size as usize - 17) / 4;
+        let mut symbol = Symbol::new(version);
+        let mask = (0..8)
+            .find(|&mask| {
+                symbol.draw_format(format_info(level, mask));
+                (0..symbol.size * symbol.size)
+                    .filter(|&i| symbol.function[i])
+                    .all(|i| symbol.modules[i] == matrix.modules[i])
+            })
+            .expect("function patterns and format information match");
+
+        let mut codewords = vec![0u8; raw_data_modules(version) / 8];
+        for (i, (x, y)) in symbol.data_positions().into_iter().enumerate() {
+            let dark = matrix.modules[y * symbol.size + x] ^ mask_bit(mask, x, y);
+            if dark && i / 8 < codewords.len() {
+                codewords[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        (version, codewords)
+    }
+
+    // Undoes the interleaving, checking the error correction of each block
+    fn data_of(codewords: &[u8], version: usize, level: QrErrorCorrection) -> Vec<u8> {
+        let blocks = ERROR_CORRECTION_BLOCKS[level.index()][version] as usize;
+        let ecc_len = ECC_CODEWORDS_PER_BLOCK[level.index()][version] as usize;
+        let short_blocks = blocks - codewords.len() % blocks;
+        let short_len = codewords.len() / blocks;
+
+        let mut split = vec![Vec::new(); blocks];
+        let mut codewords = codewords.iter();
+        for i in 0..=short_len {
+            for (j, block) in split.iter_mut().enumerate() {
+                if i != short_len - ecc_len || j >= short_blocks {
+                    block.push(*codewords.next().unwrap());
+                }
+            }
+        }
+
+        let divisor = rs_divisor(ecc_len);
+        let mut data = Vec::new();
+        for block in split {
+            let (block_data, ecc) = block.split_at(block.len() - ecc_len);
+            assert_eq!(rs_remainder(block_data, &divisor), ecc);
+            data.extend_from_slice(block_data);
+        }
+        data
+    }
+
+    // Reads a byte mode segment
+    fn byte_payload(data: &[u8], version: usize) -> Vec<u8> {
+        let bit = |i: usize| (data[i / 8] >> (7 - i % 8)) & 1;
+        let read =
+            |from: usize, len: usize| (from..from + len).fold(0, |v, i| (v << 1) | bit(i) as usize);
+        assert_eq!(read(0, 4), 0x4);
+        let count_bits = Mode::Byte.char_count_bits(version);
+        let len = read(4, count_bits);
+        (0..len)
+            .map(|i| read(4 + count_bits + i * 8, 8) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_reed_solomon() {
+        // "HELLO WORLD" at 1-Q, from the thonky.com QR code tutorial
+        let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236];
+        let ecc = [168, 72, 22, 82, 217, 54, 156, 0, 46, 15, 180, 122, 16];
+        assert_eq!(rs_remainder(&data, &rs_divisor(13)), ecc);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let expected = [(1, [19, 16, 13, 9]), (40, [2956, 2334, 1666, 1276])];
+        for (version, capacities) in expected {
+            for (level, capacity) in LEVELS.iter().zip(capacities) {
+                assert_eq!(data_codewords(version, *level), capacity);
+            }
+        }
+        assert_eq!(raw_data_modules(1) / 8, 26);
+        assert_eq!(raw_data_modules(7) / 8, 196);
+        assert_eq!(raw_data_modules(40) / 8, 3706);
+    }
+
+    #[test]
+    fn test_format_and_version_info() {
+        assert_eq!(format_info(QrErrorCorrection::Low, 0), 0b111011111000100);
+        assert_eq!(format_info(QrErrorCorrection::Low, 4), 0b110011000101111);
+        assert_eq!(format_info(QrErrorCorrection::Medium, 0), 0b101010000010010);
+        assert_eq!(
+            format_info(QrErrorCorrection::Quartile, 0),
+            0b011010101011111
+        );
+        assert_eq!(format_info(QrErrorCorrection::High, 0), 0b001011010001001);
+        assert_eq!(version_info(7), 0b000111110010010100);
+        assert_eq!(alignment_positions(7), [6, 22, 38]);
+        assert_eq!(alignment_positions(32), [6, 34, 60, 86, 112, 138]);
+    }
+
+    #[test]
+    fn test_hello_world() {
+        let payload = b"HELLO WORLD";
+        assert_eq!(Mode::of(payload), Mode::Alphanumeric);
+        assert_eq!(
+            encode_data(payload, Mode::Alphanumeric, 1, 13),
+            [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236]
+        );
+
+        let matrix = encode_qr(payload, QrErrorCorrection::Quartile).unwrap();
+        assert_eq!(matrix.size, 21);
+        // Dark module next to the bottom left finder
+        assert!(matrix.modules[(21 - 8) * 21 + 8]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let payloads = [
+            "otpauth://totp/Indidus:alice?secret=JBSWY3DPEHPK3PXP&issuer=Indidus".to_string(),
+            "WIFI:T:WPA;S:Home network;P:correct horse battery staple;;".to_string(),
+            "é".repeat(400),
+        ];
+        for payload in payloads {
+            for level in LEVELS {
+                let matrix = encode_qr(payload.as_bytes(), level).unwrap();
+                let (version, codewords) = read_codewords(&matrix, level);
+                let data = data_of(&codewords, version, level);
+                assert_eq!(byte_payload(&data, version), payload.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn test_version_selection() {
+        let matrix = encode_qr(&[b'a'; 2953], QrErrorCorrection::Low).unwrap();
+        assert_eq!(matrix.size, 177);
+        assert!(encode_qr(&[b'a'; 2954], QrErrorCorrection::Low).is_err());
+        assert!(encode_qr(&[b'a'; 1274], QrErrorCorrection::High).is_err());
+
+        // Numeric payloads pack 3 digits in 10 bits
+        assert_eq!(
+            encode_qr(&[b'7'; 41], QrErrorCorrection::Low).unwrap().size,
+            21
+        );
+        assert_eq!(
+            encode_qr(&[b'7'; 42], QrErrorCorrection::Low).unwrap().size,
+            25
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let options = QrOptions::default();
+        let svg = qr_svg(b"HELLO WORLD", &options).unwrap();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("viewBox=\"0 0 29 29\""));
+        assert!(svg.contains("width=\"232\""));
+        // Top left corner of the top left finder
+        assert!(svg.contains("\"M4,4h1v1h-1z "));
+    }
+
+    #[test]
+    fn test_png() {
+        let options = QrOptions {
+            module_size: 2,
+            ..Default::default()
+        };
+        let png = qr_png(b"HELLO WORLD", &options).unwrap();
+        let pixels = (21 + 2 * 4) * 2;
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..20], &(pixels as u32).to_be_bytes());
+        assert_eq!(&png[24..29], &[1, 0, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut rows = Vec::new();
+        ZlibDecoder::new(&png[41..41 + idat_len])
+            .read_to_end(&mut rows)
+            .unwrap();
+        let row_len = 1 + pixels / 8 + 1;
+        assert_eq!(rows.len(), row_len * pixels);
+
+        let white = |x: usize, y: usize| rows[y * row_len + 1 + x / 8] & (0x80 >> (x % 8)) != 0;
+        assert!(white(0, 0));
+        assert!(white(7, 7));
+        assert!(!white(8, 8));
+        assert!(!white(9, 9));
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let options = |quiet_zone, module_size| QrOptions {
+            quiet_zone,
+            module_size,
+            ..Default::default()
+        };
+        assert!(qr_svg(b"x", &options(MAX_QUIET_ZONE + 1, 8)).is_err());
+        assert!(qr_png(b"x", &options(4, 0)).is_err());
+        assert!(qr_png(b"x", &options(4, MAX_MODULE_SIZE + 1)).is_err());
+        assert!(qr_png(b"x", &options(0, 1)).is_ok());
+    }
+}
@@ -0,0 +1,251 @@
+use rusqlite::{Connection, OptionalExtension};
+use security::SecretString;
+use sql::SqlError;
+
+use crate::models::{Claims, JwtTokens, RefreshTokenClaims};
+
+use super::{
+    errors::AppError,
+    jwt::{
+        encode_access_claims, encode_refresh_claims, get_access_claims, get_refresh_claims,
+        ACCESS_TOKEN_LIFETIME, REFRESH_TOKEN_LIFETIME,
+    },
+    time::now,
+};
+
+// A refresh token as it is recorded when issued
+struct StoredRefreshToken {
+    family_id: String,
+    user_id: String,
+    revoked_at: Option<i64>,
+}
+
+// Returns the jti of the new token along with it
+fn record_refresh_token(
+    conn: &Connection,
+    user_id: &str,
+    family_id: &str,
+) -> Result<(String, SecretString), AppError> {
+    let n = now();
+    let claims = RefreshTokenClaims {
+        jti: uuid::Uuid::new_v4().to_string(),
+        uid: user_id.to_string(),
+        iat: n,
+        exp: n + REFRESH_TOKEN_LIFETIME,
+    };
+    conn.execute(
+        "INSERT INTO refresh_tokens (jti, family_id, user_id, issued_at, expires_at) \
+         VALUES (?, ?, ?, ?, ?)",
+        (
+            &claims.jti,
+            family_id,
+            user_id,
+            claims.iat as i64,
+            claims.exp as i64,
+        ),
+    )
+    .map_err(SqlError::from)?;
+    let refresh_token = encode_refresh_claims(&claims)?;
+    Ok((claims.jti, refresh_token.into()))
+}
+
+// Access tokens carry the epoch of their user, bumping it revokes them all
+fn token_epoch(conn: &Connection, user_id: &str) -> Result<i64, AppError> {
+    let epoch = conn
+        .query_row(
+            "SELECT token_epoch FROM users WHERE id = ?",
+            [user_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(SqlError::from)?;
+    Ok(epoch.unwrap_or_default())
+}
+
+fn sign_access_token(conn: &Connection, user_id: &str) -> Result<SecretString, AppError> {
+    let n = now();
+    let claims = Claims {
+        iat: n,
+        exp: n + ACCESS_TOKEN_LIFETIME,
+        uid: user_id.to_string(),
+        epoch: token_epoch(conn, user_id)?,
+    };
+    Ok(encode_access_claims(&claims)?.into())
+}
+
+/// Checks an access token, which also stops working once its user is
+/// signed out everywhere with [`revoke_sessions`].
+pub fn check_access_token(token: &str) -> Result<Claims, AppError> {
+    let claims = get_access_claims(token)?;
+    let conn = sql::get_db_connection()?;
+    if claims.epoch != token_epoch(&conn, &claims.uid)? {
+        return Err(AppError::Unauthorized);
+    }
+    Ok(claims)
+}
+
+/// Signs a user in with a new token family. Every refresh token issued from
+/// here on by [`rotate_refresh_token`] belongs to the same family.
+pub(crate) fn issue_tokens(user_id: &str) -> Result<(SecretString, SecretString), AppError> {
+    let conn = sql::get_db_connection()?;
+    // Expired tokens no longer pass validation, their rows are not needed
+    conn.execute(
+        "DELETE FROM refresh_tokens WHERE user_id = ? AND expires_at <= ?",
+        (user_id, now() as i64),
+    )
+    .map_err(SqlError::from)?;
+    let family_id = uuid::Uuid::new_v4().to_string();
+    let (_, refresh_token) = record_refresh_token(&conn, user_id, &family_id)?;
+    let access_token = sign_access_token(&conn, user_id)?;
+    Ok((access_token, refresh_token))
+}
+
+/// Trades a refresh token for a new access token and a new refresh token.
+/// Each refresh token works once: presenting a used one again means it was
+/// stolen, so the whole family is revoked and its holder has to sign in.
+pub fn rotate_refresh_token(refresh_token: &str) -> Result<JwtTokens, AppError> {
+    let claims = get_refresh_claims(refresh_token)?;
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+
+    let stored = tx
+        .query_row(
+            "SELECT family_id, user_id, revoked_at FROM refresh_tokens WHERE jti = ?",
+            [&claims.jti],
+            |row| {
+                Ok(StoredRefreshToken {
+                    family_id: row.get(0)?,
+                    user_id: row.get(1)?,
+                    revoked_at: row.get(2)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(SqlError::from)?
+        .filter(|stored| stored.user_id == claims.uid)
+        .ok_or(AppError::RefreshTokenInvalid)?;
+    if stored.revoked_at.is_some() {
+        return Err(reuse_detected(tx, &stored.family_id));
+    }
+
+    let n = now() as i64;
+    let (next_jti, next) = record_refresh_token(&tx, &stored.user_id, &stored.family_id)?;
+    // Only one rotation wins when the same token is presented twice at once
+    let rotated = tx
+        .execute(
+            "UPDATE refresh_tokens SET revoked_at = ?, replaced_by = ? \
+             WHERE jti = ? AND revoked_at IS NULL",
+            (n, &next_jti, &claims.jti),
+        )
+        .map_err(SqlError::from)?;
+    if rotated == 0 {
+        return Err(reuse_detected(tx, &stored.family_id));
+    }
+    let access_token = sign_access_token(&tx, &stored.user_id)?;
+    tx.commit().map_err(SqlError::from)?;
+
+    Ok(JwtTokens {
+        access_token,
+        refresh_token: next,
+        recovery_key: None,
+    })
+}
+
+// Revokes every token of the family, and what the caller gets back
+fn reuse_detected(tx: rusqlite::Transaction, family_id: &str) -> AppError {
+    let revoked = tx
+        .execute(
+            "UPDATE refresh_tokens SET revoked_at = ? \
+             WHERE family_id = ? AND revoked_at IS NULL",
+            (now() as i64, family_id),
+        )
+        .and_then(|_| tx.commit());
+    match revoked {
+        Ok(()) => AppError::RefreshTokenReused,
+        Err(e) => SqlError::from(e).into(),
+    }
+}
+
+/// Signs a user out everywhere, every refresh token and access token
+/// issued so far stops working.
+pub fn revoke_sessions(user_id: &str) -> Result<(), AppError> {
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    tx.execute(
+        "UPDATE refresh_tokens SET revoked_at = ? WHERE user_id = ? AND revoked_at IS NULL",
+        (now() as i64, user_id),
+    )
+    .map_err(SqlError::from)?;
+    tx.execute(
+        "UPDATE users SET token_epoch = token_epoch + 1 WHERE id = ?",
+        [user_id],
+    )
+    .map_err(SqlError::from)?;
+    tx.commit().map_err(SqlError::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn family_revoked(refresh_token: &SecretString) -> bool {
+        let jti = get_refresh_claims(refresh_token.expose_secret())
+            .unwrap()
+            .jti;
+        let conn = sql::get_db_connection().unwrap();
+        conn.query_row(
+            "SELECT COUNT(*) = 0 FROM refresh_tokens WHERE revoked_at IS NULL AND family_id = \
+             (SELECT family_id FROM refresh_tokens WHERE jti = ?)",
+            [jti],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rotate_refresh_token() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+        let (_, first) = issue_tokens(&user).unwrap();
+
+        let second = rotate_refresh_token(first.expose_secret()).unwrap();
+        assert_ne!(second.refresh_token, first);
+        let third = rotate_refresh_token(second.refresh_token.expose_secret()).unwrap();
+        assert!(!family_revoked(&third.refresh_token));
+
+        // Another login is a family of its own
+        let (_, other) = issue_tokens(&user).unwrap();
+
+        // Replaying a used token revokes the family, the latest token too
+        assert!(matches!(
+            rotate_refresh_token(first.expose_secret()),
+            Err(AppError::RefreshTokenReused)
+        ));
+        assert!(family_revoked(&third.refresh_token));
+        assert!(matches!(
+            rotate_refresh_token(third.refresh_token.expose_secret()),
+            Err(AppError::RefreshTokenReused)
+        ));
+        assert!(!family_revoked(&other));
+        rotate_refresh_token(other.expose_secret()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unknown_refresh_tokens() {
+        crate::common::test_utils::init_test_db().await;
+        let user = sql::get_ulid();
+
+        // Signed, but never recorded
+        let unrecorded = super::super::jwt::generate_refresh_token(user.clone(), 60).unwrap();
+        assert!(matches!(
+            rotate_refresh_token(&unrecorded),
+            Err(AppError::RefreshTokenInvalid)
+        ));
+        assert!(rotate_refresh_token("invalid.token.here").is_err());
+
+        let (_, refresh_token) = issue_tokens(&user).unwrap();
+        revoke_sessions(&user).unwrap();
+        assert!(rotate_refresh_token(refresh_token.expose_secret()).is_err());
+    }
+}
@@ -0,0 +1,1041 @@
+use std::collections::HashMap;
+
+use chrono::Datelike;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::models::{PasswordFeedback, PasswordStrength};
+
+use super::generator::WORDLIST;
+
+// Longer passwords are rated on their first characters, the rest only adds
+// brute force guesses
+const MAX_ANALYZED_LENGTH: usize = 100;
+
+const BRUTEFORCE_CARDINALITY: f64 = 10.0;
+const MIN_GUESSES_SINGLE_CHAR: f64 = 10.0;
+const MIN_GUESSES_MULTI_CHAR: f64 = 50.0;
+// Keeps the estimate from favouring many small matches over a few big ones
+const MATCH_PENALTY: f64 = 10000.0;
+
+// Words of the embedded wordlist are ranked as fairly common words
+const ENGLISH_WORD_RANK: f64 = 5000.0;
+
+// Years close to now are guessed first
+const MIN_YEAR_SPACE: f64 = 20.0;
+
+// Offline attack on a slow hash such as Argon2 or bcrypt
+const GUESSES_PER_SECOND: f64 = 1e4;
+
+// Upper bounds on guesses for scores 0 to 3
+const SCORE_THRESHOLDS: [f64; 4] = [1e3, 1e6, 1e8, 1e10];
+
+static COMMON_PASSWORDS: Lazy<HashMap<&'static str, usize>> =
+    Lazy::new(|| ranked(include_str!("passwords.txt").lines()));
+
+static ENGLISH_WORDS: Lazy<HashMap<&'static str, usize>> =
+    Lazy::new(|| ranked(WORDLIST.iter().copied()));
+
+static KEYBOARDS: Lazy<[Keyboard; 2]> = Lazy::new(|| {
+    [
+        Keyboard::new(
+            "qwerty",
+            &[
+                "`1234567890-=",
+                " qwertyuiop[]\\",
+                " asdfghjkl;'",
+                " zxcvbnm,./",
+            ],
+            &[
+                "~!@#$%^&*()_+",
+                " QWERTYUIOP{}|",
+                " ASDFGHJKL:\"",
+                " ZXCVBNM<>?",
+            ],
+            true,
+        ),
+        Keyboard::new("keypad", &[" /*-", "789+", "456+", "123", "0."], &[], false),
+    ]
+});
+
+static SEPARATED_DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^([0-9]{1,4})[\s/\\_.-]([0-9]{1,2})[\s/\\_.-]([0-9]{1,4})$").unwrap()
+});
+
+// Substitutions people make for letters, the first letter is tried first
+const L33T: &[(char, &[char])] = &[
+    ('4', &['a']),
+    ('@', &['a']),
+    ('8', &['b']),
+    ('(', &['c']),
+    ('3', &['e']),
+    ('6', &['g']),
+    ('1', &['i', 'l']),
+    ('!', &['i']),
+    ('|', &['i', 'l']),
+    ('0', &['o']),
+    ('$', &['s']),
+    ('5', &['s']),
+    ('7', &['t']),
+    ('+', &['t']),
+    ('2', &['z']),
+];
+
+fn ranked<'a>(words: impl Iterator<Item = &'a str>) -> HashMap<&'a str, usize> {
+    let mut ranks = HashMap::new();
+    for (index, word) in words.enumerate() {
+        ranks.entry(word).or_insert(index + 1);
+    }
+    ranks
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Pattern {
+    Dictionary {
+        source: Source,
+        rank: usize,
+        reversed: bool,
+        l33t: bool,
+    },
+    Spatial {
+        keyboard: &'static str,
+        turns: usize,
+    },
+    Repeat {
+        unit: String,
+    },
+    Sequence {
+        ascending: bool,
+    },
+    Date {
+        year_only: bool,
+    },
+    Bruteforce,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Passwords,
+    English,
+    UserInputs,
+}
+
+#[derive(Debug, Clone)]
+struct Match {
+    // Inclusive character positions of the token
+    i: usize,
+    j: usize,
+    token: String,
+    pattern: Pattern,
+    guesses: f64,
+}
+
+/// Estimates how many guesses an attacker needs to find `password`.
+///
+/// The password is split into the most guessable sequence of dictionary
+/// words, keyboard patterns, repeats, sequences and dates, the way zxcvbn
+/// does. `user_inputs` such as the name and email of the user count as the
+/// most likely words of all.
+pub fn estimate_strength(password: &str, user_inputs: &[&str]) -> PasswordStrength {
+    let chars = password.chars().collect::<Vec<_>>();
+    let analyzed = &chars[..chars.len().min(MAX_ANALYZED_LENGTH)];
+    let inputs = user_input_ranks(user_inputs);
+
+    let (mut guesses, sequence) = most_guessable_sequence(analyzed, &inputs);
+    let unanalyzed = chars.len() - analyzed.len();
+    guesses *= BRUTEFORCE_CARDINALITY.powi(unanalyzed as i32);
+    let guesses = guesses.min(f64::MAX);
+
+    let score = SCORE_THRESHOLDS
+        .iter()
+        .take_while(|threshold| guesses >= **threshold)
+        .count() as u8;
+    let crack_time_seconds = guesses / GUESSES_PER_SECOND;
+
+    PasswordStrength {
+        score,
+        guesses,
+        guesses_log10: guesses.log10(),
+        crack_time_seconds,
+        crack_time_display: display_time(crack_time_seconds),
+        feedback: feedback(score, &sequence),
+    }
+}
+
+// Splits user inputs into lowercase words, so "jane.doe@example.com" matches
+// "jane.doe" as well as "jane"
+fn user_input_ranks(user_inputs: &[&str]) -> HashMap<String, usize> {
+    let mut ranks = HashMap::new();
+    let mut rank = 1;
+    for input in user_inputs {
+        let input = input.to_lowercase();
+        let parts = std::iter::once(input.as_str())
+            .chain(input.split(|c: char| c == '@' || c.is_whitespace()))
+            .chain(input.split(|c: char| !c.is_alphanumeric()))
+            .filter(|part| part.chars().count() >= 3);
+        for part in parts {
+            ranks.entry(part.to_string()).or_insert_with(|| {
+                rank += 1;
+                rank - 1
+            });
+        }
+    }
+    ranks
+}
+
+fn most_guessable_sequence(
+    chars: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> (f64, Vec<Match>) {
+    let n = chars.len();
+    if n == 0 {
+        return (1.0, Vec::new());
+    }
+    let matches = omnimatch(chars, user_inputs);
+
+    // best[k][l] is the lowest product of guesses covering the first k
+    // characters with l matches, from[k][l] the match that got there
+    let mut best = vec![vec![f64::INFINITY; n + 1]; n + 1];
+    let mut from: Vec<Vec<Option<Match>>> = vec![vec![None; n + 1]; n + 1];
+    best[0][0] = 1.0;
+    for k in 1..=n {
+        let candidates = matches
+            .iter()
+            .filter(|m| m.j + 1 == k)
+            .cloned()
+            .chain((0..k).map(|i| bruteforce_match(chars, i, k - 1)));
+        for candidate in candidates {
+            for l in 0..n {
+                let product = best[candidate.i][l] * candidate.guesses;
+                if product < best[k][l + 1] {
+                    best[k][l + 1] = product;
+                    from[k][l + 1] = Some(candidate.clone());
+                }
+            }
+        }
+    }
+
+    let mut optimal = (f64::INFINITY, 0);
+    let mut factorial = 1.0;
+    for (l, product) in best[n].iter().enumerate().skip(1) {
+        factorial *= l as f64;
+        let guesses = factorial * product + MATCH_PENALTY.powi(l as i32 - 1);
+        if guesses < optimal.0 {
+            optimal = (guesses, l);
+        }
+    }
+
+    let (guesses, mut l) = optimal;
+    let mut sequence = Vec::with_capacity(l);
+    let mut k = n;
+    while l > 0 {
+        let m = from[k][l].clone().expect("every prefix has a sequence");
+        k = m.i;
+        l -= 1;
+        sequence.push(m);
+    }
+    sequence.reverse();
+    (guesses, sequence)
+}
+
+fn bruteforce_match(chars: &[char], i: usize, j: usize) -> Match {
+    let length = j - i + 1;
+    let minimum = match length {
+        1 => MIN_GUESSES_SINGLE_CHAR,
+        _ => MIN_GUESSES_MULTI_CHAR,
+    } + 1.0;
+    Match {
+        i,
+        j,
+        token: chars[i..=j].iter().collect(),
+        pattern: Pattern::Bruteforce,
+        guesses: BRUTEFORCE_CARDINALITY.powi(length as i32).max(minimum),
+    }
+}
+
+fn omnimatch(chars: &[char], user_inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let mut matches = Vec::new();
+    matches.extend(dictionary_matches(chars, user_inputs));
+    matches.extend(spatial_matches(chars));
+    matches.extend(repeat_matches(chars, user_inputs));
+    matches.extend(sequence_matches(chars));
+    matches.extend(date_matches(chars));
+    for m in matches.iter_mut() {
+        let minimum = match m.token.chars().count() {
+            1 => MIN_GUESSES_SINGLE_CHAR,
+            _ => MIN_GUESSES_MULTI_CHAR,
+        };
+        m.guesses = m.guesses.max(minimum);
+    }
+    matches
+}
+
+fn lookup(word: &str, user_inputs: &HashMap<String, usize>) -> Option<(Source, usize)> {
+    if let Some(rank) = user_inputs.get(word) {
+        return Some((Source::UserInputs, *rank));
+    }
+    if let Some(rank) = COMMON_PASSWORDS.get(word) {
+        return Some((Source::Passwords, *rank));
+    }
+    ENGLISH_WORDS
+        .get(word)
+        .map(|_| (Source::English, ENGLISH_WORD_RANK as usize))
+}
+
+fn dictionary_matches(chars: &[char], user_inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let n = chars.len();
+    let lower = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<_>>();
+    let mut matches = Vec::new();
+
+    // Plain and reversed words
+    for i in 0..n {
+        for j in i + 2..n {
+            let word = lower[i..=j].iter().collect::<String>();
+            let reversed = lower[i..=j].iter().rev().collect::<String>();
+            let token = chars[i..=j].iter().collect::<String>();
+            if let Some((source, rank)) = lookup(&word, user_inputs) {
+                matches.push(dictionary_match(i, j, &token, source, rank, false, 1.0));
+            }
+            if reversed != word {
+                if let Some((source, rank)) = lookup(&reversed, user_inputs) {
+                    matches.push(dictionary_match(i, j, &token, source, rank, true, 1.0));
+                }
+            }
+        }
+    }
+
+    // Words with l33t substitutions, every ambiguous substitution is tried
+    // with its first and with its last letter
+    for choice in [0, 1] {
+        let unleeted = lower
+            .iter()
+            .map(|c| match L33T.iter().find(|(from, _)| from == c) {
+                Some((_, letters)) => letters[choice.min(letters.len() - 1)],
+                None => *c,
+            })
+            .collect::<Vec<_>>();
+        if unleeted == lower {
+            continue;
+        }
+        for i in 0..n {
+            for j in i + 2..n {
+                if unleeted[i..=j] == lower[i..=j] {
+                    continue;
+                }
+                let word = unleeted[i..=j].iter().collect::<String>();
+                if let Some((source, rank)) = lookup(&word, user_inputs) {
+                    let token = chars[i..=j].iter().collect::<String>();
+                    let variations = l33t_variations(&lower[i..=j], &unleeted[i..=j]);
+                    let m = dictionary_match(i, j, &token, source, rank, false, variations);
+                    if !matches.iter().any(|other: &Match| {
+                        other.i == i && other.j == j && other.guesses <= m.guesses
+                    }) {
+                        matches.push(m);
+                    }
+                }
+            }
+        }
+    }
+    matches
+}
+
+fn dictionary_match(
+    i: usize,
+    j: usize,
+    token: &str,
+    source: Source,
+    rank: usize,
+    reversed: bool,
+    l33t_variations: f64,
+) -> Match {
+    let mut guesses = rank as f64 * uppercase_variations(token) * l33t_variations;
+    if reversed {
+        guesses *= 2.0;
+    }
+    Match {
+        i,
+        j,
+        token: token.to_string(),
+        pattern: Pattern::Dictionary {
+            source,
+            rank,
+            reversed,
+            l33t: l33t_variations > 1.0,
+        },
+        guesses,
+    }
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (1..=k).fold(1.0, |acc, i| acc * (n - k + i) as f64 / i as f64)
+}
+
+// Ways to mix `changed` and `unchanged` characters, at least one of each
+fn mixed_variations(changed: usize, unchanged: usize) -> f64 {
+    (1..=changed.min(unchanged))
+        .map(|i| binomial(changed + unchanged, i))
+        .sum()
+}
+
+fn uppercase_variations(token: &str) -> f64 {
+    let upper = token.chars().filter(|c| c.is_uppercase()).count();
+    let lower = token.chars().filter(|c| c.is_lowercase()).count();
+    if upper == 0 {
+        return 1.0;
+    }
+    // Capitalized, all caps and a capital at the end are what people try
+    let first_upper = token.chars().next().is_some_and(char::is_uppercase);
+    let last_upper = token.chars().last().is_some_and(char::is_uppercase);
+    if lower == 0 || (upper == 1 && (first_upper || last_upper)) {
+        return 2.0;
+    }
+    mixed_variations(upper, lower)
+}
+
+fn l33t_variations(token: &[char], unleeted: &[char]) -> f64 {
+    let mut variations = 1.0;
+    for (from, letters) in L33T {
+        let subbed = token.iter().filter(|c| *c == from).count();
+        if subbed == 0 {
+            continue;
+        }
+        let unsubbed = unleeted
+            .iter()
+            .zip(token)
+            .filter(|(plain, original)| letters.contains(plain) && *original != from)
+            .count();
+        variations *= match unsubbed {
+            0 => 2.0,
+            unsubbed => mixed_variations(subbed, unsubbed),
+        };
+    }
+    variations
+}
+
+struct Keyboard {
+    name: &'static str,
+    // Keys with their row and column, shifted keys share their position
+    positions: HashMap<char, (i32, i32, bool)>,
+    staggered: bool,
+    starting_positions: f64,
+    average_degree: f64,
+}
+
+impl Keyboard {
+    fn new(name: &'static str, rows: &[&str], shifted: &[&str], staggered: bool) -> Keyboard {
+        let mut positions = HashMap::new();
+        for (layer, shift) in [(rows, false), (shifted, true)] {
+            for (row, keys) in layer.iter().enumerate() {
+                for (column, key) in keys.chars().enumerate() {
+                    if key != ' ' {
+                        positions
+                            .entry(key)
+                            .or_insert((row as i32, column as i32, shift));
+                    }
+                }
+            }
+        }
+        let mut keyboard = Keyboard {
+            name,
+            positions,
+            staggered,
+            starting_positions: 0.0,
+            average_degree: 0.0,
+        };
+        let keys = keyboard.positions.keys().copied().collect::<Vec<_>>();
+        let degrees = keys
+            .iter()
+            .map(|a| {
+                keys.iter()
+                    .filter(|b| keyboard.direction(*a, **b).is_some())
+                    .count()
+            })
+            .sum::<usize>();
+        keyboard.starting_positions = keys.len() as f64;
+        keyboard.average_degree = degrees as f64 / keys.len() as f64;
+        keyboard
+    }
+
+    // Which neighbour of `a` the key `b` is, if it is one
+    fn direction(&self, a: char, b: char) -> Option<(i32, i32)> {
+        let (row_a, column_a, _) = self.positions.get(&a)?;
+        let (row_b, column_b, _) = self.positions.get(&b)?;
+        let (rows, columns) = (row_b - row_a, column_b - column_a);
+        let neighbour = match self.staggered {
+            // Rows are offset by half a key, so only two keys touch above
+            // and below
+            true => match rows {
+                0 => columns.abs() == 1,
+                -1 => columns == 0 || columns == 1,
+                1 => columns == 0 || columns == -1,
+                _ => false,
+            },
+            false => rows.abs() <= 1 && columns.abs() <= 1 && (rows, columns) != (0, 0),
+        };
+        neighbour.then_some((rows, columns))
+    }
+
+    fn is_shifted(&self, c: char) -> bool {
+        self.positions
+            .get(&c)
+            .is_some_and(|(_, _, shifted)| *shifted)
+    }
+}
+
+fn spatial_matches(chars: &[char]) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for keyboard in KEYBOARDS.iter() {
+        let mut i = 0;
+        while i + 2 < chars.len() {
+            let mut j = i;
+            let mut turns = 0;
+            let mut last_direction = None;
+            while j + 1 < chars.len() {
+                match keyboard.direction(chars[j], chars[j + 1]) {
+                    Some(direction) => {
+                        if last_direction != Some(direction) {
+                            turns += 1;
+                            last_direction = Some(direction);
+                        }
+                        j += 1;
+                    }
+                    None => break,
+                }
+            }
+            if j - i + 1 >= 3 {
+                let token = &chars[i..=j];
+                let shifted = token.iter().filter(|c| keyboard.is_shifted(**c)).count();
+                matches.push(Match {
+                    i,
+                    j,
+                    token: token.iter().collect(),
+                    pattern: Pattern::Spatial {
+                        keyboard: keyboard.name,
+                        turns,
+                    },
+                    guesses: spatial_guesses(keyboard, token.len(), turns, shifted),
+                });
+                i = j;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    matches
+}
+
+fn spatial_guesses(keyboard: &Keyboard, length: usize, turns: usize, shifted: usize) -> f64 {
+    let mut guesses = 0.0;
+    for i in 2..=length {
+        for j in 1..=turns.min(i - 1) {
+            guesses += binomial(i - 1, j - 1)
+                * keyboard.starting_positions
+                * keyboard.average_degree.powi(j as i32);
+        }
+    }
+    let unshifted = length - shifted;
+    guesses
+        * match (shifted, unshifted) {
+            (0, _) => 1.0,
+            (_, 0) => 2.0,
+            (shifted, unshifted) => mixed_variations(shifted, unshifted),
+        }
+}
+
+fn repeat_matches(chars: &[char], user_inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let n = chars.len();
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < n {
+        // The unit that repeats over the longest stretch starting at i
+        let mut longest: Option<(usize, usize)> = None;
+        for unit in 1..=(n - i) / 2 {
+            let mut count = 1;
+            while i + (count + 1) * unit <= n
+                && chars[i..i + unit] == chars[i + count * unit..i + (count + 1) * unit]
+            {
+                count += 1;
+            }
+            let enough = match unit {
+                1 => count >= 3,
+                _ => count >= 2,
+            };
+            if enough && longest.is_none_or(|(u, c)| unit * count > u * c) {
+                longest = Some((unit, count));
+            }
+        }
+        match longest {
+            Some((unit, count)) => {
+                let base = &chars[i..i + unit];
+                let (base_guesses, _) = most_guessable_sequence(base, user_inputs);
+                matches.push(Match {
+                    i,
+                    j: i + unit * count - 1,
+                    token: chars[i..i + unit * count].iter().collect(),
+                    pattern: Pattern::Repeat {
+                        unit: base.iter().collect(),
+                    },
+                    guesses: base_guesses * count as f64,
+                });
+                i += unit * count;
+            }
+            None => i += 1,
+        }
+    }
+    matches
+}
+
+fn sequence_matches(chars: &[char]) -> Vec<Match> {
+    let n = chars.len();
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + 2 < n {
+        let delta = chars[i + 1] as i64 - chars[i] as i64;
+        let same_class = |a: char, b: char| {
+            (a.is_ascii_lowercase() && b.is_ascii_lowercase())
+                || (a.is_ascii_uppercase() && b.is_ascii_uppercase())
+                || (a.is_ascii_digit() && b.is_ascii_digit())
+        };
+        let mut j = i + 1;
+        if delta != 0 && delta.abs() <= 5 && same_class(chars[i], chars[j]) {
+            while j + 1 < n
+                && chars[j + 1] as i64 - chars[j] as i64 == delta
+                && same_class(chars[j], chars[j + 1])
+            {
+                j += 1;
+            }
+        }
+        if j - i + 1 >= 3 {
+            let first = chars[i];
+            let base = match first {
+                'a' | 'A' | 'z' | 'Z' | '0' | '1' | '9' => 4.0,
+                c if c.is_ascii_digit() => 10.0,
+                _ => 26.0,
+            };
+            let ascending = delta > 0;
+            let direction = if ascending { 1.0 } else { 2.0 };
+            matches.push(Match {
+                i,
+                j,
+                token: chars[i..=j].iter().collect(),
+                pattern: Pattern::Sequence { ascending },
+                guesses: base * (j - i + 1) as f64 * direction,
+            });
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+fn reference_year() -> i32 {
+    chrono::Utc::now().year()
+}
+
+fn year_guesses(year: i32) -> f64 {
+    ((year - reference_year()).abs() as f64).max(MIN_YEAR_SPACE)
+}
+
+// Two digit years are read as the closest century
+fn expand_year(year: i32, digits: usize) -> i32 {
+    match digits {
+        2 if year > 50 => 1900 + year,
+        2 => 2000 + year,
+        _ => year,
+    }
+}
+
+fn valid_date(day: i32, month: i32, year: i32) -> bool {
+    (1..=31).contains(&day) && (1..=12).contains(&month) && (1000..=2050).contains(&year)
+}
+
+fn date_matches(chars: &[char]) -> Vec<Match> {
+    let n = chars.len();
+    let mut matches = Vec::new();
+    for i in 0..n {
+        for j in i + 3..n.min(i + 10) {
+            let token = chars[i..=j].iter().collect::<String>();
+            let digits_only = token.chars().all(|c| c.is_ascii_digit());
+            let found = match (digits_only, token.len()) {
+                (true, 4) => {
+                    let year = token.parse::<i32>().unwrap();
+                    (1900..=2050)
+                        .contains(&year)
+                        .then(|| (year_guesses(year), true))
+                }
+                (true, 6) | (true, 8) => {
+                    date_without_separator(&token).map(|year| (year_guesses(year) * 365.0, false))
+                }
+                (false, _) => date_with_separator(&token)
+                    .map(|year| (year_guesses(year) * 365.0 * 4.0, false)),
+                _ => None,
+            };
+            if let Some((guesses, year_only)) = found {
+                matches.push(Match {
+                    i,
+                    j,
+                    token,
+                    pattern: Pattern::Date { year_only },
+                    guesses,
+                });
+            }
+        }
+    }
+    matches
+}
+
+// Tries day-month-year, month-day-year and year-month-day orders
+fn date_without_separator(token: &str) -> Option<i32> {
+    let year_digits = token.len() - 4;
+    let number = |range: std::ops::Range<usize>| token[range].parse::<i32>().unwrap();
+    let tail = 4..token.len();
+    let candidates = [
+        (number(0..2), number(2..4), number(tail.clone())),
+        (number(2..4), number(0..2), number(tail)),
+        (
+            number(year_digits + 2..token.len()),
+            number(year_digits..year_digits + 2),
+            number(0..year_digits),
+        ),
+    ];
+    candidates.into_iter().find_map(|(day, month, year)| {
+        let year = expand_year(year, year_digits);
+        valid_date(day, month, year).then_some(year)
+    })
+}
+
+fn date_with_separator(token: &str) -> Option<i32> {
+    let captures = SEPARATED_DATE.captures(token)?;
+    let parts = (1..=3)
+        .map(|group| {
+            let part = &captures[group];
+            Some((part.parse::<i32>().ok()?, part.len()))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let candidates = [
+        (parts[0], parts[1], parts[2]),
+        (parts[1], parts[0], parts[2]),
+        (parts[2], parts[1], parts[0]),
+    ];
+    candidates
+        .into_iter()
+        .find_map(|((day, _), (month, _), (year, digits))| {
+            if digits == 1 || digits == 3 {
+                return None;
+            }
+            let year = expand_year(year, digits);
+            valid_date(day, month, year).then_some(year)
+        })
+}
+
+fn display_time(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = MINUTE * 60.0;
+    const DAY: f64 = HOUR * 24.0;
+    const MONTH: f64 = DAY * 31.0;
+    const YEAR: f64 = MONTH * 12.0;
+    const CENTURY: f64 = YEAR * 100.0;
+
+    let (amount, unit) = match seconds {
+        s if s < 1.0 => return "less than a second".to_string(),
+        s if s < MINUTE => (s, "second"),
+        s if s < HOUR => (s / MINUTE, "minute"),
+        s if s < DAY => (s / HOUR, "hour"),
+        s if s < MONTH => (s / DAY, "day"),
+        s if s < YEAR => (s / MONTH, "month"),
+        s if s < CENTURY => (s / YEAR, "year"),
+        _ => return "centuries".to_string(),
+    };
+    let amount = amount.round() as u64;
+    match amount {
+        1 => format!("1 {}", unit),
+        amount => format!("{} {}s", amount, unit),
+    }
+}
+
+fn feedback(score: u8, sequence: &[Match]) -> PasswordFeedback {
+    if sequence.is_empty() {
+        return PasswordFeedback {
+            warning: None,
+            suggestions: vec![
+                "Use a few words, avoid common phrases".to_string(),
+                "No need for symbols, digits, or uppercase letters".to_string(),
+            ],
+        };
+    }
+    if score > 2 {
+        return PasswordFeedback::default();
+    }
+
+    // The longest match says the most about the password
+    let longest = sequence
+        .iter()
+        .filter(|m| m.pattern != Pattern::Bruteforce)
+        .max_by_key(|m| m.j - m.i);
+    let mut feedback = match longest {
+        Some(m) => match_feedback(m, sequence.len() == 1),
+        None => PasswordFeedback::default(),
+    };
+    feedback.suggestions.insert(
+        0,
+        "Add another word or two. Uncommon words are better.".to_string(),
+    );
+    feedback
+}
+
+fn match_feedback(m: &Match, sole_match: bool) -> PasswordFeedback {
+    let mut suggestions = Vec::new();
+    let warning = match &m.pattern {
+        Pattern::Dictionary {
+            source,
+            rank,
+            reversed,
+            l33t,
+        } => {
+            let token = m.token.as_str();
+            let upper = token.chars().filter(|c| c.is_uppercase()).count();
+            if token.chars().next().is_some_and(char::is_uppercase) && upper == 1 {
+                suggestions.push("Capitalization doesn't help very much".to_string());
+            } else if upper > 0 && upper == token.chars().filter(|c| c.is_alphabetic()).count() {
+                suggestions
+                    .push("All-uppercase is almost as easy to guess as all-lowercase".to_string());
+            }
+            if *reversed && token.chars().count() >= 4 {
+                suggestions.push("Reversed words aren't much harder to guess".to_string());
+            }
+            if *l33t {
+                suggestions.push(
+                    "Predictable substitutions like '@' instead of 'a' don't help very much"
+                        .to_string(),
+                );
+            }
+            match source {
+                Source::UserInputs => Some("Avoid your name or email in the password".to_string()),
+                Source::Passwords if sole_match && !*l33t && !*reversed && *rank <= 10 => {
+                    Some("This is a top-10 common password".to_string())
+                }
+                Source::Passwords if sole_match && !*l33t && !*reversed && *rank <= 100 => {
+                    Some("This is a top-100 common password".to_string())
+                }
+                Source::Passwords if sole_match && !*l33t && !*reversed => {
+                    Some("This is a very common password".to_string())
+                }
+                Source::Passwords => {
+                    Some("This is similar to a commonly used password".to_string())
+                }
+                Source::English if sole_match => {
+                    Some("A word by itself is easy to guess".to_string())
+                }
+                Source::English => None,
+            }
+        }
+        Pattern::Spatial { turns, .. } => {
+            suggestions.push("Use a longer keyboard pattern with more turns".to_string());
+            match turns {
+                1 => Some("Straight rows of keys are easy to guess".to_string()),
+                _ => Some("Short keyboard patterns are easy to guess".to_string()),
+            }
+        }
+        Pattern::Repeat { unit } => {
+            suggestions.push("Avoid repeated words and characters".to_string());
+            match unit.chars().count() {
+                1 => Some("Repeats like \"aaa\" are easy to guess".to_string()),
+                _ => Some(
+                    "Repeats like \"abcabcabc\" are only slightly harder to guess than \"abc\""
+                        .to_string(),
+                ),
+            }
+        }
+        Pattern::Sequence { .. } => {
+            suggestions.push("Avoid sequences".to_string());
+            Some("Sequences like abc or 6543 are easy to guess".to_string())
+        }
+        Pattern::Date { year_only } => {
+            suggestions.push("Avoid dates and years that are associated with you".to_string());
+            match year_only {
+                true => Some("Recent years are easy to guess".to_string()),
+                false => Some("Dates are often easy to guess".to_string()),
+            }
+        }
+        Pattern::Bruteforce => None,
+    };
+    PasswordFeedback {
+        warning,
+        suggestions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(password: &str) -> Vec<Pattern> {
+        let chars = password.chars().collect::<Vec<_>>();
+        most_guessable_sequence(&chars, &HashMap::new())
+            .1
+            .into_iter()
+            .map(|m| m.pattern)
+            .collect()
+    }
+
+    #[test]
+    fn test_scores() {
+        let weak = [
+            "",
+            "password",
+            "Password1!",
+            "qwerty123",
+            "123456789",
+            "aaaaaaaa",
+        ];
+        for password in weak {
+            assert!(
+                estimate_strength(password, &[]).score <= 1,
+                "{} should be weak",
+                password
+            );
+        }
+
+        let strong = [
+            "correct horse battery staple",
+            "AshimTest@1234",
+            "tr0ub4dor&3-Xq9#",
+            "Ünïcödé pässwörd wïth späces",
+        ];
+        for password in strong {
+            assert!(
+                estimate_strength(password, &[]).score >= 3,
+                "{} should be strong",
+                password
+            );
+        }
+    }
+
+    #[test]
+    fn test_common_password() {
+        let strength = estimate_strength("password", &[]);
+
+        assert_eq!(strength.score, 0);
+        assert_eq!(
+            strength.feedback.warning.as_deref(),
+            Some("This is a top-10 common password")
+        );
+        assert_eq!(strength.crack_time_display, "less than a second");
+    }
+
+    #[test]
+    fn test_l33t_and_reversed_words() {
+        assert!(matches!(
+            patterns("p@ssw0rd")[..],
+            [Pattern::Dictionary { l33t: true, .. }]
+        ));
+        assert!(matches!(
+            patterns("drowssap")[..],
+            [Pattern::Dictionary { reversed: true, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_keyboard_patterns() {
+        assert!(matches!(
+            patterns("zxcvbnm,./")[..],
+            [Pattern::Spatial { turns: 1, .. }]
+        ));
+        assert!(matches!(
+            patterns("qwertgfdsa")[..],
+            [Pattern::Spatial { turns: 3, .. }]
+        ));
+        let strength = estimate_strength("zxcvbnm,./", &[]);
+        assert_eq!(
+            strength.feedback.warning.as_deref(),
+            Some("Straight rows of keys are easy to guess")
+        );
+    }
+
+    #[test]
+    fn test_repeats_and_sequences() {
+        assert!(matches!(patterns("zzzzzzzz")[..], [Pattern::Repeat { .. }]));
+        assert!(matches!(
+            patterns("jkhgjkhgjkhg")[..],
+            [Pattern::Repeat { .. }]
+        ));
+        assert!(matches!(
+            patterns("lmnopqrs")[..],
+            [Pattern::Sequence { ascending: true }]
+        ));
+        assert!(matches!(
+            patterns("97531")[..],
+            [Pattern::Sequence { ascending: false }]
+        ));
+    }
+
+    #[test]
+    fn test_dates() {
+        assert!(matches!(
+            patterns("13/05/1987")[..],
+            [Pattern::Date { year_only: false }]
+        ));
+        assert!(matches!(
+            patterns("19870513")[..],
+            [Pattern::Date { year_only: false }]
+        ));
+        assert!(matches!(
+            patterns("1987")[..],
+            [Pattern::Date { year_only: true }]
+        ));
+    }
+
+    #[test]
+    fn test_non_ascii_digits() {
+        // Arabic-Indic digits must not be taken for a date
+        for password in ["١/١/١٩٩٠", "١٣/٠٥/١٩٨٧", "١٩٨٧٠٥١٣"] {
+            let strength = estimate_strength(password, &[]);
+            assert!(strength.guesses > 0.0);
+            assert!(!patterns(password)
+                .iter()
+                .any(|pattern| matches!(pattern, Pattern::Date { .. })));
+        }
+    }
+
+    #[test]
+    fn test_user_inputs() {
+        let without = estimate_strength("Zebulon.Crabtree", &[]);
+        let with = estimate_strength(
+            "Zebulon.Crabtree",
+            &["Zebulon Crabtree", "zebulon.crabtree@example.com"],
+        );
+
+        assert!(with.guesses < without.guesses);
+        assert!(with.score <= 1);
+        assert_eq!(
+            with.feedback.warning.as_deref(),
+            Some("Avoid your name or email in the password")
+        );
+    }
+
+    #[test]
+    fn test_long_passphrase_is_not_capped() {
+        let passphrase = "the quick brown fox jumps over the lazy dog ".repeat(4);
+        let strength = estimate_strength(&passphrase, &[]);
+
+        assert_eq!(strength.score, 4);
+        assert_eq!(strength.crack_time_display, "centuries");
+    }
+
+    #[test]
+    fn test_display_time() {
+        assert_eq!(display_time(0.5), "less than a second");
+        assert_eq!(display_time(1.0), "1 second");
+        assert_eq!(display_time(90.0), "2 minutes");
+        assert_eq!(display_time(3.0 * 86400.0), "3 days");
+        assert_eq!(display_time(1e12), "centuries");
+    }
+}
@@ -2,9 +2,10 @@ use sql::{Filter, Glue, HttpQuery};
 
 use crate::models::Tag;
 
-use super::errors::AppError;
+use super::{errors::AppError, vault_session::ensure_unlocked};
 
 pub async fn fetch_tag(id: String, user: String) -> anyhow::Result<Tag> {
+    ensure_unlocked(&user)?;
     let tag = Tag::get(id).await.unwrap();
     if user != tag.created_by.clone().unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -13,6 +14,7 @@ pub async fn fetch_tag(id: String, user: String) -> anyhow::Result<Tag> {
 }
 
 pub async fn add_tag(mut data: Tag, user: String) -> anyhow::Result<Tag> {
+    ensure_unlocked(&user)?;
     data.created_by = Some(user.clone());
     data.created_at = Some(chrono::Utc::now().timestamp());
     let tag = Tag::insert(data).await.unwrap();
@@ -20,6 +22,7 @@ pub async fn add_tag(mut data: Tag, user: String) -> anyhow::Result<Tag> {
 }
 
 pub async fn update_tag(id: String, mut data: Tag, user: String) -> anyhow::Result<Tag> {
+    ensure_unlocked(&user)?;
     let tag = Tag::get(id.clone()).await.unwrap();
     if user != tag.created_by.unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -31,6 +34,7 @@ pub async fn update_tag(id: String, mut data: Tag, user: String) -> anyhow::Resu
 }
 
 pub async fn remove_tag(id: String, user: String) -> anyhow::Result<Tag> {
+    ensure_unlocked(&user)?;
     let tag = Tag::get(id.clone()).await.unwrap();
     if user != tag.created_by.unwrap() {
         return Err(AppError::Unauthorized.into());
@@ -40,6 +44,7 @@ pub async fn remove_tag(id: String, user: String) -> anyhow::Result<Tag> {
 }
 
 pub async fn get_all_tags(query: String, user: String) -> anyhow::Result<Vec<Tag>> {
+    ensure_unlocked(&user)?;
     let mut query = serde_json::from_str::<HttpQuery>(&query).unwrap();
     query = if query.filters.is_none() {
         query.filters = Some(vec![Filter::equal("created_by", user, None)]);
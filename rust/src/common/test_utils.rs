@@ -0,0 +1,18 @@
+use tokio::sync::OnceCell;
+
+/// Migrates a database that lives for the whole test run.
+///
+/// The connection pool is global, so every test that touches the database
+/// has to share one file that is not deleted while other tests still use it.
+pub async fn init_test_db() {
+    static DB: OnceCell<()> = OnceCell::const_new();
+    DB.get_or_init(|| async {
+        let path = tempfile::NamedTempFile::new()
+            .unwrap()
+            .into_temp_path()
+            .keep()
+            .unwrap();
+        sql::migrate_sqlite(path.to_str().unwrap()).await.unwrap();
+    })
+    .await;
+}
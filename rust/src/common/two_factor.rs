@@ -0,0 +1,885 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, Rng, RngCore};
+use rusqlite::OptionalExtension;
+use security::{
+    base32_decode, base32_encode, decrypt_with_key_aad, encrypt_with_key_aad, generate_otp_secret,
+    verify_totp, AssociatedData, OtpAlgorithm, SecretBytes, SecretString, DATA_KEY_LENGTH,
+};
+use sql::SqlError;
+
+use crate::models::{
+    TwoFactorBackupCodes, TwoFactorChallenge, TwoFactorSetup, TwoFactorStatus, User,
+};
+
+use super::{
+    errors::AppError,
+    otp::{OtpAuth, OtpKind, DEFAULT_DIGITS, DEFAULT_PERIOD},
+    password::{password_hash, verify_password},
+    time::now,
+    users::my_profile,
+};
+
+// Shown by authenticator apps next to the account
+pub const TWO_FACTOR_ISSUER: &str = "Indidus";
+pub const CHALLENGE_SECONDS: u64 = 5 * 60;
+const MAX_CHALLENGE_ATTEMPTS: u32 = 5;
+
+// Wrong codes a user gets before codes are refused for a while, across
+// challenges. Each wrong code after that doubles the wait, up to the cap.
+const MAX_FAILED_CODES: i64 = 5;
+const LOCKOUT_SECONDS: i64 = 60;
+const MAX_LOCKOUT_SECONDS: i64 = 60 * 60;
+
+const TOTP_PERIOD: u64 = DEFAULT_PERIOD;
+const TOTP_DIGITS: u32 = DEFAULT_DIGITS;
+// Steps either side of now a code is accepted in, for clock drift
+const TOTP_SKEW: u64 = 1;
+
+pub const BACKUP_CODE_COUNT: usize = 10;
+const BACKUP_CODE_LENGTH: usize = 10;
+// Crockford's base32, which leaves out letters mistaken for digits
+const BACKUP_CODE_ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+// Logins waiting for a code, keyed by challenge token. The master password
+// is held until then, so the vault only unlocks once both factors pass.
+static CHALLENGES: Lazy<Mutex<HashMap<String, Challenge>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct Challenge {
+    user_id: String,
+    master_password: SecretString,
+    expires_at: u64,
+    attempts: u32,
+}
+
+// Seals TOTP secrets in the database, see `set_two_factor_key`
+static TWO_FACTOR_KEY: Lazy<RwLock<Option<SecretBytes>>> = Lazy::new(|| RwLock::new(None));
+
+/// Sets the key TOTP secrets are sealed with in the database.
+///
+/// Two-factor authentication can't be set up without one, and secrets
+/// sealed with another key no longer open.
+pub fn set_two_factor_key(key: Option<&[u8]>) -> Result<(), AppError> {
+    if key.is_some_and(|key| key.len() != DATA_KEY_LENGTH) {
+        return Err(AppError::LibraryError(format!(
+            "Two-factor key must be {} bytes",
+            DATA_KEY_LENGTH
+        )));
+    }
+    *TWO_FACTOR_KEY.write().unwrap() = key.map(|key| key.to_vec().into());
+    Ok(())
+}
+
+fn two_factor_key() -> Result<SecretBytes, AppError> {
+    TWO_FACTOR_KEY
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| AppError::LibraryError("No two-factor key is configured".into()))
+}
+
+// Binds a sealed secret to its user, so it can't be moved to another account
+fn secret_associated_data(user_id: &str) -> AssociatedData {
+    AssociatedData::new()
+        .field("users")
+        .field("two_factor_secret")
+        .field(user_id)
+}
+
+fn seal_secret(user_id: &str, secret: &str) -> Result<String, AppError> {
+    Ok(encrypt_with_key_aad(
+        secret,
+        two_factor_key()?.expose_secret(),
+        &secret_associated_data(user_id),
+    )?)
+}
+
+fn open_secret(user: &User) -> Result<SecretBytes, AppError> {
+    let sealed = user
+        .two_factor_secret
+        .as_deref()
+        .ok_or(AppError::TOTPNotEnabled)?;
+    let secret = decrypt_with_key_aad(
+        sealed,
+        two_factor_key()?.expose_secret(),
+        &secret_associated_data(user.id.as_deref().unwrap_or_default()),
+    )
+    .map_err(|_| AppError::TOTPSecretParseError)?;
+    base32_decode(&secret).map_err(|_| AppError::TOTPSecretParseError)
+}
+
+fn otpauth_url(email: &str, secret: SecretBytes) -> String {
+    OtpAuth {
+        kind: OtpKind::Totp {
+            period: TOTP_PERIOD,
+        },
+        secret,
+        algorithm: OtpAlgorithm::Sha1,
+        digits: TOTP_DIGITS,
+        issuer: Some(TWO_FACTOR_ISSUER.to_string()),
+        account: Some(email.to_string()),
+    }
+    .to_uri()
+}
+
+fn save_two_factor(
+    user_id: &str,
+    secret: Option<&str>,
+    enabled: bool,
+    last_step: Option<i64>,
+) -> Result<(), AppError> {
+    let conn = sql::get_db_connection()?;
+    conn.execute(
+        "UPDATE users SET two_factor_secret = ?, two_factor_enabled = ?, \
+         two_factor_last_step = ?, updated_at = ?, updated_by = ? WHERE id = ?",
+        (secret, enabled, last_step, now() as i64, user_id, user_id),
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+/// Checks a TOTP code of a user. Each code is accepted once, a replayed
+/// code fails like a wrong one.
+pub(crate) fn verify_two_factor_code(user: &User, code: &str) -> Result<(), AppError> {
+    let secret = open_secret(user)?;
+    let step = verify_totp(
+        secret.expose_secret(),
+        code,
+        now() as u64,
+        TOTP_PERIOD,
+        TOTP_DIGITS,
+        OtpAlgorithm::Sha1,
+        TOTP_SKEW,
+    )?
+    .ok_or(AppError::TOTPVerificationFailed)? as i64;
+
+    // Only moves forward, so two requests can't both spend the same code
+    let conn = sql::get_db_connection()?;
+    let updated = conn
+        .execute(
+            "UPDATE users SET two_factor_last_step = ?1 WHERE id = ?2 \
+             AND (two_factor_last_step IS NULL OR two_factor_last_step < ?1)",
+            (step, user.id.as_deref()),
+        )
+        .map_err(SqlError::from)?;
+    match updated {
+        0 => Err(AppError::TOTPVerificationFailed),
+        _ => Ok(()),
+    }
+}
+
+fn generate_backup_code() -> String {
+    let code: String = (0..BACKUP_CODE_LENGTH)
+        .map(|_| BACKUP_CODE_ALPHABET[OsRng.gen_range(0..BACKUP_CODE_ALPHABET.len())] as char)
+        .collect();
+    // Grouped for reading out, the dash is optional when typed back
+    format!("{}-{}", &code[..5], &code[5..])
+}
+
+fn normalize_backup_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Replaces the backup codes of a user with a new set, of which only the
+/// Argon2 hashes are kept.
+fn replace_backup_codes(user_id: &str) -> Result<TwoFactorBackupCodes, AppError> {
+    let codes = (0..BACKUP_CODE_COUNT)
+        .map(|_| generate_backup_code())
+        .collect::<Vec<_>>();
+    let code_hashes = codes
+        .iter()
+        .map(|code| password_hash(&normalize_backup_code(code)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    tx.execute(
+        "DELETE FROM two_factor_backup_codes WHERE user_id = ?",
+        [user_id],
+    )
+    .map_err(SqlError::from)?;
+    for code_hash in code_hashes {
+        tx.execute(
+            "INSERT INTO two_factor_backup_codes (id, created_at, created_by, user_id, code_hash) \
+             VALUES (?, ?, ?, ?, ?)",
+            (sql::get_ulid(), now() as i64, user_id, user_id, code_hash),
+        )
+        .map_err(SqlError::from)?;
+    }
+    tx.commit().map_err(SqlError::from)?;
+
+    Ok(TwoFactorBackupCodes {
+        codes: codes.into_iter().map(SecretString::from).collect(),
+    })
+}
+
+fn delete_backup_codes(user_id: &str) -> Result<(), AppError> {
+    let conn = sql::get_db_connection()?;
+    conn.execute(
+        "DELETE FROM two_factor_backup_codes WHERE user_id = ?",
+        [user_id],
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+fn backup_codes_remaining(user_id: &str) -> Result<u32, AppError> {
+    let conn = sql::get_db_connection()?;
+    let remaining = conn
+        .query_row(
+            "SELECT COUNT(*) FROM two_factor_backup_codes WHERE user_id = ? AND used_at IS NULL",
+            [user_id],
+            |row| row.get(0),
+        )
+        .map_err(SqlError::from)?;
+    Ok(remaining)
+}
+
+/// Spends one of the backup codes of a user. A code is marked used in the
+/// same statement that checks it is unused, so it can't let in two logins.
+fn use_backup_code(user_id: &str, code: &str) -> Result<(), AppError> {
+    let code = normalize_backup_code(code);
+    // Not worth hashing
+    if code.len() != BACKUP_CODE_LENGTH {
+        return Err(AppError::TOTPVerificationFailed);
+    }
+
+    let conn = sql::get_db_connection()?;
+    let unused = conn
+        .prepare(
+            "SELECT id, code_hash FROM two_factor_backup_codes \
+             WHERE user_id = ? AND used_at IS NULL",
+        )
+        .and_then(|mut stmt| {
+            stmt.query_map([user_id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(SqlError::from)?;
+
+    for (id, code_hash) in unused {
+        if !verify_password(&code, &code_hash)? {
+            continue;
+        }
+        let n = now() as i64;
+        let updated = conn
+            .execute(
+                "UPDATE two_factor_backup_codes SET used_at = ?, updated_at = ?, updated_by = ? \
+                 WHERE id = ? AND used_at IS NULL",
+                (n, n, user_id, &id),
+            )
+            .map_err(SqlError::from)?;
+        return match updated {
+            0 => Err(AppError::TOTPVerificationFailed),
+            _ => Ok(()),
+        };
+    }
+    Err(AppError::TOTPVerificationFailed)
+}
+
+/// Refuses codes while a user is locked out after too many wrong ones.
+fn check_lockout(user_id: &str) -> Result<(), AppError> {
+    let conn = sql::get_db_connection()?;
+    let locked_until: Option<i64> = conn
+        .query_row(
+            "SELECT two_factor_locked_until FROM users WHERE id = ?",
+            [user_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(SqlError::from)?
+        .flatten();
+    match locked_until {
+        Some(locked_until) if locked_until > now() as i64 => Err(AppError::TwoFactorLocked),
+        _ => Ok(()),
+    }
+}
+
+fn record_failed_code(user_id: &str) -> Result<(), AppError> {
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    tx.execute(
+        "UPDATE users SET two_factor_failures = two_factor_failures + 1 WHERE id = ?",
+        [user_id],
+    )
+    .map_err(SqlError::from)?;
+    let failures: i64 = tx
+        .query_row(
+            "SELECT two_factor_failures FROM users WHERE id = ?",
+            [user_id],
+            |row| row.get(0),
+        )
+        .map_err(SqlError::from)?;
+    if failures >= MAX_FAILED_CODES {
+        let doublings = (failures - MAX_FAILED_CODES).min(32) as u32;
+        let seconds = LOCKOUT_SECONDS
+            .saturating_mul(1 << doublings)
+            .min(MAX_LOCKOUT_SECONDS);
+        tx.execute(
+            "UPDATE users SET two_factor_locked_until = ? WHERE id = ?",
+            (now() as i64 + seconds, user_id),
+        )
+        .map_err(SqlError::from)?;
+    }
+    tx.commit().map_err(SqlError::from)?;
+    Ok(())
+}
+
+fn reset_failed_codes(user_id: &str) -> Result<(), AppError> {
+    let conn = sql::get_db_connection()?;
+    conn.execute(
+        "UPDATE users SET two_factor_failures = 0, two_factor_locked_until = NULL WHERE id = ?",
+        [user_id],
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+/// Checks the second factor of a user, a TOTP code or else a backup code.
+/// Wrong codes count against the user, see `MAX_FAILED_CODES`.
+pub(crate) fn verify_second_factor(user: &User, code: &str) -> Result<(), AppError> {
+    let user_id = user.id.as_deref().unwrap();
+    check_lockout(user_id)?;
+    let code = code.trim();
+    let verified = if code.len() == TOTP_DIGITS as usize && code.bytes().all(|b| b.is_ascii_digit())
+    {
+        verify_two_factor_code(user, code)
+    } else {
+        use_backup_code(user_id, code)
+    };
+    match verified {
+        Ok(()) => reset_failed_codes(user_id),
+        Err(AppError::TOTPVerificationFailed) => {
+            record_failed_code(user_id)?;
+            Err(AppError::TOTPVerificationFailed)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Starts two-factor enrollment with a new secret. Logins don't ask for
+/// codes until [`confirm_two_factor`] proves the authenticator has it.
+pub async fn begin_two_factor(user_id: &str) -> Result<TwoFactorSetup, AppError> {
+    let user = my_profile(user_id.to_string()).await?;
+    if user.two_factor_enabled {
+        return Err(AppError::TOTPAlreadyEnabled);
+    }
+    let secret = generate_otp_secret();
+    let encoded = base32_encode(secret.expose_secret());
+    save_two_factor(user_id, Some(&seal_secret(user_id, &encoded)?), false, None)?;
+    Ok(TwoFactorSetup {
+        otpauth_url: otpauth_url(&user.email, secret).into(),
+        secret: encoded.into(),
+    })
+}
+
+/// Turns two-factor authentication on once a code from the new secret
+/// checks out, and hands out the first set of backup codes.
+pub async fn confirm_two_factor(
+    user_id: &str,
+    code: &str,
+) -> Result<TwoFactorBackupCodes, AppError> {
+    let user = my_profile(user_id.to_string()).await?;
+    if user.two_factor_enabled {
+        return Err(AppError::TOTPAlreadyEnabled);
+    }
+    verify_two_factor_code(&user, code)?;
+    let conn = sql::get_db_connection()?;
+    conn.execute(
+        "UPDATE users SET two_factor_enabled = TRUE, updated_at = ?, updated_by = ? WHERE id = ?",
+        (now() as i64, user_id, user_id),
+    )
+    .map_err(SqlError::from)?;
+    replace_backup_codes(user_id)
+}
+
+/// Turns two-factor authentication off, which takes a current code so a
+/// stolen session can't do it.
+pub async fn disable_two_factor(user_id: &str, code: &str) -> Result<(), AppError> {
+    let user = my_profile(user_id.to_string()).await?;
+    if !user.two_factor_enabled {
+        return Err(AppError::TOTPNotEnabled);
+    }
+    verify_second_factor(&user, code)?;
+    save_two_factor(user_id, None, false, None)?;
+    delete_backup_codes(user_id)
+}
+
+/// Replaces the backup codes of a user, for when they run low or may have
+/// been seen. Takes a current code like [`disable_two_factor`].
+pub async fn regenerate_backup_codes(
+    user_id: &str,
+    code: &str,
+) -> Result<TwoFactorBackupCodes, AppError> {
+    let user = my_profile(user_id.to_string()).await?;
+    if !user.two_factor_enabled {
+        return Err(AppError::TOTPNotEnabled);
+    }
+    verify_second_factor(&user, code)?;
+    replace_backup_codes(user_id)
+}
+
+pub async fn two_factor_status(user_id: &str) -> Result<TwoFactorStatus, AppError> {
+    let user = my_profile(user_id.to_string()).await?;
+    Ok(TwoFactorStatus {
+        enabled: user.two_factor_enabled,
+        backup_codes_remaining: backup_codes_remaining(user_id)?,
+    })
+}
+
+/// Holds a login that passed the password check until a code arrives.
+/// A user locked out by wrong codes gets no challenge until the wait ends.
+pub(crate) fn issue_challenge(
+    user_id: &str,
+    master_password: SecretString,
+) -> Result<TwoFactorChallenge, AppError> {
+    check_lockout(user_id)?;
+    let mut token = [0u8; 32];
+    OsRng.fill_bytes(&mut token);
+    let token = hex::encode(token);
+    let n = now() as u64;
+
+    let mut challenges = CHALLENGES.lock().unwrap();
+    challenges.retain(|_, challenge| challenge.expires_at > n);
+    challenges.insert(
+        token.clone(),
+        Challenge {
+            user_id: user_id.to_string(),
+            master_password,
+            expires_at: n + CHALLENGE_SECONDS,
+            attempts: 0,
+        },
+    );
+    Ok(TwoFactorChallenge {
+        challenge_token: token.into(),
+        expires_in: CHALLENGE_SECONDS,
+    })
+}
+
+/// Checks the code for a challenge, returning the user and master password
+/// it holds. A challenge is spent on success and after too many wrong codes.
+pub(crate) async fn answer_challenge(
+    token: &str,
+    code: &str,
+) -> Result<(User, SecretString), AppError> {
+    let user_id = {
+        let mut challenges = CHALLENGES.lock().unwrap();
+        match challenges.get(token) {
+            Some(challenge) if challenge.expires_at > now() as u64 => challenge.user_id.clone(),
+            Some(_) => {
+                challenges.remove(token);
+                return Err(AppError::TwoFactorChallengeInvalid);
+            }
+            None => return Err(AppError::TwoFactorChallengeInvalid),
+        }
+    };
+    let user = my_profile(user_id).await?;
+    let verified = verify_second_factor(&user, code);
+
+    let mut challenges = CHALLENGES.lock().unwrap();
+    if let Err(err) = verified {
+        if let Some(challenge) = challenges.get_mut(token) {
+            challenge.attempts += 1;
+            if challenge.attempts >= MAX_CHALLENGE_ATTEMPTS {
+                challenges.remove(token);
+            }
+        }
+        return Err(err);
+    }
+    // Gone if a concurrent answer spent it first
+    let challenge = challenges
+        .remove(token)
+        .ok_or(AppError::TwoFactorChallengeInvalid)?;
+    Ok((user, challenge.master_password))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::{
+            jwt::get_user_id_from_token,
+            users::{create_user, login_two_factor, login_user, recover_account},
+            vault::lock_vault,
+            vault_session::is_unlocked,
+        },
+        models::{LoginData, LoginResult, RecoverAccountData, RegisterData, TwoFactorLoginData},
+    };
+    use security::totp;
+
+    fn init_two_factor_key() {
+        set_two_factor_key(Some(&[7; DATA_KEY_LENGTH])).unwrap();
+    }
+
+    // Code of the time step `steps` after the current one
+    fn code(user: &User, steps: u64) -> String {
+        let secret = open_secret(user).unwrap();
+        totp(
+            secret.expose_secret(),
+            now() as u64 + steps * TOTP_PERIOD,
+            TOTP_PERIOD,
+            TOTP_DIGITS,
+            OtpAlgorithm::Sha1,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_otpauth_url() {
+        assert_eq!(
+            otpauth_url(
+                "user@example.com",
+                base32_decode("JBSWY3DPEHPK3PXP").unwrap()
+            ),
+            "otpauth://totp/Indidus:user@example.com?secret=JBSWY3DPEHPK3PXP\
+             &issuer=Indidus&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_two_factor_key_length() {
+        assert!(set_two_factor_key(Some(&[7; 16])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_two_factor_lifecycle() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        let user_id = sql::get_ulid();
+        User::insert(User {
+            id: Some(user_id.clone()),
+            created_at: Some(now() as i64),
+            created_by: Some(user_id.clone()),
+            email: format!("{}@example.com", user_id.to_lowercase()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            disable_two_factor(&user_id, "000000").await,
+            Err(AppError::TOTPNotEnabled)
+        ));
+        let setup = begin_two_factor(&user_id).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        assert!(!user.two_factor_enabled);
+        // Stored sealed, and only opens for its own user
+        assert_ne!(
+            user.two_factor_secret.as_deref(),
+            Some(setup.secret.expose_secret())
+        );
+        assert_eq!(
+            base32_encode(open_secret(&user).unwrap().expose_secret()),
+            setup.secret.expose_secret()
+        );
+        let moved = User {
+            id: Some(sql::get_ulid()),
+            ..user.clone()
+        };
+        assert!(matches!(
+            open_secret(&moved),
+            Err(AppError::TOTPSecretParseError)
+        ));
+
+        let code = code(&user, 0);
+        let wrong = if code == "000000" { "111111" } else { "000000" };
+        assert!(matches!(
+            confirm_two_factor(&user_id, wrong).await,
+            Err(AppError::TOTPVerificationFailed)
+        ));
+        confirm_two_factor(&user_id, &code).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        assert!(user.two_factor_enabled);
+        assert!(matches!(
+            begin_two_factor(&user_id).await,
+            Err(AppError::TOTPAlreadyEnabled)
+        ));
+
+        // A code works once
+        assert!(matches!(
+            disable_two_factor(&user_id, &code).await,
+            Err(AppError::TOTPVerificationFailed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backup_codes() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        let user_id = sql::get_ulid();
+        User::insert(User {
+            id: Some(user_id.clone()),
+            created_at: Some(now() as i64),
+            created_by: Some(user_id.clone()),
+            email: format!("{}@example.com", user_id.to_lowercase()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        begin_two_factor(&user_id).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        let backup = confirm_two_factor(&user_id, &code(&user, 0)).await.unwrap();
+        let codes = backup
+            .codes
+            .iter()
+            .map(|code| code.expose_secret().to_string())
+            .collect::<Vec<_>>();
+        let status = || async { two_factor_status(&user_id).await.unwrap() };
+
+        assert_eq!(codes.len(), BACKUP_CODE_COUNT);
+        assert!(codes
+            .iter()
+            .all(|code| code.len() == BACKUP_CODE_LENGTH + 1));
+        assert_eq!(
+            status().await,
+            TwoFactorStatus {
+                enabled: true,
+                backup_codes_remaining: BACKUP_CODE_COUNT as u32,
+            }
+        );
+
+        // Typed without the dash and in capitals, then spent
+        let typed = codes[0].replace('-', "").to_uppercase();
+        verify_second_factor(&user, &typed).unwrap();
+        assert!(matches!(
+            verify_second_factor(&user, &codes[0]),
+            Err(AppError::TOTPVerificationFailed)
+        ));
+        assert_eq!(
+            status().await.backup_codes_remaining,
+            BACKUP_CODE_COUNT as u32 - 1
+        );
+
+        // A new set replaces the old one
+        let backup = regenerate_backup_codes(&user_id, &codes[1]).await.unwrap();
+        assert!(verify_second_factor(&user, &codes[2]).is_err());
+        assert_eq!(
+            status().await.backup_codes_remaining,
+            BACKUP_CODE_COUNT as u32
+        );
+
+        disable_two_factor(&user_id, backup.codes[0].expose_secret())
+            .await
+            .unwrap();
+        assert_eq!(
+            status().await,
+            TwoFactorStatus {
+                enabled: false,
+                backup_codes_remaining: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_two_factor_lockout() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        let user_id = sql::get_ulid();
+        User::insert(User {
+            id: Some(user_id.clone()),
+            created_at: Some(now() as i64),
+            created_by: Some(user_id.clone()),
+            email: format!("{}@example.com", user_id.to_lowercase()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        begin_two_factor(&user_id).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        confirm_two_factor(&user_id, &code(&user, 0)).await.unwrap();
+        let locked_until = || {
+            let conn = sql::get_db_connection().unwrap();
+            conn.query_row(
+                "SELECT two_factor_locked_until FROM users WHERE id = ?",
+                [&user_id],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .unwrap()
+        };
+
+        // Far outside the accepted steps, so always wrong
+        let wrong = code(&user, 10);
+        for _ in 0..MAX_FAILED_CODES {
+            assert!(matches!(
+                verify_second_factor(&user, &wrong),
+                Err(AppError::TOTPVerificationFailed)
+            ));
+        }
+        let first = locked_until().unwrap();
+        assert!(first > now() as i64);
+
+        // Locked, the right code and new challenges are refused too
+        assert!(matches!(
+            verify_second_factor(&user, &code(&user, 1)),
+            Err(AppError::TwoFactorLocked)
+        ));
+        assert!(matches!(
+            issue_challenge(&user_id, "Master@12345".into()),
+            Err(AppError::TwoFactorLocked)
+        ));
+
+        // The next wrong code after the wait locks for longer
+        let conn = sql::get_db_connection().unwrap();
+        conn.execute(
+            "UPDATE users SET two_factor_locked_until = ? WHERE id = ?",
+            (now() as i64 - 1, &user_id),
+        )
+        .unwrap();
+        verify_second_factor(&user, &wrong).unwrap_err();
+        assert!(locked_until().unwrap() - now() as i64 > LOCKOUT_SECONDS);
+
+        conn.execute(
+            "UPDATE users SET two_factor_locked_until = ? WHERE id = ?",
+            (now() as i64 - 1, &user_id),
+        )
+        .unwrap();
+        verify_second_factor(&user, &code(&user, 1)).unwrap();
+        assert_eq!(locked_until(), None);
+        issue_challenge(&user_id, "Master@12345".into()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_challenge() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        assert!(matches!(
+            answer_challenge("unknown", "000000").await,
+            Err(AppError::TwoFactorChallengeInvalid)
+        ));
+
+        let user_id = sql::get_ulid();
+        let challenge = issue_challenge(&user_id, "Master@12345".into()).unwrap();
+        assert_eq!(challenge.expires_in, CHALLENGE_SECONDS);
+        CHALLENGES
+            .lock()
+            .unwrap()
+            .get_mut(challenge.challenge_token.expose_secret())
+            .unwrap()
+            .expires_at = now() as u64;
+        assert!(matches!(
+            answer_challenge(challenge.challenge_token.expose_secret(), "000000").await,
+            Err(AppError::TwoFactorChallengeInvalid)
+        ));
+        assert!(!CHALLENGES
+            .lock()
+            .unwrap()
+            .contains_key(challenge.challenge_token.expose_secret()));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_two_factor() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        let email = format!("{}@example.com", sql::get_ulid().to_lowercase());
+        let login = || LoginData {
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+        };
+        create_user(RegisterData {
+            name: "User".to_string(),
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+            re_password: "Master-lantern-orbit-71".into(),
+            recovery_key: false,
+        })
+        .await
+        .unwrap();
+        let LoginResult::Tokens(tokens) = login_user(login()).await.unwrap() else {
+            panic!("two-factor authentication is off");
+        };
+        let user_id = get_user_id_from_token(tokens.access_token.into_exposed())
+            .await
+            .unwrap();
+        begin_two_factor(&user_id).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        // Hashing the backup codes can take the clock past the step of this
+        // code, so the replay below reuses it rather than the current code
+        let confirm_code = code(&user, 0);
+        let backup = confirm_two_factor(&user_id, &confirm_code).await.unwrap();
+
+        lock_vault(&user_id);
+        let LoginResult::TwoFactorRequired(challenge) = login_user(login()).await.unwrap() else {
+            panic!("two-factor authentication is on");
+        };
+        assert!(!is_unlocked(&user_id));
+        let answer = |code: String| TwoFactorLoginData {
+            challenge_token: challenge.challenge_token.clone(),
+            code,
+        };
+        assert!(matches!(
+            login_two_factor(answer(confirm_code)).await,
+            Err(AppError::TOTPVerificationFailed)
+        ));
+        login_two_factor(answer(code(&user, 1))).await.unwrap();
+        assert!(is_unlocked(&user_id));
+
+        // Spent
+        assert!(matches!(
+            login_two_factor(answer(code(&user, 1))).await,
+            Err(AppError::TwoFactorChallengeInvalid)
+        ));
+
+        // A backup code stands in for the authenticator
+        lock_vault(&user_id);
+        let LoginResult::TwoFactorRequired(challenge) = login_user(login()).await.unwrap() else {
+            panic!("two-factor authentication is on");
+        };
+        login_two_factor(TwoFactorLoginData {
+            challenge_token: challenge.challenge_token,
+            code: backup.codes[0].expose_secret().to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(is_unlocked(&user_id));
+    }
+
+    #[tokio::test]
+    async fn test_recover_account_with_two_factor() {
+        crate::common::test_utils::init_test_db().await;
+        init_two_factor_key();
+        let email = format!("{}@example.com", sql::get_ulid().to_lowercase());
+        let tokens = create_user(RegisterData {
+            name: "User".to_string(),
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+            re_password: "Master-lantern-orbit-71".into(),
+            recovery_key: true,
+        })
+        .await
+        .unwrap();
+        let recovery_key = tokens.recovery_key.unwrap();
+        let user_id = get_user_id_from_token(tokens.access_token.into_exposed())
+            .await
+            .unwrap();
+        begin_two_factor(&user_id).await.unwrap();
+        let user = my_profile(user_id.clone()).await.unwrap();
+        let backup = confirm_two_factor(&user_id, &code(&user, 0)).await.unwrap();
+
+        let recover = |two_factor_code: Option<String>| RecoverAccountData {
+            email: email.clone(),
+            recovery_key: recovery_key.clone(),
+            new_password: "Recovered-harbor-kiwi-28".into(),
+            re_password: "Recovered-harbor-kiwi-28".into(),
+            two_factor_code,
+        };
+        assert!(matches!(
+            recover_account(recover(None)).await,
+            Err(AppError::TwoFactorCodeRequired)
+        ));
+        assert!(matches!(
+            recover_account(recover(Some("0000000000".to_string()))).await,
+            Err(AppError::TOTPVerificationFailed)
+        ));
+        let backup_code = backup.codes[0].expose_secret().to_string();
+        recover_account(recover(Some(backup_code))).await.unwrap();
+    }
+}
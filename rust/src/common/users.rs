@@ -1,28 +1,37 @@
-use sql::{FilterOperator, HttpQuery};
+use security::SecretString;
+use sql::{FilterOperator, HttpQuery, SqlError};
 
-use crate::models::{JwtTokens, LoginData, RegisterData, User};
+use crate::models::{
+    ChangePasswordData, JwtTokens, LoginData, LoginResult, RecoverAccountData, RegisterData,
+    TwoFactorLoginData, User, VaultKey,
+};
 
 use super::{
     errors::AppError,
-    jwt::generate_access_and_refresh_tokens,
-    password::{password_hash, verify_password},
+    password::{needs_rehash, password_hash, verify_password},
+    refresh_tokens::{issue_tokens, revoke_sessions},
     time::now,
-    validation::{is_valid_email_regex, is_valid_password},
+    two_factor::{answer_challenge, issue_challenge, verify_second_factor},
+    validation::{check_password, is_valid_email_regex},
+    vault::{
+        create_recovery_key, recover_vault_key, rewrap_vault_key, save_vault_key, setup_vault,
+        unlock_vault,
+    },
 };
 
 pub async fn create_user(data: RegisterData) -> Result<JwtTokens, AppError> {
     if data.password != data.re_password {
         return Err(AppError::PasswordsDoNotMatch);
     }
-    if !is_valid_password(&data.password) {
-        return Err(AppError::PasswordTooWeak);
-    }
+    check_password(data.password.expose_secret(), &[&data.name, &data.email])?;
     if !is_valid_email_regex(&data.email) {
         return Err(AppError::InvalidEmail);
     }
 
     let email = data.email.to_lowercase();
-    let password_hash = password_hash(&data.password)?;
+    let password_hash = password_hash(data.password.expose_secret())?;
+    let master_password = data.password.clone();
+    let with_recovery_key = data.recovery_key;
 
     let mut user = User::from(data);
     user.password_hash = password_hash;
@@ -35,12 +44,18 @@ pub async fn create_user(data: RegisterData) -> Result<JwtTokens, AppError> {
     user.created_by = Some(id);
 
     let user = User::insert(user).await?;
+    setup_vault(user.id.as_ref().unwrap(), &master_password).await?;
+    let recovery_key = match with_recovery_key {
+        true => Some(create_recovery_key(user.id.as_ref().unwrap()).await?),
+        false => None,
+    };
 
     // Login the user after registration
-    let (access_token, refresh_token) = generate_access_and_refresh_tokens(user.id.unwrap())?;
+    let (access_token, refresh_token) = issue_tokens(user.id.as_ref().unwrap())?;
     Ok(JwtTokens {
         access_token,
         refresh_token,
+        recovery_key,
     })
 }
 
@@ -61,8 +76,8 @@ pub async fn my_profile(user_id: String) -> Result<User, AppError> {
     Ok(users.first().unwrap().to_owned())
 }
 
-pub async fn login_user(data: LoginData) -> Result<JwtTokens, AppError> {
-    let email = data.email.to_lowercase();
+async fn find_user_by_email(email: &str) -> Result<Option<User>, AppError> {
+    let email = email.to_lowercase();
     let query = HttpQuery::builder()
         .filter(|filter| {
             filter
@@ -74,24 +89,321 @@ pub async fn login_user(data: LoginData) -> Result<JwtTokens, AppError> {
         .limit(1)
         .build();
 
-    let users = User::get_list(query)
-        .await?
-        .into_iter()
-        .collect::<Vec<User>>();
+    let users = User::get_list(query).await?;
+    Ok(users.into_iter().next())
+}
 
-    if users.is_empty() {
+/// Checks the password of a user and signs them in, or asks for a code
+/// when two-factor authentication is on.
+pub async fn login_user(data: LoginData) -> Result<LoginResult, AppError> {
+    let user = match find_user_by_email(&data.email).await? {
+        Some(user) => user,
+        None => return Err(AppError::InvalidCredentials),
+    };
+    let match_password = verify_password(data.password.expose_secret(), &user.password_hash)?;
+    if !match_password {
         return Err(AppError::InvalidCredentials);
     }
-    let user = users.first().unwrap();
-    let match_password = verify_password(&data.password, &user.password_hash)?;
+    if user.two_factor_enabled {
+        let challenge = issue_challenge(user.id.as_ref().unwrap(), data.password)?;
+        return Ok(LoginResult::TwoFactorRequired(challenge));
+    }
+    let tokens = complete_login(&user, &data.password).await?;
+    Ok(LoginResult::Tokens(tokens))
+}
+
+/// Finishes a login [`login_user`] answered with a two-factor challenge.
+pub async fn login_two_factor(data: TwoFactorLoginData) -> Result<JwtTokens, AppError> {
+    let (user, master_password) =
+        answer_challenge(data.challenge_token.expose_secret(), &data.code).await?;
+    complete_login(&user, &master_password).await
+}
+
+async fn complete_login(
+    user: &User,
+    master_password: &SecretString,
+) -> Result<JwtTokens, AppError> {
+    unlock_vault(user.id.as_ref().unwrap(), master_password).await?;
+    // The password is only known here, so older hashes are upgraded on login
+    if needs_rehash(&user.password_hash) {
+        let password_hash = password_hash(master_password.expose_secret())?;
+        save_password_hash(user.id.as_ref().unwrap(), &password_hash)?;
+    }
+
+    let (access_token, refresh_token) = issue_tokens(user.id.as_ref().unwrap())?;
+    Ok(JwtTokens {
+        access_token,
+        refresh_token,
+        recovery_key: None,
+    })
+}
+
+fn save_password_hash(user_id: &str, password_hash: &str) -> Result<(), AppError> {
+    let conn = sql::get_db_connection()?;
+    conn.execute(
+        "UPDATE users SET password_hash = ? WHERE id = ?",
+        (password_hash, user_id),
+    )
+    .map_err(SqlError::from)?;
+    Ok(())
+}
+
+// Replaces the password hash and the wrapped data key together, so the
+// account password and the master password never drift apart
+fn save_password(user_id: &str, password_hash: &str, vault_key: &VaultKey) -> Result<(), AppError> {
+    let mut conn = sql::get_db_connection()?;
+    let tx = conn.transaction().map_err(SqlError::from)?;
+    tx.execute(
+        "UPDATE users SET password_hash = ?, updated_at = ?, updated_by = ? WHERE id = ?",
+        (password_hash, now() as i64, user_id, user_id),
+    )
+    .map_err(SqlError::from)?;
+    save_vault_key(&tx, vault_key)?;
+    tx.commit().map_err(SqlError::from)?;
+    Ok(())
+}
+
+/// Changes the password of a user, which is also the master password of the
+/// vault.
+///
+/// The password hash and the wrapped data key are replaced in one
+/// transaction, items stay sealed with the same data key.
+pub async fn change_master_password(
+    user_id: String,
+    data: ChangePasswordData,
+) -> Result<(), AppError> {
+    if data.new_password != data.re_password {
+        return Err(AppError::PasswordsDoNotMatch);
+    }
+    let user = my_profile(user_id.clone()).await?;
+    let match_password = verify_password(data.old_password.expose_secret(), &user.password_hash)?;
     if !match_password {
         return Err(AppError::InvalidCredentials);
     }
+    check_password(
+        data.new_password.expose_secret(),
+        &[&user.name, &user.email],
+    )?;
+    let password_hash = password_hash(data.new_password.expose_secret())?;
+    let vault_key = rewrap_vault_key(&user_id, &data.old_password, &data.new_password).await?;
+    save_password(&user_id, &password_hash, &vault_key)
+}
+
+/// Sets a new password for a user who lost it, proven with the recovery key.
+///
+/// The vault is opened with the recovery key, so every item stays readable.
+/// The recovery key is rotated and the new one is returned with the tokens.
+/// With two-factor authentication on, a code is needed as well, and every
+/// other session of the user ends.
+pub async fn recover_account(data: RecoverAccountData) -> Result<JwtTokens, AppError> {
+    if data.new_password != data.re_password {
+        return Err(AppError::PasswordsDoNotMatch);
+    }
+    let user = find_user_by_email(&data.email)
+        .await?
+        .ok_or(AppError::InvalidRecoveryKey)?;
+    check_password(
+        data.new_password.expose_secret(),
+        &[&user.name, &user.email],
+    )?;
+    let user_id = user.id.clone().unwrap();
 
-    let (access_token, refresh_token) =
-        generate_access_and_refresh_tokens(user.id.clone().unwrap())?;
+    let password_hash = password_hash(data.new_password.expose_secret())?;
+    let (vault_key, recovery_key) =
+        recover_vault_key(&user_id, &data.recovery_key, &data.new_password).await?;
+    // Checked once the recovery key is, a code is spent even when it fails
+    if user.two_factor_enabled {
+        let code = data
+            .two_factor_code
+            .as_deref()
+            .ok_or(AppError::TwoFactorCodeRequired)?;
+        verify_second_factor(&user, code)?;
+    }
+    save_password(&user_id, &password_hash, &vault_key)?;
+    unlock_vault(&user_id, &data.new_password).await?;
+
+    // Whoever else held a session loses it with the old password
+    revoke_sessions(&user_id)?;
+    let (access_token, refresh_token) = issue_tokens(&user_id)?;
     Ok(JwtTokens {
         access_token,
         refresh_token,
+        recovery_key: Some(recovery_key),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{
+        jwt::get_user_id_from_token,
+        vault::{get_data_key, lock_vault},
+    };
+
+    async fn register(email: &str) -> String {
+        create_user(RegisterData {
+            name: "User".to_string(),
+            email: email.to_string(),
+            password: "Master-lantern-orbit-71".into(),
+            re_password: "Master-lantern-orbit-71".into(),
+            recovery_key: false,
+        })
+        .await
+        .unwrap();
+        let query = HttpQuery::builder()
+            .filter(|filter| {
+                filter
+                    .column("email")
+                    .operator(FilterOperator::Eq)
+                    .value(email)
+                    .build()
+            })
+            .build();
+        User::get_list(query).await.unwrap()[0].id.clone().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_change_master_password() {
+        crate::common::test_utils::init_test_db().await;
+
+        let email = format!("{}@example.com", sql::get_ulid().to_lowercase());
+        let user_id = register(&email).await;
+        let key = get_data_key(&user_id).unwrap();
+
+        let wrong = change_master_password(
+            user_id.clone(),
+            ChangePasswordData {
+                old_password: "Wrong@12345".into(),
+                new_password: "Changed-staple-fig-93".into(),
+                re_password: "Changed-staple-fig-93".into(),
+            },
+        )
+        .await;
+        assert!(matches!(wrong, Err(AppError::InvalidCredentials)));
+
+        change_master_password(
+            user_id.clone(),
+            ChangePasswordData {
+                old_password: "Master-lantern-orbit-71".into(),
+                new_password: "Changed-staple-fig-93".into(),
+                re_password: "Changed-staple-fig-93".into(),
+            },
+        )
+        .await
+        .unwrap();
+
+        lock_vault(&user_id);
+        let old_login = login_user(LoginData {
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+        })
+        .await;
+        assert!(matches!(old_login, Err(AppError::InvalidCredentials)));
+        login_user(LoginData {
+            email,
+            password: "Changed-staple-fig-93".into(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(get_data_key(&user_id).unwrap(), key);
+    }
+
+    #[tokio::test]
+    async fn test_recover_account() {
+        crate::common::test_utils::init_test_db().await;
+
+        let email = format!("{}@example.com", sql::get_ulid().to_lowercase());
+        let tokens = create_user(RegisterData {
+            name: "User".to_string(),
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+            re_password: "Master-lantern-orbit-71".into(),
+            recovery_key: true,
+        })
+        .await
+        .unwrap();
+        let recovery_key = tokens.recovery_key.unwrap();
+        let user_id = find_user_by_email(&email)
+            .await
+            .unwrap()
+            .unwrap()
+            .id
+            .unwrap();
+        let key = get_data_key(&user_id).unwrap();
+        lock_vault(&user_id);
+
+        let recover = |recovery_key: SecretString| RecoverAccountData {
+            email: email.clone(),
+            recovery_key,
+            new_password: "Recovered-harbor-kiwi-28".into(),
+            re_password: "Recovered-harbor-kiwi-28".into(),
+            two_factor_code: None,
+        };
+        let other_key = security::generate_recovery_key();
+        assert!(matches!(
+            recover_account(recover(other_key)).await,
+            Err(AppError::InvalidRecoveryKey)
+        ));
+
+        let old_token = tokens.access_token.into_exposed();
+        assert!(get_user_id_from_token(old_token.clone()).await.is_ok());
+        let tokens = recover_account(recover(recovery_key.clone()))
+            .await
+            .unwrap();
+        let rotated = tokens.recovery_key.unwrap();
+        assert_ne!(rotated, recovery_key);
+        assert_eq!(get_data_key(&user_id).unwrap(), key);
+
+        // Sessions from before the recovery end, the new one works
+        assert!(get_user_id_from_token(old_token).await.is_err());
+        assert_eq!(
+            get_user_id_from_token(tokens.access_token.into_exposed())
+                .await
+                .unwrap(),
+            user_id
+        );
+
+        // The used recovery key is spent, the rotated one takes its place
+        assert!(matches!(
+            recover_account(recover(recovery_key)).await,
+            Err(AppError::InvalidRecoveryKey)
+        ));
+        lock_vault(&user_id);
+        login_user(LoginData {
+            email,
+            password: "Recovered-harbor-kiwi-28".into(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(get_data_key(&user_id).unwrap(), key);
+    }
+
+    #[tokio::test]
+    async fn test_login_rehashes_legacy_hash() {
+        crate::common::test_utils::init_test_db().await;
+
+        let email = format!("{}@example.com", sql::get_ulid().to_lowercase());
+        let user_id = register(&email).await;
+        let legacy = bcrypt::hash("Master-lantern-orbit-71", 4).unwrap();
+        save_password_hash(&user_id, &legacy).unwrap();
+
+        lock_vault(&user_id);
+        login_user(LoginData {
+            email: email.clone(),
+            password: "Master-lantern-orbit-71".into(),
+        })
+        .await
+        .unwrap();
+        let user = find_user_by_email(&email).await.unwrap().unwrap();
+        assert!(user.password_hash.starts_with("$argon2id$"));
+        assert!(!needs_rehash(&user.password_hash));
+
+        lock_vault(&user_id);
+        login_user(LoginData {
+            email,
+            password: "Master-lantern-orbit-71".into(),
+        })
+        .await
+        .unwrap();
+    }
+}
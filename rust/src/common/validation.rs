@@ -1,5 +1,10 @@
+use once_cell::sync::Lazy;
 use regex::Regex;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
+
+use crate::models::{PasswordPolicy, PasswordRule};
+
+use super::{errors::AppError, strength::estimate_strength};
 
 const EMAIL_REGEX_STR: &str = r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$";
 
@@ -11,28 +16,114 @@ pub fn is_valid_email_regex(email: &str) -> bool {
     regex.is_match(email)
 }
 
-const PASSWORD_MIN_LENGTH: usize = 8; // Minimum password length
-const PASSWORD_MAX_LENGTH: usize = 64; // Maximum password length (optional)
+// Policy enforced on account passwords, see `set_password_policy`
+static PASSWORD_POLICY: Lazy<RwLock<PasswordPolicy>> =
+    Lazy::new(|| RwLock::new(PasswordPolicy::default()));
+
+/// Sets the process wide policy account passwords are checked against.
+pub fn set_password_policy(policy: PasswordPolicy) -> Result<(), AppError> {
+    let lengths_valid = policy.min_length <= policy.max_length && policy.max_length > 0;
+    if !lengths_valid || policy.min_score > 4 {
+        return Err(AppError::InvalidPasswordConfig);
+    }
+    *PASSWORD_POLICY.write().unwrap() = policy;
+    Ok(())
+}
+
+pub fn password_policy() -> PasswordPolicy {
+    PASSWORD_POLICY.read().unwrap().clone()
+}
+
+// Whether the class is required, the rule broken without it, and its test
+type CharClass = (bool, PasswordRule, fn(char) -> bool);
 
-pub fn is_valid_password(password: &str) -> bool {
-    let len = password.len();
-    if len < PASSWORD_MIN_LENGTH || len > PASSWORD_MAX_LENGTH {
-        return false;
+/// Every rule of `policy` that `password` fails. `user_inputs` are the
+/// name, email and other details of the user, which make a password easier
+/// to guess.
+pub fn password_violations(
+    policy: &PasswordPolicy,
+    password: &str,
+    user_inputs: &[&str],
+) -> Vec<PasswordRule> {
+    let mut violations = Vec::new();
+    let length = password.chars().count() as u32;
+    if length < policy.min_length {
+        violations.push(PasswordRule::TooShort {
+            min_length: policy.min_length,
+        });
     }
-    let mut criteria = 0b0000;
-
-    for &byte in password.as_bytes() {
-        match byte {
-            b'A'..=b'Z' => criteria |= 0b0001,
-            b'a'..=b'z' => criteria |= 0b0010,
-            b'0'..=b'9' => criteria |= 0b0100,
-            b'!' | b'@' | b'#' | b'$' | b'%' | b'^' | b'&' | b'*' | b'_' | b'-' => {
-                criteria |= 0b1000
-            }
-            _ => return false, // Invalid character
+    if length > policy.max_length {
+        violations.push(PasswordRule::TooLong {
+            max_length: policy.max_length,
+        });
+    }
+    let classes: [CharClass; 4] = [
+        (
+            policy.require_uppercase,
+            PasswordRule::MissingUppercase,
+            char::is_uppercase,
+        ),
+        (
+            policy.require_lowercase,
+            PasswordRule::MissingLowercase,
+            char::is_lowercase,
+        ),
+        (
+            policy.require_digit,
+            PasswordRule::MissingDigit,
+            |c: char| c.is_ascii_digit(),
+        ),
+        (
+            policy.require_symbol,
+            PasswordRule::MissingSymbol,
+            |c: char| !c.is_alphanumeric() && !c.is_whitespace(),
+        ),
+    ];
+    for (required, rule, matches) in classes {
+        if required && !password.chars().any(matches) {
+            violations.push(rule);
+        }
+    }
+    if !policy.allow_unicode && !password.is_ascii() {
+        violations.push(PasswordRule::UnicodeNotAllowed);
+    }
+    let lowercase = password.to_lowercase();
+    for word in &policy.banned_words {
+        if !word.is_empty() && lowercase.contains(&word.to_lowercase()) {
+            violations.push(PasswordRule::BannedWord { word: word.clone() });
         }
     }
-    criteria == 0b1111
+    // Long passwords are not worth rating, they fail on length already
+    if length <= policy.max_length {
+        let banned = policy.banned_words.iter().map(String::as_str);
+        let inputs = user_inputs
+            .iter()
+            .copied()
+            .chain(banned)
+            .collect::<Vec<_>>();
+        let score = estimate_strength(password, &inputs).score;
+        if score < policy.min_score {
+            violations.push(PasswordRule::TooWeak {
+                score,
+                min_score: policy.min_score,
+            });
+        }
+    }
+    violations
+}
+
+/// Checks an account password against the current policy, every failed
+/// rule is listed in the error.
+pub fn check_password(password: &str, user_inputs: &[&str]) -> Result<(), AppError> {
+    let violations = password_violations(&password_policy(), password, user_inputs);
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(AppError::PasswordPolicyViolation(violations)),
+    }
+}
+
+pub fn is_valid_password(password: &str, user_inputs: &[&str]) -> bool {
+    check_password(password, user_inputs).is_ok()
 }
 
 #[cfg(test)]
@@ -93,10 +184,83 @@ mod tests {
 
     #[test]
     fn test_is_valid_password() {
-        assert_eq!(super::is_valid_password("AshimTest@1234"), true);
-        assert_eq!(super::is_valid_password("Password123"), false);
-        assert_eq!(super::is_valid_password("password123"), false);
-        assert_eq!(super::is_valid_password("password123!"), false);
-        assert_eq!(super::is_valid_password(""), false);
+        assert_eq!(super::is_valid_password("AshimTest@1234", &[]), true);
+        assert_eq!(super::is_valid_password("Password123", &[]), false);
+        assert_eq!(super::is_valid_password("password123", &[]), false);
+        assert_eq!(super::is_valid_password("password123!", &[]), false);
+        assert_eq!(super::is_valid_password("", &[]), false);
+    }
+
+    #[test]
+    fn test_is_valid_password_strength() {
+        assert!(super::is_valid_password(
+            "correct horse battery staple",
+            &[]
+        ));
+        assert!(!super::is_valid_password("Password1!", &[]));
+        assert!(!super::is_valid_password("Xq9#", &[]));
+        assert!(!super::is_valid_password(
+            "Ashim@Test.com",
+            &["Ashim Test", "ashim@test.com"]
+        ));
+    }
+
+    #[test]
+    fn test_password_violations() {
+        use crate::models::{PasswordPolicy, PasswordRule};
+
+        let strict = PasswordPolicy {
+            min_length: 12,
+            max_length: 16,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: true,
+            min_score: 4,
+            allow_unicode: false,
+            banned_words: vec!["Acme".to_string()],
+        };
+
+        assert_eq!(
+            super::password_violations(&strict, "acmé", &[]),
+            vec![
+                PasswordRule::TooShort { min_length: 12 },
+                PasswordRule::MissingUppercase,
+                PasswordRule::MissingDigit,
+                PasswordRule::MissingSymbol,
+                PasswordRule::UnicodeNotAllowed,
+                PasswordRule::TooWeak {
+                    score: 1,
+                    min_score: 4
+                },
+            ]
+        );
+        assert_eq!(
+            super::password_violations(&strict, "xACME-corp-9f2#kq", &[]),
+            vec![
+                PasswordRule::TooLong { max_length: 16 },
+                PasswordRule::BannedWord {
+                    word: "Acme".to_string()
+                },
+            ]
+        );
+        assert!(super::password_violations(&strict, "Vq7#mZp2!rLw9x", &[]).is_empty());
+        assert!(
+            super::password_violations(&PasswordPolicy::default(), "Vq7#mZp2!rLw9x", &[])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_set_invalid_password_policy() {
+        use crate::models::PasswordPolicy;
+
+        let policy = PasswordPolicy {
+            min_length: 20,
+            max_length: 10,
+            ..Default::default()
+        };
+        assert!(super::set_password_policy(policy).is_err());
+        assert_eq!(super::password_policy(), PasswordPolicy::default());
     }
 }
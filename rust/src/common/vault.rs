@@ -0,0 +1,1103 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use rusqlite::{types::ToSql, Connection, Transaction, TransactionBehavior};
+use security::{
+    check_share_threshold, decrypt_with_key, decrypt_with_key_aad, encrypt_with_key_aad,
+    generate_data_key, generate_recovery_key, needs_reencrypt, reencrypt, split_recovery_key,
+    unwrap_data_key, unwrap_data_key_with_recovery_key, wrap_data_key, wrap_data_key_with_params,
+    wrap_data_key_with_recovery_key, AssociatedData, EncryptionError, KdfParams, SecretBytes,
+    SecretString,
+};
+use sql::{FilterOperator, HttpQuery, SqlError};
+
+use crate::models::{
+    FinancialCard, IdentityCard, Login, Passkey, PasswordHistory, RekeyProgress, RekeyResult,
+    ShareSettings, VaultKey,
+};
+
+use super::{
+    errors::AppError,
+    time::now,
+    vault_session::{lock, session_key, start_session},
+};
+
+// Sealed item columns are bound to their table, column, record and owner
+pub const ITEM_VERSION: i64 = 2;
+
+// Progress of the latest re-key job, keyed by user id
+static REKEY_PROGRESS: Lazy<Mutex<HashMap<String, RekeyProgress>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Vault items whose secret columns are sealed with the owner's data key
+/// before they reach the database.
+///
+/// Every column is bound to where it is stored, a ciphertext copied into
+/// another column, record or vault no longer opens.
+pub trait SealedItem {
+    const TABLE: &'static str;
+
+    fn record_id(&self) -> Option<&str>;
+    fn owner_id(&self) -> Option<&str>;
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)>;
+
+    fn seal(&mut self, key: &SecretBytes) -> Result<(), AppError> {
+        let (id, owner) = binding(self)?;
+        for (column, value) in self.sealed_columns() {
+            let aad = associated_data(Self::TABLE, column, &id, &owner);
+            let sealed = encrypt_with_key_aad(value.expose_secret(), key.expose_secret(), &aad)?;
+            *value = sealed.into();
+        }
+        Ok(())
+    }
+
+    fn open(&mut self, key: &SecretBytes) -> Result<(), AppError> {
+        let (id, owner) = binding(self)?;
+        for (column, value) in self.sealed_columns() {
+            let aad = associated_data(Self::TABLE, column, &id, &owner);
+            let opened = decrypt_with_key_aad(value.expose_secret(), key.expose_secret(), &aad)?;
+            *value = opened.into();
+        }
+        Ok(())
+    }
+
+    /// Opens columns sealed before they were bound to their record.
+    fn open_unbound(&mut self, key: &SecretBytes) -> Result<(), AppError> {
+        for (_, value) in self.sealed_columns() {
+            let opened = decrypt_with_key(value.expose_secret(), key.expose_secret())?;
+            *value = opened.into();
+        }
+        Ok(())
+    }
+}
+
+fn binding<T: SealedItem + ?Sized>(item: &T) -> Result<(String, String), AppError> {
+    match (item.record_id(), item.owner_id()) {
+        (Some(id), Some(owner)) => Ok((id.to_string(), owner.to_string())),
+        _ => Err(AppError::LibraryError(format!(
+            "{} item needs an id and an owner to be sealed",
+            T::TABLE
+        ))),
+    }
+}
+
+fn associated_data(table: &str, column: &str, id: &str, owner: &str) -> AssociatedData {
+    AssociatedData::new()
+        .field(table)
+        .field(column)
+        .field(id)
+        .field(owner)
+}
+
+impl SealedItem for Login {
+    const TABLE: &'static str = "logins";
+
+    fn record_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn owner_id(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)> {
+        let mut columns = Vec::new();
+        if let Some(password) = self.password.as_mut() {
+            columns.push(("password", password));
+        }
+        if let Some(otpauth) = self.otpauth.as_mut() {
+            columns.push(("otpauth", otpauth));
+        }
+        columns
+    }
+}
+
+impl SealedItem for FinancialCard {
+    const TABLE: &'static str = "financial_cards";
+
+    fn record_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn owner_id(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)> {
+        let mut columns = vec![("card_number", &mut self.card_number)];
+        if let Some(cvv) = self.cvv.as_mut() {
+            columns.push(("cvv", cvv));
+        }
+        if let Some(pin) = self.pin.as_mut() {
+            columns.push(("pin", pin));
+        }
+        columns
+    }
+}
+
+impl SealedItem for IdentityCard {
+    const TABLE: &'static str = "identity_cards";
+
+    fn record_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn owner_id(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)> {
+        vec![("identity_card_number", &mut self.identity_card_number)]
+    }
+}
+
+impl SealedItem for PasswordHistory {
+    const TABLE: &'static str = "password_history";
+
+    fn record_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn owner_id(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)> {
+        vec![("password", &mut self.password)]
+    }
+}
+
+impl SealedItem for Passkey {
+    const TABLE: &'static str = "passkeys";
+
+    fn record_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn owner_id(&self) -> Option<&str> {
+        self.created_by.as_deref()
+    }
+
+    fn sealed_columns(&mut self) -> Vec<(&'static str, &mut SecretString)> {
+        vec![("private_key", &mut self.private_key)]
+    }
+}
+
+/// Returns the data key of a user whose vault is unlocked.
+pub fn get_data_key(user_id: &str) -> Result<SecretBytes, AppError> {
+    session_key(user_id)
+}
+
+/// Wipes the data key of a user, sealed items stay unreadable until the
+/// vault is unlocked again.
+pub fn lock_vault(user_id: &str) {
+    lock(user_id);
+}
+
+async fn fetch_vault_key(user_id: &str) -> Result<Option<VaultKey>, AppError> {
+    let conn = sql::get_db_connection()?;
+    fetch_vault_key_with(&conn, user_id)
+}
+
+fn fetch_vault_key_with(conn: &Connection, user_id: &str) -> Result<Option<VaultKey>, AppError> {
+    let query = HttpQuery::builder()
+        .filter(|filter| {
+            filter
+                .column("created_by")
+                .operator(FilterOperator::Eq)
+                .value(user_id)
+                .build()
+        })
+        .limit(1)
+        .build();
+    let vault_keys = VaultKey::get_list_with(conn, query)?;
+    Ok(vault_keys.into_iter().next())
+}
+
+// A wrapped key that does not open means the master password is wrong
+fn unwrap_vault_key(
+    vault_key: &VaultKey,
+    master_password: &SecretString,
+) -> Result<SecretBytes, AppError> {
+    unwrap_data_key(&vault_key.wrapped_data_key, master_password.expose_secret()).map_err(|e| {
+        match e {
+            EncryptionError::DecryptionFailed => AppError::InvalidCredentials,
+            e => e.into(),
+        }
+    })
+}
+
+pub(crate) fn save_vault_key(tx: &Transaction, vault_key: &VaultKey) -> Result<(), SqlError> {
+    tx.execute(
+        "UPDATE vault_keys SET wrapped_data_key = ?, recovery_wrapped_key = ?, item_version = ?, updated_at = ?, updated_by = ? WHERE id = ?",
+        (
+            &vault_key.wrapped_data_key,
+            &vault_key.recovery_wrapped_key,
+            vault_key.item_version,
+            vault_key.updated_at,
+            &vault_key.updated_by,
+            &vault_key.id,
+        ),
+    )?;
+    Ok(())
+}
+
+/// Generates a data key for a new user, stores it wrapped with the master
+/// password and unlocks the vault.
+pub async fn setup_vault(user_id: &str, master_password: &SecretString) -> Result<(), AppError> {
+    let data_key = generate_data_key();
+    VaultKey::insert(new_vault_key(user_id, &data_key, master_password)?).await?;
+    start_session(user_id, data_key);
+    Ok(())
+}
+
+fn new_vault_key(
+    user_id: &str,
+    data_key: &SecretBytes,
+    master_password: &SecretString,
+) -> Result<VaultKey, AppError> {
+    Ok(VaultKey {
+        created_at: Some(now() as i64),
+        created_by: Some(user_id.to_string()),
+        wrapped_data_key: wrap_data_key(data_key.expose_secret(), master_password.expose_secret())?,
+        item_version: ITEM_VERSION,
+        ..Default::default()
+    })
+}
+
+// The parameters chosen for a vault, stored values that fail validation fall
+// back to the defaults rather than locking the user out
+fn vault_kdf_params(vault_key: &VaultKey) -> KdfParams {
+    let stored = match (
+        vault_key.kdf_memory_cost,
+        vault_key.kdf_time_cost,
+        vault_key.kdf_parallelism,
+    ) {
+        (Some(memory_cost), Some(time_cost), Some(parallelism)) => KdfParams {
+            memory_cost: memory_cost as u32,
+            time_cost: time_cost as u32,
+            parallelism: parallelism as u32,
+        },
+        _ => return KdfParams::default(),
+    };
+    match stored.validate() {
+        Ok(()) => stored,
+        Err(_) => KdfParams::default(),
+    }
+}
+
+/// Returns the Argon2id parameters the user's data key is wrapped with.
+pub async fn get_vault_kdf_params(user_id: &str) -> Result<KdfParams, AppError> {
+    let vault_key = fetch_vault_key(user_id)
+        .await?
+        .ok_or(AppError::VaultLocked)?;
+    Ok(vault_kdf_params(&vault_key))
+}
+
+/// Moves the user's data key to new Argon2id parameters.
+///
+/// Costly parameters slow down every later unlock, so the change needs the
+/// master password and not just a session. The data key is rewrapped right
+/// away.
+pub async fn set_vault_kdf_params(
+    user_id: &str,
+    master_password: &SecretString,
+    params: &KdfParams,
+) -> Result<(), AppError> {
+    params.validate()?;
+    let mut vault_key = fetch_vault_key(user_id)
+        .await?
+        .ok_or(AppError::VaultLocked)?;
+    let data_key = unwrap_vault_key(&vault_key, master_password)?;
+    vault_key.wrapped_data_key = wrap_data_key_with_params(
+        data_key.expose_secret(),
+        master_password.expose_secret(),
+        params,
+    )?;
+    vault_key.kdf_memory_cost = Some(params.memory_cost as i64);
+    vault_key.kdf_time_cost = Some(params.time_cost as i64);
+    vault_key.kdf_parallelism = Some(params.parallelism as i64);
+    vault_key.updated_at = Some(now() as i64);
+    vault_key.updated_by = Some(user_id.to_string());
+    VaultKey::update(vault_key.id.clone().unwrap(), vault_key).await?;
+    Ok(())
+}
+
+/// Unwraps the data key of an existing user with the master password and
+/// unlocks the vault.
+///
+/// Users created before item encryption existed have no data key yet, one is
+/// created for them and their plaintext items are sealed in place. Wrapped
+/// keys in an outdated envelope and items sealed in an older format are
+/// upgraded on the way.
+pub async fn unlock_vault(user_id: &str, master_password: &SecretString) -> Result<(), AppError> {
+    match fetch_vault_key(user_id).await? {
+        Some(mut vault_key) => {
+            let data_key = unwrap_vault_key(&vault_key, master_password)?;
+            let params = vault_kdf_params(&vault_key);
+            let rewrap = needs_reencrypt(&vault_key.wrapped_data_key, &params)?;
+            if rewrap {
+                vault_key.wrapped_data_key = reencrypt(
+                    &vault_key.wrapped_data_key,
+                    master_password.expose_secret(),
+                    &params,
+                )?;
+            }
+            let rebind = vault_key.item_version < ITEM_VERSION;
+            if rewrap || rebind {
+                vault_key.item_version = ITEM_VERSION;
+                vault_key.updated_at = Some(now() as i64);
+                vault_key.updated_by = Some(user_id.to_string());
+
+                // The items and the vault key move to the new format together
+                let mut conn = sql::get_db_connection()?;
+                let tx = conn.transaction().map_err(SqlError::from)?;
+                if rebind {
+                    rebind_items(&tx, user_id, &data_key)?;
+                }
+                save_vault_key(&tx, &vault_key)?;
+                tx.commit().map_err(SqlError::from)?;
+            }
+            start_session(user_id, data_key);
+        }
+        None => {
+            // A data key without sealed items would read plaintext as
+            // ciphertext, the key and the sealed items are saved together
+            let data_key = generate_data_key();
+            let mut conn = sql::get_db_connection()?;
+            let tx = conn.transaction().map_err(SqlError::from)?;
+            VaultKey::insert_with(&tx, new_vault_key(user_id, &data_key, master_password)?)?;
+            seal_legacy_items(&tx, user_id, &data_key)?;
+            tx.commit().map_err(SqlError::from)?;
+            start_session(user_id, data_key);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the vault key of a user with the data key wrapped by a new master
+/// password, ready to be saved with [`save_vault_key`].
+///
+/// Items stay sealed with the same data key, so no item row is touched.
+pub(crate) async fn rewrap_vault_key(
+    user_id: &str,
+    old_password: &SecretString,
+    new_password: &SecretString,
+) -> Result<VaultKey, AppError> {
+    let mut vault_key = fetch_vault_key(user_id)
+        .await?
+        .ok_or(AppError::VaultLocked)?;
+    let data_key = unwrap_vault_key(&vault_key, old_password)?;
+    vault_key.wrapped_data_key = wrap_data_key_with_params(
+        data_key.expose_secret(),
+        new_password.expose_secret(),
+        &vault_kdf_params(&vault_key),
+    )?;
+    vault_key.updated_at = Some(now() as i64);
+    vault_key.updated_by = Some(user_id.to_string());
+    Ok(vault_key)
+}
+
+// A recovery key only opens the data key of the vault it was issued for
+fn recovery_associated_data(user_id: &str) -> AssociatedData {
+    AssociatedData::new()
+        .field("vault_keys")
+        .field("recovery_wrapped_key")
+        .field(user_id)
+}
+
+// Wraps the data key with a new recovery key, the previous one stops working
+fn issue_recovery_key(
+    user_id: &str,
+    vault_key: &mut VaultKey,
+    data_key: &SecretBytes,
+) -> Result<SecretString, AppError> {
+    let recovery_key = generate_recovery_key();
+    vault_key.recovery_wrapped_key = Some(wrap_data_key_with_recovery_key(
+        data_key.expose_secret(),
+        recovery_key.expose_secret(),
+        &recovery_associated_data(user_id),
+    )?);
+    Ok(recovery_key)
+}
+
+/// Generates a recovery key for an unlocked vault and returns it as a list
+/// of words.
+///
+/// The recovery key wraps the data key independently of the master
+/// password, a previously issued recovery key stops working.
+pub async fn create_recovery_key(user_id: &str) -> Result<SecretString, AppError> {
+    let data_key = get_data_key(user_id)?;
+    let mut vault_key = fetch_vault_key(user_id)
+        .await?
+        .ok_or(AppError::VaultLocked)?;
+    let recovery_key = issue_recovery_key(user_id, &mut vault_key, &data_key)?;
+    vault_key.updated_at = Some(now() as i64);
+    vault_key.updated_by = Some(user_id.to_string());
+    VaultKey::update(vault_key.id.clone().unwrap(), vault_key).await?;
+    Ok(recovery_key)
+}
+
+/// Issues a new recovery key and splits it among trusted people, returning
+/// each share as printable text.
+///
+/// The recovery key itself is never shown, any `threshold` shares restore it
+/// for [`recover_account`](super::users::recover_account).
+pub async fn create_recovery_shares(
+    user_id: &str,
+    settings: &ShareSettings,
+) -> Result<Vec<SecretString>, AppError> {
+    // Reject bad settings before the current recovery key is replaced
+    check_share_threshold(settings.threshold, settings.shares)?;
+    let recovery_key = create_recovery_key(user_id).await?;
+    let shares = split_recovery_key(
+        recovery_key.expose_secret(),
+        settings.threshold,
+        settings.shares,
+    )?;
+    Ok(shares.iter().map(|share| share.to_text()).collect())
+}
+
+/// Opens the data key with the recovery key and wraps it with a new master
+/// password, ready to be saved with [`save_vault_key`].
+///
+/// A recovery key is only good once, the returned vault key carries a new
+/// one which is returned alongside.
+pub(crate) async fn recover_vault_key(
+    user_id: &str,
+    recovery_key: &SecretString,
+    new_password: &SecretString,
+) -> Result<(VaultKey, SecretString), AppError> {
+    let mut vault_key = fetch_vault_key(user_id)
+        .await?
+        .ok_or(AppError::InvalidRecoveryKey)?;
+    let wrapped_key = vault_key
+        .recovery_wrapped_key
+        .as_deref()
+        .ok_or(AppError::InvalidRecoveryKey)?;
+    let data_key = unwrap_data_key_with_recovery_key(
+        wrapped_key,
+        recovery_key.expose_secret(),
+        &recovery_associated_data(user_id),
+    )
+    .map_err(|e| match e {
+        EncryptionError::InvalidRecoveryKey | EncryptionError::DecryptionFailed => {
+            AppError::InvalidRecoveryKey
+        }
+        e => e.into(),
+    })?;
+
+    vault_key.wrapped_data_key = wrap_data_key_with_params(
+        data_key.expose_secret(),
+        new_password.expose_secret(),
+        &vault_kdf_params(&vault_key),
+    )?;
+    let recovery_key = issue_recovery_key(user_id, &mut vault_key, &data_key)?;
+    vault_key.updated_at = Some(now() as i64);
+    vault_key.updated_by = Some(user_id.to_string());
+    Ok((vault_key, recovery_key))
+}
+
+/// Returns the progress of the latest re-key job of a user.
+pub fn get_rekey_progress(user_id: &str) -> Option<RekeyProgress> {
+    REKEY_PROGRESS.lock().unwrap().get(user_id).copied()
+}
+
+fn report_progress(
+    user_id: &str,
+    progress: RekeyProgress,
+    on_progress: &mut impl FnMut(RekeyProgress),
+) {
+    REKEY_PROGRESS
+        .lock()
+        .unwrap()
+        .insert(user_id.to_string(), progress);
+    on_progress(progress);
+}
+
+/// Generates a new data key and moves every item of the vault to it.
+///
+/// All items and the new wrapped key are read and written in one
+/// transaction, a job that fails part way leaves the vault on the old key.
+/// The vault is locked while the job runs so no item is written under the
+/// old key meanwhile. A recovery key wraps the old data key, when the vault
+/// had one a new recovery key is issued and returned in its place.
+///
+/// Backups taken before the re-key hold the old wrapped data key, restoring
+/// one brings back the vault as it was and items saved since then no longer
+/// open. Take a new backup once the job finishes.
+pub async fn rekey_vault(
+    user_id: &str,
+    master_password: &SecretString,
+    mut on_progress: impl FnMut(RekeyProgress),
+) -> Result<RekeyResult, AppError> {
+    let mut conn = sql::get_db_connection()?;
+    // Writers wait for the job instead of changing items it has read
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(SqlError::from)?;
+    let mut vault_key = fetch_vault_key_with(&tx, user_id)?.ok_or(AppError::VaultLocked)?;
+    let old_key = unwrap_vault_key(&vault_key, master_password)?;
+
+    lock(user_id);
+    match reseal_vault(
+        tx,
+        user_id,
+        &mut vault_key,
+        &old_key,
+        master_password,
+        &mut on_progress,
+    ) {
+        Ok((new_key, result)) => {
+            start_session(user_id, new_key);
+            Ok(result)
+        }
+        Err(e) => {
+            start_session(user_id, old_key);
+            Err(e)
+        }
+    }
+}
+
+fn reseal_vault(
+    tx: Transaction,
+    user_id: &str,
+    vault_key: &mut VaultKey,
+    old_key: &SecretBytes,
+    master_password: &SecretString,
+    on_progress: &mut impl FnMut(RekeyProgress),
+) -> Result<(SecretBytes, RekeyResult), AppError> {
+    let query = owned_by(user_id);
+    let mut logins = Login::get_list_with(&tx, query.clone())?;
+    let mut financial_cards = FinancialCard::get_list_with(&tx, query.clone())?;
+    let mut identity_cards = IdentityCard::get_list_with(&tx, query.clone())?;
+    let mut password_history = PasswordHistory::get_list_with(&tx, query.clone())?;
+    let mut passkeys = Passkey::get_list_with(&tx, query)?;
+
+    let mut progress = RekeyProgress {
+        done: 0,
+        total: (logins.len()
+            + financial_cards.len()
+            + identity_cards.len()
+            + password_history.len()
+            + passkeys.len()) as u32,
+        finished: false,
+    };
+    report_progress(user_id, progress, on_progress);
+
+    let bound = vault_key.item_version >= ITEM_VERSION;
+    let new_key = generate_data_key();
+    for login in logins.iter_mut() {
+        reseal_item(login, old_key, &new_key, bound)?;
+        progress.done += 1;
+        report_progress(user_id, progress, on_progress);
+    }
+    for card in financial_cards.iter_mut() {
+        reseal_item(card, old_key, &new_key, bound)?;
+        progress.done += 1;
+        report_progress(user_id, progress, on_progress);
+    }
+    for card in identity_cards.iter_mut() {
+        reseal_item(card, old_key, &new_key, bound)?;
+        progress.done += 1;
+        report_progress(user_id, progress, on_progress);
+    }
+    // History entries are only ever written bound to their record
+    for entry in password_history.iter_mut() {
+        reseal_item(entry, old_key, &new_key, true)?;
+        progress.done += 1;
+        report_progress(user_id, progress, on_progress);
+    }
+    // Passkeys came after items were bound to their record
+    for passkey in passkeys.iter_mut() {
+        reseal_item(passkey, old_key, &new_key, true)?;
+        progress.done += 1;
+        report_progress(user_id, progress, on_progress);
+    }
+
+    vault_key.wrapped_data_key = wrap_data_key_with_params(
+        new_key.expose_secret(),
+        master_password.expose_secret(),
+        &vault_kdf_params(vault_key),
+    )?;
+    let recovery_key = match vault_key.recovery_wrapped_key {
+        Some(_) => Some(issue_recovery_key(user_id, vault_key, &new_key)?),
+        None => None,
+    };
+    vault_key.item_version = ITEM_VERSION;
+    vault_key.updated_at = Some(now() as i64);
+    vault_key.updated_by = Some(user_id.to_string());
+
+    for login in logins.iter_mut() {
+        save_sealed_columns(&tx, login)?;
+    }
+    for card in financial_cards.iter_mut() {
+        save_sealed_columns(&tx, card)?;
+    }
+    for card in identity_cards.iter_mut() {
+        save_sealed_columns(&tx, card)?;
+    }
+    for entry in password_history.iter_mut() {
+        save_sealed_columns(&tx, entry)?;
+    }
+    for passkey in passkeys.iter_mut() {
+        save_sealed_columns(&tx, passkey)?;
+    }
+    save_vault_key(&tx, vault_key)?;
+    tx.commit().map_err(SqlError::from)?;
+
+    progress.finished = true;
+    report_progress(user_id, progress, on_progress);
+    Ok((
+        new_key,
+        RekeyResult {
+            progress,
+            recovery_key,
+        },
+    ))
+}
+
+fn reseal_item<T: SealedItem>(
+    item: &mut T,
+    old_key: &SecretBytes,
+    new_key: &SecretBytes,
+    bound: bool,
+) -> Result<(), AppError> {
+    if bound {
+        item.open(old_key)?;
+    } else {
+        item.open_unbound(old_key)?;
+    }
+    item.seal(new_key)
+}
+
+// Writes only the sealed columns of an item, the rest of the row is left as is
+fn save_sealed_columns<T: SealedItem>(tx: &Transaction, item: &mut T) -> Result<(), AppError> {
+    let id = item.record_id().map(str::to_string);
+    let columns = item.sealed_columns();
+    if columns.is_empty() {
+        return Ok(());
+    }
+    let assignments = columns
+        .iter()
+        .map(|(column, _)| format!("{} = ?", column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("UPDATE {} SET {} WHERE id = ?", T::TABLE, assignments);
+    let mut params = columns
+        .iter()
+        .map(|(_, value)| &**value as &dyn ToSql)
+        .collect::<Vec<_>>();
+    params.push(&id);
+    tx.execute(&query, params.as_slice())
+        .map_err(SqlError::from)?;
+    Ok(())
+}
+
+pub(crate) fn owned_by(user_id: &str) -> HttpQuery {
+    HttpQuery::builder()
+        .filter(|filter| {
+            filter
+                .column("created_by")
+                .operator(FilterOperator::Eq)
+                .value(user_id)
+                .build()
+        })
+        .build()
+}
+
+fn seal_legacy_items(tx: &Transaction, user_id: &str, key: &SecretBytes) -> Result<(), AppError> {
+    let query = owned_by(user_id);
+
+    for mut login in Login::get_list_with(tx, query.clone())? {
+        login.seal(key)?;
+        save_sealed_columns(tx, &mut login)?;
+    }
+    for mut card in FinancialCard::get_list_with(tx, query.clone())? {
+        card.seal(key)?;
+        save_sealed_columns(tx, &mut card)?;
+    }
+    for mut card in IdentityCard::get_list_with(tx, query)? {
+        card.seal(key)?;
+        save_sealed_columns(tx, &mut card)?;
+    }
+    Ok(())
+}
+
+// Binds items sealed before associated data was introduced to their records
+fn rebind_items(tx: &Transaction, user_id: &str, key: &SecretBytes) -> Result<(), AppError> {
+    let query = owned_by(user_id);
+
+    for mut login in Login::get_list_with(tx, query.clone())? {
+        login.open_unbound(key)?;
+        login.seal(key)?;
+        save_sealed_columns(tx, &mut login)?;
+    }
+    for mut card in FinancialCard::get_list_with(tx, query.clone())? {
+        card.open_unbound(key)?;
+        card.seal(key)?;
+        save_sealed_columns(tx, &mut card)?;
+    }
+    for mut card in IdentityCard::get_list_with(tx, query)? {
+        card.open_unbound(key)?;
+        card.seal(key)?;
+        save_sealed_columns(tx, &mut card)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use security::encrypt_with_key;
+
+    use super::*;
+
+    fn login(id: &str, owner: &str, password: &str) -> Login {
+        Login {
+            id: Some(id.to_string()),
+            created_at: Some(now() as i64),
+            created_by: Some(owner.to_string()),
+            name: "Example".to_string(),
+            username: "user".to_string(),
+            password: Some(password.into()),
+            is_favorite: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_seal_and_open_login() {
+        let key = generate_data_key();
+        let mut login = login("1", "user_1", "hunter2");
+
+        login.seal(&key).unwrap();
+        assert_ne!(login.password, Some("hunter2".into()));
+        assert_eq!(login.username, "user");
+
+        login.open(&key).unwrap();
+        assert_eq!(login.password, Some("hunter2".into()));
+        assert!(!format!("{:?}", login).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_seal_and_open_financial_card() {
+        let key = generate_data_key();
+        let mut card = FinancialCard {
+            id: Some("1".to_string()),
+            created_by: Some("user_1".to_string()),
+            card_number: "4111111111111111".into(),
+            cvv: Some("123".into()),
+            pin: None,
+            ..Default::default()
+        };
+
+        card.seal(&key).unwrap();
+        assert_ne!(card.card_number, "4111111111111111".into());
+        assert_eq!(card.pin, None);
+
+        card.open(&key).unwrap();
+        assert_eq!(card.card_number, "4111111111111111".into());
+        assert_eq!(card.cvv, Some("123".into()));
+    }
+
+    #[test]
+    fn test_open_with_another_users_key() {
+        let key = generate_data_key();
+        let other_key = generate_data_key();
+        let mut card = IdentityCard {
+            id: Some("1".to_string()),
+            created_by: Some("user_1".to_string()),
+            identity_card_number: "X1234567".into(),
+            ..Default::default()
+        };
+
+        card.seal(&key).unwrap();
+        assert!(card.open(&other_key).is_err());
+    }
+
+    #[test]
+    fn test_seal_requires_binding() {
+        let key = generate_data_key();
+        let mut login = login("1", "user_1", "hunter2");
+        login.id = None;
+
+        assert!(matches!(login.seal(&key), Err(AppError::LibraryError(_))));
+    }
+
+    #[test]
+    fn test_relocated_ciphertext_does_not_open() {
+        let key = generate_data_key();
+        let mut victim = login("1", "user_1", "hunter2");
+        victim.seal(&key).unwrap();
+
+        // Another record of the same owner
+        let mut moved = login(
+            "2",
+            "user_1",
+            victim.password.as_ref().unwrap().expose_secret(),
+        );
+        assert!(moved.open(&key).is_err());
+
+        // Another column of the same record
+        let mut card = FinancialCard {
+            id: Some("1".to_string()),
+            created_by: Some("user_1".to_string()),
+            card_number: victim.password.clone().unwrap(),
+            ..Default::default()
+        };
+        assert!(card.open(&key).is_err());
+    }
+
+    #[test]
+    fn test_open_unbound_legacy_value() {
+        let key = generate_data_key();
+        let legacy = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+        let mut login = login("1", "user_1", &legacy);
+
+        assert!(login.clone().open(&key).is_err());
+        login.open_unbound(&key).unwrap();
+        assert_eq!(login.password, Some("hunter2".into()));
+    }
+
+    #[tokio::test]
+    async fn test_setup_and_unlock_vault() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let key = get_data_key(&user_id).unwrap();
+
+        lock_vault(&user_id);
+        assert!(matches!(get_data_key(&user_id), Err(AppError::VaultLocked)));
+        assert!(matches!(
+            unlock_vault(&user_id, &"Wrong@12345".into()).await,
+            Err(AppError::InvalidCredentials)
+        ));
+
+        unlock_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+        assert_eq!(get_data_key(&user_id).unwrap(), key);
+    }
+
+    #[tokio::test]
+    async fn test_set_vault_kdf_params_rewraps_key() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let key = get_data_key(&user_id).unwrap();
+        let params = KdfParams {
+            memory_cost: 8192,
+            time_cost: 1,
+            parallelism: 1,
+        };
+        assert!(matches!(
+            set_vault_kdf_params(&user_id, &"Wrong@12345".into(), &params).await,
+            Err(AppError::InvalidCredentials)
+        ));
+        assert_eq!(
+            get_vault_kdf_params(&user_id).await.unwrap(),
+            KdfParams::default()
+        );
+
+        set_vault_kdf_params(&user_id, &"Master@12345".into(), &params)
+            .await
+            .unwrap();
+        assert_eq!(get_vault_kdf_params(&user_id).await.unwrap(), params);
+        let vault_key = fetch_vault_key(&user_id).await.unwrap().unwrap();
+        assert_eq!(
+            security::envelope_params(&vault_key.wrapped_data_key).unwrap(),
+            params
+        );
+
+        lock_vault(&user_id);
+        unlock_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+        assert_eq!(get_data_key(&user_id).unwrap(), key);
+    }
+
+    #[tokio::test]
+    async fn test_set_vault_kdf_params_rejects_weak_params() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let weak = KdfParams {
+            memory_cost: 64,
+            time_cost: 1,
+            parallelism: 1,
+        };
+
+        assert!(
+            set_vault_kdf_params(&user_id, &"Master@12345".into(), &weak)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unlock_vault_rebinds_items() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let key = get_data_key(&user_id).unwrap();
+
+        let mut vault_key = fetch_vault_key(&user_id).await.unwrap().unwrap();
+        vault_key.item_version = 1;
+        VaultKey::update(vault_key.id.clone().unwrap(), vault_key)
+            .await
+            .unwrap();
+        let legacy = encrypt_with_key("hunter2", key.expose_secret()).unwrap();
+        let inserted = Login::insert(login(&sql::get_ulid(), &user_id, &legacy))
+            .await
+            .unwrap();
+
+        lock_vault(&user_id);
+        unlock_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+
+        let vault_key = fetch_vault_key(&user_id).await.unwrap().unwrap();
+        assert_eq!(vault_key.item_version, ITEM_VERSION);
+        let mut stored = Login::get(inserted.id.unwrap()).await.unwrap();
+        stored.open(&key).unwrap();
+        assert_eq!(stored.password, Some("hunter2".into()));
+    }
+
+    #[tokio::test]
+    async fn test_unlock_vault_seals_legacy_items() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        let inserted = Login::insert(login(&sql::get_ulid(), &user_id, "hunter2"))
+            .await
+            .unwrap();
+
+        unlock_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+
+        let key = get_data_key(&user_id).unwrap();
+        let stored = Login::get(inserted.id.unwrap()).await.unwrap();
+        assert_ne!(stored.password, Some("hunter2".into()));
+        let mut opened = stored;
+        opened.open(&key).unwrap();
+        assert_eq!(opened.password, Some("hunter2".into()));
+    }
+
+    #[tokio::test]
+    async fn test_rekey_vault() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let old_key = get_data_key(&user_id).unwrap();
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let mut item = login(&sql::get_ulid(), &user_id, "hunter2");
+            item.seal(&old_key).unwrap();
+            ids.push(Login::insert(item).await.unwrap().id.unwrap());
+        }
+
+        assert!(matches!(
+            rekey_vault(&user_id, &"Wrong@12345".into(), |_| {}).await,
+            Err(AppError::InvalidCredentials)
+        ));
+
+        let mut reported = Vec::new();
+        let result = rekey_vault(&user_id, &"Master@12345".into(), |progress| {
+            reported.push(progress)
+        })
+        .await
+        .unwrap();
+        let progress = result.progress;
+        assert!(result.recovery_key.is_none());
+        assert_eq!(
+            progress,
+            RekeyProgress {
+                done: 3,
+                total: 3,
+                finished: true
+            }
+        );
+        assert_eq!(reported.len(), 5);
+        assert_eq!(get_rekey_progress(&user_id), Some(progress));
+
+        let new_key = get_data_key(&user_id).unwrap();
+        assert_ne!(new_key, old_key);
+        for id in ids {
+            let stored = Login::get(id).await.unwrap();
+            assert!(stored.clone().open(&old_key).is_err());
+            let mut opened = stored;
+            opened.open(&new_key).unwrap();
+            assert_eq!(opened.username, "user");
+            assert_eq!(opened.password, Some("hunter2".into()));
+        }
+
+        lock_vault(&user_id);
+        unlock_vault(&user_id, &"Master@12345".into())
+            .await
+            .unwrap();
+        assert_eq!(get_data_key(&user_id).unwrap(), new_key);
+    }
+
+    #[tokio::test]
+    async fn test_rekey_vault_replaces_recovery_key() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let old_recovery_key = create_recovery_key(&user_id).await.unwrap();
+
+        let result = rekey_vault(&user_id, &"Master@12345".into(), |_| {})
+            .await
+            .unwrap();
+        let new_key = get_data_key(&user_id).unwrap();
+        let recovery_key = result.recovery_key.unwrap();
+
+        assert!(matches!(
+            recover_vault_key(&user_id, &old_recovery_key, &"Recovered@12345".into()).await,
+            Err(AppError::InvalidRecoveryKey)
+        ));
+        let (vault_key, _) = recover_vault_key(&user_id, &recovery_key, &"Recovered@12345".into())
+            .await
+            .unwrap();
+        let recovered = unwrap_data_key(&vault_key.wrapped_data_key, "Recovered@12345").unwrap();
+        assert_eq!(recovered, new_key);
+    }
+
+    #[tokio::test]
+    async fn test_recovery_shares_restore_recovery_key() {
+        crate::common::test_utils::init_test_db().await;
+
+        let user_id = sql::get_ulid();
+        setup_vault(&user_id, &"Master@12345".into()).await.unwrap();
+        let key = get_data_key(&user_id).unwrap();
+        let settings = ShareSettings {
+            threshold: 2,
+            shares: 3,
+        };
+        let shares = create_recovery_shares(&user_id, &settings).await.unwrap();
+        assert_eq!(shares.len(), 3);
+
+        let shares = [&shares[0], &shares[2]]
+            .iter()
+            .map(|share| security::Share::from_text(share.expose_secret()).unwrap())
+            .collect::<Vec<_>>();
+        let recovery_key = security::recovery_key_from_shares(&shares).unwrap();
+        let (vault_key, _) = recover_vault_key(&user_id, &recovery_key, &"Recovered@12345".into())
+            .await
+            .unwrap();
+        let recovered = unwrap_data_key(&vault_key.wrapped_data_key, "Recovered@12345").unwrap();
+        assert_eq!(recovered, key);
+
+        let invalid = ShareSettings {
+            threshold: 4,
+            shares: 3,
+        };
+        assert!(create_recovery_shares(&user_id, &invalid).await.is_err());
+    }
+}
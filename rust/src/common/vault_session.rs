@@ -0,0 +1,177 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, Once,
+    },
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+use security::SecretBytes;
+
+use super::errors::AppError;
+
+// Idle seconds after which an unlocked vault locks itself, 0 never locks
+const DEFAULT_AUTO_LOCK_SECONDS: u64 = 15 * 60;
+const SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+
+static AUTO_LOCK_SECONDS: AtomicU64 = AtomicU64::new(DEFAULT_AUTO_LOCK_SECONDS);
+
+// Unlocked vaults of this process, keyed by user id
+static SESSIONS: Lazy<Mutex<HashMap<String, VaultSession>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static SWEEPER: Once = Once::new();
+
+struct VaultSession {
+    data_key: SecretBytes,
+    last_used: Instant,
+}
+
+impl VaultSession {
+    fn is_idle(&self, timeout: Option<Duration>, now: Instant) -> bool {
+        match timeout {
+            Some(timeout) => now.saturating_duration_since(self.last_used) >= timeout,
+            None => false,
+        }
+    }
+}
+
+/// Sets how long an unlocked vault may stay unused before it locks itself,
+/// `None` keeps it unlocked until [`lock`] is called.
+pub fn set_auto_lock_timeout(timeout: Option<Duration>) {
+    let seconds = timeout.map(|timeout| timeout.as_secs().max(1)).unwrap_or(0);
+    AUTO_LOCK_SECONDS.store(seconds, Ordering::Relaxed);
+}
+
+pub fn auto_lock_timeout() -> Option<Duration> {
+    match AUTO_LOCK_SECONDS.load(Ordering::Relaxed) {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    }
+}
+
+/// Holds the data key of a user until the vault is locked.
+pub(crate) fn start_session(user_id: &str, data_key: SecretBytes) {
+    SWEEPER.call_once(|| {
+        std::thread::spawn(|| loop {
+            std::thread::sleep(SWEEP_INTERVAL);
+            lock_idle_sessions(Instant::now());
+        });
+    });
+
+    let mut sessions = SESSIONS.lock().unwrap();
+    sessions.insert(
+        user_id.to_string(),
+        VaultSession {
+            data_key,
+            last_used: Instant::now(),
+        },
+    );
+}
+
+/// Wipes the data key of a user, sealed items stay unreadable until the
+/// vault is unlocked again.
+pub fn lock(user_id: &str) {
+    let mut sessions = SESSIONS.lock().unwrap();
+    sessions.remove(user_id);
+}
+
+/// Returns the data key of an unlocked vault and restarts its idle timer.
+pub(crate) fn session_key(user_id: &str) -> Result<SecretBytes, AppError> {
+    let mut sessions = SESSIONS.lock().unwrap();
+    let now = Instant::now();
+    match sessions.get_mut(user_id) {
+        Some(session) if !session.is_idle(auto_lock_timeout(), now) => {
+            session.last_used = now;
+            Ok(session.data_key.clone())
+        }
+        Some(_) => {
+            sessions.remove(user_id);
+            Err(AppError::VaultLocked)
+        }
+        None => Err(AppError::VaultLocked),
+    }
+}
+
+/// Fails with [`AppError::VaultLocked`] unless the user's vault is unlocked,
+/// counts as activity for the idle timer.
+pub fn ensure_unlocked(user_id: &str) -> Result<(), AppError> {
+    session_key(user_id).map(|_| ())
+}
+
+pub fn is_unlocked(user_id: &str) -> bool {
+    let sessions = SESSIONS.lock().unwrap();
+    sessions
+        .get(user_id)
+        .is_some_and(|session| !session.is_idle(auto_lock_timeout(), Instant::now()))
+}
+
+fn lock_idle_sessions(now: Instant) {
+    let mut sessions = SESSIONS.lock().unwrap();
+    drop_idle(&mut sessions, auto_lock_timeout(), now);
+}
+
+fn drop_idle(
+    sessions: &mut HashMap<String, VaultSession>,
+    timeout: Option<Duration>,
+    now: Instant,
+) {
+    sessions.retain(|_, session| !session.is_idle(timeout, now));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(last_used: Instant) -> VaultSession {
+        VaultSession {
+            data_key: security::generate_data_key(),
+            last_used,
+        }
+    }
+
+    #[test]
+    fn test_session_is_idle() {
+        let timeout = Some(Duration::from_secs(60));
+        let session = session(Instant::now());
+
+        assert!(!session.is_idle(timeout, session.last_used + Duration::from_secs(10)));
+        assert!(session.is_idle(timeout, session.last_used + Duration::from_secs(61)));
+        assert!(!session.is_idle(None, session.last_used + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn test_lock_and_unlock() {
+        let user_id = sql::get_ulid();
+        assert!(matches!(
+            ensure_unlocked(&user_id),
+            Err(AppError::VaultLocked)
+        ));
+
+        let data_key = security::generate_data_key();
+        start_session(&user_id, data_key.clone());
+        assert!(is_unlocked(&user_id));
+        assert_eq!(session_key(&user_id).unwrap(), data_key);
+
+        lock(&user_id);
+        assert!(!is_unlocked(&user_id));
+        assert!(matches!(session_key(&user_id), Err(AppError::VaultLocked)));
+    }
+
+    #[test]
+    fn test_idle_sessions_are_dropped() {
+        let now = Instant::now();
+        let mut sessions = HashMap::new();
+        sessions.insert("idle".to_string(), session(now));
+        sessions.insert("active".to_string(), session(now + Duration::from_secs(60)));
+
+        // Sweep once the first session has been unused for the default timeout
+        let later = now + Duration::from_secs(DEFAULT_AUTO_LOCK_SECONDS);
+        drop_idle(&mut sessions, auto_lock_timeout(), later);
+
+        assert!(sessions.contains_key("active"));
+        assert!(!sessions.contains_key("idle"));
+    }
+}
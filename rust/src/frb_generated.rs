@@ -25,6 +25,7 @@
 
 // Section: imports
 
+use crate::api::secret::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -37,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.7.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1303389640;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1484889814;
 
 // Section: executor
 
@@ -79,7 +80,7 @@ fn wire__crate__api__simple__backup_data_to_server_impl(
         },
     )
 }
-fn wire__crate__api__simple__create_tag_impl(
+fn wire__crate__api__simple__begin_two_factor_setup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +88,7 @@ fn wire__crate__api__simple__create_tag_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_tag",
+            debug_name: "begin_two_factor_setup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,13 +102,12 @@ fn wire__crate__api__simple__create_tag_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <crate::models::tags::Tag>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::create_tag(api_tag, api_token)?;
+                        let output_ok = crate::api::simple::begin_two_factor_setup(api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -115,7 +115,7 @@ fn wire__crate__api__simple__create_tag_impl(
         },
     )
 }
-fn wire__crate__api__simple__decrypt_data_impl(
+fn wire__crate__api__simple__build_password_breach_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -123,7 +123,7 @@ fn wire__crate__api__simple__decrypt_data_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decrypt_data",
+            debug_name: "build_password_breach_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -137,13 +137,16 @@ fn wire__crate__api__simple__decrypt_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_dataset_path = <String>::sse_decode(&mut deserializer);
+            let api_index_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::decrypt_data(api_data, api_password)?;
+                        let output_ok = crate::api::simple::build_password_breach_index(
+                            api_dataset_path,
+                            api_index_path,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -151,17 +154,16 @@ fn wire__crate__api__simple__decrypt_data_impl(
         },
     )
 }
-fn wire__crate__api__simple__delete_financial_card_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__calibrate_kdf_settings_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_financial_card",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "calibrate_kdf_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -173,22 +175,20 @@ fn wire__crate__api__simple__delete_financial_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_target_ms = <u64>::sse_decode(&mut deserializer);
+            let api_max_memory = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::simple::delete_financial_card(api_id, api_token)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::simple::calibrate_kdf_settings(api_target_ms, api_max_memory)?;
+                    Ok(output_ok)
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__simple__delete_identity_card_impl(
+fn wire__crate__api__simple__change_password_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -196,7 +196,7 @@ fn wire__crate__api__simple__delete_identity_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_identity_card",
+            debug_name: "change_password",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -210,14 +210,15 @@ fn wire__crate__api__simple__delete_identity_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <crate::models::others::authentication::ChangePasswordData>::sse_decode(
+                &mut deserializer,
+            );
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::delete_identity_card(api_id, api_token)?;
+                        let output_ok = crate::api::simple::change_password(api_data, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -225,17 +226,16 @@ fn wire__crate__api__simple__delete_identity_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__delete_login_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__check_account_password_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_login",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "check_account_password",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -247,21 +247,20 @@ fn wire__crate__api__simple__delete_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_user_inputs = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::delete_login(api_id, api_token)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::check_account_password(
+                    api_password,
+                    api_user_inputs,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__simple__delete_note_impl(
+fn wire__crate__api__simple__check_breached_passwords_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -269,7 +268,7 @@ fn wire__crate__api__simple__delete_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_note",
+            debug_name: "check_breached_passwords",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -283,13 +282,12 @@ fn wire__crate__api__simple__delete_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::delete_note(api_id, api_token)?;
+                        let output_ok = crate::api::simple::check_breached_passwords(api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -297,17 +295,16 @@ fn wire__crate__api__simple__delete_note_impl(
         },
     )
 }
-fn wire__crate__api__simple__delete_tag_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__combine_recovery_key_shares_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_tag",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "combine_recovery_key_shares",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -319,31 +316,27 @@ fn wire__crate__api__simple__delete_tag_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_shares = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::delete_tag(api_id, api_token)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::combine_recovery_key_shares(api_shares)?;
+                    Ok(output_ok)
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__simple__encrypt_data_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__combine_secret_shares_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encrypt_data",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "combine_secret_shares",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -355,21 +348,18 @@ fn wire__crate__api__simple__encrypt_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_shares = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::encrypt_data(api_data, api_password)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::combine_secret_shares(api_shares)?;
+                    Ok(output_ok)
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__simple__export_all_data_to_json_impl(
+fn wire__crate__api__simple__confirm_two_factor_setup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -377,7 +367,7 @@ fn wire__crate__api__simple__export_all_data_to_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_all_data_to_json",
+            debug_name: "confirm_two_factor_setup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -391,11 +381,14 @@ fn wire__crate__api__simple__export_all_data_to_json_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_code = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::export_all_data_to_json()?;
+                        let output_ok =
+                            crate::api::simple::confirm_two_factor_setup(api_code, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -403,7 +396,7 @@ fn wire__crate__api__simple__export_all_data_to_json_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_financial_card_impl(
+fn wire__crate__api__simple__create_passkey_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -411,7 +404,7 @@ fn wire__crate__api__simple__get_financial_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_financial_card",
+            debug_name: "create_passkey",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -425,13 +418,14 @@ fn wire__crate__api__simple__get_financial_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_request =
+                <crate::models::others::passkey::PasskeyCreation>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::get_financial_card(api_id, api_token)?;
+                        let output_ok = crate::api::simple::create_passkey(api_request, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -439,7 +433,7 @@ fn wire__crate__api__simple__get_financial_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_identity_card_impl(
+fn wire__crate__api__simple__create_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -447,7 +441,7 @@ fn wire__crate__api__simple__get_identity_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_identity_card",
+            debug_name: "create_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -461,13 +455,13 @@ fn wire__crate__api__simple__get_identity_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_tag = <crate::models::tags::Tag>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::get_identity_card(api_id, api_token)?;
+                        let output_ok = crate::api::simple::create_tag(api_tag, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -475,7 +469,7 @@ fn wire__crate__api__simple__get_identity_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_login_impl(
+fn wire__crate__api__simple__decrypt_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -483,7 +477,7 @@ fn wire__crate__api__simple__get_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_login",
+            debug_name: "decrypt_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -497,13 +491,13 @@ fn wire__crate__api__simple__get_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_data = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::get_login(api_id, api_token)?;
+                        let output_ok = crate::api::simple::decrypt_data(api_data, api_password)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -511,7 +505,7 @@ fn wire__crate__api__simple__get_login_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_note_impl(
+fn wire__crate__api__simple__delete_financial_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -519,7 +513,7 @@ fn wire__crate__api__simple__get_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_note",
+            debug_name: "delete_financial_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -539,7 +533,8 @@ fn wire__crate__api__simple__get_note_impl(
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::get_note(api_id, api_token)?;
+                        let output_ok =
+                            crate::api::simple::delete_financial_card(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -547,7 +542,7 @@ fn wire__crate__api__simple__get_note_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_tag_impl(
+fn wire__crate__api__simple__delete_identity_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -555,7 +550,7 @@ fn wire__crate__api__simple__get_tag_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_tag",
+            debug_name: "delete_identity_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -575,7 +570,8 @@ fn wire__crate__api__simple__get_tag_impl(
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::get_tag(api_id, api_token)?;
+                        let output_ok =
+                            crate::api::simple::delete_identity_card(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -583,37 +579,7 @@ fn wire__crate__api__simple__get_tag_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__simple__init_impl(
+fn wire__crate__api__simple__delete_login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -621,7 +587,7 @@ fn wire__crate__api__simple__init_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init",
+            debug_name: "delete_login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -635,18 +601,21 @@ fn wire__crate__api__simple__init_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_db_path = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::init(api_db_path))?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::delete_login(api_id, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__simple__delete_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -654,7 +623,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "delete_note",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -668,19 +637,21 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::delete_note(api_id, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__is_database_initialized_impl(
+fn wire__crate__api__simple__delete_passkey_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -688,7 +659,7 @@ fn wire__crate__api__simple__is_database_initialized_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_database_initialized",
+            debug_name: "delete_passkey",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -702,11 +673,13 @@ fn wire__crate__api__simple__is_database_initialized_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::is_database_initialized()?;
+                        let output_ok = crate::api::simple::delete_passkey(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -714,7 +687,7 @@ fn wire__crate__api__simple__is_database_initialized_impl(
         },
     )
 }
-fn wire__crate__api__simple__list_financial_card_impl(
+fn wire__crate__api__simple__delete_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -722,7 +695,7 @@ fn wire__crate__api__simple__list_financial_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_financial_card",
+            debug_name: "delete_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -736,14 +709,13 @@ fn wire__crate__api__simple__list_financial_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::list_financial_card(api_query, api_token)?;
+                        let output_ok = crate::api::simple::delete_tag(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -751,7 +723,7 @@ fn wire__crate__api__simple__list_financial_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__list_identity_card_impl(
+fn wire__crate__api__simple__encrypt_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -759,7 +731,7 @@ fn wire__crate__api__simple__list_identity_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_identity_card",
+            debug_name: "encrypt_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -773,14 +745,13 @@ fn wire__crate__api__simple__list_identity_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_data = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::list_identity_card(api_query, api_token)?;
+                        let output_ok = crate::api::simple::encrypt_data(api_data, api_password)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -788,17 +759,16 @@ fn wire__crate__api__simple__list_identity_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__list_login_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__estimate_password_strength_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_login",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "estimate_password_strength",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -810,21 +780,19 @@ fn wire__crate__api__simple__list_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_user_inputs = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::list_login(api_query, api_token)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::simple::estimate_password_strength(api_password, api_user_inputs),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__simple__list_note_impl(
+fn wire__crate__api__simple__export_all_data_to_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -832,7 +800,7 @@ fn wire__crate__api__simple__list_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_note",
+            debug_name: "export_all_data_to_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -846,13 +814,11 @@ fn wire__crate__api__simple__list_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::list_note(api_query, api_token)?;
+                        let output_ok = crate::api::simple::export_all_data_to_json()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -860,17 +826,16 @@ fn wire__crate__api__simple__list_note_impl(
         },
     )
 }
-fn wire__crate__api__simple__list_tags_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__generate_password_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_tags",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "generate_password",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -882,21 +847,109 @@ fn wire__crate__api__simple__list_tags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::models::others::generator::GeneratorOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::list_tags(api_query, api_token)?;
-                        Ok(output_ok)
-                    })(),
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::generate_password(api_options)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__generate_two_factor_encryption_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_two_factor_encryption_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::simple::generate_two_factor_encryption_key())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__simple__login_impl(
+fn wire__crate__api__simple__get_account_password_hash_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_account_password_hash_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::simple::get_account_password_hash_settings())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_account_password_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_account_password_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::simple::get_account_password_policy())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__get_financial_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -904,7 +957,7 @@ fn wire__crate__api__simple__login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "get_financial_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -918,13 +971,13 @@ fn wire__crate__api__simple__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user =
-                <crate::models::others::authentication::LoginData>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::login(api_user)?;
+                        let output_ok = crate::api::simple::get_financial_card(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -932,7 +985,7 @@ fn wire__crate__api__simple__login_impl(
         },
     )
 }
-fn wire__crate__api__simple__post_financial_card_impl(
+fn wire__crate__api__simple__get_identity_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -940,7 +993,7 @@ fn wire__crate__api__simple__post_financial_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "post_financial_card",
+            debug_name: "get_identity_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -954,15 +1007,13 @@ fn wire__crate__api__simple__post_financial_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data =
-                <crate::models::financial_cards::FinancialCard>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::post_financial_card(api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_identity_card(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -970,7 +1021,7 @@ fn wire__crate__api__simple__post_financial_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__post_identity_card_impl(
+fn wire__crate__api__simple__get_kdf_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -978,7 +1029,7 @@ fn wire__crate__api__simple__post_identity_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "post_identity_card",
+            debug_name: "get_kdf_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -992,15 +1043,12 @@ fn wire__crate__api__simple__post_identity_card_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data =
-                <crate::models::identity_cards::IdentityCard>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::post_identity_card(api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_kdf_settings(api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1008,7 +1056,7 @@ fn wire__crate__api__simple__post_identity_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__post_login_impl(
+fn wire__crate__api__simple__get_login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1016,7 +1064,7 @@ fn wire__crate__api__simple__post_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "post_login",
+            debug_name: "get_login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1030,13 +1078,13 @@ fn wire__crate__api__simple__post_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <crate::models::logins::Login>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::post_login(api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_login(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1044,7 +1092,7 @@ fn wire__crate__api__simple__post_login_impl(
         },
     )
 }
-fn wire__crate__api__simple__post_note_impl(
+fn wire__crate__api__simple__get_login_totp_code_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1052,7 +1100,7 @@ fn wire__crate__api__simple__post_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "post_note",
+            debug_name: "get_login_totp_code",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1066,13 +1114,13 @@ fn wire__crate__api__simple__post_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <crate::models::notes::Note>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::post_note(api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_login_totp_code(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1080,7 +1128,7 @@ fn wire__crate__api__simple__post_note_impl(
         },
     )
 }
-fn wire__crate__api__simple__put_financial_card_impl(
+fn wire__crate__api__simple__get_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1088,7 +1136,7 @@ fn wire__crate__api__simple__put_financial_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "put_financial_card",
+            debug_name: "get_note",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1103,15 +1151,12 @@ fn wire__crate__api__simple__put_financial_card_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_id = <String>::sse_decode(&mut deserializer);
-            let api_data =
-                <crate::models::financial_cards::FinancialCard>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::put_financial_card(api_id, api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_note(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1119,7 +1164,7 @@ fn wire__crate__api__simple__put_financial_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__put_identity_card_impl(
+fn wire__crate__api__simple__get_passkey_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1127,7 +1172,7 @@ fn wire__crate__api__simple__put_identity_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "put_identity_card",
+            debug_name: "get_passkey",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1142,15 +1187,12 @@ fn wire__crate__api__simple__put_identity_card_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_id = <String>::sse_decode(&mut deserializer);
-            let api_data =
-                <crate::models::identity_cards::IdentityCard>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::simple::put_identity_card(api_id, api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_passkey(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1158,7 +1200,7 @@ fn wire__crate__api__simple__put_identity_card_impl(
         },
     )
 }
-fn wire__crate__api__simple__put_login_impl(
+fn wire__crate__api__simple__get_password_breach_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1166,7 +1208,7 @@ fn wire__crate__api__simple__put_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "put_login",
+            debug_name: "get_password_breach_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1180,14 +1222,13 @@ fn wire__crate__api__simple__put_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_data = <crate::models::logins::Login>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::put_login(api_id, api_data, api_token)?;
+                        let output_ok =
+                            crate::api::simple::get_password_breach_count(api_password)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1195,7 +1236,7 @@ fn wire__crate__api__simple__put_login_impl(
         },
     )
 }
-fn wire__crate__api__simple__put_note_impl(
+fn wire__crate__api__simple__get_rekey_vault_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1203,7 +1244,7 @@ fn wire__crate__api__simple__put_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "put_note",
+            debug_name: "get_rekey_vault_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1217,14 +1258,12 @@ fn wire__crate__api__simple__put_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_data = <crate::models::notes::Note>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::put_note(api_id, api_data, api_token)?;
+                        let output_ok = crate::api::simple::get_rekey_vault_progress(api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1232,7 +1271,7 @@ fn wire__crate__api__simple__put_note_impl(
         },
     )
 }
-fn wire__crate__api__simple__put_tag_impl(
+fn wire__crate__api__simple__get_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1240,7 +1279,7 @@ fn wire__crate__api__simple__put_tag_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "put_tag",
+            debug_name: "get_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1255,13 +1294,12 @@ fn wire__crate__api__simple__put_tag_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_id = <String>::sse_decode(&mut deserializer);
-            let api_tag = <crate::models::tags::Tag>::sse_decode(&mut deserializer);
             let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::put_tag(api_id, api_tag, api_token)?;
+                        let output_ok = crate::api::simple::get_tag(api_id, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1269,7 +1307,7 @@ fn wire__crate__api__simple__put_tag_impl(
         },
     )
 }
-fn wire__crate__api__simple__register_impl(
+fn wire__crate__api__simple__get_two_factor_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1277,7 +1315,7 @@ fn wire__crate__api__simple__register_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "register",
+            debug_name: "get_two_factor_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1291,14 +1329,12 @@ fn wire__crate__api__simple__register_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user = <crate::models::others::authentication::RegisterData>::sse_decode(
-                &mut deserializer,
-            );
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::register(api_user)?;
+                        let output_ok = crate::api::simple::get_two_factor_status(api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1306,7 +1342,7 @@ fn wire__crate__api__simple__register_impl(
         },
     )
 }
-fn wire__crate__api__simple__restore_data_from_json_impl(
+fn wire__crate__api__simple__get_vault_health_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1314,7 +1350,7 @@ fn wire__crate__api__simple__restore_data_from_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "restore_data_from_json",
+            debug_name: "get_vault_health",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1328,12 +1364,14 @@ fn wire__crate__api__simple__restore_data_from_json_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <String>::sse_decode(&mut deserializer);
+            let api_stale_days = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::restore_data_from_json(api_data)?;
+                        let output_ok =
+                            crate::api::simple::get_vault_health(api_stale_days, api_token)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1341,7 +1379,37 @@ fn wire__crate__api__simple__restore_data_from_json_impl(
         },
     )
 }
-fn wire__crate__api__simple__toggle_favorite_impl(
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1349,7 +1417,7 @@ fn wire__crate__api__simple__toggle_favorite_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "toggle_favorite",
+            debug_name: "init",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1363,706 +1431,4749 @@ fn wire__crate__api__simple__toggle_favorite_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_item_type = <String>::sse_decode(&mut deserializer);
+            let api_db_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple::toggle_favorite(api_id, api_item_type)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::init(api_db_path))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__is_database_initialized_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_database_initialized",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::is_database_initialized()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__is_vault_unlocked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_vault_unlocked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::is_vault_unlocked(api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_financial_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_financial_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::list_financial_card(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_identity_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_identity_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::list_identity_card(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::list_login(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_login_password_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_login_password_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::list_login_password_history(api_id, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::list_note(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_passkey_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_passkey",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::list_passkey(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_passkeys_for_rp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_passkeys_for_rp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rp_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::list_passkeys_for_rp(api_rp_id, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__list_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::list_tags(api_query, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__load_password_breach_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_password_breach_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_index_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::load_password_breach_index(api_index_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::lock(api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user =
+                <crate::models::others::authentication::LoginData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::login(api_user)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__login_with_two_factor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "login_with_two_factor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::others::authentication::TwoFactorLoginData>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::login_with_two_factor(api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__new_recovery_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_recovery_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::new_recovery_key(api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__new_recovery_key_shares_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_recovery_key_shares",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::models::others::share_settings::ShareSettings>::sse_decode(
+                &mut deserializer,
+            );
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::new_recovery_key_shares(api_settings, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__post_financial_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_financial_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data =
+                <crate::models::financial_cards::FinancialCard>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::post_financial_card(api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__post_identity_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_identity_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data =
+                <crate::models::identity_cards::IdentityCard>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::post_identity_card(api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__post_login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::logins::Login>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::post_login(api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__post_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::notes::Note>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::post_note(api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__post_passkey_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "post_passkey",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::passkeys::Passkey>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::post_passkey(api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_financial_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_financial_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data =
+                <crate::models::financial_cards::FinancialCard>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::put_financial_card(api_id, api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_identity_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_identity_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data =
+                <crate::models::identity_cards::IdentityCard>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::put_identity_card(api_id, api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <crate::models::logins::Login>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::put_login(api_id, api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <crate::models::notes::Note>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::put_note(api_id, api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_passkey_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_passkey",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <crate::models::passkeys::Passkey>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::put_passkey(api_id, api_data, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__put_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "put_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_tag = <crate::models::tags::Tag>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::put_tag(api_id, api_tag, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__qr_code_matrix_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "qr_code_matrix",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::others::qr::QrOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::qr_code_matrix(api_payload, api_options)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__qr_code_png_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "qr_code_png",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::others::qr::QrOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::qr_code_png(api_payload, api_options)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__qr_code_svg_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "qr_code_svg",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::others::qr::QrOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::qr_code_svg(api_payload, api_options)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__recover_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recover",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::others::authentication::RecoverAccountData>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::recover(api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__refresh_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <crate::models::others::authentication::RefreshTokenData>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::refresh_tokens(api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__regenerate_two_factor_backup_codes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "regenerate_two_factor_backup_codes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_code = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::regenerate_two_factor_backup_codes(
+                            api_code, api_token,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__register_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user = <crate::models::others::authentication::RegisterData>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::register(api_user)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__rekey_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rekey",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_master_password = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::rekey(api_master_password, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__restore_data_from_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_data_from_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::restore_data_from_json(api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__restore_login_password_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_login_password",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_history_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::restore_login_password(
+                            api_id,
+                            api_history_id,
+                            api_token,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__save_kdf_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_kdf_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::models::others::kdf_settings::KdfSettings>::sse_decode(&mut deserializer);
+            let api_master_password = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::save_kdf_settings(
+                            api_settings,
+                            api_master_password,
+                            api_token,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_account_password_hash_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_account_password_hash_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::models::others::kdf_settings::KdfSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::simple::set_account_password_hash_settings(api_settings)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__set_account_password_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_account_password_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::models::others::password_policy::PasswordPolicy>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::set_account_password_policy(api_policy)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__set_auto_lock_seconds_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_auto_lock_seconds",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_seconds = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::simple::set_auto_lock_seconds(api_seconds);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__set_encryption_cipher_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_encryption_cipher",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::set_encryption_cipher(api_policy)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__set_two_factor_encryption_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_two_factor_encryption_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::simple::set_two_factor_encryption_key(api_key)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__sign_passkey_assertion_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sign_passkey_assertion",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_request = <crate::models::others::passkey::PasskeyAssertionRequest>::sse_decode(
+                &mut deserializer,
+            );
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::sign_passkey_assertion(
+                            api_id,
+                            api_request,
+                            api_token,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__split_secret_into_shares_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "split_secret_into_shares",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret = <String>::sse_decode(&mut deserializer);
+            let api_settings = <crate::models::others::share_settings::ShareSettings>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::simple::split_secret_into_shares(api_secret, api_settings)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__simple__toggle_favorite_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "toggle_favorite",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_item_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::toggle_favorite(api_id, api_item_type)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__turn_off_two_factor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "turn_off_two_factor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_code = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::turn_off_two_factor(api_code, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__unlock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_master_password = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::unlock(api_master_password, api_token)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for SecretString {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return crate::api::secret::decode_secret_string(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::models::others::breach::BreachReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_checked = <u32>::sse_decode(deserializer);
+        let mut var_breachedCount = <u32>::sse_decode(deserializer);
+        let mut var_breached =
+            <Vec<crate::models::others::breach::BreachedLogin>>::sse_decode(deserializer);
+        let mut var_checkedAt = <i64>::sse_decode(deserializer);
+        return crate::models::others::breach::BreachReport {
+            checked: var_checked,
+            breached_count: var_breachedCount,
+            breached: var_breached,
+            checked_at: var_checkedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::breach::BreachedLogin {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::models::others::breach::BreachedLogin {
+            id: var_id,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::Capitalization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::others::generator::Capitalization::Lowercase,
+            1 => crate::models::others::generator::Capitalization::Uppercase,
+            2 => crate::models::others::generator::Capitalization::Capitalize,
+            3 => crate::models::others::generator::Capitalization::Random,
+            _ => unreachable!("Invalid variant for Capitalization: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::ChangePasswordData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_oldPassword = <SecretString>::sse_decode(deserializer);
+        let mut var_newPassword = <SecretString>::sse_decode(deserializer);
+        let mut var_rePassword = <SecretString>::sse_decode(deserializer);
+        return crate::models::others::authentication::ChangePasswordData {
+            old_password: var_oldPassword,
+            new_password: var_newPassword,
+            re_password: var_rePassword,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::models::financial_cards::FinancialCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_cardHolderName = <String>::sse_decode(deserializer);
+        let mut var_cardNumber = <SecretString>::sse_decode(deserializer);
+        let mut var_cardProviderName = <Option<String>>::sse_decode(deserializer);
+        let mut var_cardType = <Option<String>>::sse_decode(deserializer);
+        let mut var_cvv = <Option<SecretString>>::sse_decode(deserializer);
+        let mut var_expiryDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_issueDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_pin = <Option<SecretString>>::sse_decode(deserializer);
+        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Option<String>>::sse_decode(deserializer);
+        return crate::models::financial_cards::FinancialCard {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            card_holder_name: var_cardHolderName,
+            card_number: var_cardNumber,
+            card_provider_name: var_cardProviderName,
+            card_type: var_cardType,
+            cvv: var_cvv,
+            expiry_date: var_expiryDate,
+            issue_date: var_issueDate,
+            name: var_name,
+            note: var_note,
+            pin: var_pin,
+            is_favorite: var_isFavorite,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::GeneratedPassword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_password = <SecretString>::sse_decode(deserializer);
+        let mut var_entropyBits = <f64>::sse_decode(deserializer);
+        return crate::models::others::generator::GeneratedPassword {
+            password: var_password,
+            entropy_bits: var_entropyBits,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::GeneratorOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::models::others::generator::PasswordOptions>::sse_decode(deserializer);
+                return crate::models::others::generator::GeneratorOptions::Password(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::models::others::generator::PronounceableOptions>::sse_decode(
+                        deserializer,
+                    );
+                return crate::models::others::generator::GeneratorOptions::Pronounceable(
+                    var_field0,
+                );
+            }
+            2 => {
+                let mut var_field0 =
+                    <crate::models::others::generator::PassphraseOptions>::sse_decode(deserializer);
+                return crate::models::others::generator::GeneratorOptions::Passphrase(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::models::identity_cards::IdentityCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_country = <Option<String>>::sse_decode(deserializer);
+        let mut var_expiryDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_identityCardNumber = <SecretString>::sse_decode(deserializer);
+        let mut var_identityCardType = <Option<String>>::sse_decode(deserializer);
+        let mut var_issueDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_nameOnCard = <String>::sse_decode(deserializer);
+        let mut var_state = <Option<String>>::sse_decode(deserializer);
+        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Option<String>>::sse_decode(deserializer);
+        return crate::models::identity_cards::IdentityCard {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            name: var_name,
+            note: var_note,
+            country: var_country,
+            expiry_date: var_expiryDate,
+            identity_card_number: var_identityCardNumber,
+            identity_card_type: var_identityCardType,
+            issue_date: var_issueDate,
+            name_on_card: var_nameOnCard,
+            state: var_state,
+            is_favorite: var_isFavorite,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::JwtTokens {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accessToken = <SecretString>::sse_decode(deserializer);
+        let mut var_refreshToken = <SecretString>::sse_decode(deserializer);
+        let mut var_recoveryKey = <Option<SecretString>>::sse_decode(deserializer);
+        return crate::models::others::authentication::JwtTokens {
+            access_token: var_accessToken,
+            refresh_token: var_refreshToken,
+            recovery_key: var_recoveryKey,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::kdf_settings::KdfSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_memoryCost = <u32>::sse_decode(deserializer);
+        let mut var_timeCost = <u32>::sse_decode(deserializer);
+        let mut var_parallelism = <u32>::sse_decode(deserializer);
+        return crate::models::others::kdf_settings::KdfSettings {
+            memory_cost: var_memoryCost,
+            time_cost: var_timeCost,
+            parallelism: var_parallelism,
+        };
+    }
+}
+
+impl SseDecode for Vec<SecretString> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<SecretString>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<bool>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::others::breach::BreachedLogin> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::others::breach::BreachedLogin>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::financial_cards::FinancialCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::financial_cards::FinancialCard>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::identity_cards::IdentityCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::identity_cards::IdentityCard>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<String>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::logins::Login> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::logins::Login>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::notes::Note> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::notes::Note>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::passkeys::Passkey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::passkeys::Passkey>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::password_history::PasswordHistory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::password_history::PasswordHistory>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::others::password_policy::PasswordRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::models::others::password_policy::PasswordRule>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::tags::Tag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::tags::Tag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::others::health::WeakPassword> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::others::health::WeakPassword>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::models::logins::Login {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<SecretString>>::sse_decode(deserializer);
+        let mut var_passwordHint = <Option<String>>::sse_decode(deserializer);
+        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Option<String>>::sse_decode(deserializer);
+        let mut var_apiKeys = <Option<String>>::sse_decode(deserializer);
+        let mut var_breachCount = <Option<i64>>::sse_decode(deserializer);
+        let mut var_breachCheckedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_historyLimit = <Option<i64>>::sse_decode(deserializer);
+        let mut var_otpauth = <Option<SecretString>>::sse_decode(deserializer);
+        return crate::models::logins::Login {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            name: var_name,
+            note: var_note,
+            username: var_username,
+            url: var_url,
+            password: var_password,
+            password_hint: var_passwordHint,
+            is_favorite: var_isFavorite,
+            tags: var_tags,
+            api_keys: var_apiKeys,
+            breach_count: var_breachCount,
+            breach_checked_at: var_breachCheckedAt,
+            history_limit: var_historyLimit,
+            otpauth: var_otpauth,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::LoginData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_password = <SecretString>::sse_decode(deserializer);
+        return crate::models::others::authentication::LoginData {
+            email: var_email,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::LoginResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::models::others::authentication::JwtTokens>::sse_decode(deserializer);
+                return crate::models::others::authentication::LoginResult::Tokens(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <crate::models::others::authentication::TwoFactorChallenge>::sse_decode(
+                        deserializer,
+                    );
+                return crate::models::others::authentication::LoginResult::TwoFactorRequired(
+                    var_field0,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::models::notes::Note {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Option<String>>::sse_decode(deserializer);
+        return crate::models::notes::Note {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            name: var_name,
+            note: var_note,
+            is_favorite: var_isFavorite,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for Option<SecretString> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<SecretString>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::others::rekey_progress::RekeyProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::models::others::rekey_progress::RekeyProgress>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::models::others::otp::OtpCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <String>::sse_decode(deserializer);
+        let mut var_period = <u64>::sse_decode(deserializer);
+        let mut var_remainingSeconds = <u64>::sse_decode(deserializer);
+        let mut var_counter = <Option<u64>>::sse_decode(deserializer);
+        return crate::models::others::otp::OtpCode {
+            code: var_code,
+            period: var_period,
+            remaining_seconds: var_remainingSeconds,
+            counter: var_counter,
+        };
+    }
+}
+
+impl SseDecode for crate::models::passkeys::Passkey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_rpId = <String>::sse_decode(deserializer);
+        let mut var_rpName = <Option<String>>::sse_decode(deserializer);
+        let mut var_userHandle = <String>::sse_decode(deserializer);
+        let mut var_userName = <Option<String>>::sse_decode(deserializer);
+        let mut var_userDisplayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_credentialId = <String>::sse_decode(deserializer);
+        let mut var_algorithm = <i64>::sse_decode(deserializer);
+        let mut var_privateKey = <SecretString>::sse_decode(deserializer);
+        let mut var_signCount = <i64>::sse_decode(deserializer);
+        let mut var_lastUsedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Option<String>>::sse_decode(deserializer);
+        return crate::models::passkeys::Passkey {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            name: var_name,
+            note: var_note,
+            rp_id: var_rpId,
+            rp_name: var_rpName,
+            user_handle: var_userHandle,
+            user_name: var_userName,
+            user_display_name: var_userDisplayName,
+            credential_id: var_credentialId,
+            algorithm: var_algorithm,
+            private_key: var_privateKey,
+            sign_count: var_signCount,
+            last_used_at: var_lastUsedAt,
+            is_favorite: var_isFavorite,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::passkey::PasskeyAssertion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_credentialId = <String>::sse_decode(deserializer);
+        let mut var_authenticatorData = <String>::sse_decode(deserializer);
+        let mut var_signature = <String>::sse_decode(deserializer);
+        let mut var_userHandle = <String>::sse_decode(deserializer);
+        return crate::models::others::passkey::PasskeyAssertion {
+            credential_id: var_credentialId,
+            authenticator_data: var_authenticatorData,
+            signature: var_signature,
+            user_handle: var_userHandle,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::passkey::PasskeyAssertionRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rpId = <String>::sse_decode(deserializer);
+        let mut var_clientDataHash = <String>::sse_decode(deserializer);
+        let mut var_userVerified = <bool>::sse_decode(deserializer);
+        return crate::models::others::passkey::PasskeyAssertionRequest {
+            rp_id: var_rpId,
+            client_data_hash: var_clientDataHash,
+            user_verified: var_userVerified,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::passkey::PasskeyCreation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rpId = <String>::sse_decode(deserializer);
+        let mut var_rpName = <Option<String>>::sse_decode(deserializer);
+        let mut var_userHandle = <String>::sse_decode(deserializer);
+        let mut var_userName = <Option<String>>::sse_decode(deserializer);
+        let mut var_userDisplayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_clientDataHash = <String>::sse_decode(deserializer);
+        let mut var_algorithms = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_userVerified = <bool>::sse_decode(deserializer);
+        return crate::models::others::passkey::PasskeyCreation {
+            rp_id: var_rpId,
+            rp_name: var_rpName,
+            user_handle: var_userHandle,
+            user_name: var_userName,
+            user_display_name: var_userDisplayName,
+            client_data_hash: var_clientDataHash,
+            algorithms: var_algorithms,
+            user_verified: var_userVerified,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::passkey::PasskeyRegistration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_passkey = <crate::models::passkeys::Passkey>::sse_decode(deserializer);
+        let mut var_credentialId = <String>::sse_decode(deserializer);
+        let mut var_attestationObject = <String>::sse_decode(deserializer);
+        let mut var_authenticatorData = <String>::sse_decode(deserializer);
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_publicKeyAlgorithm = <i64>::sse_decode(deserializer);
+        return crate::models::others::passkey::PasskeyRegistration {
+            passkey: var_passkey,
+            credential_id: var_credentialId,
+            attestation_object: var_attestationObject,
+            authenticator_data: var_authenticatorData,
+            public_key: var_publicKey,
+            public_key_algorithm: var_publicKeyAlgorithm,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::PassphraseOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_words = <u32>::sse_decode(deserializer);
+        let mut var_separator = <String>::sse_decode(deserializer);
+        let mut var_capitalization =
+            <crate::models::others::generator::Capitalization>::sse_decode(deserializer);
+        return crate::models::others::generator::PassphraseOptions {
+            words: var_words,
+            separator: var_separator,
+            capitalization: var_capitalization,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::strength::PasswordFeedback {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_warning = <Option<String>>::sse_decode(deserializer);
+        let mut var_suggestions = <Vec<String>>::sse_decode(deserializer);
+        return crate::models::others::strength::PasswordFeedback {
+            warning: var_warning,
+            suggestions: var_suggestions,
+        };
+    }
+}
+
+impl SseDecode for crate::models::password_history::PasswordHistory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_loginId = <String>::sse_decode(deserializer);
+        let mut var_password = <SecretString>::sse_decode(deserializer);
+        return crate::models::password_history::PasswordHistory {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            login_id: var_loginId,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::PasswordOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_length = <u32>::sse_decode(deserializer);
+        let mut var_uppercase = <bool>::sse_decode(deserializer);
+        let mut var_lowercase = <bool>::sse_decode(deserializer);
+        let mut var_digits = <bool>::sse_decode(deserializer);
+        let mut var_symbols = <bool>::sse_decode(deserializer);
+        let mut var_minUppercase = <u32>::sse_decode(deserializer);
+        let mut var_minLowercase = <u32>::sse_decode(deserializer);
+        let mut var_minDigits = <u32>::sse_decode(deserializer);
+        let mut var_minSymbols = <u32>::sse_decode(deserializer);
+        let mut var_symbolSet = <Option<String>>::sse_decode(deserializer);
+        let mut var_excludeAmbiguous = <bool>::sse_decode(deserializer);
+        return crate::models::others::generator::PasswordOptions {
+            length: var_length,
+            uppercase: var_uppercase,
+            lowercase: var_lowercase,
+            digits: var_digits,
+            symbols: var_symbols,
+            min_uppercase: var_minUppercase,
+            min_lowercase: var_minLowercase,
+            min_digits: var_minDigits,
+            min_symbols: var_minSymbols,
+            symbol_set: var_symbolSet,
+            exclude_ambiguous: var_excludeAmbiguous,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::password_policy::PasswordPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minLength = <u32>::sse_decode(deserializer);
+        let mut var_maxLength = <u32>::sse_decode(deserializer);
+        let mut var_requireUppercase = <bool>::sse_decode(deserializer);
+        let mut var_requireLowercase = <bool>::sse_decode(deserializer);
+        let mut var_requireDigit = <bool>::sse_decode(deserializer);
+        let mut var_requireSymbol = <bool>::sse_decode(deserializer);
+        let mut var_minScore = <u8>::sse_decode(deserializer);
+        let mut var_allowUnicode = <bool>::sse_decode(deserializer);
+        let mut var_bannedWords = <Vec<String>>::sse_decode(deserializer);
+        return crate::models::others::password_policy::PasswordPolicy {
+            min_length: var_minLength,
+            max_length: var_maxLength,
+            require_uppercase: var_requireUppercase,
+            require_lowercase: var_requireLowercase,
+            require_digit: var_requireDigit,
+            require_symbol: var_requireSymbol,
+            min_score: var_minScore,
+            allow_unicode: var_allowUnicode,
+            banned_words: var_bannedWords,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::password_policy::PasswordRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_minLength = <u32>::sse_decode(deserializer);
+                return crate::models::others::password_policy::PasswordRule::TooShort {
+                    min_length: var_minLength,
+                };
+            }
+            1 => {
+                let mut var_maxLength = <u32>::sse_decode(deserializer);
+                return crate::models::others::password_policy::PasswordRule::TooLong {
+                    max_length: var_maxLength,
+                };
+            }
+            2 => {
+                return crate::models::others::password_policy::PasswordRule::MissingUppercase;
+            }
+            3 => {
+                return crate::models::others::password_policy::PasswordRule::MissingLowercase;
+            }
+            4 => {
+                return crate::models::others::password_policy::PasswordRule::MissingDigit;
+            }
+            5 => {
+                return crate::models::others::password_policy::PasswordRule::MissingSymbol;
+            }
+            6 => {
+                let mut var_score = <u8>::sse_decode(deserializer);
+                let mut var_minScore = <u8>::sse_decode(deserializer);
+                return crate::models::others::password_policy::PasswordRule::TooWeak {
+                    score: var_score,
+                    min_score: var_minScore,
+                };
+            }
+            7 => {
+                return crate::models::others::password_policy::PasswordRule::UnicodeNotAllowed;
+            }
+            8 => {
+                let mut var_word = <String>::sse_decode(deserializer);
+                return crate::models::others::password_policy::PasswordRule::BannedWord {
+                    word: var_word,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::models::others::strength::PasswordStrength {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_score = <u8>::sse_decode(deserializer);
+        let mut var_guesses = <f64>::sse_decode(deserializer);
+        let mut var_guessesLog10 = <f64>::sse_decode(deserializer);
+        let mut var_crackTimeSeconds = <f64>::sse_decode(deserializer);
+        let mut var_crackTimeDisplay = <String>::sse_decode(deserializer);
+        let mut var_feedback =
+            <crate::models::others::strength::PasswordFeedback>::sse_decode(deserializer);
+        return crate::models::others::strength::PasswordStrength {
+            score: var_score,
+            guesses: var_guesses,
+            guesses_log10: var_guessesLog10,
+            crack_time_seconds: var_crackTimeSeconds,
+            crack_time_display: var_crackTimeDisplay,
+            feedback: var_feedback,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::generator::PronounceableOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_length = <u32>::sse_decode(deserializer);
+        let mut var_capitalize = <bool>::sse_decode(deserializer);
+        let mut var_digits = <u32>::sse_decode(deserializer);
+        return crate::models::others::generator::PronounceableOptions {
+            length: var_length,
+            capitalize: var_capitalize,
+            digits: var_digits,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::qr::QrErrorCorrection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::others::qr::QrErrorCorrection::Low,
+            1 => crate::models::others::qr::QrErrorCorrection::Medium,
+            2 => crate::models::others::qr::QrErrorCorrection::Quartile,
+            3 => crate::models::others::qr::QrErrorCorrection::High,
+            _ => unreachable!("Invalid variant for QrErrorCorrection: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::qr::QrMatrix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_size = <u32>::sse_decode(deserializer);
+        let mut var_modules = <Vec<bool>>::sse_decode(deserializer);
+        return crate::models::others::qr::QrMatrix {
+            size: var_size,
+            modules: var_modules,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::qr::QrOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_errorCorrection =
+            <crate::models::others::qr::QrErrorCorrection>::sse_decode(deserializer);
+        let mut var_quietZone = <u32>::sse_decode(deserializer);
+        let mut var_moduleSize = <u32>::sse_decode(deserializer);
+        return crate::models::others::qr::QrOptions {
+            error_correction: var_errorCorrection,
+            quiet_zone: var_quietZone,
+            module_size: var_moduleSize,
+        };
+    }
+}
+
+impl SseDecode for (bool, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <bool>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::RecoverAccountData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_recoveryKey = <SecretString>::sse_decode(deserializer);
+        let mut var_newPassword = <SecretString>::sse_decode(deserializer);
+        let mut var_rePassword = <SecretString>::sse_decode(deserializer);
+        let mut var_twoFactorCode = <Option<String>>::sse_decode(deserializer);
+        return crate::models::others::authentication::RecoverAccountData {
+            email: var_email,
+            recovery_key: var_recoveryKey,
+            new_password: var_newPassword,
+            re_password: var_rePassword,
+            two_factor_code: var_twoFactorCode,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::RefreshTokenData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_refreshToken = <SecretString>::sse_decode(deserializer);
+        return crate::models::others::authentication::RefreshTokenData {
+            refresh_token: var_refreshToken,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::RegisterData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_email = <String>::sse_decode(deserializer);
+        let mut var_password = <SecretString>::sse_decode(deserializer);
+        let mut var_rePassword = <SecretString>::sse_decode(deserializer);
+        let mut var_recoveryKey = <bool>::sse_decode(deserializer);
+        return crate::models::others::authentication::RegisterData {
+            name: var_name,
+            email: var_email,
+            password: var_password,
+            re_password: var_rePassword,
+            recovery_key: var_recoveryKey,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::rekey_progress::RekeyProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_finished = <bool>::sse_decode(deserializer);
+        return crate::models::others::rekey_progress::RekeyProgress {
+            done: var_done,
+            total: var_total,
+            finished: var_finished,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::rekey_progress::RekeyResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_progress =
+            <crate::models::others::rekey_progress::RekeyProgress>::sse_decode(deserializer);
+        let mut var_recoveryKey = <Option<SecretString>>::sse_decode(deserializer);
+        return crate::models::others::rekey_progress::RekeyResult {
+            progress: var_progress,
+            recovery_key: var_recoveryKey,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::share_settings::ShareSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_threshold = <u8>::sse_decode(deserializer);
+        let mut var_shares = <u8>::sse_decode(deserializer);
+        return crate::models::others::share_settings::ShareSettings {
+            threshold: var_threshold,
+            shares: var_shares,
+        };
+    }
+}
+
+impl SseDecode for crate::models::tags::Tag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::models::tags::Tag {
+            id: var_id,
+            created_at: var_createdAt,
+            created_by: var_createdBy,
+            updated_at: var_updatedAt,
+            updated_by: var_updatedBy,
+            name: var_name,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::two_factor::TwoFactorBackupCodes {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_codes = <Vec<SecretString>>::sse_decode(deserializer);
+        return crate::models::others::two_factor::TwoFactorBackupCodes { codes: var_codes };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::TwoFactorChallenge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_challengeToken = <SecretString>::sse_decode(deserializer);
+        let mut var_expiresIn = <u64>::sse_decode(deserializer);
+        return crate::models::others::authentication::TwoFactorChallenge {
+            challenge_token: var_challengeToken,
+            expires_in: var_expiresIn,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::authentication::TwoFactorLoginData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_challengeToken = <SecretString>::sse_decode(deserializer);
+        let mut var_code = <String>::sse_decode(deserializer);
+        return crate::models::others::authentication::TwoFactorLoginData {
+            challenge_token: var_challengeToken,
+            code: var_code,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::two_factor::TwoFactorSetup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_secret = <SecretString>::sse_decode(deserializer);
+        let mut var_otpauthUrl = <SecretString>::sse_decode(deserializer);
+        return crate::models::others::two_factor::TwoFactorSetup {
+            secret: var_secret,
+            otpauth_url: var_otpauthUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::two_factor::TwoFactorStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_backupCodesRemaining = <u32>::sse_decode(deserializer);
+        return crate::models::others::two_factor::TwoFactorStatus {
+            enabled: var_enabled,
+            backup_codes_remaining: var_backupCodesRemaining,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::models::others::health::VaultHealth {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_reusedCount = <u32>::sse_decode(deserializer);
+        let mut var_weakCount = <u32>::sse_decode(deserializer);
+        let mut var_staleCount = <u32>::sse_decode(deserializer);
+        let mut var_emptyCount = <u32>::sse_decode(deserializer);
+        let mut var_reused = <Vec<Vec<String>>>::sse_decode(deserializer);
+        let mut var_weak =
+            <Vec<crate::models::others::health::WeakPassword>>::sse_decode(deserializer);
+        let mut var_stale = <Vec<String>>::sse_decode(deserializer);
+        let mut var_staleDays = <u32>::sse_decode(deserializer);
+        let mut var_empty = <Vec<String>>::sse_decode(deserializer);
+        return crate::models::others::health::VaultHealth {
+            total: var_total,
+            reused_count: var_reusedCount,
+            weak_count: var_weakCount,
+            stale_count: var_staleCount,
+            empty_count: var_emptyCount,
+            reused: var_reused,
+            weak: var_weak,
+            stale: var_stale,
+            stale_days: var_staleDays,
+            empty: var_empty,
+        };
+    }
+}
+
+impl SseDecode for crate::models::others::health::WeakPassword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_score = <u8>::sse_decode(deserializer);
+        let mut var_warning = <Option<String>>::sse_decode(deserializer);
+        return crate::models::others::health::WeakPassword {
+            id: var_id,
+            score: var_score,
+            warning: var_warning,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => {
+            wire__crate__api__simple__backup_data_to_server_impl(port, ptr, rust_vec_len, data_len)
+        }
+        2 => {
+            wire__crate__api__simple__begin_two_factor_setup_impl(port, ptr, rust_vec_len, data_len)
+        }
+        3 => wire__crate__api__simple__build_password_breach_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__simple__change_password_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__simple__check_breached_passwords_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__simple__confirm_two_factor_setup_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__simple__create_passkey_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__simple__create_tag_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__simple__decrypt_data_impl(port, ptr, rust_vec_len, data_len),
+        14 => {
+            wire__crate__api__simple__delete_financial_card_impl(port, ptr, rust_vec_len, data_len)
+        }
+        15 => {
+            wire__crate__api__simple__delete_identity_card_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => wire__crate__api__simple__delete_login_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__simple__delete_note_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__simple__delete_passkey_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple__delete_tag_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__simple__encrypt_data_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__simple__export_all_data_to_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__simple__get_financial_card_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__get_identity_card_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__simple__get_kdf_settings_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__simple__get_login_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__simple__get_login_totp_code_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__get_note_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__simple__get_passkey_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__simple__get_password_breach_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__simple__get_rekey_vault_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__simple__get_tag_impl(port, ptr, rust_vec_len, data_len),
+        37 => {
+            wire__crate__api__simple__get_two_factor_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        38 => wire__crate__api__simple__get_vault_health_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__init_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__is_database_initialized_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__simple__is_vault_unlocked_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__simple__list_financial_card_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__simple__list_identity_card_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__simple__list_login_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__simple__list_login_password_history_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__simple__list_note_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__simple__list_passkey_impl(port, ptr, rust_vec_len, data_len),
+        50 => {
+            wire__crate__api__simple__list_passkeys_for_rp_impl(port, ptr, rust_vec_len, data_len)
+        }
+        51 => wire__crate__api__simple__list_tags_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__simple__load_password_breach_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__simple__lock_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__simple__login_impl(port, ptr, rust_vec_len, data_len),
+        55 => {
+            wire__crate__api__simple__login_with_two_factor_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => wire__crate__api__simple__new_recovery_key_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__simple__new_recovery_key_shares_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__simple__post_financial_card_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__simple__post_identity_card_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__simple__post_login_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__simple__post_note_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__simple__post_passkey_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__simple__put_financial_card_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__simple__put_identity_card_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__simple__put_login_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__simple__put_note_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__simple__put_passkey_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__simple__put_tag_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__simple__recover_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__simple__refresh_tokens_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__simple__regenerate_two_factor_backup_codes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => wire__crate__api__simple__register_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__simple__rekey_impl(port, ptr, rust_vec_len, data_len),
+        77 => {
+            wire__crate__api__simple__restore_data_from_json_impl(port, ptr, rust_vec_len, data_len)
+        }
+        78 => {
+            wire__crate__api__simple__restore_login_password_impl(port, ptr, rust_vec_len, data_len)
+        }
+        79 => wire__crate__api__simple__save_kdf_settings_impl(port, ptr, rust_vec_len, data_len),
+        85 => {
+            wire__crate__api__simple__sign_passkey_assertion_impl(port, ptr, rust_vec_len, data_len)
+        }
+        87 => wire__crate__api__simple__toggle_favorite_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__simple__turn_off_two_factor_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__simple__unlock_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        4 => wire__crate__api__simple__calibrate_kdf_settings_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__simple__check_account_password_impl(ptr, rust_vec_len, data_len),
+        8 => {
+            wire__crate__api__simple__combine_recovery_key_shares_impl(ptr, rust_vec_len, data_len)
+        }
+        9 => wire__crate__api__simple__combine_secret_shares_impl(ptr, rust_vec_len, data_len),
+        21 => {
+            wire__crate__api__simple__estimate_password_strength_impl(ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__simple__generate_password_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__simple__generate_two_factor_encryption_key_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__simple__get_account_password_hash_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => {
+            wire__crate__api__simple__get_account_password_policy_impl(ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__simple__qr_code_matrix_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__simple__qr_code_png_impl(ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__simple__qr_code_svg_impl(ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__simple__set_account_password_hash_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => {
+            wire__crate__api__simple__set_account_password_policy_impl(ptr, rust_vec_len, data_len)
+        }
+        82 => wire__crate__api__simple__set_auto_lock_seconds_impl(ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__simple__set_encryption_cipher_impl(ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__simple__set_two_factor_encryption_key_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__simple__split_secret_into_shares_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SecretString> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        unimplemented!()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SecretString> {}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SecretString>> for SecretString {
+    fn into_into_dart(self) -> FrbWrapper<SecretString> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::breach::BreachReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.checked.into_into_dart().into_dart(),
+            self.breached_count.into_into_dart().into_dart(),
+            self.breached.into_into_dart().into_dart(),
+            self.checked_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::breach::BreachReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::breach::BreachReport>
+    for crate::models::others::breach::BreachReport
+{
+    fn into_into_dart(self) -> crate::models::others::breach::BreachReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::breach::BreachedLogin {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::breach::BreachedLogin
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::breach::BreachedLogin>
+    for crate::models::others::breach::BreachedLogin
+{
+    fn into_into_dart(self) -> crate::models::others::breach::BreachedLogin {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::Capitalization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Lowercase => 0.into_dart(),
+            Self::Uppercase => 1.into_dart(),
+            Self::Capitalize => 2.into_dart(),
+            Self::Random => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::Capitalization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::Capitalization>
+    for crate::models::others::generator::Capitalization
+{
+    fn into_into_dart(self) -> crate::models::others::generator::Capitalization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::ChangePasswordData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.old_password.into_into_dart().into_dart(),
+            self.new_password.into_into_dart().into_dart(),
+            self.re_password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::ChangePasswordData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::ChangePasswordData>
+    for crate::models::others::authentication::ChangePasswordData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::ChangePasswordData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::financial_cards::FinancialCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.card_holder_name.into_into_dart().into_dart(),
+            self.card_number.into_into_dart().into_dart(),
+            self.card_provider_name.into_into_dart().into_dart(),
+            self.card_type.into_into_dart().into_dart(),
+            self.cvv.into_into_dart().into_dart(),
+            self.expiry_date.into_into_dart().into_dart(),
+            self.issue_date.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.pin.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::financial_cards::FinancialCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::financial_cards::FinancialCard>
+    for crate::models::financial_cards::FinancialCard
+{
+    fn into_into_dart(self) -> crate::models::financial_cards::FinancialCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::GeneratedPassword {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.password.into_into_dart().into_dart(),
+            self.entropy_bits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::GeneratedPassword
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::GeneratedPassword>
+    for crate::models::others::generator::GeneratedPassword
+{
+    fn into_into_dart(self) -> crate::models::others::generator::GeneratedPassword {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::GeneratorOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::others::generator::GeneratorOptions::Password(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::others::generator::GeneratorOptions::Pronounceable(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::others::generator::GeneratorOptions::Passphrase(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::GeneratorOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::GeneratorOptions>
+    for crate::models::others::generator::GeneratorOptions
+{
+    fn into_into_dart(self) -> crate::models::others::generator::GeneratorOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::identity_cards::IdentityCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.country.into_into_dart().into_dart(),
+            self.expiry_date.into_into_dart().into_dart(),
+            self.identity_card_number.into_into_dart().into_dart(),
+            self.identity_card_type.into_into_dart().into_dart(),
+            self.issue_date.into_into_dart().into_dart(),
+            self.name_on_card.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::identity_cards::IdentityCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::identity_cards::IdentityCard>
+    for crate::models::identity_cards::IdentityCard
+{
+    fn into_into_dart(self) -> crate::models::identity_cards::IdentityCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::JwtTokens {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.access_token.into_into_dart().into_dart(),
+            self.refresh_token.into_into_dart().into_dart(),
+            self.recovery_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::JwtTokens
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::JwtTokens>
+    for crate::models::others::authentication::JwtTokens
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::JwtTokens {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::kdf_settings::KdfSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.memory_cost.into_into_dart().into_dart(),
+            self.time_cost.into_into_dart().into_dart(),
+            self.parallelism.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::kdf_settings::KdfSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::kdf_settings::KdfSettings>
+    for crate::models::others::kdf_settings::KdfSettings
+{
+    fn into_into_dart(self) -> crate::models::others::kdf_settings::KdfSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::logins::Login {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.password_hint.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.api_keys.into_into_dart().into_dart(),
+            self.breach_count.into_into_dart().into_dart(),
+            self.breach_checked_at.into_into_dart().into_dart(),
+            self.history_limit.into_into_dart().into_dart(),
+            self.otpauth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::logins::Login {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::logins::Login>
+    for crate::models::logins::Login
+{
+    fn into_into_dart(self) -> crate::models::logins::Login {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::LoginData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.email.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::LoginData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::LoginData>
+    for crate::models::others::authentication::LoginData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::LoginData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::LoginResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::others::authentication::LoginResult::Tokens(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::others::authentication::LoginResult::TwoFactorRequired(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::LoginResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::LoginResult>
+    for crate::models::others::authentication::LoginResult
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::LoginResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::notes::Note {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::notes::Note {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::notes::Note> for crate::models::notes::Note {
+    fn into_into_dart(self) -> crate::models::notes::Note {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::otp::OtpCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.period.into_into_dart().into_dart(),
+            self.remaining_seconds.into_into_dart().into_dart(),
+            self.counter.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::otp::OtpCode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::otp::OtpCode>
+    for crate::models::others::otp::OtpCode
+{
+    fn into_into_dart(self) -> crate::models::others::otp::OtpCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::passkeys::Passkey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.rp_id.into_into_dart().into_dart(),
+            self.rp_name.into_into_dart().into_dart(),
+            self.user_handle.into_into_dart().into_dart(),
+            self.user_name.into_into_dart().into_dart(),
+            self.user_display_name.into_into_dart().into_dart(),
+            self.credential_id.into_into_dart().into_dart(),
+            self.algorithm.into_into_dart().into_dart(),
+            self.private_key.into_into_dart().into_dart(),
+            self.sign_count.into_into_dart().into_dart(),
+            self.last_used_at.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::passkeys::Passkey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::passkeys::Passkey>
+    for crate::models::passkeys::Passkey
+{
+    fn into_into_dart(self) -> crate::models::passkeys::Passkey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::passkey::PasskeyAssertion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.credential_id.into_into_dart().into_dart(),
+            self.authenticator_data.into_into_dart().into_dart(),
+            self.signature.into_into_dart().into_dart(),
+            self.user_handle.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::passkey::PasskeyAssertion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::passkey::PasskeyAssertion>
+    for crate::models::others::passkey::PasskeyAssertion
+{
+    fn into_into_dart(self) -> crate::models::others::passkey::PasskeyAssertion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::passkey::PasskeyAssertionRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rp_id.into_into_dart().into_dart(),
+            self.client_data_hash.into_into_dart().into_dart(),
+            self.user_verified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::passkey::PasskeyAssertionRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::passkey::PasskeyAssertionRequest>
+    for crate::models::others::passkey::PasskeyAssertionRequest
+{
+    fn into_into_dart(self) -> crate::models::others::passkey::PasskeyAssertionRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::passkey::PasskeyCreation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rp_id.into_into_dart().into_dart(),
+            self.rp_name.into_into_dart().into_dart(),
+            self.user_handle.into_into_dart().into_dart(),
+            self.user_name.into_into_dart().into_dart(),
+            self.user_display_name.into_into_dart().into_dart(),
+            self.client_data_hash.into_into_dart().into_dart(),
+            self.algorithms.into_into_dart().into_dart(),
+            self.user_verified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::passkey::PasskeyCreation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::passkey::PasskeyCreation>
+    for crate::models::others::passkey::PasskeyCreation
+{
+    fn into_into_dart(self) -> crate::models::others::passkey::PasskeyCreation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::passkey::PasskeyRegistration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.passkey.into_into_dart().into_dart(),
+            self.credential_id.into_into_dart().into_dart(),
+            self.attestation_object.into_into_dart().into_dart(),
+            self.authenticator_data.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.public_key_algorithm.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::passkey::PasskeyRegistration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::passkey::PasskeyRegistration>
+    for crate::models::others::passkey::PasskeyRegistration
+{
+    fn into_into_dart(self) -> crate::models::others::passkey::PasskeyRegistration {
+        self
     }
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::PassphraseOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.words.into_into_dart().into_dart(),
+            self.separator.into_into_dart().into_dart(),
+            self.capitalization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::PassphraseOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::PassphraseOptions>
+    for crate::models::others::generator::PassphraseOptions
+{
+    fn into_into_dart(self) -> crate::models::others::generator::PassphraseOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::strength::PasswordFeedback {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.warning.into_into_dart().into_dart(),
+            self.suggestions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::strength::PasswordFeedback
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::strength::PasswordFeedback>
+    for crate::models::others::strength::PasswordFeedback
+{
+    fn into_into_dart(self) -> crate::models::others::strength::PasswordFeedback {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::password_history::PasswordHistory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.login_id.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::password_history::PasswordHistory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::password_history::PasswordHistory>
+    for crate::models::password_history::PasswordHistory
+{
+    fn into_into_dart(self) -> crate::models::password_history::PasswordHistory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::PasswordOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.length.into_into_dart().into_dart(),
+            self.uppercase.into_into_dart().into_dart(),
+            self.lowercase.into_into_dart().into_dart(),
+            self.digits.into_into_dart().into_dart(),
+            self.symbols.into_into_dart().into_dart(),
+            self.min_uppercase.into_into_dart().into_dart(),
+            self.min_lowercase.into_into_dart().into_dart(),
+            self.min_digits.into_into_dart().into_dart(),
+            self.min_symbols.into_into_dart().into_dart(),
+            self.symbol_set.into_into_dart().into_dart(),
+            self.exclude_ambiguous.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::PasswordOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::PasswordOptions>
+    for crate::models::others::generator::PasswordOptions
+{
+    fn into_into_dart(self) -> crate::models::others::generator::PasswordOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::password_policy::PasswordPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min_length.into_into_dart().into_dart(),
+            self.max_length.into_into_dart().into_dart(),
+            self.require_uppercase.into_into_dart().into_dart(),
+            self.require_lowercase.into_into_dart().into_dart(),
+            self.require_digit.into_into_dart().into_dart(),
+            self.require_symbol.into_into_dart().into_dart(),
+            self.min_score.into_into_dart().into_dart(),
+            self.allow_unicode.into_into_dart().into_dart(),
+            self.banned_words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::password_policy::PasswordPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::password_policy::PasswordPolicy>
+    for crate::models::others::password_policy::PasswordPolicy
+{
+    fn into_into_dart(self) -> crate::models::others::password_policy::PasswordPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::password_policy::PasswordRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::others::password_policy::PasswordRule::TooShort { min_length } => {
+                [0.into_dart(), min_length.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::TooLong { max_length } => {
+                [1.into_dart(), max_length.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::MissingUppercase => {
+                [2.into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::MissingLowercase => {
+                [3.into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::MissingDigit => {
+                [4.into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::MissingSymbol => {
+                [5.into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::TooWeak { score, min_score } => [
+                6.into_dart(),
+                score.into_into_dart().into_dart(),
+                min_score.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::models::others::password_policy::PasswordRule::UnicodeNotAllowed => {
+                [7.into_dart()].into_dart()
+            }
+            crate::models::others::password_policy::PasswordRule::BannedWord { word } => {
+                [8.into_dart(), word.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::password_policy::PasswordRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::password_policy::PasswordRule>
+    for crate::models::others::password_policy::PasswordRule
+{
+    fn into_into_dart(self) -> crate::models::others::password_policy::PasswordRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::strength::PasswordStrength {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.score.into_into_dart().into_dart(),
+            self.guesses.into_into_dart().into_dart(),
+            self.guesses_log10.into_into_dart().into_dart(),
+            self.crack_time_seconds.into_into_dart().into_dart(),
+            self.crack_time_display.into_into_dart().into_dart(),
+            self.feedback.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::strength::PasswordStrength
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::strength::PasswordStrength>
+    for crate::models::others::strength::PasswordStrength
+{
+    fn into_into_dart(self) -> crate::models::others::strength::PasswordStrength {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::generator::PronounceableOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.length.into_into_dart().into_dart(),
+            self.capitalize.into_into_dart().into_dart(),
+            self.digits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::generator::PronounceableOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::generator::PronounceableOptions>
+    for crate::models::others::generator::PronounceableOptions
+{
+    fn into_into_dart(self) -> crate::models::others::generator::PronounceableOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::qr::QrErrorCorrection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Low => 0.into_dart(),
+            Self::Medium => 1.into_dart(),
+            Self::Quartile => 2.into_dart(),
+            Self::High => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::qr::QrErrorCorrection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::qr::QrErrorCorrection>
+    for crate::models::others::qr::QrErrorCorrection
+{
+    fn into_into_dart(self) -> crate::models::others::qr::QrErrorCorrection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::qr::QrMatrix {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.size.into_into_dart().into_dart(),
+            self.modules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::qr::QrMatrix
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::qr::QrMatrix>
+    for crate::models::others::qr::QrMatrix
+{
+    fn into_into_dart(self) -> crate::models::others::qr::QrMatrix {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::qr::QrOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.error_correction.into_into_dart().into_dart(),
+            self.quiet_zone.into_into_dart().into_dart(),
+            self.module_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::qr::QrOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::qr::QrOptions>
+    for crate::models::others::qr::QrOptions
+{
+    fn into_into_dart(self) -> crate::models::others::qr::QrOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::RecoverAccountData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.email.into_into_dart().into_dart(),
+            self.recovery_key.into_into_dart().into_dart(),
+            self.new_password.into_into_dart().into_dart(),
+            self.re_password.into_into_dart().into_dart(),
+            self.two_factor_code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::RecoverAccountData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::RecoverAccountData>
+    for crate::models::others::authentication::RecoverAccountData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::RecoverAccountData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::RefreshTokenData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.refresh_token.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::RefreshTokenData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::RefreshTokenData>
+    for crate::models::others::authentication::RefreshTokenData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::RefreshTokenData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::RegisterData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.email.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.re_password.into_into_dart().into_dart(),
+            self.recovery_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::RegisterData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::RegisterData>
+    for crate::models::others::authentication::RegisterData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::RegisterData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::rekey_progress::RekeyProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.finished.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::rekey_progress::RekeyProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::rekey_progress::RekeyProgress>
+    for crate::models::others::rekey_progress::RekeyProgress
+{
+    fn into_into_dart(self) -> crate::models::others::rekey_progress::RekeyProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::rekey_progress::RekeyResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.progress.into_into_dart().into_dart(),
+            self.recovery_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::rekey_progress::RekeyResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::rekey_progress::RekeyResult>
+    for crate::models::others::rekey_progress::RekeyResult
+{
+    fn into_into_dart(self) -> crate::models::others::rekey_progress::RekeyResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::share_settings::ShareSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.threshold.into_into_dart().into_dart(),
+            self.shares.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::share_settings::ShareSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::share_settings::ShareSettings>
+    for crate::models::others::share_settings::ShareSettings
+{
+    fn into_into_dart(self) -> crate::models::others::share_settings::ShareSettings {
+        self
     }
 }
-
-impl SseDecode for crate::models::financial_cards::FinancialCard {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_cardHolderName = <String>::sse_decode(deserializer);
-        let mut var_cardNumber = <String>::sse_decode(deserializer);
-        let mut var_cardProviderName = <Option<String>>::sse_decode(deserializer);
-        let mut var_cardType = <Option<String>>::sse_decode(deserializer);
-        let mut var_cvv = <Option<String>>::sse_decode(deserializer);
-        let mut var_expiryDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_issueDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<String>>::sse_decode(deserializer);
-        let mut var_pin = <Option<String>>::sse_decode(deserializer);
-        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
-        let mut var_tags = <Option<String>>::sse_decode(deserializer);
-        return crate::models::financial_cards::FinancialCard {
-            id: var_id,
-            created_at: var_createdAt,
-            created_by: var_createdBy,
-            updated_at: var_updatedAt,
-            updated_by: var_updatedBy,
-            card_holder_name: var_cardHolderName,
-            card_number: var_cardNumber,
-            card_provider_name: var_cardProviderName,
-            card_type: var_cardType,
-            cvv: var_cvv,
-            expiry_date: var_expiryDate,
-            issue_date: var_issueDate,
-            name: var_name,
-            note: var_note,
-            pin: var_pin,
-            is_favorite: var_isFavorite,
-            tags: var_tags,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::tags::Tag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.created_by.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.updated_by.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::tags::Tag {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::tags::Tag> for crate::models::tags::Tag {
+    fn into_into_dart(self) -> crate::models::tags::Tag {
+        self
     }
 }
-
-impl SseDecode for crate::models::identity_cards::IdentityCard {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<String>>::sse_decode(deserializer);
-        let mut var_country = <Option<String>>::sse_decode(deserializer);
-        let mut var_expiryDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_identityCardNumber = <String>::sse_decode(deserializer);
-        let mut var_identityCardType = <Option<String>>::sse_decode(deserializer);
-        let mut var_issueDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_nameOnCard = <String>::sse_decode(deserializer);
-        let mut var_state = <Option<String>>::sse_decode(deserializer);
-        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
-        let mut var_tags = <Option<String>>::sse_decode(deserializer);
-        return crate::models::identity_cards::IdentityCard {
-            id: var_id,
-            created_at: var_createdAt,
-            created_by: var_createdBy,
-            updated_at: var_updatedAt,
-            updated_by: var_updatedBy,
-            name: var_name,
-            note: var_note,
-            country: var_country,
-            expiry_date: var_expiryDate,
-            identity_card_number: var_identityCardNumber,
-            identity_card_type: var_identityCardType,
-            issue_date: var_issueDate,
-            name_on_card: var_nameOnCard,
-            state: var_state,
-            is_favorite: var_isFavorite,
-            tags: var_tags,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::two_factor::TwoFactorBackupCodes {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.codes.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::models::others::authentication::JwtTokens {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_accessToken = <String>::sse_decode(deserializer);
-        let mut var_refreshToken = <String>::sse_decode(deserializer);
-        return crate::models::others::authentication::JwtTokens {
-            access_token: var_accessToken,
-            refresh_token: var_refreshToken,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::two_factor::TwoFactorBackupCodes
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::two_factor::TwoFactorBackupCodes>
+    for crate::models::others::two_factor::TwoFactorBackupCodes
+{
+    fn into_into_dart(self) -> crate::models::others::two_factor::TwoFactorBackupCodes {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::models::financial_cards::FinancialCard> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::models::financial_cards::FinancialCard>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::TwoFactorChallenge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.challenge_token.into_into_dart().into_dart(),
+            self.expires_in.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::models::identity_cards::IdentityCard> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::models::identity_cards::IdentityCard>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::TwoFactorChallenge
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::TwoFactorChallenge>
+    for crate::models::others::authentication::TwoFactorChallenge
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::TwoFactorChallenge {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::models::logins::Login> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::models::logins::Login>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::TwoFactorLoginData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.challenge_token.into_into_dart().into_dart(),
+            self.code.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::models::notes::Note> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::models::notes::Note>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::authentication::TwoFactorLoginData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::TwoFactorLoginData>
+    for crate::models::others::authentication::TwoFactorLoginData
+{
+    fn into_into_dart(self) -> crate::models::others::authentication::TwoFactorLoginData {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::two_factor::TwoFactorSetup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.secret.into_into_dart().into_dart(),
+            self.otpauth_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::models::tags::Tag> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::models::tags::Tag>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::two_factor::TwoFactorSetup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::two_factor::TwoFactorSetup>
+    for crate::models::others::two_factor::TwoFactorSetup
+{
+    fn into_into_dart(self) -> crate::models::others::two_factor::TwoFactorSetup {
+        self
     }
 }
-
-impl SseDecode for crate::models::logins::Login {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<String>>::sse_decode(deserializer);
-        let mut var_username = <String>::sse_decode(deserializer);
-        let mut var_url = <Option<String>>::sse_decode(deserializer);
-        let mut var_password = <Option<String>>::sse_decode(deserializer);
-        let mut var_passwordHint = <Option<String>>::sse_decode(deserializer);
-        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
-        let mut var_tags = <Option<String>>::sse_decode(deserializer);
-        let mut var_apiKeys = <Option<String>>::sse_decode(deserializer);
-        return crate::models::logins::Login {
-            id: var_id,
-            created_at: var_createdAt,
-            created_by: var_createdBy,
-            updated_at: var_updatedAt,
-            updated_by: var_updatedBy,
-            name: var_name,
-            note: var_note,
-            username: var_username,
-            url: var_url,
-            password: var_password,
-            password_hint: var_passwordHint,
-            is_favorite: var_isFavorite,
-            tags: var_tags,
-            api_keys: var_apiKeys,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::two_factor::TwoFactorStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.backup_codes_remaining.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::models::others::authentication::LoginData {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_email = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        return crate::models::others::authentication::LoginData {
-            email: var_email,
-            password: var_password,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::two_factor::TwoFactorStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::two_factor::TwoFactorStatus>
+    for crate::models::others::two_factor::TwoFactorStatus
+{
+    fn into_into_dart(self) -> crate::models::others::two_factor::TwoFactorStatus {
+        self
     }
 }
-
-impl SseDecode for crate::models::notes::Note {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<String>>::sse_decode(deserializer);
-        let mut var_isFavorite = <Option<bool>>::sse_decode(deserializer);
-        let mut var_tags = <Option<String>>::sse_decode(deserializer);
-        return crate::models::notes::Note {
-            id: var_id,
-            created_at: var_createdAt,
-            created_by: var_createdBy,
-            updated_at: var_updatedAt,
-            updated_by: var_updatedBy,
-            name: var_name,
-            note: var_note,
-            is_favorite: var_isFavorite,
-            tags: var_tags,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::health::VaultHealth {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.reused_count.into_into_dart().into_dart(),
+            self.weak_count.into_into_dart().into_dart(),
+            self.stale_count.into_into_dart().into_dart(),
+            self.empty_count.into_into_dart().into_dart(),
+            self.reused.into_into_dart().into_dart(),
+            self.weak.into_into_dart().into_dart(),
+            self.stale.into_into_dart().into_dart(),
+            self.stale_days.into_into_dart().into_dart(),
+            self.empty.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::health::VaultHealth
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::health::VaultHealth>
+    for crate::models::others::health::VaultHealth
+{
+    fn into_into_dart(self) -> crate::models::others::health::VaultHealth {
+        self
     }
 }
-
-impl SseDecode for Option<bool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::others::health::WeakPassword {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.warning.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::others::health::WeakPassword
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::others::health::WeakPassword>
+    for crate::models::others::health::WeakPassword
+{
+    fn into_into_dart(self) -> crate::models::others::health::WeakPassword {
+        self
     }
 }
 
-impl SseDecode for Option<i64> {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for (bool, String) {
+impl SseEncode for SecretString {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <bool>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(crate::api::secret::encode_secret_string(self), serializer);
     }
 }
 
-impl SseDecode for crate::models::others::authentication::RegisterData {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_email = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        let mut var_rePassword = <String>::sse_decode(deserializer);
-        return crate::models::others::authentication::RegisterData {
-            name: var_name,
-            email: var_email,
-            password: var_password,
-            re_password: var_rePassword,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for crate::models::tags::Tag {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_createdBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_updatedBy = <Option<String>>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        return crate::models::tags::Tag {
-            id: var_id,
-            created_at: var_createdAt,
-            created_by: var_createdBy,
-            updated_at: var_updatedAt,
-            updated_by: var_updatedBy,
-            name: var_name,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::models::others::breach::BreachReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.checked, serializer);
+        <u32>::sse_encode(self.breached_count, serializer);
+        <Vec<crate::models::others::breach::BreachedLogin>>::sse_encode(self.breached, serializer);
+        <i64>::sse_encode(self.checked_at, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::models::others::breach::BreachedLogin {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <u32>::sse_encode(self.count, serializer);
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::models::others::generator::Capitalization {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::others::generator::Capitalization::Lowercase => 0,
+                crate::models::others::generator::Capitalization::Uppercase => 1,
+                crate::models::others::generator::Capitalization::Capitalize => 2,
+                crate::models::others::generator::Capitalization::Random => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => {
-            wire__crate__api__simple__backup_data_to_server_impl(port, ptr, rust_vec_len, data_len)
-        }
-        2 => wire__crate__api__simple__create_tag_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__decrypt_data_impl(port, ptr, rust_vec_len, data_len),
-        4 => {
-            wire__crate__api__simple__delete_financial_card_impl(port, ptr, rust_vec_len, data_len)
-        }
-        5 => wire__crate__api__simple__delete_identity_card_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__simple__delete_login_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__delete_note_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__simple__delete_tag_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__simple__encrypt_data_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__simple__export_all_data_to_json_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__simple__get_financial_card_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__simple__get_identity_card_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__simple__get_login_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__simple__get_note_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__simple__get_tag_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__simple__init_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__simple__is_database_initialized_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__simple__list_financial_card_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__simple__list_identity_card_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__simple__list_login_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__simple__list_note_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__simple__list_tags_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__simple__login_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__simple__post_financial_card_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__simple__post_identity_card_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__simple__post_login_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__simple__post_note_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__simple__put_financial_card_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__simple__put_identity_card_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__simple__put_login_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__simple__put_note_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__simple__put_tag_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__simple__register_impl(port, ptr, rust_vec_len, data_len),
-        36 => {
-            wire__crate__api__simple__restore_data_from_json_impl(port, ptr, rust_vec_len, data_len)
-        }
-        37 => wire__crate__api__simple__toggle_favorite_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::models::others::authentication::ChangePasswordData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.old_password, serializer);
+        <SecretString>::sse_encode(self.new_password, serializer);
+        <SecretString>::sse_encode(self.re_password, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        16 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::financial_cards::FinancialCard {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.created_by.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.updated_by.into_into_dart().into_dart(),
-            self.card_holder_name.into_into_dart().into_dart(),
-            self.card_number.into_into_dart().into_dart(),
-            self.card_provider_name.into_into_dart().into_dart(),
-            self.card_type.into_into_dart().into_dart(),
-            self.cvv.into_into_dart().into_dart(),
-            self.expiry_date.into_into_dart().into_dart(),
-            self.issue_date.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
-            self.pin.into_into_dart().into_dart(),
-            self.is_favorite.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::models::financial_cards::FinancialCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.id, serializer);
+        <Option<i64>>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.created_by, serializer);
+        <Option<i64>>::sse_encode(self.updated_at, serializer);
+        <Option<String>>::sse_encode(self.updated_by, serializer);
+        <String>::sse_encode(self.card_holder_name, serializer);
+        <SecretString>::sse_encode(self.card_number, serializer);
+        <Option<String>>::sse_encode(self.card_provider_name, serializer);
+        <Option<String>>::sse_encode(self.card_type, serializer);
+        <Option<SecretString>>::sse_encode(self.cvv, serializer);
+        <Option<String>>::sse_encode(self.expiry_date, serializer);
+        <Option<String>>::sse_encode(self.issue_date, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
+        <Option<SecretString>>::sse_encode(self.pin, serializer);
+        <Option<bool>>::sse_encode(self.is_favorite, serializer);
+        <Option<String>>::sse_encode(self.tags, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::financial_cards::FinancialCard
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::models::financial_cards::FinancialCard>
-    for crate::models::financial_cards::FinancialCard
-{
-    fn into_into_dart(self) -> crate::models::financial_cards::FinancialCard {
-        self
+
+impl SseEncode for crate::models::others::generator::GeneratedPassword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.password, serializer);
+        <f64>::sse_encode(self.entropy_bits, serializer);
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::identity_cards::IdentityCard {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.created_by.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.updated_by.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
-            self.country.into_into_dart().into_dart(),
-            self.expiry_date.into_into_dart().into_dart(),
-            self.identity_card_number.into_into_dart().into_dart(),
-            self.identity_card_type.into_into_dart().into_dart(),
-            self.issue_date.into_into_dart().into_dart(),
-            self.name_on_card.into_into_dart().into_dart(),
-            self.state.into_into_dart().into_dart(),
-            self.is_favorite.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+}
+
+impl SseEncode for crate::models::others::generator::GeneratorOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::models::others::generator::GeneratorOptions::Password(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::models::others::generator::PasswordOptions>::sse_encode(field0, serializer);
+            }
+            crate::models::others::generator::GeneratorOptions::Pronounceable(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::models::others::generator::PronounceableOptions>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            crate::models::others::generator::GeneratorOptions::Passphrase(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::models::others::generator::PassphraseOptions>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::identity_cards::IdentityCard
-{
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::models::identity_cards::IdentityCard>
-    for crate::models::identity_cards::IdentityCard
-{
-    fn into_into_dart(self) -> crate::models::identity_cards::IdentityCard {
-        self
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::JwtTokens {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.access_token.into_into_dart().into_dart(),
-            self.refresh_token.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::models::identity_cards::IdentityCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.id, serializer);
+        <Option<i64>>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.created_by, serializer);
+        <Option<i64>>::sse_encode(self.updated_at, serializer);
+        <Option<String>>::sse_encode(self.updated_by, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
+        <Option<String>>::sse_encode(self.country, serializer);
+        <Option<String>>::sse_encode(self.expiry_date, serializer);
+        <SecretString>::sse_encode(self.identity_card_number, serializer);
+        <Option<String>>::sse_encode(self.identity_card_type, serializer);
+        <Option<String>>::sse_encode(self.issue_date, serializer);
+        <String>::sse_encode(self.name_on_card, serializer);
+        <Option<String>>::sse_encode(self.state, serializer);
+        <Option<bool>>::sse_encode(self.is_favorite, serializer);
+        <Option<String>>::sse_encode(self.tags, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::others::authentication::JwtTokens
-{
+
+impl SseEncode for crate::models::others::authentication::JwtTokens {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.access_token, serializer);
+        <SecretString>::sse_encode(self.refresh_token, serializer);
+        <Option<SecretString>>::sse_encode(self.recovery_key, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::JwtTokens>
-    for crate::models::others::authentication::JwtTokens
-{
-    fn into_into_dart(self) -> crate::models::others::authentication::JwtTokens {
-        self
+
+impl SseEncode for crate::models::others::kdf_settings::KdfSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.memory_cost, serializer);
+        <u32>::sse_encode(self.time_cost, serializer);
+        <u32>::sse_encode(self.parallelism, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::logins::Login {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.created_by.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.updated_by.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
-            self.username.into_into_dart().into_dart(),
-            self.url.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.password_hint.into_into_dart().into_dart(),
-            self.is_favorite.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.api_keys.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<SecretString> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <SecretString>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::logins::Login {}
-impl flutter_rust_bridge::IntoIntoDart<crate::models::logins::Login>
-    for crate::models::logins::Login
-{
-    fn into_into_dart(self) -> crate::models::logins::Login {
-        self
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::LoginData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.email.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <bool>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::others::authentication::LoginData
-{
+
+impl SseEncode for Vec<crate::models::others::breach::BreachedLogin> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::others::breach::BreachedLogin>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::LoginData>
-    for crate::models::others::authentication::LoginData
-{
-    fn into_into_dart(self) -> crate::models::others::authentication::LoginData {
-        self
+
+impl SseEncode for Vec<crate::models::financial_cards::FinancialCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::financial_cards::FinancialCard>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::notes::Note {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.created_by.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.updated_by.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
-            self.is_favorite.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::models::identity_cards::IdentityCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::identity_cards::IdentityCard>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::notes::Note {}
-impl flutter_rust_bridge::IntoIntoDart<crate::models::notes::Note> for crate::models::notes::Note {
-    fn into_into_dart(self) -> crate::models::notes::Note {
-        self
+
+impl SseEncode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<String>>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::others::authentication::RegisterData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.email.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.re_password.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::models::logins::Login> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::logins::Login>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::others::authentication::RegisterData
-{
+
+impl SseEncode for Vec<crate::models::notes::Note> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::notes::Note>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::models::others::authentication::RegisterData>
-    for crate::models::others::authentication::RegisterData
-{
-    fn into_into_dart(self) -> crate::models::others::authentication::RegisterData {
-        self
+
+impl SseEncode for Vec<crate::models::passkeys::Passkey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::passkeys::Passkey>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::tags::Tag {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.created_by.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.updated_by.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::models::password_history::PasswordHistory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::password_history::PasswordHistory>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::tags::Tag {}
-impl flutter_rust_bridge::IntoIntoDart<crate::models::tags::Tag> for crate::models::tags::Tag {
-    fn into_into_dart(self) -> crate::models::tags::Tag {
-        self
+
+impl SseEncode for Vec<crate::models::others::password_policy::PasswordRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::others::password_policy::PasswordRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i64>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::models::tags::Tag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::tags::Tag>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<crate::models::others::health::WeakPassword> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::others::health::WeakPassword>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::models::financial_cards::FinancialCard {
+impl SseEncode for crate::models::logins::Login {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.id, serializer);
@@ -2070,29 +6181,52 @@ impl SseEncode for crate::models::financial_cards::FinancialCard {
         <Option<String>>::sse_encode(self.created_by, serializer);
         <Option<i64>>::sse_encode(self.updated_at, serializer);
         <Option<String>>::sse_encode(self.updated_by, serializer);
-        <String>::sse_encode(self.card_holder_name, serializer);
-        <String>::sse_encode(self.card_number, serializer);
-        <Option<String>>::sse_encode(self.card_provider_name, serializer);
-        <Option<String>>::sse_encode(self.card_type, serializer);
-        <Option<String>>::sse_encode(self.cvv, serializer);
-        <Option<String>>::sse_encode(self.expiry_date, serializer);
-        <Option<String>>::sse_encode(self.issue_date, serializer);
         <String>::sse_encode(self.name, serializer);
         <Option<String>>::sse_encode(self.note, serializer);
-        <Option<String>>::sse_encode(self.pin, serializer);
+        <String>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.url, serializer);
+        <Option<SecretString>>::sse_encode(self.password, serializer);
+        <Option<String>>::sse_encode(self.password_hint, serializer);
         <Option<bool>>::sse_encode(self.is_favorite, serializer);
         <Option<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.api_keys, serializer);
+        <Option<i64>>::sse_encode(self.breach_count, serializer);
+        <Option<i64>>::sse_encode(self.breach_checked_at, serializer);
+        <Option<i64>>::sse_encode(self.history_limit, serializer);
+        <Option<SecretString>>::sse_encode(self.otpauth, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::models::others::authentication::LoginData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.email, serializer);
+        <SecretString>::sse_encode(self.password, serializer);
     }
 }
 
-impl SseEncode for crate::models::identity_cards::IdentityCard {
+impl SseEncode for crate::models::others::authentication::LoginResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::models::others::authentication::LoginResult::Tokens(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::models::others::authentication::JwtTokens>::sse_encode(field0, serializer);
+            }
+            crate::models::others::authentication::LoginResult::TwoFactorRequired(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::models::others::authentication::TwoFactorChallenge>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::models::notes::Note {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.id, serializer);
@@ -2102,87 +6236,92 @@ impl SseEncode for crate::models::identity_cards::IdentityCard {
         <Option<String>>::sse_encode(self.updated_by, serializer);
         <String>::sse_encode(self.name, serializer);
         <Option<String>>::sse_encode(self.note, serializer);
-        <Option<String>>::sse_encode(self.country, serializer);
-        <Option<String>>::sse_encode(self.expiry_date, serializer);
-        <String>::sse_encode(self.identity_card_number, serializer);
-        <Option<String>>::sse_encode(self.identity_card_type, serializer);
-        <Option<String>>::sse_encode(self.issue_date, serializer);
-        <String>::sse_encode(self.name_on_card, serializer);
-        <Option<String>>::sse_encode(self.state, serializer);
         <Option<bool>>::sse_encode(self.is_favorite, serializer);
         <Option<String>>::sse_encode(self.tags, serializer);
     }
 }
 
-impl SseEncode for crate::models::others::authentication::JwtTokens {
+impl SseEncode for Option<SecretString> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.access_token, serializer);
-        <String>::sse_encode(self.refresh_token, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <SecretString>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<crate::models::financial_cards::FinancialCard> {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::models::financial_cards::FinancialCard>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::models::identity_cards::IdentityCard> {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::models::identity_cards::IdentityCard>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::models::logins::Login> {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::models::logins::Login>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::models::notes::Note> {
+impl SseEncode for Option<crate::models::others::rekey_progress::RekeyProgress> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::models::notes::Note>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::others::rekey_progress::RekeyProgress>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::models::tags::Tag> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::models::tags::Tag>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for crate::models::logins::Login {
+impl SseEncode for crate::models::others::otp::OtpCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.code, serializer);
+        <u64>::sse_encode(self.period, serializer);
+        <u64>::sse_encode(self.remaining_seconds, serializer);
+        <Option<u64>>::sse_encode(self.counter, serializer);
+    }
+}
+
+impl SseEncode for crate::models::passkeys::Passkey {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.id, serializer);
@@ -2192,25 +6331,87 @@ impl SseEncode for crate::models::logins::Login {
         <Option<String>>::sse_encode(self.updated_by, serializer);
         <String>::sse_encode(self.name, serializer);
         <Option<String>>::sse_encode(self.note, serializer);
-        <String>::sse_encode(self.username, serializer);
-        <Option<String>>::sse_encode(self.url, serializer);
-        <Option<String>>::sse_encode(self.password, serializer);
-        <Option<String>>::sse_encode(self.password_hint, serializer);
+        <String>::sse_encode(self.rp_id, serializer);
+        <Option<String>>::sse_encode(self.rp_name, serializer);
+        <String>::sse_encode(self.user_handle, serializer);
+        <Option<String>>::sse_encode(self.user_name, serializer);
+        <Option<String>>::sse_encode(self.user_display_name, serializer);
+        <String>::sse_encode(self.credential_id, serializer);
+        <i64>::sse_encode(self.algorithm, serializer);
+        <SecretString>::sse_encode(self.private_key, serializer);
+        <i64>::sse_encode(self.sign_count, serializer);
+        <Option<i64>>::sse_encode(self.last_used_at, serializer);
         <Option<bool>>::sse_encode(self.is_favorite, serializer);
         <Option<String>>::sse_encode(self.tags, serializer);
-        <Option<String>>::sse_encode(self.api_keys, serializer);
     }
 }
 
-impl SseEncode for crate::models::others::authentication::LoginData {
+impl SseEncode for crate::models::others::passkey::PasskeyAssertion {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.email, serializer);
-        <String>::sse_encode(self.password, serializer);
+        <String>::sse_encode(self.credential_id, serializer);
+        <String>::sse_encode(self.authenticator_data, serializer);
+        <String>::sse_encode(self.signature, serializer);
+        <String>::sse_encode(self.user_handle, serializer);
     }
 }
 
-impl SseEncode for crate::models::notes::Note {
+impl SseEncode for crate::models::others::passkey::PasskeyAssertionRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.rp_id, serializer);
+        <String>::sse_encode(self.client_data_hash, serializer);
+        <bool>::sse_encode(self.user_verified, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::passkey::PasskeyCreation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.rp_id, serializer);
+        <Option<String>>::sse_encode(self.rp_name, serializer);
+        <String>::sse_encode(self.user_handle, serializer);
+        <Option<String>>::sse_encode(self.user_name, serializer);
+        <Option<String>>::sse_encode(self.user_display_name, serializer);
+        <String>::sse_encode(self.client_data_hash, serializer);
+        <Vec<i64>>::sse_encode(self.algorithms, serializer);
+        <bool>::sse_encode(self.user_verified, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::passkey::PasskeyRegistration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::passkeys::Passkey>::sse_encode(self.passkey, serializer);
+        <String>::sse_encode(self.credential_id, serializer);
+        <String>::sse_encode(self.attestation_object, serializer);
+        <String>::sse_encode(self.authenticator_data, serializer);
+        <String>::sse_encode(self.public_key, serializer);
+        <i64>::sse_encode(self.public_key_algorithm, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::generator::PassphraseOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.words, serializer);
+        <String>::sse_encode(self.separator, serializer);
+        <crate::models::others::generator::Capitalization>::sse_encode(
+            self.capitalization,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::others::strength::PasswordFeedback {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.warning, serializer);
+        <Vec<String>>::sse_encode(self.suggestions, serializer);
+    }
+}
+
+impl SseEncode for crate::models::password_history::PasswordHistory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.id, serializer);
@@ -2218,43 +6419,145 @@ impl SseEncode for crate::models::notes::Note {
         <Option<String>>::sse_encode(self.created_by, serializer);
         <Option<i64>>::sse_encode(self.updated_at, serializer);
         <Option<String>>::sse_encode(self.updated_by, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <Option<String>>::sse_encode(self.note, serializer);
-        <Option<bool>>::sse_encode(self.is_favorite, serializer);
-        <Option<String>>::sse_encode(self.tags, serializer);
+        <String>::sse_encode(self.login_id, serializer);
+        <SecretString>::sse_encode(self.password, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::models::others::generator::PasswordOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <u32>::sse_encode(self.length, serializer);
+        <bool>::sse_encode(self.uppercase, serializer);
+        <bool>::sse_encode(self.lowercase, serializer);
+        <bool>::sse_encode(self.digits, serializer);
+        <bool>::sse_encode(self.symbols, serializer);
+        <u32>::sse_encode(self.min_uppercase, serializer);
+        <u32>::sse_encode(self.min_lowercase, serializer);
+        <u32>::sse_encode(self.min_digits, serializer);
+        <u32>::sse_encode(self.min_symbols, serializer);
+        <Option<String>>::sse_encode(self.symbol_set, serializer);
+        <bool>::sse_encode(self.exclude_ambiguous, serializer);
     }
 }
 
-impl SseEncode for Option<bool> {
+impl SseEncode for crate::models::others::password_policy::PasswordPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
-        }
+        <u32>::sse_encode(self.min_length, serializer);
+        <u32>::sse_encode(self.max_length, serializer);
+        <bool>::sse_encode(self.require_uppercase, serializer);
+        <bool>::sse_encode(self.require_lowercase, serializer);
+        <bool>::sse_encode(self.require_digit, serializer);
+        <bool>::sse_encode(self.require_symbol, serializer);
+        <u8>::sse_encode(self.min_score, serializer);
+        <bool>::sse_encode(self.allow_unicode, serializer);
+        <Vec<String>>::sse_encode(self.banned_words, serializer);
     }
 }
 
-impl SseEncode for Option<i64> {
+impl SseEncode for crate::models::others::password_policy::PasswordRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
+        match self {
+            crate::models::others::password_policy::PasswordRule::TooShort { min_length } => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(min_length, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::TooLong { max_length } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(max_length, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::MissingUppercase => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::MissingLowercase => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::MissingDigit => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::MissingSymbol => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::TooWeak { score, min_score } => {
+                <i32>::sse_encode(6, serializer);
+                <u8>::sse_encode(score, serializer);
+                <u8>::sse_encode(min_score, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::UnicodeNotAllowed => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::models::others::password_policy::PasswordRule::BannedWord { word } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(word, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
+impl SseEncode for crate::models::others::strength::PasswordStrength {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.score, serializer);
+        <f64>::sse_encode(self.guesses, serializer);
+        <f64>::sse_encode(self.guesses_log10, serializer);
+        <f64>::sse_encode(self.crack_time_seconds, serializer);
+        <String>::sse_encode(self.crack_time_display, serializer);
+        <crate::models::others::strength::PasswordFeedback>::sse_encode(self.feedback, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::generator::PronounceableOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.length, serializer);
+        <bool>::sse_encode(self.capitalize, serializer);
+        <u32>::sse_encode(self.digits, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::qr::QrErrorCorrection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::others::qr::QrErrorCorrection::Low => 0,
+                crate::models::others::qr::QrErrorCorrection::Medium => 1,
+                crate::models::others::qr::QrErrorCorrection::Quartile => 2,
+                crate::models::others::qr::QrErrorCorrection::High => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::others::qr::QrMatrix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.size, serializer);
+        <Vec<bool>>::sse_encode(self.modules, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::qr::QrOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::others::qr::QrErrorCorrection>::sse_encode(
+            self.error_correction,
+            serializer,
+        );
+        <u32>::sse_encode(self.quiet_zone, serializer);
+        <u32>::sse_encode(self.module_size, serializer);
+    }
+}
+
 impl SseEncode for (bool, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2263,13 +6566,60 @@ impl SseEncode for (bool, String) {
     }
 }
 
+impl SseEncode for crate::models::others::authentication::RecoverAccountData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.email, serializer);
+        <SecretString>::sse_encode(self.recovery_key, serializer);
+        <SecretString>::sse_encode(self.new_password, serializer);
+        <SecretString>::sse_encode(self.re_password, serializer);
+        <Option<String>>::sse_encode(self.two_factor_code, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::authentication::RefreshTokenData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.refresh_token, serializer);
+    }
+}
+
 impl SseEncode for crate::models::others::authentication::RegisterData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.name, serializer);
         <String>::sse_encode(self.email, serializer);
-        <String>::sse_encode(self.password, serializer);
-        <String>::sse_encode(self.re_password, serializer);
+        <SecretString>::sse_encode(self.password, serializer);
+        <SecretString>::sse_encode(self.re_password, serializer);
+        <bool>::sse_encode(self.recovery_key, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::rekey_progress::RekeyProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <bool>::sse_encode(self.finished, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::rekey_progress::RekeyResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::others::rekey_progress::RekeyProgress>::sse_encode(
+            self.progress,
+            serializer,
+        );
+        <Option<SecretString>>::sse_encode(self.recovery_key, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::share_settings::ShareSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.threshold, serializer);
+        <u8>::sse_encode(self.shares, serializer);
     }
 }
 
@@ -2285,6 +6635,59 @@ impl SseEncode for crate::models::tags::Tag {
     }
 }
 
+impl SseEncode for crate::models::others::two_factor::TwoFactorBackupCodes {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<SecretString>>::sse_encode(self.codes, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::authentication::TwoFactorChallenge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.challenge_token, serializer);
+        <u64>::sse_encode(self.expires_in, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::authentication::TwoFactorLoginData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.challenge_token, serializer);
+        <String>::sse_encode(self.code, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::two_factor::TwoFactorSetup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <SecretString>::sse_encode(self.secret, serializer);
+        <SecretString>::sse_encode(self.otpauth_url, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::two_factor::TwoFactorStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.backup_codes_remaining, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2297,10 +6700,28 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::models::others::health::VaultHealth {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <u32>::sse_encode(self.total, serializer);
+        <u32>::sse_encode(self.reused_count, serializer);
+        <u32>::sse_encode(self.weak_count, serializer);
+        <u32>::sse_encode(self.stale_count, serializer);
+        <u32>::sse_encode(self.empty_count, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.reused, serializer);
+        <Vec<crate::models::others::health::WeakPassword>>::sse_encode(self.weak, serializer);
+        <Vec<String>>::sse_encode(self.stale, serializer);
+        <u32>::sse_encode(self.stale_days, serializer);
+        <Vec<String>>::sse_encode(self.empty, serializer);
+    }
+}
+
+impl SseEncode for crate::models::others::health::WeakPassword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <u8>::sse_encode(self.score, serializer);
+        <Option<String>>::sse_encode(self.warning, serializer);
     }
 }
 
@@ -2312,6 +6733,7 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::secret::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -2334,6 +6756,7 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::secret::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -1,5 +1,6 @@
 use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
 use flutter_rust_bridge::frb;
+use security::SecretString;
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -42,7 +43,7 @@ pub struct FinancialCard {
     pub card_holder_name: String,
 
     #[serde(rename = "card_number")]
-    pub card_number: String,
+    pub card_number: SecretString,
 
     #[serde(rename = "card_provider_name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,7 +55,7 @@ pub struct FinancialCard {
 
     #[serde(rename = "cvv")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cvv: Option<String>,
+    pub cvv: Option<SecretString>,
 
     #[serde(rename = "expiry_date")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +74,7 @@ pub struct FinancialCard {
 
     #[serde(rename = "pin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pin: Option<String>,
+    pub pin: Option<SecretString>,
 
     #[serde(rename = "is_favorite")]
     pub is_favorite: Option<bool>,
@@ -1,5 +1,6 @@
 use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
 use flutter_rust_bridge::frb;
+use security::SecretString;
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -54,7 +55,7 @@ pub struct IdentityCard {
     pub expiry_date: Option<String>,
 
     #[serde(rename = "identity_card_number")]
-    pub identity_card_number: String,
+    pub identity_card_number: SecretString,
 
     #[serde(rename = "identity_card_type")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,5 +1,6 @@
 use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
 use flutter_rust_bridge::frb;
+use security::SecretString;
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -54,7 +55,7 @@ pub struct Login {
 
     #[serde(rename = "password")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<SecretString>,
 
     #[serde(rename = "password_hint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,4 +71,23 @@ pub struct Login {
     // JSON array of API keys
     #[serde(rename = "api_keys")]
     pub api_keys: Option<String>,
+
+    // Times the password appears in the breach dataset, unset until checked
+    #[serde(rename = "breach_count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breach_count: Option<i64>,
+
+    #[serde(rename = "breach_checked_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breach_checked_at: Option<i64>,
+
+    // Previous passwords kept, 10 when unset and none when 0
+    #[serde(rename = "history_limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_limit: Option<i64>,
+
+    // `otpauth://` or `steam://` URI of the login's authenticator
+    #[serde(rename = "otpauth")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otpauth: Option<SecretString>,
 }
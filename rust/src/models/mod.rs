@@ -3,13 +3,19 @@ pub mod identity_cards;
 pub mod logins;
 pub mod notes;
 pub mod others;
+pub mod passkeys;
+pub mod password_history;
 pub mod tags;
 pub mod users;
+pub mod vault_keys;
 
 pub use financial_cards::*;
 pub use identity_cards::*;
 pub use logins::*;
 pub use notes::*;
 pub use others::*;
+pub use passkeys::*;
+pub use password_history::*;
 pub use tags::*;
 pub use users::*;
+pub use vault_keys::*;
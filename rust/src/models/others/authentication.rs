@@ -1,4 +1,5 @@
 use flutter_rust_bridge::frb;
+use security::SecretString;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -6,8 +7,11 @@ use serde::{Deserialize, Serialize};
 pub struct RegisterData {
     pub name: String,
     pub email: String,
-    pub password: String,
-    pub re_password: String,
+    pub password: SecretString,
+    pub re_password: SecretString,
+    /// Whether to issue a recovery key along with the account
+    #[serde(default)]
+    pub recovery_key: bool,
 }
 
 impl RegisterData {
@@ -24,12 +28,75 @@ impl RegisterData {
 #[frb(dart_metadata=("freezed"))]
 pub struct LoginData {
     pub email: String,
-    pub password: String,
+    pub password: SecretString,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct UnlockVaultData {
+    pub master_password: SecretString,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct ChangePasswordData {
+    pub old_password: SecretString,
+    pub new_password: SecretString,
+    pub re_password: SecretString,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct RecoverAccountData {
+    pub email: String,
+    pub recovery_key: SecretString,
+    pub new_password: SecretString,
+    pub re_password: SecretString,
+    /// A code from the authenticator app, or one of the backup codes, when
+    /// two-factor authentication is on
+    #[serde(default)]
+    pub two_factor_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct RefreshTokenData {
+    pub refresh_token: SecretString,
 }
 
 #[derive(Debug, Serialize)]
 #[frb(dart_metadata=("freezed"))]
 pub struct JwtTokens {
-    pub access_token: String,
-    pub refresh_token: String,
+    pub access_token: SecretString,
+    pub refresh_token: SecretString,
+    /// A newly issued recovery key, shown to the user once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_key: Option<SecretString>,
+}
+
+/// Outcome of signing in with a password.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+#[frb(dart_metadata=("freezed"))]
+pub enum LoginResult {
+    Tokens(JwtTokens),
+    /// The account has two-factor authentication on, the login completes
+    /// with a code for the challenge
+    TwoFactorRequired(TwoFactorChallenge),
+}
+
+#[derive(Debug, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorChallenge {
+    pub challenge_token: SecretString,
+    /// Seconds until the challenge expires
+    pub expires_in: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorLoginData {
+    pub challenge_token: SecretString,
+    /// A code from the authenticator app, or one of the backup codes
+    pub code: String,
 }
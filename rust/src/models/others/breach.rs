@@ -0,0 +1,22 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of checking a user's login passwords against the breach index.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct BreachReport {
+    /// Logins with a password that were checked
+    pub checked: u32,
+    pub breached_count: u32,
+    /// Most exposed passwords first
+    pub breached: Vec<BreachedLogin>,
+    pub checked_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct BreachedLogin {
+    pub id: String,
+    /// Times the password appears in the breach dataset
+    pub count: u32,
+}
@@ -0,0 +1,82 @@
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// What kind of password to generate and how.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+#[frb(dart_metadata=("freezed"))]
+pub enum GeneratorOptions {
+    Password(PasswordOptions),
+    Pronounceable(PronounceableOptions),
+    Passphrase(PassphraseOptions),
+}
+
+/// Random characters drawn from the enabled character classes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasswordOptions {
+    pub length: u32,
+    pub uppercase: bool,
+    pub lowercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    /// Minimum number of characters of each enabled class
+    #[serde(default)]
+    pub min_uppercase: u32,
+    #[serde(default)]
+    pub min_lowercase: u32,
+    #[serde(default)]
+    pub min_digits: u32,
+    #[serde(default)]
+    pub min_symbols: u32,
+    /// Symbols to draw from instead of the default set
+    #[serde(default)]
+    pub symbol_set: Option<String>,
+    /// Leaves out characters that are easily confused, such as `0` and `O`
+    #[serde(default)]
+    pub exclude_ambiguous: bool,
+}
+
+/// Alternating consonants and vowels that can be read out loud.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PronounceableOptions {
+    pub length: u32,
+    /// Capitalizes the first letter
+    #[serde(default)]
+    pub capitalize: bool,
+    /// Number of random digits appended
+    #[serde(default)]
+    pub digits: u32,
+}
+
+/// Random words from the embedded wordlist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PassphraseOptions {
+    pub words: u32,
+    pub separator: String,
+    #[serde(default)]
+    pub capitalization: Capitalization,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Capitalization {
+    #[default]
+    Lowercase,
+    Uppercase,
+    /// First letter of every word
+    Capitalize,
+    /// First letter of each word at random, one more bit per word
+    Random,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct GeneratedPassword {
+    pub password: SecretString,
+    /// Entropy of the generator settings in bits
+    pub entropy_bits: f64,
+}
@@ -0,0 +1,40 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// Problems with the passwords of a user's logins, by login id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct VaultHealth {
+    /// Logins checked
+    pub total: u32,
+    /// Logins sharing a password with at least one other login
+    pub reused_count: u32,
+    pub weak_count: u32,
+    pub stale_count: u32,
+    pub empty_count: u32,
+    /// Each group holds the ids of logins with the same password
+    pub reused: Vec<Vec<String>>,
+    pub weak: Vec<WeakPassword>,
+    /// Logins whose password has not changed for `stale_days` days
+    pub stale: Vec<String>,
+    pub stale_days: u32,
+    /// Logins without a password
+    pub empty: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct WeakPassword {
+    pub id: String,
+    /// 0 (too guessable) to 4 (very unguessable), see `PasswordStrength`
+    pub score: u8,
+    pub warning: Option<String>,
+}
+
+/// Options of a vault health report.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct HealthQuery {
+    /// Passwords older than this many days are stale, 365 by default
+    pub stale_days: Option<u32>,
+}
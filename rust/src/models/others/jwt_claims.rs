@@ -20,4 +20,21 @@ pub struct Claims {
     pub exp: usize,
     // User ID
     pub uid: String,
+    // Token epoch of the user, see `revoke_sessions`
+    #[serde(default)]
+    pub epoch: i64,
+}
+
+/// Key ids new tokens are signed with, after a rotation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JwtKeyIds {
+    pub access_kid: String,
+    pub refresh_kid: String,
+}
+
+/// Options of an admin key rotation.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JwtRotateQuery {
+    /// Drops the previous keys, tokens signed with them stop working at once
+    pub revoke: Option<bool>,
 }
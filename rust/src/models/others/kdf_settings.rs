@@ -0,0 +1,36 @@
+use flutter_rust_bridge::frb;
+use security::KdfParams;
+use serde::{Deserialize, Serialize};
+
+/// Argon2id parameters used to wrap the vault's data key and to hash
+/// account passwords.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct KdfSettings {
+    /// Memory size in KiB
+    pub memory_cost: u32,
+    /// Number of iterations
+    pub time_cost: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
+
+impl From<KdfParams> for KdfSettings {
+    fn from(params: KdfParams) -> Self {
+        KdfSettings {
+            memory_cost: params.memory_cost,
+            time_cost: params.time_cost,
+            parallelism: params.parallelism,
+        }
+    }
+}
+
+impl From<KdfSettings> for KdfParams {
+    fn from(settings: KdfSettings) -> Self {
+        KdfParams {
+            memory_cost: settings.memory_cost,
+            time_cost: settings.time_cost,
+            parallelism: settings.parallelism,
+        }
+    }
+}
@@ -1,5 +1,29 @@
 pub mod authentication;
+pub mod breach;
+pub mod generator;
+pub mod health;
 pub mod jwt_claims;
+pub mod kdf_settings;
+pub mod otp;
+pub mod passkey;
+pub mod password_policy;
+pub mod qr;
+pub mod rekey_progress;
+pub mod share_settings;
+pub mod strength;
+pub mod two_factor;
 
 pub use authentication::*;
+pub use breach::*;
+pub use generator::*;
+pub use health::*;
 pub use jwt_claims::*;
+pub use kdf_settings::*;
+pub use otp::*;
+pub use passkey::*;
+pub use password_policy::*;
+pub use qr::*;
+pub use rekey_progress::*;
+pub use share_settings::*;
+pub use strength::*;
+pub use two_factor::*;
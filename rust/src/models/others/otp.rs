@@ -0,0 +1,15 @@
+use flutter_rust_bridge::frb;
+use serde::Serialize;
+
+/// A one-time password from the authenticator of a login.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct OtpCode {
+    pub code: String,
+    /// Seconds each code lasts, 0 for counter based codes
+    pub period: u64,
+    /// Seconds until the next code, 0 for counter based codes
+    pub remaining_seconds: u64,
+    /// Counter the code was made for, HOTP only
+    pub counter: Option<u64>,
+}
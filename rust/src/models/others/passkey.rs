@@ -0,0 +1,62 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Passkey;
+
+/// A relying party asking for a new passkey, the parts of
+/// `PublicKeyCredentialCreationOptions` an authenticator uses. Binary
+/// fields are base64url without padding.
+#[derive(Debug, Clone, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasskeyCreation {
+    pub rp_id: String,
+    #[serde(default)]
+    pub rp_name: Option<String>,
+    pub user_handle: String,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    #[serde(default)]
+    pub user_display_name: Option<String>,
+    /// SHA-256 of the client data JSON
+    pub client_data_hash: String,
+    /// COSE algorithms the relying party accepts, most preferred first
+    pub algorithms: Vec<i64>,
+    /// Whether the user was verified, by biometrics or the master password,
+    /// rather than just present
+    #[serde(default)]
+    pub user_verified: bool,
+}
+
+/// The new passkey, and the response for the relying party.
+#[derive(Debug, Clone, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasskeyRegistration {
+    pub passkey: Passkey,
+    pub credential_id: String,
+    pub attestation_object: String,
+    pub authenticator_data: String,
+    /// COSE_Key of the new credential
+    pub public_key: String,
+    pub public_key_algorithm: i64,
+}
+
+/// A relying party asking a passkey to sign in.
+#[derive(Debug, Clone, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasskeyAssertionRequest {
+    pub rp_id: String,
+    /// SHA-256 of the client data JSON
+    pub client_data_hash: String,
+    #[serde(default)]
+    pub user_verified: bool,
+}
+
+/// The response for the relying party, base64url without padding.
+#[derive(Debug, Clone, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasskeyAssertion {
+    pub credential_id: String,
+    pub authenticator_data: String,
+    pub signature: String,
+    pub user_handle: String,
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// Rules account passwords must follow, set by the server admin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasswordPolicy {
+    /// In characters
+    pub min_length: u32,
+    pub max_length: u32,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    /// Any character that is not a letter, digit or space
+    pub require_symbol: bool,
+    /// 0 to 4, see `PasswordStrength`
+    pub min_score: u8,
+    /// Whether characters outside ASCII are allowed
+    pub allow_unicode: bool,
+    /// Words the password must not contain, ignoring case
+    pub banned_words: Vec<String>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 8,
+            max_length: 1024,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            min_score: 3,
+            allow_unicode: true,
+            banned_words: Vec::new(),
+        }
+    }
+}
+
+/// A rule of the [`PasswordPolicy`] a password fails.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+#[frb(dart_metadata=("freezed"))]
+pub enum PasswordRule {
+    TooShort { min_length: u32 },
+    TooLong { max_length: u32 },
+    MissingUppercase,
+    MissingLowercase,
+    MissingDigit,
+    MissingSymbol,
+    TooWeak { score: u8, min_score: u8 },
+    UnicodeNotAllowed,
+    BannedWord { word: String },
+}
+
+impl fmt::Display for PasswordRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordRule::TooShort { min_length } => {
+                write!(f, "must be at least {} characters", min_length)
+            }
+            PasswordRule::TooLong { max_length } => {
+                write!(f, "must be at most {} characters", max_length)
+            }
+            PasswordRule::MissingUppercase => write!(f, "must contain an uppercase letter"),
+            PasswordRule::MissingLowercase => write!(f, "must contain a lowercase letter"),
+            PasswordRule::MissingDigit => write!(f, "must contain a digit"),
+            PasswordRule::MissingSymbol => write!(f, "must contain a symbol"),
+            PasswordRule::TooWeak { score, min_score } => write!(
+                f,
+                "is too easy to guess (strength {} of 4, {} needed)",
+                score, min_score
+            ),
+            PasswordRule::UnicodeNotAllowed => write!(f, "must only contain ASCII characters"),
+            PasswordRule::BannedWord { word } => write!(f, "must not contain \"{}\"", word),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// How much of a QR code can be damaged and still be read, roughly 7%,
+/// 15%, 25% and 30%. Higher levels make bigger codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QrErrorCorrection {
+    Low,
+    #[default]
+    Medium,
+    Quartile,
+    High,
+}
+
+/// How a QR code is encoded and drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct QrOptions {
+    #[serde(default)]
+    pub error_correction: QrErrorCorrection,
+    /// Light modules around the code, scanners need at least 4
+    #[serde(default = "default_quiet_zone")]
+    pub quiet_zone: u32,
+    /// Pixels per module in PNG images, and the size SVG images ask for
+    #[serde(default = "default_module_size")]
+    pub module_size: u32,
+}
+
+fn default_quiet_zone() -> u32 {
+    4
+}
+
+fn default_module_size() -> u32 {
+    8
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        Self {
+            error_correction: QrErrorCorrection::default(),
+            quiet_zone: default_quiet_zone(),
+            module_size: default_module_size(),
+        }
+    }
+}
+
+/// Modules of a QR code, for clients that draw it themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct QrMatrix {
+    /// Modules per side, without the quiet zone
+    pub size: u32,
+    /// Row by row, `true` for dark modules
+    pub modules: Vec<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QrImageFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+/// A payload to render as a QR code image.
+#[derive(Debug, Clone, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct QrRequest {
+    pub payload: String,
+    #[serde(default)]
+    pub format: QrImageFormat,
+    #[serde(flatten)]
+    pub options: QrOptions,
+}
@@ -0,0 +1,25 @@
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// Progress of a job moving every vault item to a new data key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct RekeyProgress {
+    /// Items sealed with the new data key so far
+    pub done: u32,
+    /// Items in the vault
+    pub total: u32,
+    /// Whether the new data key has been committed
+    pub finished: bool,
+}
+
+/// Outcome of a finished re-key job.
+#[derive(Debug, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct RekeyResult {
+    pub progress: RekeyProgress,
+    /// A new recovery key when the vault had one, shown to the user once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_key: Option<SecretString>,
+}
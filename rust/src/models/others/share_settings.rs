@@ -0,0 +1,12 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+/// How a secret is split among trusted people.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct ShareSettings {
+    /// Number of shares needed to recover the secret
+    pub threshold: u8,
+    /// Number of shares handed out
+    pub shares: u8,
+}
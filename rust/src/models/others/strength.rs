@@ -0,0 +1,36 @@
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// How hard a password is to guess.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasswordStrength {
+    /// 0 (too guessable) to 4 (very unguessable)
+    pub score: u8,
+    /// Estimated number of guesses needed to find the password
+    pub guesses: f64,
+    pub guesses_log10: f64,
+    /// Time to find it offline against a slow hash, at 10k guesses per second
+    pub crack_time_seconds: f64,
+    pub crack_time_display: String,
+    pub feedback: PasswordFeedback,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasswordFeedback {
+    /// What makes the password weak, if anything stands out
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+/// A password to rate, along with words the user is likely to put in it.
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct StrengthCheck {
+    pub password: SecretString,
+    /// Such as the user's name and email
+    #[serde(default)]
+    pub user_inputs: Vec<String>,
+}
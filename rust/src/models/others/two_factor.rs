@@ -0,0 +1,36 @@
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// Secret of a pending two-factor enrollment, for the user to add to an
+/// authenticator app.
+#[derive(Debug, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorSetup {
+    /// Base32, for typing into the authenticator
+    pub secret: SecretString,
+    /// `otpauth://` URI, for a QR code
+    pub otpauth_url: SecretString,
+}
+
+/// A code from the authenticator app, or one of the backup codes.
+#[derive(Debug, Deserialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorCode {
+    pub code: String,
+}
+
+/// Single-use codes for logging in without the authenticator. Only shown
+/// once, the account keeps their hashes.
+#[derive(Debug, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorBackupCodes {
+    pub codes: Vec<SecretString>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub backup_codes_remaining: u32,
+}
@@ -0,0 +1,95 @@
+use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// A WebAuthn credential the app signs for as a software authenticator.
+/// Binary fields are base64url without padding, as in WebAuthn JSON.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    SqliteInsert,
+    SqliteSelect,
+    SqliteUpdate,
+    SqliteDelete,
+    SqliteListFilter,
+    Default,
+)]
+#[crudlf(table_name = "passkeys")]
+#[frb(dart_metadata=("freezed"))]
+pub struct Passkey {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(rename = "created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+
+    #[serde(rename = "created_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    #[serde(rename = "updated_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<i64>,
+
+    #[serde(rename = "updated_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "note")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    // Relying party id, the domain the passkey signs in to
+    #[serde(rename = "rp_id")]
+    pub rp_id: String,
+
+    #[serde(rename = "rp_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rp_name: Option<String>,
+
+    // Account id the relying party gave the passkey
+    #[serde(rename = "user_handle")]
+    pub user_handle: String,
+
+    #[serde(rename = "user_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+
+    #[serde(rename = "user_display_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_display_name: Option<String>,
+
+    #[serde(rename = "credential_id")]
+    pub credential_id: String,
+
+    // COSE algorithm, -7 for ES256 and -8 for EdDSA
+    #[serde(rename = "algorithm")]
+    pub algorithm: i64,
+
+    // P-256 scalar or Ed25519 seed
+    #[serde(rename = "private_key")]
+    pub private_key: SecretString,
+
+    #[serde(rename = "sign_count")]
+    pub sign_count: i64,
+
+    #[serde(rename = "last_used_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<i64>,
+
+    #[serde(rename = "is_favorite")]
+    pub is_favorite: Option<bool>,
+
+    // CSV of tags
+    #[serde(rename = "tags")]
+    pub tags: Option<String>,
+}
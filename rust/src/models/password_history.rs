@@ -0,0 +1,49 @@
+use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
+use flutter_rust_bridge::frb;
+use security::SecretString;
+use serde::{Deserialize, Serialize};
+
+/// A password a login had before it was changed.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    SqliteInsert,
+    SqliteSelect,
+    SqliteUpdate,
+    SqliteDelete,
+    SqliteListFilter,
+    Default,
+)]
+#[crudlf(table_name = "password_history")]
+#[frb(dart_metadata=("freezed"))]
+pub struct PasswordHistory {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    // When the password was replaced
+    #[serde(rename = "created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+
+    #[serde(rename = "created_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    #[serde(rename = "updated_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<i64>,
+
+    #[serde(rename = "updated_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+
+    #[serde(rename = "login_id")]
+    pub login_id: String,
+
+    #[serde(rename = "password")]
+    pub password: SecretString,
+}
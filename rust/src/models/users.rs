@@ -48,8 +48,18 @@ pub struct User {
     #[serde(rename = "role")]
     pub role: String,
 
-    #[serde(rename = "two_factor_secret")]
-    pub two_factor_secret: Option<String>, // New field for 2FA secret
+    /// Base32 TOTP secret sealed with the two-factor key, set from the start
+    /// of enrollment
+    #[serde(rename = "two_factor_secret", skip_serializing)]
+    pub two_factor_secret: Option<String>,
+
+    /// Whether logins need a TOTP code, once enrollment is confirmed
+    #[serde(rename = "two_factor_enabled", default)]
+    pub two_factor_enabled: bool,
+
+    /// Time step of the last accepted code, which can't be used again
+    #[serde(rename = "two_factor_last_step", skip_serializing)]
+    pub two_factor_last_step: Option<i64>,
 }
 
 impl From<RegisterData> for User {
@@ -62,9 +72,11 @@ impl From<RegisterData> for User {
             updated_by: None,
             name: data.name,
             email: data.email,
-            password_hash: data.password,
+            password_hash: String::new(),
             role: "user".to_string(),
             two_factor_secret: None,
+            two_factor_enabled: false,
+            two_factor_last_step: None,
         }
     }
 }
@@ -0,0 +1,67 @@
+use crudlf_derive::{SqliteDelete, SqliteInsert, SqliteListFilter, SqliteSelect, SqliteUpdate};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    SqliteInsert,
+    SqliteSelect,
+    SqliteUpdate,
+    SqliteDelete,
+    SqliteListFilter,
+    Default,
+)]
+#[crudlf(table_name = "vault_keys")]
+#[frb(dart_metadata=("freezed"))]
+pub struct VaultKey {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(rename = "created_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+
+    // Owner of the vault
+    #[serde(rename = "created_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    #[serde(rename = "updated_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<i64>,
+
+    #[serde(rename = "updated_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+
+    // Data key wrapped with a key derived from the master password
+    #[serde(rename = "wrapped_data_key")]
+    pub wrapped_data_key: String,
+
+    // Format of the sealed item columns owned by this vault
+    #[serde(rename = "item_version")]
+    pub item_version: i64,
+
+    // Argon2id parameters chosen for this vault, defaults when unset
+    #[serde(rename = "kdf_memory_cost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kdf_memory_cost: Option<i64>,
+
+    #[serde(rename = "kdf_time_cost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kdf_time_cost: Option<i64>,
+
+    #[serde(rename = "kdf_parallelism")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kdf_parallelism: Option<i64>,
+
+    // Data key wrapped with the recovery key, when the user has one
+    #[serde(rename = "recovery_wrapped_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_wrapped_key: Option<String>,
+}
@@ -1,6 +1,7 @@
 use actix_web::HttpResponse;
 use anyhow::Error as AnyhowError;
 use rust_lib_password::common::errors::AppError;
+use serde_json::json;
 use std::fmt;
 
 #[derive(Debug)]
@@ -24,9 +25,17 @@ impl actix_web::ResponseError for MyAppError {
             AppError::PasswordHashError(_) => HttpResponse::InternalServerError().finish(),
             AppError::JWTKeyError(_) => HttpResponse::InternalServerError().finish(),
             AppError::JWTTokenExpired => HttpResponse::Unauthorized().finish(),
+            AppError::RefreshTokenInvalid => HttpResponse::Unauthorized().body(self.0.to_string()),
+            AppError::RefreshTokenReused => HttpResponse::Unauthorized().body(self.0.to_string()),
             AppError::UsernameTooShort => HttpResponse::BadRequest().body(self.0.to_string()),
             AppError::PasswordTooShort => HttpResponse::BadRequest().body(self.0.to_string()),
             AppError::PasswordTooWeak => HttpResponse::BadRequest().body(self.0.to_string()),
+            AppError::PasswordPolicyViolation(violations) => {
+                HttpResponse::BadRequest().json(json!({
+                    "error": self.0.to_string(),
+                    "violations": violations,
+                }))
+            }
             AppError::InvalidEmail => HttpResponse::BadRequest().body(self.0.to_string()),
             AppError::PasswordTooLong => HttpResponse::BadRequest().body(self.0.to_string()),
             AppError::PasswordNotValid => HttpResponse::BadRequest().body(self.0.to_string()),
@@ -44,9 +53,27 @@ impl actix_web::ResponseError for MyAppError {
             AppError::InvalidCredentials => HttpResponse::Unauthorized().body(self.0.to_string()),
             AppError::Unauthorized => HttpResponse::Unauthorized().finish(),
             AppError::TOTPSecretParseError => HttpResponse::InternalServerError().finish(),
+            AppError::InvalidOtpAuthUri(_) => HttpResponse::BadRequest().body(self.0.to_string()),
+            AppError::InvalidPasskey(_) => HttpResponse::BadRequest().body(self.0.to_string()),
             AppError::TOTPAlreadyEnabled => HttpResponse::BadRequest().body(self.0.to_string()),
+            AppError::TwoFactorChallengeInvalid => {
+                HttpResponse::Unauthorized().body(self.0.to_string())
+            }
+            AppError::TwoFactorCodeRequired => {
+                HttpResponse::Unauthorized().body(self.0.to_string())
+            }
+            AppError::TwoFactorLocked => HttpResponse::TooManyRequests().body(self.0.to_string()),
             AppError::InternalServerError => HttpResponse::InternalServerError().finish(),
-            AppError::QRCodeGenerationError => HttpResponse::InternalServerError().finish(),
+            AppError::QRCodeGenerationError(_) => {
+                HttpResponse::BadRequest().body(self.0.to_string())
+            }
+            AppError::VaultLocked => HttpResponse::Locked().body(self.0.to_string()),
+            AppError::InvalidRecoveryKey => HttpResponse::BadRequest().body(self.0.to_string()),
+            AppError::BreachIndexNotLoaded => {
+                HttpResponse::ServiceUnavailable().body(self.0.to_string())
+            }
+            AppError::BreachIndexError(_) => HttpResponse::InternalServerError().finish(),
+            AppError::VaultEncryptionError(_) => HttpResponse::InternalServerError().finish(),
         }
     }
 
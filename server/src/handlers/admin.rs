@@ -0,0 +1,34 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use rust_lib_password::{
+    common::{errors::AppError, jwt_key_manager::rotate_jwt_keys},
+    models::JwtRotateQuery,
+};
+use security::SecretString;
+
+use crate::errors::MyAppError;
+
+/// Bearer token of the server admin, from `ADMIN_TOKEN`. Admin routes are
+/// refused when it is not set.
+pub struct AdminToken(pub Option<SecretString>);
+
+fn check_admin(req: &HttpRequest, admin_token: &AdminToken) -> Result<(), MyAppError> {
+    let token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+    match (token, &admin_token.0) {
+        (Some(token), Some(admin_token)) if SecretString::from(token) == *admin_token => Ok(()),
+        _ => Err(AppError::Unauthorized.into()),
+    }
+}
+
+pub async fn rotate_jwt_keys_handler(
+    req: HttpRequest,
+    admin_token: web::Data<AdminToken>,
+    query: web::Query<JwtRotateQuery>,
+) -> Result<HttpResponse, MyAppError> {
+    check_admin(&req, &admin_token)?;
+    let kids = rotate_jwt_keys(query.revoke.unwrap_or(false))?;
+    Ok(HttpResponse::Ok().json(kids))
+}
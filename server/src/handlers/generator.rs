@@ -0,0 +1,10 @@
+use crate::errors::MyAppError;
+use actix_web::{web, HttpResponse};
+use rust_lib_password::{common::generator::generate, models::GeneratorOptions};
+
+pub async fn generate_password(
+    options: web::Json<GeneratorOptions>,
+) -> Result<HttpResponse, MyAppError> {
+    let generated = generate(&options.into_inner())?;
+    Ok(HttpResponse::Ok().json(generated))
+}
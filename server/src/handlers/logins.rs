@@ -1,7 +1,11 @@
 use crate::errors::MyAppError;
 use actix_web::{web, HttpResponse};
 use rust_lib_password::{
-    common::logins::{add_login, fetch_login, get_all_logins, remove_login, update_login},
+    common::{
+        logins::{add_login, fetch_login, get_all_logins, remove_login, update_login},
+        otp::get_totp_code,
+        password_history::{get_password_history, restore_password},
+    },
     models::{Claims, Login},
 };
 
@@ -46,3 +50,28 @@ pub async fn list_logins(
     let logins = get_all_logins(query.into_inner(), claims.uid.clone()).await?;
     Ok(HttpResponse::Ok().json(logins))
 }
+
+pub async fn list_password_history(
+    id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let history = get_password_history(id.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(history))
+}
+
+pub async fn restore_password_history(
+    path: web::Path<(String, String)>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let (id, history_id) = path.into_inner();
+    let restored_login = restore_password(id, history_id, claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(restored_login))
+}
+
+pub async fn get_login_totp_code(
+    id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let code = get_totp_code(id.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(code))
+}
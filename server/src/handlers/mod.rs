@@ -1,6 +1,14 @@
+pub mod admin;
 pub mod financial_cards;
+pub mod generator;
 pub mod identity_cards;
 pub mod logins;
 pub mod notes;
+pub mod passkeys;
+pub mod qr;
+pub mod reports;
+pub mod strength;
 pub mod tags;
+pub mod two_factor;
 pub mod users;
+pub mod vault;
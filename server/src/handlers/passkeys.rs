@@ -0,0 +1,77 @@
+use crate::errors::MyAppError;
+use actix_web::{web, HttpResponse};
+use rust_lib_password::{
+    common::passkeys::{
+        add_passkey, create_passkey, fetch_passkey, find_passkeys, get_all_passkeys,
+        remove_passkey, sign_passkey_assertion, update_passkey,
+    },
+    models::{Claims, Passkey, PasskeyAssertionRequest, PasskeyCreation},
+};
+
+pub async fn get_passkey(
+    id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let passkey = fetch_passkey(id.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(passkey))
+}
+
+pub async fn import_passkey(
+    passkey: web::Json<Passkey>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let created_passkey = add_passkey(passkey.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Created().json(created_passkey))
+}
+
+pub async fn edit_passkey(
+    id: web::Path<String>,
+    passkey: web::Json<Passkey>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let updated_passkey =
+        update_passkey(id.into_inner(), passkey.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(updated_passkey))
+}
+
+pub async fn delete_passkey(
+    id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let deleted_passkey = remove_passkey(id.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(deleted_passkey))
+}
+
+pub async fn list_passkeys(
+    query: web::Query<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let passkeys = get_all_passkeys(query.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(passkeys))
+}
+
+pub async fn list_passkeys_for_rp(
+    rp_id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let passkeys = find_passkeys(rp_id.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(passkeys))
+}
+
+pub async fn register_passkey(
+    request: web::Json<PasskeyCreation>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let registration = create_passkey(request.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Created().json(registration))
+}
+
+pub async fn passkey_assertion(
+    id: web::Path<String>,
+    request: web::Json<PasskeyAssertionRequest>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, MyAppError> {
+    let assertion =
+        sign_passkey_assertion(id.into_inner(), request.into_inner(), claims.uid.clone()).await?;
+    Ok(HttpResponse::Ok().json(assertion))
+}
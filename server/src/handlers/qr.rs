@@ -0,0 +1,19 @@
+use crate::errors::MyAppError;
+use actix_web::{web, HttpResponse};
+use rust_lib_password::{
+    common::qr::{qr_png, qr_svg},
+    models::{QrImageFormat, QrRequest},
+};
+
+pub async fn qr_code_handler(request: web::Json<QrRequest>) -> Result<HttpResponse, MyAppError> {
+    let payload = request.payload.as_bytes();
+    let response = match request.format {
+        QrImageFormat::Svg => HttpResponse::Ok()
+            .content_type("image/svg+xml")
+            .body(qr_svg(payload, &request.options)?),
+        QrImageFormat::Png => HttpResponse::Ok()
+            .content_type("image/png")
+            .body(qr_png(payload, &request.options)?),
+    };
+    Ok(response)
+}
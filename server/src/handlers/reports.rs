@@ -0,0 +1,37 @@
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use rust_lib_password::{
+    common::{
+        breach::check_breached_logins,
+        errors::AppError,
+        health::{vault_health, DEFAULT_STALE_DAYS},
+    },
+    models::{Claims, HealthQuery},
+};
+
+use crate::errors::MyAppError;
+
+pub async fn vault_health_handler(
+    req: HttpRequest,
+    query: web::Query<HealthQuery>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let stale_days = query.stale_days.unwrap_or(DEFAULT_STALE_DAYS);
+    let health = vault_health(&claims.uid, stale_days).await?;
+    Ok(HttpResponse::Ok().json(health))
+}
+
+pub async fn breach_report_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let report = check_breached_logins(&claims.uid).await?;
+    Ok(HttpResponse::Ok().json(report))
+}
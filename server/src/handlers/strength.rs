@@ -0,0 +1,15 @@
+use crate::errors::MyAppError;
+use actix_web::{web, HttpResponse};
+use rust_lib_password::{common::strength::estimate_strength, models::StrengthCheck};
+
+pub async fn estimate_strength_handler(
+    check: web::Json<StrengthCheck>,
+) -> Result<HttpResponse, MyAppError> {
+    let user_inputs = check
+        .user_inputs
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let strength = estimate_strength(check.password.expose_secret(), &user_inputs);
+    Ok(HttpResponse::Ok().json(strength))
+}
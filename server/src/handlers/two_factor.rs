@@ -0,0 +1,85 @@
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use rust_lib_password::{
+    common::{
+        errors::AppError,
+        two_factor::{
+            begin_two_factor, confirm_two_factor, disable_two_factor, regenerate_backup_codes,
+            two_factor_status,
+        },
+        users::login_two_factor,
+    },
+    models::{Claims, TwoFactorCode, TwoFactorLoginData},
+};
+
+use crate::errors::MyAppError;
+
+pub async fn login_two_factor_handler(
+    data: web::Json<TwoFactorLoginData>,
+) -> Result<HttpResponse, MyAppError> {
+    let tokens = login_two_factor(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(tokens))
+}
+
+pub async fn begin_two_factor_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let setup = begin_two_factor(&claims.uid).await?;
+    Ok(HttpResponse::Ok().json(setup))
+}
+
+pub async fn confirm_two_factor_handler(
+    req: HttpRequest,
+    data: web::Json<TwoFactorCode>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let backup_codes = confirm_two_factor(&claims.uid, &data.code).await?;
+    Ok(HttpResponse::Ok().json(backup_codes))
+}
+
+pub async fn disable_two_factor_handler(
+    req: HttpRequest,
+    data: web::Json<TwoFactorCode>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    disable_two_factor(&claims.uid, &data.code).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn regenerate_backup_codes_handler(
+    req: HttpRequest,
+    data: web::Json<TwoFactorCode>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let backup_codes = regenerate_backup_codes(&claims.uid, &data.code).await?;
+    Ok(HttpResponse::Ok().json(backup_codes))
+}
+
+pub async fn two_factor_status_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let status = two_factor_status(&claims.uid).await?;
+    Ok(HttpResponse::Ok().json(status))
+}
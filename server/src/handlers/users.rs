@@ -2,10 +2,17 @@ use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use rust_lib_password::{
     common::{
         errors::AppError,
-        users::{create_user, login_user, my_profile},
+        refresh_tokens::rotate_refresh_token,
+        users::{change_master_password, create_user, login_user, my_profile, recover_account},
+        validation::password_policy,
+        vault::{create_recovery_key, create_recovery_shares},
+    },
+    models::{
+        ChangePasswordData, Claims, LoginData, RecoverAccountData, RefreshTokenData, RegisterData,
+        ShareSettings,
     },
-    models::{Claims, LoginData, RegisterData},
 };
+use serde_json::json;
 
 use crate::errors::MyAppError;
 
@@ -27,6 +34,27 @@ pub async fn login_user_handler(user: web::Json<LoginData>) -> Result<HttpRespon
     Ok(HttpResponse::Ok().json(token.unwrap()))
 }
 
+pub async fn refresh_token_handler(
+    data: web::Json<RefreshTokenData>,
+) -> Result<HttpResponse, MyAppError> {
+    let tokens = rotate_refresh_token(data.refresh_token.expose_secret())?;
+    Ok(HttpResponse::Ok().json(tokens))
+}
+
+pub async fn recover_account_handler(
+    data: web::Json<RecoverAccountData>,
+) -> Result<HttpResponse, MyAppError> {
+    let tokens = recover_account(data.into_inner()).await;
+    if tokens.is_err() {
+        return Err(MyAppError(tokens.err().unwrap()));
+    }
+    Ok(HttpResponse::Ok().json(tokens.unwrap()))
+}
+
+pub async fn password_policy_handler() -> Result<HttpResponse, MyAppError> {
+    Ok(HttpResponse::Ok().json(password_policy()))
+}
+
 pub async fn my_profile_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
     // Use the `extensions()` method on HttpRequest to get the Claims
     let claims: Claims = req
@@ -42,3 +70,51 @@ pub async fn my_profile_handler(req: HttpRequest) -> Result<HttpResponse, MyAppE
     }
     Ok(HttpResponse::Ok().json(user.unwrap()))
 }
+
+pub async fn change_password_handler(
+    req: HttpRequest,
+    data: web::Json<ChangePasswordData>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let result = change_master_password(claims.uid.clone(), data.into_inner()).await;
+    if result.is_err() {
+        return Err(MyAppError(result.err().unwrap()));
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn recovery_key_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let recovery_key = create_recovery_key(&claims.uid).await;
+    if recovery_key.is_err() {
+        return Err(MyAppError(recovery_key.err().unwrap()));
+    }
+    Ok(HttpResponse::Ok().json(json!({ "recovery_key": recovery_key.unwrap() })))
+}
+
+pub async fn recovery_shares_handler(
+    req: HttpRequest,
+    settings: web::Json<ShareSettings>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    let shares = create_recovery_shares(&claims.uid, &settings.into_inner()).await;
+    if shares.is_err() {
+        return Err(MyAppError(shares.err().unwrap()));
+    }
+    Ok(HttpResponse::Ok().json(json!({ "shares": shares.unwrap() })))
+}
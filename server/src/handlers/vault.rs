@@ -0,0 +1,35 @@
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use rust_lib_password::{
+    common::{
+        errors::AppError,
+        vault::{lock_vault, unlock_vault},
+    },
+    models::{Claims, UnlockVaultData},
+};
+
+use crate::errors::MyAppError;
+
+pub async fn unlock_vault_handler(
+    req: HttpRequest,
+    data: web::Json<UnlockVaultData>,
+) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    unlock_vault(&claims.uid, &data.master_password).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn lock_vault_handler(req: HttpRequest) -> Result<HttpResponse, MyAppError> {
+    let claims: Claims = req
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(|| AppError::Unauthorized)?;
+
+    lock_vault(&claims.uid);
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -4,21 +4,52 @@ use actix_web::{
     App, HttpResponse, HttpServer, Responder,
 };
 use handlers::{
+    admin::{rotate_jwt_keys_handler, AdminToken},
     financial_cards::{
         create_financial_card, delete_financial_card, edit_financial_card, get_financial_card,
         list_financial_cards,
     },
+    generator::generate_password,
     identity_cards::{
         create_identity_card, delete_identity_card, edit_identity_card, get_identity_card,
         list_identity_cards,
     },
-    logins::{create_login, delete_login, edit_login, get_login, list_logins},
+    logins::{
+        create_login, delete_login, edit_login, get_login, get_login_totp_code, list_logins,
+        list_password_history, restore_password_history,
+    },
     notes::{create_note, delete_note, edit_note, get_note, list_notes},
+    passkeys::{
+        delete_passkey, edit_passkey, get_passkey, import_passkey, list_passkeys,
+        list_passkeys_for_rp, passkey_assertion, register_passkey,
+    },
+    qr::qr_code_handler,
+    reports::{breach_report_handler, vault_health_handler},
+    strength::estimate_strength_handler,
     tags::{create_tag, delete_tag, edit_tag, get_tag, list_tags},
-    users::{login_user_handler, my_profile_handler, register_user_handler},
+    two_factor::{
+        begin_two_factor_handler, confirm_two_factor_handler, disable_two_factor_handler,
+        login_two_factor_handler, regenerate_backup_codes_handler, two_factor_status_handler,
+    },
+    users::{
+        change_password_handler, login_user_handler, my_profile_handler, password_policy_handler,
+        recover_account_handler, recovery_key_handler, recovery_shares_handler,
+        refresh_token_handler, register_user_handler,
+    },
+    vault::{lock_vault_handler, unlock_vault_handler},
 };
 use pnet::datalink;
 use rcgen::{generate_simple_self_signed, CertifiedKey};
+use rust_lib_password::{
+    common::{
+        breach::load_breach_index,
+        jwt_key_manager::init_jwt_key_manager,
+        password::{set_password_hash_settings, set_password_pepper},
+        two_factor::set_two_factor_key,
+        validation::set_password_policy,
+    },
+    models::{KdfSettings, PasswordPolicy},
+};
 use rustls::ServerConfig;
 use serde_json::json;
 use std::env;
@@ -47,6 +78,82 @@ async fn main() -> std::io::Result<()> {
         .await
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
 
+    // Offline breached password checks, see `build_breach_index`
+    if let Ok(index_path) = env::var("BREACH_INDEX_PATH") {
+        let records = load_breach_index(std::path::Path::new(&index_path))
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        println!(
+            "{}",
+            json!({
+                "message": "Loaded breach index",
+                "index_path": index_path,
+                "records": records
+            })
+        );
+    }
+
+    // Account password rules, a JSON file with the fields of `PasswordPolicy`
+    if let Ok(policy_path) = env::var("PASSWORD_POLICY_PATH") {
+        let policy = std::fs::read_to_string(&policy_path)
+            .map_err(std::io::Error::other)
+            .and_then(|policy| {
+                serde_json::from_str::<PasswordPolicy>(&policy).map_err(std::io::Error::other)
+            })?;
+        set_password_policy(policy.clone()).map_err(std::io::Error::other)?;
+        println!(
+            "{}",
+            json!({
+                "message": "Using password policy",
+                "policy_path": policy_path,
+                "policy": policy
+            })
+        );
+    }
+
+    // Argon2id parameters of new password hashes, a JSON file with the
+    // fields of `KdfSettings`
+    if let Ok(settings_path) = env::var("PASSWORD_HASH_SETTINGS_PATH") {
+        let settings = std::fs::read_to_string(&settings_path)
+            .map_err(std::io::Error::other)
+            .and_then(|settings| {
+                serde_json::from_str::<KdfSettings>(&settings).map_err(std::io::Error::other)
+            })?;
+        set_password_hash_settings(settings).map_err(std::io::Error::other)?;
+        println!(
+            "{}",
+            json!({
+                "message": "Using password hash settings",
+                "settings_path": settings_path,
+                "settings": settings
+            })
+        );
+    }
+
+    // Secret mixed into account passwords before hashing, at least 16 bytes.
+    // Hashes made with it can't be verified once it is lost or changed.
+    if let Ok(pepper) = env::var("PASSWORD_PEPPER") {
+        set_password_pepper(Some(pepper.as_bytes())).map_err(std::io::Error::other)?;
+        println!("{}", json!({ "message": "Using password pepper" }));
+    }
+
+    // Key sealing the JWT signing keys in the database, 64 hex digits. Without
+    // it the keys only live in memory and a restart signs everyone out.
+    if let Ok(encryption_key) = env::var("JWT_KEY_ENCRYPTION_KEY") {
+        let encryption_key = hex::decode(encryption_key.trim()).map_err(std::io::Error::other)?;
+        init_jwt_key_manager(&encryption_key).map_err(std::io::Error::other)?;
+        println!("{}", json!({ "message": "Using persisted JWT keys" }));
+    }
+
+    // Key sealing TOTP secrets in the database, 64 hex digits. Two-factor
+    // authentication can't be set up without it.
+    if let Ok(two_factor_key) = env::var("TWO_FACTOR_ENCRYPTION_KEY") {
+        let two_factor_key = hex::decode(two_factor_key.trim()).map_err(std::io::Error::other)?;
+        set_two_factor_key(Some(&two_factor_key)).map_err(std::io::Error::other)?;
+        println!("{}", json!({ "message": "Using two-factor key" }));
+    }
+
+    let admin_token = web::Data::new(AdminToken(env::var("ADMIN_TOKEN").ok().map(Into::into)));
+
     let config = MyServerConfig::load_from_env()?;
 
     println!(
@@ -61,22 +168,64 @@ async fn main() -> std::io::Result<()> {
     HttpServer::new(move || {
         App::new()
             .wrap(Logger::default())
+            .app_data(admin_token.clone())
             .service(
                 web::scope("/auth")
                     .route("/", web::get().to(index))
                     .route("/register", web::post().to(register_user_handler))
-                    .route("/login", web::post().to(login_user_handler)),
+                    .route("/login", web::post().to(login_user_handler))
+                    .route("/login/2fa", web::post().to(login_two_factor_handler))
+                    .route("/refresh", web::post().to(refresh_token_handler))
+                    .route("/recover", web::post().to(recover_account_handler))
+                    .route("/password-policy", web::get().to(password_policy_handler)),
+            )
+            .service(
+                web::scope("/admin")
+                    .route("/jwt-keys/rotate", web::post().to(rotate_jwt_keys_handler)),
             )
             .service(
                 web::scope("/api")
                     .wrap(AuthMiddleware)
                     .route("/me", web::get().to(my_profile_handler))
+                    .route("/me/password", web::put().to(change_password_handler))
+                    .route("/me/recovery-key", web::post().to(recovery_key_handler))
+                    .route(
+                        "/me/recovery-shares",
+                        web::post().to(recovery_shares_handler),
+                    )
+                    .route("/me/2fa", web::get().to(two_factor_status_handler))
+                    .route("/me/2fa", web::post().to(begin_two_factor_handler))
+                    .route(
+                        "/me/2fa/confirm",
+                        web::post().to(confirm_two_factor_handler),
+                    )
+                    .route(
+                        "/me/2fa/disable",
+                        web::post().to(disable_two_factor_handler),
+                    )
+                    .route(
+                        "/me/2fa/backup-codes",
+                        web::post().to(regenerate_backup_codes_handler),
+                    )
+                    .route("/vault/unlock", web::post().to(unlock_vault_handler))
+                    .route("/vault/lock", web::post().to(lock_vault_handler))
+                    .route("/generator", web::post().to(generate_password))
+                    .route("/qr", web::post().to(qr_code_handler))
+                    .route("/strength", web::post().to(estimate_strength_handler))
+                    .route("/reports/health", web::get().to(vault_health_handler))
+                    .route("/reports/breaches", web::post().to(breach_report_handler))
                     // Logins
                     .route("/logins", web::post().to(create_login))
                     .route("/logins", web::get().to(list_logins))
                     .route("/logins/{id}", web::get().to(get_login))
                     .route("/logins/{id}", web::put().to(edit_login))
                     .route("/logins/{id}", web::delete().to(delete_login))
+                    .route("/logins/{id}/history", web::get().to(list_password_history))
+                    .route("/logins/{id}/totp", web::get().to(get_login_totp_code))
+                    .route(
+                        "/logins/{id}/history/{history_id}/restore",
+                        web::post().to(restore_password_history),
+                    )
                     // Notes
                     .route("/notes", web::post().to(create_note))
                     .route("/notes", web::get().to(list_notes))
@@ -101,6 +250,18 @@ async fn main() -> std::io::Result<()> {
                         "/identity-cards/{id}",
                         web::delete().to(delete_identity_card),
                     )
+                    // Passkeys
+                    .route("/passkeys", web::post().to(import_passkey))
+                    .route("/passkeys", web::get().to(list_passkeys))
+                    .route("/passkeys/register", web::post().to(register_passkey))
+                    .route("/passkeys/rp/{rp_id}", web::get().to(list_passkeys_for_rp))
+                    .route("/passkeys/{id}", web::get().to(get_passkey))
+                    .route("/passkeys/{id}", web::put().to(edit_passkey))
+                    .route("/passkeys/{id}", web::delete().to(delete_passkey))
+                    .route(
+                        "/passkeys/{id}/assertion",
+                        web::post().to(passkey_assertion),
+                    )
                     // Tags
                     .route("/tags", web::post().to(create_tag))
                     .route("/tags", web::get().to(list_tags))
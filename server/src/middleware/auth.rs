@@ -4,7 +4,7 @@ use actix_web::{
     Error, HttpMessage,
 };
 use futures_util::future::LocalBoxFuture;
-use rust_lib_password::common::{jwt::get_access_claims, time::now};
+use rust_lib_password::common::{refresh_tokens::check_access_token, time::now};
 use std::{
     future::{ready, Ready},
     rc::Rc,
@@ -59,7 +59,7 @@ where
             match auth_header {
                 Some(auth_str) if auth_str.starts_with("Bearer ") => {
                     let token = &auth_str[7..];
-                    let claims = get_access_claims(token);
+                    let claims = check_access_token(token);
 
                     // Check if the token is expired
                     if claims.is_err() {